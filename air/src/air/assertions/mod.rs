@@ -27,8 +27,8 @@ const NO_STRIDE: usize = 0;
 
 /// An assertion made against an execution trace.
 ///
-/// An assertion is always placed against a single column of an execution trace, but can cover
-/// multiple steps and multiple values. Specifically, there are three kinds of assertions:
+/// An assertion is usually placed against a single column of an execution trace, but can cover
+/// multiple steps and multiple values. Specifically, there are three kinds of such assertions:
 ///
 /// 1. **Single** assertion - which requires that a value in a single cell of an execution trace
 ///    is equal to the specified value.
@@ -43,6 +43,11 @@ const NO_STRIDE: usize = 0;
 ///    be equal to a sequence 1, 2, 3, 4 at steps 0, 8, 16, 24. That is, value at step 0 should be
 ///    equal to 1, value at step 8 should be equal to 2 etc.
 ///
+/// Additionally, a **linear combination** assertion requires that a linear combination of values
+/// in several columns at a single step is equal to the specified value. For example, we can
+/// specify that `2 * c0 + c3 - c7` must be equal to 42 at the last step of the trace (where `ci`
+/// is the value in column `i`) without adding a dedicated column for this combination.
+///
 /// Note that single and periodic assertions are succinct. That is, a verifier can evaluate them
 /// very efficiently. However, sequence assertions have liner complexity in the number of
 /// asserted values. Though, unless many thousands of values are asserted, practical impact of
@@ -53,6 +58,7 @@ pub struct Assertion<E: FieldElement> {
     pub(super) first_step: usize,
     pub(super) stride: usize,
     pub(super) values: Vec<E>,
+    pub(super) linear_terms: Vec<(usize, E)>,
 }
 
 impl<E: FieldElement> Assertion<E> {
@@ -68,6 +74,7 @@ impl<E: FieldElement> Assertion<E> {
             first_step: step,
             stride: NO_STRIDE,
             values: vec![value],
+            linear_terms: Vec::new(),
         }
     }
 
@@ -88,6 +95,7 @@ impl<E: FieldElement> Assertion<E> {
             first_step,
             stride,
             values: vec![value],
+            linear_terms: Vec::new(),
         }
     }
 
@@ -119,13 +127,80 @@ impl<E: FieldElement> Assertion<E> {
             first_step,
             stride: if values.len() == 1 { NO_STRIDE } else { stride },
             values,
+            linear_terms: Vec::new(),
         }
     }
 
+    /// Returns an assertion against a linear combination of cells in a single row of an
+    /// execution trace.
+    ///
+    /// The returned assertion requires that the sum of values in the specified columns at the
+    /// specified `step`, each multiplied by its coefficient, is equal to the provided `value`.
+    /// `coefficients` is a list of `(column, coefficient)` tuples; the order of the tuples does
+    /// not matter.
+    ///
+    /// # Panics
+    /// Panics if `coefficients` is empty or references the same column more than once.
+    pub fn linear_combination(coefficients: Vec<(usize, E)>, step: usize, value: E) -> Self {
+        assert!(
+            !coefficients.is_empty(),
+            "invalid linear combination assertion: at least one column must be referenced"
+        );
+        let mut linear_terms = coefficients;
+        linear_terms.sort_by_key(|&(column, _)| column);
+        for terms in linear_terms.windows(2) {
+            assert!(
+                terms[0].0 != terms[1].0,
+                "invalid linear combination assertion: column {} is referenced more than once",
+                terms[0].0
+            );
+        }
+        Assertion {
+            column: linear_terms[0].0,
+            first_step: step,
+            stride: NO_STRIDE,
+            values: vec![value],
+            linear_terms,
+        }
+    }
+
+    /// Returns single assertions against all cells in the first row of an execution trace.
+    ///
+    /// The returned assertions require that the value in column `i` at step 0 is equal to
+    /// `values[i]`.
+    pub fn first_row(values: &[E]) -> Vec<Self> {
+        Self::row(0, values)
+    }
+
+    /// Returns single assertions against all cells in the last row of an execution trace of the
+    /// specified length.
+    ///
+    /// The returned assertions require that the value in column `i` at step `trace_length - 1`
+    /// is equal to `values[i]`.
+    ///
+    /// # Panics
+    /// Panics if `trace_length` is zero.
+    pub fn last_row(trace_length: usize, values: &[E]) -> Vec<Self> {
+        assert!(trace_length > 0, "trace length must be greater than zero");
+        Self::row(trace_length - 1, values)
+    }
+
+    /// Returns single assertions against all cells in the specified row of an execution trace.
+    fn row(step: usize, values: &[E]) -> Vec<Self> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(column, &value)| Self::single(column, step, value))
+            .collect()
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
     /// Returns index of the column against which this assertion is placed.
+    ///
+    /// For linear combination assertions, this is the smallest index of a column referenced by
+    /// the combination.
     pub fn column(&self) -> usize {
         self.column
     }
@@ -151,7 +226,22 @@ impl<E: FieldElement> Assertion<E> {
         &self.values
     }
 
+    /// Returns `(column, coefficient)` tuples of the linear combination asserted by this
+    /// assertion sorted by column index.
+    ///
+    /// For assertions against a single column, this will be an empty slice.
+    pub fn linear_terms(&self) -> &[(usize, E)] {
+        &self.linear_terms
+    }
+
+    /// Returns true if this is a linear combination assertion (many columns, one value, one step).
+    pub fn is_linear_combination(&self) -> bool {
+        !self.linear_terms.is_empty()
+    }
+
     /// Returns true if this is a single-value assertion (one value, one step).
+    ///
+    /// Linear combination assertions are also single-value assertions.
     pub fn is_single(&self) -> bool {
         self.stride == NO_STRIDE
     }
@@ -171,8 +261,13 @@ impl<E: FieldElement> Assertion<E> {
 
     /// Checks if this assertion overlaps with the provided assertion.
     ///
-    /// Overlap is defined as asserting a value for the same step in the same column.
+    /// Overlap is defined as asserting a value for the same step in the same column. A linear
+    /// combination assertion overlaps only with an assertion against the same linear combination
+    /// at the same step.
     pub fn overlaps_with(&self, other: &Assertion<E>) -> bool {
+        if self.is_linear_combination() || other.is_linear_combination() {
+            return self.linear_terms == other.linear_terms && self.first_step == other.first_step;
+        }
         if self.column != other.column {
             return false;
         }
@@ -209,8 +304,10 @@ impl<E: FieldElement> Assertion<E> {
 
     /// Panics if the assertion cannot be placed against an execution trace of the specified width.
     pub fn validate_trace_width(&self, trace_width: usize) -> Result<(), AssertionError> {
-        if self.column >= trace_width {
-            return Err(AssertionError::TraceWidthTooShort(self.column, trace_width));
+        // linear terms are sorted by column, and thus, the last term references the largest column
+        let max_column = self.linear_terms.last().map_or(self.column, |&(column, _)| column);
+        if max_column >= trace_width {
+            return Err(AssertionError::TraceWidthTooShort(max_column, trace_width));
         }
         Ok(())
     }
@@ -272,6 +369,24 @@ impl<E: FieldElement> Assertion<E> {
         }
     }
 
+    /// Returns the value of the expression constrained by this assertion given a function which
+    /// returns the value of the specified column at the asserted step.
+    ///
+    /// For assertions against a single column, this is the value of that column; for linear
+    /// combination assertions, this is the value of the linear combination.
+    pub fn evaluate_columns<F>(&self, column_value: F) -> E
+    where
+        F: Fn(usize) -> E,
+    {
+        if self.is_linear_combination() {
+            self.linear_terms
+                .iter()
+                .fold(E::ZERO, |acc, &(column, coeff)| acc + coeff * column_value(column))
+        } else {
+            column_value(self.column)
+        }
+    }
+
     /// Returns the number of steps against which this assertion will be applied given an
     /// execution trace of the specified length.
     ///
@@ -299,12 +414,17 @@ impl<E: FieldElement> Assertion<E> {
 // =================================================================================================
 
 /// We define ordering of assertions to be first by stride, then by first_step, and finally by
-/// column in ascending order.
+/// column in ascending order. Assertions against the same column and step are ordered by their
+/// linear terms, where assertions against a single column come first.
 impl<E: FieldElement> Ord for Assertion<E> {
     fn cmp(&self, other: &Self) -> Ordering {
         if self.stride == other.stride {
             if self.first_step == other.first_step {
-                self.column.partial_cmp(&other.column).unwrap()
+                self.column.cmp(&other.column).then_with(|| {
+                    let self_terms = self.linear_terms.iter().map(|(c, e)| (c, e.to_bytes()));
+                    let other_terms = other.linear_terms.iter().map(|(c, e)| (c, e.to_bytes()));
+                    self_terms.cmp(other_terms)
+                })
             } else {
                 self.first_step.partial_cmp(&other.first_step).unwrap()
             }
@@ -322,6 +442,16 @@ impl<E: FieldElement> PartialOrd for Assertion<E> {
 
 impl<E: FieldElement> Display for Assertion<E> {
     fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        if self.is_linear_combination() {
+            write!(f, "(combination=")?;
+            for (i, (column, coeff)) in self.linear_terms.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " + ")?;
+                }
+                write!(f, "{coeff}*c{column}")?;
+            }
+            return write!(f, ", step={}, value={})", self.first_step, self.values[0]);
+        }
         write!(f, "(column={}, ", self.column)?;
         match self.stride {
             0 => write!(f, "step={}, ", self.first_step)?,
@@ -176,9 +176,85 @@ fn sequence_assertion_num_values_not_power_of_two() {
         Assertion::sequence(3, 2, 4, vec![BaseElement::ONE, BaseElement::ZERO, BaseElement::ONE]);
 }
 
+// LINEAR COMBINATION ASSERTIONS
+// ================================================================================================
+
+#[test]
+fn linear_combination_assertion() {
+    let value = rand_value::<BaseElement>();
+    let two = BaseElement::new(2);
+    let three = BaseElement::new(3);
+    let a = Assertion::linear_combination(vec![(4, three), (1, two)], 8, value);
+    assert_eq!(1, a.column);
+    assert_eq!(8, a.first_step);
+    assert_eq!(vec![value], a.values);
+    assert_eq!(0, a.stride);
+    assert_eq!(&[(1, two), (4, three)], a.linear_terms());
+    assert!(a.is_linear_combination());
+    assert!(a.is_single());
+    assert_eq!(1, a.get_num_steps(16));
+
+    let row = rand_vector::<BaseElement>(5);
+    assert_eq!(two * row[1] + three * row[4], a.evaluate_columns(|column| row[column]));
+
+    // the width of the trace is validated against the largest referenced column
+    assert_eq!(Ok(()), a.validate_trace_width(5));
+    assert_eq!(Err(AssertionError::TraceWidthTooShort(4, 4)), a.validate_trace_width(4));
+
+    assert_eq!(Ok(()), a.validate_trace_length(16));
+    assert_eq!(Err(AssertionError::TraceLengthTooShort(16, 8)), a.validate_trace_length(8));
+}
+
+#[test]
+#[should_panic(
+    expected = "invalid linear combination assertion: at least one column must be referenced"
+)]
+fn linear_combination_assertion_no_columns() {
+    let _ = Assertion::linear_combination(Vec::new(), 0, BaseElement::ONE);
+}
+
+#[test]
+#[should_panic(
+    expected = "invalid linear combination assertion: column 2 is referenced more than once"
+)]
+fn linear_combination_assertion_duplicate_columns() {
+    let terms = vec![(2, BaseElement::ONE), (0, BaseElement::ONE), (2, BaseElement::ONE)];
+    let _ = Assertion::linear_combination(terms, 0, BaseElement::ONE);
+}
+
+#[test]
+fn linear_combination_assertion_ordering() {
+    let terms_a = vec![(0, BaseElement::ONE), (1, BaseElement::ONE)];
+    let terms_b = vec![(0, BaseElement::ONE), (1, BaseElement::new(2))];
+    let a = Assertion::linear_combination(terms_a, 4, BaseElement::ONE);
+    let b = Assertion::linear_combination(terms_b, 4, BaseElement::ONE);
+    let c = Assertion::single(0, 4, BaseElement::ONE);
+
+    // combinations with different coefficients are different assertions
+    assert_ne!(core::cmp::Ordering::Equal, a.cmp(&b));
+
+    // assertions against a single column come before combinations starting at the same column
+    assert!(c < a);
+    assert!(c < b);
+}
+
 // OVERLAPPING ASSERTIONS
 // ================================================================================================
 
+#[test]
+fn row_assertions() {
+    let values = rand_vector::<BaseElement>(3);
+    let first = Assertion::first_row(&values);
+    let last = Assertion::last_row(16, &values);
+    for (column, &value) in values.iter().enumerate() {
+        assert_eq!(Assertion::single(column, 0, value), first[column]);
+        assert_eq!(Assertion::single(column, 15, value), last[column]);
+    }
+    assert_eq!(3, first.len());
+    assert_eq!(3, last.len());
+    assert!(Assertion::<BaseElement>::first_row(&[]).is_empty());
+}
+
 #[test]
 fn assertion_overlap() {
     // ----- single-single overlap ----------------------------------------------------------------
@@ -339,4 +415,27 @@ fn assertion_overlap() {
     let b = Assertion::periodic(0, 0, 16, BaseElement::ONE);
     assert!(!a.overlaps_with(&b));
     assert!(!b.overlaps_with(&a));
+
+    // ----- linear combination overlap -----------------------------------------------------------
+
+    let terms = vec![(1, BaseElement::ONE), (3, BaseElement::new(2))];
+    let a = Assertion::linear_combination(terms.clone(), 2, BaseElement::ONE);
+    let b = Assertion::linear_combination(terms.clone(), 2, BaseElement::ZERO);
+    assert!(a.overlaps_with(&b));
+    assert!(b.overlaps_with(&a));
+
+    // different steps: no overlap
+    let b = Assertion::linear_combination(terms, 3, BaseElement::ONE);
+    assert!(!a.overlaps_with(&b));
+    assert!(!b.overlaps_with(&a));
+
+    // different combinations: no overlap
+    let b = Assertion::linear_combination(vec![(1, BaseElement::ONE)], 2, BaseElement::ONE);
+    assert!(!a.overlaps_with(&b));
+    assert!(!b.overlaps_with(&a));
+
+    // assertions against a referenced column: no overlap
+    let b = Assertion::single(1, 2, BaseElement::ONE);
+    assert!(!a.overlaps_with(&b));
+    assert!(!b.overlaps_with(&a));
 }
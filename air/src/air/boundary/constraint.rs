@@ -20,6 +20,9 @@ use super::{Assertion, ExtensionOf, FieldElement};
 /// * $b(b)$ is the value polynomial for this constraint.
 /// * $z(x)$ is the constraint divisor polynomial.
 ///
+/// For constraints derived from linear combination assertions, $f(x)$ is the linear combination
+/// of trace polynomials for the columns referenced by the assertion, and $b(x)$ is a constant.
+///
 /// In addition to the value polynomial, a [BoundaryConstraint] also contains info needed to
 /// evaluate the constraint and to compose constraint evaluations with other constraints (i.e.,
 /// constraint composition coefficient).
@@ -38,6 +41,7 @@ where
     E: FieldElement<BaseField = F::BaseField> + ExtensionOf<F>,
 {
     column: usize,
+    linear_terms: Vec<(usize, F)>,
     poly: Vec<F>,
     poly_offset: (usize, F::BaseField),
     cc: E,
@@ -84,6 +88,7 @@ where
 
         BoundaryConstraint {
             column: assertion.column,
+            linear_terms: assertion.linear_terms,
             poly,
             poly_offset,
             cc: composition_coefficient,
@@ -98,6 +103,14 @@ where
         self.column
     }
 
+    /// Returns `(column, coefficient)` tuples of the linear combination of columns against which
+    /// this constraint applies.
+    ///
+    /// For constraints against a single column, this will be an empty slice.
+    pub fn linear_terms(&self) -> &[(usize, F)] {
+        &self.linear_terms
+    }
+
     /// Returns a value polynomial for this constraint.
     pub fn poly(&self) -> &[F] {
         &self.poly
@@ -119,6 +132,22 @@ where
 
     // CONSTRAINT EVALUATOR
     // --------------------------------------------------------------------------------------------
+    /// Returns the value of the trace polynomial $f$ for this constraint given the values of all
+    /// trace polynomials in `state`.
+    ///
+    /// For constraints derived from linear combination assertions, this is the linear
+    /// combination of the referenced values; otherwise, this is the value for the column against
+    /// which the constraint is placed.
+    pub fn trace_value(&self, state: &[E]) -> E {
+        if self.linear_terms.is_empty() {
+            state[self.column]
+        } else {
+            self.linear_terms
+                .iter()
+                .fold(E::ZERO, |acc, &(column, coeff)| acc + E::from(coeff) * state[column])
+        }
+    }
+
     /// Evaluates this constraint at the specified point `x`.
     ///
     /// The constraint is evaluated by computing $f(x) - b(x)$, where:
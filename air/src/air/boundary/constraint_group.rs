@@ -101,7 +101,7 @@ where
     pub fn evaluate_at(&self, state: &[E], x: E) -> E {
         let mut numerator = E::ZERO;
         for constraint in self.constraints().iter() {
-            let trace_value = state[constraint.column()];
+            let trace_value = constraint.trace_value(state);
             let evaluation = constraint.evaluate_at(x, trace_value);
             numerator += evaluation * *constraint.cc();
         }
@@ -3,12 +3,15 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 use core::cmp;
 
 use math::StarkField;
 
-use crate::{air::TransitionConstraintDegree, ProofOptions, TraceInfo};
+use crate::{
+    air::{TransitionConstraintDegree, TransitionExemption},
+    ProofOptions, TraceInfo,
+};
 
 // AIR CONTEXT
 // ================================================================================================
@@ -26,6 +29,9 @@ pub struct AirContext<B: StarkField> {
     pub(super) trace_domain_generator: B,
     pub(super) lde_domain_generator: B,
     pub(super) num_transition_exemptions: usize,
+    pub(super) transition_exemptions: BTreeMap<usize, TransitionExemption>,
+    pub(super) frame_size: usize,
+    pub(super) num_composition_columns: Option<usize>,
 }
 
 impl<B: StarkField> AirContext<B> {
@@ -133,32 +139,10 @@ impl<B: StarkField> AirContext<B> {
             );
         }
 
-        // determine minimum blowup factor needed to evaluate transition constraints by taking
-        // the blowup factor of the highest degree constraint
-        let mut ce_blowup_factor = 0;
-        for degree in main_transition_constraint_degrees.iter() {
-            if degree.min_blowup_factor() > ce_blowup_factor {
-                ce_blowup_factor = degree.min_blowup_factor();
-            }
-        }
-
-        for degree in aux_transition_constraint_degrees.iter() {
-            if degree.min_blowup_factor() > ce_blowup_factor {
-                ce_blowup_factor = degree.min_blowup_factor();
-            }
-        }
-
-        assert!(
-            options.blowup_factor() >= ce_blowup_factor,
-            "blowup factor too small; expected at least {}, but was {}",
-            ce_blowup_factor,
-            options.blowup_factor()
-        );
-
         let trace_length = trace_info.length();
         let lde_domain_size = trace_length * options.blowup_factor();
 
-        AirContext {
+        let mut context = AirContext {
             options,
             trace_info,
             main_transition_constraint_degrees,
@@ -166,11 +150,19 @@ impl<B: StarkField> AirContext<B> {
             num_main_assertions,
             num_aux_assertions,
             lagrange_kernel_aux_column_idx,
-            ce_blowup_factor,
+            ce_blowup_factor: ProofOptions::MIN_BLOWUP_FACTOR,
             trace_domain_generator: B::get_root_of_unity(trace_length.ilog2()),
             lde_domain_generator: B::get_root_of_unity(lde_domain_size.ilog2()),
             num_transition_exemptions: 1,
-        }
+            transition_exemptions: BTreeMap::new(),
+            frame_size: 2,
+            num_composition_columns: None,
+        };
+
+        // the constraint evaluation domain is derived from the degrees of the constraints, and
+        // thus, is independent of the blowup factor of the LDE domain
+        context.update_ce_blowup_factor();
+        context
     }
 
     // PUBLIC ACCESSORS
@@ -195,6 +187,18 @@ impl<B: StarkField> AirContext<B> {
         self.trace_info.length() - 1
     }
 
+    /// Returns the blowup factor of the constraint evaluation domain.
+    ///
+    /// This is the smallest power of two (but at least 2) which is greater than or equal to the
+    /// effective degrees of all transition constraints (see
+    /// [max_effective_constraint_degree()](Self::max_effective_constraint_degree)); thus, it
+    /// accounts for both the degrees of the constraints and the degrees of their divisors. The
+    /// constraint evaluation domain blowup factor is independent of the blowup factor of the LDE
+    /// domain specified in the proof options.
+    pub fn ce_blowup_factor(&self) -> usize {
+        self.ce_blowup_factor
+    }
+
     /// Returns size of the constraint evaluation domain.
     ///
     /// This is guaranteed to be a power of two, and is equal to `trace_length * ce_blowup_factor`.
@@ -230,6 +234,24 @@ impl<B: StarkField> AirContext<B> {
         self.aux_transition_constraint_degrees.len()
     }
 
+    /// Returns the degree descriptor of the transition constraint at the specified index.
+    ///
+    /// Main trace constraints are indexed first, followed by the auxiliary trace constraints.
+    ///
+    /// # Panics
+    /// Panics if `constraint_idx` is not a valid index of a transition constraint.
+    pub fn transition_constraint_degree(
+        &self,
+        constraint_idx: usize,
+    ) -> &TransitionConstraintDegree {
+        let num_main_constraints = self.num_main_transition_constraints();
+        if constraint_idx < num_main_constraints {
+            &self.main_transition_constraint_degrees[constraint_idx]
+        } else {
+            &self.aux_transition_constraint_degrees[constraint_idx - num_main_constraints]
+        }
+    }
+
     /// Returns the index of the auxiliary column which implements the Lagrange kernel, if any
     pub fn lagrange_kernel_aux_column_idx(&self) -> Option<usize> {
         self.lagrange_kernel_aux_column_idx
@@ -249,6 +271,17 @@ impl<B: StarkField> AirContext<B> {
         self.num_main_assertions + self.num_aux_assertions
     }
 
+    /// Returns the number of assertions placed against the main segment of an execution trace.
+    pub fn num_main_assertions(&self) -> usize {
+        self.num_main_assertions
+    }
+
+    /// Returns the number of assertions placed against the auxiliary segment of an execution
+    /// trace, excluding the Lagrange kernel assertion.
+    pub fn num_aux_assertions(&self) -> usize {
+        self.num_aux_assertions
+    }
+
     /// Returns the number of rows at the end of an execution trace to which transition constraints
     /// do not apply.
     ///
@@ -259,6 +292,29 @@ impl<B: StarkField> AirContext<B> {
         self.num_transition_exemptions
     }
 
+    /// Returns the steps of the execution trace on which the transition constraint at the
+    /// specified index does not need to hold.
+    ///
+    /// Main trace constraints are indexed first, followed by the auxiliary trace constraints.
+    /// Unless custom exemptions were specified for the constraint via
+    /// [set_transition_exemption()](Self::set_transition_exemption), the constraint is exempt
+    /// from the last [num_transition_exemptions()](Self::num_transition_exemptions) steps.
+    pub fn transition_exemption(&self, constraint_idx: usize) -> TransitionExemption {
+        self.transition_exemptions
+            .get(&constraint_idx)
+            .copied()
+            .unwrap_or(TransitionExemption::LastSteps(self.num_transition_exemptions))
+    }
+
+    /// Returns the number of consecutive trace rows in the evaluation frames passed to
+    /// [Air::evaluate_transition()](crate::Air::evaluate_transition) and
+    /// [Air::evaluate_aux_transition()](crate::Air::evaluate_aux_transition).
+    ///
+    /// This is guaranteed to be at least 2 (which is the default value), but could be greater.
+    pub fn frame_size(&self) -> usize {
+        self.frame_size
+    }
+
     /// Returns the number of columns needed to store the constraint composition polynomial.
     ///
     /// This is the maximum of:
@@ -292,26 +348,61 @@ impl<B: StarkField> AirContext<B> {
     /// numerator is `trace_len - 1` for all transition constraints (i.e. the base degree is 1).
     /// Hence, no matter what the degree of the divisor is for each, the degree of the fraction will
     /// be at most `trace_len - 1`.
+    ///
+    /// A larger number of columns can be requested via
+    /// [set_num_constraint_composition_columns()](Self::set_num_constraint_composition_columns);
+    /// in this case, the requested number is returned.
     pub fn num_constraint_composition_columns(&self) -> usize {
-        let mut highest_constraint_degree = 0_usize;
-        for degree in self
-            .main_transition_constraint_degrees
-            .iter()
-            .chain(self.aux_transition_constraint_degrees.iter())
-        {
-            let eval_degree = degree.get_evaluation_degree(self.trace_len());
-            if eval_degree > highest_constraint_degree {
-                highest_constraint_degree = eval_degree
-            }
-        }
-        let trace_length = self.trace_len();
-        let transition_divisior_degree = trace_length - self.num_transition_exemptions();
+        self.num_composition_columns
+            .unwrap_or_else(|| self.max_effective_constraint_degree())
+    }
 
-        // we use the identity: ceil(a/b) = (a + b - 1)/b
-        let num_constraint_col =
-            (highest_constraint_degree - transition_divisior_degree).div_ceil(trace_length);
+    /// Returns the degree bound of the constraint composition polynomial.
+    ///
+    /// The composition polynomial is split into
+    /// [num_constraint_composition_columns()](Self::num_constraint_composition_columns) columns
+    /// of degree smaller than the trace length, and thus, its degree is smaller than the number
+    /// of columns times the trace length.
+    pub fn constraint_composition_degree(&self) -> usize {
+        self.num_constraint_composition_columns() * self.trace_len() - 1
+    }
+
+    /// Returns the maximum degree of all constraints of a computation after division by their
+    /// divisors, expressed as the number of polynomials of degree smaller than the trace length
+    /// needed to represent the constraint of the highest degree.
+    ///
+    /// The degree of a transition constraint `C(x) / z(x)` is computed from the evaluation degree
+    /// of `C(x)` and the degree of the divisor `z(x)` of the constraint, which depends on the
+    /// exemptions of the constraint (see [transition_exemption()](Self::transition_exemption));
+    /// this includes constraints placed against the auxiliary trace segment. Boundary constraints
+    /// and the Lagrange kernel constraints are always of degree smaller than the trace length,
+    /// and thus, the returned value is at least `1`.
+    ///
+    /// The constraint evaluation domain must be large enough to accommodate this degree, and
+    /// thus, the prover refuses to generate proofs if the blowup factor specified in the proof
+    /// options is smaller than the next power of two of this value (see
+    /// [ce_blowup_factor()](Self::ce_blowup_factor)).
+    pub fn max_effective_constraint_degree(&self) -> usize {
+        (0..self.num_transition_constraints())
+            .map(|constraint_idx| self.effective_constraint_degree(constraint_idx))
+            .fold(1, cmp::max)
+    }
 
-        cmp::max(num_constraint_col, 1)
+    /// Returns the degree of the transition constraint at the specified index after division by
+    /// its divisor, expressed as the number of polynomials of degree smaller than the trace length
+    /// needed to represent the constraint.
+    ///
+    /// Main trace constraints are indexed first, followed by the auxiliary trace constraints.
+    ///
+    /// # Panics
+    /// Panics if `constraint_idx` is not a valid index of a transition constraint.
+    pub fn effective_constraint_degree(&self, constraint_idx: usize) -> usize {
+        let degree = self.transition_constraint_degree(constraint_idx);
+        let quotient_degree = self.get_quotient_degree(constraint_idx, degree);
+
+        // a polynomial of degree d requires d + 1 coefficients, and thus, it can be split into
+        // ceil((d + 1) / n) polynomials of degree smaller than n
+        (quotient_degree + 1).div_ceil(self.trace_len())
     }
 
     // DATA MUTATORS
@@ -322,12 +413,21 @@ impl<B: StarkField> AirContext<B> {
     /// # Panics
     /// Panics if:
     /// * The number of exemptions is zero.
+    /// * The number of exemptions is smaller than the frame size minus one; transition
+    ///   constraints over a frame of `k` rows cannot apply to the last `k - 1` rows of the trace.
     /// * The number of exemptions exceeds half of the trace length.
-    /// * Given the combination of transition constraints degrees and the blowup factor in this
-    ///   context, the number of exemptions is too larger for a valid computation of the constraint
-    ///   composition polynomial.
+    /// * With the specified number of exemptions, the degree of some transition constraint
+    ///   requires a constraint evaluation domain larger than the largest domain supported by the
+    ///   base field.
     pub fn set_num_transition_exemptions(mut self, n: usize) -> Self {
         assert!(n > 0, "number of transition exemptions must be greater than zero");
+        assert!(
+            n >= self.frame_size - 1,
+            "number of transition exemptions must be at least {} for a frame of {} rows, but was {}",
+            self.frame_size - 1,
+            self.frame_size,
+            n
+        );
         // exemptions which are for more than half the trace plus one are probably a mistake
         assert!(
             n <= self.trace_len() / 2 + 1,
@@ -335,28 +435,190 @@ impl<B: StarkField> AirContext<B> {
             self.trace_len() / 2 + 1,
             n
         );
-        // make sure the composition polynomial can be computed correctly with the specified
-        // number of exemptions.
-        // The `ce_blowup` factor puts a ceiling on the maximal degree of a constraint composition
-        // polynomial we can accommodate. On the other hand, adding exemption points reduces the
-        // degree of the divisor which results in an increase of the resulting constraint composition
-        // polynomial.Thus we need to check that the number of exemption points is not too large
-        // given the above.
-        for degree in self
-            .main_transition_constraint_degrees
-            .iter()
-            .chain(self.aux_transition_constraint_degrees.iter())
-        {
-            let eval_degree = degree.get_evaluation_degree(self.trace_len());
-            let max_constraint_composition_degree = self.ce_domain_size() - 1;
-            let max_exemptions = max_constraint_composition_degree + self.trace_len() - eval_degree;
-            assert!(
-                n <= max_exemptions,
-                "number of transition exemptions cannot exceed: {max_exemptions}, but was {n}"
-            )
+        self.num_transition_exemptions = n;
+
+        // fewer steps in the divisors of the constraints may increase their degrees, and thus,
+        // may require a larger constraint evaluation domain
+        self.update_ce_blowup_factor();
+        self
+    }
+
+    /// Sets the steps of the execution trace on which the transition constraint at the specified
+    /// index does not need to hold.
+    ///
+    /// Main trace constraints are indexed first, followed by the auxiliary trace constraints. The
+    /// exemptions are removed from the divisor of the constraint, and thus, making the constraint
+    /// exempt from steps on which it does not hold is usually cheaper than multiplying it by a
+    /// periodic selector column. However, a smaller divisor increases the degree of the
+    /// constraint; when needed, the constraint evaluation domain is extended to accommodate
+    /// this degree.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `constraint_idx` is not a valid index of a transition constraint.
+    /// * A [TransitionExemption::LastSteps] exemption covers fewer steps than the frame size
+    ///   minus one, or more than half of the trace length plus one.
+    /// * A [TransitionExemption::Periodic] exemption has a period which is not a power of two,
+    ///   is smaller than 2, or is greater than the trace length, or an offset which is not
+    ///   smaller than the period.
+    /// * The degree of the constraint with the specified exemption requires a constraint
+    ///   evaluation domain larger than the largest domain supported by the base field.
+    pub fn set_transition_exemption(
+        mut self,
+        constraint_idx: usize,
+        exemption: TransitionExemption,
+    ) -> Self {
+        let num_constraints = self.num_transition_constraints();
+        assert!(
+            constraint_idx < num_constraints,
+            "transition constraint index must be smaller than {num_constraints}, but was {constraint_idx}"
+        );
+        let trace_length = self.trace_len();
+        match exemption {
+            TransitionExemption::LastSteps(n) => {
+                assert!(
+                    n >= cmp::max(self.frame_size - 1, 1),
+                    "number of transition exemptions must be at least {}, but was {n}",
+                    cmp::max(self.frame_size - 1, 1)
+                );
+                assert!(
+                    n <= trace_length / 2 + 1,
+                    "number of transition exemptions cannot exceed {}, but was {n}",
+                    trace_length / 2 + 1
+                );
+            },
+            TransitionExemption::Periodic { offset, period } => {
+                assert!(
+                    period.is_power_of_two() && period >= 2 && period <= trace_length,
+                    "exemption period must be a power of two between 2 and {trace_length}, but was {period}"
+                );
+                assert!(
+                    offset < period,
+                    "exemption offset must be smaller than exemption period {period}, but was {offset}"
+                );
+            },
         }
+        self.transition_exemptions.insert(constraint_idx, exemption);
 
-        self.num_transition_exemptions = n;
+        // extend the constraint evaluation domain if it is too small for the degree of the
+        // constraint with the reduced divisor
+        self.update_ce_blowup_factor();
+
+        self
+    }
+
+    /// Sets the number of consecutive trace rows in the evaluation frames for this context.
+    ///
+    /// Transition constraints over a frame of `n` rows relate each step of the trace to the next
+    /// `n - 1` steps, and thus, cannot apply to the last `n - 1` rows of the trace. If the number
+    /// of transition exemptions is smaller than `n - 1`, it is increased to `n - 1`. Each extra
+    /// row also increases the size of the out-of-domain frame in the proof by one evaluation per
+    /// trace column.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `n` is smaller than 2 or greater than 255.
+    /// * The number of transition exemptions required for frames of `n` rows is invalid for
+    ///   this context (see [set_num_transition_exemptions()](Self::set_num_transition_exemptions)).
+    pub fn set_frame_size(mut self, n: usize) -> Self {
+        assert!(n >= 2, "frame size must be at least 2, but was {n}");
+        assert!(n <= u8::MAX as usize, "frame size cannot exceed {}, but was {n}", u8::MAX);
+        self.frame_size = n;
+        if self.num_transition_exemptions < n - 1 {
+            self = self.set_num_transition_exemptions(n - 1);
+        }
         self
     }
+
+    /// Sets the number of columns into which the constraint composition polynomial is split.
+    ///
+    /// By default, the composition polynomial is split into the smallest number of columns
+    /// required by the degrees of the constraints (see
+    /// [max_effective_constraint_degree()](Self::max_effective_constraint_degree)). Requesting
+    /// more columns can be useful when proofs are verified by a circuit which expects a fixed
+    /// number of composition columns; the extra columns contain zero polynomials. The number of
+    /// columns is recorded in the proof context, and the prover and the verifier split and
+    /// recompose the composition polynomial accordingly.
+    ///
+    /// Since the minimum number of columns depends on the transition exemptions, this method
+    /// should be invoked after the exemptions of this context have been set.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `n` is not a power of two.
+    /// * `n` is smaller than the number of columns required by the degrees of the constraints.
+    /// * `n` is greater than the blowup factor.
+    pub fn set_num_constraint_composition_columns(mut self, n: usize) -> Self {
+        assert!(
+            n.is_power_of_two(),
+            "number of constraint composition columns must be a power of two, but was {n}"
+        );
+        let min_columns = self.max_effective_constraint_degree();
+        assert!(
+            n >= min_columns,
+            "number of constraint composition columns must be at least {min_columns}, but was {n}"
+        );
+        assert!(
+            n <= self.options.blowup_factor(),
+            "number of constraint composition columns cannot exceed blowup factor {}, but was {n}",
+            self.options.blowup_factor()
+        );
+        self.num_composition_columns = Some(n);
+        self
+    }
+
+    /// Sets the number of columns into which the constraint composition polynomial is split
+    /// without checking the number against the degrees of the constraints.
+    ///
+    /// This is needed for contexts which do not describe the actual constraints of a
+    /// computation (e.g., contexts of AIRs reconstructed from proof metadata), and for which the
+    /// number of columns is taken from a proof instead.
+    pub(crate) fn set_num_constraint_composition_columns_unchecked(mut self, n: usize) -> Self {
+        self.num_composition_columns = Some(n);
+        self
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the degree of the rational function `C(x) / z(x)` for the transition constraint
+    /// at the specified index, where `C(x)` is the constraint polynomial and `z(x)` is the
+    /// divisor of the constraint.
+    /// Sets the constraint evaluation domain blowup factor to the smallest power of two (but at
+    /// least 2) which can accommodate the effective degrees of all transition constraints.
+    ///
+    /// # Panics
+    /// Panics if the constraint evaluation domain required by some transition constraint is
+    /// larger than the largest domain supported by the base field.
+    fn update_ce_blowup_factor(&mut self) {
+        let trace_length = self.trace_len();
+        let mut ce_blowup_factor = ProofOptions::MIN_BLOWUP_FACTOR;
+        for constraint_idx in 0..self.num_transition_constraints() {
+            let blowup_factor =
+                self.effective_constraint_degree(constraint_idx).next_power_of_two();
+            let ce_domain_size = trace_length.checked_mul(blowup_factor);
+            assert!(
+                ce_domain_size.is_some_and(|size| size.ilog2() <= B::TWO_ADICITY),
+                "transition constraint {constraint_idx} of degree {} requires a constraint \
+                evaluation domain of {trace_length} * {blowup_factor} elements, but the base \
+                field supports domains of at most 2^{} elements",
+                self.transition_constraint_degree(constraint_idx),
+                B::TWO_ADICITY
+            );
+            ce_blowup_factor = cmp::max(ce_blowup_factor, blowup_factor);
+        }
+        self.ce_blowup_factor = ce_blowup_factor;
+    }
+
+    fn get_quotient_degree(
+        &self,
+        constraint_idx: usize,
+        degree: &TransitionConstraintDegree,
+    ) -> usize {
+        let trace_length = self.trace_len();
+        let eval_degree = degree.get_evaluation_degree(trace_length);
+        let num_exempt_steps =
+            self.transition_exemption(constraint_idx).num_exempt_steps(trace_length);
+        eval_degree - (trace_length - num_exempt_steps)
+    }
 }
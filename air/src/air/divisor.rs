@@ -22,12 +22,18 @@ use crate::air::Assertion;
 /// For example divisor $(x^a - 1) \cdot (x^b - 2) / (x - 3)$ can be represented as:
 /// numerator: `[(a, 1), (b, 2)]`, exemptions: `[3]`.
 ///
+/// When many exemption points form a coset of a subgroup of the trace domain, they are described
+/// more succinctly by a sparse denominator polynomial, encoded in the same way as the numerator.
+/// For example, divisor $(x^a - 1) / (x^c - 4)$ can be represented as: numerator: `[(a, 1)]`,
+/// denominator: `[(c, 4)]`.
+///
 /// A divisor cannot be instantiated directly, and instead must be created either for an
 /// [Assertion] or for a transition constraint.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ConstraintDivisor<B: StarkField> {
     pub(super) numerator: Vec<(usize, B)>,
     pub(super) exemptions: Vec<B>,
+    pub(super) denominator: Vec<(usize, B)>,
 }
 
 impl<B: StarkField> ConstraintDivisor<B> {
@@ -36,7 +42,11 @@ impl<B: StarkField> ConstraintDivisor<B> {
 
     /// Returns a new divisor instantiated from the provided parameters.
     fn new(numerator: Vec<(usize, B)>, exemptions: Vec<B>) -> Self {
-        ConstraintDivisor { numerator, exemptions }
+        ConstraintDivisor {
+            numerator,
+            exemptions,
+            denominator: Vec::new(),
+        }
     }
 
     /// Builds a divisor for transition constraints.
@@ -63,6 +73,43 @@ impl<B: StarkField> ConstraintDivisor<B> {
         Self::new(vec![(constraint_enforcement_domain_size, B::ONE)], exemptions)
     }
 
+    /// Builds a divisor for transition constraints which do not need to hold on steps `offset`,
+    /// `offset + period`, `offset + 2 * period` etc. of an execution trace.
+    ///
+    /// The divisor polynomial $z(x)$ is defined as:
+    ///
+    /// $$ z(x) = \frac{x^n - 1}{x^{n / p} - g^{a \cdot n / p}} $$
+    ///
+    /// where, $n$ is the length of the execution trace, $g$ is the generator of the trace domain,
+    /// $p$ is the period, and $a$ is the offset. The denominator is the vanishing polynomial of
+    /// the exempt steps, which form a coset of the subgroup of the trace domain of size $n / p$.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `period` is not a power of two, is smaller than 2, or is greater than `trace_length`.
+    /// * `offset` is greater than or equal to `period`.
+    pub fn from_periodic_transition(trace_length: usize, offset: usize, period: usize) -> Self {
+        assert!(
+            period.is_power_of_two() && period >= 2,
+            "exemption period must be a power of two greater than one, but was {period}"
+        );
+        assert!(
+            period <= trace_length,
+            "exemption period cannot exceed trace length {trace_length}, but was {period}"
+        );
+        assert!(
+            offset < period,
+            "exemption offset must be smaller than exemption period {period}, but was {offset}"
+        );
+        let num_exempt_steps = trace_length / period;
+        let coset_offset = get_trace_domain_value_at::<B>(trace_length, offset * num_exempt_steps);
+        ConstraintDivisor {
+            numerator: vec![(trace_length, B::ONE)],
+            exemptions: Vec::new(),
+            denominator: vec![(num_exempt_steps, coset_offset)],
+        }
+    }
+
     /// Builds a divisor for a boundary constraint described by the assertion.
     ///
     /// For boundary constraints, the divisor polynomial is defined as:
@@ -113,10 +160,23 @@ impl<B: StarkField> ConstraintDivisor<B> {
         &self.exemptions
     }
 
+    /// Returns the sparse denominator polynomial of this constraint divisor; this does not
+    /// include the exemption points.
+    pub fn denominator(&self) -> &[(usize, B)] {
+        &self.denominator
+    }
+
+    /// Returns true if the divisor has a denominator (i.e., exemption points or a sparse
+    /// denominator polynomial).
+    pub fn has_exemptions(&self) -> bool {
+        !self.exemptions.is_empty() || !self.denominator.is_empty()
+    }
+
     /// Returns the degree of the divisor polynomial
     pub fn degree(&self) -> usize {
         let numerator_degree = self.numerator.iter().fold(0, |degree, term| degree + term.0);
-        let denominator_degree = self.exemptions.len();
+        let denominator_degree =
+            self.exemptions.len() + self.denominator.iter().fold(0, |degree, term| degree + term.0);
         numerator_degree - denominator_degree
     }
 
@@ -138,11 +198,14 @@ impl<B: StarkField> ConstraintDivisor<B> {
         numerator / denominator
     }
 
-    /// Evaluates the denominator of this divisor (the exemption points) at the provided `x`
-    /// coordinate.
+    /// Evaluates the denominator of this divisor (the exemption points and the sparse
+    /// denominator polynomial) at the provided `x` coordinate.
     #[inline(always)]
     pub fn evaluate_exemptions_at<E: FieldElement<BaseField = B>>(&self, x: E) -> E {
-        self.exemptions.iter().fold(E::ONE, |r, &e| r * (x - E::from(e)))
+        let result = self.exemptions.iter().fold(E::ONE, |r, &e| r * (x - E::from(e)));
+        self.denominator.iter().fold(result, |r, &(degree, constant)| {
+            r * (x.exp((degree as u32).into()) - E::from(constant))
+        })
     }
 }
 
@@ -151,11 +214,14 @@ impl<B: StarkField> Display for ConstraintDivisor<B> {
         for (degree, offset) in self.numerator.iter() {
             write!(f, "(x^{degree} - {offset})")?;
         }
-        if !self.exemptions.is_empty() {
+        if self.has_exemptions() {
             write!(f, " / ")?;
             for x in self.exemptions.iter() {
                 write!(f, "(x - {x})")?;
             }
+            for (degree, offset) in self.denominator.iter() {
+                write!(f, "(x^{degree} - {offset})")?;
+            }
         }
         Ok(())
     }
@@ -315,4 +381,28 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn periodic_transition_divisor() {
+        let n = 16_usize;
+        let period = 4_usize;
+        let offset = 3_usize;
+        let g = BaseElement::get_root_of_unity(n.trailing_zeros());
+
+        let divisor = ConstraintDivisor::<BaseElement>::from_periodic_transition(n, offset, period);
+        assert_eq!(n - n / period, divisor.degree());
+        assert!(divisor.has_exemptions());
+
+        // z(x) = (x^16 - 1) / (x^4 - g^12) is the product of (x - g^i) over all non-exempt steps
+        let xs = (0..n)
+            .filter(|i| i % period != offset)
+            .map(|i| g.exp((i as u64).into()))
+            .collect::<Vec<_>>();
+        let poly = polynom::poly_from_roots(&xs);
+        assert_eq!(divisor.degree(), polynom::degree_of(&poly));
+
+        for x in [BaseElement::new(2), BaseElement::new(5), BaseElement::new(1234567)] {
+            assert_eq!(polynom::eval(&poly, x), divisor.evaluate_at(x));
+        }
+    }
 }
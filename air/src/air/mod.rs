@@ -26,7 +26,10 @@ mod boundary;
 pub use boundary::{BoundaryConstraint, BoundaryConstraintGroup, BoundaryConstraints};
 
 mod transition;
-pub use transition::{EvaluationFrame, TransitionConstraintDegree, TransitionConstraints};
+pub use transition::{
+    ConstraintExpression, EvaluationFrame, ExprDegree, TransitionConstraintDegree,
+    TransitionConstraints, TransitionExemption,
+};
 
 mod lagrange;
 pub use lagrange::{
@@ -42,7 +45,10 @@ pub use coefficients::{
 
 mod divisor;
 pub use divisor::ConstraintDivisor;
+
+mod violation;
 use utils::{Deserializable, Serializable};
+pub use violation::{ConstraintType, ConstraintViolation};
 
 #[cfg(test)]
 mod tests;
@@ -84,7 +90,10 @@ const MIN_CYCLE_LENGTH: usize = 2;
 /// [Air::evaluate_transition()] function which takes the following parameters:
 ///
 /// - [EvaluationFrame] which contains vectors with current and next states of the
-///   computation.
+///   computation. If transition constraints need to relate more than two consecutive steps
+///   (e.g., a second-difference constraint over steps `t`, `t + 1`, and `t + 2`), a wider frame
+///   can be requested via [AirContext::set_frame_size()]; rows of the frame can then be accessed
+///   via [EvaluationFrame::row()].
 /// - A list of periodic values. When periodic columns are defined for a computation,
 ///   this will contain values of periodic columns at the current step of the computation.
 ///   Otherwise, this will be an empty list.
@@ -236,6 +245,24 @@ pub trait Air: Send + Sync {
     /// Returns a set of assertions against a concrete execution trace of this computation.
     fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>>;
 
+    /// Returns indexes of the main trace columns referenced by transition constraints of this
+    /// computation, or `None` if the constraints may reference any column.
+    ///
+    /// When column indexes are provided, the prover loads only these columns (together with the
+    /// columns referenced by assertions) of the extended main trace into evaluation frames passed
+    /// to [evaluate_transition()](Air::evaluate_transition) and
+    /// [evaluate_aux_transition()](Air::evaluate_aux_transition); values of all other columns in
+    /// these frames are unspecified. This reduces memory bandwidth of constraint evaluation for
+    /// computations with wide traces in which constraints reference only a few columns.
+    ///
+    /// The returned columns must include every main trace column read by either main or
+    /// auxiliary transition constraints; otherwise, the prover will generate invalid proofs.
+    ///
+    /// The default implementation returns `None`.
+    fn used_columns(&self) -> Option<&[usize]> {
+        None
+    }
+
     // AUXILIARY TRACE CONSTRAINTS
     // --------------------------------------------------------------------------------------------
 
@@ -435,6 +462,111 @@ pub trait Air: Send + Sync {
         )
     }
 
+    // CONSTRAINT CHECKING
+    // --------------------------------------------------------------------------------------------
+
+    /// Evaluates all transition constraints and all boundary constraints of this AIR against
+    /// the provided main trace segment, and returns all constraint violations.
+    ///
+    /// `main_trace` must contain the columns of the main trace segment. Unlike proof generation,
+    /// this method does not stop at the first violation; instead, every transition constraint is
+    /// evaluated at every (non-exempt) row, and every assertion is checked at every row to which
+    /// it applies. An empty vector is returned if the trace satisfies all constraints.
+    ///
+    /// NOTE: this is an expensive operation intended for use in AIR development and testing.
+    /// Constraints against the auxiliary trace segment are not checked.
+    ///
+    /// # Panics
+    /// Panics if the number of columns or the length of any column in `main_trace` is
+    /// inconsistent with the trace info of this AIR.
+    fn check_constraints<C: AsRef<[Self::BaseField]>>(
+        &self,
+        main_trace: &[C],
+    ) -> Vec<ConstraintViolation<Self::BaseField>> {
+        let trace_width = self.trace_info().main_trace_width();
+        let trace_length = self.trace_length();
+        assert_eq!(
+            main_trace.len(),
+            trace_width,
+            "inconsistent trace width: expected {trace_width}, but was {}",
+            main_trace.len()
+        );
+        for column in main_trace {
+            assert_eq!(
+                column.as_ref().len(),
+                trace_length,
+                "inconsistent trace length: expected {trace_length}, but was {}",
+                column.as_ref().len()
+            );
+        }
+
+        let mut violations = Vec::new();
+
+        // check boundary constraints derived from assertions
+        for (constraint_idx, assertion) in self.get_assertions().into_iter().enumerate() {
+            assertion.apply(trace_length, |row, value| {
+                let actual = assertion.evaluate_columns(|column| main_trace[column].as_ref()[row]);
+                if actual != value {
+                    violations.push(ConstraintViolation {
+                        constraint_type: ConstraintType::Boundary,
+                        constraint_idx,
+                        row,
+                        degree: self.trace_poly_degree(),
+                        evaluation: actual - value,
+                    });
+                }
+            });
+        }
+
+        // check transition constraints at all steps except for the exempt ones
+        let periodic_columns = self.get_periodic_column_values();
+        let mut periodic_values = vec![Self::BaseField::ZERO; periodic_columns.len()];
+        let degrees: Vec<usize> = self
+            .context()
+            .main_transition_constraint_degrees
+            .iter()
+            .map(|degree| degree.get_evaluation_degree(trace_length))
+            .collect();
+
+        let exemptions: Vec<TransitionExemption> = (0..degrees.len())
+            .map(|constraint_idx| self.context().transition_exemption(constraint_idx))
+            .collect();
+
+        let mut frame = EvaluationFrame::with_num_rows(trace_width, self.frame_size());
+        let mut evaluations = vec![Self::BaseField::ZERO; degrees.len()];
+        for row in 0..trace_length {
+            if exemptions.iter().all(|exemption| exemption.is_exempt(row, trace_length)) {
+                continue;
+            }
+            for offset in 0..frame.num_rows() {
+                for (column, value) in main_trace.iter().zip(frame.row_mut(offset)) {
+                    *value = column.as_ref()[(row + offset) % trace_length];
+                }
+            }
+            for (value, column) in periodic_values.iter_mut().zip(periodic_columns.iter()) {
+                *value = column[row % column.len()];
+            }
+
+            evaluations.fill(Self::BaseField::ZERO);
+            self.evaluate_transition(&frame, &periodic_values, &mut evaluations);
+            for (constraint_idx, &evaluation) in evaluations.iter().enumerate() {
+                if evaluation != Self::BaseField::ZERO
+                    && !exemptions[constraint_idx].is_exempt(row, trace_length)
+                {
+                    violations.push(ConstraintViolation {
+                        constraint_type: ConstraintType::Transition,
+                        constraint_idx,
+                        row,
+                        degree: degrees[constraint_idx],
+                        evaluation,
+                    });
+                }
+            }
+        }
+
+        violations
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -458,6 +590,27 @@ pub trait Air: Send + Sync {
         self.context().trace_info.length()
     }
 
+    /// Returns the number of rows of the execution trace which describe the actual computation,
+    /// i.e., the length of the trace before it was padded to a power of two.
+    ///
+    /// By default, this is the same as [trace_length()](Air::trace_length). AIRs for padded
+    /// traces (e.g., traces built via `DynamicTraceTable` in the prover crate) should override
+    /// this method to return the original length (usually, via public inputs), and use it to
+    /// place their assertions and to exempt the padding rows from transition constraints (see
+    /// [AirContext::set_num_transition_exemptions()](crate::AirContext::set_num_transition_exemptions)).
+    fn unpadded_trace_length(&self) -> usize {
+        self.trace_length()
+    }
+
+    /// Returns the number of consecutive trace rows in the evaluation frames of the computation
+    /// described by this AIR.
+    ///
+    /// This is 2 unless a different frame size was set via
+    /// [AirContext::set_frame_size()](crate::AirContext::set_frame_size).
+    fn frame_size(&self) -> usize {
+        self.context().frame_size()
+    }
+
     /// Returns degree of trace polynomials for an instance of the computation described by
     /// this AIR.
     ///
@@ -478,12 +631,14 @@ pub trait Air: Send + Sync {
     /// AIR.
     ///
     /// The blowup factor is defined as the smallest power of two greater than or equal to the
-    /// hightest transition constraint degree. For example, if the hightest transition
-    /// constraint degree = 3, `ce_blowup_factor` will be set to 4.
+    /// highest effective transition constraint degree (i.e., the degree of the constraint after
+    /// division by its divisor). For example, if the highest transition constraint degree = 5,
+    /// `ce_blowup_factor` will be set to 4.
     ///
-    /// `ce_blowup_factor` is guaranteed to be smaller than or equal to the `lde_blowup_factor`.
+    /// `ce_blowup_factor` is derived from the constraint degrees only, and is independent of the
+    /// `lde_blowup_factor`.
     fn ce_blowup_factor(&self) -> usize {
-        self.context().ce_blowup_factor
+        self.context().ce_blowup_factor()
     }
 
     /// Returns size of the constraint evaluation domain.
@@ -493,9 +648,29 @@ pub trait Air: Send + Sync {
         self.context().ce_domain_size()
     }
 
+    /// Returns the degree bound of the constraint composition polynomial for the computation
+    /// described by this AIR.
+    ///
+    /// This is equal to `num_constraint_composition_columns * trace_length - 1`.
+    fn constraint_composition_degree(&self) -> usize {
+        self.context().constraint_composition_degree()
+    }
+
+    /// Returns the number of columns into which the constraint composition polynomial for the
+    /// computation described by this AIR is split.
+    ///
+    /// Each column is a polynomial of degree smaller than the trace length; the number of
+    /// columns is guaranteed to be smaller than `lde_blowup_factor`.
+    fn num_constraint_composition_columns(&self) -> usize {
+        self.context().num_constraint_composition_columns()
+    }
+
     /// Returns low-degree extension domain blowup factor for the computation described by this
-    /// AIR. This is guaranteed to be a power of two, and is always either equal to or greater
-    /// than ce_blowup_factor.
+    /// AIR. This is guaranteed to be a power of two.
+    ///
+    /// Proofs can be generated only if this is greater than the maximum effective constraint
+    /// degree, and thus, for such computations, this is always either equal to or greater than
+    /// ce_blowup_factor.
     fn lde_blowup_factor(&self) -> usize {
         self.context().options.blowup_factor()
     }
@@ -9,8 +9,8 @@ use crypto::{hashers::Blake3_256, DefaultRandomCoin, RandomCoin};
 use math::{fields::f64::BaseElement, get_power_series, polynom, FieldElement, StarkField};
 
 use super::{
-    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
-    TransitionConstraintDegree,
+    Air, AirContext, Assertion, ConstraintType, ConstraintViolation, EvaluationFrame, ProofOptions,
+    TraceInfo, TransitionConstraintDegree, TransitionExemption,
 };
 use crate::FieldExtension;
 
@@ -188,6 +188,280 @@ fn get_boundary_constraints() {
     assert_eq!(expected_cc[&5], constraint.cc().clone());
 }
 
+#[test]
+fn get_boundary_constraints_linear_combination() {
+    let terms = vec![(3, BaseElement::new(2)), (1, BaseElement::new(5))];
+    let assertions = vec![
+        Assertion::single(1, 15, BaseElement::new(4)),
+        Assertion::linear_combination(terms, 15, BaseElement::new(7)),
+    ];
+    let air = MockAir::with_assertions(assertions, 16);
+
+    let mut prng = build_prng();
+    let coefficients = (0..2).map(|_| prng.draw().unwrap()).collect::<Vec<BaseElement>>();
+    let constraints = air.get_boundary_constraints(None, &coefficients);
+    let groups = constraints.main_constraints().to_vec();
+
+    // both assertions are placed against the last step, and thus, share a divisor
+    assert_eq!(1, groups.len());
+    let group = &groups[0];
+    assert_eq!(2, group.constraints().len());
+
+    let constraint = &group.constraints()[0];
+    assert_eq!(1, constraint.column());
+    assert!(constraint.linear_terms().is_empty());
+
+    let constraint = &group.constraints()[1];
+    assert_eq!(1, constraint.column());
+    assert_eq!(&[(1, BaseElement::new(5)), (3, BaseElement::new(2))], constraint.linear_terms());
+    assert_eq!(vec![BaseElement::new(7)], constraint.poly());
+
+    let state = [1, 2, 3, 4].map(BaseElement::new);
+    assert_eq!(BaseElement::new(5 * 2 + 2 * 4), constraint.trace_value(&state));
+}
+
+#[test]
+#[should_panic(expected = "expected trace width to be at least 5, but was 4")]
+fn get_boundary_constraints_linear_combination_column_out_of_bounds() {
+    let terms = vec![(0, BaseElement::ONE), (5, BaseElement::ONE)];
+    let assertions = vec![Assertion::linear_combination(terms, 0, BaseElement::ONE)];
+    let air = MockAir::with_assertions(assertions, 16);
+
+    let coefficients = vec![BaseElement::ONE];
+    let _ = air.get_boundary_constraints(None, &coefficients);
+}
+
+// CONSTRAINT DEGREES
+// ================================================================================================
+
+#[test]
+fn max_effective_constraint_degree() {
+    let options = ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31);
+    let trace_info = TraceInfo::new(4, 16);
+
+    // linear constraints are dominated by boundary constraints
+    let degrees = vec![TransitionConstraintDegree::new(1)];
+    let context = AirContext::<BaseElement>::new(trace_info.clone(), degrees, 1, options.clone());
+    assert_eq!(1, context.max_effective_constraint_degree());
+
+    let degrees = vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(3)];
+    let context = AirContext::<BaseElement>::new(trace_info.clone(), degrees, 1, options.clone());
+    assert_eq!(2, context.max_effective_constraint_degree());
+
+    // periodic columns increase the degree of a constraint
+    let degrees = vec![TransitionConstraintDegree::with_cycles(3, vec![8])];
+    let context = AirContext::<BaseElement>::new(trace_info.clone(), degrees, 1, options.clone());
+    assert_eq!(3, context.max_effective_constraint_degree());
+
+    // transition exemptions reduce the degree of the divisor, and thus, increase the degree of
+    // the constraint
+    let degrees = vec![TransitionConstraintDegree::new(2)];
+    let context = AirContext::<BaseElement>::new(trace_info, degrees, 1, options.clone())
+        .set_num_transition_exemptions(3);
+    assert_eq!(2, context.max_effective_constraint_degree());
+
+    // constraints against the auxiliary trace segment are taken into account
+    let trace_info = TraceInfo::new_multi_segment(4, 1, 1, 16, vec![]);
+    let context = AirContext::<BaseElement>::new_multi_segment(
+        trace_info,
+        vec![TransitionConstraintDegree::new(2)],
+        vec![TransitionConstraintDegree::new(5)],
+        1,
+        1,
+        None,
+        options,
+    );
+    assert_eq!(4, context.max_effective_constraint_degree());
+    assert_eq!(4, context.num_constraint_composition_columns());
+}
+
+#[test]
+fn constraint_composition_columns() {
+    let options = ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31);
+    let trace_info = TraceInfo::new(4, 16);
+
+    // (degree of the highest-degree constraint, number of columns, CE domain size)
+    for (degree, num_columns, ce_domain_size) in [(2, 1, 32), (3, 2, 32), (5, 4, 64)] {
+        let degrees =
+            vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(degree)];
+        let context =
+            AirContext::<BaseElement>::new(trace_info.clone(), degrees, 1, options.clone());
+        assert_eq!(num_columns, context.num_constraint_composition_columns());
+        assert_eq!(num_columns * 16 - 1, context.constraint_composition_degree());
+        assert_eq!(ce_domain_size, context.ce_domain_size());
+    }
+}
+
+#[test]
+fn ce_blowup_factor() {
+    // the constraint evaluation domain is derived from constraint degrees only, and thus, can be
+    // larger than the LDE domain
+    let options = ProofOptions::new(32, 2, 0, FieldExtension::None, 4, 31);
+    let trace_info = TraceInfo::new(4, 16);
+    for (degree, ce_blowup_factor) in [(1, 2), (2, 2), (3, 2), (5, 4), (9, 8)] {
+        let degrees = vec![TransitionConstraintDegree::new(degree)];
+        let context =
+            AirContext::<BaseElement>::new(trace_info.clone(), degrees, 1, options.clone());
+        assert_eq!(ce_blowup_factor, context.ce_blowup_factor());
+        assert_eq!(16 * ce_blowup_factor, context.ce_domain_size());
+    }
+
+    // exemptions which increase the degree of a constraint extend the constraint evaluation
+    // domain beyond the LDE domain
+    let degrees = vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(3)];
+    let context = AirContext::<BaseElement>::new(trace_info, degrees, 1, options);
+    assert_eq!(2, context.ce_blowup_factor());
+    let context = context.set_transition_exemption(1, TransitionExemption::LastSteps(3));
+    assert_eq!(3, context.effective_constraint_degree(1));
+    assert_eq!(4, context.ce_blowup_factor());
+}
+
+#[test]
+#[should_panic(
+    expected = "transition constraint 1 of degree 9 requires a constraint evaluation \
+    domain of 1073741824 * 8 elements, but the base field supports domains of at most 2^32 elements"
+)]
+fn ce_blowup_factor_too_large() {
+    let options = ProofOptions::new(32, 2, 0, FieldExtension::None, 4, 31);
+    let degrees = vec![TransitionConstraintDegree::new(2), TransitionConstraintDegree::new(9)];
+    let _ = AirContext::<BaseElement>::new(TraceInfo::new(4, 1 << 30), degrees, 1, options);
+}
+
+#[test]
+fn set_num_constraint_composition_columns() {
+    let context = build_context::<BaseElement>(16, 4, 1);
+    assert_eq!(1, context.num_constraint_composition_columns());
+
+    let context = context.set_num_constraint_composition_columns(4);
+    assert_eq!(4, context.num_constraint_composition_columns());
+    assert_eq!(4 * 16 - 1, context.constraint_composition_degree());
+    assert_eq!(32, context.ce_domain_size());
+}
+
+#[test]
+#[should_panic(expected = "number of constraint composition columns must be at least 4, but was 2")]
+fn set_num_constraint_composition_columns_too_few() {
+    let options = ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31);
+    let degrees = vec![TransitionConstraintDegree::new(5)];
+    let _ = AirContext::<BaseElement>::new(TraceInfo::new(4, 16), degrees, 1, options)
+        .set_num_constraint_composition_columns(2);
+}
+
+#[test]
+#[should_panic(
+    expected = "number of constraint composition columns must be a power of two, but was 3"
+)]
+fn set_num_constraint_composition_columns_not_power_of_two() {
+    let _ = build_context::<BaseElement>(16, 4, 1).set_num_constraint_composition_columns(3);
+}
+
+#[test]
+#[should_panic(
+    expected = "number of constraint composition columns cannot exceed blowup factor 8, but was 16"
+)]
+fn set_num_constraint_composition_columns_too_many() {
+    let _ = build_context::<BaseElement>(16, 4, 1).set_num_constraint_composition_columns(16);
+}
+
+#[test]
+fn set_transition_exemption() {
+    let options = ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31);
+    let trace_info = TraceInfo::new(4, 16);
+    let degrees = vec![TransitionConstraintDegree::new(3), TransitionConstraintDegree::new(1)];
+    let context = AirContext::<BaseElement>::new(trace_info, degrees, 1, options);
+    assert_eq!(32, context.ce_domain_size());
+    assert_eq!(2, context.max_effective_constraint_degree());
+
+    // a periodic exemption removes 4 roots from the divisor of the first constraint, and thus,
+    // the degree of the constraint becomes 3 * 15 - 12 = 33
+    let exemption = TransitionExemption::Periodic { offset: 3, period: 4 };
+    let context = context.set_transition_exemption(0, exemption);
+    assert_eq!(exemption, context.transition_exemption(0));
+    assert_eq!(TransitionExemption::LastSteps(1), context.transition_exemption(1));
+    assert_eq!(64, context.ce_domain_size());
+    assert_eq!(3, context.max_effective_constraint_degree());
+}
+
+#[test]
+#[should_panic(expected = "transition constraint index must be smaller than 1, but was 1")]
+fn set_transition_exemption_invalid_index() {
+    let _ = build_context::<BaseElement>(16, 4, 1)
+        .set_transition_exemption(1, TransitionExemption::LastSteps(1));
+}
+
+#[test]
+#[should_panic(expected = "exemption period must be a power of two between 2 and 16, but was 6")]
+fn set_transition_exemption_invalid_period() {
+    let _ = build_context::<BaseElement>(16, 4, 1)
+        .set_transition_exemption(0, TransitionExemption::Periodic { offset: 5, period: 6 });
+}
+
+#[test]
+#[should_panic(expected = "exemption offset must be smaller than exemption period 4, but was 4")]
+fn set_transition_exemption_invalid_offset() {
+    let _ = build_context::<BaseElement>(16, 4, 1)
+        .set_transition_exemption(0, TransitionExemption::Periodic { offset: 4, period: 4 });
+}
+
+// CONSTRAINT CHECKING
+// ================================================================================================
+
+#[test]
+fn check_constraints_collects_all_boundary_violations() {
+    let assertions = vec![
+        Assertion::single(0, 0, BaseElement::new(1)),
+        Assertion::periodic(1, 0, 4, BaseElement::new(2)),
+    ];
+    let air = MockAir::with_assertions(assertions, 16);
+
+    let mut trace = vec![vec![BaseElement::ONE; 16], vec![BaseElement::new(2); 16]];
+    trace.extend(vec![vec![BaseElement::ZERO; 16]; 2]);
+    assert!(air.check_constraints(&trace).is_empty());
+
+    // break the periodic assertion at two different steps
+    trace[1][4] = BaseElement::new(5);
+    trace[1][12] = BaseElement::new(3);
+    let expected = vec![
+        ConstraintViolation {
+            constraint_type: ConstraintType::Boundary,
+            constraint_idx: 1,
+            row: 4,
+            degree: 15,
+            evaluation: BaseElement::new(3),
+        },
+        ConstraintViolation {
+            constraint_type: ConstraintType::Boundary,
+            constraint_idx: 1,
+            row: 12,
+            degree: 15,
+            evaluation: BaseElement::ONE,
+        },
+    ];
+    assert_eq!(expected, air.check_constraints(&trace));
+}
+
+#[test]
+fn check_constraints_linear_combination() {
+    let terms = vec![(0, BaseElement::new(2)), (2, BaseElement::ONE)];
+    let assertions = vec![Assertion::linear_combination(terms, 15, BaseElement::new(7))];
+    let air = MockAir::with_assertions(assertions, 16);
+
+    let mut trace = vec![vec![BaseElement::new(3); 16], vec![BaseElement::ZERO; 16]];
+    trace.extend(vec![vec![BaseElement::ONE; 16]; 2]);
+    assert!(air.check_constraints(&trace).is_empty());
+
+    // changing a single referenced cell breaks the combination
+    trace[2][15] = BaseElement::new(4);
+    let expected = vec![ConstraintViolation {
+        constraint_type: ConstraintType::Boundary,
+        constraint_idx: 0,
+        row: 15,
+        degree: 15,
+        evaluation: BaseElement::new(3),
+    }];
+    assert_eq!(expected, air.check_constraints(&trace));
+}
+
 // MOCK AIR
 // ================================================================================================
 
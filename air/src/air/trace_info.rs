@@ -225,8 +225,7 @@ impl<E: StarkField> ToElements<E> for TraceInfo {
         }
         result.push(E::from(buf));
 
-        // We assume here that the trace length is never greater than 2^32.
-        result.push(E::from(self.trace_length as u32));
+        result.push(E::from_u64(self.trace_length as u64));
 
         // convert trace metadata to elements; this is done by breaking trace metadata into chunks
         // of bytes which are slightly smaller than the number of bytes needed to encode a field
@@ -316,7 +315,13 @@ impl Deserializable for TraceInfo {
                 trace_length
             )));
         }
-        let trace_length = 2_usize.pow(trace_length as u32);
+        let trace_length = 2_usize.checked_pow(trace_length as u32).ok_or_else(|| {
+            DeserializationError::InvalidValue(format!(
+                "trace length cannot be greater than 2^{}, but was 2^{}",
+                usize::BITS - 1,
+                trace_length
+            ))
+        })?;
 
         // read trace metadata
         let num_meta_bytes = source.read_u16()? as usize;
@@ -342,6 +347,7 @@ impl Deserializable for TraceInfo {
 #[cfg(test)]
 mod tests {
     use math::{fields::f64::BaseElement, FieldElement};
+    use utils::Deserializable;
 
     use super::{ToElements, TraceInfo};
 
@@ -391,4 +397,15 @@ mod tests {
 
         assert_eq!(expected, info.to_elements());
     }
+
+    #[test]
+    fn trace_info_read_from_invalid_trace_length() {
+        // main width, aux width, aux rands, log2(trace length), and meta length
+        let bytes = [4_u8, 0, 0, 64, 0, 0];
+        assert!(TraceInfo::read_from_bytes(&bytes).is_err());
+
+        let bytes = [4_u8, 0, 0, 6, 0, 0];
+        let info = TraceInfo::read_from_bytes(&bytes).unwrap();
+        assert_eq!(64, info.length());
+    }
 }
@@ -4,7 +4,7 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
-use core::cmp;
+use core::{cmp, fmt};
 
 use super::{super::super::ProofOptions, MIN_CYCLE_LENGTH};
 
@@ -114,3 +114,13 @@ impl TransitionConstraintDegree {
         cmp::max(degree_bound.next_power_of_two(), ProofOptions::MIN_BLOWUP_FACTOR)
     }
 }
+
+impl fmt::Display for TransitionConstraintDegree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.cycles.is_empty() {
+            write!(f, "{}", self.base)
+        } else {
+            write!(f, "{} with periodic cycles {:?}", self.base, self.cycles)
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use math::StarkField;
+
+use crate::air::ConstraintDivisor;
+
+// TRANSITION EXEMPTION
+// ================================================================================================
+
+/// Describes the steps of an execution trace on which a transition constraint does not need to
+/// hold.
+///
+/// Exempt steps are removed from the divisor of the constraint. By default, all transition
+/// constraints are exempt from the last step of the trace only (see
+/// [AirContext::set_num_transition_exemptions()](crate::AirContext::set_num_transition_exemptions)),
+/// but the exemptions can also be specified for individual constraints via
+/// [AirContext::set_transition_exemption()](crate::AirContext::set_transition_exemption).
+///
+/// Note that a transition constraint evaluated at step `i` relates row `i` to the following
+/// rows of the trace, and at the last step, wraps around to the first row. Thus, the last step
+/// should usually be exempt. For periodic exemptions, this is the case when `offset` is equal to
+/// `period - 1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransitionExemption {
+    /// The constraint does not need to hold on the last $k$ steps of the trace.
+    ///
+    /// The divisor of such a constraint is $\frac{x^n - 1}{\prod_{i=1}^k (x - g^{n-i})}$, where
+    /// $n$ is the length of the trace and $g$ is the generator of the trace domain.
+    LastSteps(usize),
+    /// The constraint does not need to hold on steps `offset`, `offset + period`,
+    /// `offset + 2 * period` etc.
+    ///
+    /// This is useful for computations which reset their state every `period` steps. The divisor
+    /// of such a constraint is $\frac{x^n - 1}{x^{n / p} - g^{a \cdot n / p}}$, where $n$ is the
+    /// length of the trace, $g$ is the generator of the trace domain, $p$ is the period, and $a$
+    /// is the offset. The degree of this divisor is smaller than the degree of the default
+    /// divisor by $n / p - 1$, and thus, the degree of the constraint is increased by the same
+    /// amount.
+    Periodic { offset: usize, period: usize },
+}
+
+impl TransitionExemption {
+    /// Returns true if a constraint with this exemption does not need to hold on the specified
+    /// step of an execution trace of the specified length.
+    pub fn is_exempt(&self, step: usize, trace_length: usize) -> bool {
+        match *self {
+            Self::LastSteps(num_steps) => step >= trace_length - num_steps,
+            Self::Periodic { offset, period } => step % period == offset,
+        }
+    }
+
+    /// Returns the number of steps of an execution trace of the specified length on which a
+    /// constraint with this exemption does not need to hold.
+    pub fn num_exempt_steps(&self, trace_length: usize) -> usize {
+        match *self {
+            Self::LastSteps(num_steps) => num_steps,
+            Self::Periodic { period, .. } => trace_length / period,
+        }
+    }
+
+    /// Returns the divisor for a transition constraint with this exemption applied against an
+    /// execution trace of the specified length.
+    pub fn divisor<B: StarkField>(&self, trace_length: usize) -> ConstraintDivisor<B> {
+        match *self {
+            Self::LastSteps(num_steps) => {
+                ConstraintDivisor::from_transition(trace_length, num_steps)
+            },
+            Self::Periodic { offset, period } => {
+                ConstraintDivisor::from_periodic_transition(trace_length, offset, period)
+            },
+        }
+    }
+}
@@ -0,0 +1,267 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::{
+    cmp,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use super::{EvaluationFrame, FieldElement, TransitionConstraintDegree};
+
+// CONSTRAINT EXPRESSION
+// ================================================================================================
+
+/// A transition constraint described by a polynomial expression over two consecutive rows of
+/// an execution trace.
+///
+/// Constraint expressions are usually built via the [constraint!](crate::constraint) macro,
+/// which derives the degree of the constraint from the expression itself. This way, the degree
+/// of a constraint can be passed to [AirContext::new()](crate::AirContext::new), and the same
+/// constraint can be evaluated in [Air::evaluate_transition()](crate::Air::evaluate_transition)
+/// without describing it twice.
+pub trait ConstraintExpression {
+    /// Returns the degree descriptor of this constraint.
+    fn degree(&self) -> TransitionConstraintDegree;
+
+    /// Evaluates this constraint against the specified evaluation frame.
+    fn evaluate<E: FieldElement>(&self, frame: &EvaluationFrame<E>) -> E;
+}
+
+// CONSTRAINT MACRO
+// ================================================================================================
+
+/// Builds a constraint from a polynomial expression over the columns of an execution trace.
+///
+/// The macro accepts the width of the execution trace followed by one of the following:
+/// * A polynomial expression over the current row `col[i]` and the next row `next[i]` of the
+///   trace. The expression may contain integer literals, parentheses, and `+`, `-`, `*`
+///   operators. The result implements [ConstraintExpression]; its degree is computed from the
+///   expression by adding up degrees of multiplied terms.
+/// * An expression of the form `col[i] @ step => value`, which is expanded into an
+///   [Assertion::single()](crate::Assertion::single) against column `i` at the specified step.
+///
+/// Column indexes and the trace width must be constant expressions; a column index which is
+/// out of bounds for the declared trace width results in a compile-time error.
+///
+/// Degrees of constraints built with this macro are always an upper bound. Specifically, terms
+/// which cancel out are not taken into account (e.g., the degree of `col[0] * col[0] - col[0] *
+/// col[0]` is 2). Constraints involving periodic columns should be described manually.
+///
+/// # Examples
+/// ```
+/// # use winter_air::{constraint, ConstraintExpression, TransitionConstraintDegree};
+/// # use math::fields::f128::BaseElement;
+/// const WIDTH: usize = 3;
+///
+/// let constraint = constraint!(width = WIDTH; next[2] - col[0] * col[1] + 1);
+/// assert_eq!(TransitionConstraintDegree::new(2), constraint.degree());
+///
+/// let assertion = constraint!(width = WIDTH; col[1] @ 0 => BaseElement::new(3));
+/// assert_eq!(1, assertion.column());
+/// ```
+///
+/// The following does not compile because column 3 is out of bounds for a trace of width 3:
+/// ```compile_fail
+/// # use winter_air::constraint;
+/// let constraint = constraint!(width = 3; next[0] - col[3]);
+/// ```
+#[macro_export]
+macro_rules! constraint {
+    (width = $width:expr; col[$col:expr] @ $step:expr => $value:expr) => {{
+        const _: () = assert!($col < $width, "column index is out of bounds");
+        $crate::Assertion::single($col, $step, $value)
+    }};
+    (width = $width:expr; $($expr:tt)+) => {
+        $crate::__constraint_expr!(@root [$width] (current, next, literal) [] [] $($expr)+)
+    };
+}
+
+/// Rewrites a constraint expression into an expression over the current and the next rows of
+/// the trace; this is an implementation detail of the [constraint!](crate::constraint) macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __constraint_expr {
+    (@root [$w:expr] ($cur:ident, $nxt:ident, $lit:ident) [$($checks:tt)*] [$($out:tt)*]) => {{
+        $($checks)*
+        struct Constraint;
+        impl $crate::ConstraintExpression for Constraint {
+            #[allow(unused_variables)]
+            fn degree(&self) -> $crate::TransitionConstraintDegree {
+                let $cur = [$crate::__private::ExprDegree::COLUMN; $w];
+                let $nxt = $cur;
+                let $lit = |_: u32| $crate::__private::ExprDegree::CONSTANT;
+                ($($out)*).into()
+            }
+
+            #[allow(unused_variables)]
+            fn evaluate<E: $crate::__private::FieldElement>(
+                &self,
+                frame: &$crate::EvaluationFrame<E>,
+            ) -> E {
+                let $cur = frame.current();
+                let $nxt = frame.next();
+                let $lit = |value: u32| E::from(value);
+                $($out)*
+            }
+        }
+        Constraint
+    }};
+    (@nested [$w:expr] ($cur:ident, $nxt:ident, $lit:ident) [$($checks:tt)*] [$($out:tt)*]) => {{
+        $($checks)*
+        $($out)*
+    }};
+    (@$mode:ident [$w:expr] ($cur:ident, $nxt:ident, $lit:ident) [$($checks:tt)*] [$($out:tt)*]
+        col [$i:expr] $($rest:tt)*) => {
+        $crate::__constraint_expr!(@$mode [$w] ($cur, $nxt, $lit)
+            [$($checks)* const _: () = assert!($i < $w, "column index is out of bounds");]
+            [$($out)* $cur[$i]] $($rest)*)
+    };
+    (@$mode:ident [$w:expr] ($cur:ident, $nxt:ident, $lit:ident) [$($checks:tt)*] [$($out:tt)*]
+        next [$i:expr] $($rest:tt)*) => {
+        $crate::__constraint_expr!(@$mode [$w] ($cur, $nxt, $lit)
+            [$($checks)* const _: () = assert!($i < $w, "column index is out of bounds");]
+            [$($out)* $nxt[$i]] $($rest)*)
+    };
+    (@$mode:ident [$w:expr] ($cur:ident, $nxt:ident, $lit:ident) [$($checks:tt)*] [$($out:tt)*]
+        ($($inner:tt)+) $($rest:tt)*) => {
+        $crate::__constraint_expr!(@$mode [$w] ($cur, $nxt, $lit) [$($checks)*]
+            [$($out)* ($crate::__constraint_expr!(@nested [$w] ($cur, $nxt, $lit) [] [] $($inner)+))]
+            $($rest)*)
+    };
+    (@$mode:ident [$w:expr] ($cur:ident, $nxt:ident, $lit:ident) [$($checks:tt)*] [$($out:tt)*]
+        + $($rest:tt)*) => {
+        $crate::__constraint_expr!(@$mode [$w] ($cur, $nxt, $lit) [$($checks)*] [$($out)* +] $($rest)*)
+    };
+    (@$mode:ident [$w:expr] ($cur:ident, $nxt:ident, $lit:ident) [$($checks:tt)*] [$($out:tt)*]
+        - $($rest:tt)*) => {
+        $crate::__constraint_expr!(@$mode [$w] ($cur, $nxt, $lit) [$($checks)*] [$($out)* -] $($rest)*)
+    };
+    (@$mode:ident [$w:expr] ($cur:ident, $nxt:ident, $lit:ident) [$($checks:tt)*] [$($out:tt)*]
+        * $($rest:tt)*) => {
+        $crate::__constraint_expr!(@$mode [$w] ($cur, $nxt, $lit) [$($checks)*] [$($out)* *] $($rest)*)
+    };
+    (@$mode:ident [$w:expr] ($cur:ident, $nxt:ident, $lit:ident) [$($checks:tt)*] [$($out:tt)*]
+        $value:literal $($rest:tt)*) => {
+        $crate::__constraint_expr!(@$mode [$w] ($cur, $nxt, $lit) [$($checks)*]
+            [$($out)* $lit($value)] $($rest)*)
+    };
+    (@$mode:ident [$w:expr] ($cur:ident, $nxt:ident, $lit:ident) [$($checks:tt)*] [$($out:tt)*]
+        $token:tt $($rest:tt)*) => {
+        compile_error!(concat!(
+            "unsupported token in constraint expression: ",
+            stringify!($token)
+        ))
+    };
+}
+
+// EXPRESSION DEGREE
+// ================================================================================================
+
+/// Degree of a constraint expression in trace columns; used by the [constraint!](crate::constraint)
+/// macro to compute degrees of constraints.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExprDegree(usize);
+
+impl ExprDegree {
+    /// Degree of a single trace column.
+    pub const COLUMN: Self = Self(1);
+
+    /// Degree of a constant.
+    pub const CONSTANT: Self = Self(0);
+}
+
+impl Add for ExprDegree {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(cmp::max(self.0, rhs.0))
+    }
+}
+
+impl Sub for ExprDegree {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(cmp::max(self.0, rhs.0))
+    }
+}
+
+impl Mul for ExprDegree {
+    type Output = Self;
+
+    // degrees of multiplied expressions add up
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Neg for ExprDegree {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl From<ExprDegree> for TransitionConstraintDegree {
+    fn from(degree: ExprDegree) -> Self {
+        TransitionConstraintDegree::new(degree.0)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use math::{fields::f64::BaseElement, FieldElement};
+
+    use super::*;
+
+    const WIDTH: usize = 3;
+
+    #[test]
+    fn constraint_degree() {
+        let constraint = constraint!(width = WIDTH; next[0] - col[0] - col[1]);
+        assert_eq!(TransitionConstraintDegree::new(1), constraint.degree());
+
+        let constraint = constraint!(width = WIDTH; next[2] - col[0] * col[1]);
+        assert_eq!(TransitionConstraintDegree::new(2), constraint.degree());
+
+        let constraint = constraint!(width = WIDTH; 3 * next[1] - (col[0] + 1) * col[1] * -col[2]);
+        assert_eq!(TransitionConstraintDegree::new(3), constraint.degree());
+
+        let constraint = constraint!(width = WIDTH; ((col[0] - 1) * (col[0] - 2)) * next[0]);
+        assert_eq!(TransitionConstraintDegree::new(3), constraint.degree());
+    }
+
+    #[test]
+    fn constraint_evaluation() {
+        let current = vec![BaseElement::new(2), BaseElement::new(3), BaseElement::new(5)];
+        let next = vec![BaseElement::new(7), BaseElement::new(11), BaseElement::new(13)];
+        let frame = EvaluationFrame::from_rows(current, next);
+
+        let constraint = constraint!(width = WIDTH; next[2] - col[0] * col[1]);
+        assert_eq!(BaseElement::new(7), constraint.evaluate(&frame));
+
+        // 3 * 11 - (2 + 1) * 3 * -5 = 78
+        let constraint = constraint!(width = WIDTH; 3 * next[1] - (col[0] + 1) * col[1] * -col[2]);
+        assert_eq!(BaseElement::new(78), constraint.evaluate(&frame));
+
+        // the constraint is satisfied when the expression evaluates to zero
+        let constraint = constraint!(width = WIDTH; next[0] - col[0] - col[2]);
+        assert_eq!(BaseElement::ZERO, constraint.evaluate(&frame));
+    }
+
+    #[test]
+    fn constraint_assertion() {
+        let assertion = constraint!(width = WIDTH; col[2] @ 7 => BaseElement::new(11));
+        assert_eq!(crate::Assertion::single(2, 7, BaseElement::new(11)), assertion);
+    }
+}
@@ -12,56 +12,104 @@ use super::FieldElement;
 
 /// A set of execution trace rows required for evaluation of transition constraints.
 ///
-/// In the current implementation, an evaluation frame always contains two consecutive rows of the
-/// execution trace. It is passed in as one of the parameters into
+/// An evaluation frame contains a window of consecutive rows of the execution trace; by default,
+/// the window consists of two rows (the current and the next row), but an AIR can request a
+/// wider window via [AirContext::set_frame_size()](crate::AirContext::set_frame_size). It is
+/// passed in as one of the parameters into
 /// [Air::evaluate_transition()](crate::Air::evaluate_transition) function.
 #[derive(Debug, Clone)]
 pub struct EvaluationFrame<E: FieldElement> {
-    current: Vec<E>,
-    next: Vec<E>,
+    rows: Vec<Vec<E>>,
 }
 
 impl<E: FieldElement> EvaluationFrame<E> {
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns a new evaluation frame instantiated with the specified number of columns.
+    /// Returns a new evaluation frame of two rows instantiated with the specified number of
+    /// columns.
     ///
     /// # Panics
     /// Panics if `num_columns` is zero.
     pub fn new(num_columns: usize) -> Self {
+        Self::with_num_rows(num_columns, 2)
+    }
+
+    /// Returns a new evaluation frame of `num_rows` rows instantiated with the specified number
+    /// of columns.
+    ///
+    /// # Panics
+    /// Panics if `num_columns` is zero or `num_rows` is smaller than two.
+    pub fn with_num_rows(num_columns: usize, num_rows: usize) -> Self {
         assert!(num_columns > 0, "number of columns must be greater than zero");
+        assert!(num_rows >= 2, "number of rows must be at least 2, but was {num_rows}");
         EvaluationFrame {
-            current: vec![E::ZERO; num_columns],
-            next: vec![E::ZERO; num_columns],
+            rows: vec![vec![E::ZERO; num_columns]; num_rows],
         }
     }
 
-    /// Returns a new evaluation frame instantiated from the provided rows.
+    /// Returns a new evaluation frame instantiated from the provided current and next rows.
     ///
     /// # Panics
     /// Panics if:
     /// * Lengths of the provided rows are zero.
     /// * Lengths of the provided rows are not the same.
     pub fn from_rows(current: Vec<E>, next: Vec<E>) -> Self {
-        assert!(!current.is_empty(), "a row must contain at least one value");
-        assert_eq!(current.len(), next.len(), "number of values in the rows must be the same");
-        Self { current, next }
+        Self::from_row_vec(vec![current, next])
+    }
+
+    /// Returns a new evaluation frame instantiated from the provided consecutive rows.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * Fewer than two rows are provided.
+    /// * Lengths of the provided rows are zero.
+    /// * Lengths of the provided rows are not the same.
+    pub fn from_row_vec(rows: Vec<Vec<E>>) -> Self {
+        assert!(rows.len() >= 2, "number of rows must be at least 2, but was {}", rows.len());
+        assert!(!rows[0].is_empty(), "a row must contain at least one value");
+        assert!(
+            rows.iter().all(|row| row.len() == rows[0].len()),
+            "number of values in the rows must be the same"
+        );
+        Self { rows }
     }
 
     // ROW ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the number of rows in this frame.
+    #[inline(always)]
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the number of columns in this frame.
+    #[inline(always)]
+    pub fn num_columns(&self) -> usize {
+        self.rows[0].len()
+    }
+
     /// Returns a reference to the current row.
     #[inline(always)]
     pub fn current(&self) -> &[E] {
-        &self.current
+        &self.rows[0]
     }
 
     /// Returns a reference to the next row.
     #[inline(always)]
     pub fn next(&self) -> &[E] {
-        &self.next
+        &self.rows[1]
+    }
+
+    /// Returns a reference to the row at the specified offset from the current row; offset 0
+    /// refers to the current row, offset 1 refers to the next row etc.
+    ///
+    /// # Panics
+    /// Panics if `offset` is greater than or equal to the number of rows in this frame.
+    #[inline(always)]
+    pub fn row(&self, offset: usize) -> &[E] {
+        &self.rows[offset]
     }
 
     // DATA MUTATORS
@@ -70,12 +118,21 @@ impl<E: FieldElement> EvaluationFrame<E> {
     /// Returns a mutable reference to the current row.
     #[inline(always)]
     pub fn current_mut(&mut self) -> &mut [E] {
-        &mut self.current
+        &mut self.rows[0]
     }
 
     /// Returns a mutable reference to the next row.
     #[inline(always)]
     pub fn next_mut(&mut self) -> &mut [E] {
-        &mut self.next
+        &mut self.rows[1]
+    }
+
+    /// Returns a mutable reference to the row at the specified offset from the current row.
+    ///
+    /// # Panics
+    /// Panics if `offset` is greater than or equal to the number of rows in this frame.
+    #[inline(always)]
+    pub fn row_mut(&mut self, offset: usize) -> &mut [E] {
+        &mut self.rows[offset]
     }
 }
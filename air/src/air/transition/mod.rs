@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 use super::{AirContext, ConstraintDivisor, ExtensionOf, FieldElement};
 
@@ -13,6 +13,12 @@ pub use frame::EvaluationFrame;
 mod degree;
 pub use degree::TransitionConstraintDegree;
 
+mod exemption;
+pub use exemption::TransitionExemption;
+
+mod expression;
+pub use expression::{ConstraintExpression, ExprDegree};
+
 // CONSTANTS
 // ================================================================================================
 
@@ -28,13 +34,15 @@ const MIN_CYCLE_LENGTH: usize = 2;
 ///   trace segments (if any).
 /// - Groupings of random composition constraint coefficients separately for the main trace segment
 ///   and for auxiliary tace segment.
-/// - Divisor of transition constraints for a computation.
+/// - Divisors of transition constraints for a computation, and the index of the divisor of each
+///   constraint.
 pub struct TransitionConstraints<E: FieldElement> {
     main_constraint_coef: Vec<E>,
     main_constraint_degrees: Vec<TransitionConstraintDegree>,
     aux_constraint_coef: Vec<E>,
     aux_constraint_degrees: Vec<TransitionConstraintDegree>,
-    divisor: ConstraintDivisor<E::BaseField>,
+    divisors: Vec<ConstraintDivisor<E::BaseField>>,
+    divisor_indexes: Vec<usize>,
 }
 
 impl<E: FieldElement> TransitionConstraints<E> {
@@ -53,11 +61,23 @@ impl<E: FieldElement> TransitionConstraints<E> {
             "number of transition constraints must match the number of composition coefficient tuples"
         );
 
-        // build constraint divisor; the same divisor applies to all transition constraints
-        let divisor = ConstraintDivisor::from_transition(
+        // build the default constraint divisor, which applies to all transition constraints
+        // without custom exemptions; constraints with the same custom exemptions share divisors
+        let mut divisors = vec![ConstraintDivisor::from_transition(
             context.trace_len(),
             context.num_transition_exemptions(),
-        );
+        )];
+        let mut divisor_indexes = Vec::with_capacity(context.num_transition_constraints());
+        for constraint_idx in 0..context.num_transition_constraints() {
+            let divisor = context.transition_exemption(constraint_idx).divisor(context.trace_len());
+            match divisors.iter().position(|d| d == &divisor) {
+                Some(divisor_idx) => divisor_indexes.push(divisor_idx),
+                None => {
+                    divisor_indexes.push(divisors.len());
+                    divisors.push(divisor);
+                },
+            }
+        }
 
         let main_constraint_degrees = context.main_transition_constraint_degrees.clone();
         let aux_constraint_degrees = context.aux_transition_constraint_degrees.clone();
@@ -69,7 +89,8 @@ impl<E: FieldElement> TransitionConstraints<E> {
             main_constraint_degrees,
             aux_constraint_coef: aux_constraint_coef.to_vec(),
             aux_constraint_degrees,
-            divisor,
+            divisors,
+            divisor_indexes,
         }
     }
 
@@ -118,24 +139,84 @@ impl<E: FieldElement> TransitionConstraints<E> {
         self.aux_constraint_coef.clone()
     }
 
-    /// Returns a divisor for transition constraints.
+    /// Returns the default divisor for transition constraints.
     ///
-    /// All transition constraints have the same divisor which has the form:
+    /// Unless custom exemptions are specified for a constraint (see
+    /// [AirContext::set_transition_exemption()]), the divisor of a transition constraint has the
+    /// form:
     /// $$
-    /// z(x) = \frac{x^n - 1}{x - g^{n - 1}}
+    /// z(x) = \frac{x^n - 1}{\prod_{i=1}^k (x - g^{n-i})}
     /// $$
-    /// where: $n$ is the length of the execution trace and $g$ is the generator of the trace
-    /// domain.
+    /// where: $n$ is the length of the execution trace, $g$ is the generator of the trace
+    /// domain, and $k$ is the number of exemptions (the default value for $k$ is $1$).
     ///
     /// This divisor specifies that transition constraints must hold on all steps of the
-    /// execution trace except for the last one.
+    /// execution trace except for the last $k$ steps.
     pub fn divisor(&self) -> &ConstraintDivisor<E::BaseField> {
-        &self.divisor
+        &self.divisors[0]
+    }
+
+    /// Returns all distinct divisors of transition constraints; the first divisor is always the
+    /// default divisor (see [Self::divisor()]).
+    pub fn divisors(&self) -> &[ConstraintDivisor<E::BaseField>] {
+        &self.divisors
+    }
+
+    /// Returns the divisor of the transition constraint at the specified index; main trace
+    /// constraints are indexed first, followed by the auxiliary trace constraints.
+    pub fn constraint_divisor(&self, constraint_idx: usize) -> &ConstraintDivisor<E::BaseField> {
+        &self.divisors[self.divisor_indexes[constraint_idx]]
     }
 
     // CONSTRAINT COMPOSITION
     // --------------------------------------------------------------------------------------------
 
+    /// Computes linear combinations of transition constraint evaluations for each divisor of
+    /// transition constraints.
+    ///
+    /// Evaluations of constraints sharing a divisor are multiplied by their composition
+    /// coefficients and added into the slot of `result` corresponding to the index of the
+    /// divisor in [Self::divisors()]; no division is performed. Length of `result` must be
+    /// equal to the number of divisors. `aux_evaluations` may be empty if the auxiliary trace
+    /// constraints should not be included.
+    pub fn merge_evaluations<F>(
+        &self,
+        main_evaluations: &[F],
+        aux_evaluations: &[E],
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = E::BaseField>,
+        E: ExtensionOf<F>,
+    {
+        // most computations have a single divisor; in this case, there is no need to look up
+        // divisor indexes
+        if self.divisors.len() == 1 {
+            result[0] = main_evaluations
+                .iter()
+                .zip(self.main_constraint_coef.iter())
+                .fold(E::ZERO, |acc, (&const_eval, &coef)| acc + coef.mul_base(const_eval));
+            result[0] += aux_evaluations
+                .iter()
+                .zip(self.aux_constraint_coef.iter())
+                .fold(E::ZERO, |acc, (&const_eval, &coef)| acc + coef * const_eval);
+            return;
+        }
+
+        result.fill(E::ZERO);
+        let (main_indexes, aux_indexes) =
+            self.divisor_indexes.split_at(self.num_main_constraints());
+        for ((&const_eval, &coef), &divisor_idx) in
+            main_evaluations.iter().zip(self.main_constraint_coef.iter()).zip(main_indexes)
+        {
+            result[divisor_idx] += coef.mul_base(const_eval);
+        }
+        for ((&const_eval, &coef), &divisor_idx) in
+            aux_evaluations.iter().zip(self.aux_constraint_coef.iter()).zip(aux_indexes)
+        {
+            result[divisor_idx] += coef * const_eval;
+        }
+    }
+
     /// Computes a linear combination of all transition constraint evaluations and divides the
     /// result by transition constraint divisor.
     ///
@@ -146,30 +227,25 @@ impl<E: FieldElement> TransitionConstraints<E> {
     ///
     /// Thus, this function computes a linear combination of $C(x)$ evaluations.
     ///
-    /// Since, the divisor polynomial is the same for all transition constraints (see
-    /// [ConstraintDivisor::from_transition]), we can divide the linear combination by the
-    /// divisor rather than dividing each individual $C(x)$ evaluation. This requires executing only
-    /// one division at the end.
+    /// Since, the divisor polynomial is usually the same for all transition constraints (see
+    /// [ConstraintDivisor::from_transition]), we divide the linear combination of constraints
+    /// sharing a divisor by the divisor rather than dividing each individual $C(x)$ evaluation.
+    /// This requires executing only one division per distinct divisor at the end.
     pub fn combine_evaluations<F>(&self, main_evaluations: &[F], aux_evaluations: &[E], x: F) -> E
     where
         F: FieldElement<BaseField = E::BaseField>,
         E: ExtensionOf<F>,
     {
-        // merge constraint evaluations for the main trace segment
-        let mut result = main_evaluations
-            .iter()
-            .zip(self.main_constraint_coef.iter())
-            .fold(E::ZERO, |acc, (&const_eval, &coef)| acc + coef.mul_base(const_eval));
-
-        if !self.aux_constraint_coef.is_empty() {
-            result += aux_evaluations
-                .iter()
-                .zip(self.aux_constraint_coef.iter())
-                .fold(E::ZERO, |acc, (&const_eval, &coef)| acc + coef * const_eval);
-        };
-        // divide out the evaluation of divisor at x and return the result
-        let z = E::from(self.divisor.evaluate_at(x));
-
-        result / z
+        // merge constraint evaluations for each divisor
+        let mut merged_evaluations = vec![E::ZERO; self.divisors.len()];
+        self.merge_evaluations(main_evaluations, aux_evaluations, &mut merged_evaluations);
+
+        // divide out the evaluation of each divisor at x and return the sum of the results
+        merged_evaluations
+            .into_iter()
+            .zip(self.divisors.iter())
+            .fold(E::ZERO, |acc, (evaluation, divisor)| {
+                acc + evaluation / E::from(divisor.evaluate_at(x))
+            })
     }
 }
@@ -0,0 +1,65 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::fmt;
+
+use math::FieldElement;
+
+// CONSTRAINT VIOLATION
+// ================================================================================================
+
+/// Describes a single violation of an AIR constraint by an execution trace.
+///
+/// Constraint violations are reported by [Air::check_constraints()](crate::Air::check_constraints)
+/// and are intended to help with debugging AIR implementations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintViolation<E: FieldElement> {
+    /// Type of the violated constraint.
+    pub constraint_type: ConstraintType,
+    /// Index of the violated constraint. For transition constraints, this is the index of the
+    /// constraint in the `result` slice of [Air::evaluate_transition()](crate::Air::evaluate_transition);
+    /// for boundary constraints, this is the index of the assertion in the list returned by
+    /// [Air::get_assertions()](crate::Air::get_assertions).
+    pub constraint_idx: usize,
+    /// Index of the trace row at which the constraint was violated.
+    pub row: usize,
+    /// Degree of the constraint polynomial when evaluated over the execution trace.
+    pub degree: usize,
+    /// Value to which the constraint evaluated at the specified row. For boundary constraints,
+    /// this is the difference between the value in the trace and the asserted value.
+    pub evaluation: E,
+}
+
+impl<E: FieldElement> fmt::Display for ConstraintViolation<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} constraint {} of degree {} evaluated to {} at row {}",
+            self.constraint_type, self.constraint_idx, self.degree, self.evaluation, self.row
+        )
+    }
+}
+
+// CONSTRAINT TYPE
+// ================================================================================================
+
+/// Defines the type of a constraint described by an AIR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintType {
+    /// A transition constraint defined by [Air::evaluate_transition()](crate::Air::evaluate_transition).
+    Transition,
+    /// A boundary constraint derived from an assertion returned by
+    /// [Air::get_assertions()](crate::Air::get_assertions).
+    Boundary,
+}
+
+impl fmt::Display for ConstraintType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transition => write!(f, "transition"),
+            Self::Boundary => write!(f, "boundary"),
+        }
+    }
+}
@@ -0,0 +1,550 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Compiler of arithmetic circuits into AIR.
+//!
+//! An arithmetic [Circuit] is a directed acyclic graph of [AddGate], [MulGate], and
+//! [ConstantGate] nodes over a set of circuit inputs. A circuit is built incrementally: every
+//! gate can take as operands only the wires which were added to the circuit before it, and thus,
+//! the graph is acyclic by construction.
+//!
+//! A circuit is compiled into [CircuitAir] which describes many independent evaluations of the
+//! circuit: every row of the execution trace holds the values of a single evaluation. The columns
+//! of a row are laid out as follows:
+//!
+//! * One column for each circuit input.
+//! * Three columns `a`, `b`, and `c` for each multiplication gate, holding the operands and the
+//!   result of the gate.
+//! * One column for each circuit output.
+//!
+//! Additions and constants are not allocated any columns. Instead, every wire of the circuit is
+//! resolved into a linear combination of input and multiplication result columns (plus a
+//! constant). The AIR then contains:
+//!
+//! * One transition constraint `a * b - c = 0` of degree 2 for each multiplication gate.
+//! * Wiring (copy) constraints of degree 1 which make sure that the operand columns of every
+//!   multiplication gate and the output columns are equal to the linear combinations of the
+//!   wires they are connected to.
+//!
+//! Transition constraints are evaluated against the current row only; the last row of the trace
+//! is exempt from all constraints, and should hold padding values (see [Circuit::build_row()]).
+//! Public inputs of [CircuitAir] consist of the circuit together with the expected outputs of
+//! the evaluations in the first rows of the trace.
+
+use alloc::vec::Vec;
+
+use math::{ExtensibleField, FieldElement, StarkField, ToElements};
+
+use crate::{
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+#[cfg(test)]
+mod tests;
+
+// WIRE
+// ================================================================================================
+
+/// A reference to the output of a circuit input or a gate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wire(usize);
+
+impl Wire {
+    /// Returns the index of the node in the circuit which this wire is the output of.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+// GATES
+// ================================================================================================
+
+/// A gate which outputs the sum of its operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddGate {
+    pub lhs: Wire,
+    pub rhs: Wire,
+}
+
+/// A gate which outputs the product of its operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MulGate {
+    pub lhs: Wire,
+    pub rhs: Wire,
+}
+
+/// A gate which outputs a constant value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstantGate<B: StarkField> {
+    pub value: B,
+}
+
+/// A node of an arithmetic circuit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gate<B: StarkField> {
+    /// The circuit input with the specified index.
+    Input(usize),
+    Add(AddGate),
+    Mul(MulGate),
+    Constant(ConstantGate<B>),
+}
+
+// CIRCUIT
+// ================================================================================================
+
+/// An arithmetic circuit described as a directed acyclic graph of gates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Circuit<B: StarkField> {
+    gates: Vec<Gate<B>>,
+    num_inputs: usize,
+    num_mul_gates: usize,
+    outputs: Vec<Wire>,
+}
+
+impl<B: StarkField> Circuit<B> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new circuit without any gates.
+    pub fn new() -> Self {
+        Self {
+            gates: Vec::new(),
+            num_inputs: 0,
+            num_mul_gates: 0,
+            outputs: Vec::new(),
+        }
+    }
+
+    // CIRCUIT BUILDERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds a new input to this circuit and returns the wire carrying its value.
+    pub fn input(&mut self) -> Wire {
+        self.num_inputs += 1;
+        self.push(Gate::Input(self.num_inputs - 1))
+    }
+
+    /// Adds a gate outputting the specified constant to this circuit.
+    pub fn constant(&mut self, value: B) -> Wire {
+        self.push(Gate::Constant(ConstantGate { value }))
+    }
+
+    /// Adds a gate outputting the sum of the specified wires to this circuit.
+    ///
+    /// # Panics
+    /// Panics if any of the wires does not belong to this circuit.
+    pub fn add(&mut self, lhs: Wire, rhs: Wire) -> Wire {
+        self.check_wire(lhs);
+        self.check_wire(rhs);
+        self.push(Gate::Add(AddGate { lhs, rhs }))
+    }
+
+    /// Adds a gate outputting the product of the specified wires to this circuit.
+    ///
+    /// # Panics
+    /// Panics if any of the wires does not belong to this circuit.
+    pub fn mul(&mut self, lhs: Wire, rhs: Wire) -> Wire {
+        self.check_wire(lhs);
+        self.check_wire(rhs);
+        self.num_mul_gates += 1;
+        self.push(Gate::Mul(MulGate { lhs, rhs }))
+    }
+
+    /// Marks the specified wire as an output of this circuit.
+    ///
+    /// # Panics
+    /// Panics if the wire does not belong to this circuit.
+    pub fn output(&mut self, wire: Wire) {
+        self.check_wire(wire);
+        self.outputs.push(wire);
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns all nodes of this circuit (including inputs) in the order in which they were
+    /// added.
+    pub fn gates(&self) -> &[Gate<B>] {
+        &self.gates
+    }
+
+    /// Returns the number of inputs of this circuit.
+    pub fn num_inputs(&self) -> usize {
+        self.num_inputs
+    }
+
+    /// Returns the number of multiplication gates in this circuit.
+    pub fn num_mul_gates(&self) -> usize {
+        self.num_mul_gates
+    }
+
+    /// Returns the output wires of this circuit.
+    pub fn outputs(&self) -> &[Wire] {
+        &self.outputs
+    }
+
+    /// Returns the number of columns in a row of the execution trace of this circuit.
+    pub fn trace_width(&self) -> usize {
+        self.num_inputs + 3 * self.num_mul_gates + self.outputs.len()
+    }
+
+    /// Returns the index of the trace column holding the value of the specified output.
+    ///
+    /// # Panics
+    /// Panics if `output_idx` is not smaller than the number of outputs of this circuit.
+    pub fn output_column(&self, output_idx: usize) -> usize {
+        assert!(output_idx < self.outputs.len(), "output {output_idx} does not exist");
+        self.num_inputs + 3 * self.num_mul_gates + output_idx
+    }
+
+    // EVALUATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Evaluates this circuit on the specified inputs and returns the values of all wires.
+    ///
+    /// # Panics
+    /// Panics if the number of inputs does not match the number of inputs of this circuit.
+    pub fn evaluate(&self, inputs: &[B]) -> Vec<B> {
+        assert_eq!(
+            self.num_inputs,
+            inputs.len(),
+            "expected {} circuit inputs, but received {}",
+            self.num_inputs,
+            inputs.len()
+        );
+
+        let mut values: Vec<B> = Vec::with_capacity(self.gates.len());
+        for gate in self.gates.iter() {
+            let value = match gate {
+                Gate::Input(idx) => inputs[*idx],
+                Gate::Add(gate) => values[gate.lhs.0] + values[gate.rhs.0],
+                Gate::Mul(gate) => values[gate.lhs.0] * values[gate.rhs.0],
+                Gate::Constant(gate) => gate.value,
+            };
+            values.push(value);
+        }
+        values
+    }
+
+    /// Evaluates this circuit on the specified inputs and returns the values of this evaluation
+    /// laid out as a row of the execution trace.
+    ///
+    /// The last row of the execution trace is exempt from all constraints; it should be filled
+    /// with values which do not satisfy the constraints (e.g., random values), as otherwise the
+    /// degrees of the constraints may be lower than the degrees expected by the prover.
+    ///
+    /// # Panics
+    /// Panics if the number of inputs does not match the number of inputs of this circuit.
+    pub fn build_row(&self, inputs: &[B]) -> Vec<B> {
+        let values = self.evaluate(inputs);
+
+        let mut row = inputs.to_vec();
+        for gate in self.gates.iter() {
+            if let Gate::Mul(gate) = gate {
+                let (lhs, rhs) = (values[gate.lhs.0], values[gate.rhs.0]);
+                row.extend_from_slice(&[lhs, rhs, lhs * rhs]);
+            }
+        }
+        row.extend(self.outputs.iter().map(|wire| values[wire.0]));
+        row
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn push(&mut self, gate: Gate<B>) -> Wire {
+        self.gates.push(gate);
+        Wire(self.gates.len() - 1)
+    }
+
+    fn check_wire(&self, wire: Wire) {
+        assert!(wire.0 < self.gates.len(), "wire {} does not belong to the circuit", wire.0);
+    }
+
+    /// Resolves every wire of this circuit into a linear combination of trace columns.
+    fn wire_combinations(&self) -> Vec<LinearCombination<B>> {
+        let mut combinations: Vec<LinearCombination<B>> = Vec::with_capacity(self.gates.len());
+        let mut mul_idx = 0;
+        for gate in self.gates.iter() {
+            let combination = match gate {
+                Gate::Input(idx) => LinearCombination::column(*idx),
+                Gate::Add(gate) => combinations[gate.lhs.0].add(&combinations[gate.rhs.0]),
+                Gate::Mul(_) => {
+                    mul_idx += 1;
+                    LinearCombination::column(self.num_inputs + 3 * mul_idx - 1)
+                },
+                Gate::Constant(gate) => LinearCombination::constant(gate.value),
+            };
+            combinations.push(combination);
+        }
+        combinations
+    }
+}
+
+impl<B: StarkField> Default for Circuit<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// CIRCUIT PUBLIC INPUTS
+// ================================================================================================
+
+/// Public inputs of [CircuitAir].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitInputs<B: StarkField> {
+    /// The circuit evaluated in every row of the execution trace.
+    pub circuit: Circuit<B>,
+    /// Expected outputs of the evaluations in the first `outputs.len()` rows of the trace.
+    pub outputs: Vec<Vec<B>>,
+}
+
+impl<B: StarkField> ToElements<B> for CircuitInputs<B> {
+    fn to_elements(&self) -> Vec<B> {
+        let circuit = &self.circuit;
+        let mut result = vec![
+            B::from_u64(circuit.num_inputs as u64),
+            B::from_u64(circuit.gates.len() as u64),
+            B::from_u64(circuit.outputs.len() as u64),
+        ];
+        for gate in circuit.gates.iter() {
+            match gate {
+                Gate::Input(idx) => result.extend([B::ZERO, B::from_u64(*idx as u64)]),
+                Gate::Add(gate) => result.extend([
+                    B::from(1u8),
+                    B::from_u64(gate.lhs.0 as u64),
+                    B::from_u64(gate.rhs.0 as u64),
+                ]),
+                Gate::Mul(gate) => result.extend([
+                    B::from(2u8),
+                    B::from_u64(gate.lhs.0 as u64),
+                    B::from_u64(gate.rhs.0 as u64),
+                ]),
+                Gate::Constant(gate) => result.extend([B::from(3u8), gate.value]),
+            }
+        }
+        result.extend(circuit.outputs.iter().map(|wire| B::from_u64(wire.0 as u64)));
+        result.push(B::from_u64(self.outputs.len() as u64));
+        result.extend(self.outputs.iter().flatten().copied());
+        result
+    }
+}
+
+// CIRCUIT AIR
+// ================================================================================================
+
+/// AIR for many independent evaluations of an arithmetic circuit.
+///
+/// See the [module documentation](self) for the layout of the execution trace and the
+/// constraints of this AIR.
+pub struct CircuitAir<B: StarkField> {
+    context: AirContext<B>,
+    circuit: Circuit<B>,
+    outputs: Vec<Vec<B>>,
+    mul_operands: Vec<(LinearCombination<B>, LinearCombination<B>)>,
+    output_combinations: Vec<LinearCombination<B>>,
+}
+
+impl<B: StarkField> CircuitAir<B> {
+    /// Returns the circuit this AIR was compiled from.
+    pub fn circuit(&self) -> &Circuit<B> {
+        &self.circuit
+    }
+}
+
+impl<B> Air for CircuitAir<B>
+where
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3>,
+{
+    type BaseField = B;
+    type PublicInputs = CircuitInputs<B>;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    /// Compiles the circuit specified in the public inputs into AIR.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The circuit does not have any outputs.
+    /// * Width of the trace does not match the width of the circuit trace.
+    /// * No expected outputs are provided, or expected outputs are provided for the last row
+    ///   of the trace.
+    /// * The number of values in any of the expected outputs does not match the number of
+    ///   outputs of the circuit.
+    fn new(trace_info: TraceInfo, pub_inputs: CircuitInputs<B>, options: ProofOptions) -> Self {
+        let CircuitInputs { circuit, outputs } = pub_inputs;
+        assert!(!circuit.outputs.is_empty(), "circuit must have at least one output");
+        assert_eq!(
+            circuit.trace_width(),
+            trace_info.main_trace_width(),
+            "expected trace width {}, but was {}",
+            circuit.trace_width(),
+            trace_info.main_trace_width()
+        );
+        assert!(!outputs.is_empty(), "expected outputs of at least one evaluation are required");
+        assert!(
+            outputs.len() < trace_info.length(),
+            "expected outputs of at most {} evaluations can be specified, but received {}",
+            trace_info.length() - 1,
+            outputs.len()
+        );
+        for values in outputs.iter() {
+            assert_eq!(
+                circuit.outputs.len(),
+                values.len(),
+                "expected {} output values per evaluation, but received {}",
+                circuit.outputs.len(),
+                values.len()
+            );
+        }
+
+        // resolve operands of all multiplication gates and all outputs into linear combinations
+        // of trace columns
+        let combinations = circuit.wire_combinations();
+        let mul_operands = circuit
+            .gates
+            .iter()
+            .filter_map(|gate| match gate {
+                Gate::Mul(gate) => {
+                    Some((combinations[gate.lhs.0].clone(), combinations[gate.rhs.0].clone()))
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let output_combinations = circuit
+            .outputs
+            .iter()
+            .map(|wire| combinations[wire.0].clone())
+            .collect::<Vec<_>>();
+
+        // multiplication constraints come first, followed by wiring constraints for the operands
+        // of every multiplication gate and for every output
+        let num_wiring_constraints = 2 * mul_operands.len() + output_combinations.len();
+        let mut degrees = vec![TransitionConstraintDegree::new(2); mul_operands.len()];
+        degrees.resize(
+            mul_operands.len() + num_wiring_constraints,
+            TransitionConstraintDegree::new(1),
+        );
+
+        let num_assertions = outputs.len() * circuit.outputs.len();
+        let context = AirContext::new(trace_info, degrees, num_assertions, options);
+
+        CircuitAir {
+            context,
+            circuit,
+            outputs,
+            mul_operands,
+            output_combinations,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let row = frame.current();
+        let num_mul_gates = self.mul_operands.len();
+        let mul_columns = &row[self.circuit.num_inputs..];
+
+        for (i, (lhs, rhs)) in self.mul_operands.iter().enumerate() {
+            let (a, b, c) = (mul_columns[3 * i], mul_columns[3 * i + 1], mul_columns[3 * i + 2]);
+            result[i] = a * b - c;
+            result[num_mul_gates + 2 * i] = a - lhs.evaluate(row);
+            result[num_mul_gates + 2 * i + 1] = b - rhs.evaluate(row);
+        }
+
+        let output_columns = &mul_columns[3 * num_mul_gates..];
+        for (i, combination) in self.output_combinations.iter().enumerate() {
+            result[3 * num_mul_gates + i] = output_columns[i] - combination.evaluate(row);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let mut assertions = Vec::new();
+        for (step, values) in self.outputs.iter().enumerate() {
+            for (output_idx, &value) in values.iter().enumerate() {
+                let column = self.circuit.output_column(output_idx);
+                assertions.push(Assertion::single(column, step, value));
+            }
+        }
+        assertions
+    }
+}
+
+// LINEAR COMBINATION
+// ================================================================================================
+
+/// A linear combination of trace columns plus a constant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LinearCombination<B: StarkField> {
+    terms: Vec<(usize, B)>,
+    constant: B,
+}
+
+impl<B: StarkField> LinearCombination<B> {
+    pub(crate) fn column(column: usize) -> Self {
+        Self::term(column, B::ONE)
+    }
+
+    /// Returns a linear combination consisting of the specified column multiplied by `coeff`.
+    pub(crate) fn term(column: usize, coeff: B) -> Self {
+        let terms = if coeff == B::ZERO {
+            Vec::new()
+        } else {
+            vec![(column, coeff)]
+        };
+        Self { terms, constant: B::ZERO }
+    }
+
+    pub(crate) fn constant(value: B) -> Self {
+        Self { terms: Vec::new(), constant: value }
+    }
+
+    /// Returns the sum of this and the other linear combination; terms are kept sorted by column
+    /// index, and terms for the same column are merged together.
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        let mut terms = Vec::with_capacity(self.terms.len() + other.terms.len());
+        let (mut lhs, mut rhs) = (self.terms.iter().peekable(), other.terms.iter().peekable());
+        loop {
+            let term = match (lhs.peek(), rhs.peek()) {
+                (Some(&&(l, lc)), Some(&&(r, rc))) if l == r => {
+                    lhs.next();
+                    rhs.next();
+                    (l, lc + rc)
+                },
+                (Some(&&l), Some(&&r)) if l.0 < r.0 => *lhs.next().unwrap(),
+                (Some(_), Some(_)) => *rhs.next().unwrap(),
+                (Some(_), None) => *lhs.next().unwrap(),
+                (None, Some(_)) => *rhs.next().unwrap(),
+                (None, None) => break,
+            };
+            if term.1 != B::ZERO {
+                terms.push(term);
+            }
+        }
+        Self {
+            terms,
+            constant: self.constant + other.constant,
+        }
+    }
+
+    /// Returns true if this linear combination does not depend on any of the trace columns.
+    pub(crate) fn is_constant(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub(crate) fn evaluate<E: FieldElement<BaseField = B>>(&self, row: &[E]) -> E {
+        self.terms.iter().fold(E::from(self.constant), |acc, &(column, coeff)| {
+            acc + E::from(coeff) * row[column]
+        })
+    }
+}
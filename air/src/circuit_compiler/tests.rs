@@ -0,0 +1,180 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::{fields::f64::BaseElement, FieldElement, ToElements};
+
+use super::{Circuit, CircuitAir, CircuitInputs};
+use crate::{
+    Air, EvaluationFrame, FieldExtension, ProofOptions, TraceInfo, TransitionConstraintDegree,
+};
+
+// CIRCUIT TESTS
+// ================================================================================================
+
+#[test]
+fn circuit_evaluation() {
+    let circuit = build_circuit();
+    assert_eq!(2, circuit.num_inputs());
+    assert_eq!(2, circuit.num_mul_gates());
+    assert_eq!(2 + 3 * 2 + 1, circuit.trace_width());
+    assert_eq!(8, circuit.output_column(0));
+
+    // (x + 3) * y * y
+    let inputs = [BaseElement::new(2), BaseElement::new(5)];
+    let values = circuit.evaluate(&inputs);
+    assert_eq!(BaseElement::new(125), values[circuit.outputs()[0].index()]);
+
+    let row = circuit.build_row(&inputs);
+    let expected = [2, 5, 5, 5, 25, 25, 5, 125, 125].map(BaseElement::new);
+    assert_eq!(expected.to_vec(), row);
+}
+
+#[test]
+#[should_panic(expected = "wire 3 does not belong to the circuit")]
+fn circuit_rejects_foreign_wires() {
+    let mut other = Circuit::<BaseElement>::new();
+    let x = other.input();
+    let y = other.input();
+    let z = other.add(x, y);
+    let foreign = other.mul(z, z);
+
+    let mut circuit = Circuit::<BaseElement>::new();
+    let x = circuit.input();
+    circuit.add(x, foreign);
+}
+
+// CIRCUIT AIR TESTS
+// ================================================================================================
+
+#[test]
+fn circuit_air_constraints() {
+    let circuit = build_circuit();
+    let air = build_air(&circuit, vec![vec![BaseElement::new(125)]]);
+
+    // two multiplication constraints, four operand wiring constraints and one output wiring
+    // constraint
+    let mut expected_degrees = vec![TransitionConstraintDegree::new(2); 2];
+    expected_degrees.resize(7, TransitionConstraintDegree::new(1));
+    let context = air.context();
+    assert_eq!(7, context.num_main_transition_constraints());
+    for (i, degree) in expected_degrees.iter().enumerate() {
+        assert_eq!(degree, context.transition_constraint_degree(i));
+    }
+
+    // a valid row satisfies all constraints
+    let row = circuit.build_row(&[BaseElement::new(2), BaseElement::new(5)]);
+    assert_eq!(vec![BaseElement::ZERO; 7], evaluate_row(&air, row.clone()));
+
+    // an invalid multiplication result is caught by the multiplication constraint
+    let mut invalid = row.clone();
+    invalid[4] += BaseElement::ONE;
+    let result = evaluate_row(&air, invalid);
+    assert_ne!(BaseElement::ZERO, result[0]);
+
+    // an operand which is not connected to its wire is caught by the wiring constraint
+    let mut invalid = row.clone();
+    invalid[2] = BaseElement::new(6);
+    let result = evaluate_row(&air, invalid);
+    assert_ne!(BaseElement::ZERO, result[2]);
+
+    // an output which does not match its wire is caught by the output wiring constraint
+    let mut invalid = row;
+    invalid[8] = BaseElement::new(126);
+    let result = evaluate_row(&air, invalid);
+    assert_eq!(vec![BaseElement::ZERO; 6], result[..6].to_vec());
+    assert_ne!(BaseElement::ZERO, result[6]);
+}
+
+#[test]
+fn circuit_air_assertions() {
+    let circuit = build_circuit();
+    let outputs = vec![vec![BaseElement::new(125)], vec![BaseElement::new(7)]];
+    let air = build_air(&circuit, outputs);
+
+    let assertions = air.get_assertions();
+    assert_eq!(2, assertions.len());
+    assert_eq!((8, 0, BaseElement::new(125)), to_tuple(&assertions[0]));
+    assert_eq!((8, 1, BaseElement::new(7)), to_tuple(&assertions[1]));
+}
+
+#[test]
+fn circuit_inputs_to_elements() {
+    let circuit = build_circuit();
+    let inputs = CircuitInputs {
+        circuit: circuit.clone(),
+        outputs: vec![vec![BaseElement::new(125)]],
+    };
+    let other = CircuitInputs {
+        circuit,
+        outputs: vec![vec![BaseElement::new(126)]],
+    };
+    assert_ne!(inputs.to_elements(), other.to_elements());
+
+    // circuits which differ only in the order of operands are encoded differently
+    let mut circuit = Circuit::<BaseElement>::new();
+    let x = circuit.input();
+    let y = circuit.input();
+    let z = circuit.constant(BaseElement::new(3));
+    let w = circuit.add(z, x);
+    let w = circuit.mul(w, y);
+    let w = circuit.mul(w, y);
+    circuit.output(w);
+    let other = CircuitInputs { circuit, outputs: inputs.outputs.clone() };
+    assert_ne!(inputs.to_elements(), other.to_elements());
+}
+
+#[test]
+#[should_panic(expected = "expected trace width 9, but was 8")]
+fn circuit_air_invalid_trace_width() {
+    let circuit = build_circuit();
+    let pub_inputs = CircuitInputs {
+        circuit,
+        outputs: vec![vec![BaseElement::new(125)]],
+    };
+    CircuitAir::new(TraceInfo::new(8, 8), pub_inputs, build_options());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds a circuit computing (x + 3) * y * y.
+fn build_circuit() -> Circuit<BaseElement> {
+    let mut circuit = Circuit::new();
+    let x = circuit.input();
+    let y = circuit.input();
+    let z = circuit.constant(BaseElement::new(3));
+    let w = circuit.add(x, z);
+    let w = circuit.mul(w, y);
+    let w = circuit.mul(w, y);
+    circuit.output(w);
+    circuit
+}
+
+fn build_air(
+    circuit: &Circuit<BaseElement>,
+    outputs: Vec<Vec<BaseElement>>,
+) -> CircuitAir<BaseElement> {
+    let trace_info = TraceInfo::new(circuit.trace_width(), 8);
+    let pub_inputs = CircuitInputs { circuit: circuit.clone(), outputs };
+    CircuitAir::new(trace_info, pub_inputs, build_options())
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31)
+}
+
+fn evaluate_row(air: &CircuitAir<BaseElement>, row: Vec<BaseElement>) -> Vec<BaseElement> {
+    let next = vec![BaseElement::ZERO; row.len()];
+    let frame = EvaluationFrame::from_rows(row, next);
+    let mut result = vec![BaseElement::ZERO; air.context().num_main_transition_constraints()];
+    air.evaluate_transition(&frame, &[], &mut result);
+    result
+}
+
+fn to_tuple(assertion: &crate::Assertion<BaseElement>) -> (usize, usize, BaseElement) {
+    (assertion.column(), assertion.first_step(), assertion.values()[0])
+}
@@ -5,6 +5,8 @@
 
 use core::fmt;
 
+use utils::DeserializationError;
+
 // ASSERTION ERROR
 // ================================================================================================
 /// Represents an error returned during assertion evaluation.
@@ -44,3 +46,114 @@ impl fmt::Display for AssertionError {
 }
 
 impl core::error::Error for AssertionError {}
+
+// PROOF OPTIONS ERROR
+// ================================================================================================
+/// Represents an error returned when a set of STARK protocol parameters is not valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProofOptionsError {
+    /// This error occurs when the number of queries is zero.
+    NoQueries,
+    /// This error occurs when the number of queries is greater than the maximum number of
+    /// queries which can be encoded in proof options.
+    TooManyQueries { max: usize, got: usize },
+    /// This error occurs when the blowup factor is not a power of two, or is outside of the
+    /// range of supported blowup factors.
+    InvalidBlowupFactor { got: usize },
+    /// This error occurs when the grinding factor is greater than the maximum supported grinding
+    /// factor.
+    GrindingFactorTooLarge { max: u32, got: u32 },
+    /// This error occurs when the FRI folding factor is not a power of two, or is outside of the
+    /// range of supported folding factors.
+    InvalidFriFoldingFactor { got: usize },
+    /// This error occurs when the maximum degree of the FRI remainder polynomial is not one less
+    /// than a power of two, or is greater than the maximum supported degree.
+    InvalidFriRemainderMaxDegree { got: usize },
+    /// This error occurs when the conjectured security level implied by the number of queries,
+    /// the blowup factor, and the grinding factor is below the minimum security level.
+    InsufficientSecurity { min: u32, got: u32 },
+    /// This error occurs when the field extension of the specified degree is not supported by
+    /// the base field of the computation.
+    IncompatibleFieldExtension { degree: u32 },
+    /// This error occurs when the number of trace partitions is zero or greater than 16.
+    InvalidNumPartitions { got: usize },
+    /// This error occurs when the hash rate used to compute partition sizes is zero or greater
+    /// than 256.
+    InvalidHashRate { got: usize },
+}
+
+impl fmt::Display for ProofOptionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoQueries => {
+                write!(f, "number of queries must be greater than 0")
+            },
+            Self::TooManyQueries { max, got } => {
+                write!(f, "number of queries cannot be greater than {max}, but was {got}")
+            },
+            Self::InvalidBlowupFactor { got } => {
+                write!(f, "blowup factor must be a power of two between 2 and 128, but was {got}")
+            },
+            Self::GrindingFactorTooLarge { max, got } => {
+                write!(f, "grinding factor cannot be greater than {max}, but was {got}")
+            },
+            Self::InvalidFriFoldingFactor { got } => {
+                write!(
+                    f,
+                    "FRI folding factor must be a power of two between 2 and 16, but was {got}"
+                )
+            },
+            Self::InvalidFriRemainderMaxDegree { got } => {
+                write!(f, "FRI polynomial remainder degree must be one less than a power of two and at most 255, but was {got}")
+            },
+            Self::InsufficientSecurity { min, got } => {
+                write!(f, "conjectured security of proof options must be at least {min} bits, but was {got} bits")
+            },
+            Self::IncompatibleFieldExtension { degree } => {
+                write!(f, "field extension of degree {degree} is not supported by the base field")
+            },
+            Self::InvalidNumPartitions { got } => {
+                write!(f, "number of partitions must be between 1 and 16, but was {got}")
+            },
+            Self::InvalidHashRate { got } => {
+                write!(f, "hash rate must be between 1 and 256, but was {got}")
+            },
+        }
+    }
+}
+
+impl core::error::Error for ProofOptionsError {}
+
+// PROOF DESERIALIZE ERROR
+// ================================================================================================
+/// Represents an error returned when a proof could not be deserialized from a sequence of bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProofDeserializeError {
+    /// This error occurs when the proof was serialized using a proof format version which is not
+    /// supported by this library.
+    UnsupportedVersion(u8),
+    /// This error occurs when the proof bytes could not be parsed into a valid proof of a
+    /// supported proof format version.
+    InvalidProof(DeserializationError),
+}
+
+impl fmt::Display for ProofDeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => {
+                write!(f, "proof format version {version} is not supported")
+            },
+            Self::InvalidProof(err) => {
+                write!(f, "failed to deserialize proof: {err}")
+            },
+        }
+    }
+}
+
+impl From<DeserializationError> for ProofDeserializeError {
+    fn from(err: DeserializationError) -> Self {
+        Self::InvalidProof(err)
+    }
+}
+
+impl core::error::Error for ProofDeserializeError {}
@@ -0,0 +1,16 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Conversions of constraint systems produced by other proving toolchains into AIR.
+//!
+//! Currently, only rank-1 constraint systems ([R1csInstance]) are supported. These are produced
+//! by many existing toolchains (e.g., Circom, Bellman, or Arkworks), and can be converted into
+//! [R1csAir] via [r1cs_to_air()].
+
+mod r1cs;
+pub use r1cs::{r1cs_to_air, R1csAir, R1csConstraint, R1csInputs, R1csInstance};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,358 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::{ExtensibleField, FieldElement, StarkField, ToElements};
+
+use crate::{
+    circuit_compiler::LinearCombination, Air, AirContext, Assertion, EvaluationFrame, ProofOptions,
+    TraceInfo, TransitionConstraintDegree,
+};
+
+// R1CS INSTANCE
+// ================================================================================================
+
+/// A single constraint of a rank-1 constraint system.
+///
+/// The constraint is satisfied by an assignment `z` if `<a, z> * <b, z> = <c, z>`, where each of
+/// `a`, `b`, and `c` is a sparse list of `(variable index, coefficient)` pairs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct R1csConstraint<B: StarkField> {
+    pub a: Vec<(usize, B)>,
+    pub b: Vec<(usize, B)>,
+    pub c: Vec<(usize, B)>,
+}
+
+/// A rank-1 constraint system (R1CS).
+///
+/// Variables of the system are laid out following the usual convention: variable 0 is always
+/// equal to one, it is followed by `num_public` public variables, and the remaining variables are
+/// private. An assignment of all variables (including the leading one) satisfying all constraints
+/// is the witness of the system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct R1csInstance<B: StarkField> {
+    num_variables: usize,
+    num_public: usize,
+    constraints: Vec<R1csConstraint<B>>,
+}
+
+impl<B: StarkField> R1csInstance<B> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new constraint system without any constraints over the specified number of
+    /// variables (including the constant variable 0).
+    ///
+    /// # Panics
+    /// Panics if the system has no variables other than the constant, or if the number of public
+    /// variables is not smaller than the number of variables.
+    pub fn new(num_variables: usize, num_public: usize) -> Self {
+        assert!(
+            num_variables > 1,
+            "constraint system must have at least one non-constant variable"
+        );
+        assert!(
+            num_public < num_variables,
+            "number of public variables must be smaller than {num_variables}, but was {num_public}"
+        );
+        Self {
+            num_variables,
+            num_public,
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Adds the constraint `<a, z> * <b, z> = <c, z>` to this system.
+    ///
+    /// # Panics
+    /// Panics if any of the variable indexes is out of bounds, or if the constraint does not
+    /// depend on any of the non-constant variables.
+    pub fn add_constraint(&mut self, a: Vec<(usize, B)>, b: Vec<(usize, B)>, c: Vec<(usize, B)>) {
+        for &(variable, _) in a.iter().chain(b.iter()).chain(c.iter()) {
+            assert!(
+                variable < self.num_variables,
+                "variable {variable} is out of bounds for a system with {} variables",
+                self.num_variables
+            );
+        }
+        let constraint = R1csConstraint { a, b, c };
+        let [a, b, c] = to_combinations(&constraint);
+        assert!(
+            !(a.is_constant() && b.is_constant() && c.is_constant()),
+            "constraint {} does not depend on any variables",
+            self.constraints.len()
+        );
+        self.constraints.push(constraint);
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of variables in this system (including the constant variable 0).
+    pub fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    /// Returns the number of public variables in this system.
+    pub fn num_public(&self) -> usize {
+        self.num_public
+    }
+
+    /// Returns the constraints of this system.
+    pub fn constraints(&self) -> &[R1csConstraint<B>] {
+        &self.constraints
+    }
+
+    /// Returns the number of columns in a row of the execution trace of this system; every
+    /// variable except the constant one is assigned a column.
+    pub fn trace_width(&self) -> usize {
+        self.num_variables - 1
+    }
+
+    // WITNESS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the specified assignment of all variables satisfies all constraints of
+    /// this system.
+    ///
+    /// # Panics
+    /// Panics if the number of values in the assignment is not equal to the number of variables.
+    pub fn is_satisfied(&self, assignment: &[B]) -> bool {
+        assert_eq!(
+            self.num_variables,
+            assignment.len(),
+            "expected {} variables in the assignment, but received {}",
+            self.num_variables,
+            assignment.len()
+        );
+        let dot = |lc: &[(usize, B)]| {
+            lc.iter()
+                .fold(B::ZERO, |acc, &(variable, coeff)| acc + coeff * assignment[variable])
+        };
+        assignment[0] == B::ONE
+            && self.constraints.iter().all(|c| dot(&c.a) * dot(&c.b) == dot(&c.c))
+    }
+
+    /// Returns the specified assignment of all variables laid out as a row of the execution
+    /// trace; this omits the leading constant variable.
+    ///
+    /// The last row of the execution trace is exempt from all constraints; it should be filled
+    /// with values which do not satisfy the constraints (e.g., random values), as otherwise the
+    /// degrees of the constraints may be lower than the degrees expected by the prover.
+    ///
+    /// # Panics
+    /// Panics if the number of values in the assignment is not equal to the number of variables.
+    pub fn build_row(&self, assignment: &[B]) -> Vec<B> {
+        assert_eq!(
+            self.num_variables,
+            assignment.len(),
+            "expected {} variables in the assignment, but received {}",
+            self.num_variables,
+            assignment.len()
+        );
+        assignment[1..].to_vec()
+    }
+}
+
+// R1CS PUBLIC INPUTS
+// ================================================================================================
+
+/// Public inputs of [R1csAir].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct R1csInputs<B: StarkField> {
+    /// The constraint system satisfied by the assignment in every row of the execution trace.
+    pub r1cs: R1csInstance<B>,
+    /// Values of the public variables of the assignments in the first `public_values.len()`
+    /// rows of the trace.
+    pub public_values: Vec<Vec<B>>,
+}
+
+impl<B: StarkField> ToElements<B> for R1csInputs<B> {
+    fn to_elements(&self) -> Vec<B> {
+        let r1cs = &self.r1cs;
+        let mut result = vec![
+            B::from_u64(r1cs.num_variables as u64),
+            B::from_u64(r1cs.num_public as u64),
+            B::from_u64(r1cs.constraints.len() as u64),
+        ];
+        for constraint in r1cs.constraints.iter() {
+            for lc in [&constraint.a, &constraint.b, &constraint.c] {
+                result.push(B::from_u64(lc.len() as u64));
+                for &(variable, coeff) in lc.iter() {
+                    result.extend([B::from_u64(variable as u64), coeff]);
+                }
+            }
+        }
+        result.push(B::from_u64(self.public_values.len() as u64));
+        result.extend(self.public_values.iter().flatten().copied());
+        result
+    }
+}
+
+// R1CS AIR
+// ================================================================================================
+
+/// AIR for many independent assignments satisfying a rank-1 constraint system.
+///
+/// Every row of the execution trace holds a single assignment of all non-constant variables of
+/// the system (see [R1csInstance::build_row()]), and every constraint of the system becomes one
+/// transition constraint evaluated against the current row. The degree of a transition
+/// constraint is 2 if both `a` and `b` depend on non-constant variables, and 1 otherwise. The
+/// last row of the trace is exempt from all constraints.
+///
+/// Public inputs of the AIR consist of the constraint system together with the values of the
+/// public variables in the first rows of the trace.
+pub struct R1csAir<B: StarkField> {
+    context: AirContext<B>,
+    r1cs: R1csInstance<B>,
+    public_values: Vec<Vec<B>>,
+    constraints: Vec<[LinearCombination<B>; 3]>,
+}
+
+impl<B: StarkField> R1csAir<B> {
+    /// Returns the constraint system this AIR was converted from.
+    pub fn r1cs(&self) -> &R1csInstance<B> {
+        &self.r1cs
+    }
+}
+
+impl<B> Air for R1csAir<B>
+where
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3>,
+{
+    type BaseField = B;
+    type PublicInputs = R1csInputs<B>;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    /// Converts the constraint system specified in the public inputs into AIR.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The constraint system does not have any constraints or public variables.
+    /// * Width of the trace does not match the width of the constraint system trace.
+    /// * No public values are provided, or public values are provided for the last row of the
+    ///   trace.
+    /// * The number of values in any of the public value lists does not match the number of
+    ///   public variables of the system.
+    fn new(trace_info: TraceInfo, pub_inputs: R1csInputs<B>, options: ProofOptions) -> Self {
+        let R1csInputs { r1cs, public_values } = pub_inputs;
+        assert!(
+            !r1cs.constraints.is_empty(),
+            "constraint system must have at least one constraint"
+        );
+        assert!(r1cs.num_public > 0, "constraint system must have at least one public variable");
+        assert_eq!(
+            r1cs.trace_width(),
+            trace_info.main_trace_width(),
+            "expected trace width {}, but was {}",
+            r1cs.trace_width(),
+            trace_info.main_trace_width()
+        );
+        assert!(
+            !public_values.is_empty(),
+            "public values of at least one assignment are required"
+        );
+        assert!(
+            public_values.len() < trace_info.length(),
+            "public values of at most {} assignments can be specified, but received {}",
+            trace_info.length() - 1,
+            public_values.len()
+        );
+        for values in public_values.iter() {
+            assert_eq!(
+                r1cs.num_public,
+                values.len(),
+                "expected {} public values per assignment, but received {}",
+                r1cs.num_public,
+                values.len()
+            );
+        }
+
+        let constraints = r1cs.constraints.iter().map(to_combinations).collect::<Vec<_>>();
+        let degrees = constraints
+            .iter()
+            .map(|[a, b, _]| {
+                let degree = usize::from(!a.is_constant()) + usize::from(!b.is_constant());
+                TransitionConstraintDegree::new(degree.max(1))
+            })
+            .collect();
+
+        let num_assertions = public_values.len() * r1cs.num_public;
+        let context = AirContext::new(trace_info, degrees, num_assertions, options);
+
+        R1csAir {
+            context,
+            r1cs,
+            public_values,
+            constraints,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let row = frame.current();
+        for ([a, b, c], result) in self.constraints.iter().zip(result.iter_mut()) {
+            *result = a.evaluate(row) * b.evaluate(row) - c.evaluate(row);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let mut assertions = Vec::new();
+        for (step, values) in self.public_values.iter().enumerate() {
+            // public variables 1..=num_public are located in columns 0..num_public
+            for (column, &value) in values.iter().enumerate() {
+                assertions.push(Assertion::single(column, step, value));
+            }
+        }
+        assertions
+    }
+}
+
+// CONVERSION
+// ================================================================================================
+
+/// Converts the specified rank-1 constraint system into AIR.
+///
+/// This is equivalent to instantiating [R1csAir] via [Air::new()] with public inputs consisting
+/// of the constraint system and the specified public values; see [R1csAir] for details.
+pub fn r1cs_to_air<B>(
+    r1cs: &R1csInstance<B>,
+    public_values: Vec<Vec<B>>,
+    trace_info: TraceInfo,
+    options: ProofOptions,
+) -> R1csAir<B>
+where
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3>,
+{
+    let pub_inputs = R1csInputs { r1cs: r1cs.clone(), public_values };
+    R1csAir::new(trace_info, pub_inputs, options)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts the `a`, `b`, and `c` vectors of the constraint into linear combinations of trace
+/// columns; variable 0 becomes the constant term, and variable i is located in column i - 1.
+fn to_combinations<B: StarkField>(constraint: &R1csConstraint<B>) -> [LinearCombination<B>; 3] {
+    [&constraint.a, &constraint.b, &constraint.c].map(|lc| {
+        lc.iter().fold(LinearCombination::constant(B::ZERO), |acc, &(variable, coeff)| {
+            let term = match variable {
+                0 => LinearCombination::constant(coeff),
+                _ => LinearCombination::term(variable - 1, coeff),
+            };
+            acc.add(&term)
+        })
+    })
+}
@@ -0,0 +1,164 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::{fields::f64::BaseElement, FieldElement, ToElements};
+
+use super::{r1cs_to_air, R1csAir, R1csInputs, R1csInstance};
+use crate::{
+    Air, EvaluationFrame, FieldExtension, ProofOptions, TraceInfo, TransitionConstraintDegree,
+};
+
+// R1CS INSTANCE TESTS
+// ================================================================================================
+
+#[test]
+fn r1cs_is_satisfied() {
+    let r1cs = build_r1cs();
+    assert_eq!(6, r1cs.num_variables());
+    assert_eq!(1, r1cs.num_public());
+    assert_eq!(4, r1cs.constraints().len());
+    assert_eq!(5, r1cs.trace_width());
+
+    let assignment = build_assignment(3);
+    assert_eq!(BaseElement::new(35), assignment[1]);
+    assert!(r1cs.is_satisfied(&assignment));
+    assert_eq!(assignment[1..].to_vec(), r1cs.build_row(&assignment));
+
+    // an assignment with a wrong intermediate value or constant does not satisfy the system
+    let mut invalid = assignment.clone();
+    invalid[3] += BaseElement::ONE;
+    assert!(!r1cs.is_satisfied(&invalid));
+
+    let mut invalid = assignment;
+    invalid[0] = BaseElement::new(2);
+    assert!(!r1cs.is_satisfied(&invalid));
+}
+
+#[test]
+#[should_panic(expected = "variable 6 is out of bounds for a system with 6 variables")]
+fn r1cs_invalid_variable() {
+    let mut r1cs = build_r1cs();
+    r1cs.add_constraint(vec![(6, BaseElement::ONE)], vec![(0, BaseElement::ONE)], vec![]);
+}
+
+#[test]
+#[should_panic(expected = "constraint 4 does not depend on any variables")]
+fn r1cs_constant_constraint() {
+    let mut r1cs = build_r1cs();
+    r1cs.add_constraint(vec![(0, BaseElement::ONE)], vec![(0, BaseElement::ONE)], vec![]);
+}
+
+// R1CS AIR TESTS
+// ================================================================================================
+
+#[test]
+fn r1cs_air_constraints() {
+    let r1cs = build_r1cs();
+    let air = build_air(&r1cs, vec![vec![BaseElement::new(35)]]);
+
+    // constraints with a constant `b` vector are linear
+    let context = air.context();
+    assert_eq!(4, context.num_main_transition_constraints());
+    for (i, degree) in [2, 2, 1, 1].into_iter().enumerate() {
+        let expected = TransitionConstraintDegree::new(degree);
+        assert_eq!(&expected, context.transition_constraint_degree(i));
+    }
+
+    // a valid assignment satisfies all constraints
+    let row = r1cs.build_row(&build_assignment(3));
+    assert_eq!(vec![BaseElement::ZERO; 4], evaluate_row(&air, row.clone()));
+
+    // each constraint catches a violation of its own equation
+    for (column, constraint) in [(2, 0), (3, 1), (4, 2), (0, 3)] {
+        let mut invalid = row.clone();
+        invalid[column] += BaseElement::ONE;
+        let result = evaluate_row(&air, invalid);
+        assert_ne!(BaseElement::ZERO, result[constraint]);
+    }
+}
+
+#[test]
+fn r1cs_air_assertions() {
+    let r1cs = build_r1cs();
+    let public_values = vec![vec![BaseElement::new(35)], vec![BaseElement::new(15)]];
+    let air = build_air(&r1cs, public_values);
+
+    let assertions = air.get_assertions();
+    assert_eq!(2, assertions.len());
+    assert_eq!((0, 0, BaseElement::new(35)), to_tuple(&assertions[0]));
+    assert_eq!((0, 1, BaseElement::new(15)), to_tuple(&assertions[1]));
+}
+
+#[test]
+fn r1cs_inputs_to_elements() {
+    let inputs = R1csInputs {
+        r1cs: build_r1cs(),
+        public_values: vec![vec![BaseElement::new(35)]],
+    };
+
+    let mut other = inputs.clone();
+    other.public_values[0][0] = BaseElement::new(36);
+    assert_ne!(inputs.to_elements(), other.to_elements());
+
+    let mut other = inputs.clone();
+    other
+        .r1cs
+        .add_constraint(vec![(2, BaseElement::ONE)], vec![(0, BaseElement::ONE)], vec![]);
+    assert_ne!(inputs.to_elements(), other.to_elements());
+}
+
+#[test]
+#[should_panic(expected = "expected 1 public values per assignment, but received 2")]
+fn r1cs_air_invalid_public_values() {
+    let r1cs = build_r1cs();
+    build_air(&r1cs, vec![vec![BaseElement::new(35), BaseElement::new(3)]]);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds a constraint system for x^3 + x + 5 = out over variables (1, out, x, sym1, y, sym2).
+fn build_r1cs() -> R1csInstance<BaseElement> {
+    let one = BaseElement::ONE;
+    let mut r1cs = R1csInstance::new(6, 1);
+    // x * x = sym1
+    r1cs.add_constraint(vec![(2, one)], vec![(2, one)], vec![(3, one)]);
+    // sym1 * x = y
+    r1cs.add_constraint(vec![(3, one)], vec![(2, one)], vec![(4, one)]);
+    // (y + x) * 1 = sym2
+    r1cs.add_constraint(vec![(4, one), (2, one)], vec![(0, one)], vec![(5, one)]);
+    // (sym2 + 5) * 1 = out
+    r1cs.add_constraint(vec![(5, one), (0, BaseElement::new(5))], vec![(0, one)], vec![(1, one)]);
+    r1cs
+}
+
+fn build_assignment(x: u64) -> Vec<BaseElement> {
+    [1, x.pow(3) + x + 5, x, x * x, x.pow(3), x.pow(3) + x]
+        .map(BaseElement::new)
+        .to_vec()
+}
+
+fn build_air(
+    r1cs: &R1csInstance<BaseElement>,
+    public_values: Vec<Vec<BaseElement>>,
+) -> R1csAir<BaseElement> {
+    let trace_info = TraceInfo::new(r1cs.trace_width(), 8);
+    let options = ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31);
+    r1cs_to_air(r1cs, public_values, trace_info, options)
+}
+
+fn evaluate_row(air: &R1csAir<BaseElement>, row: Vec<BaseElement>) -> Vec<BaseElement> {
+    let next = vec![BaseElement::ZERO; row.len()];
+    let frame = EvaluationFrame::from_rows(row, next);
+    let mut result = vec![BaseElement::ZERO; air.context().num_main_transition_constraints()];
+    air.evaluate_transition(&frame, &[], &mut result);
+    result
+}
+
+fn to_tuple(assertion: &crate::Assertion<BaseElement>) -> (usize, usize, BaseElement) {
+    (assertion.column(), assertion.first_step(), assertion.values()[0])
+}
@@ -0,0 +1,31 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Domain separation labels for data absorbed into the Fiat-Shamir transcript of a STARK proof.
+//!
+//! Both the prover and the verifier reseed the public coin via
+//! [RandomCoin::reseed_with_label()](crypto::RandomCoin::reseed_with_label()) using these labels.
+//! Every label carries a version suffix; changing the way a piece of data is absorbed into the
+//! transcript requires bumping the version of its label (and the proof format version).
+//!
+//! Labels for FRI commitments are defined in the FRI crate (see [fri::labels]).
+
+/// Label for the hash of the application context data a proof is bound to.
+pub const CONTEXT_DATA: &[u8] = b"winterfell:context-data:v1";
+
+/// Label for the commitment to the main segment of the execution trace.
+pub const MAIN_TRACE_COMMITMENT: &[u8] = b"winterfell:main-trace-commit:v1";
+
+/// Label for the commitment to the auxiliary segment of the execution trace.
+pub const AUX_TRACE_COMMITMENT: &[u8] = b"winterfell:aux-trace-commit:v1";
+
+/// Label for the commitment to the evaluations of the constraint composition polynomial.
+pub const CONSTRAINT_COMMITMENT: &[u8] = b"winterfell:constraint-commit:v1";
+
+/// Label for the hash of the out-of-domain trace evaluation frame.
+pub const OOD_TRACE_STATES: &[u8] = b"winterfell:ood-trace-states:v1";
+
+/// Label for the hash of the out-of-domain evaluations of constraint composition columns.
+pub const OOD_CONSTRAINT_EVALUATIONS: &[u8] = b"winterfell:ood-constraint-evals:v1";
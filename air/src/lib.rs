@@ -32,21 +32,39 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod circuit_compiler;
+pub mod interop;
+pub mod labels;
 pub mod proof;
 
 mod errors;
-pub use errors::AssertionError;
+pub use errors::{AssertionError, ProofDeserializeError, ProofOptionsError};
 
 mod options;
+pub use crypto::PowScheme;
+pub use fri::FriFoldingFactor;
 pub use options::{FieldExtension, PartitionOptions, ProofOptions};
 
+mod optimizer;
+pub use optimizer::{FieldDescriptor, HashFunction, ProofSizeBreakdown, ProofSizeOptimizer};
+
 mod air;
 pub use air::{
     Air, AirContext, Assertion, AuxRandElements, BoundaryConstraint, BoundaryConstraintGroup,
     BoundaryConstraints, ConstraintCompositionCoefficients, ConstraintDivisor,
-    DeepCompositionCoefficients, EvaluationFrame, GkrRandElements, GkrVerifier,
-    LagrangeConstraintsCompositionCoefficients, LagrangeKernelBoundaryConstraint,
-    LagrangeKernelConstraints, LagrangeKernelEvaluationFrame, LagrangeKernelRandElements,
-    LagrangeKernelTransitionConstraints, TraceInfo, TransitionConstraintDegree,
-    TransitionConstraints,
+    ConstraintExpression, ConstraintType, ConstraintViolation, DeepCompositionCoefficients,
+    EvaluationFrame, GkrRandElements, GkrVerifier, LagrangeConstraintsCompositionCoefficients,
+    LagrangeKernelBoundaryConstraint, LagrangeKernelConstraints, LagrangeKernelEvaluationFrame,
+    LagrangeKernelRandElements, LagrangeKernelTransitionConstraints, TraceInfo,
+    TransitionConstraintDegree, TransitionConstraints, TransitionExemption,
 };
+
+#[doc(hidden)]
+pub mod __private {
+    pub use math::FieldElement;
+
+    pub use crate::air::ExprDegree;
+}
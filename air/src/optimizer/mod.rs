@@ -0,0 +1,417 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use crypto::{hashers, Hasher};
+use fri::FriOptions;
+use math::{fields::f64::BaseElement, StarkField};
+use utils::Serializable;
+
+use crate::{ProofOptions, TraceInfo};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Blowup factors considered by the optimizer.
+const BLOWUP_FACTORS: [usize; 7] = [2, 4, 8, 16, 32, 64, 128];
+
+/// Largest grinding factor which can be considered by the optimizer.
+const MAX_GRINDING_FACTOR: u32 = 32;
+
+/// Largest grinding factor considered by the optimizer by default; larger grinding factors make
+/// finding the proof-of-work nonce too slow for most applications.
+const DEFAULT_MAX_GRINDING_FACTOR: u32 = 16;
+
+/// Largest number of queries considered by the optimizer.
+const MAX_NUM_QUERIES: usize = 255;
+
+/// Number of rows in the out-of-domain frame.
+const OOD_FRAME_SIZE: usize = 2;
+
+/// Size of the proof-of-work nonce in bytes.
+const POW_NONCE_BYTES: usize = 8;
+
+// HASH FUNCTION
+// ================================================================================================
+
+/// Hash functions available in this library, described for the purposes of proof size
+/// estimation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum HashFunction {
+    /// BLAKE3 hash function with 192-bit output.
+    Blake3_192,
+    /// BLAKE3 hash function with 256-bit output.
+    Blake3_256,
+    /// SHA3 hash function with 256-bit output.
+    Sha3_256,
+    /// Rescue Prime hash function over the 62-bit field with 248-bit output.
+    Rp62_248,
+    /// Rescue Prime hash function over the 64-bit field with 256-bit output.
+    Rp64_256,
+    /// Rescue Prime Optimized hash function over the 64-bit field with 256-bit output using the
+    /// Jive compression mode.
+    RpJive64_256,
+    /// Anemoi hash function over the 64-bit field with 256-bit output using the Jive compression
+    /// mode.
+    AnemoiJive64_256,
+    /// Griffin hash function over the 64-bit field with 256-bit output.
+    Griffin64_256,
+}
+
+impl HashFunction {
+    /// Returns collision resistance of this hash function in bits.
+    pub fn collision_resistance(&self) -> u32 {
+        match self {
+            Self::Blake3_192 => <hashers::Blake3_192<BaseElement> as Hasher>::COLLISION_RESISTANCE,
+            Self::Blake3_256 => <hashers::Blake3_256<BaseElement> as Hasher>::COLLISION_RESISTANCE,
+            Self::Sha3_256 => <hashers::Sha3_256<BaseElement> as Hasher>::COLLISION_RESISTANCE,
+            Self::Rp62_248 => <hashers::Rp62_248 as Hasher>::COLLISION_RESISTANCE,
+            Self::Rp64_256 => <hashers::Rp64_256 as Hasher>::COLLISION_RESISTANCE,
+            Self::RpJive64_256 => <hashers::RpJive64_256 as Hasher>::COLLISION_RESISTANCE,
+            Self::AnemoiJive64_256 => <hashers::AnemoiJive64_256 as Hasher>::COLLISION_RESISTANCE,
+            Self::Griffin64_256 => <hashers::Griffin64_256 as Hasher>::COLLISION_RESISTANCE,
+        }
+    }
+
+    /// Returns the size of a serialized digest of this hash function in bytes.
+    pub fn digest_bytes(&self) -> usize {
+        match self {
+            Self::Blake3_192 => digest_bytes::<hashers::Blake3_192<BaseElement>>(),
+            Self::Blake3_256 => digest_bytes::<hashers::Blake3_256<BaseElement>>(),
+            Self::Sha3_256 => digest_bytes::<hashers::Sha3_256<BaseElement>>(),
+            Self::Rp62_248 => digest_bytes::<hashers::Rp62_248>(),
+            Self::Rp64_256 => digest_bytes::<hashers::Rp64_256>(),
+            Self::RpJive64_256 => digest_bytes::<hashers::RpJive64_256>(),
+            Self::AnemoiJive64_256 => digest_bytes::<hashers::AnemoiJive64_256>(),
+            Self::Griffin64_256 => digest_bytes::<hashers::Griffin64_256>(),
+        }
+    }
+}
+
+// FIELD DESCRIPTOR
+// ================================================================================================
+
+/// Describes the base field of a computation for the purposes of proof size estimation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldDescriptor {
+    /// Number of bits needed to represent the field modulus.
+    pub modulus_bits: u32,
+    /// Number of bytes in a serialized field element.
+    pub element_bytes: usize,
+    /// Largest `n` such that the field contains a multiplicative subgroup of size 2^n.
+    pub two_adicity: u32,
+}
+
+impl FieldDescriptor {
+    /// Returns a descriptor of the field `B`.
+    pub fn of<B: StarkField>() -> Self {
+        Self {
+            modulus_bits: B::MODULUS_BITS,
+            element_bytes: B::ELEMENT_BYTES,
+            two_adicity: B::TWO_ADICITY,
+        }
+    }
+}
+
+// PROOF SIZE BREAKDOWN
+// ================================================================================================
+
+/// Estimated size of a STARK proof split by the parts of the proof the bytes are spent on.
+///
+/// All values are in bytes. Small fixed-size parts of the proof (e.g., the proof context) are
+/// not included.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProofSizeBreakdown {
+    /// Values of all FRI layers at the queried positions and the FRI remainder.
+    pub fri_layers_bytes: usize,
+    /// Commitments to the trace segments, the constraint evaluations, and the FRI layers, and
+    /// authentication paths for all queried positions against these commitments.
+    pub merkle_paths_bytes: usize,
+    /// Values of the trace segments and the constraint evaluations at the queried positions.
+    pub query_values_bytes: usize,
+    /// Out-of-domain trace frame and constraint evaluations.
+    pub ood_frame_bytes: usize,
+    /// Proof-of-work nonce.
+    pub pow_nonce_bytes: usize,
+}
+
+impl ProofSizeBreakdown {
+    /// Returns the total estimated size of the proof in bytes.
+    pub fn total(&self) -> usize {
+        self.fri_layers_bytes
+            + self.merkle_paths_bytes
+            + self.query_values_bytes
+            + self.ood_frame_bytes
+            + self.pow_nonce_bytes
+    }
+}
+
+// PROOF SIZE OPTIMIZER
+// ================================================================================================
+
+/// Searches for proof options which minimize the size of proofs for a computation while meeting
+/// a target security level.
+///
+/// The search goes over blowup factors, grinding factors, and numbers of queries. For each pair
+/// of blowup and grinding factors, the smallest number of queries for which the conjectured
+/// security of the proof (see [ProofOptions::conjectured_security()]) reaches the target is
+/// selected, together with the smallest field extension which does not limit the security.
+/// Proof sizes are estimated using an analytical model of the proof structure (see
+/// [ProofSizeOptimizer::estimate_size()]).
+///
+/// Larger blowup factors and grinding factors make proof generation more expensive, and thus,
+/// instead of a single set of options, the optimizer can also return all Pareto-optimal options
+/// with respect to the proof size, the blowup factor and the grinding factor. The cost of
+/// grinding doubles with every bit, and thus, only grinding factors up to a cap (16 by default,
+/// see [ProofSizeOptimizer::with_max_grinding_factor()]) are considered.
+#[derive(Debug, Clone)]
+pub struct ProofSizeOptimizer {
+    target_security_bits: u32,
+    hash_fn: HashFunction,
+    field: FieldDescriptor,
+    trace_info: TraceInfo,
+    num_composition_columns: usize,
+    fri_folding_factor: usize,
+    fri_remainder_max_degree: usize,
+    max_grinding_factor: u32,
+}
+
+impl ProofSizeOptimizer {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new optimizer for proofs of computations with the execution trace described by
+    /// `trace_info` over the specified base field, using the specified hash function.
+    ///
+    /// By default, the constraint composition polynomial is assumed to consist of a single
+    /// column, FRI is assumed to use folding factor 8 and remainder of degree at most 31, and
+    /// grinding factors of at most 16 bits are considered.
+    pub fn new(
+        target_security_bits: u32,
+        hash_fn: HashFunction,
+        field: FieldDescriptor,
+        trace_info: TraceInfo,
+    ) -> Self {
+        Self {
+            target_security_bits,
+            hash_fn,
+            field,
+            trace_info,
+            num_composition_columns: 1,
+            fri_folding_factor: 8,
+            fri_remainder_max_degree: 31,
+            max_grinding_factor: DEFAULT_MAX_GRINDING_FACTOR,
+        }
+    }
+
+    /// Sets the number of columns of the constraint composition polynomial; this is one less than
+    /// the highest constraint degree of the computation.
+    pub fn with_composition_columns(mut self, num_columns: usize) -> Self {
+        self.num_composition_columns = num_columns;
+        self
+    }
+
+    /// Sets the FRI folding factor and the maximum degree of the FRI remainder.
+    pub fn with_fri_options(mut self, folding_factor: usize, remainder_max_degree: usize) -> Self {
+        self.fri_folding_factor = folding_factor;
+        self.fri_remainder_max_degree = remainder_max_degree;
+        self
+    }
+
+    /// Sets the largest grinding factor considered by the optimizer.
+    ///
+    /// # Panics
+    /// Panics if `grinding_factor` is greater than 32.
+    pub fn with_max_grinding_factor(mut self, grinding_factor: u32) -> Self {
+        assert!(
+            grinding_factor <= MAX_GRINDING_FACTOR,
+            "grinding factor cannot be greater than {MAX_GRINDING_FACTOR}, but was {grinding_factor}"
+        );
+        self.max_grinding_factor = grinding_factor;
+        self
+    }
+
+    // OPTIMIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns proof options yielding the smallest proofs which meet the target security level,
+    /// together with the estimated size breakdown of such proofs.
+    ///
+    /// When several options yield proofs of the same size, the options with the smallest blowup
+    /// factor and then the smallest grinding factor are returned. Returns None if the target
+    /// security level cannot be reached (e.g., if it exceeds collision resistance of the hash
+    /// function).
+    pub fn minimize_size(&self) -> Option<(ProofOptions, ProofSizeBreakdown)> {
+        self.pareto_front().into_iter().next()
+    }
+
+    /// Returns all proof options which meet the target security level and are Pareto-optimal
+    /// with respect to the proof size, the blowup factor, and the grinding factor, sorted by the
+    /// estimated proof size.
+    ///
+    /// Options are Pareto-optimal if no other options yield proofs which are at most as large
+    /// while having blowup and grinding factors which are at most as large, with at least one of
+    /// the three being strictly smaller.
+    pub fn pareto_front(&self) -> Vec<(ProofOptions, ProofSizeBreakdown)> {
+        let candidates: Vec<_> = BLOWUP_FACTORS
+            .into_iter()
+            .flat_map(|blowup_factor| {
+                (0..=self.max_grinding_factor).filter_map(move |grinding_factor| {
+                    self.min_queries_options(blowup_factor, grinding_factor)
+                })
+            })
+            .map(|options| {
+                let breakdown = self.estimate_size(&options);
+                (options, breakdown)
+            })
+            .collect();
+
+        let mut result: Vec<_> = candidates
+            .iter()
+            .filter(|(options, breakdown)| {
+                !candidates.iter().any(|(other, other_breakdown)| {
+                    dominates(other, other_breakdown, options, breakdown)
+                })
+            })
+            .cloned()
+            .collect();
+        result.sort_by_key(|(options, breakdown)| {
+            (breakdown.total(), options.blowup_factor(), options.grinding_factor())
+        });
+        result
+    }
+
+    // SIZE ESTIMATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the estimated size of a proof generated with the specified options.
+    ///
+    /// The estimate assumes that all queried positions are distinct, and that openings of
+    /// `q` positions in a Merkle tree of depth `d` require about `q * (d - log2(q))` nodes.
+    pub fn estimate_size(&self, options: &ProofOptions) -> ProofSizeBreakdown {
+        let digest_bytes = self.hash_fn.digest_bytes();
+        let base_bytes = self.field.element_bytes;
+        let ext_bytes = base_bytes * options.field_extension().degree() as usize;
+        let num_queries = options.num_queries();
+        let lde_domain_size = self.trace_info.length() * options.blowup_factor();
+
+        let main_width = self.trace_info.main_trace_width();
+        let aux_width = self.trace_info.aux_segment_width();
+        let num_segments = self.trace_info.num_segments();
+
+        // trace segments and constraint evaluations are committed to in trees with one leaf per
+        // row of the LDE domain
+        let num_trees = num_segments + 1;
+        let mut merkle_paths_bytes =
+            num_trees * digest_bytes * (1 + num_path_nodes(num_queries, lde_domain_size));
+        let query_values_bytes = num_queries
+            * (main_width * base_bytes + (aux_width + self.num_composition_columns) * ext_bytes);
+
+        // each FRI layer is committed to in a tree with one leaf per coset of `folding_factor`
+        // elements; the remainder is committed to as well, and is sent in coefficient form
+        let fri_options = FriOptions::new(
+            options.blowup_factor(),
+            self.fri_folding_factor,
+            self.fri_remainder_max_degree,
+        );
+        let mut fri_layers_bytes = 0;
+        let mut domain_size = lde_domain_size;
+        for _ in 0..fri_options.num_fri_layers(lde_domain_size) {
+            let num_leaves = domain_size / self.fri_folding_factor;
+            let num_layer_queries = num_queries.min(num_leaves);
+            fri_layers_bytes += num_layer_queries * self.fri_folding_factor * ext_bytes;
+            merkle_paths_bytes +=
+                digest_bytes * (1 + num_path_nodes(num_layer_queries, num_leaves));
+            domain_size = num_leaves;
+        }
+        fri_layers_bytes += (domain_size / options.blowup_factor()) * ext_bytes;
+        merkle_paths_bytes += digest_bytes;
+
+        let ood_frame_bytes =
+            (OOD_FRAME_SIZE * (main_width + aux_width) + self.num_composition_columns) * ext_bytes;
+
+        ProofSizeBreakdown {
+            fri_layers_bytes,
+            merkle_paths_bytes,
+            query_values_bytes,
+            ood_frame_bytes,
+            pow_nonce_bytes: POW_NONCE_BYTES,
+        }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns options with the specified blowup and grinding factors and the smallest number of
+    /// queries which meet the target security level, or None if no such options exist.
+    fn min_queries_options(
+        &self,
+        blowup_factor: usize,
+        grinding_factor: u32,
+    ) -> Option<ProofOptions> {
+        let trace_length = self.trace_info.length();
+        let lde_domain_size = trace_length * blowup_factor;
+        if lde_domain_size.ilog2() > self.field.two_adicity {
+            return None;
+        }
+
+        let field_extension = ProofOptions::required_extension_degree(
+            self.field.modulus_bits,
+            self.target_security_bits,
+            lde_domain_size,
+        );
+        (1..=MAX_NUM_QUERIES).find_map(|num_queries| {
+            let options = ProofOptions::try_new(
+                num_queries,
+                blowup_factor,
+                grinding_factor,
+                field_extension,
+                self.fri_folding_factor,
+                self.fri_remainder_max_degree,
+            )
+            .ok()?;
+            let security = options.conjectured_security(
+                self.field.modulus_bits,
+                trace_length,
+                self.hash_fn.collision_resistance(),
+            );
+            (security >= self.target_security_bits).then_some(options)
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the size of a serialized digest of the hash function `H`.
+fn digest_bytes<H: Hasher>() -> usize {
+    H::Digest::default().to_bytes().len()
+}
+
+/// Returns the approximate number of nodes needed to open `num_queries` leaves of a Merkle tree
+/// with `num_leaves` leaves; the nodes of the top `log2(num_queries)` levels are mostly computed
+/// by the verifier from the nodes below them.
+fn num_path_nodes(num_queries: usize, num_leaves: usize) -> usize {
+    let depth = num_leaves.ilog2() as usize;
+    num_queries * depth.saturating_sub(num_queries.ilog2() as usize)
+}
+
+/// Returns true if `a` options dominate `b` options with respect to the proof size, the blowup
+/// factor, and the grinding factor.
+fn dominates(
+    a: &ProofOptions,
+    a_breakdown: &ProofSizeBreakdown,
+    b: &ProofOptions,
+    b_breakdown: &ProofSizeBreakdown,
+) -> bool {
+    let a_key = (a_breakdown.total(), a.blowup_factor(), a.grinding_factor());
+    let b_key = (b_breakdown.total(), b.blowup_factor(), b.grinding_factor());
+    a_key.0 <= b_key.0 && a_key.1 <= b_key.1 && a_key.2 <= b_key.2 && a_key != b_key
+}
@@ -0,0 +1,171 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use math::fields::{f128, f64::BaseElement};
+
+use super::{FieldDescriptor, HashFunction, ProofSizeOptimizer};
+use crate::{FieldExtension, ProofOptions, TraceInfo};
+
+// HASH FUNCTION TESTS
+// ================================================================================================
+
+#[test]
+fn hash_function_parameters() {
+    assert_eq!(96, HashFunction::Blake3_192.collision_resistance());
+    assert_eq!(24, HashFunction::Blake3_192.digest_bytes());
+    assert_eq!(128, HashFunction::Sha3_256.collision_resistance());
+    assert_eq!(32, HashFunction::Rp64_256.digest_bytes());
+    assert_eq!(124, HashFunction::Rp62_248.collision_resistance());
+}
+
+// OPTIMIZER TESTS
+// ================================================================================================
+
+#[test]
+fn pareto_front() {
+    let optimizer = build_optimizer(100, HashFunction::Blake3_256);
+    let front = optimizer.pareto_front();
+    assert!(!front.is_empty());
+
+    for (options, breakdown) in front.iter() {
+        // all options meet the target and use the smallest possible number of queries
+        let security = options.conjectured_security(64, 1 << 16, 128);
+        assert!(security >= 100);
+        let fewer_queries = ProofOptions::new(
+            options.num_queries() - 1,
+            options.blowup_factor(),
+            options.grinding_factor(),
+            options.field_extension(),
+            8,
+            31,
+        );
+        assert!(fewer_queries.conjectured_security(64, 1 << 16, 128) < 100);
+
+        assert_eq!(FieldExtension::Quadratic, options.field_extension());
+        assert_eq!(*breakdown, optimizer.estimate_size(options));
+    }
+
+    // options are sorted by proof size, and no point dominates another point
+    assert!(front.windows(2).all(|pair| pair[0].1.total() <= pair[1].1.total()));
+    for (i, (a, _)) in front.iter().enumerate() {
+        for (b, _) in front[i + 1..].iter() {
+            assert!(
+                a.blowup_factor() > b.blowup_factor() || a.grinding_factor() > b.grinding_factor()
+            );
+        }
+    }
+
+    // the smallest options are the first point of the front
+    let (options, breakdown) = optimizer.minimize_size().unwrap();
+    assert_eq!(front[0], (options, breakdown));
+}
+
+#[test]
+fn minimize_size() {
+    let optimizer = build_optimizer(100, HashFunction::Blake3_256);
+    let (options, breakdown) = optimizer.minimize_size().unwrap();
+
+    // grinding replaces queries above the grinding contribution floor
+    assert!(options.grinding_factor() > 0);
+
+    // grinding factors above the default cap are not considered
+    assert!(options.grinding_factor() <= 16);
+
+    // every other combination of parameters meeting the target yields larger proofs
+    for blowup_factor in [2, 4, 8, 16, 32, 64, 128] {
+        for grinding_factor in [0, 8, 16] {
+            if let Some(other) = optimizer.min_queries_options(blowup_factor, grinding_factor) {
+                assert!(optimizer.estimate_size(&other).total() >= breakdown.total());
+            }
+        }
+    }
+
+    assert_eq!(8, breakdown.pow_nonce_bytes);
+    assert!(breakdown.merkle_paths_bytes > breakdown.ood_frame_bytes);
+    assert_eq!(
+        breakdown.fri_layers_bytes
+            + breakdown.merkle_paths_bytes
+            + breakdown.query_values_bytes
+            + breakdown.ood_frame_bytes
+            + breakdown.pow_nonce_bytes,
+        breakdown.total()
+    );
+}
+
+#[test]
+fn max_grinding_factor() {
+    let optimizer = build_optimizer(100, HashFunction::Blake3_256);
+    let (_, breakdown) = optimizer.minimize_size().unwrap();
+
+    // without grinding, more queries are needed, and thus, proofs are larger
+    let no_grinding = optimizer.clone().with_max_grinding_factor(0);
+    let (options, no_grinding_breakdown) = no_grinding.minimize_size().unwrap();
+    assert_eq!(0, options.grinding_factor());
+    assert!(no_grinding_breakdown.total() > breakdown.total());
+    assert!(no_grinding
+        .pareto_front()
+        .iter()
+        .all(|(options, _)| options.grinding_factor() == 0));
+
+    // raising the cap never yields larger proofs
+    let max_grinding = optimizer.with_max_grinding_factor(32);
+    let (_, max_grinding_breakdown) = max_grinding.minimize_size().unwrap();
+    assert!(max_grinding_breakdown.total() <= breakdown.total());
+}
+
+#[test]
+#[should_panic(expected = "grinding factor cannot be greater than 32, but was 33")]
+fn max_grinding_factor_too_large() {
+    build_optimizer(100, HashFunction::Blake3_256).with_max_grinding_factor(33);
+}
+
+#[test]
+fn minimize_size_unreachable_target() {
+    // conjectured security cannot exceed collision resistance of the hash function
+    let optimizer = build_optimizer(100, HashFunction::Blake3_192);
+    assert!(optimizer.minimize_size().is_none());
+    assert!(optimizer.pareto_front().is_empty());
+
+    // a smaller target is reachable with the same hash function
+    let optimizer = build_optimizer(90, HashFunction::Blake3_192);
+    assert!(optimizer.minimize_size().is_some());
+}
+
+#[test]
+fn estimate_size() {
+    let optimizer = build_optimizer(100, HashFunction::Blake3_256);
+    let options = ProofOptions::new(28, 8, 16, FieldExtension::Quadratic, 8, 31);
+    let breakdown = optimizer.estimate_size(&options);
+
+    // 2 rows of 4 main trace columns and 1 composition column, in the quadratic extension
+    assert_eq!((2 * 4 + 1) * 16, breakdown.ood_frame_bytes);
+    // 4 main trace columns in the base field and 1 composition column in the extension
+    assert_eq!(28 * (4 * 8 + 16), breakdown.query_values_bytes);
+
+    // more queries yield larger proofs
+    let more_queries = ProofOptions::new(40, 8, 16, FieldExtension::Quadratic, 8, 31);
+    assert!(optimizer.estimate_size(&more_queries).total() > breakdown.total());
+
+    // a wider field yields larger proofs
+    let optimizer = ProofSizeOptimizer::new(
+        100,
+        HashFunction::Blake3_256,
+        FieldDescriptor::of::<f128::BaseElement>(),
+        TraceInfo::new(4, 1 << 16),
+    );
+    assert!(optimizer.estimate_size(&options).total() > breakdown.total());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_optimizer(target_security_bits: u32, hash_fn: HashFunction) -> ProofSizeOptimizer {
+    ProofSizeOptimizer::new(
+        target_security_bits,
+        hash_fn,
+        FieldDescriptor::of::<BaseElement>(),
+        TraceInfo::new(4, 1 << 16),
+    )
+}
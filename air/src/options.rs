@@ -3,13 +3,16 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 use core::cmp;
 
-use fri::FriOptions;
-use math::{FieldElement, StarkField, ToElements};
+use crypto::PowScheme;
+use fri::{FriFoldingFactor, FriOptions};
+use math::{ExtensibleField, FieldElement, StarkField, ToElements};
 use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
+use crate::ProofOptionsError;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -22,10 +25,19 @@ const MAX_BLOWUP_FACTOR: usize = 128;
 
 const MAX_GRINDING_FACTOR: u32 = 32;
 
-const FRI_MIN_FOLDING_FACTOR: usize = 2;
-const FRI_MAX_FOLDING_FACTOR: usize = 16;
 const FRI_MAX_REMAINDER_DEGREE: usize = 255;
 
+const MIN_CONJECTURED_SECURITY: u32 = 8;
+
+const SECURITY_FLOOR: u32 = 80;
+
+/// Query security level (in bits) starting from which grinding contributes fully to the
+/// conjectured security of a proof.
+const GRINDING_CONTRIBUTION_FLOOR: u32 = 80;
+
+const MAX_NUM_PARTITIONS: usize = 16;
+const MAX_HASH_RATE: usize = 256;
+
 // TYPES AND INTERFACES
 // ================================================================================================
 
@@ -69,7 +81,8 @@ pub enum FieldExtension {
 ///    time decrease the number of queries in such a way that the proofs become smaller.
 /// 4. Grinding factor - higher values increase proof soundness, but also may increase proof
 ///    generation time. More precisely, conjectured proof soundness is bounded by
-///    `num_queries * log2(blowup_factor) + grinding_factor`.
+///    `num_queries * log2(blowup_factor) + grinding_factor`. The proof-of-work function used for
+///    grinding is defined by [PowScheme].
 ///
 /// Another important parameter in defining STARK security level, which is not a part of [ProofOptions]
 /// is the hash function used in the protocol. The soundness of a STARK proof is limited by the
@@ -88,6 +101,7 @@ pub struct ProofOptions {
     num_queries: u8,
     blowup_factor: u8,
     grinding_factor: u8,
+    pow_scheme: PowScheme,
     field_extension: FieldExtension,
     fri_folding_factor: u8,
     fri_remainder_max_degree: u8,
@@ -107,10 +121,36 @@ impl ProofOptions {
     /// have a blowup factor smaller than 2.
     pub const MIN_BLOWUP_FACTOR: usize = MIN_BLOWUP_FACTOR;
 
+    /// Number of bytes in the serialized representation of proof options.
+    ///
+    /// See the [Serializable] implementation of [ProofOptions] for the layout of these bytes.
+    pub const SERIALIZED_SIZE: usize = 8;
+
+    /// Minimum conjectured security level (in bits) of proof options, which is currently set
+    /// to 8.
+    ///
+    /// Conjectured security is estimated as `num_queries * log2(blowup_factor) + grinding_factor`.
+    /// Options below this level (e.g., a single query with blowup factor 2) can be constructed
+    /// from otherwise valid parameters, but a forged proof would be accepted with probability
+    /// greater than 1/256, and so such options are rejected.
+    pub const MIN_CONJECTURED_SECURITY: u32 = MIN_CONJECTURED_SECURITY;
+
+    /// Minimum conjectured security level (in bits) of proofs generated by the prover, which is
+    /// currently set to 80.
+    ///
+    /// Unlike [ProofOptions::MIN_CONJECTURED_SECURITY], this level is checked against the
+    /// conjectured security of a proof for the actual trace length and hash function (see
+    /// [ProofOptions::conjectured_security()]). Provers refuse to generate proofs below this level
+    /// unless insecure proofs are explicitly allowed, and the default acceptable options of the
+    /// verifier require the same level.
+    pub const SECURITY_FLOOR: u32 = SECURITY_FLOOR;
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
     /// Returns a new instance of [ProofOptions] struct constructed from the specified parameters.
     ///
+    /// This is a panicking version of [ProofOptions::try_new()].
+    ///
     /// # Panics
     /// Panics if:
     /// - `num_queries` is zero or greater than 255.
@@ -118,6 +158,7 @@ impl ProofOptions {
     /// - `grinding_factor` is greater than 32.
     /// - `fri_folding_factor` is not 2, 4, 8, or 16.
     /// - `fri_remainder_max_degree` is greater than 255 or is not a power of two minus 1.
+    /// - Conjectured security of the options is smaller than [ProofOptions::MIN_CONJECTURED_SECURITY].
     pub const fn new(
         num_queries: usize,
         blowup_factor: usize,
@@ -126,47 +167,93 @@ impl ProofOptions {
         fri_folding_factor: usize,
         fri_remainder_max_degree: usize,
     ) -> ProofOptions {
-        // TODO: return errors instead of panicking
-        assert!(num_queries > 0, "number of queries must be greater than 0");
-        assert!(num_queries <= MAX_NUM_QUERIES, "number of queries cannot be greater than 255");
+        match Self::try_new(
+            num_queries,
+            blowup_factor,
+            grinding_factor,
+            field_extension,
+            fri_folding_factor,
+            fri_remainder_max_degree,
+        ) {
+            Ok(options) => options,
+            Err(err) => panic!("{}", panic_message(&err)),
+        }
+    }
+
+    /// Returns a new instance of [ProofOptions] struct constructed from the specified parameters.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `num_queries` is zero or greater than 255.
+    /// - `blowup_factor` is smaller than 2, greater than 128, or is not a power of two.
+    /// - `grinding_factor` is greater than 32.
+    /// - `fri_folding_factor` is not 2, 4, 8, or 16.
+    /// - `fri_remainder_max_degree` is greater than 255 or is not a power of two minus 1.
+    /// - Conjectured security of the options is smaller than [ProofOptions::MIN_CONJECTURED_SECURITY].
+    pub const fn try_new(
+        num_queries: usize,
+        blowup_factor: usize,
+        grinding_factor: u32,
+        field_extension: FieldExtension,
+        fri_folding_factor: usize,
+        fri_remainder_max_degree: usize,
+    ) -> Result<ProofOptions, ProofOptionsError> {
+        if num_queries == 0 {
+            return Err(ProofOptionsError::NoQueries);
+        }
+        if num_queries > MAX_NUM_QUERIES {
+            return Err(ProofOptionsError::TooManyQueries {
+                max: MAX_NUM_QUERIES,
+                got: num_queries,
+            });
+        }
 
-        assert!(blowup_factor.is_power_of_two(), "blowup factor must be a power of 2");
-        assert!(blowup_factor >= MIN_BLOWUP_FACTOR, "blowup factor cannot be smaller than 2");
-        assert!(blowup_factor <= MAX_BLOWUP_FACTOR, "blowup factor cannot be greater than 128");
+        if !blowup_factor.is_power_of_two()
+            || blowup_factor < MIN_BLOWUP_FACTOR
+            || blowup_factor > MAX_BLOWUP_FACTOR
+        {
+            return Err(ProofOptionsError::InvalidBlowupFactor { got: blowup_factor });
+        }
 
-        assert!(
-            grinding_factor <= MAX_GRINDING_FACTOR,
-            "grinding factor cannot be greater than 32"
-        );
+        if grinding_factor > MAX_GRINDING_FACTOR {
+            return Err(ProofOptionsError::GrindingFactorTooLarge {
+                max: MAX_GRINDING_FACTOR,
+                got: grinding_factor,
+            });
+        }
 
-        assert!(fri_folding_factor.is_power_of_two(), "FRI folding factor must be a power of 2");
-        assert!(
-            fri_folding_factor >= FRI_MIN_FOLDING_FACTOR,
-            "FRI folding factor cannot be smaller than 2"
-        );
-        assert!(
-            fri_folding_factor <= FRI_MAX_FOLDING_FACTOR,
-            "FRI folding factor cannot be greater than 16"
-        );
+        if FriFoldingFactor::from_usize(fri_folding_factor).is_none() {
+            return Err(ProofOptionsError::InvalidFriFoldingFactor { got: fri_folding_factor });
+        }
 
-        assert!(
-            (fri_remainder_max_degree + 1).is_power_of_two(),
-            "FRI polynomial remainder degree must be one less than a power of two"
-        );
-        assert!(
-            fri_remainder_max_degree <= FRI_MAX_REMAINDER_DEGREE,
-            "FRI polynomial remainder degree cannot be greater than 255"
-        );
+        if !(fri_remainder_max_degree + 1).is_power_of_two()
+            || fri_remainder_max_degree > FRI_MAX_REMAINDER_DEGREE
+        {
+            return Err(ProofOptionsError::InvalidFriRemainderMaxDegree {
+                got: fri_remainder_max_degree,
+            });
+        }
+
+        // num_queries and log2(blowup_factor) are at most 255 and 7 respectively, and so the
+        // conjectured security is guaranteed to fit into a u32
+        let conjectured_security = num_queries as u32 * blowup_factor.ilog2() + grinding_factor;
+        if conjectured_security < MIN_CONJECTURED_SECURITY {
+            return Err(ProofOptionsError::InsufficientSecurity {
+                min: MIN_CONJECTURED_SECURITY,
+                got: conjectured_security,
+            });
+        }
 
-        Self {
+        Ok(Self {
             num_queries: num_queries as u8,
             blowup_factor: blowup_factor as u8,
             grinding_factor: grinding_factor as u8,
+            pow_scheme: PowScheme::Hash,
             field_extension,
             fri_folding_factor: fri_folding_factor as u8,
             fri_remainder_max_degree: fri_remainder_max_degree as u8,
             partition_options: PartitionOptions::new(1, 1),
-        }
+        })
     }
 
     /// Updates the provided [ProofOptions] instance with the specified partition parameters.
@@ -185,6 +272,17 @@ impl ProofOptions {
         self
     }
 
+    /// Updates the provided [ProofOptions] instance with the specified proof-of-work scheme for
+    /// query seed grinding.
+    ///
+    /// By default, [PowScheme::Hash] is used. The scheme must be the same as the
+    /// [SCHEME](crypto::ProofOfWork::SCHEME) of the proof-of-work function the prover and the
+    /// verifier are instantiated with; otherwise, proof generation and verification fail.
+    pub const fn with_pow_scheme(mut self, pow_scheme: PowScheme) -> ProofOptions {
+        self.pow_scheme = pow_scheme;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -218,6 +316,11 @@ impl ProofOptions {
         self.grinding_factor as u32
     }
 
+    /// Returns the proof-of-work scheme used for query seed grinding.
+    pub const fn pow_scheme(&self) -> PowScheme {
+        self.pow_scheme
+    }
+
     /// Specifies whether composition polynomial should be constructed in an extension field
     /// of STARK protocol.
     ///
@@ -235,6 +338,17 @@ impl ProofOptions {
         B::GENERATOR
     }
 
+    /// Returns the factor by which the degree of a polynomial is reduced with each FRI layer.
+    ///
+    /// Larger folding factors result in fewer FRI layers and smaller proofs, but make each layer
+    /// more expensive to build (see [FriFoldingFactor]).
+    pub const fn fri_folding_factor(&self) -> FriFoldingFactor {
+        match FriFoldingFactor::from_usize(self.fri_folding_factor as usize) {
+            Some(folding_factor) => folding_factor,
+            None => panic!("proof options contain an unsupported FRI folding factor"),
+        }
+    }
+
     /// Returns options for FRI protocol instantiated with parameters from this proof options.
     pub fn to_fri_options(&self) -> FriOptions {
         let folding_factor = self.fri_folding_factor as usize;
@@ -246,12 +360,115 @@ impl ProofOptions {
     pub fn partition_options(&self) -> PartitionOptions {
         self.partition_options
     }
+
+    // SECURITY
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of bits of security contributed by the field for proofs generated with
+    /// these options over a base field of `base_field_bits` bits and an LDE domain of
+    /// `lde_domain_size` elements.
+    ///
+    /// This is the size of the field in which the composition polynomial is constructed (i.e.,
+    /// the base field or its extension) less `log2(lde_domain_size)` bits.
+    pub fn field_security(&self, base_field_bits: u32, lde_domain_size: usize) -> u32 {
+        get_field_security(self.field_extension, base_field_bits, lde_domain_size)
+    }
+
+    /// Returns the number of bits of security contributed by the query phase of the protocol for
+    /// proofs generated with these options.
+    ///
+    /// Each query contributes `log2(blowup_factor)` bits, and grinding contributes
+    /// `min(grinding_factor, floor(grinding_factor * q / 80))` bits, where `q` is the security
+    /// provided by the queries alone. That is, grinding is included fully once the queries provide
+    /// at least 80 bits of security, and below this level, its contribution is scaled down in
+    /// proportion to the security of the queries, so that grinding cannot make up for a weak
+    /// query phase.
+    pub fn query_security(&self) -> u32 {
+        let security_per_query = self.blowup_factor().ilog2();
+        let query_security = security_per_query * self.num_queries() as u32;
+        query_security + get_grinding_security(query_security, self.grinding_factor())
+    }
+
+    /// Returns the conjectured security level (in bits) of proofs generated with these options
+    /// for a trace of `trace_length` steps over a base field of `base_field_bits` bits, using a
+    /// hash function with `collision_resistance` bits of collision resistance.
+    ///
+    /// This is the smaller of the field security and the query security (see
+    /// [ProofOptions::field_security()] and [ProofOptions::query_security()]) less one bit,
+    /// capped at the collision resistance of the hash function. The extra bit accounts for the
+    /// soundness errors of the field and of the queries adding up, and thus, it is not subtracted
+    /// when the collision resistance is the limiting factor.
+    pub fn conjectured_security(
+        &self,
+        base_field_bits: u32,
+        trace_length: usize,
+        collision_resistance: u32,
+    ) -> u32 {
+        let lde_domain_size = trace_length.saturating_mul(self.blowup_factor());
+        let field_security = self.field_security(base_field_bits, lde_domain_size);
+        let query_security = self.query_security();
+        cmp::min(cmp::min(field_security, query_security).saturating_sub(1), collision_resistance)
+    }
+
+    /// Returns the smallest field extension for which the field does not limit conjectured
+    /// security of a proof to fewer than `target_security_bits` bits.
+    ///
+    /// The field security is computed as described in [ProofOptions::field_security()] for a base
+    /// field of `base_field_bits` bits and an LDE domain of `lde_domain_size` elements. If even
+    /// the cubic extension is not sufficient to reach the target, [FieldExtension::Cubic] is
+    /// returned.
+    ///
+    /// Note that this does not check whether the returned extension is supported by the base field
+    /// (see [ProofOptions::validate_field_extension()]).
+    pub fn required_extension_degree(
+        base_field_bits: u32,
+        target_security_bits: u32,
+        lde_domain_size: usize,
+    ) -> FieldExtension {
+        // conjectured security is one bit smaller than the security of its weakest component
+        [FieldExtension::None, FieldExtension::Quadratic]
+            .into_iter()
+            .find(|&extension| {
+                get_field_security(extension, base_field_bits, lde_domain_size)
+                    > target_security_bits
+            })
+            .unwrap_or(FieldExtension::Cubic)
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the field extension specified by these options can be used with the base
+    /// field `B`.
+    ///
+    /// # Errors
+    /// Returns an error if `B` does not support the extension of the degree specified by these
+    /// options (e.g., the cubic extension of the 128-bit field).
+    pub fn validate_field_extension<B>(&self) -> Result<(), ProofOptionsError>
+    where
+        B: StarkField + ExtensibleField<2> + ExtensibleField<3>,
+    {
+        let is_supported = match self.field_extension {
+            FieldExtension::None => true,
+            FieldExtension::Quadratic => <B as ExtensibleField<2>>::is_supported(),
+            FieldExtension::Cubic => <B as ExtensibleField<3>>::is_supported(),
+        };
+        if !is_supported {
+            return Err(ProofOptionsError::IncompatibleFieldExtension {
+                degree: self.field_extension.degree(),
+            });
+        }
+        Ok(())
+    }
 }
 
 impl<E: StarkField> ToElements<E> for ProofOptions {
     fn to_elements(&self) -> Vec<E> {
-        // encode field extension and FRI parameters into a single field element
-        let mut buf = self.field_extension as u32;
+        // encode proof-of-work scheme, field extension, and FRI parameters into a single field
+        // element; the default proof-of-work scheme is encoded as 0, and so options using it are
+        // encoded in the same way as before the scheme became configurable
+        let mut buf = self.pow_scheme as u32;
+        buf = (buf << 8) | self.field_extension as u32;
         buf = (buf << 8) | self.fri_folding_factor as u32;
         buf = (buf << 8) | self.fri_remainder_max_degree as u32;
 
@@ -264,35 +481,73 @@ impl<E: StarkField> ToElements<E> for ProofOptions {
     }
 }
 
+/// Proof options are serialized into [ProofOptions::SERIALIZED_SIZE] bytes with the following
+/// layout:
+///
+/// | byte | field                                                      | valid values         |
+/// | ---- | ---------------------------------------------------------- | -------------------- |
+/// | 0    | number of queries                                          | 1 - 255              |
+/// | 1    | blowup factor                                              | 2, 4, 8, ..., 128    |
+/// | 2    | grinding factor                                            | 0 - 32               |
+/// | 3    | field extension (bits 0 - 3), proof-of-work scheme (4 - 7) | 1 - 3, 0 - 1         |
+/// | 4    | FRI folding factor                                         | 2, 4, 8, 16          |
+/// | 5    | FRI remainder max degree                                   | 0, 1, 3, 7, ..., 255 |
+/// | 6    | number of trace partitions                                 | 1 - 16               |
+/// | 7    | hash rate used to compute partition sizes                  | 1 - 255              |
+///
+/// Field extensions and proof-of-work schemes are encoded as the discriminants of the
+/// [FieldExtension] and [PowScheme] enums. In addition to the ranges above, the conjectured
+/// security `num_queries * log2(blowup_factor) + grinding_factor` must be at least
+/// [ProofOptions::MIN_CONJECTURED_SECURITY] bits.
 impl Serializable for ProofOptions {
     /// Serializes `self` and writes the resulting bytes into the `target`.
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         target.write_u8(self.num_queries);
         target.write_u8(self.blowup_factor);
         target.write_u8(self.grinding_factor);
-        target.write(self.field_extension);
+        // the proof-of-work scheme is written into the upper 4 bits of the field extension byte;
+        // the default scheme is encoded as 0, and so this byte is the same as for proofs which
+        // were generated before the scheme became configurable
+        target.write_u8(((self.pow_scheme as u8) << 4) | self.field_extension as u8);
         target.write_u8(self.fri_folding_factor);
         target.write_u8(self.fri_remainder_max_degree);
         target.write_u8(self.partition_options.num_partitions);
         target.write_u8(self.partition_options.hash_rate);
     }
+
+    /// Returns the number of bytes needed to represent self.
+    fn get_size_hint(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
 }
 
 impl Deserializable for ProofOptions {
     /// Reads proof options from the specified `source` and returns the result.
     ///
     /// # Errors
-    /// Returns an error of a valid proof options could not be read from the specified `source`.
+    /// Returns an error if fewer than [ProofOptions::SERIALIZED_SIZE] bytes could be read from
+    /// the specified `source`, or if any of the fields read is outside of the range of its valid
+    /// values.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let result = ProofOptions::new(
-            source.read_u8()? as usize,
-            source.read_u8()? as usize,
-            source.read_u8()? as u32,
-            FieldExtension::read_from(source)?,
+        let num_queries = source.read_u8()? as usize;
+        let blowup_factor = source.read_u8()? as usize;
+        let grinding_factor = source.read_u8()? as u32;
+        let scheme_and_extension = source.read_u8()?;
+        let pow_scheme = PowScheme::read_from_bytes(&[scheme_and_extension >> 4])?;
+        let field_extension = FieldExtension::read_from_bytes(&[scheme_and_extension & 0xf])?;
+        let result = ProofOptions::try_new(
+            num_queries,
+            blowup_factor,
+            grinding_factor,
+            field_extension,
             source.read_u8()? as usize,
             source.read_u8()? as usize,
-        );
-        Ok(result.with_partitions(source.read_u8()? as usize, source.read_u8()? as usize))
+        )
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        let partition_options =
+            PartitionOptions::try_new(source.read_u8()? as usize, source.read_u8()? as usize)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        Ok(ProofOptions { pow_scheme, partition_options, ..result })
     }
 }
 
@@ -365,17 +620,41 @@ pub struct PartitionOptions {
 
 impl PartitionOptions {
     /// Returns a new instance of `[PartitionOptions]`.
+    ///
+    /// This is a panicking version of [PartitionOptions::try_new()].
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - `num_partitions` is zero or greater than 16.
+    /// - `hash_rate` is zero or greater than 256.
     pub const fn new(num_partitions: usize, hash_rate: usize) -> Self {
-        assert!(num_partitions >= 1, "number of partitions must be greater than or equal to 1");
-        assert!(num_partitions <= 16, "number of partitions must be smaller than or equal to 16");
+        match Self::try_new(num_partitions, hash_rate) {
+            Ok(options) => options,
+            Err(err) => panic!("{}", panic_message(&err)),
+        }
+    }
 
-        assert!(hash_rate >= 1, "hash rate must be greater than or equal to 1");
-        assert!(hash_rate <= 256, "hash rate must be smaller than or equal to 256");
+    /// Returns a new instance of `[PartitionOptions]`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `num_partitions` is zero or greater than 16.
+    /// - `hash_rate` is zero or greater than 256.
+    pub const fn try_new(
+        num_partitions: usize,
+        hash_rate: usize,
+    ) -> Result<Self, ProofOptionsError> {
+        if num_partitions < 1 || num_partitions > MAX_NUM_PARTITIONS {
+            return Err(ProofOptionsError::InvalidNumPartitions { got: num_partitions });
+        }
+        if hash_rate < 1 || hash_rate > MAX_HASH_RATE {
+            return Err(ProofOptionsError::InvalidHashRate { got: hash_rate });
+        }
 
-        Self {
+        Ok(Self {
             num_partitions: num_partitions as u8,
             hash_rate: hash_rate as u8,
-        }
+        })
     }
 
     /// Returns the size of each partition used when committing to the main and auxiliary traces as
@@ -406,14 +685,77 @@ impl Default for PartitionOptions {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the number of bits of security contributed by a grinding factor of `grinding_factor`
+/// bits to a query phase which provides `query_security` bits of security on its own.
+///
+/// The contribution is `min(grinding_factor, floor(grinding_factor * query_security /
+/// GRINDING_CONTRIBUTION_FLOOR))`: grinding contributes fully once the query security reaches
+/// the floor, and proportionally less below it.
+fn get_grinding_security(query_security: u32, grinding_factor: u32) -> u32 {
+    if query_security >= GRINDING_CONTRIBUTION_FLOOR {
+        return grinding_factor;
+    }
+    // the result is smaller than the grinding factor
+    (grinding_factor as u64 * query_security as u64 / GRINDING_CONTRIBUTION_FLOOR as u64) as u32
+}
+
+/// Returns the number of bits of security contributed by the field for the specified field
+/// extension.
+fn get_field_security(
+    extension: FieldExtension,
+    base_field_bits: u32,
+    lde_domain_size: usize,
+) -> u32 {
+    let domain_bits = lde_domain_size.checked_ilog2().unwrap_or(0);
+    (base_field_bits * extension.degree()).saturating_sub(domain_bits)
+}
+
+/// Returns the message with which panicking constructors abort on the specified error; this is
+/// needed because errors cannot be formatted in const functions.
+const fn panic_message(err: &ProofOptionsError) -> &'static str {
+    match err {
+        ProofOptionsError::NoQueries => "number of queries must be greater than 0",
+        ProofOptionsError::TooManyQueries { .. } => "number of queries cannot be greater than 255",
+        ProofOptionsError::InvalidBlowupFactor { .. } => {
+            "blowup factor must be a power of 2 between 2 and 128"
+        },
+        ProofOptionsError::GrindingFactorTooLarge { .. } => {
+            "grinding factor cannot be greater than 32"
+        },
+        ProofOptionsError::InvalidFriFoldingFactor { .. } => {
+            "FRI folding factor must be a power of 2 between 2 and 16"
+        },
+        ProofOptionsError::InvalidFriRemainderMaxDegree { .. } => {
+            "FRI polynomial remainder degree must be one less than a power of two and at most 255"
+        },
+        ProofOptionsError::InsufficientSecurity { .. } => {
+            "conjectured security of proof options cannot be smaller than 8 bits"
+        },
+        ProofOptionsError::IncompatibleFieldExtension { .. } => {
+            "field extension is not supported by the base field"
+        },
+        ProofOptionsError::InvalidNumPartitions { .. } => {
+            "number of partitions must be between 1 and 16"
+        },
+        ProofOptionsError::InvalidHashRate { .. } => "hash rate must be between 1 and 256",
+    }
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use math::fields::{f64::BaseElement, CubeExtension};
+    use math::fields::{f128, f64::BaseElement, CubeExtension};
+    use utils::{Deserializable, Serializable};
 
-    use super::{FieldExtension, PartitionOptions, ProofOptions, ToElements};
+    use super::{
+        FieldExtension, FriFoldingFactor, PartitionOptions, PowScheme, ProofOptions,
+        ProofOptionsError, ToElements,
+    };
 
     #[test]
     fn proof_options_to_elements() {
@@ -480,4 +822,331 @@ mod tests {
         assert_eq!(2, options.partition_size::<E3>(columns));
         assert_eq!(2, options.num_partitions::<E3>(columns));
     }
+
+    #[test]
+    fn proof_options_try_new() {
+        let options = ProofOptions::try_new(28, 8, 16, FieldExtension::Quadratic, 4, 31).unwrap();
+        assert_eq!(28, options.num_queries());
+        assert_eq!(8, options.blowup_factor());
+        assert_eq!(16, options.grinding_factor());
+        assert_eq!(FieldExtension::Quadratic, options.field_extension());
+        assert_eq!(FriFoldingFactor::Factor4, options.fri_folding_factor());
+        assert_eq!(options, ProofOptions::new(28, 8, 16, FieldExtension::Quadratic, 4, 31));
+
+        // all supported FRI folding factors are accepted and carried over to FRI options
+        for folding_factor in FriFoldingFactor::ALL {
+            let options = ProofOptions::try_new(
+                28,
+                8,
+                16,
+                FieldExtension::Quadratic,
+                folding_factor.as_usize(),
+                31,
+            )
+            .unwrap();
+            assert_eq!(folding_factor, options.fri_folding_factor());
+            assert_eq!(folding_factor.as_usize(), options.to_fri_options().folding_factor());
+        }
+        for folding_factor in [0, 1, 3, 6, 32] {
+            assert_eq!(None, FriFoldingFactor::from_usize(folding_factor));
+        }
+
+        let options = options.with_partitions(4, 8);
+        let bytes = options.to_bytes();
+        assert_eq!(options, ProofOptions::read_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn proof_options_pow_scheme() {
+        let options = ProofOptions::new(28, 8, 16, FieldExtension::Quadratic, 4, 31);
+        assert_eq!(PowScheme::Hash, options.pow_scheme());
+
+        // the default scheme does not change the encoding of the options
+        let bytes = options.to_bytes();
+        assert_eq!(FieldExtension::Quadratic as u8, bytes[3]);
+
+        let blake3_options = options.clone().with_pow_scheme(PowScheme::Blake3);
+        assert_eq!(PowScheme::Blake3, blake3_options.pow_scheme());
+        assert_ne!(options, blake3_options);
+        assert_ne!(
+            ToElements::<BaseElement>::to_elements(&options),
+            ToElements::<BaseElement>::to_elements(&blake3_options)
+        );
+
+        let bytes = blake3_options.to_bytes();
+        assert_eq!(0x10 | FieldExtension::Quadratic as u8, bytes[3]);
+        assert_eq!(blake3_options, ProofOptions::read_from_bytes(&bytes).unwrap());
+
+        // unknown schemes are rejected
+        let mut invalid = bytes.clone();
+        invalid[3] = 0x20 | FieldExtension::Quadratic as u8;
+        assert!(ProofOptions::read_from_bytes(&invalid).is_err());
+    }
+
+    #[test]
+    fn proof_options_try_new_errors() {
+        let ext = FieldExtension::None;
+
+        assert_eq!(Err(ProofOptionsError::NoQueries), ProofOptions::try_new(0, 8, 0, ext, 4, 31));
+        assert_eq!(
+            Err(ProofOptionsError::TooManyQueries { max: 255, got: 256 }),
+            ProofOptions::try_new(256, 8, 0, ext, 4, 31)
+        );
+
+        for blowup_factor in [0, 1, 6, 256] {
+            assert_eq!(
+                Err(ProofOptionsError::InvalidBlowupFactor { got: blowup_factor }),
+                ProofOptions::try_new(28, blowup_factor, 0, ext, 4, 31)
+            );
+        }
+
+        assert_eq!(
+            Err(ProofOptionsError::GrindingFactorTooLarge { max: 32, got: 33 }),
+            ProofOptions::try_new(28, 8, 33, ext, 4, 31)
+        );
+
+        for folding_factor in [0, 1, 3, 32] {
+            assert_eq!(
+                Err(ProofOptionsError::InvalidFriFoldingFactor { got: folding_factor }),
+                ProofOptions::try_new(28, 8, 0, ext, folding_factor, 31)
+            );
+        }
+
+        for remainder_max_degree in [2, 30, 511] {
+            assert_eq!(
+                Err(ProofOptionsError::InvalidFriRemainderMaxDegree { got: remainder_max_degree }),
+                ProofOptions::try_new(28, 8, 0, ext, 4, remainder_max_degree)
+            );
+        }
+
+        // a single query with blowup factor 2 yields only 1 bit of conjectured security; the
+        // same number of queries is fine when grinding makes up for the difference
+        assert_eq!(
+            Err(ProofOptionsError::InsufficientSecurity { min: 8, got: 1 }),
+            ProofOptions::try_new(1, 2, 0, ext, 2, 1)
+        );
+        assert_eq!(
+            Err(ProofOptionsError::InsufficientSecurity { min: 8, got: 7 }),
+            ProofOptions::try_new(3, 4, 1, ext, 2, 1)
+        );
+        assert!(ProofOptions::try_new(1, 2, 7, ext, 2, 1).is_ok());
+
+        assert_eq!(
+            Err(ProofOptionsError::InvalidNumPartitions { got: 17 }),
+            PartitionOptions::try_new(17, 8)
+        );
+        assert_eq!(
+            Err(ProofOptionsError::InvalidHashRate { got: 0 }),
+            PartitionOptions::try_new(4, 0)
+        );
+    }
+
+    #[test]
+    fn proof_options_field_extension_validation() {
+        let options = ProofOptions::new(28, 8, 0, FieldExtension::Cubic, 4, 31);
+        assert!(options.validate_field_extension::<BaseElement>().is_ok());
+        assert_eq!(
+            Err(ProofOptionsError::IncompatibleFieldExtension { degree: 3 }),
+            options.validate_field_extension::<f128::BaseElement>()
+        );
+
+        let options = ProofOptions::new(28, 8, 0, FieldExtension::None, 4, 31);
+        assert!(options.validate_field_extension::<f128::BaseElement>().is_ok());
+    }
+
+    #[test]
+    fn conjectured_security_regression() {
+        // num_queries, blowup_factor, grinding_factor, field_extension, base_field_bits,
+        // trace_length, collision_resistance, expected conjectured security
+        #[rustfmt::skip]
+        let cases = [
+            // query security straddles the grinding contribution floor
+            (79, 2, 20, FieldExtension::Cubic, 64, 1 << 20, 256, 97),
+            (80, 2, 20, FieldExtension::Cubic, 64, 1 << 20, 256, 99),
+            (26, 8, 16, FieldExtension::Quadratic, 64, 1 << 20, 128, 92),
+            (27, 8, 16, FieldExtension::Quadratic, 64, 1 << 20, 128, 96),
+            (28, 8, 16, FieldExtension::Quadratic, 64, 1 << 20, 128, 99),
+            // weak query phases get a proportionally small contribution from grinding
+            (40, 2, 20, FieldExtension::Cubic, 64, 1 << 20, 256, 49),
+            (4, 4, 32, FieldExtension::None, 128, 1 << 10, 256, 10),
+            (10, 4, 0, FieldExtension::None, 128, 1 << 10, 256, 19),
+            // security limited by the field
+            (40, 16, 0, FieldExtension::Quadratic, 64, 1 << 20, 256, 103),
+            (40, 16, 20, FieldExtension::None, 64, 1 << 10, 256, 49),
+            // security limited by the hash function; no bit is subtracted
+            (100, 8, 20, FieldExtension::Cubic, 64, 1 << 16, 128, 128),
+            (30, 16, 8, FieldExtension::None, 128, 1 << 16, 96, 96),
+        ];
+
+        for (i, (queries, blowup, grinding, extension, field_bits, trace_length, cr, expected)) in
+            cases.into_iter().enumerate()
+        {
+            let options = ProofOptions::new(queries, blowup, grinding, extension, 4, 31);
+            let security = options.conjectured_security(field_bits, trace_length, cr);
+            assert_eq!(expected, security, "case {i}");
+        }
+
+        // conjectured security does not decrease as the number of queries grows
+        let mut prev_security = 0;
+        for num_queries in 1..=160 {
+            let options = ProofOptions::new(num_queries, 2, 20, FieldExtension::Cubic, 4, 31);
+            let security = options.conjectured_security(64, 1 << 20, 256);
+            assert!(security >= prev_security);
+            assert!(security <= prev_security + 2 || num_queries == 1);
+            prev_security = security;
+        }
+    }
+
+    #[test]
+    fn proof_options_security_components() {
+        // 28 queries with blowup factor 8 yield 84 bits, and so grinding is included fully
+        let options = ProofOptions::new(28, 8, 16, FieldExtension::Quadratic, 4, 31);
+        assert_eq!(100, options.query_security());
+        assert_eq!(128 - 20, options.field_security(64, 1 << 20));
+
+        // grinding is scaled down when queries provide fewer than 80 bits: 16 * 60 / 80 = 12
+        let options = ProofOptions::new(20, 8, 16, FieldExtension::None, 4, 31);
+        assert_eq!(72, options.query_security());
+        assert_eq!(64 - 20, options.field_security(64, 1 << 20));
+        assert_eq!(0, options.field_security(16, 1 << 20));
+    }
+
+    #[test]
+    fn required_extension_degree() {
+        let lde_domain_size = 1 << 20;
+
+        // 64-bit field: 44, 108, and 172 bits of field security
+        assert_eq!(
+            FieldExtension::None,
+            ProofOptions::required_extension_degree(64, 40, lde_domain_size)
+        );
+        assert_eq!(
+            FieldExtension::Quadratic,
+            ProofOptions::required_extension_degree(64, 100, lde_domain_size)
+        );
+        assert_eq!(
+            FieldExtension::Quadratic,
+            ProofOptions::required_extension_degree(64, 107, lde_domain_size)
+        );
+        assert_eq!(
+            FieldExtension::Cubic,
+            ProofOptions::required_extension_degree(64, 108, lde_domain_size)
+        );
+        assert_eq!(
+            FieldExtension::Cubic,
+            ProofOptions::required_extension_degree(64, 200, lde_domain_size)
+        );
+
+        // 128-bit field: 108 bits of field security
+        assert_eq!(
+            FieldExtension::None,
+            ProofOptions::required_extension_degree(128, 100, lde_domain_size)
+        );
+        assert_eq!(
+            FieldExtension::Quadratic,
+            ProofOptions::required_extension_degree(128, 108, lde_domain_size)
+        );
+
+        // the returned extension is not the bottleneck of conjectured security
+        let options = ProofOptions::new(40, 8, 0, FieldExtension::None, 4, 31);
+        let extension = ProofOptions::required_extension_degree(
+            64,
+            options.query_security() - 1,
+            lde_domain_size,
+        );
+        let options = ProofOptions::new(40, 8, 0, extension, 4, 31);
+        assert!(options.field_security(64, lde_domain_size) >= options.query_security());
+    }
+
+    #[test]
+    fn proof_options_serialization_layout() {
+        let options = ProofOptions::new(28, 8, 16, FieldExtension::Cubic, 4, 31)
+            .with_partitions(4, 8)
+            .with_pow_scheme(PowScheme::Blake3);
+        let bytes = options.to_bytes();
+        assert_eq!(vec![28, 8, 16, 0x13, 4, 31, 4, 8], bytes);
+        assert_eq!(ProofOptions::SERIALIZED_SIZE, options.get_size_hint());
+    }
+
+    #[test]
+    fn proof_options_serialization_round_trip() {
+        let extensions = [FieldExtension::None, FieldExtension::Quadratic, FieldExtension::Cubic];
+        for blowup_factor in (1..=7).map(|log_blowup| 1 << log_blowup) {
+            for grinding_factor in [0, 1, 20, 32] {
+                for field_extension in extensions {
+                    for pow_scheme in [PowScheme::Hash, PowScheme::Blake3] {
+                        for fri_folding_factor in [2, 4, 8, 16] {
+                            for fri_remainder_max_degree in [0, 1, 7, 255] {
+                                for (num_partitions, hash_rate) in [(1, 1), (16, 255)] {
+                                    let options = ProofOptions::new(
+                                        255,
+                                        blowup_factor,
+                                        grinding_factor,
+                                        field_extension,
+                                        fri_folding_factor,
+                                        fri_remainder_max_degree,
+                                    )
+                                    .with_partitions(num_partitions, hash_rate)
+                                    .with_pow_scheme(pow_scheme);
+                                    let bytes = options.to_bytes();
+                                    assert_eq!(ProofOptions::SERIALIZED_SIZE, bytes.len());
+                                    assert_eq!(
+                                        options,
+                                        ProofOptions::read_from_bytes(&bytes).unwrap()
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn proof_options_deserialization_errors() {
+        let bytes = ProofOptions::new(28, 8, 0, FieldExtension::None, 4, 31).to_bytes();
+
+        // every value of every byte is accepted if and only if it is in the range of valid values
+        // of the corresponding field; with blowup factor 8, at least 3 queries are needed to reach
+        // the minimum conjectured security
+        let is_valid = |idx: usize, value: u8| match idx {
+            0 => value >= 3,
+            1 => value.is_power_of_two() && value >= 2,
+            2 => value <= 32,
+            3 => (1..=3).contains(&(value & 0xf)) && value >> 4 <= 1,
+            4 => [2, 4, 8, 16].contains(&value),
+            5 => (value as usize + 1).is_power_of_two(),
+            6 => (1..=16).contains(&value),
+            7 => value >= 1,
+            _ => unreachable!(),
+        };
+        for idx in 0..ProofOptions::SERIALIZED_SIZE {
+            for value in 0..=u8::MAX {
+                let mut candidate = bytes.clone();
+                candidate[idx] = value;
+                let result = ProofOptions::read_from_bytes(&candidate);
+                assert_eq!(is_valid(idx, value), result.is_ok(), "byte {idx}, value {value}");
+            }
+        }
+
+        // options with insufficient conjectured security are rejected
+        let mut invalid = bytes.clone();
+        invalid[0] = 2;
+        invalid[1] = 2;
+        assert!(ProofOptions::read_from_bytes(&invalid).is_err());
+
+        // truncated options are rejected
+        for len in 0..ProofOptions::SERIALIZED_SIZE {
+            assert!(ProofOptions::read_from_bytes(&bytes[..len]).is_err());
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "conjectured security of proof options cannot be smaller than 8 bits"
+    )]
+    fn proof_options_new_insufficient_security() {
+        ProofOptions::new(1, 2, 0, FieldExtension::None, 2, 1);
+    }
 }
@@ -0,0 +1,366 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{string::String, vec::Vec};
+
+use math::{ExtensibleField, FieldElement, StarkField, ToElements};
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use super::Proof;
+use crate::{
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of transition constraints or assertions which can be described by metadata
+/// of a single trace segment; this prevents metadata from untrusted sources from causing
+/// excessive allocations during verification.
+const MAX_NUM_CONSTRAINTS: usize = 1 << 20;
+
+// AIR METADATA
+// ================================================================================================
+
+/// Description of the shape of an AIR which is sufficient for checking the structure of a proof
+/// generated for the AIR.
+///
+/// Metadata does not describe the constraints of the AIR, and thus, proofs verified against the
+/// metadata alone are checked only for consistency of all commitments and for the low-degree of
+/// the DEEP composition polynomial (see [AnnotatedProof]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AirMetadata {
+    /// Number of columns in the main segment of the execution trace.
+    pub num_main_columns: usize,
+    /// Number of columns in the auxiliary segment of the execution trace.
+    pub num_aux_columns: usize,
+    /// Maximum degree of the constraints of the AIR after division by their divisors, as
+    /// returned by [AirContext::max_effective_constraint_degree()].
+    pub max_constraint_degree: usize,
+    /// Length of the execution trace.
+    pub trace_length: usize,
+    /// Number of transition constraints placed against the main segment of the trace.
+    pub num_main_transition_constraints: usize,
+    /// Number of transition constraints placed against the auxiliary segment of the trace.
+    pub num_aux_transition_constraints: usize,
+    /// Number of assertions placed against the main segment of the trace.
+    pub num_main_assertions: usize,
+    /// Number of assertions placed against the auxiliary segment of the trace.
+    pub num_aux_assertions: usize,
+    /// Number of consecutive rows of the trace over which transition constraints are evaluated.
+    pub frame_size: usize,
+}
+
+impl AirMetadata {
+    /// Returns metadata describing the specified AIR.
+    ///
+    /// # Panics
+    /// Panics if the AIR has a Lagrange kernel column; metadata cannot describe the GKR-based
+    /// constraints of such AIRs.
+    pub fn from_air<A: Air>(air: &A) -> Self {
+        let context = air.context();
+        assert!(
+            !context.has_lagrange_kernel_aux_column(),
+            "metadata of AIRs with a Lagrange kernel column is not supported"
+        );
+        Self {
+            num_main_columns: context.trace_info().main_trace_width(),
+            num_aux_columns: context.trace_info().aux_segment_width(),
+            max_constraint_degree: context.max_effective_constraint_degree(),
+            trace_length: context.trace_len(),
+            num_main_transition_constraints: context.num_main_transition_constraints(),
+            num_aux_transition_constraints: context.num_aux_transition_constraints(),
+            num_main_assertions: context.num_main_assertions(),
+            num_aux_assertions: context.num_aux_assertions(),
+            frame_size: context.frame_size(),
+        }
+    }
+
+    /// Checks that this metadata describes an AIR for which the specified proof could have been
+    /// generated.
+    fn validate(&self, proof: &Proof) -> Result<(), String> {
+        let trace_info = proof.trace_info();
+        if self.num_main_columns != trace_info.main_trace_width()
+            || self.num_aux_columns != trace_info.aux_segment_width()
+            || self.trace_length != trace_info.length()
+        {
+            return Err(format!(
+                "metadata describes a trace of {} main columns, {} auxiliary columns, and {} rows, \
+                but the proof is for a trace of {} main columns, {} auxiliary columns, and {} rows",
+                self.num_main_columns,
+                self.num_aux_columns,
+                self.trace_length,
+                trace_info.main_trace_width(),
+                trace_info.aux_segment_width(),
+                trace_info.length()
+            ));
+        }
+
+        if self.num_main_transition_constraints == 0 || self.num_main_assertions == 0 {
+            return Err("metadata must describe at least one main transition constraint and \
+                one main assertion"
+                .into());
+        }
+        if [
+            self.num_main_transition_constraints,
+            self.num_aux_transition_constraints,
+            self.num_main_assertions,
+            self.num_aux_assertions,
+        ]
+        .iter()
+        .any(|&n| n > MAX_NUM_CONSTRAINTS)
+        {
+            return Err(format!(
+                "metadata cannot describe more than {MAX_NUM_CONSTRAINTS} transition constraints \
+                or assertions per trace segment"
+            ));
+        }
+        let has_aux_constraints = match trace_info.is_multi_segment() {
+            true => self.num_aux_transition_constraints > 0 && self.num_aux_assertions > 0,
+            false => self.num_aux_transition_constraints == 0 && self.num_aux_assertions == 0,
+        };
+        if !has_aux_constraints {
+            return Err("metadata must describe auxiliary transition constraints and assertions \
+                if and only if the trace has an auxiliary segment"
+                .into());
+        }
+
+        let num_composition_columns = proof.context.num_constraint_composition_columns().ok_or(
+            "metadata can annotate only proofs which record the number of constraint \
+            composition columns",
+        )?;
+        if self.max_constraint_degree == 0 || self.max_constraint_degree > num_composition_columns {
+            return Err(format!(
+                "maximum constraint degree must be between 1 and {num_composition_columns}, \
+                but was {}",
+                self.max_constraint_degree
+            ));
+        }
+
+        // transition constraints over a frame of n rows do not apply to the last n - 1 rows of
+        // the trace, and at most half of the trace plus one row can be exempt from constraints
+        if self.frame_size < 2 || self.frame_size > self.trace_length / 2 + 2 {
+            return Err(format!(
+                "frame size must be between 2 and {}, but was {}",
+                self.trace_length / 2 + 2,
+                self.frame_size
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Serializable for AirMetadata {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.num_main_columns);
+        target.write_usize(self.num_aux_columns);
+        target.write_usize(self.max_constraint_degree);
+        target.write_usize(self.trace_length);
+        target.write_usize(self.num_main_transition_constraints);
+        target.write_usize(self.num_aux_transition_constraints);
+        target.write_usize(self.num_main_assertions);
+        target.write_usize(self.num_aux_assertions);
+        target.write_usize(self.frame_size);
+    }
+}
+
+impl Deserializable for AirMetadata {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            num_main_columns: source.read_usize()?,
+            num_aux_columns: source.read_usize()?,
+            max_constraint_degree: source.read_usize()?,
+            trace_length: source.read_usize()?,
+            num_main_transition_constraints: source.read_usize()?,
+            num_aux_transition_constraints: source.read_usize()?,
+            num_main_assertions: source.read_usize()?,
+            num_aux_assertions: source.read_usize()?,
+            frame_size: source.read_usize()?,
+        })
+    }
+}
+
+// ANNOTATED PROOF
+// ================================================================================================
+
+/// A STARK proof bundled with the metadata of the AIR for which the proof was generated.
+///
+/// An annotated proof can be verified structurally without access to the AIR: the verifier
+/// replays the Fiat-Shamir transcript of the proof, checks the proof-of-work, all openings
+/// against the trace and constraint commitments, and the FRI proof for the DEEP composition
+/// polynomial, but it does not evaluate the constraints of the AIR. Thus, structural
+/// verification attests that the proof is internally consistent, but not that the computation
+/// described by the AIR was executed correctly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedProof {
+    proof: Proof,
+    metadata: AirMetadata,
+}
+
+impl AnnotatedProof {
+    /// Returns a new [AnnotatedProof] instantiated from the specified proof and AIR metadata.
+    ///
+    /// # Panics
+    /// Panics if the metadata is inconsistent with the proof (e.g., describes a trace of a
+    /// different shape).
+    pub fn new(proof: Proof, metadata: AirMetadata) -> Self {
+        if let Err(err) = metadata.validate(&proof) {
+            panic!("invalid AIR metadata: {err}");
+        }
+        Self { proof, metadata }
+    }
+
+    /// Returns the underlying STARK proof.
+    pub fn proof(&self) -> &Proof {
+        &self.proof
+    }
+
+    /// Returns metadata of the AIR for which the proof was generated.
+    pub fn metadata(&self) -> &AirMetadata {
+        &self.metadata
+    }
+
+    /// Consumes this proof and returns the underlying STARK proof and the AIR metadata.
+    pub fn into_parts(self) -> (Proof, AirMetadata) {
+        (self.proof, self.metadata)
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes this proof into a vector of bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        Serializable::to_bytes(self)
+    }
+
+    /// Returns an annotated proof read from the specified `source`.
+    ///
+    /// # Errors
+    /// Returns an error if a valid annotated proof could not be read from the specified
+    /// `source`, or if the metadata is inconsistent with the proof.
+    pub fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+        Deserializable::read_from_bytes(source)
+    }
+}
+
+impl Serializable for AnnotatedProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.metadata.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for AnnotatedProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let metadata = AirMetadata::read_from(source)?;
+        let proof = Proof::read_from(source)?;
+        metadata.validate(&proof).map_err(DeserializationError::InvalidValue)?;
+        Ok(Self { proof, metadata })
+    }
+}
+
+// METADATA AIR
+// ================================================================================================
+
+/// Public inputs of [MetadataAir].
+///
+/// Besides the public inputs of the original AIR (as a sequence of field elements), these
+/// contain the metadata of the original AIR and the number of constraint composition columns of
+/// the proof; only the former are absorbed into the Fiat-Shamir transcript.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataInputs<B: StarkField> {
+    metadata: AirMetadata,
+    num_constraint_composition_columns: usize,
+    elements: Vec<B>,
+}
+
+impl<B: StarkField> MetadataInputs<B> {
+    /// Returns public inputs for verifying the specified proof structurally against public
+    /// inputs of the original AIR serialized into `elements`.
+    pub fn new(proof: &AnnotatedProof, elements: Vec<B>) -> Self {
+        Self {
+            metadata: proof.metadata,
+            num_constraint_composition_columns: proof
+                .proof
+                .context
+                .num_constraint_composition_columns()
+                .expect("annotated proofs record the number of constraint composition columns"),
+            elements,
+        }
+    }
+}
+
+impl<B: StarkField> ToElements<B> for MetadataInputs<B> {
+    fn to_elements(&self) -> Vec<B> {
+        self.elements.clone()
+    }
+}
+
+/// AIR reconstructed from [AirMetadata].
+///
+/// The context of this AIR is the same as the context of the original AIR in all aspects which
+/// affect the structure of a proof: trace shape, number of constraints and assertions (which
+/// determine the number of random coefficients drawn by the verifier), frame size, and the
+/// number of constraint composition columns. However, this AIR does not define any constraints:
+/// [Air::evaluate_transition()] leaves all evaluations at zero and [Air::get_assertions()] returns
+/// no assertions. Thus, this AIR is suitable only for structural verification of proofs (see
+/// [AnnotatedProof]) and must not be used to generate proofs.
+pub struct MetadataAir<B: StarkField> {
+    context: AirContext<B>,
+}
+
+impl<B> Air for MetadataAir<B>
+where
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3>,
+{
+    type BaseField = B;
+    type PublicInputs = MetadataInputs<B>;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    /// Reconstructs the context of the original AIR from the metadata in the public inputs.
+    ///
+    /// # Panics
+    /// Panics if the metadata was not validated against a proof for the specified trace.
+    fn new(trace_info: TraceInfo, pub_inputs: MetadataInputs<B>, options: ProofOptions) -> Self {
+        let metadata = pub_inputs.metadata;
+        let degrees = |n| vec![TransitionConstraintDegree::new(1); n];
+        let context = AirContext::new_multi_segment(
+            trace_info,
+            degrees(metadata.num_main_transition_constraints),
+            degrees(metadata.num_aux_transition_constraints),
+            metadata.num_main_assertions,
+            metadata.num_aux_assertions,
+            None,
+            options,
+        )
+        .set_frame_size(metadata.frame_size)
+        .set_num_constraint_composition_columns_unchecked(
+            pub_inputs.num_constraint_composition_columns,
+        );
+        Self { context }
+    }
+
+    fn context(&self) -> &AirContext<B> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<B>>(
+        &self,
+        _frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        _result: &mut [E],
+    ) {
+        // constraints of the original AIR are not known; this AIR is used only for structural
+        // verification, which does not evaluate constraints
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<B>> {
+        // assertions of the original AIR are not known
+        Vec::new()
+    }
+}
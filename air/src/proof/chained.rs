@@ -0,0 +1,98 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use super::Proof;
+
+// CHAINED PROOF
+// ================================================================================================
+
+/// A pair of proofs for two computations where the output of the first computation is the input
+/// of the second computation.
+///
+/// Besides the two STARK proofs, a chained proof contains serialized public inputs of the first
+/// computation. Public inputs of the second computation are not included: these are derived
+/// from the public inputs of the first computation during verification, and thus, the output of
+/// the first computation (i.e., the intermediate value) does not need to be known to the
+/// verifier in advance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainedProof {
+    first_proof: Proof,
+    first_pub_inputs: Vec<u8>,
+    second_proof: Proof,
+}
+
+impl ChainedProof {
+    /// Returns a new [ChainedProof] instantiated from the provided proofs and serialized public
+    /// inputs of the first proof.
+    pub fn new(first_proof: Proof, first_pub_inputs: Vec<u8>, second_proof: Proof) -> Self {
+        Self {
+            first_proof,
+            first_pub_inputs,
+            second_proof,
+        }
+    }
+
+    /// Returns the proof of the first computation.
+    pub fn first_proof(&self) -> &Proof {
+        &self.first_proof
+    }
+
+    /// Returns serialized public inputs of the first computation.
+    pub fn first_pub_inputs(&self) -> &[u8] {
+        &self.first_pub_inputs
+    }
+
+    /// Returns the proof of the second computation.
+    pub fn second_proof(&self) -> &Proof {
+        &self.second_proof
+    }
+
+    /// Consumes this proof and returns the proof of the first computation, serialized public
+    /// inputs of the first computation, and the proof of the second computation.
+    pub fn into_parts(self) -> (Proof, Vec<u8>, Proof) {
+        (self.first_proof, self.first_pub_inputs, self.second_proof)
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes this proof into a vector of bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        Serializable::to_bytes(self)
+    }
+
+    /// Returns a chained proof read from the specified `source`.
+    ///
+    /// # Errors
+    /// Returns an error of a valid chained proof could not be read from the specified `source`.
+    pub fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+        Deserializable::read_from_bytes(source)
+    }
+}
+
+impl Serializable for ChainedProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.first_proof.write_into(target);
+        self.first_pub_inputs.write_into(target);
+        self.second_proof.write_into(target);
+    }
+}
+
+impl Deserializable for ChainedProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let first_proof = Proof::read_from(source)?;
+        let first_pub_inputs = Vec::<u8>::read_from(source)?;
+        let second_proof = Proof::read_from(source)?;
+        Ok(Self {
+            first_proof,
+            first_pub_inputs,
+            second_proof,
+        })
+    }
+}
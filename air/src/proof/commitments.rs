@@ -5,7 +5,7 @@
 
 use alloc::vec::Vec;
 
-use crypto::Hasher;
+use crypto::{Digest, Hasher};
 use utils::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
 };
@@ -21,7 +21,8 @@ use utils::{
 /// * Commitments to the evaluations of polynomials at all FRI layers.
 ///
 /// Internally, the commitments are stored as a sequence of bytes. Thus, to retrieve the
-/// commitments, [parse()](Commitments::parse) function should be used.
+/// commitments, [parse()](Commitments::parse) function should be used; this returns the
+/// commitments as [ParsedCommitments] which provide typed access to each of the commitments.
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct Commitments(Vec<u8>);
 
@@ -45,29 +46,44 @@ impl Commitments {
     // --------------------------------------------------------------------------------------------
 
     /// Adds the specified commitment to the list of commitments.
+    ///
+    /// The commitment is encoded in the same way as by [new()](Commitments::new), and thus,
+    /// commitments built by adding the trace roots, the constraint root, and the FRI roots in this
+    /// order are the same as the commitments built by [new()](Commitments::new).
+    #[deprecated(note = "use `Commitments::new()` to build commitments from all of the roots")]
     pub fn add<H: Hasher>(&mut self, commitment: &H::Digest) {
-        commitment.write_into(&mut self.0);
+        self.0.write(commitment);
     }
 
     // PARSING
     // --------------------------------------------------------------------------------------------
 
-    /// Parses the serialized commitments into distinct parts.
-    ///
-    /// The parts are (in the order in which they appear in the tuple):
-    /// 1. Extended execution trace commitments.
-    /// 2. Constraint composition polynomial evaluation commitment.
-    /// 3. FRI layer commitments.
+    /// Parses the serialized commitments into commitments to `num_trace_segments` trace segments,
+    /// a commitment to the constraint composition polynomial evaluations, and commitments to
+    /// `num_fri_layers` FRI layers plus the FRI remainder.
     ///
     /// # Errors
-    /// Returns an error if the bytes stored in self could not be parsed into the requested number
-    /// of commitments, or if there are any unconsumed bytes remaining after the parsing completes.
-    #[allow(clippy::type_complexity)]
+    /// Returns an error if the number of bytes stored in self is not equal to the size of the
+    /// requested number of digests of `H`, or if the bytes could not be parsed into digests of
+    /// `H`.
     pub fn parse<H: Hasher>(
         self,
         num_trace_segments: usize,
         num_fri_layers: usize,
-    ) -> Result<(Vec<H::Digest>, H::Digest, Vec<H::Digest>), DeserializationError> {
+    ) -> Result<ParsedCommitments<H::Digest>, DeserializationError> {
+        // +1 for the constraint commitment, and +1 for the FRI remainder commitment
+        let num_digests = num_trace_segments + num_fri_layers + 2;
+        let digest_size = H::Digest::default().to_bytes().len();
+        if self.0.len() != num_digests * digest_size {
+            return Err(DeserializationError::InvalidValue(format!(
+                "expected {} bytes for {} commitments of {} bytes each, but was {}",
+                num_digests * digest_size,
+                num_digests,
+                digest_size,
+                self.0.len()
+            )));
+        }
+
         let mut reader = SliceReader::new(&self.0);
 
         // parse trace commitments
@@ -83,7 +99,60 @@ impl Commitments {
         if reader.has_more_bytes() {
             return Err(DeserializationError::UnconsumedBytes);
         }
-        Ok((trace_commitments, constraint_commitment, fri_commitments))
+        Ok(ParsedCommitments {
+            trace_roots: trace_commitments,
+            constraint_root: constraint_commitment,
+            fri_roots: fri_commitments,
+        })
+    }
+}
+
+// PARSED COMMITMENTS
+// ================================================================================================
+
+/// Commitments made by the prover during commit phase of the protocol parsed into digests of a
+/// specific hash function.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParsedCommitments<D: Digest> {
+    trace_roots: Vec<D>,
+    constraint_root: D,
+    fri_roots: Vec<D>,
+}
+
+impl<D: Digest> ParsedCommitments<D> {
+    /// Returns the commitment to the main segment of the extended execution trace.
+    ///
+    /// # Panics
+    /// Panics if the commitments were parsed for zero trace segments.
+    pub fn trace_root(&self) -> D {
+        self.trace_roots[0]
+    }
+
+    /// Returns the commitment to the auxiliary segment of the extended execution trace, or `None`
+    /// if the trace consists of the main segment only.
+    pub fn aux_trace_root(&self) -> Option<D> {
+        self.trace_roots.get(1).copied()
+    }
+
+    /// Returns the commitments to all segments of the extended execution trace.
+    pub fn trace_roots(&self) -> &[D] {
+        &self.trace_roots
+    }
+
+    /// Returns the commitment to the evaluations of the constraint composition polynomial.
+    pub fn constraint_root(&self) -> D {
+        self.constraint_root
+    }
+
+    /// Returns the commitments to all FRI layers; the last commitment is the commitment to the
+    /// FRI remainder polynomial.
+    pub fn fri_roots(&self) -> &[D] {
+        &self.fri_roots
+    }
+
+    /// Returns the trace commitments, the constraint commitment, and the FRI commitments.
+    pub fn into_parts(self) -> (Vec<D>, D, Vec<D>) {
+        (self.trace_roots, self.constraint_root, self.fri_roots)
     }
 }
 
@@ -8,28 +8,47 @@ use alloc::{string::ToString, vec::Vec};
 use math::{StarkField, ToElements};
 use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
+use super::{LEGACY_PROOF_VERSION, PROOF_VERSION, UNLABELED_PROOF_VERSION};
 use crate::{ProofOptions, TraceInfo};
 
+// CONSTANTS
+// ================================================================================================
+
+/// Base-2 logarithm of the largest LDE domain size accepted in a proof context; this matches
+/// the largest power of two not exceeding [u32::MAX], which is enforced by [Context::new()].
+const MAX_LDE_DOMAIN_SIZE_LOG2: u32 = 31;
+
 // PROOF CONTEXT
 // ================================================================================================
 /// Basic metadata about a specific execution of a computation.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Context {
+    proof_version: u8,
     trace_info: TraceInfo,
     field_modulus_bytes: Vec<u8>,
     options: ProofOptions,
+    num_constraint_composition_columns: Option<usize>,
 }
 
 impl Context {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Creates a new context for a computation described by the specified field, trace info, and
-    /// proof options.
+    /// Creates a new context for a computation described by the specified field, trace info,
+    /// proof options, and the number of columns of the constraint composition polynomial.
+    ///
+    /// The context is tagged with the current proof format version ([PROOF_VERSION]).
     ///
     /// # Panics
-    /// Panics if either trace length or the LDE domain size implied by the trace length and the
-    /// blowup factor is greater then [u32::MAX].
-    pub fn new<B: StarkField>(trace_info: TraceInfo, options: ProofOptions) -> Self {
+    /// Panics if:
+    /// * Either trace length or the LDE domain size implied by the trace length and the blowup
+    ///   factor is greater then [u32::MAX].
+    /// * The number of constraint composition columns is zero or is greater than the blowup
+    ///   factor.
+    pub fn new<B: StarkField>(
+        trace_info: TraceInfo,
+        options: ProofOptions,
+        num_constraint_composition_columns: usize,
+    ) -> Self {
         // TODO: return errors instead of panicking?
 
         let trace_length = trace_info.length();
@@ -38,16 +57,31 @@ impl Context {
         let lde_domain_size = trace_length * options.blowup_factor();
         assert!(lde_domain_size <= u32::MAX as usize, "LDE domain size too big");
 
+        assert!(
+            num_constraint_composition_columns > 0
+                && num_constraint_composition_columns <= options.blowup_factor(),
+            "number of constraint composition columns must be between 1 and {}, but was {}",
+            options.blowup_factor(),
+            num_constraint_composition_columns
+        );
+
         Context {
+            proof_version: PROOF_VERSION,
             trace_info,
             field_modulus_bytes: B::get_modulus_le_bytes(),
             options,
+            num_constraint_composition_columns: Some(num_constraint_composition_columns),
         }
     }
 
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the version of the proof format for the proof described by this context.
+    pub fn proof_version(&self) -> u8 {
+        self.proof_version
+    }
+
     /// Returns execution trace info for the computation described by this context.
     pub fn trace_info(&self) -> &TraceInfo {
         &self.trace_info
@@ -84,6 +118,18 @@ impl Context {
     pub fn options(&self) -> &ProofOptions {
         &self.options
     }
+
+    /// Returns the number of columns into which the constraint composition polynomial was split
+    /// by the prover, or `None` if the proof format does not record this number.
+    ///
+    /// For a valid proof, this is equal to the number of columns required by the AIR of the
+    /// computation (see
+    /// [AirContext::num_constraint_composition_columns()](crate::AirContext::num_constraint_composition_columns)).
+    /// Contexts of [LEGACY_PROOF_VERSION] proofs do not contain the number of columns, and
+    /// thus, it can be determined only from the AIR of the computation.
+    pub fn num_constraint_composition_columns(&self) -> Option<usize> {
+        self.num_constraint_composition_columns
+    }
 }
 
 impl<E: StarkField> ToElements<E> for Context {
@@ -92,10 +138,12 @@ impl<E: StarkField> ToElements<E> for Context {
     /// The elements are laid out as follows:
     /// - trace info [2 or more elements].
     /// - field modulus bytes [2 field elements].
-    /// - field extension and FRI parameters [1 element].
+    /// - proof-of-work scheme, field extension, and FRI parameters [1 element].
     /// - grinding factor [1 element].
     /// - blowup factor [1 element].
     /// - number of queries [1 element].
+    /// - number of constraint composition columns [1 element], except for contexts of
+    ///   [LEGACY_PROOF_VERSION] proofs.
     fn to_elements(&self) -> Vec<E> {
         // convert trace layout
         let mut result = self.trace_info.to_elements();
@@ -109,6 +157,10 @@ impl<E: StarkField> ToElements<E> for Context {
         // convert proof options to elements
         result.append(&mut self.options.to_elements());
 
+        if let Some(num_columns) = self.num_constraint_composition_columns {
+            result.push(E::from_u64(num_columns as u64));
+        }
+
         result
     }
 }
@@ -119,11 +171,15 @@ impl<E: StarkField> ToElements<E> for Context {
 impl Serializable for Context {
     /// Serializes `self` and writes the resulting bytes into the `target`.
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.proof_version);
         self.trace_info.write_into(target);
         assert!(self.field_modulus_bytes.len() < u8::MAX as usize);
         target.write_u8(self.field_modulus_bytes.len() as u8);
         target.write_bytes(&self.field_modulus_bytes);
         self.options.write_into(target);
+        if let Some(num_columns) = self.num_constraint_composition_columns {
+            target.write_u8(num_columns as u8);
+        }
     }
 }
 
@@ -133,6 +189,15 @@ impl Deserializable for Context {
     /// # Errors
     /// Returns an error of a valid Context struct could not be read from the specified `source`.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // read and validate proof format version
+        let proof_version = source.read_u8()?;
+        if ![LEGACY_PROOF_VERSION, UNLABELED_PROOF_VERSION, PROOF_VERSION].contains(&proof_version)
+        {
+            return Err(DeserializationError::InvalidValue(format!(
+                "proof format version {proof_version} is not supported"
+            )));
+        }
+
         // read and validate trace info
         let trace_info = TraceInfo::read_from(source)?;
 
@@ -148,7 +213,39 @@ impl Deserializable for Context {
         // read options
         let options = ProofOptions::read_from(source)?;
 
-        Ok(Context { trace_info, field_modulus_bytes, options })
+        // make sure the LDE domain is not larger than the prover could have created; this also
+        // guarantees that the LDE domain size can be represented by usize on 32-bit targets
+        let lde_domain_size_log2 = trace_info.length().ilog2() + options.blowup_factor().ilog2();
+        if lde_domain_size_log2 > MAX_LDE_DOMAIN_SIZE_LOG2 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "LDE domain size cannot be greater than 2^{MAX_LDE_DOMAIN_SIZE_LOG2}, but was 2^{lde_domain_size_log2}"
+            )));
+        }
+
+        // read and validate the number of constraint composition columns, which is not recorded
+        // in legacy proofs; the degree of the composition polynomial must be smaller than the
+        // LDE domain size, and thus, the number of columns cannot exceed the blowup factor
+        let num_constraint_composition_columns = if proof_version == LEGACY_PROOF_VERSION {
+            None
+        } else {
+            let num_columns = source.read_u8()? as usize;
+            if num_columns == 0 || num_columns > options.blowup_factor() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "number of constraint composition columns must be between 1 and {}, but was \
+                    {num_columns}",
+                    options.blowup_factor()
+                )));
+            }
+            Some(num_columns)
+        };
+
+        Ok(Context {
+            proof_version,
+            trace_info,
+            field_modulus_bytes,
+            options,
+            num_constraint_composition_columns,
+        })
     }
 }
 
@@ -158,6 +255,7 @@ impl Deserializable for Context {
 #[cfg(test)]
 mod tests {
     use math::fields::f64::BaseElement;
+    use utils::{Deserializable, Serializable};
 
     use super::{Context, ProofOptions, ToElements, TraceInfo};
     use crate::FieldExtension;
@@ -175,6 +273,7 @@ mod tests {
         let aux_width = 9;
         let aux_rands = 12;
         let trace_length = 4096;
+        let num_composition_columns = 3;
 
         let ext_fri = u32::from_le_bytes([
             fri_remainder_max_degree,
@@ -200,6 +299,7 @@ mod tests {
                 BaseElement::from(grinding_factor),
                 BaseElement::from(blowup_factor as u32),
                 BaseElement::from(num_queries as u32),
+                BaseElement::from(num_composition_columns as u32),
             ]);
 
             expected
@@ -215,7 +315,22 @@ mod tests {
         );
         let trace_info =
             TraceInfo::new_multi_segment(main_width, aux_width, aux_rands, trace_length, vec![]);
-        let context = Context::new::<BaseElement>(trace_info, options);
+        let context = Context::new::<BaseElement>(trace_info, options, num_composition_columns);
         assert_eq!(expected, context.to_elements());
     }
+
+    #[test]
+    fn context_max_lde_domain_size() {
+        let options = ProofOptions::new(30, 8, 0, FieldExtension::None, 8, 31);
+
+        // LDE domain of size 2^31 is the largest domain which can be created by the prover
+        let trace_info = TraceInfo::new(4, 1 << 28);
+        let context = Context::new::<BaseElement>(trace_info, options.clone(), 1);
+        assert_eq!(context, Context::read_from_bytes(&context.to_bytes()).unwrap());
+
+        // LDE domain of size 2^32 would overflow usize on 32-bit targets
+        let mut context = context;
+        context.trace_info = TraceInfo::new(4, 1 << 29);
+        assert!(Context::read_from_bytes(&context.to_bytes()).is_err());
+    }
 }
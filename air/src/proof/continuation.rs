@@ -0,0 +1,205 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::{StarkField, ToElements};
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use super::Proof;
+use crate::Assertion;
+
+// CONTINUATION STATE
+// ================================================================================================
+
+/// Boundary states of a single segment of a computation which has been split into multiple
+/// segments.
+///
+/// The initial state describes the state of the computation at the first step of the segment,
+/// and the final state describes the state of the computation at the last step of the segment.
+/// For a valid sequence of segments, the final state of every segment must be equal to the
+/// initial state of the next segment.
+///
+/// What exactly constitutes a state is defined by the AIR of the computation: public inputs of
+/// an AIR which supports continuations must be derivable from a [ContinuationState], and the AIR
+/// is expected to enforce (e.g., via assertions) that the execution trace of a segment starts in
+/// the initial state and ends in the final state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContinuationState<B: StarkField> {
+    initial_state: Vec<B>,
+    final_state: Vec<B>,
+}
+
+impl<B: StarkField> ContinuationState<B> {
+    /// Returns a new [ContinuationState] instantiated from the provided initial and final states.
+    pub fn new(initial_state: Vec<B>, final_state: Vec<B>) -> Self {
+        Self { initial_state, final_state }
+    }
+
+    /// Returns the state of the computation at the first step of the segment.
+    pub fn initial_state(&self) -> &[B] {
+        &self.initial_state
+    }
+
+    /// Returns the state of the computation at the last step of the segment.
+    pub fn final_state(&self) -> &[B] {
+        &self.final_state
+    }
+
+    /// Returns assertions which require the execution trace of a segment to start in the
+    /// initial state and to end in the final state of this segment.
+    ///
+    /// The returned assertions require that the value in column `input_columns[i]` at the first
+    /// step of the trace is equal to `initial_state[i]`, and that the value in column
+    /// `output_columns[i]` at the last step of the trace is equal to `final_state[i]`.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The number of input columns is not equal to the number of elements in the initial state.
+    /// * The number of output columns is not equal to the number of elements in the final state.
+    /// * `trace_length` is zero.
+    pub fn to_assertions(
+        &self,
+        input_columns: &[usize],
+        output_columns: &[usize],
+        trace_length: usize,
+    ) -> Vec<Assertion<B>> {
+        assert_eq!(
+            input_columns.len(),
+            self.initial_state.len(),
+            "expected {} input columns, but was {}",
+            self.initial_state.len(),
+            input_columns.len()
+        );
+        assert_eq!(
+            output_columns.len(),
+            self.final_state.len(),
+            "expected {} output columns, but was {}",
+            self.final_state.len(),
+            output_columns.len()
+        );
+        assert!(trace_length > 0, "trace length must be greater than zero");
+
+        let last_step = trace_length - 1;
+        let inputs = input_columns
+            .iter()
+            .zip(&self.initial_state)
+            .map(|(&column, &value)| Assertion::single(column, 0, value));
+        let outputs = output_columns
+            .iter()
+            .zip(&self.final_state)
+            .map(|(&column, &value)| Assertion::single(column, last_step, value));
+        inputs.chain(outputs).collect()
+    }
+}
+
+impl<B: StarkField> ToElements<B> for ContinuationState<B> {
+    fn to_elements(&self) -> Vec<B> {
+        let mut result = self.initial_state.clone();
+        result.extend_from_slice(&self.final_state);
+        result
+    }
+}
+
+impl<B: StarkField> Serializable for ContinuationState<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.initial_state.write_into(target);
+        self.final_state.write_into(target);
+    }
+}
+
+impl<B: StarkField> Deserializable for ContinuationState<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let initial_state = Vec::<B>::read_from(source)?;
+        let final_state = Vec::<B>::read_from(source)?;
+        Ok(Self { initial_state, final_state })
+    }
+}
+
+// CONTINUATION PROOF
+// ================================================================================================
+
+/// A proof of a computation which has been split into multiple segments, each proven
+/// independently.
+///
+/// For every segment, the proof contains the boundary states of the segment together with a
+/// STARK proof attesting to the correct execution of the segment between these states. A
+/// verifier must check each of the segment proofs as well as that the final state of every
+/// segment is equal to the initial state of the next segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContinuationProof<B: StarkField> {
+    segments: Vec<(ContinuationState<B>, Proof)>,
+}
+
+impl<B: StarkField> ContinuationProof<B> {
+    /// Returns a new [ContinuationProof] instantiated from the provided segment states and
+    /// proofs.
+    pub fn new(segments: Vec<(ContinuationState<B>, Proof)>) -> Self {
+        Self { segments }
+    }
+
+    /// Returns the number of segments in this proof.
+    pub fn num_segments(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns the boundary states and the proofs of all segments in the order of execution.
+    pub fn segments(&self) -> &[(ContinuationState<B>, Proof)] {
+        &self.segments
+    }
+
+    /// Consumes this proof and returns the boundary states and the proofs of all segments.
+    pub fn into_segments(self) -> Vec<(ContinuationState<B>, Proof)> {
+        self.segments
+    }
+
+    /// Returns the index of the first segment whose final state is not equal to the initial
+    /// state of the next segment, or `None` if all consecutive segments are linked correctly.
+    pub fn find_unlinked_segment(&self) -> Option<usize> {
+        self.segments
+            .windows(2)
+            .position(|pair| pair[0].0.final_state() != pair[1].0.initial_state())
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes this proof into a vector of bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        Serializable::to_bytes(self)
+    }
+
+    /// Returns a continuation proof read from the specified `source`.
+    ///
+    /// # Errors
+    /// Returns an error of a valid continuation proof could not be read from the specified
+    /// `source`.
+    pub fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+        Deserializable::read_from_bytes(source)
+    }
+}
+
+impl<B: StarkField> Serializable for ContinuationProof<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.segments.len());
+        for (state, proof) in self.segments.iter() {
+            state.write_into(target);
+            proof.write_into(target);
+        }
+    }
+}
+
+impl<B: StarkField> Deserializable for ContinuationProof<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_segments = source.read_usize()?;
+        let mut segments = Vec::new();
+        for _ in 0..num_segments {
+            let state = ContinuationState::read_from(source)?;
+            let proof = Proof::read_from(source)?;
+            segments.push((state, proof));
+        }
+        Ok(Self { segments })
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+#[cfg(feature = "json")]
+use alloc::{string::String, vec::Vec};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Error, ErrorKind, Write},
+    path::Path,
+};
+
+use super::Proof;
+
+// PROOF WRITER
+// ================================================================================================
+
+/// Writes STARK proofs into files.
+///
+/// By default, proofs are written in the binary format produced by [Proof::to_bytes()]. When
+/// `json` feature is enabled, proofs can also be written as JSON documents via
+/// [ProofWriter::write_json()].
+pub struct ProofWriter;
+
+impl ProofWriter {
+    /// Writes the binary encoding of the `proof` into the file at the specified `path`.
+    ///
+    /// The file is created if it does not exist, and is truncated otherwise.
+    ///
+    /// # Errors
+    /// Returns an error if the file could not be created or written.
+    pub fn write(proof: &Proof, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        proof.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Writes the `proof` as a JSON document into the file at the specified `path`.
+    ///
+    /// The document is an object with two fields: `version`, which contains the version of the
+    /// proof format, and `proof`, which contains the hex-encoded binary encoding of the proof.
+    ///
+    /// The file is created if it does not exist, and is truncated otherwise.
+    ///
+    /// # Errors
+    /// Returns an error if the file could not be created or written.
+    #[cfg(feature = "json")]
+    pub fn write_json(proof: &Proof, path: &Path) -> io::Result<()> {
+        let document = serde_json::json!({
+            "version": proof.context.proof_version(),
+            "proof": to_hex(&proof.to_bytes()),
+        });
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, &document)?;
+        writer.flush()
+    }
+}
+
+// PROOF READER
+// ================================================================================================
+
+/// Reads STARK proofs from files.
+///
+/// Proofs are parsed using the logic for the proof format version recorded in the proof (see
+/// [Proof::deserialize_versioned()]).
+pub struct ProofReader;
+
+impl ProofReader {
+    /// Reads a STARK proof from the file at the specified `path`.
+    ///
+    /// The file is expected to contain the binary encoding of a proof. When `json` feature is
+    /// enabled, the file may also contain a JSON document written by
+    /// [ProofWriter::write_json()]; JSON documents are recognized by their leading `{`
+    /// character, which is never the first byte of a binary proof.
+    ///
+    /// # Errors
+    /// Returns an error if the file could not be read; an error of kind
+    /// [InvalidData](ErrorKind::InvalidData) is returned if the file does not contain a valid
+    /// STARK proof.
+    pub fn read(path: &Path) -> io::Result<Proof> {
+        let bytes = std::fs::read(path)?;
+
+        #[cfg(feature = "json")]
+        if bytes.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'{') {
+            return read_json(&bytes);
+        }
+
+        Proof::deserialize_versioned(&bytes).map_err(|err| Error::new(ErrorKind::InvalidData, err))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+#[cfg(feature = "json")]
+fn read_json(bytes: &[u8]) -> io::Result<Proof> {
+    let document: serde_json::Value = serde_json::from_slice(bytes)?;
+    let proof_hex = document
+        .get("proof")
+        .and_then(|proof| proof.as_str())
+        .ok_or_else(|| invalid_data("proof document must contain a hex-encoded proof"))?;
+    let proof = from_hex(proof_hex)
+        .ok_or_else(|| invalid_data("proof document contains an invalid hex string"))
+        .and_then(|bytes| {
+            Proof::deserialize_versioned(&bytes)
+                .map_err(|err| Error::new(ErrorKind::InvalidData, err))
+        })?;
+
+    // the version recorded in the document must match the version of the parsed proof
+    let version = document.get("version").and_then(|version| version.as_u64());
+    if version != Some(proof.context.proof_version() as u64) {
+        return Err(invalid_data("proof document version does not match the proof"));
+    }
+
+    Ok(proof)
+}
+
+#[cfg(feature = "json")]
+fn invalid_data(msg: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, msg)
+}
+
+/// Encodes the `bytes` as a lowercase hex string.
+#[cfg(feature = "json")]
+fn to_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut result = String::with_capacity(bytes.len() * 2);
+    for &byte in bytes {
+        result.push(DIGITS[(byte >> 4) as usize] as char);
+        result.push(DIGITS[(byte & 0xf) as usize] as char);
+    }
+    result
+}
+
+/// Decodes a hex string into bytes; returns `None` if the string is not a valid hex string.
+#[cfg(feature = "json")]
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high << 4 | low) as u8)
+        })
+        .collect()
+}
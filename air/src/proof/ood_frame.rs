@@ -21,6 +21,8 @@ use crate::{EvaluationFrame, LagrangeKernelEvaluationFrame};
 /// This struct contains the following evaluations:
 /// * Evaluations of all trace polynomials at *z*.
 /// * Evaluations of all trace polynomials at *z * g*.
+/// * If the evaluation frame of the AIR contains `k > 2` rows, evaluations of all trace
+///   polynomials at *z * g^2*, ..., *z * g^(k-1)*.
 /// * Evaluations of Lagrange kernel trace polynomial (if any) at *z*, *z * g*, *z * g^2*, ...,
 ///   *z * g^(2^(v-1))*, where `v == log(trace_len)`
 /// * Evaluations of constraint composition column polynomials at *z*.
@@ -29,6 +31,15 @@ use crate::{EvaluationFrame, LagrangeKernelEvaluationFrame};
 ///
 /// Internally, the evaluations are stored as a sequence of bytes. Thus, to retrieve the
 /// evaluations, [parse()](OodFrame::parse) function should be used.
+///
+/// The size of the frame grows linearly with the number of trace and constraint composition
+/// columns: for a trace with `n` columns and `k` constraint composition columns, the frame
+/// contains `2 * n + k` field elements (plus the Lagrange kernel evaluations, if any); for
+/// evaluation frames of more than two rows, the frame contains `n` field elements per row. Note
+/// that these values cannot be replaced by a random linear combination of them: the verifier
+/// needs each of the individual evaluations to evaluate transition constraints at *z*, and the
+/// evaluations are already batched via the DEEP composition polynomial when their consistency
+/// with the committed polynomials is checked.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct OodFrame {
     trace_states: Vec<u8>,
@@ -37,6 +48,22 @@ pub struct OodFrame {
 }
 
 impl OodFrame {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of field elements in this frame, assuming each element is encoded
+    /// using `element_bytes` bytes.
+    ///
+    /// The count includes trace evaluations, Lagrange kernel evaluations (if any), and constraint
+    /// evaluations.
+    pub(crate) fn num_elements(&self, element_bytes: usize) -> usize {
+        // both trace sections are prefixed with a single byte specifying the number of rows
+        let num_bytes = self.trace_states.len().saturating_sub(1)
+            + self.lagrange_kernel_trace_states.len().saturating_sub(1)
+            + self.evaluations.len();
+        num_bytes / element_bytes
+    }
+
     // UPDATERS
     // --------------------------------------------------------------------------------------------
 
@@ -44,7 +71,8 @@ impl OodFrame {
     /// trace states.
     ///
     /// The out-of-domain frame is stored as one vector of interleaved values, one from the current
-    /// row and the other from the next row. Given the input frame
+    /// row and the other from the next row (if the frame contains more than two rows, the values
+    /// from all rows are interleaved in the same manner). Given the input frame
     ///
     ///    +-------+-------+-------+-------+-------+-------+-------+-------+
     ///    |   a1  |   a2  |  ...  |  an   |  c1   |  c2   |  ...  |  cm   |
@@ -70,9 +98,10 @@ impl OodFrame {
         // save the evaluations with the current and next evaluations interleaved for each polynomial
         let (main_and_aux_trace_states, lagrange_trace_states) = trace_ood_frame.to_trace_states();
 
-        // there are 2 frames: current and next
-        let frame_size: u8 = 2;
-        self.trace_states.write_u8(frame_size);
+        // the number of rows in the frame is guaranteed to fit into a u8 by the AIR context
+        let frame_size = trace_ood_frame.frame_size();
+        debug_assert!(frame_size <= u8::MAX as usize);
+        self.trace_states.write_u8(frame_size as u8);
         self.trace_states.write_many(&main_and_aux_trace_states);
 
         // save the Lagrange kernel evaluation frame (if any)
@@ -107,12 +136,14 @@ impl OodFrame {
     /// contained in `self`.
     ///
     /// # Panics
-    /// Panics if either `main_trace_width` or `num_evaluations` are equal to zero.
+    /// Panics if either `main_trace_width` or `num_evaluations` are equal to zero, or if
+    /// `frame_size` is smaller than two.
     ///
     /// # Errors
     /// Returns an error if:
-    /// * Valid [`crate::EvaluationFrame`]s for the specified `main_trace_width` and
-    ///   `aux_trace_width` could not be parsed from the internal bytes.
+    /// * The number of rows in the serialized frame is not equal to `frame_size`.
+    /// * Valid [`crate::EvaluationFrame`]s for the specified `main_trace_width`,
+    ///   `aux_trace_width`, and `frame_size` could not be parsed from the internal bytes.
     /// * A vector of evaluations specified by `num_evaluations` could not be parsed from the
     ///   internal bytes.
     /// * Any unconsumed bytes remained after the parsing was complete.
@@ -120,16 +151,21 @@ impl OodFrame {
         self,
         main_trace_width: usize,
         aux_trace_width: usize,
+        frame_size: usize,
         num_evaluations: usize,
     ) -> Result<(TraceOodFrame<E>, Vec<E>), DeserializationError> {
         assert!(main_trace_width > 0, "trace width cannot be zero");
+        assert!(frame_size >= 2, "frame size must be at least 2");
         assert!(num_evaluations > 0, "number of evaluations cannot be zero");
 
         // parse Lagrange kernel column trace, if any
         let mut reader = SliceReader::new(&self.lagrange_kernel_trace_states);
-        let lagrange_kernel_frame_size = reader.read_u8()? as usize;
+        let lagrange_kernel_frame_size =
+            reader.context("Lagrange kernel frame size").read_u8()? as usize;
         let lagrange_kernel_frame = if lagrange_kernel_frame_size > 0 {
-            let lagrange_kernel_trace = reader.read_many(lagrange_kernel_frame_size)?;
+            let lagrange_kernel_trace = reader
+                .context("Lagrange kernel trace states")
+                .read_many(lagrange_kernel_frame_size)?;
 
             Some(LagrangeKernelEvaluationFrame::new(lagrange_kernel_trace))
         } else {
@@ -141,37 +177,42 @@ impl OodFrame {
 
         // parse main and auxiliary trace evaluation frames. This does the reverse operation done in
         // `set_trace_states()`.
-        let (current_row, next_row) = {
+        let rows = {
             let mut reader = SliceReader::new(&self.trace_states);
-            let frame_size = reader.read_u8()? as usize;
-            let trace = reader.read_many((main_trace_width + aux_trace_width) * frame_size)?;
+            let num_rows = reader.context("trace frame size").read_u8()? as usize;
+            if num_rows != frame_size {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "out-of-domain trace frame must contain {frame_size} rows, but was {num_rows}"
+                )));
+            }
+            let trace = reader
+                .context("out-of-domain trace states")
+                .read_many((main_trace_width + aux_trace_width) * frame_size)?;
 
             if reader.has_more_bytes() {
                 return Err(DeserializationError::UnconsumedBytes);
             }
 
-            let mut current_row = Vec::with_capacity(main_trace_width);
-            let mut next_row = Vec::with_capacity(main_trace_width);
-
-            for col in trace.chunks_exact(2) {
-                current_row.push(col[0]);
-                next_row.push(col[1]);
+            let mut rows = vec![Vec::with_capacity(main_trace_width + aux_trace_width); frame_size];
+            for col in trace.chunks_exact(frame_size) {
+                for (row, &value) in rows.iter_mut().zip(col) {
+                    row.push(value);
+                }
             }
 
-            (current_row, next_row)
+            rows
         };
 
         // parse the constraint evaluations
         let mut reader = SliceReader::new(&self.evaluations);
-        let evaluations = reader.read_many(num_evaluations)?;
+        let evaluations = reader
+            .context("out-of-domain constraint evaluations")
+            .read_many(num_evaluations)?;
         if reader.has_more_bytes() {
             return Err(DeserializationError::UnconsumedBytes);
         }
 
-        Ok((
-            TraceOodFrame::new(current_row, next_row, main_trace_width, lagrange_kernel_frame),
-            evaluations,
-        ))
+        Ok((TraceOodFrame::new(rows, main_trace_width, lagrange_kernel_frame), evaluations))
     }
 }
 
@@ -196,7 +237,10 @@ impl Serializable for OodFrame {
 
     /// Returns an estimate of how many bytes are needed to represent self.
     fn get_size_hint(&self) -> usize {
-        self.trace_states.len() + self.evaluations.len() + 4
+        self.trace_states.len()
+            + self.lagrange_kernel_trace_states.len()
+            + self.evaluations.len()
+            + 6
     }
 }
 
@@ -231,32 +275,34 @@ impl Deserializable for OodFrame {
 
 /// Trace evaluation frame at the out-of-domain point.
 ///
-/// Stores the trace evaluations at `z` and `gz`, where `z` is a random Field element in
-/// `current_row` and `next_row`, respectively.
+/// Stores the trace evaluations at `z`, `gz`, ..., `g^(k-1) z`, where `z` is a random field
+/// element and `k` is the number of rows in the evaluation frame of the AIR (at least 2).
 ///
 /// If the Air contains a Lagrange kernel auxiliary column, then that column interpolated polynomial
 /// will be evaluated at `z`, `gz`, `g^2 z`, ... `g^(2^(v-1)) z`, where `v == log(trace_len)`, and
 /// stored in `lagrange_kernel_frame`.
 pub struct TraceOodFrame<E: FieldElement> {
-    current_row: Vec<E>,
-    next_row: Vec<E>,
+    rows: Vec<Vec<E>>,
     main_trace_width: usize,
     lagrange_kernel_frame: Option<LagrangeKernelEvaluationFrame<E>>,
 }
 
 impl<E: FieldElement> TraceOodFrame<E> {
-    /// Creates a new [`TraceOodFrame`] from current, next and optionally Lagrange kernel frames.
+    /// Creates a new [`TraceOodFrame`] from the rows of the frame (the first row is the current
+    /// row, the second row is the next row etc.) and optionally the Lagrange kernel frame.
+    ///
+    /// # Panics
+    /// Panics if fewer than two rows are provided, or if the rows are not of the same length.
     pub fn new(
-        current_row: Vec<E>,
-        next_row: Vec<E>,
+        rows: Vec<Vec<E>>,
         main_trace_width: usize,
         lagrange_kernel_frame: Option<LagrangeKernelEvaluationFrame<E>>,
     ) -> Self {
-        assert_eq!(current_row.len(), next_row.len());
+        assert!(rows.len() >= 2, "frame must contain at least 2 rows, but was {}", rows.len());
+        assert!(rows.iter().all(|row| row.len() == rows[0].len()));
 
         Self {
-            current_row,
-            next_row,
+            rows,
             main_trace_width,
             lagrange_kernel_frame,
         }
@@ -264,34 +310,44 @@ impl<E: FieldElement> TraceOodFrame<E> {
 
     /// Returns the number of columns for the current and next frames.
     pub fn num_columns(&self) -> usize {
-        self.current_row.len()
+        self.rows[0].len()
+    }
+
+    /// Returns the number of rows in this frame.
+    pub fn frame_size(&self) -> usize {
+        self.rows.len()
     }
 
     /// Returns the current row, consisting of both main and auxiliary columns.
     pub fn current_row(&self) -> &[E] {
-        &self.current_row
+        &self.rows[0]
     }
 
     /// Returns the next frame, consisting of both main and auxiliary columns.
     pub fn next_row(&self) -> &[E] {
-        &self.next_row
+        &self.rows[1]
+    }
+
+    /// Returns the row at the specified offset from the current row, consisting of both main and
+    /// auxiliary columns.
+    ///
+    /// # Panics
+    /// Panics if `offset` is greater than or equal to the number of rows in this frame.
+    pub fn row(&self, offset: usize) -> &[E] {
+        &self.rows[offset]
     }
 
     /// Returns the evaluation frame for the main trace
     pub fn main_frame(&self) -> EvaluationFrame<E> {
-        let current = self.current_row[0..self.main_trace_width].to_vec();
-        let next = self.next_row[0..self.main_trace_width].to_vec();
-
-        EvaluationFrame::from_rows(current, next)
+        let rows = self.rows.iter().map(|row| row[..self.main_trace_width].to_vec()).collect();
+        EvaluationFrame::from_row_vec(rows)
     }
 
     /// Returns the evaluation frame for the auxiliary trace
     pub fn aux_frame(&self) -> Option<EvaluationFrame<E>> {
         if self.has_aux_frame() {
-            let current = self.current_row[self.main_trace_width..].to_vec();
-            let next = self.next_row[self.main_trace_width..].to_vec();
-
-            Some(EvaluationFrame::from_rows(current, next))
+            let rows = self.rows.iter().map(|row| row[self.main_trace_width..].to_vec()).collect();
+            Some(EvaluationFrame::from_row_vec(rows))
         } else {
             None
         }
@@ -313,16 +369,18 @@ impl<E: FieldElement> TraceOodFrame<E> {
 
     /// Returns true if an auxiliary frame is present
     fn has_aux_frame(&self) -> bool {
-        self.current_row.len() > self.main_trace_width
+        self.num_columns() > self.main_trace_width
     }
 
     /// Returns the main/aux frame and Lagrange kernel frame as element vectors. Specifically, the
     /// main and auxiliary frames are interleaved, as described in [`OodFrame::set_trace_states`].
     fn to_trace_states(&self) -> (Vec<E>, Vec<E>) {
-        let mut main_and_aux_frame_states = Vec::new();
-        for col in 0..self.current_row.len() {
-            main_and_aux_frame_states.push(self.current_row[col]);
-            main_and_aux_frame_states.push(self.next_row[col]);
+        let mut main_and_aux_frame_states =
+            Vec::with_capacity(self.num_columns() * self.frame_size());
+        for col in 0..self.num_columns() {
+            for row in self.rows.iter() {
+                main_and_aux_frame_states.push(row[col]);
+            }
         }
 
         let lagrange_frame_states = match self.lagrange_kernel_frame {
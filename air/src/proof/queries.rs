@@ -11,7 +11,7 @@ use utils::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
 };
 
-use super::Table;
+use super::{Table, TableRef};
 
 // QUERIES
 // ================================================================================================
@@ -29,7 +29,7 @@ use super::Table;
 /// Internally, all opening proofs and query values are stored as a sequence of bytes. Thus, to
 /// retrieve query values and their corresponding opening proofs, [parse()](Queries::parse)
 /// function should be used.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct Queries {
     opening_proof: Vec<u8>,
     values: Vec<u8>,
@@ -72,6 +72,57 @@ impl Queries {
         Queries { opening_proof, values }
     }
 
+    // METADATA
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of bytes occupied by query values.
+    pub fn values_byte_len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the number of bytes occupied by the batch opening proof.
+    pub fn paths_byte_len(&self) -> usize {
+        self.opening_proof.len()
+    }
+
+    /// Returns the number of queries read from the header of the batch opening proof, or `None`
+    /// if the header is malformed.
+    ///
+    /// The opening proof is assumed to be encoded as a batch Merkle proof (as done by
+    /// [MerkleTree](crypto::MerkleTree)): a single byte of tree depth followed by the number of
+    /// opened leaves. Neither query values nor Merkle paths are parsed.
+    pub fn num_queries(&self) -> Option<usize> {
+        let mut reader = SliceReader::new(&self.opening_proof);
+        read_proof_header(&mut reader)
+    }
+
+    /// Returns the number of value bytes in each query, or `None` if the lengths encoded in this
+    /// struct are inconsistent.
+    ///
+    /// Lengths are considered consistent if the batch opening proof (encoded as described in
+    /// [num_queries()](Queries::num_queries)) consists of exactly the number of digests of
+    /// `digest_size` bytes its length prefixes specify, and query values divide evenly among the
+    /// opened leaves. This check reads only length prefixes, and thus, is much cheaper than
+    /// [parse()](Queries::parse).
+    pub fn leaf_width(&self, digest_size: usize) -> Option<usize> {
+        let mut reader = SliceReader::new(&self.opening_proof);
+        let num_queries = read_proof_header(&mut reader)?;
+
+        // skip over the digests of each node vector
+        for _ in 0..num_queries {
+            let num_nodes = reader.read_usize().ok()?;
+            reader.read_slice(num_nodes.checked_mul(digest_size)?).ok()?;
+        }
+        if reader.has_more_bytes() {
+            return None;
+        }
+
+        if self.values.is_empty() || self.values.len() % num_queries != 0 {
+            return None;
+        }
+        Some(self.values.len() / num_queries)
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Convert internally stored bytes into a set of query values and the corresponding batch
@@ -93,11 +144,61 @@ impl Queries {
         H: ElementHasher<BaseField = E::BaseField>,
         V: VectorCommitment<H>,
     {
+        self.check_values_len::<E>(domain_size, num_queries, values_per_query)?;
+
+        // read bytes corresponding to each query and convert them into field elements.
+        let query_values = Table::<E>::from_bytes(&self.values, num_queries, values_per_query)?;
+
+        // build batch opening proof
+        let opening_proof = self.parse_opening_proof::<H, V>(domain_size)?;
+
+        Ok((opening_proof, query_values))
+    }
+
+    /// Convert internally stored bytes into a set of query values and the corresponding batch
+    /// opening proof without taking ownership of the bytes.
+    ///
+    /// This works the same as [parse()](Queries::parse), but the returned table borrows query
+    /// values from the bytes of this struct whenever the layout of elements in `E` permits it
+    /// (see [TableRef] for details); otherwise, the values are copied into the table.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `domain_size` is not a power of two.
+    /// * `num_queries` is zero.
+    /// * `values_per_query` is zero.
+    pub fn parse_ref<'a, E, H, V>(
+        &'a self,
+        domain_size: usize,
+        num_queries: usize,
+        values_per_query: usize,
+    ) -> Result<(V::MultiProof, TableRef<'a, E>), DeserializationError>
+    where
+        E: FieldElement,
+        H: ElementHasher<BaseField = E::BaseField>,
+        V: VectorCommitment<H>,
+    {
+        self.check_values_len::<E>(domain_size, num_queries, values_per_query)?;
+        let query_values = TableRef::<E>::from_bytes(&self.values, num_queries, values_per_query)?;
+        let opening_proof = self.parse_opening_proof::<H, V>(domain_size)?;
+
+        Ok((opening_proof, query_values))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Makes sure there are exactly enough bytes to read the expected number of queries.
+    fn check_values_len<E: FieldElement>(
+        &self,
+        domain_size: usize,
+        num_queries: usize,
+        values_per_query: usize,
+    ) -> Result<(), DeserializationError> {
         assert!(domain_size.is_power_of_two(), "domain size must be a power of two");
         assert!(num_queries > 0, "there must be at least one query");
         assert!(values_per_query > 0, "a query must contain at least one value");
 
-        // make sure we have enough bytes to read the expected number of queries
         let num_query_bytes = E::ELEMENT_BYTES * values_per_query;
         let expected_bytes = num_queries * num_query_bytes;
         if self.values.len() != expected_bytes {
@@ -108,12 +209,21 @@ impl Queries {
             )));
         }
 
-        // read bytes corresponding to each query and convert them into field elements.
-        let query_values = Table::<E>::from_bytes(&self.values, num_queries, values_per_query)?;
+        Ok(())
+    }
 
-        // build batch opening proof
+    /// Parses the batch opening proof and makes sure it matches the domain length.
+    fn parse_opening_proof<H, V>(
+        &self,
+        domain_size: usize,
+    ) -> Result<V::MultiProof, DeserializationError>
+    where
+        H: Hasher,
+        V: VectorCommitment<H>,
+    {
         let mut reader = SliceReader::new(&self.opening_proof);
-        let opening_proof = <V::MultiProof as Deserializable>::read_from(&mut reader)?;
+        let opening_proof =
+            reader.context("query opening proof").read_element::<V::MultiProof>()?;
 
         // check that the opening proof matches the domain length
         if <V as VectorCommitment<H>>::get_multiproof_domain_len(&opening_proof) != domain_size {
@@ -128,7 +238,7 @@ impl Queries {
             return Err(DeserializationError::UnconsumedBytes);
         }
 
-        Ok((opening_proof, query_values))
+        Ok(opening_proof)
     }
 }
 
@@ -166,3 +276,20 @@ impl Deserializable for Queries {
         Ok(Queries { opening_proof: paths, values })
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads the tree depth and the number of opened leaves from the header of a batch Merkle proof,
+/// and returns the number of opened leaves; `None` is returned if the header is malformed or no
+/// leaves are opened.
+fn read_proof_header(reader: &mut SliceReader) -> Option<usize> {
+    let depth = reader.read_u8().ok()?;
+    if depth as u32 >= usize::BITS {
+        return None;
+    }
+    match reader.read_usize().ok()? {
+        0 => None,
+        num_queries => Some(num_queries),
+    }
+}
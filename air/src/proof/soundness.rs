@@ -0,0 +1,311 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{ceil, log2, powf, sqrt, MAX_PROXIMITY_PARAMETER};
+use crate::FieldExtension;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Smallest proximity parameter m considered in the list-decoding regime.
+const MIN_PROXIMITY_PARAMETER: u64 = 3;
+
+// FRI SOUNDNESS ANALYSIS
+// ================================================================================================
+
+/// Soundness error bounds for the FRI low-degree test derived from the proximity gaps analysis
+/// of Reed-Solomon codes in [BCIKS20](https://eprint.iacr.org/2020/654.pdf).
+///
+/// All bounds are returned as probabilities rather than as bits of security; the latter can be
+/// obtained as `-log2(bound)`. In all functions, `rate` is the rate of the Reed-Solomon code
+/// (i.e., the inverse of the blowup factor), `num_queries` is the number of FRI queries,
+/// `field_bits` is the size (in bits) of the field from which FRI folding challenges are drawn,
+/// and `domain_size` is the size of the evaluation domain of the first FRI layer.
+pub struct FriSoundnessAnalysis;
+
+impl FriSoundnessAnalysis {
+    /// Returns an upper bound on the soundness error of FRI in the unique decoding regime.
+    ///
+    /// In this regime, the proximity parameter is set to the unique decoding radius
+    /// (1 - rate) / 2. The commit-phase error is bounded by Theorem 1.2 of BCIKS20 which limits
+    /// the error of a single folding round to domain_size / |F|; a union bound is then taken over
+    /// at most log2(domain_size) folding rounds. Each query is passed by a far word with
+    /// probability at most (1 + rate) / 2.
+    ///
+    /// # Panics
+    /// Panics if `rate` is not in the range (0, 1) or if `domain_size` is not a power of two.
+    pub fn unique_decoding_bound(
+        rate: f64,
+        num_queries: u32,
+        field_bits: u32,
+        domain_size: usize,
+    ) -> f64 {
+        validate_parameters(rate, domain_size);
+
+        let n = domain_size as f64;
+        let num_rounds = log2(n);
+        let commit_err = num_rounds * n / powf(2.0, field_bits as f64);
+        let query_err = powf((1.0 + rate) / 2.0, num_queries as f64);
+
+        commit_err + query_err
+    }
+
+    /// Returns an upper bound on the soundness error of FRI in the list decoding regime.
+    ///
+    /// In this regime, the proximity parameter approaches the Johnson bound 1 - sqrt(rate). For
+    /// a given integer m >= 3, a far word passes each query with probability at most
+    /// (1 + 1/(2m)) * sqrt(rate), while the commit-phase error is dominated by the first term of
+    /// eq. 7 in <https://eprint.iacr.org/2022/1216.pdf>, i.e., (m + 1/2)^7 * n^2 / (2 * rate^1.5 * |F|).
+    /// The returned bound is the smallest combined error over all m in [3, 1000].
+    ///
+    /// # Panics
+    /// Panics if `rate` is not in the range (0, 1) or if `domain_size` is not a power of two.
+    pub fn list_decoding_bound(
+        rate: f64,
+        num_queries: u32,
+        field_bits: u32,
+        domain_size: usize,
+    ) -> f64 {
+        validate_parameters(rate, domain_size);
+        Self::optimal_list_decoding_bound(rate, num_queries, field_bits, domain_size, 0).0
+    }
+
+    /// Returns the list decoding bound together with the proximity parameter m at which it is
+    /// achieved. The query-phase error is reduced by `grinding_factor` bits.
+    pub(super) fn optimal_list_decoding_bound(
+        rate: f64,
+        num_queries: u32,
+        field_bits: u32,
+        domain_size: usize,
+        grinding_factor: u32,
+    ) -> (f64, f64) {
+        (MIN_PROXIMITY_PARAMETER..=MAX_PROXIMITY_PARAMETER)
+            .map(|m| {
+                let m = m as f64;
+                let err = list_decoding_error_for_m(
+                    rate,
+                    num_queries,
+                    field_bits,
+                    domain_size,
+                    grinding_factor,
+                    m,
+                );
+                (err, m)
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .expect("range of proximity parameters is not empty")
+    }
+}
+
+// OOD SAMPLE ANALYSIS
+// ================================================================================================
+
+/// Analysis of the security provided by the out-of-domain (OOD) consistency check of the DEEP
+/// step of the protocol.
+///
+/// A prover who committed to a trace which does not satisfy the constraints passes the
+/// out-of-domain consistency check at a point `z` drawn from a field `F` only if `z` is a root of
+/// a non-zero polynomial obtained by combining `num_columns` trace columns via constraints of
+/// degree at most `max_constraint_degree`. Over a trace of `trace_length` steps, such a
+/// polynomial has degree of roughly `max_constraint_degree * trace_length`, and thus, this
+/// happens with probability of roughly `max_constraint_degree * trace_length * num_columns / |F|`.
+///
+/// The protocol draws exactly one out-of-domain point, and so, this error can be reduced only by
+/// drawing the point from a larger field, i.e., by choosing a larger field extension.
+pub struct OodSampleAnalyzer;
+
+impl OodSampleAnalyzer {
+    /// Security level (in bits) which the OOD sample needs to provide for the DEEP step not to be
+    /// the bottleneck of the protocol.
+    pub const TARGET_SECURITY: u32 = 100;
+
+    /// Returns the number of bits of security provided by the out-of-domain consistency check at
+    /// a single point drawn from a field of `field_bits` bits.
+    ///
+    /// # Panics
+    /// Panics if `max_constraint_degree`, `trace_length`, or `num_columns` is zero.
+    pub fn security(
+        field_bits: u32,
+        max_constraint_degree: usize,
+        trace_length: usize,
+        num_columns: usize,
+    ) -> u32 {
+        assert!(max_constraint_degree > 0, "max constraint degree must be greater than zero");
+        assert!(trace_length > 0, "trace length must be greater than zero");
+        assert!(num_columns > 0, "number of columns must be greater than zero");
+
+        let error_bits = log2(max_constraint_degree as f64)
+            + log2(trace_length as f64)
+            + log2(num_columns as f64);
+        let security = field_bits as f64 - ceil(error_bits);
+        if security <= 0.0 {
+            0
+        } else {
+            security as u32
+        }
+    }
+
+    /// Returns the smallest extension of a base field of `base_field_bits` bits from which the
+    /// out-of-domain point needs to be drawn for the error of the out-of-domain consistency
+    /// check to be at most `2^-TARGET_SECURITY`.
+    ///
+    /// If even the cubic extension is not sufficient to reach the target, [FieldExtension::Cubic]
+    /// is returned.
+    ///
+    /// # Panics
+    /// Panics if `max_constraint_degree`, `trace_length`, or `num_columns` is zero.
+    pub fn required_extension(
+        base_field_bits: u32,
+        max_constraint_degree: usize,
+        trace_length: usize,
+        num_columns: usize,
+    ) -> FieldExtension {
+        [FieldExtension::None, FieldExtension::Quadratic]
+            .into_iter()
+            .find(|extension| {
+                let field_bits = base_field_bits * extension.degree();
+                Self::security(field_bits, max_constraint_degree, trace_length, num_columns)
+                    >= Self::TARGET_SECURITY
+            })
+            .unwrap_or(FieldExtension::Cubic)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Computes the combined FRI soundness error in the list-decoding regime for a fixed proximity
+/// parameter m.
+fn list_decoding_error_for_m(
+    rate: f64,
+    num_queries: u32,
+    field_bits: u32,
+    domain_size: usize,
+    grinding_factor: u32,
+    m: f64,
+) -> f64 {
+    let n = domain_size as f64;
+    let commit_err =
+        0.5 * powf(m + 0.5, 7.0) / powf(rate, 1.5) * n * n / powf(2.0, field_bits as f64);
+
+    let alpha = (1.0 + 0.5 / m) * sqrt(rate);
+    let query_err = powf(alpha, num_queries as f64) / powf(2.0, grinding_factor as f64);
+
+    commit_err + query_err
+}
+
+fn validate_parameters(rate: f64, domain_size: usize) {
+    assert!(rate > 0.0 && rate < 1.0, "rate must be in the range (0, 1), but was {rate}");
+    assert!(
+        domain_size.is_power_of_two(),
+        "domain size must be a power of two, but was {domain_size}"
+    );
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{log2, powf, FieldExtension, FriSoundnessAnalysis, OodSampleAnalyzer};
+
+    fn bits(error: f64) -> f64 {
+        -log2(error)
+    }
+
+    #[test]
+    fn unique_decoding_bound() {
+        // with a large field, the commit-phase error is negligible and each query contributes
+        // log2(2 / (1 + rate)) bits; for rate 1/4 this is log2(8/5) ~ 0.678 bits per query
+        let error = FriSoundnessAnalysis::unique_decoding_bound(0.25, 80, 256, 1 << 20);
+        assert!((bits(error) - 80.0 * log2(8.0 / 5.0)).abs() < 1e-9);
+
+        // for rate 1/2, each query contributes log2(4/3) ~ 0.415 bits
+        let error = FriSoundnessAnalysis::unique_decoding_bound(0.5, 100, 256, 1 << 20);
+        assert!((bits(error) - 100.0 * log2(4.0 / 3.0)).abs() < 1e-9);
+
+        // with a 64-bit field and many queries, the commit-phase error of 20 rounds of folding
+        // over a domain of size 2^20 dominates: 64 - 20 - log2(20) ~ 39.68 bits
+        let error = FriSoundnessAnalysis::unique_decoding_bound(0.125, 400, 64, 1 << 20);
+        assert!((bits(error) - (44.0 - log2(20.0))).abs() < 1e-6);
+    }
+
+    #[test]
+    fn list_decoding_bound() {
+        // with a large field, the optimal m is large and each query contributes close to
+        // log2(1 / sqrt(rate)) bits, i.e., 1 bit per query for rate 1/4
+        let error = FriSoundnessAnalysis::list_decoding_bound(0.25, 80, 256, 1 << 20);
+        assert!(bits(error) < 80.0);
+        assert!(bits(error) > 79.9);
+
+        // the list decoding regime always yields more security per query than unique decoding
+        for (rate, num_queries) in [(0.5, 80), (0.25, 54), (0.125, 40), (0.0625, 30)] {
+            let unique =
+                FriSoundnessAnalysis::unique_decoding_bound(rate, num_queries, 192, 1 << 21);
+            let list = FriSoundnessAnalysis::list_decoding_bound(rate, num_queries, 192, 1 << 21);
+            assert!(list < unique);
+        }
+
+        // over a small field, the commit-phase error limits the achievable security regardless
+        // of the number of queries; it is minimized at m = 3 where it is equal to
+        // (m + 1/2)^7 * n^2 / (2 * rate^1.5 * |F|) ~ 2^-7.85
+        let error = FriSoundnessAnalysis::list_decoding_bound(0.125, 1000, 64, 1 << 20);
+        let expected = 64.0 - 40.0 - log2(0.5 * powf(3.5, 7.0) / powf(0.125, 1.5));
+        assert!((bits(error) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "rate must be in the range (0, 1)")]
+    fn invalid_rate() {
+        FriSoundnessAnalysis::list_decoding_bound(1.0, 80, 128, 1 << 20);
+    }
+
+    #[test]
+    fn ood_sample_security() {
+        // the error is degree * trace length * columns / |F|: 2^3 * 2^20 * 2^7 = 2^30
+        assert_eq!(98, OodSampleAnalyzer::security(128, 8, 1 << 20, 128));
+        assert_eq!(34, OodSampleAnalyzer::security(64, 8, 1 << 20, 128));
+
+        // the error bits are rounded up
+        assert_eq!(128 - 2 - 10 - 7, OodSampleAnalyzer::security(128, 3, 1 << 10, 100));
+
+        // a field which is too small cannot provide any security
+        assert_eq!(0, OodSampleAnalyzer::security(16, 16, 1 << 10, 16));
+    }
+
+    #[test]
+    fn ood_sample_required_extension() {
+        // a 64-bit field is never sufficient, but its quadratic extension suffices for small traces
+        assert_eq!(
+            FieldExtension::Quadratic,
+            OodSampleAnalyzer::required_extension(64, 2, 1 << 10, 2)
+        );
+        assert_eq!(
+            FieldExtension::Quadratic,
+            OodSampleAnalyzer::required_extension(64, 4, 1 << 16, 64)
+        );
+
+        // increasing the trace length requires a larger extension
+        assert_eq!(
+            FieldExtension::Cubic,
+            OodSampleAnalyzer::required_extension(64, 8, 1 << 20, 128)
+        );
+
+        // a 128-bit field suffices on its own for moderately sized computations
+        assert_eq!(FieldExtension::None, OodSampleAnalyzer::required_extension(128, 2, 1 << 16, 8));
+        assert_eq!(
+            FieldExtension::Quadratic,
+            OodSampleAnalyzer::required_extension(128, 8, 1 << 20, 128)
+        );
+
+        // larger extensions are never needed for larger base fields
+        let mut prev = FieldExtension::Cubic;
+        for base_field_bits in [32, 62, 64, 96, 128, 192] {
+            let extension = OodSampleAnalyzer::required_extension(base_field_bits, 4, 1 << 16, 50);
+            assert!(extension.degree() <= prev.degree());
+            prev = extension;
+        }
+    }
+}
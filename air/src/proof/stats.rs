@@ -0,0 +1,29 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+// PROOF STATS
+// ================================================================================================
+
+/// Size and security metrics of a STARK proof.
+///
+/// Stats are returned by [Proof::stats()](super::Proof::stats) and are computed from the
+/// structure of the proof alone, without running the verifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofStats {
+    /// Size of the serialized proof in bytes.
+    pub serialized_bytes: usize,
+    /// Number of FRI layers in the proof, excluding the remainder.
+    pub num_fri_layers: usize,
+    /// Total number of committed leaves opened by the proof; this includes openings of all trace
+    /// segment commitments, of the constraint commitment, and of all FRI layer commitments.
+    pub total_query_nodes: usize,
+    /// Number of field elements in the out-of-domain frame of the proof.
+    pub ood_frame_elements: usize,
+    /// Conjectured security level (in bits) of the proof, excluding collision resistance of the
+    /// hash function.
+    pub conjectured_security_bits: u32,
+    /// Number of leading zeros required in the proof-of-work nonce.
+    pub pow_bits: u32,
+}
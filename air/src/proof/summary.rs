@@ -0,0 +1,107 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::string::String;
+use core::fmt;
+
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// PROOF SUMMARY
+// ================================================================================================
+
+/// Concise summary of a STARK proof suitable for logging and monitoring.
+///
+/// A summary is returned by [Proof::summary()](super::Proof::summary) and contains basic metadata
+/// of a proof without any of its commitments or decommitments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofSummary {
+    /// Length of the execution trace of the computation described by the proof.
+    pub trace_length: usize,
+    /// Size of the LDE domain of the computation described by the proof.
+    pub lde_domain_size: usize,
+    /// Conjectured security level (in bits) of the proof, computed in the same way as by
+    /// [Proof::security_level()](super::Proof::security_level).
+    pub conjectured_security: u32,
+    /// Size of the serialized proof in bytes.
+    pub proof_size: usize,
+    /// Number of FRI layers in the proof, excluding the remainder.
+    pub num_fri_layers: usize,
+    /// Number of queries specified by the proof options.
+    pub num_queries: usize,
+    /// Number of unique query positions in the proof.
+    pub num_unique_queries: usize,
+    /// Number of leading zeros required in the proof-of-work nonce.
+    pub grinding_factor: u32,
+    /// Name of the hash function used to generate the proof (with module paths omitted).
+    pub hash_fn: String,
+}
+
+impl fmt::Display for ProofSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "trace length: {}", self.trace_length)?;
+        writeln!(f, "LDE domain size: {}", self.lde_domain_size)?;
+        writeln!(f, "conjectured security: {} bits", self.conjectured_security)?;
+        writeln!(f, "proof size: {} bytes", self.proof_size)?;
+        writeln!(f, "FRI layers: {}", self.num_fri_layers)?;
+        writeln!(f, "queries: {} ({} unique)", self.num_queries, self.num_unique_queries)?;
+        writeln!(f, "grinding factor: {}", self.grinding_factor)?;
+        write!(f, "hash function: {}", self.hash_fn)
+    }
+}
+
+impl Serializable for ProofSummary {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.trace_length);
+        target.write_usize(self.lde_domain_size);
+        target.write_u32(self.conjectured_security);
+        target.write_usize(self.proof_size);
+        target.write_usize(self.num_fri_layers);
+        target.write_usize(self.num_queries);
+        target.write_usize(self.num_unique_queries);
+        target.write_u32(self.grinding_factor);
+        self.hash_fn.write_into(target);
+    }
+}
+
+impl Deserializable for ProofSummary {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            trace_length: source.read_usize()?,
+            lde_domain_size: source.read_usize()?,
+            conjectured_security: source.read_u32()?,
+            proof_size: source.read_usize()?,
+            num_fri_layers: source.read_usize()?,
+            num_queries: source.read_usize()?,
+            num_unique_queries: source.read_usize()?,
+            grinding_factor: source.read_u32()?,
+            hash_fn: String::read_from(source)?,
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the name of type `T` with module paths omitted; for example, the name of
+/// `winter_crypto::hash::Blake3_256<winter_math::field::f64::BaseElement>` is
+/// `Blake3_256<BaseElement>`.
+pub(super) fn short_type_name<T: ?Sized>() -> String {
+    let name = core::any::type_name::<T>();
+    let mut result = String::with_capacity(name.len());
+    let mut segment_start = 0;
+    for (i, c) in name.char_indices() {
+        if matches!(c, '<' | '>' | ',' | ' ' | '(' | ')' | '[' | ']' | ';' | '&') {
+            result.push_str(last_path_segment(&name[segment_start..i]));
+            result.push(c);
+            segment_start = i + 1;
+        }
+    }
+    result.push_str(last_path_segment(&name[segment_start..]));
+    result
+}
+
+fn last_path_segment(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
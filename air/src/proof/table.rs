@@ -3,8 +3,8 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
-use core::iter::FusedIterator;
+use alloc::{borrow::Cow, vec::Vec};
+use core::{iter::FusedIterator, mem, slice::ChunksExact};
 
 use math::FieldElement;
 use utils::ByteReader;
@@ -48,12 +48,12 @@ impl<E: FieldElement> Table<E> {
     ) -> Result<Self, DeserializationError> {
         assert!(num_rows > 0, "number of rows must be greater than 0");
         assert!(
-            num_rows < MAX_ROWS,
+            num_rows <= MAX_ROWS,
             "number of rows cannot exceed {MAX_ROWS}, but was {num_rows}"
         );
         assert!(num_cols > 0, "number of columns must be greater than 0");
         assert!(
-            num_cols < MAX_ROWS,
+            num_cols <= MAX_COLS,
             "number of columns cannot exceed {MAX_COLS}, but was {num_cols}"
         );
 
@@ -85,7 +85,7 @@ impl<E: FieldElement> Table<E> {
     }
 
     /// Returns an iterator over rows of this table.
-    pub fn rows(&self) -> RowIterator<E> {
+    pub fn rows(&self) -> RowIterator<'_, E> {
         RowIterator::new(self)
     }
 
@@ -109,6 +109,110 @@ impl<E: FieldElement> Table<E> {
     }
 }
 
+// TABLE REFERENCE
+// ================================================================================================
+
+/// A two-dimensional table of field elements arranged in row-major order which may borrow its
+/// elements from the bytes it was parsed from.
+///
+/// When the internal representation of elements in `E` is the same as their canonical
+/// representation (i.e., [FieldElement::IS_CANONICAL] is true) and the source bytes are suitably
+/// aligned, the table re-interprets the source bytes as field elements without copying them.
+/// Otherwise, the elements are deserialized into a freshly allocated vector, exactly as
+/// [Table::from_bytes()] does. All fields provided by the math crate store their elements in
+/// Montgomery form, and thus, tables over these fields always copy their elements.
+#[derive(Debug, Clone)]
+pub struct TableRef<'a, E: FieldElement> {
+    data: Cow<'a, [E]>,
+    row_width: usize,
+}
+
+impl<'a, E: FieldElement> TableRef<'a, E> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [TableRef] instantiated with data from the provided bytes.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * Specified number of rows is 0 or greater than 255.
+    /// * Specified number of columns is 0 or greater than 255.
+    ///
+    /// # Errors
+    /// Returns an error if the provided bytes do not encode valid field elements required to fill
+    /// the table.
+    pub fn from_bytes(
+        bytes: &'a [u8],
+        num_rows: usize,
+        num_cols: usize,
+    ) -> Result<Self, DeserializationError> {
+        assert!(num_rows > 0, "number of rows must be greater than 0");
+        assert!(
+            num_rows <= MAX_ROWS,
+            "number of rows cannot exceed {MAX_ROWS}, but was {num_rows}"
+        );
+        assert!(num_cols > 0, "number of columns must be greater than 0");
+        assert!(
+            num_cols <= MAX_COLS,
+            "number of columns cannot exceed {MAX_COLS}, but was {num_cols}"
+        );
+
+        let num_elements = num_rows * num_cols;
+        let data = match borrow_elements(bytes, num_elements)? {
+            Some(elements) => Cow::Borrowed(elements),
+            None => Cow::Owned(SliceReader::new(bytes).read_many(num_elements)?),
+        };
+
+        Ok(Self { data, row_width: num_cols })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if elements of this table are borrowed from the bytes the table was parsed
+    /// from.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.data, Cow::Borrowed(_))
+    }
+
+    /// Returns number of rows in this table.
+    pub fn num_rows(&self) -> usize {
+        self.data.len() / self.row_width
+    }
+
+    /// Returns number of columns in this table.
+    pub fn num_columns(&self) -> usize {
+        self.row_width
+    }
+
+    /// Returns a reference to a row at the specified index.
+    pub fn get_row(&self, row_idx: usize) -> &[E] {
+        let row_offset = row_idx * self.row_width;
+        &self.data[row_offset..row_offset + self.row_width]
+    }
+
+    /// Returns an iterator over rows of this table.
+    pub fn rows(&self) -> ChunksExact<'_, E> {
+        self.data.chunks_exact(self.row_width)
+    }
+
+    /// Converts this table into a [Table], copying the elements if they are borrowed.
+    pub fn into_owned(self) -> Table<E> {
+        Table {
+            data: self.data.into_owned(),
+            row_width: self.row_width,
+        }
+    }
+}
+
+impl<E: FieldElement> From<Table<E>> for TableRef<'_, E> {
+    fn from(table: Table<E>) -> Self {
+        Self {
+            data: Cow::Owned(table.data),
+            row_width: table.row_width,
+        }
+    }
+}
+
 // COLUMN ITERATOR
 // ================================================================================================
 
@@ -145,3 +249,30 @@ impl<E: FieldElement> ExactSizeIterator for RowIterator<'_, E> {
 }
 
 impl<E: FieldElement> FusedIterator for RowIterator<'_, E> {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Re-interprets the first `num_elements` elements encoded in `bytes` as a slice of field
+/// elements, or returns `None` if the encoding of elements in `E` or the alignment of `bytes`
+/// does not permit this.
+///
+/// # Errors
+/// Returns an error if the elements can be re-interpreted, but any of them is not a valid field
+/// element.
+fn borrow_elements<E: FieldElement>(
+    bytes: &[u8],
+    num_elements: usize,
+) -> Result<Option<&[E]>, DeserializationError> {
+    if !E::IS_CANONICAL || bytes.len() < num_elements * E::ELEMENT_BYTES {
+        return Ok(None);
+    }
+    let bytes = &bytes[..num_elements * E::ELEMENT_BYTES];
+    if bytes.as_ptr().align_offset(mem::align_of::<E>()) != 0 {
+        return Ok(None);
+    }
+
+    // for canonical fields, the bytes of a valid element are the same as its internal
+    // representation, and so, the elements can be validated in place
+    math::bytes_as_elements(bytes).map(Some)
+}
@@ -3,9 +3,833 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::Proof;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crypto::{
+    hashers::{Blake3_192, Blake3_256, Rp62_248, Rp64_256, RpJive64_256, Sha3_256},
+    Digest, ElementHasher, Hasher, MerkleTree,
+};
+use math::{fields::f64::BaseElement, FieldElement, StarkField, ToElements};
+use utils::{ByteReader, Deserializable, DeserializationError, Serializable, SliceReader};
+
+use super::{
+    estimate_security, Commitments, Context, ContinuationState, OodFrame, Proof, ProofReader,
+    ProofSummary, ProofWriter, Queries, TableRef, TraceOodFrame, LEGACY_PROOF_VERSION,
+    PROOF_VERSION, UNLABELED_PROOF_VERSION,
+};
+use crate::{Assertion, FieldExtension, ProofDeserializeError, ProofOptions};
+
+mod canonical_field;
+use canonical_field::CanonicalElement;
+
+type Blake3 = Blake3_256<BaseElement>;
 
 #[test]
 pub fn starkproof_new_dummy_doesnt_panic() {
     let _ = Proof::new_dummy();
 }
+
+#[test]
+fn context_serialization_preserves_proof_version() {
+    let context = Proof::new_dummy().context;
+    assert_eq!(PROOF_VERSION, context.proof_version());
+
+    let bytes = context.to_bytes();
+    assert_eq!(PROOF_VERSION, bytes[0]);
+
+    let parsed = Context::read_from_bytes(&bytes).unwrap();
+    assert_eq!(context, parsed);
+}
+
+#[test]
+fn context_serialization_legacy_version() {
+    // contexts of legacy proofs do not record the number of constraint composition columns
+    let context = Proof::new_dummy().context;
+    let mut bytes = context.to_bytes();
+    bytes[0] = LEGACY_PROOF_VERSION;
+    bytes.pop();
+
+    let parsed = Context::read_from_bytes(&bytes).unwrap();
+    assert_eq!(LEGACY_PROOF_VERSION, parsed.proof_version());
+    assert_eq!(None, parsed.num_constraint_composition_columns());
+    assert_eq!(bytes, parsed.to_bytes());
+
+    // the number of columns is not absorbed into the transcript of legacy proofs either
+    let elements: Vec<BaseElement> = context.to_elements();
+    let legacy_elements: Vec<BaseElement> = parsed.to_elements();
+    assert_eq!(elements[..elements.len() - 1], legacy_elements[..]);
+}
+
+#[test]
+fn context_serialization_validates_num_composition_columns() {
+    // the dummy proof uses blowup factor 2, and thus, the composition polynomial can consist of
+    // at most two columns
+    let context = Proof::new_dummy().context;
+    assert_eq!(Some(1), context.num_constraint_composition_columns());
+
+    for num_columns in [0, 3] {
+        let mut bytes = context.to_bytes();
+        *bytes.last_mut().unwrap() = num_columns;
+        assert_eq!(
+            Err(DeserializationError::InvalidValue(format!(
+                "number of constraint composition columns must be between 1 and 2, but was \
+                {num_columns}"
+            ))),
+            Context::read_from_bytes(&bytes)
+        );
+    }
+}
+
+#[test]
+fn deserialize_versioned_round_trip() {
+    let proof = build_proof();
+    let parsed = Proof::deserialize_versioned(&proof.to_bytes()).unwrap();
+    assert_eq!(proof, parsed);
+}
+
+#[test]
+fn deserialize_versioned_older_versions() {
+    let proof = build_proof();
+    for version in [LEGACY_PROOF_VERSION, UNLABELED_PROOF_VERSION] {
+        let mut bytes = proof.to_bytes();
+        bytes[0] = version;
+        if version == LEGACY_PROOF_VERSION {
+            // legacy contexts do not record the number of constraint composition columns
+            bytes.remove(proof.context.to_bytes().len() - 1);
+        }
+
+        let parsed = Proof::deserialize_versioned(&bytes).unwrap();
+        assert_eq!(version, parsed.context.proof_version());
+        assert_eq!(bytes, parsed.to_bytes());
+    }
+}
+
+#[test]
+fn deserialize_versioned_unsupported_version() {
+    let mut bytes = build_proof().to_bytes();
+    bytes[0] = 7;
+    assert_eq!(
+        Err(ProofDeserializeError::UnsupportedVersion(7)),
+        Proof::deserialize_versioned(&bytes)
+    );
+}
+
+#[test]
+fn deserialize_versioned_invalid_proof() {
+    assert_eq!(
+        Err(ProofDeserializeError::InvalidProof(DeserializationError::UnexpectedEOF)),
+        Proof::deserialize_versioned(&[])
+    );
+
+    let bytes = build_proof().to_bytes();
+    let result = Proof::deserialize_versioned(&bytes[..bytes.len() - 1]);
+    assert!(matches!(result, Err(ProofDeserializeError::InvalidProof(_))));
+
+    let mut bytes = build_proof().to_bytes();
+    bytes.push(0);
+    assert_eq!(
+        Err(ProofDeserializeError::InvalidProof(DeserializationError::UnconsumedBytes)),
+        Proof::deserialize_versioned(&bytes)
+    );
+}
+
+#[test]
+fn stream_serialization_round_trip() {
+    let proof = build_proof();
+
+    let mut bytes = Vec::new();
+    proof.write_to(&mut bytes).unwrap();
+    assert_eq!(proof.to_bytes(), bytes);
+
+    let parsed = Proof::read_from_stream(&mut bytes.as_slice()).unwrap();
+    assert_eq!(proof, parsed);
+}
+
+#[test]
+fn stream_serialization_errors() {
+    let bytes = build_proof().to_bytes();
+
+    // writing into a buffer which is too small fails rather than panics
+    let mut buffer = vec![0u8; bytes.len() - 1];
+    let err = build_proof().write_to(&mut buffer.as_mut_slice()).unwrap_err();
+    assert_eq!(std::io::ErrorKind::WriteZero, err.kind());
+
+    // reading a truncated proof fails with an unexpected EOF
+    let err = Proof::read_from_stream(&mut &bytes[..bytes.len() - 1]).unwrap_err();
+    assert_eq!(std::io::ErrorKind::UnexpectedEof, err.kind());
+}
+
+#[test]
+fn file_round_trip() {
+    let proof = build_proof();
+    let path = temp_path("file_round_trip.bin");
+
+    ProofWriter::write(&proof, &path).unwrap();
+    assert_eq!(proof.to_bytes(), std::fs::read(&path).unwrap());
+    assert_eq!(proof, ProofReader::read(&path).unwrap());
+
+    // a file which does not contain a valid proof cannot be read
+    std::fs::write(&path, [0u8; 8]).unwrap();
+    let err = ProofReader::read(&path).unwrap_err();
+    assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+
+    std::fs::remove_file(&path).unwrap();
+    let err = ProofReader::read(&path).unwrap_err();
+    assert_eq!(std::io::ErrorKind::NotFound, err.kind());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn file_round_trip_json() {
+    let proof = build_proof();
+    let path = temp_path("file_round_trip.json");
+
+    ProofWriter::write_json(&proof, &path).unwrap();
+    let document: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+    assert_eq!(Some(proof.context.proof_version() as u64), document["version"].as_u64());
+    assert_eq!(proof, ProofReader::read(&path).unwrap());
+
+    // the version of the document must match the version of the proof
+    let mut document = document;
+    document["version"] = serde_json::json!(0);
+    std::fs::write(&path, document.to_string()).unwrap();
+    let err = ProofReader::read(&path).unwrap_err();
+    assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn commitments_round_trip() {
+    check_commitments_round_trip::<Blake3_256<BaseElement>>();
+    check_commitments_round_trip::<Blake3_192<BaseElement>>();
+    check_commitments_round_trip::<Sha3_256<BaseElement>>();
+    check_commitments_round_trip::<Rp64_256>();
+    check_commitments_round_trip::<RpJive64_256>();
+    check_commitments_round_trip::<Rp62_248>();
+}
+
+#[test]
+#[allow(deprecated)]
+fn commitments_add() {
+    let digests: Vec<_> = (0..5_u8).map(|i| Blake3::hash(&[i])).collect();
+    let mut commitments = Commitments::default();
+    for digest in digests.iter() {
+        commitments.add::<Blake3>(digest);
+    }
+    assert_eq!(
+        Commitments::new::<Blake3>(vec![digests[0]], digests[1], digests[2..].to_vec()),
+        commitments
+    );
+}
+
+#[test]
+fn commitments_parse_invalid_length() {
+    let digests: Vec<_> = (0..5_u8).map(|i| Blake3::hash(&[i])).collect();
+    let commitments =
+        Commitments::new::<Blake3>(vec![digests[0]], digests[1], digests[2..].to_vec());
+
+    // the commitments contain 1 trace root, 1 constraint root, and 3 FRI roots (2 layers plus
+    // the remainder); parsing with any other number of digests fails
+    assert!(commitments.clone().parse::<Blake3>(1, 2).is_ok());
+    for (num_trace_segments, num_fri_layers) in [(1, 1), (1, 3), (2, 2), (0, 2)] {
+        let expected = (num_trace_segments + num_fri_layers + 2) * 32;
+        let err = commitments
+            .clone()
+            .parse::<Blake3>(num_trace_segments, num_fri_layers)
+            .unwrap_err();
+        assert_eq!(
+            DeserializationError::InvalidValue(format!(
+                "expected {expected} bytes for {} commitments of 32 bytes each, but was 160",
+                expected / 32
+            )),
+            err
+        );
+    }
+
+    // commitments of a hash function with a different digest size are rejected
+    assert!(commitments.parse::<Blake3_192<BaseElement>>(1, 2).is_err());
+
+    // truncated commitments are rejected
+    let mut bytes = Commitments::new::<Blake3>(vec![digests[0]], digests[1], vec![]).to_bytes();
+    bytes[0] -= 1;
+    bytes.pop();
+    let truncated = Commitments::read_from_bytes(&bytes).unwrap();
+    assert!(truncated.parse::<Blake3>(1, 0).is_err());
+}
+
+#[test]
+fn ood_frame_serialization() {
+    let current = vec![BaseElement::new(1), BaseElement::new(2)];
+    let next = vec![BaseElement::new(3), BaseElement::new(4)];
+    let evaluations = vec![BaseElement::new(5), BaseElement::new(6)];
+
+    let mut ood_frame = OodFrame::default();
+    ood_frame.set_trace_states::<_, Blake3>(&TraceOodFrame::new(
+        vec![current.clone(), next.clone()],
+        2,
+        None,
+    ));
+    ood_frame.set_constraint_evaluations(&evaluations);
+
+    let bytes = ood_frame.to_bytes();
+    let parsed = OodFrame::read_from_bytes(&bytes).unwrap();
+    assert_eq!(ood_frame, parsed);
+    assert!(OodFrame::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    let (trace_frame, parsed_evaluations) =
+        parsed.clone().parse::<BaseElement>(2, 0, 2, 2).unwrap();
+    assert_eq!(current, trace_frame.current_row());
+    assert_eq!(next, trace_frame.next_row());
+    assert_eq!(evaluations, parsed_evaluations);
+
+    // requesting more evaluations than the frame contains should fail at the end of evaluations
+    let err = parsed.parse::<BaseElement>(2, 0, 2, 3).err().unwrap();
+    assert_eq!(&DeserializationError::UnexpectedEOF, err.kind());
+    assert_eq!(Some(16), err.offset());
+    assert_eq!(
+        "unexpected EOF at offset 16 while reading out-of-domain constraint evaluations",
+        err.to_string()
+    );
+}
+
+#[test]
+fn ood_frame_serialization_wide_frame() {
+    let rows: Vec<Vec<BaseElement>> = (0..3)
+        .map(|i| vec![BaseElement::new(2 * i), BaseElement::new(2 * i + 1)])
+        .collect();
+    let evaluations = vec![BaseElement::new(7)];
+
+    let mut ood_frame = OodFrame::default();
+    ood_frame.set_trace_states::<_, Blake3>(&TraceOodFrame::new(rows.clone(), 1, None));
+    ood_frame.set_constraint_evaluations(&evaluations);
+
+    let (trace_frame, parsed_evaluations) =
+        ood_frame.clone().parse::<BaseElement>(1, 1, 3, 1).unwrap();
+    assert_eq!(3, trace_frame.frame_size());
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(row.as_slice(), trace_frame.row(i));
+    }
+    assert_eq!(rows[2][..1], trace_frame.main_frame().row(2)[..]);
+    assert_eq!(rows[2][1..], trace_frame.aux_frame().unwrap().row(2)[..]);
+    assert_eq!(evaluations, parsed_evaluations);
+
+    // a frame of 3 rows cannot be parsed as a frame of any other size
+    let err = ood_frame.parse::<BaseElement>(1, 1, 2, 1).err().unwrap();
+    assert_eq!(
+        DeserializationError::InvalidValue(
+            "out-of-domain trace frame must contain 2 rows, but was 3".to_string()
+        ),
+        err
+    );
+}
+
+#[test]
+fn ood_frame_size() {
+    use math::fields::f128;
+
+    // for a trace with 128 columns and 8 constraint composition columns, the frame should contain
+    // 2 * 128 trace evaluations and 8 constraint evaluations, 16 bytes each
+    let trace_width = 128;
+    let num_evaluations = 8;
+    let row = vec![f128::BaseElement::ONE; trace_width];
+    let mut ood_frame = OodFrame::default();
+    ood_frame.set_trace_states::<_, Blake3_256<f128::BaseElement>>(&TraceOodFrame::new(
+        vec![row.clone(), row],
+        trace_width,
+        None,
+    ));
+    ood_frame.set_constraint_evaluations(&vec![f128::BaseElement::ONE; num_evaluations]);
+
+    // each of the 3 sections is prefixed with its length (2 bytes); trace states also include
+    // the frame size (1 byte), and the Lagrange kernel section contains only its frame size
+    let expected_size = 3 * 2 + (1 + 2 * trace_width * 16) + 1 + num_evaluations * 16;
+    assert_eq!(expected_size, ood_frame.to_bytes().len());
+    assert_eq!(expected_size, ood_frame.get_size_hint());
+}
+
+#[test]
+fn queries_serialization() {
+    let leaves = [
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+        BaseElement::new(4),
+    ]
+    .iter()
+    .map(|&e| Blake3::hash_elements(&[e]))
+    .collect::<Vec<_>>();
+    let tree = MerkleTree::<Blake3>::new(leaves).unwrap();
+    let (_, opening_proof) = tree.prove_batch(&[1, 2]).unwrap();
+    let queries = Queries::new::<Blake3, BaseElement, MerkleTree<Blake3>>(
+        opening_proof,
+        vec![vec![BaseElement::new(2)], vec![BaseElement::new(3)]],
+    );
+
+    let bytes = queries.to_bytes();
+    let parsed = Queries::read_from_bytes(&bytes).unwrap();
+    assert_eq!(queries, parsed);
+    assert!(Queries::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    let (_, table) = parsed.parse::<BaseElement, Blake3, MerkleTree<Blake3>>(4, 2, 1).unwrap();
+    assert_eq!(&[BaseElement::new(3)][..], table.get_row(1));
+}
+
+#[test]
+fn queries_non_canonical_values() {
+    use math::{fields::f62, StarkField};
+    type Blake3F62 = Blake3_256<f62::BaseElement>;
+
+    let values = [f62::BaseElement::new(1), f62::BaseElement::new(2)];
+    let leaves = values.iter().map(|&e| Blake3F62::hash_elements(&[e])).collect::<Vec<_>>();
+    let tree = MerkleTree::<Blake3F62>::new(leaves).unwrap();
+    let (_, opening_proof) = tree.prove_batch(&[0]).unwrap();
+    let queries = Queries::new::<Blake3F62, f62::BaseElement, MerkleTree<Blake3F62>>(
+        opening_proof,
+        vec![vec![values[0]]],
+    );
+
+    let result = queries
+        .clone()
+        .parse::<f62::BaseElement, Blake3F62, MerkleTree<Blake3F62>>(2, 1, 1);
+    assert!(result.is_ok());
+
+    // bump the query value by the field modulus; the value is still congruent to the original
+    // value, but its encoding is not canonical
+    let mut bytes = queries.to_bytes();
+    let mut reader = SliceReader::new(&bytes);
+    reader.read_usize().unwrap();
+    let offset = reader.position();
+    let value = u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+    let bumped = (value + f62::BaseElement::MODULUS).to_le_bytes();
+    assert_eq!(values[0], f62::BaseElement::from_le_bytes_reduce(bumped));
+    bytes[offset..offset + 8].copy_from_slice(&bumped);
+
+    let queries = Queries::read_from_bytes(&bytes).unwrap();
+    let result = queries.parse::<f62::BaseElement, Blake3F62, MerkleTree<Blake3F62>>(2, 1, 1);
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+}
+
+#[test]
+fn queries_parse_ref_matches_parse() {
+    use math::fields::{f128, QuadExtension};
+
+    fn check<E: FieldElement>(values: Vec<E>) {
+        type H<B> = Blake3_256<B>;
+        let leaves = values.iter().map(|e| H::<E::BaseField>::hash_elements(&[*e])).collect();
+        let tree = MerkleTree::<H<E::BaseField>>::new(leaves).unwrap();
+        let (_, opening_proof) = tree.prove_batch(&[1, 2, 3]).unwrap();
+        let queries = Queries::new::<H<E::BaseField>, E, MerkleTree<H<E::BaseField>>>(
+            opening_proof,
+            vec![
+                vec![values[1], values[0]],
+                vec![values[2], values[1]],
+                vec![values[3], values[2]],
+            ],
+        );
+
+        let (proof_ref, table_ref) = queries
+            .parse_ref::<E, H<E::BaseField>, MerkleTree<H<E::BaseField>>>(4, 3, 2)
+            .unwrap();
+        let (proof, table) = queries
+            .clone()
+            .parse::<E, H<E::BaseField>, MerkleTree<H<E::BaseField>>>(4, 3, 2)
+            .unwrap();
+        assert_eq!(proof, proof_ref);
+        assert_eq!(E::IS_CANONICAL, table_ref.is_borrowed());
+        assert_eq!(table.num_rows(), table_ref.num_rows());
+        assert_eq!(table.num_columns(), table_ref.num_columns());
+        assert!(table.rows().eq(table_ref.rows()));
+
+        // misaligned bytes are always copied
+        let bytes = queries.to_bytes();
+        let mut reader = SliceReader::new(&bytes);
+        reader.read_usize().unwrap();
+        let offset = reader.position();
+        let mut shifted = vec![0u8; 6 * E::ELEMENT_BYTES + 1];
+        shifted[1..].copy_from_slice(&bytes[offset..offset + 6 * E::ELEMENT_BYTES]);
+        let table_ref = TableRef::<E>::from_bytes(&shifted[1..], 3, 2).unwrap();
+        assert!(!table_ref.is_borrowed());
+        assert!(table.rows().eq(table_ref.rows()));
+        assert!(table_ref.into_owned().rows().eq(table.rows()));
+    }
+
+    check((1..5u32).map(BaseElement::from).collect::<Vec<_>>());
+    check((1..5u32).map(f128::BaseElement::from).collect::<Vec<_>>());
+    check(
+        (1..5u32)
+            .map(|i| QuadExtension::new(BaseElement::from(i), BaseElement::from(i * 7)))
+            .collect::<Vec<_>>(),
+    );
+
+    // elements of the built-in fields are stored in Montgomery form, and so, values are borrowed
+    // from the proof bytes only for fields with the canonical representation
+    check((1..5u32).map(CanonicalElement::from).collect::<Vec<_>>());
+}
+
+#[test]
+fn table_ref_borrowed_elements() {
+    let to_bytes = |words: &[u64]| {
+        // SAFETY: the bytes of u64 values are always valid, and the slice does not outlive words
+        unsafe { core::slice::from_raw_parts(words.as_ptr() as *const u8, words.len() * 8) }
+    };
+
+    // aligned canonical values are borrowed without copying
+    let words = [1, 2, 3, 4, 5, 6];
+    let table = TableRef::<CanonicalElement>::from_bytes(to_bytes(&words), 3, 2).unwrap();
+    assert!(table.is_borrowed());
+    assert_eq!(to_bytes(&words).as_ptr(), table.get_row(0).as_ptr() as *const u8);
+    assert_eq!([CanonicalElement::new(5), CanonicalElement::new(6)], table.get_row(2));
+
+    // values which are not valid field elements are rejected rather than borrowed
+    let words = [1, 2, CanonicalElement::MODULUS, 4, 5, 6];
+    assert!(matches!(
+        TableRef::<CanonicalElement>::from_bytes(to_bytes(&words), 3, 2),
+        Err(DeserializationError::InvalidValue(_))
+    ));
+}
+
+#[test]
+fn table_ref_max_columns() {
+    let bytes = BaseElement::elements_as_bytes(&[BaseElement::ONE; 255]).to_vec();
+    let table = TableRef::<BaseElement>::from_bytes(&bytes, 1, 255).unwrap();
+    assert_eq!(255, table.get_row(0).len());
+}
+
+#[test]
+#[should_panic(expected = "number of columns cannot exceed 255, but was 256")]
+fn table_ref_too_many_columns() {
+    let bytes = BaseElement::elements_as_bytes(&[BaseElement::ONE; 256]).to_vec();
+    let _ = TableRef::<BaseElement>::from_bytes(&bytes, 1, 256);
+}
+
+#[test]
+fn queries_parse_ref_errors() {
+    let leaves = (0..4u32).map(|i| Blake3::hash_elements(&[BaseElement::from(i)])).collect();
+    let tree = MerkleTree::<Blake3>::new(leaves).unwrap();
+    let (_, opening_proof) = tree.prove_batch(&[1]).unwrap();
+    let queries = Queries::new::<Blake3, BaseElement, MerkleTree<Blake3>>(
+        opening_proof,
+        vec![vec![BaseElement::new(1)]],
+    );
+
+    // the borrowed parser rejects the same malformed queries as the copying parser
+    for (num_queries, domain_size) in [(2, 4), (1, 8)] {
+        let expected = queries
+            .clone()
+            .parse::<BaseElement, Blake3, MerkleTree<Blake3>>(domain_size, num_queries, 1)
+            .err()
+            .unwrap();
+        let result = queries.parse_ref::<BaseElement, Blake3, MerkleTree<Blake3>>(
+            domain_size,
+            num_queries,
+            1,
+        );
+        assert_eq!(Some(expected), result.err());
+    }
+
+    // values greater than the field modulus are rejected
+    let bytes = u64::MAX.to_le_bytes();
+    assert!(matches!(
+        TableRef::<BaseElement>::from_bytes(&bytes, 1, 1),
+        Err(DeserializationError::InvalidValue(_))
+    ));
+}
+
+#[test]
+fn queries_metadata() {
+    let leaves = (0..16u32).map(|i| Blake3::hash_elements(&[BaseElement::from(i)])).collect();
+    let tree = MerkleTree::<Blake3>::new(leaves).unwrap();
+    let digest_size = 32;
+
+    for positions in [vec![3], vec![0, 9], vec![1, 2, 7, 8, 15]] {
+        let (_, opening_proof) = tree.prove_batch(&positions).unwrap();
+        let values = positions.iter().map(|&p| vec![BaseElement::from(p as u32); 3]).collect();
+        let queries =
+            Queries::new::<Blake3, BaseElement, MerkleTree<Blake3>>(opening_proof, values);
+
+        assert_eq!(Some(positions.len()), queries.num_queries());
+        assert_eq!(positions.len() * 3 * BaseElement::ELEMENT_BYTES, queries.values_byte_len());
+        assert_eq!(Some(3 * BaseElement::ELEMENT_BYTES), queries.leaf_width(digest_size));
+        assert_eq!(None, queries.leaf_width(digest_size + 1));
+
+        let bytes = queries.to_bytes();
+        let mut reader = SliceReader::new(&bytes);
+        let values = Vec::<u8>::read_from(&mut reader).unwrap();
+        let paths = Vec::<u8>::read_from(&mut reader).unwrap();
+        assert_eq!(paths.len(), queries.paths_byte_len());
+
+        let build = |values: &[u8], paths: &[u8]| {
+            let mut bytes = Vec::new();
+            values.to_vec().write_into(&mut bytes);
+            paths.to_vec().write_into(&mut bytes);
+            Queries::read_from_bytes(&bytes).unwrap()
+        };
+
+        // truncated or extended Merkle paths are inconsistent with their length prefixes
+        let truncated = build(&values, &paths[..paths.len() - 1]);
+        assert_eq!(Some(positions.len()), truncated.num_queries());
+        assert_eq!(None, truncated.leaf_width(digest_size));
+        let extended = build(&values, &[paths.as_slice(), &[0]].concat());
+        assert_eq!(None, extended.leaf_width(digest_size));
+
+        // query values which do not divide evenly among queries are inconsistent
+        if positions.len() > 1 {
+            let truncated = build(&values[..values.len() - 1], &paths);
+            assert_eq!(None, truncated.leaf_width(digest_size));
+        }
+
+        // the number of queries is read from the header of the opening proof
+        let mut corrupted = paths.clone();
+        corrupted[1] = (((positions.len() + 1) << 1) | 1) as u8;
+        let corrupted = build(&values, &corrupted);
+        assert_eq!(Some(positions.len() + 1), corrupted.num_queries());
+        assert_eq!(None, corrupted.leaf_width(digest_size));
+
+        let mut corrupted = paths.clone();
+        corrupted[1] = 1;
+        assert_eq!(None, build(&values, &corrupted).num_queries());
+
+        let mut corrupted = paths.clone();
+        corrupted[0] = 64;
+        assert_eq!(None, build(&values, &corrupted).num_queries());
+
+        assert_eq!(None, build(&values, &[]).num_queries());
+    }
+}
+
+#[test]
+fn continuation_state_assertions() {
+    let state = ContinuationState::new(
+        vec![BaseElement::new(1), BaseElement::new(2)],
+        vec![BaseElement::new(3)],
+    );
+    let assertions = state.to_assertions(&[2, 0], &[1], 8);
+    assert_eq!(
+        vec![
+            Assertion::single(2, 0, BaseElement::new(1)),
+            Assertion::single(0, 0, BaseElement::new(2)),
+            Assertion::single(1, 7, BaseElement::new(3)),
+        ],
+        assertions
+    );
+}
+
+#[test]
+#[should_panic(expected = "expected 1 output columns, but was 2")]
+fn continuation_state_assertions_width_mismatch() {
+    let state = ContinuationState::new(vec![BaseElement::ONE], vec![BaseElement::ONE]);
+    state.to_assertions(&[0], &[0, 1], 8);
+}
+
+#[test]
+fn proof_summary() {
+    let proof = build_proof();
+    let summary = proof.summary::<Blake3_192<BaseElement>>();
+    assert_eq!(
+        ProofSummary {
+            trace_length: proof.trace_info().length(),
+            lde_domain_size: proof.lde_domain_size(),
+            conjectured_security: proof.security_level::<Blake3_192<BaseElement>>(true),
+            proof_size: proof.to_bytes().len(),
+            num_fri_layers: proof.fri_proof.num_layers(),
+            num_queries: proof.options().num_queries(),
+            num_unique_queries: proof.num_unique_queries as usize,
+            grinding_factor: proof.options().grinding_factor(),
+            hash_fn: "Blake3_192<BaseElement>".to_string(),
+        },
+        summary
+    );
+    assert_eq!(summary, ProofSummary::read_from_bytes(&summary.to_bytes()).unwrap());
+
+    // the summary of a proof is printed on separate lines, one line per property
+    let printed = summary.to_string();
+    assert_eq!(8, printed.lines().count());
+    assert!(printed.contains("hash function: Blake3_192<BaseElement>"));
+}
+
+#[test]
+fn proof_display() {
+    let proof = build_proof();
+    let summary = proof.summary::<Blake3_256<BaseElement>>();
+
+    // the printed proof contains the same properties as the summary, except for the hash function
+    let printed = proof.to_string();
+    let summary_lines = summary.to_string();
+    let summary_lines = summary_lines.lines().collect::<Vec<_>>();
+    for (i, line) in printed.lines().enumerate() {
+        if i == 2 {
+            let expected = format!("{} (excluding hash function)", summary_lines[i]);
+            assert_eq!(expected, line);
+        } else {
+            assert_eq!(summary_lines[i], line);
+        }
+    }
+    assert_eq!(summary_lines.len() - 1, printed.lines().count());
+}
+
+#[test]
+fn proof_stats() {
+    let proof = build_proof();
+    let stats = proof.stats();
+    assert_eq!(proof.to_bytes().len(), stats.serialized_bytes);
+    assert_eq!(proof.fri_proof.num_layers(), stats.num_fri_layers);
+    assert_eq!(proof.options().grinding_factor(), stats.pow_bits);
+
+    // the conjectured security excludes only the hash function
+    assert_eq!(
+        proof.security_level::<Blake3_256<BaseElement>>(true),
+        stats.conjectured_security_bits
+    );
+
+    // the dummy proof contains a single trace segment and a single constraint evaluation, and
+    // its OOD frame is empty
+    let mut proof = proof;
+    proof.num_unique_queries = 3;
+    assert_eq!(2 * 3, proof.stats().total_query_nodes);
+    assert_eq!(0, proof.stats().ood_frame_elements);
+
+    let row = vec![BaseElement::ONE; 4];
+    proof
+        .ood_frame
+        .set_trace_states::<_, Blake3_256<BaseElement>>(&TraceOodFrame::new(
+            vec![row.clone(), row],
+            4,
+            None,
+        ));
+    proof.ood_frame.set_constraint_evaluations(&[BaseElement::ONE; 2]);
+    let stats = proof.stats();
+    assert_eq!(2 * 4 + 2, stats.ood_frame_elements);
+    assert_eq!(proof.to_bytes().len(), stats.serialized_bytes);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that commitments built from digests of `H` survive serialization and parsing.
+fn check_commitments_round_trip<H: Hasher>() {
+    let digests: Vec<_> = (0..6_u8).map(|i| H::hash(&[i])).collect();
+    let commitments =
+        Commitments::new::<H>(digests[..2].to_vec(), digests[2], digests[3..].to_vec());
+
+    let bytes = commitments.to_bytes();
+    let parsed = Commitments::read_from_bytes(&bytes).unwrap().parse::<H>(2, 2).unwrap();
+    assert_eq!(digests[0], parsed.trace_root());
+    assert_eq!(Some(digests[1]), parsed.aux_trace_root());
+    assert_eq!(&digests[..2], parsed.trace_roots());
+    assert_eq!(digests[2], parsed.constraint_root());
+    assert_eq!(&digests[3..], parsed.fri_roots());
+
+    // the same bytes cannot be parsed with a different number of trace segments or FRI layers
+    assert!(commitments.clone().parse::<H>(1, 2).is_err());
+    assert!(commitments.parse::<H>(2, 3).is_err());
+}
+
+/// Returns a dummy proof which contains queries for its single trace segment, so that it can be
+/// serialized and then parsed back.
+/// Returns a path to a file named `name` in the temporary directory which is unique to the
+/// current process.
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("winter_air_{}_{name}", std::process::id()))
+}
+
+fn build_proof() -> Proof {
+    let mut proof = Proof::new_dummy();
+    proof.trace_queries = vec![proof.constraint_queries.clone()];
+    proof
+}
+
+#[test]
+fn proof_soundness_error() {
+    let proof = build_proof();
+    let error = proof.soundness_error::<Blake3_256<BaseElement>>();
+    let security = proof.security_level::<Blake3_256<BaseElement>>(true);
+    assert!(error > 0.0 && error < 1.0);
+    assert!((-error.log2() - security as f64).abs() < 2.0);
+
+    // a weaker hash function can only increase the soundness error
+    assert!(proof.soundness_error::<Blake3_192<BaseElement>>() >= error);
+}
+
+#[test]
+fn estimate_security_matches_security_level() {
+    let proof = build_proof();
+    for conjectured in [true, false] {
+        assert_eq!(
+            proof.security_level::<Blake3>(conjectured),
+            estimate_security::<Blake3>(
+                proof.options(),
+                BaseElement::MODULUS_BITS,
+                proof.trace_info().length(),
+                conjectured
+            )
+        );
+    }
+}
+
+#[test]
+fn estimate_security_degenerate_parameters() {
+    let options = ProofOptions::new(32, 8, 0, FieldExtension::Quadratic, 8, 31);
+    let estimate = |trace_length, conjectured| {
+        estimate_security::<Blake3>(&options, 64, trace_length, conjectured)
+    };
+
+    // the analysis of provable security does not apply to traces which are too short
+    for trace_length in [0, 1, 2, 4, 5] {
+        assert_eq!(0, estimate(trace_length, false));
+        assert_eq!(95, estimate(trace_length, true));
+    }
+    assert!(estimate(8, false) > 0);
+
+    // LDE domain sizes which do not fit into usize saturate
+    assert_eq!(128 - usize::BITS, estimate(usize::MAX, true));
+    assert_eq!(0, estimate(usize::MAX, false));
+
+    // field security which is fully consumed by the domain does not underflow
+    assert_eq!(0, estimate_security::<Blake3>(&options, 16, usize::MAX, true));
+}
+
+#[test]
+fn proof_digest() {
+    let mut proof = build_proof();
+    let states = vec![
+        vec![BaseElement::new(1), BaseElement::new(2)],
+        vec![BaseElement::new(3), BaseElement::new(4)],
+    ];
+    proof
+        .ood_frame
+        .set_trace_states::<_, Blake3>(&TraceOodFrame::new(states, 2, None));
+    proof.ood_frame.set_constraint_evaluations(&[BaseElement::new(5)]);
+    proof.pow_nonce = 42;
+
+    let digest = proof.digest::<Blake3>();
+    assert_eq!(proof.clone(), proof);
+    assert_eq!(digest, proof.clone().digest::<Blake3>());
+    assert_eq!(Blake3::hash(&proof.to_bytes()), digest);
+
+    // the digest of the proof is fixed for a given proof format version
+    assert_eq!(PROOF_VERSION, proof.to_bytes()[0]);
+    assert_eq!(
+        "e53b7e0d37e5ffc917850fcf3c199cd803a5f811e0623441f7f3e120fefa7fb6",
+        digest.as_bytes().iter().map(|b| format!("{b:02x}")).collect::<String>()
+    );
+
+    // flipping a byte in the out-of-domain frame changes the digest
+    let mut bytes = proof.to_bytes();
+    let ood_bytes = proof.ood_frame.to_bytes();
+    let ood_offset = bytes.windows(ood_bytes.len()).position(|w| w == ood_bytes).unwrap();
+    bytes[ood_offset + ood_bytes.len() - 1] ^= 1;
+    let tampered = Proof::from_bytes(&bytes).unwrap();
+    assert_ne!(proof, tampered);
+    assert_ne!(digest, tampered.digest::<Blake3>());
+
+    // the proof-of-work nonce is covered by the digest
+    let mut tampered = proof.clone();
+    tampered.pow_nonce += 1;
+    assert_ne!(proof, tampered);
+    assert_ne!(digest, tampered.digest::<Blake3>());
+}
@@ -0,0 +1,260 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! A prime field whose elements are stored in the canonical representation.
+//!
+//! All fields provided by the math crate store their elements in Montgomery form, and thus,
+//! proof data over these fields can never be borrowed by a [TableRef](super::TableRef). This
+//! field is used to exercise the borrowed parsing path; it is the 64-bit field with modulus
+//! 2^64 - 2^32 + 1, and all arithmetic is delegated to the Montgomery implementation of this
+//! field in the math crate.
+
+use alloc::{format, string::ToString, vec::Vec};
+use core::{
+    fmt::{self, Display, Formatter},
+    mem, ops, slice,
+};
+
+use math::{fields::f64::BaseElement as MontElement, FieldElement, StarkField};
+use utils::{
+    AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError, Randomizable,
+    Serializable,
+};
+
+const M: u64 = MontElement::MODULUS;
+
+/// An element of the 64-bit field with modulus 2^64 - 2^32 + 1 stored as a canonical integer.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct CanonicalElement(u64);
+
+impl CanonicalElement {
+    /// Returns a new element for the specified value reduced by the field modulus.
+    pub const fn new(value: u64) -> Self {
+        Self(value % M)
+    }
+
+    fn mont(self) -> MontElement {
+        MontElement::new(self.0)
+    }
+
+    fn from_mont(element: MontElement) -> Self {
+        Self(element.as_int())
+    }
+}
+
+impl FieldElement for CanonicalElement {
+    type PositiveInteger = u64;
+    type BaseField = Self;
+
+    const EXTENSION_DEGREE: usize = 1;
+    const ELEMENT_BYTES: usize = 8;
+    const IS_CANONICAL: bool = true;
+
+    const ZERO: Self = Self(0);
+    const ONE: Self = Self(1);
+
+    fn inv(self) -> Self {
+        Self::from_mont(self.mont().inv())
+    }
+
+    fn inv_ct(self) -> Self {
+        Self::from_mont(self.mont().inv_ct())
+    }
+
+    fn conjugate(&self) -> Self {
+        *self
+    }
+
+    fn base_element(&self, i: usize) -> Self::BaseField {
+        assert_eq!(i, 0, "element index must be 0, but was {i}");
+        *self
+    }
+
+    fn slice_as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
+        elements
+    }
+
+    fn slice_from_base_elements(elements: &[Self::BaseField]) -> &[Self] {
+        elements
+    }
+
+    fn elements_as_bytes(elements: &[Self]) -> &[u8] {
+        // SAFETY: the element is a transparent wrapper around u64
+        unsafe {
+            slice::from_raw_parts(
+                elements.as_ptr() as *const u8,
+                elements.len() * Self::ELEMENT_BYTES,
+            )
+        }
+    }
+
+    unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError> {
+        if bytes.len() % Self::ELEMENT_BYTES != 0 {
+            return Err(DeserializationError::InvalidValue(
+                "number of bytes does not divide into whole number of elements".to_string(),
+            ));
+        }
+        if bytes.as_ptr().align_offset(mem::align_of::<u64>()) != 0 {
+            return Err(DeserializationError::InvalidValue(
+                "slice memory alignment is not valid for this field element type".to_string(),
+            ));
+        }
+        Ok(slice::from_raw_parts(
+            bytes.as_ptr() as *const Self,
+            bytes.len() / Self::ELEMENT_BYTES,
+        ))
+    }
+}
+
+impl StarkField for CanonicalElement {
+    const MODULUS: Self::PositiveInteger = M;
+    const MODULUS_BITS: u32 = 64;
+    const GENERATOR: Self = Self(7);
+    const TWO_ADICITY: u32 = MontElement::TWO_ADICITY;
+    const TWO_ADIC_ROOT_OF_UNITY: Self = Self(7277203076849721926);
+
+    fn get_modulus_le_bytes() -> Vec<u8> {
+        M.to_le_bytes().to_vec()
+    }
+
+    fn as_int(&self) -> Self::PositiveInteger {
+        self.0
+    }
+}
+
+// ARITHMETIC
+// ================================================================================================
+
+macro_rules! impl_op {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+        impl ops::$op for CanonicalElement {
+            type Output = Self;
+
+            fn $fn(self, rhs: Self) -> Self {
+                Self::from_mont(ops::$op::$fn(self.mont(), rhs.mont()))
+            }
+        }
+
+        impl ops::$op_assign for CanonicalElement {
+            fn $fn_assign(&mut self, rhs: Self) {
+                *self = ops::$op::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, AddAssign, add_assign);
+impl_op!(Sub, sub, SubAssign, sub_assign);
+impl_op!(Mul, mul, MulAssign, mul_assign);
+impl_op!(Div, div, DivAssign, div_assign);
+
+impl ops::Neg for CanonicalElement {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_mont(-self.mont())
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<u8> for CanonicalElement {
+    fn from(value: u8) -> Self {
+        Self(value as u64)
+    }
+}
+
+impl From<u16> for CanonicalElement {
+    fn from(value: u16) -> Self {
+        Self(value as u64)
+    }
+}
+
+impl From<u32> for CanonicalElement {
+    fn from(value: u32) -> Self {
+        Self(value as u64)
+    }
+}
+
+impl TryFrom<u64> for CanonicalElement {
+    type Error = DeserializationError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value >= M {
+            return Err(DeserializationError::InvalidValue(format!(
+                "value {value} is greater than or equal to the field modulus"
+            )));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<u128> for CanonicalElement {
+    type Error = DeserializationError;
+
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        let value = u64::try_from(value).map_err(|_| {
+            DeserializationError::InvalidValue(format!(
+                "value {value} is greater than or equal to the field modulus"
+            ))
+        })?;
+        Self::try_from(value)
+    }
+}
+
+impl TryFrom<&'_ [u8]> for CanonicalElement {
+    type Error = DeserializationError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 8] = bytes.try_into().map_err(|_| {
+            DeserializationError::InvalidValue(format!(
+                "a field element must be encoded in 8 bytes, but was {} bytes",
+                bytes.len()
+            ))
+        })?;
+        Self::try_from(u64::from_le_bytes(bytes))
+    }
+}
+
+impl AsBytes for CanonicalElement {
+    fn as_bytes(&self) -> &[u8] {
+        Self::elements_as_bytes(slice::from_ref(self))
+    }
+}
+
+impl Randomizable for CanonicalElement {
+    const VALUE_SIZE: usize = Self::ELEMENT_BYTES;
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::try_from(bytes).ok()
+    }
+}
+
+impl Display for CanonicalElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for CanonicalElement {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.0);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        Self::ELEMENT_BYTES
+    }
+}
+
+impl Deserializable for CanonicalElement {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Self::try_from(source.read_u64()?)
+    }
+}
@@ -3,11 +3,14 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
 use math::fields::f128;
-use rand_utils::rand_value;
+use rand_utils::{rand_value, rand_vector};
 use winter_crypto::{
-    hashers::{Blake3_256, Rp62_248, Rp64_256, RpJive64_256, Sha3_256},
+    hash_rows,
+    hashers::{
+        AnemoiJive64_256, Blake3_256, Griffin64_256, Rp62_248, Rp64_256, RpJive64_256, Sha3_256,
+    },
     Hasher,
 };
 
@@ -20,6 +23,8 @@ type Sha3Digest = <Sha3 as Hasher>::Digest;
 type Rp62_248Digest = <Rp62_248 as Hasher>::Digest;
 type Rp64_256Digest = <Rp64_256 as Hasher>::Digest;
 type RpJive64_256Digest = <RpJive64_256 as Hasher>::Digest;
+type AnemoiJive64_256Digest = <AnemoiJive64_256 as Hasher>::Digest;
+type Griffin64_256Digest = <Griffin64_256 as Hasher>::Digest;
 
 fn blake3(c: &mut Criterion) {
     let v: [Blake3Digest; 2] = [Blake3::hash(&[1u8]), Blake3::hash(&[2u8])];
@@ -117,5 +122,78 @@ fn rescue_jive256(c: &mut Criterion) {
     });
 }
 
-criterion_group!(hash_group, blake3, sha3, rescue248, rescue256, rescue_jive256);
+fn anemoi_jive256(c: &mut Criterion) {
+    let v: [AnemoiJive64_256Digest; 2] =
+        [AnemoiJive64_256::hash(&[1u8]), AnemoiJive64_256::hash(&[2u8])];
+    c.bench_function("hash_anemoi_jive64_256 (cached)", |bench| {
+        bench.iter(|| AnemoiJive64_256::merge(black_box(&v)))
+    });
+
+    c.bench_function("hash_anemoi_jive64_256 (random)", |b| {
+        b.iter_batched(
+            || {
+                [
+                    AnemoiJive64_256::hash(&rand_value::<u64>().to_le_bytes()),
+                    AnemoiJive64_256::hash(&rand_value::<u64>().to_le_bytes()),
+                ]
+            },
+            |state| AnemoiJive64_256::merge(&state),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn griffin256(c: &mut Criterion) {
+    let v: [Griffin64_256Digest; 2] = [Griffin64_256::hash(&[1u8]), Griffin64_256::hash(&[2u8])];
+    c.bench_function("hash_griffin64_256 (cached)", |bench| {
+        bench.iter(|| Griffin64_256::merge(black_box(&v)))
+    });
+
+    c.bench_function("hash_griffin64_256 (random)", |b| {
+        b.iter_batched(
+            || {
+                [
+                    Griffin64_256::hash(&rand_value::<u64>().to_le_bytes()),
+                    Griffin64_256::hash(&rand_value::<u64>().to_le_bytes()),
+                ]
+            },
+            |state| Griffin64_256::merge(&state),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn blake3_rows(c: &mut Criterion) {
+    const NUM_ROWS: usize = 1 << 20;
+
+    let mut group = c.benchmark_group("hash_blake3 rows");
+    group.sample_size(10);
+
+    // rows of 4, 8, and 16 elements of the 128-bit field
+    for row_len in [64, 128, 256] {
+        let rows: Vec<Vec<u8>> = (0..NUM_ROWS).map(|_| rand_vector(row_len)).collect();
+
+        group.bench_with_input(BenchmarkId::new("sequential", row_len), &rows, |b, rows| {
+            b.iter(|| rows.iter().map(|row| Blake3::hash(row)).collect::<Vec<_>>())
+        });
+
+        group.bench_with_input(BenchmarkId::new("hash_rows", row_len), &rows, |b, rows| {
+            b.iter(|| hash_rows::<f128::BaseElement>(rows))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    hash_group,
+    blake3_rows,
+    blake3,
+    sha3,
+    rescue248,
+    rescue256,
+    rescue_jive256,
+    anemoi_jive256,
+    griffin256
+);
 criterion_main!(hash_group);
@@ -6,9 +6,15 @@
 use alloc::vec::Vec;
 use core::fmt::Debug;
 
-use utils::{Deserializable, Serializable};
+use math::StarkField;
+#[cfg(feature = "concurrent")]
+use utils::iterators::*;
+use utils::{iter, Deserializable, Serializable};
 
-use crate::Hasher;
+use crate::{hashers::Blake3_256, Hasher};
+
+// VECTOR COMMITMENT
+// ================================================================================================
 
 /// A vector commitment (VC) scheme.
 ///
@@ -84,3 +90,54 @@ pub trait VectorCommitment<H: Hasher>: Sized {
         proof: &Self::MultiProof,
     ) -> Result<(), Self::Error>;
 }
+
+// ROW HASHING
+// ================================================================================================
+
+/// Hashes each of the provided rows with BLAKE3.
+///
+/// The returned digests are identical to the digests computed by [Blake3_256::hash()] for each
+/// of the rows individually, and thus, they can be used as leaves of a vector commitment built
+/// with [Blake3_256] as the hash function. This function is used by [Blake3_256] to hash rows
+/// of field elements into Merkle leaves (see
+/// [ElementHasher::hash_element_rows()](crate::ElementHasher::hash_element_rows)).
+///
+/// When the crate is compiled with `concurrent` feature enabled, rows are hashed using multiple
+/// threads.
+pub fn hash_rows<B: StarkField>(
+    rows: &[impl AsRef<[u8]> + Sync],
+) -> Vec<<Blake3_256<B> as Hasher>::Digest> {
+    iter!(rows).map(|row| Blake3_256::<B>::hash(row.as_ref())).collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use math::fields::f64::BaseElement;
+    use rand_utils::rand_vector;
+
+    use super::hash_rows;
+    use crate::{hashers::Blake3_256, Hasher};
+
+    #[test]
+    fn hash_rows_matches_scalar_hashing() {
+        for row_len in [8, 64, 200, 1024, 2048] {
+            let rows: Vec<Vec<u8>> = (0..37).map(|_| rand_vector(row_len)).collect();
+            let expected: Vec<_> =
+                rows.iter().map(|row| Blake3_256::<BaseElement>::hash(row)).collect();
+            assert_eq!(expected, hash_rows::<BaseElement>(&rows), "row length {row_len}");
+        }
+
+        // rows of different lengths
+        let rows = vec![rand_vector(64), rand_vector(128)];
+        let expected: Vec<_> =
+            rows.iter().map(|row| Blake3_256::<BaseElement>::hash(row)).collect();
+        assert_eq!(expected, hash_rows::<BaseElement>(&rows));
+
+        assert!(hash_rows::<BaseElement>(&[] as &[Vec<u8>]).is_empty());
+    }
+}
@@ -25,6 +25,8 @@ pub enum MerkleTreeError {
     TooManyLeafIndexes(usize, usize),
     /// Merkle proof is not valid for the specified position(s).
     InvalidProof,
+    /// A leaf of an algebraic Merkle tree did not consist of the expected number of elements.
+    InvalidLeafLength(usize, usize),
 }
 
 impl fmt::Display for MerkleTreeError {
@@ -57,6 +59,9 @@ impl fmt::Display for MerkleTreeError {
             Self::InvalidProof => {
                 write!(f, "Merkle proof is invalid")
             },
+            Self::InvalidLeafLength(expected, actual) => {
+                write!(f, "a leaf must consist of {expected} elements, but was {actual}")
+            },
         }
     }
 }
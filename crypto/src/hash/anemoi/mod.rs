@@ -0,0 +1,628 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::ops::Range;
+
+use math::{fields::f64::BaseElement, FieldElement, StarkField};
+
+use super::{rescue::exp_acc, Digest, ElementHasher, Hasher};
+
+mod digest;
+pub use digest::ElementDigest;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Sponge state is set to 8 field elements or 64 bytes; 4 elements are reserved for rate and
+/// the remaining 4 elements are reserved for capacity.
+const STATE_WIDTH: usize = 8;
+
+/// The state is split into two rows of 4 elements each: the `x` coordinates are located in
+/// elements 0 through 3, and the `y` coordinates are located in elements 4 through 7.
+const NUM_COLUMNS: usize = STATE_WIDTH / 2;
+
+/// The rate portion of the state is located in elements 4 through 7.
+const RATE_RANGE: Range<usize> = 4..8;
+const RATE_WIDTH: usize = RATE_RANGE.end - RATE_RANGE.start;
+
+/// Jive compression mode doesn't consider rate and capacity registers.
+const INPUT1_RANGE: Range<usize> = 0..4;
+const INPUT2_RANGE: Range<usize> = 4..8;
+
+/// The capacity portion of the state is located in elements 0, 1, 2, and 3.
+const CAPACITY_RANGE: Range<usize> = 0..4;
+
+/// The output of the hash function is a digest which consists of 4 field elements or 32 bytes.
+///
+/// The digest is returned from state elements 4, 5, 6, and 7 (the first four elements of the
+/// rate portion).
+const DIGEST_RANGE: Range<usize> = 4..8;
+const DIGEST_SIZE: usize = DIGEST_RANGE.end - DIGEST_RANGE.start;
+
+/// The number of rounds is set to 11 to target 128-bit security level; computed as
+/// $\max(8, \min(5, \ell + 1) + 2 + r)$ for $\ell = 4$ columns, where $r = 4$ is the smallest
+/// number of rounds for which $\binom{4 \ell r + \alpha}{2 \ell r}^2 \geq 2^{128}$, as described
+/// in section 6 of <https://eprint.iacr.org/2022/840.pdf>.
+const NUM_ROUNDS: usize = 11;
+
+/// Generator of the multiplicative group of the field; used as the multiplier $\beta$ of the
+/// quadratic functions of the Flystel S-Box.
+const GENERATOR: BaseElement = BaseElement::new(7);
+
+/// Inverse of the generator; used as the constant $\delta$ of the Flystel S-Box.
+const INV_GENERATOR: BaseElement = BaseElement::new(2635249152773512046);
+
+/// Digits of $\pi$ used to derive round constants.
+#[cfg(test)]
+const PI_0: u64 = 1415926535;
+#[cfg(test)]
+const PI_1: u64 = 8979323846;
+
+/// S-Box and Inverse S-Box powers of the Flystel S-Box.
+///
+/// The constants are defined for tests only because the exponentiations in the code are unrolled
+/// for efficiency reasons.
+#[cfg(test)]
+const ALPHA: u64 = 7;
+#[cfg(test)]
+const INV_ALPHA: u64 = 10540996611094048183;
+
+// HASHER IMPLEMENTATION
+// ================================================================================================
+
+/// Implementation of [Hasher] trait for Anemoi hash function with 256-bit output.
+///
+/// The hash function is implemented according to the Anemoi
+/// [specifications](https://eprint.iacr.org/2022/840.pdf) with the following exceptions:
+/// * When hashing a sequence of elements, implement the Hirose padding rule. However, it also
+///   means that our instantiation of Anemoi cannot be used in a stream mode as the number
+///   of elements to be hashed must be known upfront.
+/// * We use the first 4 elements of the state (rather than the last 4 elements of the state) for
+///   capacity and the remaining 4 elements for rate. The output of the hash function comes from
+///   the rate portion of the state (elements 4, 5, 6, and 7). This is the same layout as the one
+///   used by [RpJive64_256](super::RpJive64_256).
+///
+/// Each round of the Anemoi permutation adds round constants to the state, applies the linear
+/// layer (an MDS matrix applied to both halves of the state followed by a pseudo-Hadamard
+/// transform), and applies the open Flystel S-Box to each pair of `x` and `y` coordinates. The
+/// linear layer is applied one more time after the last round. Compared to Rescue Prime, a round
+/// of Anemoi requires a single exponentiation by $\alpha^{-1}$ for half of the state elements,
+/// rather than exponentiations by both $\alpha$ and $\alpha^{-1}$ for all of them.
+///
+/// The parameters used to instantiate the function are:
+/// * Field: 64-bit prime field with modulus 2^64 - 2^32 + 1.
+/// * State width: 8 field elements (4 columns).
+/// * Capacity size: 4 field elements.
+/// * Number of rounds: 11.
+/// * S-Box degree: 7.
+///
+/// The above parameters target 128-bit security level. The digest consists of four field elements
+/// and it can be serialized into 32 bytes (256 bits).
+///
+/// ## Hash output consistency
+/// Functions [hash_elements()](AnemoiJive64_256::hash_elements), [merge()](AnemoiJive64_256::merge),
+/// and [merge_with_int()](AnemoiJive64_256::merge_with_int) are not consistent. This is because the
+/// former is instantiated with a sponge construction, while the latter use the Jive compression
+/// mode and hence do not rely on the sponge construction.
+///
+/// In addition, [hash()](AnemoiJive64_256::hash) function is not consistent with the functions
+/// mentioned above. For example, if we take two field elements, serialize them to bytes and hash
+/// them using [hash()](AnemoiJive64_256::hash), the result will differ from the result obtained by
+/// hashing these elements directly using [hash_elements()](AnemoiJive64_256::hash_elements)
+/// function. The reason for this difference is that [hash()](AnemoiJive64_256::hash) function
+/// needs to be able to handle arbitrary binary strings, which may or may not encode valid field
+/// elements - and thus, deserialization procedure used by this function is different from the
+/// procedure used to deserialize valid field elements.
+///
+/// Thus, if the underlying data consists of valid field elements, it might make more sense
+/// to deserialize them into field elements and then hash them using
+/// [hash_elements()](AnemoiJive64_256::hash_elements) function rather then hashing the serialized
+/// bytes using [hash()](AnemoiJive64_256::hash) function.
+pub struct AnemoiJive64_256();
+
+impl Hasher for AnemoiJive64_256 {
+    type Digest = ElementDigest;
+
+    const COLLISION_RESISTANCE: u32 = 128;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // compute the number of elements required to represent the string; we will be processing
+        // the string in 7-byte chunks, thus the number of elements will be equal to the number
+        // of such chunks (including a potential partial chunk at the end).
+        let num_elements = if bytes.len() % 7 == 0 {
+            bytes.len() / 7
+        } else {
+            bytes.len() / 7 + 1
+        };
+
+        // initialize state to all zeros, except for the first element of the capacity part, which
+        // is set to 1 if the number of elements is not a multiple of RATE_WIDTH.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        if num_elements % RATE_WIDTH != 0 {
+            state[CAPACITY_RANGE.start] = BaseElement::ONE;
+        }
+
+        // break the string into 7-byte chunks, convert each chunk into a field element, and
+        // absorb the element into the rate portion of the state. we use 7-byte chunks because
+        // every 7-byte chunk is guaranteed to map to some field element.
+        let mut i = 0;
+        let mut buf = [0_u8; 8];
+        for (index, chunk) in bytes.chunks(7).enumerate() {
+            if index < num_elements - 1 {
+                buf[..7].copy_from_slice(chunk);
+            } else {
+                // if we are dealing with the last chunk, it may be smaller than 7 bytes long, so
+                // we need to handle it slightly differently. we also append a byte with value 1
+                // to the end of the string; this pads the string in such a way that adding
+                // trailing zeros results in different hash
+                let chunk_len = chunk.len();
+                buf = [0_u8; 8];
+                buf[..chunk_len].copy_from_slice(chunk);
+                buf[chunk_len] = 1;
+            }
+
+            // convert the bytes into a field element and absorb it into the rate portion of the
+            // state; if the rate is filled up, apply the Anemoi permutation and start absorbing
+            // again from zero index.
+            state[RATE_RANGE.start + i] += BaseElement::new(u64::from_le_bytes(buf));
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                Self::apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // if we absorbed some elements but didn't apply a permutation to them (would happen when
+        // the number of elements is not a multiple of RATE_WIDTH), apply a final permutation after
+        // padding by appending a 1 followed by as many 0 as necessary to make the input length a
+        // multiple of the RATE_WIDTH.
+        if i > 0 {
+            state[RATE_RANGE.start + i] = BaseElement::ONE;
+            i += 1;
+            while i != RATE_WIDTH {
+                state[RATE_RANGE.start + i] = BaseElement::ZERO;
+                i += 1;
+            }
+            Self::apply_permutation(&mut state);
+        }
+
+        // return the first 4 elements of the state as hash result
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+
+    // We do not rely on the sponge construction to build our compression function. Instead, we use
+    // the Jive compression mode designed in https://eprint.iacr.org/2022/840.pdf.
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize the state by copying the digest elements into the state
+        let initial_state: [BaseElement; STATE_WIDTH] =
+            Self::Digest::digests_as_elements(values).try_into().unwrap();
+        let mut state = initial_state;
+
+        // apply the Anemoi permutation and apply the final Jive summation
+        Self::apply_permutation(&mut state);
+
+        Self::apply_jive_summation(&initial_state, &state)
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        Self::hash_elements(ElementDigest::digests_as_elements(values))
+    }
+
+    // We do not rely on the sponge construction to build our compression function. Instead, we use
+    // the Jive compression mode designed in https://eprint.iacr.org/2022/840.pdf.
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        // initialize the state as follows:
+        // - seed is copied into the first 4 elements of the state.
+        // - if the value fits into a single field element, copy it into the fifth rate element
+        //   and set the last state element to 5 (the number of elements to be hashed).
+        // - if the value doesn't fit into a single field element, split it into two field
+        //   elements, copy them into state elements 5 and 6, and set the last state element
+        //   to 6.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[INPUT1_RANGE].copy_from_slice(seed.as_elements());
+        state[INPUT2_RANGE.start] = BaseElement::new(value);
+        if value < BaseElement::MODULUS {
+            state[INPUT2_RANGE.end - 1] = BaseElement::new(DIGEST_SIZE as u64 + 1);
+        } else {
+            state[INPUT2_RANGE.start + 1] = BaseElement::new(value / BaseElement::MODULUS);
+            state[INPUT2_RANGE.end - 1] = BaseElement::new(DIGEST_SIZE as u64 + 2);
+        }
+
+        let initial_state = state;
+        // apply the Anemoi permutation and apply the final Jive summation
+        Self::apply_permutation(&mut state);
+
+        Self::apply_jive_summation(&initial_state, &state)
+    }
+}
+
+impl ElementHasher for AnemoiJive64_256 {
+    type BaseField = BaseElement;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        // convert the elements into a list of base field elements
+        let elements = E::slice_as_base_elements(elements);
+
+        // initialize state to all zeros, except for the first element of the capacity part, which
+        // is set to 1 if the number of elements is not a multiple of RATE_WIDTH.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        if elements.len() % RATE_WIDTH != 0 {
+            state[CAPACITY_RANGE.start] = BaseElement::ONE;
+        }
+
+        // absorb elements into the state one by one until the rate portion of the state is filled
+        // up; then apply the Anemoi permutation and start absorbing again; repeat until all
+        // elements have been absorbed
+        let mut i = 0;
+        for &element in elements.iter() {
+            state[RATE_RANGE.start + i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                Self::apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // if we absorbed some elements but didn't apply a permutation to them (would happen when
+        // the number of elements is not a multiple of RATE_WIDTH), apply a final permutation after
+        // padding by appending a 1 followed by as many 0 as necessary to make the input length a
+        // multiple of the RATE_WIDTH.
+        if i > 0 {
+            state[RATE_RANGE.start + i] = BaseElement::ONE;
+            i += 1;
+            while i != RATE_WIDTH {
+                state[RATE_RANGE.start + i] = BaseElement::ZERO;
+                i += 1;
+            }
+            Self::apply_permutation(&mut state);
+        }
+
+        // return the first 4 elements of the state as hash result
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+}
+
+// HASH FUNCTION IMPLEMENTATION
+// ================================================================================================
+
+impl AnemoiJive64_256 {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of rounds is set to 11 to target 128-bit security level.
+    pub const NUM_ROUNDS: usize = NUM_ROUNDS;
+
+    /// Sponge state is set to 8 field elements or 64 bytes; 4 elements are reserved for rate and
+    /// the remaining 4 elements are reserved for capacity.
+    pub const STATE_WIDTH: usize = STATE_WIDTH;
+
+    /// The rate portion of the state is located in elements 4 through 7 (inclusive).
+    pub const RATE_RANGE: Range<usize> = RATE_RANGE;
+
+    /// The capacity portion of the state is located in elements 0, 1, 2, and 3.
+    pub const CAPACITY_RANGE: Range<usize> = CAPACITY_RANGE;
+
+    /// The output of the hash function can be read from state elements 4, 5, 6, and 7.
+    pub const DIGEST_RANGE: Range<usize> = DIGEST_RANGE;
+
+    /// MDS matrix used for computing the linear layer in an Anemoi round.
+    pub const MDS: [[BaseElement; NUM_COLUMNS]; NUM_COLUMNS] = MDS;
+
+    /// Round constants added to the `x` coordinates of the state at the start of a round.
+    pub const ROUND_CONSTANTS_C: [[BaseElement; NUM_COLUMNS]; NUM_ROUNDS] = ROUND_CONSTANTS_C;
+
+    /// Round constants added to the `y` coordinates of the state at the start of a round.
+    pub const ROUND_CONSTANTS_D: [[BaseElement; NUM_COLUMNS]; NUM_ROUNDS] = ROUND_CONSTANTS_D;
+
+    // ANEMOI PERMUTATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies Anemoi permutation to the provided state.
+    pub fn apply_permutation(state: &mut [BaseElement; STATE_WIDTH]) {
+        // apply round function 11 times followed by the final linear layer; this provides 128-bit
+        // security level
+        for i in 0..NUM_ROUNDS {
+            Self::apply_round(state, i);
+        }
+        Self::apply_linear_layer(state);
+    }
+
+    /// Anemoi round function.
+    #[inline(always)]
+    pub fn apply_round(state: &mut [BaseElement; STATE_WIDTH], round: usize) {
+        Self::add_constants(state, round);
+        Self::apply_linear_layer(state);
+        Self::apply_sbox(state);
+    }
+
+    #[inline(always)]
+    pub fn apply_jive_summation(
+        initial_state: &[BaseElement; STATE_WIDTH],
+        final_state: &[BaseElement; STATE_WIDTH],
+    ) -> ElementDigest {
+        let mut result = [BaseElement::ZERO; DIGEST_SIZE];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = initial_state[i]
+                + initial_state[DIGEST_SIZE + i]
+                + final_state[i]
+                + final_state[DIGEST_SIZE + i];
+        }
+
+        ElementDigest::new(result)
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    #[inline(always)]
+    fn add_constants(state: &mut [BaseElement; STATE_WIDTH], round: usize) {
+        let (x, y) = state.split_at_mut(NUM_COLUMNS);
+        x.iter_mut().zip(ROUND_CONSTANTS_C[round]).for_each(|(s, k)| *s += k);
+        y.iter_mut().zip(ROUND_CONSTANTS_D[round]).for_each(|(s, k)| *s += k);
+    }
+
+    /// Applies the MDS matrix to the `x` coordinates and to the `y` coordinates rotated by one
+    /// element, and then mixes the two halves of the state using the pseudo-Hadamard transform.
+    #[inline(always)]
+    fn apply_linear_layer(state: &mut [BaseElement; STATE_WIDTH]) {
+        let mut x: [BaseElement; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [BaseElement; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+        y.rotate_left(1);
+        Self::apply_mds(&mut x);
+        Self::apply_mds(&mut y);
+
+        for i in 0..NUM_COLUMNS {
+            y[i] += x[i];
+            x[i] += y[i];
+        }
+
+        state[..NUM_COLUMNS].copy_from_slice(&x);
+        state[NUM_COLUMNS..].copy_from_slice(&y);
+    }
+
+    #[inline(always)]
+    fn apply_mds(values: &mut [BaseElement; NUM_COLUMNS]) {
+        let mut result = [BaseElement::ZERO; NUM_COLUMNS];
+        result.iter_mut().zip(MDS).for_each(|(r, mds_row)| {
+            values.iter().zip(mds_row).for_each(|(&v, m)| *r += m * v);
+        });
+        *values = result;
+    }
+
+    /// Applies the open Flystel S-Box to each pair of `x` and `y` coordinates of the state:
+    ///
+    /// $x \leftarrow x - \beta y^2$, $y \leftarrow y - x^{1/\alpha}$,
+    /// $x \leftarrow x + \beta y^2 + \delta$.
+    #[inline(always)]
+    fn apply_sbox(state: &mut [BaseElement; STATE_WIDTH]) {
+        let (x, y) = state.split_at_mut(NUM_COLUMNS);
+
+        x.iter_mut().zip(y.iter()).for_each(|(x, y)| *x -= GENERATOR * y.square());
+
+        let x_inv_alpha = Self::exp_inv_alpha(x.try_into().unwrap());
+        y.iter_mut().zip(x_inv_alpha).for_each(|(y, x)| *y -= x);
+
+        x.iter_mut()
+            .zip(y.iter())
+            .for_each(|(x, y)| *x += GENERATOR * y.square() + INV_GENERATOR);
+    }
+
+    #[inline(always)]
+    fn exp_inv_alpha(base: [BaseElement; NUM_COLUMNS]) -> [BaseElement; NUM_COLUMNS] {
+        // compute base^10540996611094048183 using 72 multiplications per array element
+        // 10540996611094048183 = b1001001001001001001001001001000110110110110110110110110110110111
+
+        // compute base^10
+        let mut t1 = base;
+        t1.iter_mut().for_each(|t| *t = t.square());
+
+        // compute base^100
+        let mut t2 = t1;
+        t2.iter_mut().for_each(|t| *t = t.square());
+
+        // compute base^100100
+        let t3 = exp_acc::<BaseElement, NUM_COLUMNS, 3>(t2, t2);
+
+        // compute base^100100100100
+        let t4 = exp_acc::<BaseElement, NUM_COLUMNS, 6>(t3, t3);
+
+        // compute base^100100100100100100100100
+        let t5 = exp_acc::<BaseElement, NUM_COLUMNS, 12>(t4, t4);
+
+        // compute base^100100100100100100100100100100
+        let t6 = exp_acc::<BaseElement, NUM_COLUMNS, 6>(t5, t3);
+
+        // compute base^1001001001001001001001001001000100100100100100100100100100100
+        let t7 = exp_acc::<BaseElement, NUM_COLUMNS, 31>(t6, t6);
+
+        // compute base^1001001001001001001001001001000110110110110110110110110110110111
+        let mut result = base;
+        for (i, r) in result.iter_mut().enumerate() {
+            let a = (t7[i].square() * t6[i]).square().square();
+            let b = t1[i] * t2[i] * *r;
+            *r = a * b;
+        }
+        result
+    }
+}
+
+// MDS
+// ================================================================================================
+/// Anemoi MDS matrix for 4 columns, as specified in section 5.2 of
+/// <https://eprint.iacr.org/2022/840.pdf> with $g = 7$.
+const MDS: [[BaseElement; NUM_COLUMNS]; NUM_COLUMNS] = [
+    [
+        BaseElement::new(1),
+        BaseElement::new(8),
+        BaseElement::new(7),
+        BaseElement::new(7),
+    ],
+    [
+        BaseElement::new(49),
+        BaseElement::new(56),
+        BaseElement::new(8),
+        BaseElement::new(15),
+    ],
+    [
+        BaseElement::new(49),
+        BaseElement::new(49),
+        BaseElement::new(1),
+        BaseElement::new(8),
+    ],
+    [
+        BaseElement::new(8),
+        BaseElement::new(15),
+        BaseElement::new(7),
+        BaseElement::new(8),
+    ],
+];
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// Round constants added to the `x` coordinates of the state; the `j`th constant of round `i`
+/// is computed as $g \cdot \pi_0^i + (\pi_0^i + \pi_1^j)^\alpha$.
+const ROUND_CONSTANTS_C: [[BaseElement; NUM_COLUMNS]; NUM_ROUNDS] = [
+    [
+        BaseElement::new(135),
+        BaseElement::new(12616817002499211280),
+        BaseElement::new(10322855620882634095),
+        BaseElement::new(11515394017209471433),
+    ],
+    [
+        BaseElement::new(9002049342496296353),
+        BaseElement::new(2584390291234849001),
+        BaseElement::new(4166208452208368636),
+        BaseElement::new(14817218184870356791),
+    ],
+    [
+        BaseElement::new(2677209583087539646),
+        BaseElement::new(13653205608663712194),
+        BaseElement::new(11623068044781424194),
+        BaseElement::new(8032655074547325148),
+    ],
+    [
+        BaseElement::new(14028343547340456491),
+        BaseElement::new(10199647495799699516),
+        BaseElement::new(16980602719545065267),
+        BaseElement::new(10026536886095554241),
+    ],
+    [
+        BaseElement::new(7174341618686026701),
+        BaseElement::new(14038545790452936179),
+        BaseElement::new(11382353009086559037),
+        BaseElement::new(2744333686151590551),
+    ],
+    [
+        BaseElement::new(1105685726565000427),
+        BaseElement::new(4933442288090874860),
+        BaseElement::new(3497005320341573620),
+        BaseElement::new(1206895969735137807),
+    ],
+    [
+        BaseElement::new(1045175758346270168),
+        BaseElement::new(10311890000811777109),
+        BaseElement::new(1731209920975696770),
+        BaseElement::new(10905725111569305359),
+    ],
+    [
+        BaseElement::new(6938233648763606320),
+        BaseElement::new(5462692038111756459),
+        BaseElement::new(7452480647038323657),
+        BaseElement::new(12598942395926914033),
+    ],
+    [
+        BaseElement::new(18269409827905307053),
+        BaseElement::new(10578340725264330704),
+        BaseElement::new(14378725426991580182),
+        BaseElement::new(14600120413495269838),
+    ],
+    [
+        BaseElement::new(7797870879830461427),
+        BaseElement::new(16622297313385841117),
+        BaseElement::new(3781900187676642754),
+        BaseElement::new(287292801254064254),
+    ],
+    [
+        BaseElement::new(5425395401555310608),
+        BaseElement::new(14958443051365368041),
+        BaseElement::new(863291064079674386),
+        BaseElement::new(13543151755714368463),
+    ],
+];
+
+/// Round constants added to the `y` coordinates of the state; the `j`th constant of round `i`
+/// is computed as $g \cdot \pi_1^j + (\pi_0^i + \pi_1^j)^\alpha + g^{-1}$.
+const ROUND_CONSTANTS_D: [[BaseElement; NUM_COLUMNS]; NUM_ROUNDS] = [
+    [
+        BaseElement::new(2635249152773512181),
+        BaseElement::new(15252066218127990241),
+        BaseElement::new(5506835741213654195),
+        BaseElement::new(16945671836758122808),
+    ],
+    [
+        BaseElement::new(11637298485358322661),
+        BaseElement::new(5219639496952142224),
+        BaseElement::new(17796932632042487319),
+        BaseElement::new(1800751925092938107),
+    ],
+    [
+        BaseElement::new(9725267137655892445),
+        BaseElement::new(2254519156672747587),
+        BaseElement::new(11219856566907285047),
+        BaseElement::new(17875741295890817276),
+    ],
+    [
+        BaseElement::new(15778423628933702095),
+        BaseElement::new(11949727640248212035),
+        BaseElement::new(11279413768695818925),
+        BaseElement::new(14571645634463939174),
+    ],
+    [
+        BaseElement::new(15842802120403555692),
+        BaseElement::new(4260262285611147764),
+        BaseElement::new(12599544478361596082),
+        BaseElement::new(14207822854644258871),
+    ],
+    [
+        BaseElement::new(1174110789228001021),
+        BaseElement::new(5001867413609142369),
+        BaseElement::new(14560905419976666589),
+        BaseElement::new(4070349699173277730),
+    ],
+    [
+        BaseElement::new(14790259923157830657),
+        BaseElement::new(5610230159064020192),
+        BaseElement::new(8025025053344765313),
+        BaseElement::new(8999093873741420856),
+    ],
+    [
+        BaseElement::new(331279510332425244),
+        BaseElement::new(17302482031950426619),
+        BaseElement::new(11841001545579234956),
+        BaseElement::new(8787016924270872286),
+    ],
+    [
+        BaseElement::new(16113664442313208359),
+        BaseElement::new(8422595402527498925),
+        BaseElement::new(4771711008956989542),
+        BaseElement::new(15239403694678310473),
+    ],
+    [
+        BaseElement::new(7461308516502810908),
+        BaseElement::new(16285735012913457513),
+        BaseElement::new(14440812861321084610),
+        BaseElement::new(2745759104701553064),
+    ],
+    [
+        BaseElement::new(351274238924496356),
+        BaseElement::new(9884321951589820704),
+        BaseElement::new(6784644938420952509),
+        BaseElement::new(11264059259858693540),
+    ],
+];
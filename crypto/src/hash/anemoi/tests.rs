@@ -0,0 +1,265 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use rand_utils::{rand_array, rand_value};
+
+use super::{
+    AnemoiJive64_256, BaseElement, ElementDigest, ElementHasher, FieldElement, Hasher, StarkField,
+    ALPHA, GENERATOR, INV_ALPHA, INV_GENERATOR, MDS, NUM_COLUMNS, NUM_ROUNDS, PI_0, PI_1,
+    ROUND_CONSTANTS_C, ROUND_CONSTANTS_D, STATE_WIDTH,
+};
+
+#[test]
+fn test_alphas() {
+    let e: BaseElement = rand_value();
+    let e_exp = e.exp(ALPHA);
+    assert_eq!(e, e_exp.exp(INV_ALPHA));
+
+    let x: [BaseElement; NUM_COLUMNS] = rand_array();
+    let mut expected = x;
+    expected.iter_mut().for_each(|v| *v = v.exp(INV_ALPHA));
+    assert_eq!(expected, AnemoiJive64_256::exp_inv_alpha(x));
+}
+
+#[test]
+fn test_generator() {
+    assert_eq!(BaseElement::GENERATOR, GENERATOR);
+    assert_eq!(GENERATOR.inv(), INV_GENERATOR);
+}
+
+#[test]
+fn round_constants() {
+    let pi_0 = BaseElement::new(PI_0);
+    let pi_1 = BaseElement::new(PI_1);
+
+    for i in 0..NUM_ROUNDS {
+        let pi_0_i = pi_0.exp(i as u64);
+        for j in 0..NUM_COLUMNS {
+            let pi_1_j = pi_1.exp(j as u64);
+            let t = (pi_0_i + pi_1_j).exp(ALPHA);
+            assert_eq!(GENERATOR * pi_0_i + t, ROUND_CONSTANTS_C[i][j]);
+            assert_eq!(GENERATOR * pi_1_j + t + INV_GENERATOR, ROUND_CONSTANTS_D[i][j]);
+        }
+    }
+}
+
+#[test]
+fn mds_is_mds() {
+    // a matrix is MDS if and only if all of its square sub-matrices are non-singular
+    for size in 1..=NUM_COLUMNS {
+        for rows in subsets(size) {
+            for cols in subsets(size) {
+                let sub_matrix = rows
+                    .iter()
+                    .map(|&r| cols.iter().map(|&c| MDS[r][c]).collect::<Vec<_>>())
+                    .collect::<Vec<_>>();
+                assert_ne!(BaseElement::ZERO, determinant(sub_matrix), "{rows:?}, {cols:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sbox() {
+    // the open Flystel maps (x, y) to (u, v) such that the closed Flystel relations hold:
+    // x = beta * y^2 + (y - v)^alpha and u = beta * v^2 + delta + (y - v)^alpha
+    let state: [BaseElement; STATE_WIDTH] = rand_array();
+    let mut result = state;
+    AnemoiJive64_256::apply_sbox(&mut result);
+
+    for i in 0..NUM_COLUMNS {
+        let (x, y) = (state[i], state[NUM_COLUMNS + i]);
+        let (u, v) = (result[i], result[NUM_COLUMNS + i]);
+        let t = (y - v).exp(ALPHA);
+        assert_eq!(x, GENERATOR * y.square() + t);
+        assert_eq!(u, GENERATOR * v.square() + INV_GENERATOR + t);
+    }
+}
+
+#[test]
+fn apply_permutation() {
+    let mut state: [BaseElement; STATE_WIDTH] = [
+        BaseElement::new(0),
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+        BaseElement::new(4),
+        BaseElement::new(5),
+        BaseElement::new(6),
+        BaseElement::new(7),
+    ];
+
+    AnemoiJive64_256::apply_permutation(&mut state);
+
+    // the expected values are not taken from a published source: the Anemoi specification does
+    // not provide test vectors for this instantiation (Goldilocks field, 4 columns, 11 rounds,
+    // and the MDS matrix of this hasher). Instead, they were computed with a standalone Python
+    // model written directly from the specification rather than from this code; the model
+    // derives the round constants from PI_0 and PI_1 as in round_constants(), and evaluates the
+    // S-Box via modular exponentiation by INV_ALPHA rather than via an addition chain. The same
+    // model, extended with the sponge and Jive modes of this hasher, was used for the vectors
+    // below.
+    let expected = vec![
+        BaseElement::new(11154036593728581505),
+        BaseElement::new(18394927771694105019),
+        BaseElement::new(10422194937302943125),
+        BaseElement::new(18203269858436166298),
+        BaseElement::new(15780333355764362724),
+        BaseElement::new(5706590276092796880),
+        BaseElement::new(18291577228230250950),
+        BaseElement::new(17122525463673124043),
+    ];
+
+    assert_eq!(expected, state);
+}
+
+#[test]
+fn hash_elements() {
+    let elements = (0..8).map(BaseElement::new).collect::<Vec<_>>();
+
+    // expected values are computed with the Python model described in apply_permutation(); the
+    // padding rule is specific to this hasher
+
+    // ----- full rate blocks ---------------------------------------------------------------------
+    let expected = ElementDigest::new([
+        BaseElement::new(10011152799164716668),
+        BaseElement::new(16336527951995661187),
+        BaseElement::new(8854160743014629401),
+        BaseElement::new(4409668343860032258),
+    ]);
+    assert_eq!(expected, AnemoiJive64_256::hash_elements(&elements));
+
+    // ----- partial rate block -------------------------------------------------------------------
+    let expected = ElementDigest::new([
+        BaseElement::new(9332030399347013068),
+        BaseElement::new(13453548042677197286),
+        BaseElement::new(15434649960893668944),
+        BaseElement::new(5478965834804226960),
+    ]);
+    assert_eq!(expected, AnemoiJive64_256::hash_elements(&elements[..5]));
+}
+
+#[test]
+fn merge() {
+    let digests = [
+        ElementDigest::new([1, 2, 3, 4].map(BaseElement::new)),
+        ElementDigest::new([5, 6, 7, 8].map(BaseElement::new)),
+    ];
+
+    // expected values are computed with the Python model described in apply_permutation()
+    let expected = ElementDigest::new([
+        BaseElement::new(9584566940059745046),
+        BaseElement::new(16997011181626863120),
+        BaseElement::new(1681141230227192800),
+        BaseElement::new(913649203620306977),
+    ]);
+    assert_eq!(expected, AnemoiJive64_256::merge(&digests));
+}
+
+#[test]
+fn hash_elements_vs_merge() {
+    let elements: [BaseElement; 8] = rand_array();
+
+    let digests: [ElementDigest; 2] = [
+        ElementDigest::new(elements[..4].try_into().unwrap()),
+        ElementDigest::new(elements[4..].try_into().unwrap()),
+    ];
+
+    let m_result = AnemoiJive64_256::merge(&digests);
+    let h_result = AnemoiJive64_256::hash_elements(&elements);
+
+    // Because we use the Jive compression mode, `merge` and
+    // `hash_elements` methods are incompatible.
+    assert_ne!(m_result, h_result);
+}
+
+#[test]
+fn hash_elements_vs_merge_with_int() {
+    let seed = ElementDigest::new(rand_array());
+
+    // ----- value fits into a field element ------------------------------------------------------
+    let val: BaseElement = rand_value();
+    let m_result = AnemoiJive64_256::merge_with_int(seed, val.as_int());
+
+    let mut elements = seed.as_elements().to_vec();
+    elements.push(val);
+    let h_result = AnemoiJive64_256::hash_elements(&elements);
+
+    // Because we use the Jive compression mode, `merge` and
+    // `hash_elements` methods are incompatible.
+    assert_ne!(m_result, h_result);
+
+    // ----- value does not fit into a field element ----------------------------------------------
+    let val = BaseElement::MODULUS + 2;
+    let m_result = AnemoiJive64_256::merge_with_int(seed, val);
+
+    let mut elements = seed.as_elements().to_vec();
+    elements.push(BaseElement::new(val));
+    elements.push(BaseElement::new(1));
+    let h_result = AnemoiJive64_256::hash_elements(&elements);
+
+    // Because we use the Jive compression mode, `merge` and
+    // `hash_elements` methods are incompatible.
+    assert_ne!(m_result, h_result);
+}
+
+#[test]
+fn hash_padding() {
+    // adding a zero bytes at the end of a byte string should result in a different hash
+    let r1 = AnemoiJive64_256::hash(&[1_u8, 2, 3]);
+    let r2 = AnemoiJive64_256::hash(&[1_u8, 2, 3, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with input splitting over two elements
+    let r1 = AnemoiJive64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7]);
+    let r2 = AnemoiJive64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with multiple zeros
+    let r1 = AnemoiJive64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0, 0]);
+    let r2 = AnemoiJive64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0, 0, 0, 0]);
+    assert_ne!(r1, r2);
+}
+
+#[test]
+fn hash_elements_padding() {
+    let e1: [BaseElement; 2] = rand_array();
+    let e2 = [e1[0], e1[1], BaseElement::ZERO];
+
+    let r1 = AnemoiJive64_256::hash_elements(&e1);
+    let r2 = AnemoiJive64_256::hash_elements(&e2);
+    assert_ne!(r1, r2);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns all subsets of the specified size of indexes in the range 0..NUM_COLUMNS.
+fn subsets(size: usize) -> Vec<Vec<usize>> {
+    (0_usize..1 << NUM_COLUMNS)
+        .filter(|mask| mask.count_ones() as usize == size)
+        .map(|mask| (0..NUM_COLUMNS).filter(|i| mask & (1 << i) != 0).collect())
+        .collect()
+}
+
+/// Computes the determinant of a square matrix via cofactor expansion along the first row.
+fn determinant(matrix: Vec<Vec<BaseElement>>) -> BaseElement {
+    if matrix.len() == 1 {
+        return matrix[0][0];
+    }
+
+    let mut result = BaseElement::ZERO;
+    for (col, &value) in matrix[0].iter().enumerate() {
+        let minor = matrix[1..]
+            .iter()
+            .map(|row| row.iter().enumerate().filter(|&(c, _)| c != col).map(|(_, &v)| v).collect())
+            .collect();
+        let term = value * determinant(minor);
+        result = if col % 2 == 0 { result + term } else { result - term };
+    }
+    result
+}
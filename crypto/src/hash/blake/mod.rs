@@ -3,12 +3,14 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::vec::Vec;
 use core::{fmt::Debug, marker::PhantomData};
 
 use math::{FieldElement, StarkField};
 use utils::ByteWriter;
 
 use super::{ByteDigest, ElementHasher, Hasher};
+use crate::hash_rows;
 
 #[cfg(test)]
 mod tests;
@@ -63,6 +65,26 @@ impl<B: StarkField> ElementHasher for Blake3_256<B> {
             ByteDigest(hasher.finalize())
         }
     }
+
+    fn hash_element_rows<E: FieldElement<BaseField = Self::BaseField>>(
+        rows: &[&[E]],
+    ) -> Vec<Self::Digest> {
+        if B::IS_CANONICAL {
+            let rows = rows.iter().map(|row| E::elements_as_bytes(row)).collect::<Vec<_>>();
+            hash_rows::<B>(&rows)
+        } else {
+            // serialize all rows into a single buffer, and then hash the serialized rows
+            let mut buffer = Vec::new();
+            let mut offsets = Vec::with_capacity(rows.len() + 1);
+            offsets.push(0);
+            for row in rows {
+                buffer.write_many(*row);
+                offsets.push(buffer.len());
+            }
+            let rows = offsets.windows(2).map(|w| &buffer[w[0]..w[1]]).collect::<Vec<_>>();
+            hash_rows::<B>(&rows)
+        }
+    }
 }
 
 // BLAKE3 192-BIT OUTPUT
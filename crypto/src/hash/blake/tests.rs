@@ -3,8 +3,13 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use math::{fields::f62::BaseElement, FieldElement};
-use rand_utils::rand_array;
+use alloc::vec::Vec;
+
+use math::{
+    fields::{f128, f62::BaseElement, QuadExtension},
+    FieldElement,
+};
+use rand_utils::{rand_array, rand_vector};
 use utils::Deserializable;
 
 use super::{Blake3_256, ElementHasher, Hasher};
@@ -53,3 +58,27 @@ fn merge_vs_merge_many_192() {
 
     assert_eq!(r1, r2)
 }
+
+#[test]
+fn hash_element_rows() {
+    // rows which fit into a single BLAKE3 chunk are hashed together, all other rows are hashed
+    // one at a time
+    for num_cols in [1, 4, 8, 64, 65] {
+        let rows: Vec<Vec<f128::BaseElement>> = (0..37).map(|_| rand_vector(num_cols)).collect();
+        let rows = rows.iter().map(|row| row.as_slice()).collect::<Vec<_>>();
+        let expected = rows
+            .iter()
+            .map(|row| Blake3_256::<f128::BaseElement>::hash_elements(row))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, Blake3_256::hash_element_rows(&rows), "{num_cols} columns");
+    }
+
+    // rows of extension field elements are serialized the same way as by hash_elements()
+    let rows: Vec<Vec<QuadExtension<BaseElement>>> = (0..10).map(|_| rand_vector(4)).collect();
+    let rows = rows.iter().map(|row| row.as_slice()).collect::<Vec<_>>();
+    let expected = rows
+        .iter()
+        .map(|row| Blake3_256::<BaseElement>::hash_elements(row))
+        .collect::<Vec<_>>();
+    assert_eq!(expected, Blake3_256::hash_element_rows(&rows));
+}
@@ -0,0 +1,565 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::ops::Range;
+
+use math::{fields::f64::BaseElement, FieldElement, StarkField};
+
+use super::{rescue::exp_acc, Digest, ElementHasher, Hasher};
+
+mod digest;
+pub use digest::ElementDigest;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Sponge state is set to 12 field elements or 96 bytes; 8 elements are reserved for rate and
+/// the remaining 4 elements are reserved for capacity.
+const STATE_WIDTH: usize = 12;
+
+/// The rate portion of the state is located in elements 4 through 11.
+const RATE_RANGE: Range<usize> = 4..12;
+const RATE_WIDTH: usize = RATE_RANGE.end - RATE_RANGE.start;
+
+const INPUT1_RANGE: Range<usize> = 4..8;
+const INPUT2_RANGE: Range<usize> = 8..12;
+
+/// The capacity portion of the state is located in elements 0, 1, 2, and 3.
+const CAPACITY_RANGE: Range<usize> = 0..4;
+
+/// The output of the hash function is a digest which consists of 4 field elements or 32 bytes.
+///
+/// The digest is returned from state elements 4, 5, 6, and 7 (the first four elements of the
+/// rate portion).
+const DIGEST_RANGE: Range<usize> = 4..8;
+const DIGEST_SIZE: usize = DIGEST_RANGE.end - DIGEST_RANGE.start;
+
+/// The number of rounds is set to 9 to target 128-bit security level; this is the number of
+/// rounds proposed for state width 12 and S-Box degree 7 in <https://eprint.iacr.org/2022/403.pdf>.
+const NUM_ROUNDS: usize = 9;
+
+/// Seed of the SHAKE128 extendable output function used to derive the constants of the hash
+/// function.
+///
+/// The constants are defined for tests only because they are hard-coded below.
+#[cfg(test)]
+const CONSTANTS_SEED: &[u8] = b"Griffin(p=2^64-2^32+1,t=12,d=7,R=9)";
+
+/// S-Box power $d$ and its inverse modulo $p - 1$.
+///
+/// The constants are defined for tests only because the exponentiations in the code are unrolled
+/// for efficiency reasons.
+#[cfg(test)]
+const ALPHA: u64 = 7;
+#[cfg(test)]
+const INV_ALPHA: u64 = 10540996611094048183;
+
+// HASHER IMPLEMENTATION
+// ================================================================================================
+
+/// Implementation of [Hasher] trait for Griffin hash function with 256-bit output.
+///
+/// **This instantiation is experimental.** Its outputs have not been checked against test vectors
+/// published by the authors of Griffin, and thus, they may change in future versions. It should
+/// not be used in production.
+///
+/// Griffin is an arithmetization-friendly hash function described in
+/// <https://eprint.iacr.org/2022/403.pdf>. Its S-Box combines a single power map and a single
+/// inverse power map with low-degree "Horst" functions across the rest of the state, which
+/// results in fewer rounds than other algebraic hash functions with comparable parameters.
+///
+/// The hash function is implemented using the Griffin-π permutation in a sponge construction
+/// with the following choices, which mirror the choices made for [Rp64_256](super::Rp64_256):
+/// * When hashing a sequence of elements, we do not append Fp(1) followed by Fp(0) elements
+///   to the end of the sequence as padding. Instead, we initialize the first capacity element
+///   to the number of elements to be hashed, and pad the sequence with Fp(0) elements only. This
+///   ensures consistency of hash outputs between different hashing methods (see section below).
+///   However, it also means that this instantiation cannot be used in a stream mode as the
+///   number of elements to be hashed must be known upfront.
+/// * We use the first 4 elements of the state for capacity and the remaining 8 elements for
+///   rate. The output of the hash function comes from the first four elements of the rate
+///   portion of the state (elements 4, 5, 6, and 7).
+/// * Round constants and the $\alpha$, $\beta$ constants of the S-Box are derived from the
+///   output of SHAKE128 seeded with a string describing the parameters of the instance.
+///
+/// The parameters used to instantiate the function are:
+/// * Field: 64-bit prime field with modulus 2^64 - 2^32 + 1.
+/// * State width: 12 field elements.
+/// * Capacity size: 4 field elements.
+/// * Number of rounds: 9.
+/// * S-Box degree: 7.
+///
+/// The above parameters target 128-bit security level. The digest consists of four field elements
+/// and it can be serialized into 32 bytes (256 bits).
+///
+/// ## Hash output consistency
+/// Functions [hash_elements()](Griffin64_256::hash_elements), [merge()](Griffin64_256::merge),
+/// and [merge_with_int()](Griffin64_256::merge_with_int) are internally consistent. That is,
+/// computing a hash for the same set of elements using these functions will always produce the
+/// same result. For example, merging two digests using [merge()](Griffin64_256::merge) will
+/// produce the same result as hashing 8 elements which make up these digests using
+/// [hash_elements()](Griffin64_256::hash_elements) function.
+///
+/// However, [hash()](Griffin64_256::hash) function is not consistent with functions mentioned
+/// above, for the same reasons as described for [Rp64_256](super::Rp64_256).
+pub struct Griffin64_256();
+
+impl Hasher for Griffin64_256 {
+    type Digest = ElementDigest;
+
+    const COLLISION_RESISTANCE: u32 = 128;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // compute the number of elements required to represent the string; we will be processing
+        // the string in 7-byte chunks, thus the number of elements will be equal to the number
+        // of such chunks (including a potential partial chunk at the end).
+        let num_elements = if bytes.len() % 7 == 0 {
+            bytes.len() / 7
+        } else {
+            bytes.len() / 7 + 1
+        };
+
+        // initialize state to all zeros, except for the first element of the capacity part, which
+        // is set to the number of elements to be hashed. this is done so that adding zero elements
+        // at the end of the list always results in a different hash.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[CAPACITY_RANGE.start] = BaseElement::new(num_elements as u64);
+
+        // break the string into 7-byte chunks, convert each chunk into a field element, and
+        // absorb the element into the rate portion of the state. we use 7-byte chunks because
+        // every 7-byte chunk is guaranteed to map to some field element.
+        let mut i = 0;
+        let mut buf = [0_u8; 8];
+        for chunk in bytes.chunks(7) {
+            if i < num_elements - 1 {
+                buf[..7].copy_from_slice(chunk);
+            } else {
+                // if we are dealing with the last chunk, it may be smaller than 7 bytes long, so
+                // we need to handle it slightly differently. we also append a byte with value 1
+                // to the end of the string; this pads the string in such a way that adding
+                // trailing zeros results in different hash
+                let chunk_len = chunk.len();
+                buf = [0_u8; 8];
+                buf[..chunk_len].copy_from_slice(chunk);
+                buf[chunk_len] = 1;
+            }
+
+            // convert the bytes into a field element and absorb it into the rate portion of the
+            // state; if the rate is filled up, apply the Griffin permutation and start absorbing
+            // again from zero index.
+            state[RATE_RANGE.start + i] += BaseElement::new(u64::from_le_bytes(buf));
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                Self::apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // if we absorbed some elements but didn't apply a permutation to them (would happen when
+        // the number of elements is not a multiple of RATE_WIDTH), apply the Griffin permutation.
+        // we don't need to apply any extra padding because we injected total number of elements
+        // in the input list into the capacity portion of the state during initialization.
+        if i > 0 {
+            Self::apply_permutation(&mut state);
+        }
+
+        // return the first 4 elements of the state as hash result
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize the state by copying the digest elements into the rate portion of the state
+        // (8 total elements), and set the first capacity element to 8 (the number of elements to
+        // be hashed).
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[RATE_RANGE].copy_from_slice(Self::Digest::digests_as_elements(values));
+        state[CAPACITY_RANGE.start] = BaseElement::new(RATE_WIDTH as u64);
+
+        // apply the Griffin permutation and return the first four elements of the state
+        Self::apply_permutation(&mut state);
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        Self::hash_elements(ElementDigest::digests_as_elements(values))
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        // initialize the state as follows:
+        // - seed is copied into the first 4 elements of the rate portion of the state.
+        // - if the value fits into a single field element, copy it into the fifth rate element
+        //   and set the first capacity element to 5 (the number of elements to be hashed).
+        // - if the value doesn't fit into a single field element, split it into two field
+        //   elements, copy them into rate elements 5 and 6, and set the first capacity element
+        //   to 6.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[INPUT1_RANGE].copy_from_slice(seed.as_elements());
+        state[INPUT2_RANGE.start] = BaseElement::new(value);
+        if value < BaseElement::MODULUS {
+            state[CAPACITY_RANGE.start] = BaseElement::new(DIGEST_SIZE as u64 + 1);
+        } else {
+            state[INPUT2_RANGE.start + 1] = BaseElement::new(value / BaseElement::MODULUS);
+            state[CAPACITY_RANGE.start] = BaseElement::new(DIGEST_SIZE as u64 + 2);
+        }
+
+        // apply the Griffin permutation and return the first four elements of the state
+        Self::apply_permutation(&mut state);
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+}
+
+impl ElementHasher for Griffin64_256 {
+    type BaseField = BaseElement;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        // convert the elements into a list of base field elements
+        let elements = E::slice_as_base_elements(elements);
+
+        // initialize state to all zeros, except for the last element of the capacity part, which
+        // is set to the number of elements to be hashed. this is done so that adding zero elements
+        // at the end of the list always results in a different hash.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[CAPACITY_RANGE.start] = BaseElement::new(elements.len() as u64);
+
+        // absorb elements into the state one by one until the rate portion of the state is filled
+        // up; then apply the Griffin permutation and start absorbing again; repeat until all
+        // elements have been absorbed
+        let mut i = 0;
+        for &element in elements.iter() {
+            state[RATE_RANGE.start + i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                Self::apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // if we absorbed some elements but didn't apply a permutation to them (would happen when
+        // the number of elements is not a multiple of RATE_WIDTH), apply the Griffin permutation.
+        // we don't need to apply any extra padding because we injected total number of elements
+        // in the input list into the capacity portion of the state during initialization.
+        if i > 0 {
+            Self::apply_permutation(&mut state);
+        }
+
+        // return the first 4 elements of the state as hash result
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+}
+
+// HASH FUNCTION IMPLEMENTATION
+// ================================================================================================
+
+impl Griffin64_256 {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of rounds is set to 9 to target 128-bit security level.
+    pub const NUM_ROUNDS: usize = NUM_ROUNDS;
+
+    /// Sponge state is set to 12 field elements or 96 bytes; 8 elements are reserved for rate and
+    /// the remaining 4 elements are reserved for capacity.
+    pub const STATE_WIDTH: usize = STATE_WIDTH;
+
+    /// The rate portion of the state is located in elements 4 through 11 (inclusive).
+    pub const RATE_RANGE: Range<usize> = RATE_RANGE;
+
+    /// The capacity portion of the state is located in elements 0, 1, 2, and 3.
+    pub const CAPACITY_RANGE: Range<usize> = CAPACITY_RANGE;
+
+    /// The output of the hash function can be read from state elements 4, 5, 6, and 7.
+    pub const DIGEST_RANGE: Range<usize> = DIGEST_RANGE;
+
+    /// Constants $\alpha_i$ of the S-Box for state elements 2 through 11.
+    pub const ALPHAS: [BaseElement; STATE_WIDTH - 2] = ALPHAS;
+
+    /// Constants $\beta_i$ of the S-Box for state elements 2 through 11.
+    pub const BETAS: [BaseElement; STATE_WIDTH - 2] = BETAS;
+
+    /// Round constants added to the hasher state at the end of all rounds but the last one.
+    pub const ROUND_CONSTANTS: [[BaseElement; STATE_WIDTH]; NUM_ROUNDS - 1] = ROUND_CONSTANTS;
+
+    // GRIFFIN PERMUTATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies Griffin-π permutation to the provided state.
+    pub fn apply_permutation(state: &mut [BaseElement; STATE_WIDTH]) {
+        // the permutation starts with a linear layer and then applies the round function 9 times;
+        // the last round does not add round constants
+        Self::apply_linear_layer(state);
+        for i in 0..NUM_ROUNDS {
+            Self::apply_round(state, i);
+        }
+    }
+
+    /// Griffin-π round function.
+    #[inline(always)]
+    pub fn apply_round(state: &mut [BaseElement; STATE_WIDTH], round: usize) {
+        Self::apply_sbox(state);
+        Self::apply_linear_layer(state);
+        if round < NUM_ROUNDS - 1 {
+            Self::add_constants(state, &ROUND_CONSTANTS[round]);
+        }
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    #[inline(always)]
+    fn add_constants(state: &mut [BaseElement; STATE_WIDTH], ark: &[BaseElement; STATE_WIDTH]) {
+        state.iter_mut().zip(ark).for_each(|(s, &k)| *s += k);
+    }
+
+    /// Applies the linear layer $\mathrm{circ}(2 M_4, M_4, M_4)$ to the state, where $M_4$ is
+    /// applied to each chunk of 4 elements of the state.
+    #[inline(always)]
+    fn apply_linear_layer(state: &mut [BaseElement; STATE_WIDTH]) {
+        for chunk in state.chunks_exact_mut(4) {
+            Self::apply_m4(chunk.try_into().unwrap());
+        }
+
+        let mut sums = [BaseElement::ZERO; 4];
+        for chunk in state.chunks_exact(4) {
+            sums.iter_mut().zip(chunk).for_each(|(s, &v)| *s += v);
+        }
+        for chunk in state.chunks_exact_mut(4) {
+            chunk.iter_mut().zip(sums).for_each(|(v, s)| *v += s);
+        }
+    }
+
+    /// Multiplies 4 elements of the state by the matrix
+    /// $M_4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]$ using 8 additions and
+    /// 4 doublings.
+    #[inline(always)]
+    fn apply_m4(x: &mut [BaseElement; 4]) {
+        let t0 = x[0] + x[1];
+        let t1 = x[2] + x[3];
+        let t2 = x[1].double() + t1;
+        let t3 = x[3].double() + t0;
+        let t4 = t1.double().double() + t3;
+        let t5 = t0.double().double() + t2;
+        let t6 = t3 + t5;
+        let t7 = t2 + t4;
+        *x = [t6, t5, t7, t4];
+    }
+
+    /// Applies the Griffin S-Box to the state:
+    ///
+    /// $y_0 = x_0^{1/d}$, $y_1 = x_1^d$, and $y_i = x_i \cdot (L_i^2 + \alpha_i L_i +
+    /// \beta_i)$ for $i \geq 2$, where $L_i = (i - 1) \cdot y_0 + y_1 + x_{i - 1}$ for $i \geq 3$
+    /// and $L_2 = y_0 + y_1$.
+    #[inline(always)]
+    fn apply_sbox(state: &mut [BaseElement; STATE_WIDTH]) {
+        let y0 = Self::exp_inv_alpha(state[0]);
+        let y1 = Self::exp_alpha(state[1]);
+
+        // l holds (i - 1) * y0 + y1, and prev holds the input value of the previous element
+        let mut l = y0 + y1;
+        let mut prev = state[1];
+        for i in 2..STATE_WIDTH {
+            let l_i = if i == 2 { l } else { l + prev };
+            prev = state[i];
+            state[i] *= l_i.square() + ALPHAS[i - 2] * l_i + BETAS[i - 2];
+            l += y0;
+        }
+
+        state[0] = y0;
+        state[1] = y1;
+    }
+
+    #[inline(always)]
+    fn exp_alpha(base: BaseElement) -> BaseElement {
+        let t2 = base.square();
+        let t4 = t2.square();
+        base * t2 * t4
+    }
+
+    #[inline(always)]
+    fn exp_inv_alpha(base: BaseElement) -> BaseElement {
+        // compute base^10540996611094048183 using 72 multiplications
+        // 10540996611094048183 = b1001001001001001001001001001000110110110110110110110110110110111
+
+        // compute base^10
+        let t1 = [base.square()];
+
+        // compute base^100
+        let t2 = [t1[0].square()];
+
+        // compute base^100100
+        let t3 = exp_acc::<BaseElement, 1, 3>(t2, t2);
+
+        // compute base^100100100100
+        let t4 = exp_acc::<BaseElement, 1, 6>(t3, t3);
+
+        // compute base^100100100100100100100100
+        let t5 = exp_acc::<BaseElement, 1, 12>(t4, t4);
+
+        // compute base^100100100100100100100100100100
+        let t6 = exp_acc::<BaseElement, 1, 6>(t5, t3);
+
+        // compute base^1001001001001001001001001001000100100100100100100100100100100
+        let t7 = exp_acc::<BaseElement, 1, 31>(t6, t6);
+
+        // compute base^1001001001001001001001001001000110110110110110110110110110110111
+        let a = (t7[0].square() * t6[0]).square().square();
+        let b = t1[0] * t2[0] * base;
+        a * b
+    }
+}
+
+// S-BOX CONSTANTS
+// ================================================================================================
+
+/// Constants $\alpha_i = (i - 1) \cdot \alpha$ and $\beta_i = (i - 1)^2 \cdot \beta$ for
+/// $i = 2, ..., 11$; $\alpha$ and $\beta$ are the first pair of values drawn from SHAKE128 for
+/// which $\alpha^2 - 4 \beta$ is not a square.
+const ALPHAS: [BaseElement; STATE_WIDTH - 2] = [
+    BaseElement::new(1150701136467397260),
+    BaseElement::new(2301402272934794520),
+    BaseElement::new(3452103409402191780),
+    BaseElement::new(4602804545869589040),
+    BaseElement::new(5753505682336986300),
+    BaseElement::new(6904206818804383560),
+    BaseElement::new(8054907955271780820),
+    BaseElement::new(9205609091739178080),
+    BaseElement::new(10356310228206575340),
+    BaseElement::new(11507011364673972600),
+];
+
+const BETAS: [BaseElement; STATE_WIDTH - 2] = [
+    BaseElement::new(14448830626887247580),
+    BaseElement::new(2455090299305237357),
+    BaseElement::new(912267156083137973),
+    BaseElement::new(9820361197220949428),
+    BaseElement::new(10732628353304087401),
+    BaseElement::new(3649068624332551892),
+    BaseElement::new(7016426079720927222),
+    BaseElement::new(2387956650054629070),
+    BaseElement::new(8210404404748241757),
+    BaseElement::new(6037025274387180962),
+];
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// Griffin round constants; drawn from SHAKE128 after the $\alpha$ and $\beta$ constants of the
+/// S-Box.
+const ROUND_CONSTANTS: [[BaseElement; STATE_WIDTH]; NUM_ROUNDS - 1] = [
+    [
+        BaseElement::new(9794003021013028384),
+        BaseElement::new(4581105882775924350),
+        BaseElement::new(12423466160950483301),
+        BaseElement::new(9068544576968721786),
+        BaseElement::new(10113876891787502924),
+        BaseElement::new(6084448875585035549),
+        BaseElement::new(12450626493654748824),
+        BaseElement::new(13165836492338680640),
+        BaseElement::new(15788897144298958194),
+        BaseElement::new(7468538971017609794),
+        BaseElement::new(5007184638592783893),
+        BaseElement::new(2392744619505346186),
+    ],
+    [
+        BaseElement::new(2680462525942069655),
+        BaseElement::new(12257653266504951197),
+        BaseElement::new(1046508879765336925),
+        BaseElement::new(4341617152959483918),
+        BaseElement::new(9446485450602795842),
+        BaseElement::new(6830164414192382552),
+        BaseElement::new(2071656817110603159),
+        BaseElement::new(2043012228489665339),
+        BaseElement::new(5500307365950601434),
+        BaseElement::new(3521334077138127493),
+        BaseElement::new(9367874731845144260),
+        BaseElement::new(17965814444604651047),
+    ],
+    [
+        BaseElement::new(11913996678217958662),
+        BaseElement::new(3023853692155464837),
+        BaseElement::new(7398154283425847507),
+        BaseElement::new(7157872163520644529),
+        BaseElement::new(3664919458525190864),
+        BaseElement::new(17730428425163490710),
+        BaseElement::new(8762345098037675631),
+        BaseElement::new(12194207792213717970),
+        BaseElement::new(4611564617299381670),
+        BaseElement::new(6427996660767914906),
+        BaseElement::new(2569387174090269680),
+        BaseElement::new(11093372078168512516),
+    ],
+    [
+        BaseElement::new(2751947923432448767),
+        BaseElement::new(9192154829887015722),
+        BaseElement::new(219659317885203122),
+        BaseElement::new(8908034426649091069),
+        BaseElement::new(10980839430513665104),
+        BaseElement::new(11123743626470714493),
+        BaseElement::new(15392888299816947208),
+        BaseElement::new(2154282728583212730),
+        BaseElement::new(13951800385667966197),
+        BaseElement::new(2252270998408860283),
+        BaseElement::new(5396135209142978194),
+        BaseElement::new(14069865289385011414),
+    ],
+    [
+        BaseElement::new(2203206313927392208),
+        BaseElement::new(4116430708012125976),
+        BaseElement::new(17973936843738155011),
+        BaseElement::new(15613735610213493211),
+        BaseElement::new(14102849536047562309),
+        BaseElement::new(8400192206148742564),
+        BaseElement::new(11109565598929162002),
+        BaseElement::new(5918680494982942386),
+        BaseElement::new(16837790077738305173),
+        BaseElement::new(8218508281827581802),
+        BaseElement::new(15184310843345972840),
+        BaseElement::new(14583862131613352530),
+    ],
+    [
+        BaseElement::new(12596283401823350827),
+        BaseElement::new(8549744076504741492),
+        BaseElement::new(11983293592033229207),
+        BaseElement::new(701811656695492715),
+        BaseElement::new(3498418418951831066),
+        BaseElement::new(13640041933438978087),
+        BaseElement::new(3916612904159850080),
+        BaseElement::new(9299918473622026761),
+        BaseElement::new(18412902396870404320),
+        BaseElement::new(12611782445278397604),
+        BaseElement::new(15043485568744344213),
+        BaseElement::new(16344950435999183006),
+    ],
+    [
+        BaseElement::new(13220883306123421963),
+        BaseElement::new(10048944317614736577),
+        BaseElement::new(3491557703127411336),
+        BaseElement::new(13294712044190772974),
+        BaseElement::new(4713398335832985152),
+        BaseElement::new(4432722676788592689),
+        BaseElement::new(4270654260491113529),
+        BaseElement::new(7456686615896499115),
+        BaseElement::new(11225711362380347110),
+        BaseElement::new(17582953109000513023),
+        BaseElement::new(15882988564990199280),
+        BaseElement::new(2280484177531875961),
+    ],
+    [
+        BaseElement::new(7993936230125469399),
+        BaseElement::new(14549084022871234492),
+        BaseElement::new(6847211081168789365),
+        BaseElement::new(15072788319182261653),
+        BaseElement::new(8876005700161500216),
+        BaseElement::new(4005466861445612367),
+        BaseElement::new(17123840489568913095),
+        BaseElement::new(4301066378671031855),
+        BaseElement::new(14574743588096944031),
+        BaseElement::new(13632870749798881248),
+        BaseElement::new(13313061226314024854),
+        BaseElement::new(8980501874363635845),
+    ],
+];
@@ -0,0 +1,282 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use proptest::prelude::*;
+use rand_utils::{rand_array, rand_value};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+
+use super::{
+    BaseElement, ElementDigest, ElementHasher, FieldElement, Griffin64_256, Hasher, StarkField,
+    ALPHA, ALPHAS, BETAS, CONSTANTS_SEED, INV_ALPHA, ROUND_CONSTANTS, STATE_WIDTH,
+};
+
+/// Matrix applied to each chunk of 4 elements of the state in the linear layer.
+const M4: [[u64; 4]; 4] = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+
+#[test]
+fn test_alphas() {
+    let e: BaseElement = rand_value();
+    let e_exp = e.exp(ALPHA);
+    assert_eq!(e, e_exp.exp(INV_ALPHA));
+
+    assert_eq!(e_exp, Griffin64_256::exp_alpha(e));
+    assert_eq!(e.exp(INV_ALPHA), Griffin64_256::exp_inv_alpha(e));
+}
+
+#[test]
+fn constants() {
+    // draw field elements from SHAKE128 by rejection sampling 8-byte little-endian chunks
+    let mut shake = Shake128::default();
+    shake.update(CONSTANTS_SEED);
+    let mut reader = shake.finalize_xof();
+    let mut draw = || loop {
+        let mut bytes = [0_u8; 8];
+        reader.read(&mut bytes);
+        let value = u64::from_le_bytes(bytes);
+        if value < BaseElement::MODULUS {
+            return BaseElement::new(value);
+        }
+    };
+
+    // alpha and beta are the first pair for which alpha^2 - 4 * beta is not a square
+    let (alpha, beta) = loop {
+        let (alpha, beta) = (draw(), draw());
+        let discriminant = alpha.square() - beta.double().double();
+        if alpha != BaseElement::ZERO && beta != BaseElement::ZERO && !is_square(discriminant) {
+            break (alpha, beta);
+        }
+    };
+    for i in 2..STATE_WIDTH {
+        let k = BaseElement::from((i - 1) as u32);
+        assert_eq!(k * alpha, ALPHAS[i - 2]);
+        assert_eq!(k.square() * beta, BETAS[i - 2]);
+    }
+
+    for round_constants in ROUND_CONSTANTS {
+        for constant in round_constants {
+            assert_eq!(draw(), constant);
+        }
+    }
+}
+
+#[test]
+fn sbox_is_invertible() {
+    // the S-Box is a permutation for any alpha and beta such that alpha^2 - 4 * beta is not a
+    // square; check this by inverting it element by element
+    let state: [BaseElement; STATE_WIDTH] = rand_array();
+    let mut result = state;
+    Griffin64_256::apply_sbox(&mut result);
+
+    let mut inverse = [BaseElement::ZERO; STATE_WIDTH];
+    inverse[0] = result[0].exp(ALPHA);
+    inverse[1] = result[1].exp(INV_ALPHA);
+    for i in 2..STATE_WIDTH {
+        let prev = if i == 2 { BaseElement::ZERO } else { inverse[i - 1] };
+        let l = BaseElement::from((i - 1) as u32) * result[0] + result[1] + prev;
+        inverse[i] = result[i] / (l.square() + ALPHAS[i - 2] * l + BETAS[i - 2]);
+    }
+    assert_eq!(state, inverse);
+}
+
+#[test]
+fn linear_layer() {
+    let state: [BaseElement; STATE_WIDTH] = rand_array();
+
+    // the linear layer is circ(2 * M4, M4, M4)
+    let mut expected = [BaseElement::ZERO; STATE_WIDTH];
+    for (i, e) in expected.iter_mut().enumerate() {
+        for (j, &s) in state.iter().enumerate() {
+            let scale = if i / 4 == j / 4 { 2 } else { 1 };
+            *e += BaseElement::new(scale * M4[i % 4][j % 4]) * s;
+        }
+    }
+
+    let mut result = state;
+    Griffin64_256::apply_linear_layer(&mut result);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn apply_permutation() {
+    let mut state: [BaseElement; STATE_WIDTH] = [
+        BaseElement::new(0),
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+        BaseElement::new(4),
+        BaseElement::new(5),
+        BaseElement::new(6),
+        BaseElement::new(7),
+        BaseElement::new(8),
+        BaseElement::new(9),
+        BaseElement::new(10),
+        BaseElement::new(11),
+    ];
+
+    Griffin64_256::apply_permutation(&mut state);
+
+    // expected values are obtained by executing a Python implementation of the permutation
+    // instantiated with the same parameters
+    let expected = vec![
+        BaseElement::new(7261716377347262582),
+        BaseElement::new(13142009490209685594),
+        BaseElement::new(391012569137340507),
+        BaseElement::new(14465114955690631199),
+        BaseElement::new(8359977688985929002),
+        BaseElement::new(5940360556311101483),
+        BaseElement::new(7313828367073915989),
+        BaseElement::new(5239995571880629473),
+        BaseElement::new(6304590394640962531),
+        BaseElement::new(1156812138598629888),
+        BaseElement::new(11554166612910465582),
+        BaseElement::new(14154222110609678022),
+    ];
+
+    assert_eq!(expected, state);
+}
+
+#[test]
+fn hash_elements() {
+    let elements = (0..10).map(BaseElement::new).collect::<Vec<_>>();
+
+    // ----- full rate block ----------------------------------------------------------------------
+    let expected = ElementDigest::new([
+        BaseElement::new(14205940220961775147),
+        BaseElement::new(15466438556861485702),
+        BaseElement::new(13372894164676848823),
+        BaseElement::new(2592729951234214154),
+    ]);
+    assert_eq!(expected, Griffin64_256::hash_elements(&elements[..8]));
+
+    // ----- partial rate block -------------------------------------------------------------------
+    let expected = ElementDigest::new([
+        BaseElement::new(15578579244568180270),
+        BaseElement::new(17561827178946621748),
+        BaseElement::new(15047160190174731868),
+        BaseElement::new(13826047031062598615),
+    ]);
+    assert_eq!(expected, Griffin64_256::hash_elements(&elements[..5]));
+
+    // ----- multiple rate blocks -----------------------------------------------------------------
+    let expected = ElementDigest::new([
+        BaseElement::new(7269499043008548156),
+        BaseElement::new(9458639437756188464),
+        BaseElement::new(1020815067837636989),
+        BaseElement::new(8961262332802897007),
+    ]);
+    assert_eq!(expected, Griffin64_256::hash_elements(&elements));
+}
+
+#[test]
+fn merge() {
+    let digests = [
+        ElementDigest::new([1, 2, 3, 4].map(BaseElement::new)),
+        ElementDigest::new([5, 6, 7, 8].map(BaseElement::new)),
+    ];
+
+    let expected = ElementDigest::new([
+        BaseElement::new(5947489271063301897),
+        BaseElement::new(2507305292724077531),
+        BaseElement::new(14279908538406442705),
+        BaseElement::new(18414184075364809937),
+    ]);
+    assert_eq!(expected, Griffin64_256::merge(&digests));
+}
+
+#[test]
+fn hash_elements_vs_merge() {
+    let elements: [BaseElement; 8] = rand_array();
+
+    let digests: [ElementDigest; 2] = [
+        ElementDigest::new(elements[..4].try_into().unwrap()),
+        ElementDigest::new(elements[4..].try_into().unwrap()),
+    ];
+
+    let m_result = Griffin64_256::merge(&digests);
+    let h_result = Griffin64_256::hash_elements(&elements);
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn hash_elements_vs_merge_with_int() {
+    let seed = ElementDigest::new(rand_array());
+
+    // ----- value fits into a field element ------------------------------------------------------
+    let val: BaseElement = rand_value();
+    let m_result = Griffin64_256::merge_with_int(seed, val.as_int());
+
+    let mut elements = seed.as_elements().to_vec();
+    elements.push(val);
+    let h_result = Griffin64_256::hash_elements(&elements);
+
+    assert_eq!(m_result, h_result);
+
+    // ----- value does not fit into a field element ----------------------------------------------
+    let val = BaseElement::MODULUS + 2;
+    let m_result = Griffin64_256::merge_with_int(seed, val);
+
+    let mut elements = seed.as_elements().to_vec();
+    elements.push(BaseElement::new(val));
+    elements.push(BaseElement::new(1));
+    let h_result = Griffin64_256::hash_elements(&elements);
+
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn hash_padding() {
+    // adding a zero bytes at the end of a byte string should result in a different hash
+    let r1 = Griffin64_256::hash(&[1_u8, 2, 3]);
+    let r2 = Griffin64_256::hash(&[1_u8, 2, 3, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with input splitting over two elements
+    let r1 = Griffin64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7]);
+    let r2 = Griffin64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with multiple zeros
+    let r1 = Griffin64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0, 0]);
+    let r2 = Griffin64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0, 0, 0, 0]);
+    assert_ne!(r1, r2);
+}
+
+#[test]
+fn hash_elements_padding() {
+    let e1: [BaseElement; 2] = rand_array();
+    let e2 = [e1[0], e1[1], BaseElement::ZERO];
+
+    let r1 = Griffin64_256::hash_elements(&e1);
+    let r2 = Griffin64_256::hash_elements(&e2);
+    assert_ne!(r1, r2);
+}
+
+proptest! {
+    #[test]
+    fn m4_proptest(a in any::<[u64; 4]>()) {
+        let mut v = a.map(BaseElement::new);
+        Griffin64_256::apply_m4(&mut v);
+
+        let mut expected = [BaseElement::ZERO; 4];
+        for (e, row) in expected.iter_mut().zip(M4) {
+            for (&m, &x) in row.iter().zip(a.iter()) {
+                *e += BaseElement::new(m) * BaseElement::new(x);
+            }
+        }
+        prop_assert_eq!(expected, v);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn is_square(value: BaseElement) -> bool {
+    value.exp((BaseElement::MODULUS - 1) / 2) != -BaseElement::ONE
+}
@@ -3,6 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::vec::Vec;
 use core::{fmt::Debug, slice};
 
 use math::{FieldElement, StarkField};
@@ -19,6 +20,12 @@ mod mds;
 mod rescue;
 pub use rescue::{Rp62_248, Rp64_256, RpJive64_256};
 
+mod anemoi;
+pub use anemoi::AnemoiJive64_256;
+
+mod griffin;
+pub use griffin::Griffin64_256;
+
 // HASHER TRAITS
 // ================================================================================================
 
@@ -61,6 +68,46 @@ pub trait ElementHasher: Hasher {
     fn hash_elements<E>(elements: &[E]) -> Self::Digest
     where
         E: FieldElement<BaseField = Self::BaseField>;
+
+    /// Returns hashes of each of the provided rows of field elements.
+    ///
+    /// The digest of each row is the same as the digest returned by
+    /// [hash_elements()](ElementHasher::hash_elements) for this row. This method is intended for
+    /// hashing rows of a matrix into leaves of a vector commitment, and hashers which can hash
+    /// several rows at once faster than one row at a time may override it.
+    fn hash_element_rows<E>(rows: &[&[E]]) -> Vec<Self::Digest>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        rows.iter().map(|row| Self::hash_elements(row)).collect()
+    }
+}
+
+/// Defines a cryptographic hash function which maps field elements directly to field elements.
+///
+/// Unlike digests of [ElementHasher], digests of this hasher are plain sequences of field
+/// elements, and thus, they can be hashed again without being converted into bytes. This is
+/// intended for Merkle trees whose paths are verified inside arithmetic circuits (e.g., by
+/// recursive STARK verifiers), where every conversion between field elements and bytes requires
+/// many additional constraints.
+pub trait AlgebraicHasher<F: StarkField> {
+    /// Number of field elements in a digest produced by this hasher.
+    const DIGEST_ELEMENTS: usize;
+
+    /// Collision resistance of the hash function measured in bits.
+    const COLLISION_RESISTANCE: u32;
+
+    /// Returns a hash of `left_child` and `right_child`. The hash consists of
+    /// [DIGEST_ELEMENTS](Self::DIGEST_ELEMENTS) field elements.
+    ///
+    /// Both children must consist of exactly [DIGEST_ELEMENTS](Self::DIGEST_ELEMENTS) elements;
+    /// otherwise, the same elements could be split between the children in different ways
+    /// without changing the hash.
+    ///
+    /// # Panics
+    /// Panics if either of the children does not consist of
+    /// [DIGEST_ELEMENTS](Self::DIGEST_ELEMENTS) elements.
+    fn hash(left_child: &[F], right_child: &[F]) -> Vec<F>;
 }
 
 // DIGEST TRAIT
@@ -77,6 +124,16 @@ pub trait Digest:
     /// upper limit on the possible digest size. For digests which are smaller than 32 bytes, the
     /// unused bytes should be set to 0.
     fn as_bytes(&self) -> [u8; 32];
+
+    /// Returns true if this digest is equal to the `other` digest.
+    ///
+    /// Unlike `==`, this comparison always examines all bytes of both digests, and thus, the time
+    /// it takes does not depend on how many leading bytes of the digests match. This should be
+    /// used to compare digests against values supplied by an untrusted party (e.g., when checking
+    /// commitments read from a proof).
+    fn ct_eq(&self, other: &Self) -> bool {
+        ct_eq_bytes(&self.as_bytes(), &other.as_bytes())
+    }
 }
 
 // BYTE DIGEST
@@ -131,9 +188,27 @@ impl<const N: usize> Deserializable for ByteDigest<N> {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns true if the two byte arrays are equal; the comparison takes the same time regardless
+/// of the position of the first differing byte.
+fn ct_eq_bytes<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
+    let diff = a.iter().zip(b.iter()).fold(0u8, |acc, (&x, &y)| acc | (x ^ y));
+    core::hint::black_box(diff) == 0
+}
+
+// TESTS
+// ================================================================================================
+
 #[cfg(test)]
 mod tests {
-    use super::{ByteDigest, Digest};
+    use math::fields::f64::BaseElement;
+
+    use super::{
+        Blake3_192, Blake3_256, ByteDigest, Digest, Griffin64_256, Hasher, Rp62_248, Rp64_256,
+        Sha3_256,
+    };
 
     #[test]
     fn byte_digest_as_bytes() {
@@ -145,4 +220,41 @@ mod tests {
         expected[31] = 0;
         assert_eq!(expected, d.as_bytes());
     }
+
+    #[test]
+    fn byte_digest_ct_eq() {
+        fn check<const N: usize>() {
+            let d = ByteDigest::new([7_u8; N]);
+            assert!(d.ct_eq(&ByteDigest::new([7_u8; N])));
+
+            // digests differing in the first, a middle, or the last byte are not equal
+            for i in [0, N / 2, N - 1] {
+                let mut bytes = [7_u8; N];
+                bytes[i] ^= 1;
+                assert!(!d.ct_eq(&ByteDigest::new(bytes)));
+            }
+        }
+
+        check::<24>();
+        check::<31>();
+        check::<32>();
+    }
+
+    #[test]
+    fn hasher_digest_ct_eq() {
+        fn check<H: Hasher>() {
+            let d1 = H::hash(b"foo");
+            let d2 = H::hash(b"bar");
+            assert!(d1.ct_eq(&H::hash(b"foo")));
+            assert!(!d1.ct_eq(&d2));
+            assert_eq!(d1 == d2, d1.ct_eq(&d2));
+        }
+
+        check::<Blake3_192<BaseElement>>();
+        check::<Blake3_256<BaseElement>>();
+        check::<Sha3_256<BaseElement>>();
+        check::<Rp62_248>();
+        check::<Rp64_256>();
+        check::<Griffin64_256>();
+    }
 }
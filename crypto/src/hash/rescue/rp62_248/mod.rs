@@ -3,9 +3,11 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::vec::Vec;
+
 use math::{fields::f62::BaseElement, FieldElement, StarkField};
 
-use super::{exp_acc, Digest, ElementHasher, Hasher};
+use super::{exp_acc, AlgebraicHasher, Digest, ElementHasher, Hasher};
 
 mod digest;
 pub use digest::ElementDigest;
@@ -232,6 +234,23 @@ impl ElementHasher for Rp62_248 {
     }
 }
 
+impl AlgebraicHasher<BaseElement> for Rp62_248 {
+    const DIGEST_ELEMENTS: usize = DIGEST_SIZE;
+    const COLLISION_RESISTANCE: u32 = <Self as Hasher>::COLLISION_RESISTANCE;
+
+    fn hash(left_child: &[BaseElement], right_child: &[BaseElement]) -> Vec<BaseElement> {
+        let to_digest = |child: &[BaseElement]| {
+            let elements = child.try_into().unwrap_or_else(|_| {
+                panic!("a child must consist of {DIGEST_SIZE} elements, but was {}", child.len())
+            });
+            ElementDigest::new(elements)
+        };
+        Self::merge(&[to_digest(left_child), to_digest(right_child)])
+            .as_elements()
+            .to_vec()
+    }
+}
+
 // RESCUE PERMUTATION
 // ================================================================================================
 
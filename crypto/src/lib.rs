@@ -22,23 +22,32 @@
 extern crate alloc;
 
 mod hash;
-pub use hash::{Digest, ElementHasher, Hasher};
+pub use hash::{AlgebraicHasher, Digest, ElementHasher, Hasher};
 pub mod hashers {
     //! Contains implementations of currently supported hash functions.
 
-    pub use super::hash::{Blake3_192, Blake3_256, Rp62_248, Rp64_256, RpJive64_256, Sha3_256};
+    pub use super::hash::{
+        AnemoiJive64_256, Blake3_192, Blake3_256, Griffin64_256, Rp62_248, Rp64_256, RpJive64_256,
+        Sha3_256,
+    };
 }
 
 mod merkle;
 #[cfg(feature = "concurrent")]
 pub use merkle::concurrent;
-pub use merkle::{build_merkle_nodes, BatchMerkleProof, MerkleTree};
+pub use merkle::{
+    build_merkle_nodes, AlgebraicMerkleTree, AlgebraicMerkleTreeOpening, BatchMerkleProof,
+    MerkleTree,
+};
 
 mod random;
-pub use random::{DefaultRandomCoin, RandomCoin};
+pub use random::{DefaultRandomCoin, LegacyRandomCoin, RandomCoin, UnlabeledRandomCoin};
+
+mod pow;
+pub use pow::{Blake3Pow, HashPow, PowScheme, ProofOfWork};
 
 mod errors;
 pub use errors::{MerkleTreeError, RandomCoinError};
 
 mod commitment;
-pub use commitment::VectorCommitment;
+pub use commitment::{hash_rows, VectorCommitment};
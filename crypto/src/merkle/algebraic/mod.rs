@@ -0,0 +1,215 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use math::StarkField;
+
+use crate::{AlgebraicHasher, MerkleTreeError};
+
+#[cfg(test)]
+mod tests;
+
+// TYPES AND INTERFACES
+// ================================================================================================
+
+/// A fully-balanced Merkle tree whose nodes are sequences of field elements.
+///
+/// This tree is similar to [MerkleTree](crate::MerkleTree), but instead of digests of a
+/// [Hasher](crate::Hasher), every node of the tree (including the leaves) consists of
+/// [AlgebraicHasher::DIGEST_ELEMENTS] elements of the field `F`, and internal nodes are computed
+/// directly from the elements of their children via [AlgebraicHasher::hash()]. Thus, verifying
+/// a Merkle path of this tree does not require any conversions between field elements and bytes,
+/// which makes the verification much cheaper when it is expressed as an arithmetic circuit
+/// (e.g., in a recursive STARK verifier).
+///
+/// As with [MerkleTree](crate::MerkleTree), the user is responsible for hashing values into leaf
+/// nodes, the number of leaves must be a power of two, and a tree must contain at least two
+/// leaves.
+///
+/// # Examples
+/// ```
+/// # use winter_crypto::{AlgebraicMerkleTree, ElementHasher, hashers::Rp64_256};
+/// # use math::{fields::f64::BaseElement, FieldElement};
+/// // build a tree from leaves consisting of 4 field elements each
+/// let leaves = (0..4u32)
+///     .map(|i| Rp64_256::hash_elements(&[BaseElement::from(i)]).as_elements().to_vec())
+///     .collect::<Vec<_>>();
+/// let tree = AlgebraicMerkleTree::<BaseElement, Rp64_256>::new(leaves.clone()).unwrap();
+/// assert_eq!(2, tree.depth());
+///
+/// // generate and verify a proof
+/// let (leaf, proof) = tree.prove(2).unwrap();
+/// assert_eq!(leaves[2], leaf);
+/// type Tree = AlgebraicMerkleTree<BaseElement, Rp64_256>;
+/// assert!(Tree::verify(tree.root(), 2, &leaf, &proof).is_ok());
+/// assert!(Tree::verify(tree.root(), 1, &leaf, &proof).is_err());
+/// ```
+#[derive(Debug)]
+pub struct AlgebraicMerkleTree<F: StarkField, H: AlgebraicHasher<F>> {
+    nodes: Vec<Vec<F>>,
+    leaves: Vec<Vec<F>>,
+    _hasher: PhantomData<H>,
+}
+
+/// Algebraic Merkle tree opening consisting of a leaf value and a Merkle path leading from this
+/// leaf up to the root (excluding the root itself).
+pub type AlgebraicMerkleTreeOpening<F> = (Vec<F>, Vec<Vec<F>>);
+
+// ALGEBRAIC MERKLE TREE IMPLEMENTATION
+// ================================================================================================
+
+impl<F: StarkField, H: AlgebraicHasher<F>> AlgebraicMerkleTree<F, H> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new Merkle tree built from the provided leaves using hash function specified by
+    /// the `H` generic parameter.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Fewer than two leaves were provided.
+    /// * Number of leaves is not a power of two.
+    /// * Any of the leaves does not consist of [AlgebraicHasher::DIGEST_ELEMENTS] elements.
+    pub fn new(leaves: Vec<Vec<F>>) -> Result<Self, MerkleTreeError> {
+        if leaves.len() < 2 {
+            return Err(MerkleTreeError::TooFewLeaves(2, leaves.len()));
+        }
+        if !leaves.len().is_power_of_two() {
+            return Err(MerkleTreeError::NumberOfLeavesNotPowerOfTwo(leaves.len()));
+        }
+        if let Some(leaf) = leaves.iter().find(|leaf| leaf.len() != H::DIGEST_ELEMENTS) {
+            return Err(MerkleTreeError::InvalidLeafLength(H::DIGEST_ELEMENTS, leaf.len()));
+        }
+
+        // nodes are stored in the same layout as for MerkleTree: the root is at position 1, the
+        // children of the node at position i are at positions 2i and 2i + 1, and the node at
+        // position 0 is unused
+        let n = leaves.len() / 2;
+        let mut nodes = vec![Vec::new(); 2 * n];
+        for i in 0..n {
+            nodes[n + i] = H::hash(&leaves[2 * i], &leaves[2 * i + 1]);
+        }
+        for i in (1..n).rev() {
+            nodes[i] = H::hash(&nodes[2 * i], &nodes[2 * i + 1]);
+        }
+
+        Ok(Self { nodes, leaves, _hasher: PhantomData })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> &[F] {
+        &self.nodes[1]
+    }
+
+    /// Returns depth of the tree.
+    ///
+    /// The depth of a tree is zero-based. Thus, a tree with two leaves has depth 1, a tree with
+    /// four leaves has depth 2 etc.
+    pub fn depth(&self) -> usize {
+        self.leaves.len().ilog2() as usize
+    }
+
+    /// Returns leaf nodes of the tree.
+    pub fn leaves(&self) -> &[Vec<F>] {
+        &self.leaves
+    }
+
+    // PROVING METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a Merkle proof to a leaf at the specified `index`.
+    ///
+    /// The leaf itself will be the first element of the returned tuple.
+    ///
+    /// # Errors
+    /// Returns an error if the specified index is greater than or equal to the number of leaves
+    /// in the tree.
+    pub fn prove(&self, index: usize) -> Result<AlgebraicMerkleTreeOpening<F>, MerkleTreeError> {
+        if index >= self.leaves.len() {
+            return Err(MerkleTreeError::LeafIndexOutOfBounds(self.leaves.len(), index));
+        }
+        let leaf = self.leaves[index].clone();
+        let mut proof = vec![self.leaves[index ^ 1].clone()];
+
+        let mut index = (index + self.nodes.len()) >> 1;
+        while index > 1 {
+            proof.push(self.nodes[index ^ 1].clone());
+            index >>= 1;
+        }
+
+        Ok((leaf, proof))
+    }
+
+    // VERIFICATION METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks whether the `proof` for the given `leaf` at the specified `index` is valid.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The specified `leaf` does not consist of [AlgebraicHasher::DIGEST_ELEMENTS] elements.
+    /// * The specified `index` is greater than or equal to the number of leaves in a tree of
+    ///   the depth implied by the `proof`.
+    /// * Any of the nodes of the `proof` does not consist of [AlgebraicHasher::DIGEST_ELEMENTS]
+    ///   elements.
+    /// * The specified `proof` (which is a Merkle path) does not resolve to the specified `root`.
+    pub fn verify(
+        root: &[F],
+        index: usize,
+        leaf: &[F],
+        proof: &[Vec<F>],
+    ) -> Result<(), MerkleTreeError> {
+        if leaf.len() != H::DIGEST_ELEMENTS {
+            return Err(MerkleTreeError::InvalidLeafLength(H::DIGEST_ELEMENTS, leaf.len()));
+        }
+        if proof.is_empty() || proof.len() >= usize::BITS as usize {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        let num_leaves = 1 << proof.len();
+        if index >= num_leaves {
+            return Err(MerkleTreeError::LeafIndexOutOfBounds(num_leaves, index));
+        }
+        if proof.iter().any(|node| node.len() != H::DIGEST_ELEMENTS) {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+
+        let mut index = index;
+        let mut v = leaf.to_vec();
+        for p in proof.iter() {
+            v = if index & 1 == 0 { H::hash(&v, p) } else { H::hash(p, &v) };
+            index >>= 1;
+        }
+
+        // the root is compared in constant time because the proof is supplied by an untrusted
+        // party
+        if !ct_eq_elements(&v, root) {
+            return Err(MerkleTreeError::InvalidProof);
+        }
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns true if the two sequences of elements are equal; similarly to
+/// [Digest::ct_eq()](crate::Digest::ct_eq), all elements are examined regardless of the position
+/// of the first differing element.
+fn ct_eq_elements<F: StarkField>(a: &[F], b: &[F]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a
+        .iter()
+        .zip(b.iter())
+        .flat_map(|(x, y)| x.to_le_bytes().into_iter().zip(y.to_le_bytes()))
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    core::hint::black_box(diff) == 0
+}
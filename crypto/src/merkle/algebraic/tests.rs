@@ -0,0 +1,256 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::{fields::f64::BaseElement, FieldElement};
+
+use super::{AlgebraicHasher, AlgebraicMerkleTree, MerkleTreeError};
+use crate::{hash::Rp64_256, ElementHasher, MerkleTree};
+
+type MockTree = AlgebraicMerkleTree<BaseElement, MockPoseidon64>;
+
+// ALGEBRAIC MERKLE TREE TESTS
+// ================================================================================================
+
+#[test]
+fn new_tree() {
+    let leaves = build_leaves(4);
+    let tree = MockTree::new(leaves.clone()).unwrap();
+    assert_eq!(2, tree.depth());
+    assert_eq!(leaves, tree.leaves());
+
+    let node2 = MockPoseidon64::hash(&leaves[0], &leaves[1]);
+    let node3 = MockPoseidon64::hash(&leaves[2], &leaves[3]);
+    assert_eq!(MockPoseidon64::hash(&node2, &node3), tree.root());
+
+    let leaves = build_leaves(8);
+    let tree = MockTree::new(leaves.clone()).unwrap();
+    assert_eq!(3, tree.depth());
+    assert_eq!(MockPoseidon64::DIGEST_ELEMENTS, tree.root().len());
+}
+
+#[test]
+fn new_tree_invalid_leaves() {
+    let result = MockTree::new(build_leaves(1));
+    assert_eq!(Some(MerkleTreeError::TooFewLeaves(2, 1)), result.err());
+
+    let result = MockTree::new(build_leaves(6));
+    assert_eq!(Some(MerkleTreeError::NumberOfLeavesNotPowerOfTwo(6)), result.err());
+
+    let mut leaves = build_leaves(4);
+    leaves[3].push(BaseElement::ONE);
+    let result = MockTree::new(leaves);
+    assert_eq!(Some(MerkleTreeError::InvalidLeafLength(4, 5)), result.err());
+}
+
+#[test]
+fn prove_and_verify() {
+    let leaves = build_leaves(16);
+    let tree = MockTree::new(leaves.clone()).unwrap();
+
+    for (index, expected) in leaves.iter().enumerate() {
+        let (leaf, proof) = tree.prove(index).unwrap();
+        assert_eq!(expected, &leaf);
+        assert_eq!(tree.depth(), proof.len());
+        assert!(proof.iter().all(|node| node.len() == MockPoseidon64::DIGEST_ELEMENTS));
+        assert!(MockTree::verify(tree.root(), index, &leaf, &proof).is_ok());
+    }
+
+    let result = tree.prove(16);
+    assert_eq!(Some(MerkleTreeError::LeafIndexOutOfBounds(16, 16)), result.err());
+}
+
+#[test]
+fn verify_invalid_proof() {
+    let tree = MockTree::new(build_leaves(8)).unwrap();
+    let (leaf, proof) = tree.prove(5).unwrap();
+
+    // wrong index
+    let result = MockTree::verify(tree.root(), 4, &leaf, &proof);
+    assert_eq!(Err(MerkleTreeError::InvalidProof), result);
+    let result = MockTree::verify(tree.root(), 8, &leaf, &proof);
+    assert_eq!(Err(MerkleTreeError::LeafIndexOutOfBounds(8, 8)), result);
+
+    // wrong leaf
+    let mut invalid_leaf = leaf.clone();
+    invalid_leaf[0] += BaseElement::ONE;
+    let result = MockTree::verify(tree.root(), 5, &invalid_leaf, &proof);
+    assert_eq!(Err(MerkleTreeError::InvalidProof), result);
+
+    // wrong path
+    for i in 0..proof.len() {
+        let mut invalid_proof = proof.clone();
+        invalid_proof[i][1] += BaseElement::ONE;
+        let result = MockTree::verify(tree.root(), 5, &leaf, &invalid_proof);
+        assert_eq!(Err(MerkleTreeError::InvalidProof), result);
+    }
+    let result = MockTree::verify(tree.root(), 5, &leaf, &proof[..2]);
+    assert_eq!(Err(MerkleTreeError::LeafIndexOutOfBounds(4, 5)), result);
+    let result = MockTree::verify(tree.root(), 1, &leaf, &proof[..2]);
+    assert_eq!(Err(MerkleTreeError::InvalidProof), result);
+    let result = MockTree::verify(tree.root(), 0, &leaf, &[]);
+    assert_eq!(Err(MerkleTreeError::InvalidProof), result);
+}
+
+#[test]
+fn verify_invalid_node_lengths() {
+    let tree = MockTree::new(build_leaves(8)).unwrap();
+    let (leaf, proof) = tree.prove(4).unwrap();
+
+    // leaves of a wrong length are rejected
+    let mut invalid_leaf = leaf.clone();
+    invalid_leaf.push(BaseElement::ONE);
+    let result = MockTree::verify(tree.root(), 4, &invalid_leaf, &proof);
+    assert_eq!(Err(MerkleTreeError::InvalidLeafLength(4, 5)), result);
+
+    // path nodes of a wrong length are rejected
+    for i in 0..proof.len() {
+        let mut invalid_proof = proof.clone();
+        invalid_proof[i].pop();
+        let result = MockTree::verify(tree.root(), 4, &leaf, &invalid_proof);
+        assert_eq!(Err(MerkleTreeError::InvalidProof), result);
+    }
+}
+
+#[test]
+fn verify_rejects_shifted_leaf_elements() {
+    // moving an element from the sibling into the leaf does not change the concatenation of the
+    // two nodes, but it must not result in a valid opening
+    let digests: Vec<_> =
+        (0..4u32).map(|i| Rp64_256::hash_elements(&[BaseElement::from(i)])).collect();
+    let leaves: Vec<Vec<_>> = digests.iter().map(|digest| digest.as_elements().to_vec()).collect();
+    let tree = AlgebraicMerkleTree::<BaseElement, Rp64_256>::new(leaves).unwrap();
+    let (leaf, mut proof) = tree.prove(0).unwrap();
+
+    let mut shifted_leaf = leaf.clone();
+    shifted_leaf.push(proof[0].remove(0));
+    let result =
+        AlgebraicMerkleTree::<BaseElement, Rp64_256>::verify(tree.root(), 0, &shifted_leaf, &proof);
+    assert_eq!(Err(MerkleTreeError::InvalidLeafLength(4, 5)), result);
+}
+
+#[test]
+#[should_panic(expected = "a child must consist of 4 elements, but was 5")]
+fn rescue_hash_invalid_child_length() {
+    let elements = [BaseElement::ONE; 8];
+    Rp64_256::hash(&elements[..5], &elements[5..]);
+}
+
+#[test]
+fn rescue_tree_matches_merkle_tree() {
+    // for Rescue, algebraic hashing of two digests is the same as merging them, and thus, the
+    // algebraic tree has the same root as a regular Merkle tree built from the same leaves
+    let digests: Vec<_> =
+        (0..8u32).map(|i| Rp64_256::hash_elements(&[BaseElement::from(i)])).collect();
+    let leaves = digests.iter().map(|digest| digest.as_elements().to_vec()).collect();
+    let tree = AlgebraicMerkleTree::<BaseElement, Rp64_256>::new(leaves).unwrap();
+
+    let expected = MerkleTree::<Rp64_256>::new(digests).unwrap();
+    assert_eq!(expected.root().as_elements(), tree.root());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_leaves(n: u32) -> Vec<Vec<BaseElement>> {
+    (0..n).map(|i| MockPoseidon64::hash_elements(&[BaseElement::from(i)])).collect()
+}
+
+// MOCK POSEIDON HASHER
+// ================================================================================================
+
+/// A stand-in for a Poseidon hasher over the 64-bit field, used to test the tree with a hasher
+/// which is not a Rescue hasher.
+///
+/// This is not Poseidon: the sponge has the shape of Poseidon (a state of 8 elements, a rate of
+/// 4 elements, the x^7 S-box, 8 full rounds, and 22 partial rounds), but the MDS matrix and the
+/// round constants are made up, and thus, its outputs do not match any published test vectors.
+/// It must not be used outside of tests.
+struct MockPoseidon64;
+
+const STATE_WIDTH: usize = 8;
+const RATE_WIDTH: usize = 4;
+const NUM_FULL_ROUNDS: usize = 8;
+const NUM_PARTIAL_ROUNDS: usize = 22;
+
+impl AlgebraicHasher<BaseElement> for MockPoseidon64 {
+    const DIGEST_ELEMENTS: usize = 4;
+    const COLLISION_RESISTANCE: u32 = 128;
+
+    fn hash(left_child: &[BaseElement], right_child: &[BaseElement]) -> Vec<BaseElement> {
+        assert_eq!(Self::DIGEST_ELEMENTS, left_child.len());
+        assert_eq!(Self::DIGEST_ELEMENTS, right_child.len());
+        Self::hash_elements(&[left_child, right_child].concat())
+    }
+}
+
+impl MockPoseidon64 {
+    fn hash_elements(elements: &[BaseElement]) -> Vec<BaseElement> {
+        // the number of absorbed elements is injected into the capacity portion of the state so
+        // that no padding is needed
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[STATE_WIDTH - 1] = BaseElement::new(elements.len() as u64);
+        for chunk in elements.chunks(RATE_WIDTH) {
+            for (s, &e) in state.iter_mut().zip(chunk) {
+                *s += e;
+            }
+            apply_permutation(&mut state);
+        }
+        if elements.is_empty() {
+            apply_permutation(&mut state);
+        }
+
+        state[..Self::DIGEST_ELEMENTS].to_vec()
+    }
+}
+
+fn apply_permutation(state: &mut [BaseElement; STATE_WIDTH]) {
+    let mds = build_mds();
+    let half_full_rounds = NUM_FULL_ROUNDS / 2;
+    for round in 0..NUM_FULL_ROUNDS + NUM_PARTIAL_ROUNDS {
+        for (i, s) in state.iter_mut().enumerate() {
+            *s += round_constant(round, i);
+        }
+
+        let is_full_round =
+            round < half_full_rounds || round >= half_full_rounds + NUM_PARTIAL_ROUNDS;
+        let num_sboxes = if is_full_round { STATE_WIDTH } else { 1 };
+        for s in state.iter_mut().take(num_sboxes) {
+            *s = s.exp(7);
+        }
+
+        let mut result = [BaseElement::ZERO; STATE_WIDTH];
+        for (r, row) in result.iter_mut().zip(mds.iter()) {
+            *r = row
+                .iter()
+                .zip(state.iter())
+                .fold(BaseElement::ZERO, |acc, (&m, &s)| acc + m * s);
+        }
+        *state = result;
+    }
+}
+
+/// Returns a Cauchy matrix with entries 1 / (x_i - y_j) for x_i = i and y_j = -(STATE_WIDTH + j);
+/// every square submatrix of a Cauchy matrix is non-singular, and thus, the matrix is MDS.
+fn build_mds() -> [[BaseElement; STATE_WIDTH]; STATE_WIDTH] {
+    let mut result = [[BaseElement::ZERO; STATE_WIDTH]; STATE_WIDTH];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, m) in row.iter_mut().enumerate() {
+            *m = BaseElement::new((i + STATE_WIDTH + j) as u64).inv();
+        }
+    }
+    result
+}
+
+/// Derives a round constant from the round and position indexes using the SplitMix64 generator.
+fn round_constant(round: usize, i: usize) -> BaseElement {
+    let mut z = ((round * STATE_WIDTH + i) as u64)
+        .wrapping_add(1)
+        .wrapping_mul(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    BaseElement::new(z ^ (z >> 31))
+}
@@ -4,9 +4,8 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
-use core::slice;
 
-use utils::{iterators::*, rayon};
+use utils::{group_slice_elements, iterators::*};
 
 use crate::Hasher;
 
@@ -15,6 +14,9 @@ use crate::Hasher;
 
 pub const MIN_CONCURRENT_LEAVES: usize = 1024;
 
+/// Rows of internal nodes smaller than this number are computed in a single thread.
+const MIN_CONCURRENT_ROW_SIZE: usize = 64;
+
 // PUBLIC FUNCTIONS
 // ================================================================================================
 
@@ -26,49 +28,34 @@ pub const MIN_CONCURRENT_LEAVES: usize = 1024;
 pub fn build_merkle_nodes<H: Hasher>(leaves: &[H::Digest]) -> Vec<H::Digest> {
     let n = leaves.len() / 2;
 
-    // create un-initialized array to hold all intermediate nodes
-    let mut nodes = unsafe { utils::uninit_vector::<H::Digest>(2 * n) };
-    nodes[0] = H::Digest::default();
+    // create an array to hold all intermediate nodes; initializing it costs much less than
+    // computing the hashes
+    let mut nodes = vec![H::Digest::default(); 2 * n];
 
     // re-interpret leaves as an array of two leaves fused together and use it to
     // build first row of internal nodes (parents of leaves)
-    let two_leaves = unsafe { slice::from_raw_parts(leaves.as_ptr() as *const [H::Digest; 2], n) };
+    let two_leaves = group_slice_elements::<_, 2>(leaves);
     nodes[n..]
         .par_iter_mut()
         .zip(two_leaves.par_iter())
         .for_each(|(target, source)| *target = H::merge(source));
 
-    // calculate all other tree nodes, we can't use regular iterators  here because
-    // access patterns are rather complicated - so, we use regular threads instead
-
-    // number of sub-trees must always be a power of 2
-    let num_subtrees = rayon::current_num_threads().next_power_of_two();
-    let batch_size = n / num_subtrees;
-
-    // re-interpret nodes as an array of two nodes fused together
-    let two_nodes = unsafe { slice::from_raw_parts(nodes.as_ptr() as *const [H::Digest; 2], n) };
-
-    // process each subtree in a separate thread
-    rayon::scope(|s| {
-        for i in 0..num_subtrees {
-            let nodes = unsafe { &mut *(&mut nodes[..] as *mut [H::Digest]) };
-            s.spawn(move |_| {
-                let mut batch_size = batch_size / 2;
-                let mut start_idx = n / 2 + batch_size * i;
-                while start_idx >= num_subtrees {
-                    for k in (start_idx..(start_idx + batch_size)).rev() {
-                        nodes[k] = H::merge(&two_nodes[k]);
-                    }
-                    start_idx /= 2;
-                    batch_size /= 2;
-                }
-            });
-        }
-    });
+    // calculate all other tree nodes one row at a time; nodes of a row are stored at positions
+    // [row_start, 2 * row_start) and are computed from their children which are stored at
+    // positions [2 * row_start, 4 * row_start)
+    let mut row_start = n / 2;
+    while row_start >= MIN_CONCURRENT_ROW_SIZE {
+        let (parents, children) = nodes.split_at_mut(2 * row_start);
+        parents[row_start..]
+            .par_iter_mut()
+            .zip(children[..2 * row_start].par_chunks(2))
+            .for_each(|(target, source)| *target = H::merge(&[source[0], source[1]]));
+        row_start /= 2;
+    }
 
-    // finish the tip of the tree
-    for i in (1..num_subtrees).rev() {
-        nodes[i] = H::merge(&two_nodes[i]);
+    // finish the tip of the tree in a single thread
+    for i in (1..2 * row_start).rev() {
+        nodes[i] = H::merge(&[nodes[2 * i], nodes[2 * i + 1]]);
     }
 
     nodes
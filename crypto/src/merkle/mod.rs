@@ -7,12 +7,16 @@ use alloc::{
     collections::{BTreeMap, BTreeSet},
     vec::Vec,
 };
-use core::slice;
+
+use utils::group_slice_elements;
 
 mod proofs;
 pub use proofs::BatchMerkleProof;
 
-use crate::{Hasher, MerkleTreeError, VectorCommitment};
+mod algebraic;
+pub use algebraic::{AlgebraicMerkleTree, AlgebraicMerkleTreeOpening};
+
+use crate::{Digest, Hasher, MerkleTreeError, VectorCommitment};
 
 #[cfg(feature = "concurrent")]
 pub mod concurrent;
@@ -303,7 +307,7 @@ impl<H: Hasher> MerkleTree<H> {
             index >>= 1;
         }
 
-        if v != root {
+        if !v.ct_eq(&root) {
             return Err(MerkleTreeError::InvalidProof);
         }
         Ok(())
@@ -312,6 +316,11 @@ impl<H: Hasher> MerkleTree<H> {
     /// Checks whether the batch `proof` contains Merkle proofs resolving to `root` for
     /// the provided `leaves` at the specified `indexes`.
     ///
+    /// All paths are reconstructed simultaneously, and every node which lies on the path of at
+    /// least one of the leaves is computed exactly once. Thus, verifying a batch proof requires
+    /// fewer hash function invocations than verifying the proofs of each leaf individually
+    /// whenever the paths of the leaves share some nodes.
+    ///
     /// # Errors
     /// Returns an error if:
     /// * No indexes were provided (i.e., `indexes` is an empty slice).
@@ -325,7 +334,7 @@ impl<H: Hasher> MerkleTree<H> {
         leaves: &[H::Digest],
         proof: &BatchMerkleProof<H>,
     ) -> Result<(), MerkleTreeError> {
-        if *root != proof.get_root(indexes, leaves)? {
+        if !root.ct_eq(&proof.get_root(indexes, leaves)?) {
             return Err(MerkleTreeError::InvalidProof);
         }
         Ok(())
@@ -345,24 +354,21 @@ impl<H: Hasher> MerkleTree<H> {
 pub fn build_merkle_nodes<H: Hasher>(leaves: &[H::Digest]) -> Vec<H::Digest> {
     let n = leaves.len() / 2;
 
-    // create un-initialized array to hold all intermediate nodes
-    let mut nodes = unsafe { utils::uninit_vector::<H::Digest>(2 * n) };
-    nodes[0] = H::Digest::default();
+    // create an array to hold all intermediate nodes; initializing it costs much less than
+    // computing the hashes
+    let mut nodes = vec![H::Digest::default(); 2 * n];
 
     // re-interpret leaves as an array of two leaves fused together
-    let two_leaves = unsafe { slice::from_raw_parts(leaves.as_ptr() as *const [H::Digest; 2], n) };
+    let two_leaves = group_slice_elements::<_, 2>(leaves);
 
     // build first row of internal nodes (parents of leaves)
-    for (i, j) in (0..n).zip(n..nodes.len()) {
-        nodes[j] = H::merge(&two_leaves[i]);
+    for (node, leaves) in nodes[n..].iter_mut().zip(two_leaves) {
+        *node = H::merge(leaves);
     }
 
-    // re-interpret nodes as an array of two nodes fused together
-    let two_nodes = unsafe { slice::from_raw_parts(nodes.as_ptr() as *const [H::Digest; 2], n) };
-
     // calculate all other tree nodes
     for i in (1..n).rev() {
-        nodes[i] = H::merge(&two_nodes[i]);
+        nodes[i] = H::merge(&[nodes[2 * i], nodes[2 * i + 1]]);
     }
 
     nodes
@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{collections::BTreeMap, format, vec::Vec};
 
 use utils::{ByteReader, Deserializable, DeserializationError, Serializable};
 
@@ -409,9 +409,17 @@ impl<H: Hasher> Deserializable for BatchMerkleProof<H> {
     /// * `source` could not be deserialized into a valid set of internal nodes.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let depth = source.read_u8()?;
+        if depth as u32 >= usize::BITS {
+            return Err(DeserializationError::InvalidValue(format!(
+                "Merkle tree depth must be smaller than {}, but was {}",
+                usize::BITS,
+                depth
+            )));
+        }
         let num_node_vectors = source.read_usize()?;
 
-        let mut nodes = Vec::with_capacity(num_node_vectors);
+        // the number of node vectors is not trusted, and so the vector is not pre-allocated
+        let mut nodes = Vec::new();
         for _ in 0..num_node_vectors {
             // read the digests and add them to the node vector
             let digests = Vec::<_>::read_from(source)?;
@@ -3,8 +3,12 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::collections::BTreeSet;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
 use math::fields::f128::BaseElement;
 use proptest::prelude::*;
+use utils::{Deserializable, Serializable};
 
 use super::*;
 
@@ -212,6 +216,43 @@ fn verify_batch() {
     );
 }
 
+#[test]
+fn verify_batch_merges_each_node_once() {
+    // 80 queries against a tree with 2^12 leaves; many of the queried paths share nodes close to
+    // the root
+    let depth = 12;
+    let leaves = (0..1u64 << depth).map(|i| Blake3_256::hash(&i.to_le_bytes())).collect();
+    let tree = MerkleTree::<CountingBlake3>::new(leaves).unwrap();
+    let indexes: Vec<usize> = (0..80).map(|i| (i * 2_654_435_761) % (1 << depth)).collect();
+
+    // verifying each path independently merges `depth` nodes per query
+    let mut individual_merges = 0;
+    for &index in indexes.iter() {
+        let (leaf, path) = tree.prove(index).unwrap();
+        let merges_before = MERGE_COUNT.load(Ordering::Relaxed);
+        assert!(MerkleTree::<CountingBlake3>::verify(*tree.root(), index, leaf, &path).is_ok());
+        individual_merges += MERGE_COUNT.load(Ordering::Relaxed) - merges_before;
+    }
+    assert_eq!(indexes.len() * depth, individual_merges);
+
+    // verifying a batch proof merges every node on the union of the paths exactly once
+    let (leaves, proof) = tree.prove_batch(&indexes).unwrap();
+    let merges_before = MERGE_COUNT.load(Ordering::Relaxed);
+    assert!(MerkleTree::verify_batch(tree.root(), &indexes, &leaves, &proof).is_ok());
+    let batch_merges = MERGE_COUNT.load(Ordering::Relaxed) - merges_before;
+
+    let mut path_nodes = BTreeSet::new();
+    for &index in indexes.iter() {
+        let mut node = (1 << depth) + index;
+        while node > 1 {
+            node >>= 1;
+            path_nodes.insert(node);
+        }
+    }
+    assert_eq!(path_nodes.len(), batch_merges);
+    assert!(batch_merges < individual_merges);
+}
+
 #[test]
 fn verify_into_openings() {
     let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
@@ -254,6 +295,21 @@ fn from_proofs() {
     assert_eq!(proof1.depth, proof2.depth);
 }
 
+#[test]
+fn batch_proof_read_from_invalid_depth() {
+    let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let tree = MerkleTree::<Blake3_256>::new(leaves).unwrap();
+    let (_, proof) = tree.prove_batch(&[1, 2]).unwrap();
+
+    let mut bytes = proof.to_bytes();
+    let parsed = BatchMerkleProof::<Blake3_256>::read_from_bytes(&bytes).unwrap();
+    assert_eq!(proof, parsed);
+
+    // the depth is written first; a depth of 200 would overflow the size of the domain
+    bytes[0] = 200;
+    assert!(BatchMerkleProof::<Blake3_256>::read_from_bytes(&bytes).is_err());
+}
+
 proptest! {
     #[test]
     fn prove_n_verify(tree in random_blake3_merkle_tree(128),
@@ -313,6 +369,37 @@ proptest! {
 
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
+
+/// Number of invocations of [CountingBlake3::merge()].
+static MERGE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// BLAKE3 hasher which counts the number of merged node pairs.
+#[derive(Debug, PartialEq, Eq)]
+struct CountingBlake3;
+
+impl Hasher for CountingBlake3 {
+    type Digest = Digest256;
+
+    const COLLISION_RESISTANCE: u32 = Blake3_256::COLLISION_RESISTANCE;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        Blake3_256::hash(bytes)
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        MERGE_COUNT.fetch_add(1, Ordering::Relaxed);
+        Blake3_256::merge(values)
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        Blake3_256::merge_many(values)
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        Blake3_256::merge_with_int(seed, value)
+    }
+}
+
 fn hash_2x1(v1: Digest256, v2: Digest256) -> Digest256 {
     Blake3_256::merge(&[v1, v2])
 }
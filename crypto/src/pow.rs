@@ -0,0 +1,206 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use crate::{Digest, Hasher, RandomCoin};
+
+// PROOF-OF-WORK SCHEME
+// ================================================================================================
+
+/// Identifies a proof-of-work scheme used to grind the query seed.
+///
+/// Grinding requires the prover to find a nonce such that the output of the proof-of-work
+/// function applied to the query seed and the nonce satisfies the difficulty specified by the
+/// grinding factor. Provers and verifiers are instantiated with an implementation of the
+/// [ProofOfWork] trait, and this tag is written into the proof options so that proofs which were
+/// generated using one scheme are rejected by verifiers instantiated with another scheme.
+///
+/// Each variant corresponds to an implementation of the [ProofOfWork] trait whose
+/// [SCHEME](ProofOfWork::SCHEME) is set to this variant.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum PowScheme {
+    /// The public coin must report the required number of leading zeros for the nonce via
+    /// [RandomCoin::check_leading_zeros()]; for the built-in coins, this is the same as
+    /// [HashPow].
+    #[default]
+    Hash = 0,
+    /// BLAKE3 hash of the query seed and the nonce must have the required number of leading zeros
+    /// (see [Blake3Pow]); this requires a public coin which exposes its seed via
+    /// [RandomCoin::seed()].
+    Blake3 = 1,
+}
+
+impl Serializable for PowScheme {
+    /// Serializes `self` and writes the resulting bytes into the `target`.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(*self as u8);
+    }
+
+    /// Returns an estimate of how many bytes are needed to represent self.
+    fn get_size_hint(&self) -> usize {
+        1
+    }
+}
+
+impl Deserializable for PowScheme {
+    /// Reads a proof-of-work scheme enum from the specified `source`.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(PowScheme::Hash),
+            1 => Ok(PowScheme::Blake3),
+            value => Err(DeserializationError::InvalidValue(format!(
+                "value {value} cannot be deserialized as PowScheme enum"
+            ))),
+        }
+    }
+}
+
+// PROOF-OF-WORK TRAIT
+// ================================================================================================
+
+/// Defines a proof-of-work scheme used to grind the seed from which query positions are drawn.
+///
+/// A nonce is valid for a given `seed` and `difficulty` if [verify_nonce()](Self::verify_nonce)
+/// returns true for it. Finding a valid nonce should require on the order of 2^difficulty units
+/// of work, while verifying a nonce should be cheap.
+///
+/// The seed is a digest of the hash function `H` which the random coin of the protocol is
+/// instantiated with; however, a scheme is free to use any other function to process the seed.
+pub trait ProofOfWork<H: Hasher> {
+    /// Tag identifying this scheme in proof options.
+    const SCHEME: PowScheme;
+
+    /// Returns the smallest positive nonce which is valid for the specified `seed` and
+    /// `difficulty`.
+    ///
+    /// # Panics
+    /// Panics if a valid nonce could not be found.
+    fn find_nonce(seed: H::Digest, difficulty: u32) -> u64 {
+        (1..u64::MAX)
+            .find(|&nonce| Self::verify_nonce(seed, nonce, difficulty))
+            .expect("nonce not found")
+    }
+
+    /// Returns true if the specified `nonce` is valid for the specified `seed` and `difficulty`.
+    fn verify_nonce(seed: H::Digest, nonce: u64, difficulty: u32) -> bool;
+
+    /// Returns true if this scheme can be used with the specified public coin.
+    ///
+    /// By default, a scheme can be used only with coins which expose their seed via
+    /// [RandomCoin::seed()].
+    fn supports_coin<R: RandomCoin<Hasher = H>>(coin: &R) -> bool {
+        coin.seed().is_some()
+    }
+
+    /// Returns true if the specified `nonce` is valid for the current seed of the specified
+    /// public coin and `difficulty`.
+    ///
+    /// By default, this returns false for coins which do not expose their seed.
+    fn verify_coin_nonce<R: RandomCoin<Hasher = H>>(coin: &R, nonce: u64, difficulty: u32) -> bool {
+        coin.seed().is_some_and(|seed| Self::verify_nonce(seed, nonce, difficulty))
+    }
+}
+
+// HASH-BASED PROOF-OF-WORK
+// ================================================================================================
+
+/// Proof-of-work scheme which requires hash(`seed` || `nonce`) to have at least `difficulty`
+/// leading zeros, where the hash function is the hash function `H` of the random coin.
+///
+/// The leading zeros are counted in the same way as by [RandomCoin::check_leading_zeros()].
+/// When used with a public coin, the nonce is checked via
+/// [RandomCoin::check_leading_zeros()], and thus, this scheme can be used with coins which do
+/// not expose their seed.
+pub struct HashPow;
+
+impl<H: Hasher> ProofOfWork<H> for HashPow {
+    const SCHEME: PowScheme = PowScheme::Hash;
+
+    fn verify_nonce(seed: H::Digest, nonce: u64, difficulty: u32) -> bool {
+        count_leading_zeros(H::merge_with_int(seed, nonce).as_bytes()) >= difficulty
+    }
+
+    fn supports_coin<R: RandomCoin<Hasher = H>>(_coin: &R) -> bool {
+        true
+    }
+
+    fn verify_coin_nonce<R: RandomCoin<Hasher = H>>(coin: &R, nonce: u64, difficulty: u32) -> bool {
+        coin.check_leading_zeros(nonce) >= difficulty
+    }
+}
+
+// BLAKE3 PROOF-OF-WORK
+// ================================================================================================
+
+/// Proof-of-work scheme which requires BLAKE3(`seed` || `nonce`) to have at least `difficulty`
+/// leading zeros regardless of the hash function of the random coin.
+///
+/// This is useful when the hash function of the random coin is expensive to evaluate natively
+/// (e.g., an arithmetization-friendly hash function), as it makes grinding much cheaper for the
+/// prover without affecting the cost of verification much.
+pub struct Blake3Pow;
+
+impl<H: Hasher> ProofOfWork<H> for Blake3Pow {
+    const SCHEME: PowScheme = PowScheme::Blake3;
+
+    fn verify_nonce(seed: H::Digest, nonce: u64, difficulty: u32) -> bool {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&seed.as_bytes());
+        hasher.update(&nonce.to_le_bytes());
+        count_leading_zeros(*hasher.finalize().as_bytes()) >= difficulty
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the number of trailing zeros of the first 8 bytes of `bytes` interpreted as a
+/// little-endian integer; this is the same as the number of leading zeros of these bytes
+/// interpreted as a bit string.
+fn count_leading_zeros(bytes: [u8; 32]) -> u32 {
+    u64::from_le_bytes(bytes[..8].try_into().unwrap()).trailing_zeros()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use math::fields::f128::BaseElement;
+
+    use super::{Blake3Pow, HashPow, ProofOfWork};
+    use crate::{hashers::Blake3_256, DefaultRandomCoin, Hasher, RandomCoin};
+
+    type H = Blake3_256<BaseElement>;
+
+    #[test]
+    fn hash_pow_matches_random_coin() {
+        let coin = DefaultRandomCoin::<H>::new(&[BaseElement::new(42)]);
+        let seed = coin.seed().unwrap();
+        for nonce in 0..64 {
+            let zeros = coin.check_leading_zeros(nonce);
+            assert!(<HashPow as ProofOfWork<H>>::verify_nonce(seed, nonce, zeros));
+            assert!(!<HashPow as ProofOfWork<H>>::verify_nonce(seed, nonce, zeros + 1));
+        }
+    }
+
+    #[test]
+    fn find_and_verify_nonce() {
+        let seed = H::hash(&[1, 2, 3, 4]);
+        let difficulty = 8;
+
+        let nonce = <HashPow as ProofOfWork<H>>::find_nonce(seed, difficulty);
+        assert!(<HashPow as ProofOfWork<H>>::verify_nonce(seed, nonce, difficulty));
+
+        let nonce = <Blake3Pow as ProofOfWork<H>>::find_nonce(seed, difficulty);
+        assert!(<Blake3Pow as ProofOfWork<H>>::verify_nonce(seed, nonce, difficulty));
+
+        // the nonce is bound to the seed
+        let other_seed = H::hash(&[5, 6, 7, 8]);
+        assert!(!<Blake3Pow as ProofOfWork<H>>::verify_nonce(other_seed, nonce, difficulty));
+    }
+}
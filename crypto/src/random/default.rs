@@ -9,6 +9,13 @@ use math::{FieldElement, StarkField};
 
 use crate::{errors::RandomCoinError, Digest, ElementHasher, RandomCoin};
 
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of bytes in a digest; this also marks the output buffer of the coin as
+/// exhausted.
+const MAX_DIGEST_BYTES: usize = 32;
+
 // DEFAULT RANDOM COIN IMPLEMENTATION
 // ================================================================================================
 
@@ -18,15 +25,23 @@ use crate::{errors::RandomCoinError, Digest, ElementHasher, RandomCoin};
 /// A random coin can be used to draw elements uniformly at random from the specified base field
 /// or from any extension of the base field.
 ///
-/// Internally we use a cryptographic hash function (which is specified via the `H` type parameter),
-/// to draw elements from the field. The coin works roughly as follows:
-/// - The internal state of the coin consists of a `seed` and a `counter`. At instantiation
-///   time, the `seed` is set to a hash of the provided bytes, and the `counter` is set to 0.
-/// - To draw the next element, we increment the `counter` and compute hash(`seed` || `counter`).
-///   If the resulting value is a valid field element, we return the result; otherwise we try
-///   again until a valid element is found or the number of allowed tries is exceeded.
-/// - We can also re-seed the coin with a new value. During the reseeding procedure, the
-///   seed is set to hash(`old_seed` || `new_seed`), and the counter is reset to 0.
+/// The coin is a duplex sponge built on top of a cryptographic hash function (which is specified
+/// via the `H` type parameter). Data is absorbed into the coin and pseudo-random values are
+/// squeezed out of it, and the two operations can be interleaved in any order. Specifically:
+/// - The internal state of the coin consists of a `state` digest and an output buffer. At
+///   instantiation time, the `state` is set to a hash of the provided elements, and the output
+///   buffer is empty.
+/// - To absorb a digest, we set the `state` to hash(`state` || `digest`) and discard the output
+///   buffer. Variable-length sequences of elements are absorbed by hashing them into a digest
+///   first (see [DefaultRandomCoin::absorb_elements()]).
+/// - To squeeze pseudo-random values, we read bytes from the output buffer. Once the buffer is
+///   exhausted, it is refilled with hash(`state` || `counter`), where `counter` is the number of
+///   times the buffer has been refilled since the last absorption. Thus, a single invocation of
+///   the hash function can provide several pseudo-random values (e.g., four 64-bit integers for
+///   a 256-bit hash function).
+///
+/// Values squeezed from the coin depend on the full sequence of data absorbed into it, and on
+/// the number of values squeezed since the last absorption.
 ///
 /// # Examples
 /// ```
@@ -39,39 +54,113 @@ use crate::{errors::RandomCoinError, Digest, ElementHasher, RandomCoin};
 /// let mut coin = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(seed);
 ///
 /// // should draw different elements each time
-/// let e1 = coin.draw::<BaseElement>().unwrap();;
-/// let e2 = coin.draw::<BaseElement>().unwrap();;
+/// let e1 = coin.draw::<BaseElement>().unwrap();
+/// let e2 = coin.draw::<BaseElement>().unwrap();
 /// assert_ne!(e1, e2);
 ///
-/// let e3 = coin.draw::<BaseElement>().unwrap();;
+/// let e3 = coin.draw::<BaseElement>().unwrap();
 /// assert_ne!(e1, e3);
 /// assert_ne!(e2, e3);
 ///
 /// // should draw same elements for the same seed
-/// let mut coin2 = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(seed);
 /// let mut coin1 = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(seed);
-/// let e1 = coin1.draw::<BaseElement>().unwrap();;
-/// let e2 = coin2.draw::<BaseElement>().unwrap();;
+/// let mut coin2 = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(seed);
+/// let e1 = coin1.draw::<BaseElement>().unwrap();
+/// let e2 = coin2.draw::<BaseElement>().unwrap();
 /// assert_eq!(e1, e2);
 ///
 /// // should draw different elements based on seed
 /// let mut coin1 = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(seed);
 /// let seed = &[BaseElement::new(2), BaseElement::new(3), BaseElement::new(4), BaseElement::new(5)];
 /// let mut coin2 = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(seed);
-/// let e1 = coin1.draw::<BaseElement>().unwrap();;
-/// let e2 = coin2.draw::<BaseElement>().unwrap();;
+/// let e1 = coin1.draw::<BaseElement>().unwrap();
+/// let e2 = coin2.draw::<BaseElement>().unwrap();
 /// assert_ne!(e1, e2);
 /// ```
 pub struct DefaultRandomCoin<H: ElementHasher> {
-    seed: H::Digest,
+    state: H::Digest,
+    output: [u8; MAX_DIGEST_BYTES],
+    num_used_bytes: usize,
     counter: u64,
 }
 
 impl<H: ElementHasher> DefaultRandomCoin<H> {
-    /// Updates the state by incrementing the counter and returns hash(seed || counter)
-    fn next(&mut self) -> H::Digest {
-        self.counter += 1;
-        H::merge_with_int(self.seed, self.counter)
+    /// Number of pseudo-random bytes provided by a single digest of the hash function.
+    const NUM_DIGEST_BYTES: usize = H::COLLISION_RESISTANCE as usize / 4;
+
+    // ABSORBING
+    // --------------------------------------------------------------------------------------------
+
+    /// Absorbs the specified sequence of elements into the coin.
+    ///
+    /// The elements are hashed into a single digest, and the digest is then absorbed in the same
+    /// way as in [RandomCoin::reseed()]. Because the hash of a sequence is bound to its length,
+    /// absorbing sequences of different lengths one after another is unambiguous.
+    ///
+    /// # Examples
+    /// ```
+    /// # use winter_crypto::{RandomCoin, DefaultRandomCoin, Hasher, hashers::Blake3_256};
+    /// # use math::fields::f128::BaseElement;
+    /// let seed = &[BaseElement::new(1), BaseElement::new(2)];
+    /// let mut coin1 = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(seed);
+    /// let mut coin2 = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(seed);
+    ///
+    /// // absorbing the same elements split into different sequences results in different draws
+    /// coin1.absorb_elements(&[BaseElement::new(3), BaseElement::new(4)]);
+    /// coin2.absorb_elements(&[BaseElement::new(3)]);
+    /// coin2.absorb_elements(&[BaseElement::new(4)]);
+    /// assert_ne!(coin1.draw::<BaseElement>().unwrap(), coin2.draw::<BaseElement>().unwrap());
+    /// ```
+    pub fn absorb_elements<E>(&mut self, elements: &[E])
+    where
+        E: FieldElement<BaseField = H::BaseField>,
+    {
+        self.absorb(H::hash_elements(elements));
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the state of the coin to hash(`state` || `data`) and discards the output buffer.
+    fn absorb(&mut self, data: H::Digest) {
+        self.state = H::merge(&[self.state, data]);
+        self.reset_output();
+    }
+
+    /// Sets the state of the coin to hash(`state` || `value`) and discards the output buffer.
+    fn absorb_int(&mut self, value: u64) {
+        self.state = H::merge_with_int(self.state, value);
+        self.reset_output();
+    }
+
+    /// Discards all unused bytes in the output buffer and resets the squeeze counter.
+    fn reset_output(&mut self) {
+        self.num_used_bytes = MAX_DIGEST_BYTES;
+        self.counter = 0;
+    }
+
+    /// Returns the next `num_bytes` of pseudo-random output in the first `num_bytes` of the
+    /// returned array, refilling the output buffer if not enough unused bytes are left in it.
+    ///
+    /// If `num_bytes` is greater than the size of the digest, the bytes of a freshly computed
+    /// digest padded with zeros are returned.
+    fn squeeze(&mut self, num_bytes: usize) -> [u8; MAX_DIGEST_BYTES] {
+        assert!(
+            num_bytes <= MAX_DIGEST_BYTES,
+            "cannot squeeze more than {MAX_DIGEST_BYTES} bytes at once, but requested {num_bytes}"
+        );
+
+        if self.num_used_bytes + num_bytes > Self::NUM_DIGEST_BYTES {
+            self.counter += 1;
+            self.output = H::merge_with_int(self.state, self.counter).as_bytes();
+            self.num_used_bytes = 0;
+        }
+
+        let mut result = [0; MAX_DIGEST_BYTES];
+        result[..num_bytes]
+            .copy_from_slice(&self.output[self.num_used_bytes..self.num_used_bytes + num_bytes]);
+        self.num_used_bytes += num_bytes;
+        result
     }
 }
 
@@ -83,14 +172,20 @@ impl<B: StarkField, H: ElementHasher<BaseField = B>> RandomCoin for DefaultRando
     // --------------------------------------------------------------------------------------------
     /// Returns a new random coin instantiated with the provided `seed`.
     fn new(seed: &[Self::BaseField]) -> Self {
-        let seed = H::hash_elements(seed);
-        Self { seed, counter: 0 }
+        let state = H::hash_elements(seed);
+        Self {
+            state,
+            output: [0; MAX_DIGEST_BYTES],
+            num_used_bytes: MAX_DIGEST_BYTES,
+            counter: 0,
+        }
     }
 
     // RESEEDING
     // --------------------------------------------------------------------------------------------
 
-    /// Reseeds the coin with the specified data by setting the new seed to hash(`seed` || `data`).
+    /// Reseeds the coin with the specified data by setting the new state to hash(`state` ||
+    /// `data`) and discarding all pseudo-random output which has not been used yet.
     ///
     /// # Examples
     /// ```
@@ -104,30 +199,34 @@ impl<B: StarkField, H: ElementHasher<BaseField = B>> RandomCoin for DefaultRando
     ///
     /// // should draw the same element form both coins
     /// let e1 = coin1.draw::<BaseElement>().unwrap();
-    /// let e2 = coin2.draw::<BaseElement>().unwrap();;
+    /// let e2 = coin2.draw::<BaseElement>().unwrap();
     /// assert_eq!(e1, e2);
     ///
     /// // after reseeding should draw different elements
     /// coin2.reseed(Blake3_256::<BaseElement>::hash(&[2, 3, 4, 5]));
-    /// let e1 = coin1.draw::<BaseElement>().unwrap();;
-    /// let e2 = coin2.draw::<BaseElement>().unwrap();;
+    /// let e1 = coin1.draw::<BaseElement>().unwrap();
+    /// let e2 = coin2.draw::<BaseElement>().unwrap();
     /// assert_ne!(e1, e2);
     /// ```
     fn reseed(&mut self, data: H::Digest) {
-        self.seed = H::merge(&[self.seed, data]);
-        self.counter = 0;
+        self.absorb(data);
     }
 
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
-    /// Computes hash(`seed` || `value`) and returns the number of leading zeros in the resulting
+    /// Returns the current state of the coin.
+    fn seed(&self) -> Option<H::Digest> {
+        Some(self.state)
+    }
+
+    /// Computes hash(`state` || `value`) and returns the number of leading zeros in the resulting
     /// value if it is interpreted as an integer in big-endian byte order.
     fn check_leading_zeros(&self, value: u64) -> u32 {
-        let new_seed = H::merge_with_int(self.seed, value);
-        let bytes = new_seed.as_bytes();
-        let seed_head = u64::from_le_bytes(bytes[..8].try_into().unwrap());
-        seed_head.trailing_zeros()
+        let new_state = H::merge_with_int(self.state, value);
+        let bytes = new_state.as_bytes();
+        let state_head = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+        state_head.trailing_zeros()
     }
 
     // DRAW METHODS
@@ -135,18 +234,19 @@ impl<B: StarkField, H: ElementHasher<BaseField = B>> RandomCoin for DefaultRando
 
     /// Returns the next pseudo-random field element.
     ///
+    /// Elements are drawn using rejection sampling: if the squeezed bytes do not encode a valid
+    /// field element (e.g., the encoded integer is greater than or equal to the field modulus),
+    /// they are discarded and the next bytes are squeezed from the coin. Thus, the returned
+    /// elements are not biased towards small values.
+    ///
     /// # Errors
-    /// Returns an error if a valid field element could not be generated after 1000 calls to the
-    /// PRNG.
+    /// Returns an error if a valid field element could not be generated after 1000 attempts.
     fn draw<E: FieldElement>(&mut self) -> Result<E, RandomCoinError> {
         for _ in 0..1000 {
-            // get the next pseudo-random value and take the first ELEMENT_BYTES from it
-            let value = self.next();
-            let bytes = &value.as_bytes()[..E::ELEMENT_BYTES];
-
-            // check if the bytes can be converted into a valid field element; if they can,
-            // return; otherwise try again
-            if let Some(element) = E::from_random_bytes(bytes) {
+            // squeeze the next ELEMENT_BYTES from the coin and check if they can be converted
+            // into a valid field element; if they can, return; otherwise try again
+            let bytes = self.squeeze(E::ELEMENT_BYTES);
+            if let Some(element) = E::from_random_bytes(&bytes[..E::ELEMENT_BYTES]) {
                 return Ok(element);
             }
         }
@@ -154,21 +254,24 @@ impl<B: StarkField, H: ElementHasher<BaseField = B>> RandomCoin for DefaultRando
         Err(RandomCoinError::FailedToDrawFieldElement(1000))
     }
 
-    /// Returns a vector of integers selected from the range [0, domain_size) after reseeding
-    /// the PRNG with the specified `nonce` by setting the new seed to hash(`seed` || `nonce`).
+    /// Returns a vector of integers selected from the range [0, domain_size) after absorbing
+    /// the specified `nonce` into the coin by setting the new state to hash(`state` || `nonce`).
+    ///
+    /// Unlike [LegacyRandomCoin](crate::LegacyRandomCoin), `domain_size` does not need to be a
+    /// power of two. To avoid modular bias for such domains, integers are drawn using rejection
+    /// sampling: a squeezed 64-bit value `v` is discarded if it is smaller than $2^{64} \bmod$
+    /// `domain_size`, and `v mod domain_size` is returned otherwise. For power-of-two domains,
+    /// no values are discarded and the result is equal to the low bits of `v`.
     ///
     /// # Errors
     /// Returns an error if the specified number of integers could not be generated after 1000
-    /// calls to the PRNG.
+    /// attempts.
     ///
     /// # Panics
-    /// Panics if:
-    /// - `domain_size` is not a power of two.
-    /// - `num_values` is greater than or equal to `domain_size`.
+    /// Panics if `num_values` is greater than or equal to `domain_size`.
     ///
     /// # Examples
     /// ```
-    /// # use std::collections::HashSet;
     /// # use winter_crypto::{RandomCoin, DefaultRandomCoin, Hasher, hashers::Blake3_256};
     /// # use math::fields::f128::BaseElement;
     /// // initial elements for seeding the random coin
@@ -193,27 +296,28 @@ impl<B: StarkField, H: ElementHasher<BaseField = B>> RandomCoin for DefaultRando
         domain_size: usize,
         nonce: u64,
     ) -> Result<Vec<usize>, RandomCoinError> {
-        assert!(domain_size.is_power_of_two(), "domain size must be a power of two");
         assert!(num_values < domain_size, "number of values must be smaller than domain size");
 
-        // reseed with nonce
-        self.seed = H::merge_with_int(self.seed, nonce);
-        self.counter = 0;
+        // absorb the nonce
+        self.absorb_int(nonce);
 
-        // determine how many bits are needed to represent valid values in the domain
-        let v_mask = (domain_size - 1) as u64;
+        // values smaller than 2^64 mod domain_size are rejected, so that the number of accepted
+        // values is a multiple of the domain size; this is zero for power-of-two domains
+        let domain_size = domain_size as u64;
+        let rejection_threshold = (u64::MAX % domain_size + 1) % domain_size;
 
-        // draw values from PRNG until we get as many unique values as specified by num_queries
+        // squeeze values from the coin until we get as many values as specified by num_values
         let mut values = Vec::new();
         for _ in 0..1000 {
-            // get the next pseudo-random value and read the first 8 bytes from it
-            let bytes: [u8; 8] = self.next().as_bytes()[..8].try_into().unwrap();
-
-            // convert to integer and limit the integer to the number of bits which can fit
-            // into the specified domain
-            let value = (u64::from_le_bytes(bytes) & v_mask) as usize;
+            // squeeze the next 8 bytes, convert them to an integer, and reduce the integer into
+            // the specified domain unless it needs to be rejected
+            let bytes: [u8; 8] = self.squeeze(8)[..8].try_into().unwrap();
+            let value = u64::from_le_bytes(bytes);
+            if value < rejection_threshold {
+                continue;
+            }
 
-            values.push(value);
+            values.push((value % domain_size) as usize);
             if values.len() == num_values {
                 break;
             }
@@ -226,3 +330,224 @@ impl<B: StarkField, H: ElementHasher<BaseField = B>> RandomCoin for DefaultRando
         Ok(values)
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use math::fields::{f128::BaseElement, f62};
+    use utils::Randomizable;
+
+    use super::*;
+    use crate::{
+        hashers::{Blake3_192, Sha3_256},
+        Hasher,
+    };
+
+    type Sha3 = Sha3_256<BaseElement>;
+
+    #[test]
+    fn draw_fixed_vectors() {
+        // expected values were computed with an independent Python implementation of the coin
+        // instantiated with SHA3-256
+        let seed = [1, 2, 3, 4].map(BaseElement::new);
+        let mut coin = DefaultRandomCoin::<Sha3>::new(&seed);
+
+        // two elements are squeezed out of a single digest
+        let expected = [
+            BaseElement::new(127224200145228060360587880047751208771),
+            BaseElement::new(189519248624220196311541049129192337408),
+            BaseElement::new(37216346461294889473951000890985833385),
+        ];
+        for expected in expected {
+            assert_eq!(expected, coin.draw::<BaseElement>().unwrap());
+        }
+
+        coin.reseed(Sha3::hash(&[5, 6, 7, 8]));
+        assert_eq!(
+            BaseElement::new(290410941211031236200082888643198370757),
+            coin.draw::<BaseElement>().unwrap()
+        );
+
+        coin.absorb_elements(&[BaseElement::new(9)]);
+        assert_eq!(
+            BaseElement::new(86663209270179089124894560188743928503),
+            coin.draw::<BaseElement>().unwrap()
+        );
+
+        assert_eq!(vec![23, 17, 10, 48, 47], coin.draw_integers(5, 64, 42).unwrap());
+    }
+
+    #[test]
+    fn draw_integers_uses_full_digests() {
+        let seed = [1, 2, 3, 4].map(BaseElement::new);
+        let mut coin = DefaultRandomCoin::<Sha3>::new(&seed);
+        let values = coin.draw_integers(9, 1 << 20, 7).unwrap();
+
+        // a 256-bit digest provides four 64-bit values; thus, 9 values are read from 3 digests
+        let state = Sha3::merge_with_int(Sha3::hash_elements(&seed), 7);
+        let expected = (1..=3)
+            .flat_map(|counter| {
+                let bytes = Sha3::merge_with_int(state, counter).as_bytes();
+                bytes
+                    .chunks(8)
+                    .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()) as usize & 0xfffff)
+                    .collect::<Vec<_>>()
+            })
+            .take(9)
+            .collect::<Vec<_>>();
+        assert_eq!(expected, values);
+    }
+
+    #[test]
+    fn draw_integers_rejects_biased_values() {
+        // for a domain of size 2^63 + 1, values smaller than 2^64 mod (2^63 + 1) = 2^63 - 1 are
+        // rejected; roughly half of the squeezed values fall into this range
+        let domain_size = (1_usize << 63) + 1;
+        let seed = [1, 2, 3, 4].map(BaseElement::new);
+        let mut coin = DefaultRandomCoin::<Sha3>::new(&seed);
+        let values = coin.draw_integers(8, domain_size, 7).unwrap();
+
+        let state = Sha3::merge_with_int(Sha3::hash_elements(&seed), 7);
+        let expected = (1..)
+            .flat_map(|counter| {
+                let bytes = Sha3::merge_with_int(state, counter).as_bytes();
+                bytes
+                    .chunks(8)
+                    .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|&value| value >= (1 << 63) - 1)
+            .map(|value| (value % domain_size as u64) as usize)
+            .take(8)
+            .collect::<Vec<_>>();
+        assert_eq!(expected, values);
+    }
+
+    #[test]
+    fn draw_integers_distribution() {
+        // for non-power-of-two domains, the number of values in each bucket should pass a
+        // chi-square test at significance level 0.001
+        let seed = [1, 2, 3, 4].map(BaseElement::new);
+        let mut coin = DefaultRandomCoin::<Sha3>::new(&seed);
+        for (domain_size, critical_value) in [(3, 13.816), (6, 20.515), (12, 31.264)] {
+            let mut counts = vec![0; domain_size];
+            for nonce in 0..1000 {
+                for value in coin.draw_integers(domain_size - 1, domain_size, nonce).unwrap() {
+                    counts[value] += 1;
+                }
+            }
+            assert!(chi_square(&counts) < critical_value, "{domain_size}: {counts:?}");
+        }
+    }
+
+    #[test]
+    fn draw_elements_distribution() {
+        // elements of the 62-bit field are drawn from 8 random bytes, and thus, about 3/4 of
+        // the draws are rejected; when split into 16 buckets by their high bits, the accepted
+        // elements should pass a chi-square test at significance level 0.001
+        let seed = [1, 2, 3, 4].map(f62::BaseElement::new);
+        let mut coin = DefaultRandomCoin::<Sha3_256<f62::BaseElement>>::new(&seed);
+
+        let bucket_size = f62::BaseElement::MODULUS / 16 + 1;
+        let mut counts = vec![0; 16];
+        for _ in 0..16000 {
+            let element: f62::BaseElement = coin.draw().unwrap();
+            counts[(element.as_int() / bucket_size) as usize] += 1;
+        }
+        assert!(chi_square(&counts) < 37.697, "{counts:?}");
+    }
+
+    #[test]
+    fn draw_with_short_digests() {
+        // a 192-bit digest provides only a single 128-bit element, so each draw should use a
+        // separate digest
+        let seed = [1, 2, 3, 4].map(BaseElement::new);
+        let mut coin = DefaultRandomCoin::<Blake3_192<BaseElement>>::new(&seed);
+        let state = Blake3_192::<BaseElement>::hash_elements(&seed);
+
+        for counter in 1..=3 {
+            let bytes = Blake3_192::<BaseElement>::merge_with_int(state, counter).as_bytes();
+            let expected = BaseElement::from_random_bytes(&bytes[..16]);
+            assert_eq!(expected, Some(coin.draw::<BaseElement>().unwrap()));
+        }
+    }
+
+    #[test]
+    fn check_leading_zeros_matches_draw_integers() {
+        // the prover grinds a nonce using `check_leading_zeros()` and the verifier absorbs the
+        // same nonce when drawing query positions; both must be derived from the same state
+        let seed = [1, 2, 3, 4].map(BaseElement::new);
+        let mut coin = DefaultRandomCoin::<Sha3>::new(&seed);
+        coin.draw::<BaseElement>().unwrap();
+
+        let nonce = 42;
+        let leading_zeros = coin.check_leading_zeros(nonce);
+        coin.draw_integers(4, 64, nonce).unwrap();
+
+        let bytes = coin.state.as_bytes();
+        assert_eq!(
+            u64::from_le_bytes(bytes[..8].try_into().unwrap()).trailing_zeros(),
+            leading_zeros
+        );
+    }
+
+    #[test]
+    fn reseed_discards_unused_output() {
+        let seed = [1, 2, 3, 4].map(BaseElement::new);
+        let data = Sha3::hash(&[5, 6, 7, 8]);
+
+        // coin1 draws an element before reseeding, leaving half of a digest unused
+        let mut coin1 = DefaultRandomCoin::<Sha3>::new(&seed);
+        coin1.draw::<BaseElement>().unwrap();
+        coin1.reseed(data);
+
+        let mut coin2 = DefaultRandomCoin::<Sha3>::new(&seed);
+        coin2.reseed(data);
+
+        assert_eq!(coin1.state, coin2.state);
+        assert_eq!(coin1.draw::<BaseElement>().unwrap(), coin2.draw::<BaseElement>().unwrap());
+    }
+
+    #[test]
+    fn reseed_with_label() {
+        let seed = [1, 2, 3, 4].map(BaseElement::new);
+        let data = Sha3::hash(&[5, 6, 7, 8]);
+
+        let reseeded = |label: Option<&'static [u8]>| {
+            let mut coin = DefaultRandomCoin::<Sha3>::new(&seed);
+            match label {
+                Some(label) => coin.reseed_with_label(label, data),
+                None => coin.reseed(data),
+            }
+            coin.state
+        };
+
+        // the label is absorbed together with the data
+        let mut expected = DefaultRandomCoin::<Sha3>::new(&seed);
+        expected.reseed(Sha3::merge(&[Sha3::hash(b"test:a:v1"), data]));
+        assert_eq!(expected.state, reseeded(Some(b"test:a:v1")));
+
+        // the same data absorbed under different labels (or without a label) results in
+        // different transcripts
+        assert_ne!(reseeded(Some(b"test:a:v1")), reseeded(Some(b"test:b:v1")));
+        assert_ne!(reseeded(Some(b"test:a:v1")), reseeded(Some(b"test:a:v2")));
+        assert_ne!(reseeded(Some(b"test:a:v1")), reseeded(None));
+    }
+
+    #[test]
+    #[should_panic(expected = "domain separation label must not be empty")]
+    fn reseed_with_empty_label() {
+        let mut coin = DefaultRandomCoin::<Sha3>::new(&[BaseElement::ONE]);
+        coin.reseed_with_label(b"", Sha3::hash(&[1, 2, 3]));
+    }
+
+    /// Returns the chi-square statistic for the specified bucket counts against the uniform
+    /// distribution.
+    fn chi_square(counts: &[usize]) -> f64 {
+        let total: usize = counts.iter().sum();
+        let expected = total as f64 / counts.len() as f64;
+        counts.iter().map(|&c| (c as f64 - expected).powi(2) / expected).sum()
+    }
+}
@@ -12,6 +12,12 @@ use crate::{errors::RandomCoinError, ElementHasher, Hasher};
 mod default;
 pub use default::DefaultRandomCoin;
 
+mod legacy;
+pub use legacy::LegacyRandomCoin;
+
+mod unlabeled;
+pub use unlabeled::UnlabeledRandomCoin;
+
 // RANDOM COIN TRAIT
 // ================================================================================================
 
@@ -58,7 +64,8 @@ pub trait RandomCoin: Sync {
     ///
     /// # Panics
     /// Panics if:
-    /// - `domain_size` is not a power of two.
+    /// - `domain_size` is not supported by the coin; e.g., some coins support only power-of-two
+    ///   domains.
     /// - `num_values` is greater than or equal to `domain_size`.
     fn draw_integers(
         &mut self,
@@ -66,4 +73,32 @@ pub trait RandomCoin: Sync {
         domain_size: usize,
         nonce: u64,
     ) -> Result<Vec<usize>, RandomCoinError>;
+
+    // PROVIDED METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the current seed of the coin, if the coin exposes it.
+    ///
+    /// The seed is required by proof-of-work schemes which do not grind the query seed via
+    /// [check_leading_zeros()](RandomCoin::check_leading_zeros) (e.g.,
+    /// [Blake3Pow](crate::Blake3Pow)); proofs using such schemes cannot be generated or verified
+    /// with coins which do not expose their seed. By default, `None` is returned.
+    fn seed(&self) -> Option<<Self::Hasher as Hasher>::Digest> {
+        None
+    }
+
+    /// Reseeds the coin with the specified data tagged with a domain separation `label`.
+    ///
+    /// The coin is reseeded with hash(hash(`label`) || `data`), and thus, the same data absorbed
+    /// under different labels results in different transcripts. Labels are expected to be
+    /// versioned (e.g., `b"winterfell:trace-commit:v1"`) so that a change to the way data is
+    /// absorbed results in a different transcript as well.
+    ///
+    /// # Panics
+    /// Panics if `label` is empty.
+    fn reseed_with_label(&mut self, label: &'static [u8], data: <Self::Hasher as Hasher>::Digest) {
+        assert!(!label.is_empty(), "domain separation label must not be empty");
+        let label = Self::Hasher::hash(label);
+        self.reseed(Self::Hasher::merge(&[label, data]));
+    }
 }
@@ -0,0 +1,85 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::FieldElement;
+
+use super::RandomCoin;
+use crate::{errors::RandomCoinError, Hasher};
+
+// UNLABELED RANDOM COIN
+// ================================================================================================
+
+/// Random coin which ignores domain separation labels.
+///
+/// This coin wraps another random coin and delegates all calls to it, except for
+/// [reseed_with_label()](RandomCoin::reseed_with_label()) which reseeds the inner coin with the
+/// data directly, without absorbing the label first. This can be used to reproduce transcripts
+/// which were generated before domain separation labels were introduced (i.e., transcripts of
+/// proofs with format versions 1 and 2).
+///
+/// # Examples
+/// ```
+/// # use winter_crypto::{
+/// #    DefaultRandomCoin, Hasher, RandomCoin, UnlabeledRandomCoin, hashers::Blake3_256,
+/// # };
+/// # use math::fields::f128::BaseElement;
+/// type Blake3 = Blake3_256<BaseElement>;
+///
+/// let seed = &[BaseElement::new(1), BaseElement::new(2), BaseElement::new(3), BaseElement::new(4)];
+/// let data = Blake3::hash(&[2, 3, 4, 5]);
+///
+/// // labels are ignored by the unlabeled coin
+/// let mut coin1 = UnlabeledRandomCoin::<DefaultRandomCoin<Blake3>>::new(seed);
+/// let mut coin2 = DefaultRandomCoin::<Blake3>::new(seed);
+/// coin1.reseed_with_label(b"label", data);
+/// coin2.reseed(data);
+/// assert_eq!(coin1.draw::<BaseElement>().unwrap(), coin2.draw::<BaseElement>().unwrap());
+/// ```
+pub struct UnlabeledRandomCoin<R: RandomCoin>(R);
+
+impl<R: RandomCoin> RandomCoin for UnlabeledRandomCoin<R> {
+    type BaseField = R::BaseField;
+    type Hasher = R::Hasher;
+
+    fn new(seed: &[Self::BaseField]) -> Self {
+        Self(R::new(seed))
+    }
+
+    fn reseed(&mut self, data: <Self::Hasher as Hasher>::Digest) {
+        self.0.reseed(data)
+    }
+
+    /// Reseeds the inner coin with the specified data; the `label` is ignored.
+    ///
+    /// # Panics
+    /// Panics if `label` is empty.
+    fn reseed_with_label(&mut self, label: &'static [u8], data: <Self::Hasher as Hasher>::Digest) {
+        assert!(!label.is_empty(), "domain separation label must not be empty");
+        self.0.reseed(data)
+    }
+
+    fn seed(&self) -> Option<<Self::Hasher as Hasher>::Digest> {
+        self.0.seed()
+    }
+
+    fn check_leading_zeros(&self, value: u64) -> u32 {
+        self.0.check_leading_zeros(value)
+    }
+
+    fn draw<E: FieldElement<BaseField = Self::BaseField>>(&mut self) -> Result<E, RandomCoinError> {
+        self.0.draw()
+    }
+
+    fn draw_integers(
+        &mut self,
+        num_values: usize,
+        domain_size: usize,
+        nonce: u64,
+    ) -> Result<Vec<usize>, RandomCoinError> {
+        self.0.draw_integers(num_values, domain_size, nonce)
+    }
+}
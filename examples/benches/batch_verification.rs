@@ -0,0 +1,49 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Benchmarks verification of a batch of proofs read from their serialized form.
+//!
+//! The verifier parses queried values via borrowed tables which re-interpret proof bytes in place
+//! when the layout of field elements permits it. Elements of the fields provided by the math
+//! crate are stored in Montgomery form, and thus, for these fields, queried values are still
+//! copied out of the proof bytes.
+
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use examples::{fibonacci, Example};
+use winterfell::{
+    crypto::hashers::Blake3_256, math::fields::f128::BaseElement, FieldExtension, Proof,
+    ProofOptions,
+};
+
+const TRACE_LENGTH: usize = 1024;
+const BATCH_SIZES: [usize; 3] = [16, 64, 256];
+
+fn batch_verification(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_verification");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    let options = ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 255);
+    let fib = fibonacci::fib2::FibExample::<Blake3_256<BaseElement>>::new(TRACE_LENGTH, options);
+    let proof_bytes = fib.prove().to_bytes();
+
+    for &batch_size in BATCH_SIZES.iter() {
+        let batch = vec![proof_bytes.clone(); batch_size];
+        group.bench_function(BenchmarkId::from_parameter(batch_size), |bench| {
+            bench.iter(|| {
+                for bytes in batch.iter() {
+                    let proof = Proof::from_bytes(bytes).expect("failed to parse proof");
+                    fib.verify(proof).expect("failed to verify proof");
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(batch_verification_group, batch_verification);
+criterion_main!(batch_verification_group);
@@ -8,7 +8,7 @@ use std::time::Instant;
 
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    crypto::{DefaultRandomCoin, ElementHasher, HashPow, MerkleTree},
     math::{fields::f128::BaseElement, FieldElement},
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
@@ -17,10 +17,10 @@ use super::utils::compute_fib_term;
 use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha3_256};
 
 mod air;
-use air::FibAir;
+pub(crate) use air::FibAir;
 
 mod prover;
-use prover::FibProver;
+pub(crate) use prover::FibProver;
 
 #[cfg(test)]
 mod tests;
@@ -37,7 +37,7 @@ pub fn get_example(
     options: &ExampleOptions,
     sequence_length: usize,
 ) -> Result<Box<dyn Example>, String> {
-    let (options, hash_fn) = options.to_proof_options(28, 8);
+    let (options, hash_fn) = options.to_proof_options(28, 8, 1);
 
     match hash_fn {
         HashFunction::Blake3_192 => {
@@ -115,7 +115,7 @@ where
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
 
-        winterfell::verify::<FibAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<FibAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             self.result,
             &acceptable_options,
@@ -125,7 +125,7 @@ where
     fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<FibAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<FibAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             self.result + BaseElement::ONE,
             &acceptable_options,
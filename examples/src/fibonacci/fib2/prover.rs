@@ -4,9 +4,11 @@
 // LICENSE file in the root directory of this source tree.
 
 use winterfell::{
-    crypto::MerkleTree, matrix::ColMatrix, AuxRandElements, CompositionPoly, CompositionPolyTrace,
-    ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
-    DefaultTraceLde, PartitionOptions, StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable,
+    crypto::{HashPow, MerkleTree},
+    matrix::ColMatrix,
+    AuxRandElements, CompositionPoly, CompositionPolyTrace, ConstraintCompositionCoefficients,
+    DefaultConstraintCommitment, DefaultConstraintEvaluator, DefaultTraceLde, PartitionOptions,
+    StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable,
 };
 
 use super::{
@@ -58,6 +60,7 @@ where
     type HashFn = H;
     type VC = MerkleTree<H>;
     type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type ProofOfWork = HashPow;
     type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
         DefaultTraceLde<E, Self::HashFn, Self::VC>;
     type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
@@ -3,7 +3,17 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::{super::utils::build_proof_options, Blake3_256};
+use core_utils::{Deserializable, Serializable};
+use winterfell::{
+    crypto::{DefaultRandomCoin, HashPow, MerkleTree},
+    estimate_security,
+    math::{fields::f128::BaseElement, FieldElement, StarkField},
+    AcceptableOptions, Air, ConstraintType, FieldExtension, Proof, ProofOptions, Prover,
+    ProverError, Trace, TraceValidationError, VerifierError,
+};
+
+use super::{super::utils::build_proof_options, Blake3_256, FibAir, FibProver};
+use crate::Example;
 
 #[test]
 fn fib2_test_basic_proof_verification() {
@@ -22,3 +32,163 @@ fn fib2_test_basic_proof_verification_fail() {
     let fib = Box::new(super::FibExample::<Blake3_256>::new(16, build_proof_options(false)));
     crate::tests::test_basic_proof_verification_fail(fib);
 }
+
+#[test]
+fn fib2_test_estimate_security() {
+    let option_sets = [
+        build_proof_options(false),
+        build_proof_options(true),
+        ProofOptions::new(12, 64, 16, FieldExtension::None, 4, 7),
+        ProofOptions::new(42, 4, 20, FieldExtension::Quadratic, 8, 7),
+        ProofOptions::new(100, 2, 8, FieldExtension::None, 2, 7),
+    ];
+
+    // the security estimated from the parameters alone is the security of actual proofs
+    for options in option_sets {
+        for sequence_length in [128, 1024] {
+            let fib = super::FibExample::<Blake3_256>::new(sequence_length, options.clone());
+            let proof = fib.prove();
+            for conjectured in [true, false] {
+                let estimate = estimate_security::<Blake3_256>(
+                    &options,
+                    BaseElement::MODULUS_BITS,
+                    proof.trace_info().length(),
+                    conjectured,
+                );
+                assert_eq!(proof.security_level::<Blake3_256>(conjectured), estimate);
+            }
+        }
+    }
+}
+
+#[test]
+fn fib2_test_unsupported_field_extension() {
+    // the 128-bit field does not support the cubic extension, and so the prover must refuse to
+    // generate proofs with it
+    let cubic = ProofOptions::new(28, 8, 0, FieldExtension::Cubic, 4, 7);
+    let prover = FibProver::<Blake3_256>::new(cubic.clone());
+    let trace = prover.build_trace(16);
+    assert_eq!(Err(ProverError::UnsupportedFieldExtension(3)), prover.prove(trace));
+
+    // a proof which claims to use the cubic extension must be rejected by the verifier; the
+    // options in the context of a valid proof are replaced with the same options using the cubic
+    // extension
+    let quadratic = ProofOptions::new(28, 8, 0, FieldExtension::Quadratic, 4, 7);
+    let fib = super::FibExample::<Blake3_256>::new(16, quadratic.clone());
+    let mut bytes = fib.prove().to_bytes();
+    let (quadratic, cubic) = (quadratic.to_bytes(), cubic.to_bytes());
+    let offset = bytes.windows(quadratic.len()).position(|w| w == quadratic).unwrap();
+    bytes[offset..offset + cubic.len()].copy_from_slice(&cubic);
+    let proof = Proof::from_bytes(&bytes).unwrap();
+    assert_eq!(FieldExtension::Cubic, proof.options().field_extension());
+
+    let result = winterfell::verify::<
+        FibAir,
+        Blake3_256,
+        DefaultRandomCoin<Blake3_256>,
+        MerkleTree<Blake3_256>,
+        HashPow,
+    >(proof, fib.result, &AcceptableOptions::MinConjecturedSecurity(0));
+    assert_eq!(Err(VerifierError::UnsupportedFieldExtension(3)), result);
+}
+
+#[test]
+fn fib2_test_public_inputs_binding() {
+    let fib = super::FibExample::<Blake3_256>::new(16, build_proof_options(false));
+    let proof = fib.prove();
+    let acceptable_options = AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+
+    // public inputs which were serialized and parsed back are equal to the original ones, and so
+    // the proof must still verify against them
+    let result = BaseElement::read_from_bytes(&fib.result.to_bytes()).unwrap();
+    let verified = winterfell::verify::<
+        FibAir,
+        Blake3_256,
+        DefaultRandomCoin<Blake3_256>,
+        MerkleTree<Blake3_256>,
+        HashPow,
+    >(proof.clone(), result, &acceptable_options);
+    assert!(verified.is_ok());
+
+    // mutated public inputs must be rejected
+    let verified = winterfell::verify::<
+        FibAir,
+        Blake3_256,
+        DefaultRandomCoin<Blake3_256>,
+        MerkleTree<Blake3_256>,
+        HashPow,
+    >(proof, result + BaseElement::ONE, &acceptable_options);
+    assert!(verified.is_err());
+}
+
+#[test]
+fn fib2_test_stream_serialization() {
+    let fib = super::FibExample::<Blake3_256>::new(16, build_proof_options(true));
+    let proof = fib.prove();
+
+    let mut bytes = Vec::new();
+    proof.write_to(&mut bytes).unwrap();
+    assert_eq!(proof.to_bytes(), bytes);
+
+    let parsed = Proof::read_from_stream(&mut bytes.as_slice()).unwrap();
+    assert_eq!(proof, parsed);
+    assert!(fib.verify(parsed).is_ok());
+}
+
+#[test]
+fn fib2_test_invalid_trace() {
+    let prover = FibProver::<Blake3_256>::new(build_proof_options(false));
+    let mut trace = prover.build_trace(16);
+    let air = FibAir::new(
+        trace.info().clone(),
+        prover.get_pub_inputs(&trace),
+        build_proof_options(false),
+    );
+    assert!(winterfell::validate_trace(&trace, &air).is_ok());
+
+    // corrupt one cell of the trace; this should break the second transition constraint at the
+    // preceding step
+    trace.set(1, 3, trace.get(1, 3) + BaseElement::ONE);
+    let expected = TraceValidationError::MainTransitionNotSatisfied {
+        constraint_idx: 1,
+        step: 2,
+        evaluation: BaseElement::ONE.to_string(),
+        row: vec![trace.get(0, 2).to_string(), trace.get(1, 2).to_string()],
+    };
+    assert_eq!(Err(expected.clone()), winterfell::validate_trace(&trace, &air));
+
+    // proof generation should fail before the proof is built; the prover validates traces only
+    // in debug mode unless validation is explicitly requested
+    if cfg!(debug_assertions) {
+        assert_eq!(Err(ProverError::InvalidTrace(expected)), prover.prove(trace));
+    }
+}
+
+#[test]
+fn fib2_test_check_constraints() {
+    let prover = FibProver::<Blake3_256>::new(build_proof_options(false));
+    let mut trace = prover.build_trace(16);
+    let air = FibAir::new(
+        trace.info().clone(),
+        prover.get_pub_inputs(&trace),
+        build_proof_options(false),
+    );
+    let columns = trace.main_segment().columns().collect::<Vec<_>>();
+    assert!(air.check_constraints(&columns).is_empty());
+
+    // corrupt the first cell of the trace; this should violate the boundary constraint on this
+    // cell as well as the first transition constraint at the first step
+    trace.set(0, 0, BaseElement::ZERO);
+    let columns = trace.main_segment().columns().collect::<Vec<_>>();
+    let violations = air.check_constraints(&columns);
+    assert_eq!(2, violations.len());
+
+    assert_eq!(ConstraintType::Boundary, violations[0].constraint_type);
+    assert_eq!((0, 0), (violations[0].constraint_idx, violations[0].row));
+    assert_eq!(-BaseElement::ONE, violations[0].evaluation);
+
+    assert_eq!(ConstraintType::Transition, violations[1].constraint_type);
+    assert_eq!((0, 0), (violations[1].constraint_idx, violations[1].row));
+    assert_eq!(BaseElement::ONE, violations[1].evaluation);
+    assert_eq!(7, violations[1].degree);
+}
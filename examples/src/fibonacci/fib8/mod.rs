@@ -8,7 +8,7 @@ use std::time::Instant;
 
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    crypto::{DefaultRandomCoin, ElementHasher, HashPow, MerkleTree},
     math::{fields::f128::BaseElement, FieldElement},
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
@@ -37,7 +37,7 @@ pub fn get_example(
     options: &ExampleOptions,
     sequence_length: usize,
 ) -> Result<Box<dyn Example>, String> {
-    let (options, hash_fn) = options.to_proof_options(28, 8);
+    let (options, hash_fn) = options.to_proof_options(28, 8, 1);
 
     match hash_fn {
         HashFunction::Blake3_192 => {
@@ -114,7 +114,7 @@ where
     fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<Fib8Air, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<Fib8Air, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             self.result,
             &acceptable_options,
@@ -124,7 +124,7 @@ where
     fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<Fib8Air, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<Fib8Air, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             self.result + BaseElement::ONE,
             &acceptable_options,
@@ -0,0 +1,76 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core_utils::{ByteReader, Deserializable, Serializable, SliceReader};
+use winterfell::{
+    math::{fields::f62::BaseElement, StarkField},
+    FieldExtension, Proof, ProofOptions, Prover, ProverError, VerifierError,
+};
+
+use super::{super::utils::build_proof_options, Blake3_256, FibF62Prover};
+use crate::Example;
+
+#[test]
+fn fib_f62_test_basic_proof_verification_insecure() {
+    // without a field extension, the 62-bit field limits security of the proof to fewer than 80
+    // bits, and so the prover refuses to generate the proof
+    let prover = FibF62Prover::<Blake3_256>::new(build_proof_options(false));
+    let trace = prover.build_trace(128);
+    let result = prover.prove(trace);
+    assert!(matches!(result, Err(ProverError::InsufficientSecurity { required: 80, .. })));
+}
+
+#[test]
+fn fib_f62_test_basic_proof_verification_extension() {
+    let fib = Box::new(super::FibExample::<Blake3_256>::new(128, build_proof_options(true)));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib_f62_test_basic_proof_verification_cubic_extension() {
+    let options = ProofOptions::new(28, 8, 0, FieldExtension::Cubic, 4, 7);
+    let fib = Box::new(super::FibExample::<Blake3_256>::new(128, options));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib_f62_test_basic_proof_verification_fail() {
+    let fib = Box::new(super::FibExample::<Blake3_256>::new(128, build_proof_options(true)));
+    crate::tests::test_basic_proof_verification_fail(fib);
+}
+
+#[test]
+fn fib_f62_test_conjectured_security() {
+    // the quadratic extension of the 62-bit field supports proofs with over 100 bits of
+    // conjectured security
+    let options = ProofOptions::new(34, 8, 0, FieldExtension::Quadratic, 4, 7);
+    let fib = super::FibExample::<Blake3_256>::new(1024, options);
+    let proof = fib.prove();
+    assert!(proof.security_level::<Blake3_256>(true) >= 100);
+    assert!(fib.verify(proof).is_ok());
+}
+
+#[test]
+fn fib_f62_test_non_canonical_query_value() {
+    let fib = super::FibExample::<Blake3_256>::new(128, build_proof_options(true));
+    let mut proof = fib.prove();
+
+    // bump the first queried trace value by the field modulus; the value is still congruent to
+    // the original value, but its encoding is not canonical
+    let mut bytes = proof.trace_queries[0].to_bytes();
+    let mut reader = SliceReader::new(&bytes);
+    reader.read_usize().unwrap();
+    let offset = reader.position();
+    let value = u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+    let bumped = value + BaseElement::MODULUS;
+    assert_eq!(BaseElement::from_le_bytes_reduce(bumped.to_le_bytes()).as_int(), value);
+    bytes[offset..offset + 8].copy_from_slice(&bumped.to_le_bytes());
+    proof.trace_queries[0] = Deserializable::read_from_bytes(&bytes).unwrap();
+
+    // the proof still deserializes, but the verifier rejects the non-canonical value
+    let proof = Proof::from_bytes(&proof.to_bytes()).unwrap();
+    let result = fib.verify(proof);
+    assert!(matches!(result, Err(VerifierError::ProofDeserializationError(_))), "{result:?}");
+}
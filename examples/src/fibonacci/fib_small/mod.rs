@@ -8,7 +8,7 @@ use std::time::Instant;
 
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    crypto::{DefaultRandomCoin, ElementHasher, HashPow, MerkleTree},
     math::{fields::f64::BaseElement, FieldElement},
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
@@ -35,6 +35,7 @@ type Blake3_256 = winterfell::crypto::hashers::Blake3_256<BaseElement>;
 type Sha3_256 = winterfell::crypto::hashers::Sha3_256<BaseElement>;
 type Rp64_256 = winterfell::crypto::hashers::Rp64_256;
 type RpJive64_256 = winterfell::crypto::hashers::RpJive64_256;
+type AnemoiJive64_256 = winterfell::crypto::hashers::AnemoiJive64_256;
 
 // FIBONACCI EXAMPLE
 // ================================================================================================
@@ -43,7 +44,7 @@ pub fn get_example(
     options: &ExampleOptions,
     sequence_length: usize,
 ) -> Result<Box<dyn Example>, String> {
-    let (options, hash_fn) = options.to_proof_options(28, 8);
+    let (options, hash_fn) = options.to_proof_options(28, 8, 2);
 
     match hash_fn {
         HashFunction::Blake3_192 => {
@@ -61,6 +62,9 @@ pub fn get_example(
         HashFunction::RpJive64_256 => {
             Ok(Box::new(FibExample::<RpJive64_256>::new(sequence_length, options)))
         },
+        HashFunction::AnemoiJive64_256 => {
+            Ok(Box::new(FibExample::<AnemoiJive64_256>::new(sequence_length, options)))
+        },
     }
 }
 
@@ -126,7 +130,7 @@ where
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
 
-        winterfell::verify::<FibSmall, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<FibSmall, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             self.result,
             &acceptable_options,
@@ -136,7 +140,7 @@ where
     fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<FibSmall, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<FibSmall, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             self.result + BaseElement::ONE,
             &acceptable_options,
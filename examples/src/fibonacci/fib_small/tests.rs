@@ -3,12 +3,18 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::{super::utils::build_proof_options, Rp64_256};
+use winterfell::{Prover, ProverError};
+
+use super::{super::utils::build_proof_options, FibSmallProver, Rp64_256};
 
 #[test]
-fn fib_small_test_basic_proof_verification() {
-    let fib = Box::new(super::FibExample::<Rp64_256>::new(128, build_proof_options(false)));
-    crate::tests::test_basic_proof_verification(fib);
+fn fib_small_test_basic_proof_verification_insecure() {
+    // without a field extension, the 64-bit field limits security of the proof to fewer than 80
+    // bits, and so the prover refuses to generate the proof
+    let prover = FibSmallProver::<Rp64_256>::new(build_proof_options(false));
+    let trace = prover.build_trace(128);
+    let result = prover.prove(trace);
+    assert!(matches!(result, Err(ProverError::InsufficientSecurity { required: 80, .. })));
 }
 
 #[test]
@@ -19,6 +25,6 @@ fn fib_small_test_basic_proof_verification_extension() {
 
 #[test]
 fn fib_small_test_basic_proof_verification_fail() {
-    let fib = Box::new(super::FibExample::<Rp64_256>::new(128, build_proof_options(false)));
+    let fib = Box::new(super::FibExample::<Rp64_256>::new(128, build_proof_options(true)));
     crate::tests::test_basic_proof_verification_fail(fib);
 }
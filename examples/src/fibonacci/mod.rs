@@ -5,6 +5,7 @@
 
 pub mod fib2;
 pub mod fib8;
+pub mod fib_f62;
 pub mod fib_small;
 pub mod mulfib2;
 pub mod mulfib8;
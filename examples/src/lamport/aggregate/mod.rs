@@ -8,7 +8,7 @@ use std::time::Instant;
 
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    crypto::{DefaultRandomCoin, ElementHasher, HashPow, MerkleTree},
     math::{fields::f128::BaseElement, get_power_series, FieldElement, StarkField},
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
@@ -36,7 +36,7 @@ pub fn get_example(
     options: &ExampleOptions,
     num_signatures: usize,
 ) -> Result<Box<dyn Example>, String> {
-    let (options, hash_fn) = options.to_proof_options(28, 8);
+    let (options, hash_fn) = options.to_proof_options(28, 8, 1);
 
     match hash_fn {
         HashFunction::Blake3_192 => {
@@ -144,7 +144,7 @@ where
         };
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<LamportAggregateAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<LamportAggregateAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             pub_inputs,
             &acceptable_options,
@@ -160,7 +160,7 @@ where
         };
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<LamportAggregateAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<LamportAggregateAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             pub_inputs,
             &acceptable_options,
@@ -33,8 +33,8 @@ pub struct PublicInputs {
 impl ToElements<BaseElement> for PublicInputs {
     fn to_elements(&self) -> Vec<BaseElement> {
         let mut result = self.pub_key_root.to_vec();
-        result.push(BaseElement::from(self.num_pub_keys as u64));
-        result.push(BaseElement::from(self.num_signatures as u64));
+        result.push(BaseElement::from_u64(self.num_pub_keys as u64));
+        result.push(BaseElement::from_u64(self.num_signatures as u64));
         result.extend_from_slice(&self.message);
         result
     }
@@ -193,7 +193,7 @@ impl Air for LamportThresholdAir {
         assertions.extend_from_slice(&[
             // signature counter starts at zero and terminates with the expected count of signatures
             Assertion::single(27, 0, BaseElement::ZERO),
-            Assertion::single(27, last_step, BaseElement::from(self.num_signatures as u64)),
+            Assertion::single(27, last_step, BaseElement::from_u64(self.num_signatures as u64)),
             // the first public key for merkle path verification should be a zero key (it is only
             // used for padding)
             Assertion::single(18, 0, BaseElement::ZERO),
@@ -344,9 +344,9 @@ fn evaluate_constraints<E: FieldElement + From<BaseElement>>(
 
 fn get_index_list(num_keys: usize) -> Vec<BaseElement> {
     let mut result = Vec::with_capacity(num_keys);
-    result.push(BaseElement::from((num_keys - 1) as u64));
+    result.push(BaseElement::from_u64((num_keys - 1) as u64));
     for i in 0..(num_keys - 1) {
-        result.push(BaseElement::from(i as u64));
+        result.push(BaseElement::from_u64(i as u64));
     }
     result
 }
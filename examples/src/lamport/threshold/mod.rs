@@ -8,7 +8,7 @@ use std::time::Instant;
 
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    crypto::{DefaultRandomCoin, ElementHasher, HashPow, MerkleTree},
     math::{fields::f128::BaseElement, get_power_series, FieldElement, StarkField},
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
@@ -41,7 +41,7 @@ pub fn get_example(
     options: &ExampleOptions,
     num_signers: usize,
 ) -> Result<Box<dyn Example>, String> {
-    let (_, hash_fn) = options.to_proof_options(28, 8);
+    let (_, hash_fn) = options.to_proof_options(28, 8, 1);
 
     match hash_fn {
         HashFunction::Blake3_192 => {
@@ -95,7 +95,7 @@ impl<H: ElementHasher> LamportThresholdExample<H> {
         let pub_key = AggPublicKey::new(public_keys);
         println!("Built aggregated public key in {} ms", now.elapsed().as_millis());
 
-        let (options, _) = options.to_proof_options(28, 8);
+        let (options, _) = options.to_proof_options(28, 8, 1);
 
         LamportThresholdExample {
             options,
@@ -152,7 +152,7 @@ where
         };
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<LamportThresholdAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<LamportThresholdAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             pub_inputs,
             &acceptable_options,
@@ -168,7 +168,7 @@ where
         };
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<LamportThresholdAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<LamportThresholdAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             pub_inputs,
             &acceptable_options,
@@ -5,7 +5,7 @@
 
 use structopt::StructOpt;
 use winterfell::{
-    crypto::hashers::{Rp64_256, RpJive64_256},
+    crypto::hashers::{AnemoiJive64_256, Rp64_256, RpJive64_256},
     math::fields::f128::BaseElement,
     FieldExtension, Proof, ProofOptions, VerifierError,
 };
@@ -20,6 +20,8 @@ pub mod rescue;
 pub mod rescue_raps;
 pub mod utils;
 pub mod vdf;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 #[cfg(test)]
 mod tests;
@@ -63,8 +65,8 @@ pub struct ExampleOptions {
     grinding_factor: u32,
 
     /// Field extension degree for composition polynomial
-    #[structopt(short = "e", long = "field_extension", default_value = "1")]
-    field_extension: u32,
+    #[structopt(short = "e", long = "field_extension")]
+    field_extension: Option<u32>,
 
     /// Folding factor for FRI protocol
     #[structopt(short = "f", long = "folding", default_value = "8")]
@@ -72,10 +74,10 @@ pub struct ExampleOptions {
 }
 
 impl ExampleOptions {
-    pub fn to_proof_options(&self, q: usize, b: usize) -> (ProofOptions, HashFunction) {
+    pub fn to_proof_options(&self, q: usize, b: usize, e: u32) -> (ProofOptions, HashFunction) {
         let num_queries = self.num_queries.unwrap_or(q);
         let blowup_factor = self.blowup_factor.unwrap_or(b);
-        let field_extension = match self.field_extension {
+        let field_extension = match self.field_extension.unwrap_or(e) {
             1 => FieldExtension::None,
             2 => FieldExtension::Quadratic,
             3 => FieldExtension::Cubic,
@@ -88,20 +90,21 @@ impl ExampleOptions {
             "sha3_256" => HashFunction::Sha3_256,
             "rp64_256" => HashFunction::Rp64_256,
             "rp_jive64_256" => HashFunction::RpJive64_256,
+            "anemoi_jive64_256" => HashFunction::AnemoiJive64_256,
             val => panic!("'{val}' is not a valid hash function option"),
         };
 
-        (
-            ProofOptions::new(
-                num_queries,
-                blowup_factor,
-                self.grinding_factor,
-                field_extension,
-                self.folding_factor,
-                31,
-            ),
-            hash_fn,
+        let options = ProofOptions::try_new(
+            num_queries,
+            blowup_factor,
+            self.grinding_factor,
+            field_extension,
+            self.folding_factor,
+            31,
         )
+        .unwrap_or_else(|err| panic!("invalid proof options: {err}"));
+
+        (options, hash_fn)
     }
 
     /// Returns security level of the input proof in bits.
@@ -112,6 +115,7 @@ impl ExampleOptions {
             "sha3_256" => proof.security_level::<Sha3_256>(conjectured),
             "rp64_256" => proof.security_level::<Rp64_256>(conjectured),
             "rp_jive64_256" => proof.security_level::<RpJive64_256>(conjectured),
+            "anemoi_jive64_256" => proof.security_level::<AnemoiJive64_256>(conjectured),
             val => panic!("'{val}' is not a valid hash function option"),
         };
 
@@ -152,6 +156,12 @@ pub enum ExampleType {
         #[structopt(short = "n", default_value = "65536")]
         sequence_length: usize,
     },
+    /// Compute a Fibonacci sequence using trace table with 2 registers in `f62` field.
+    FibF62 {
+        /// Length of Fibonacci sequence; must be a power of two
+        #[structopt(short = "n", default_value = "65536")]
+        sequence_length: usize,
+    },
     /// Execute a simple VDF function
     Vdf {
         /// Number of steps in the VDF function; must be a power of two
@@ -180,7 +190,7 @@ pub enum ExampleType {
     /// Compute a root of a Merkle path using Rescue hash function
     #[cfg(feature = "std")]
     Merkle {
-        /// Depth of the Merkle tree; must be one less than a power of two
+        /// Depth of the Merkle tree
         #[structopt(short = "n", default_value = "7")]
         tree_depth: usize,
     },
@@ -234,4 +244,10 @@ pub enum HashFunction {
     ///
     /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
     RpJive64_256,
+
+    /// Anemoi hash function with 256 bit output. It only works in `f64` field.
+    /// This instance uses the Jive compression mode in Merkle trees.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
+    AnemoiJive64_256,
 }
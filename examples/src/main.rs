@@ -65,6 +65,9 @@ fn main() {
         ExampleType::FibSmall { sequence_length } => {
             fibonacci::fib_small::get_example(&options, sequence_length)
         },
+        ExampleType::FibF62 { sequence_length } => {
+            fibonacci::fib_f62::get_example(&options, sequence_length)
+        },
         ExampleType::Vdf { num_steps } => vdf::regular::get_example(&options, num_steps),
         ExampleType::VdfExempt { num_steps } => vdf::exempt::get_example(&options, num_steps),
         ExampleType::Rescue { chain_length } => rescue::get_example(&options, chain_length),
@@ -16,17 +16,24 @@ use crate::utils::{are_equal, is_binary, is_zero, not, EvaluationResult};
 
 pub struct PublicInputs {
     pub tree_root: [BaseElement; 2],
+    pub index: usize,
+    pub tree_depth: usize,
 }
 
 impl ToElements<BaseElement> for PublicInputs {
     fn to_elements(&self) -> Vec<BaseElement> {
-        self.tree_root.to_vec()
+        let mut result = self.tree_root.to_vec();
+        result.push(BaseElement::from_u64(self.index as u64));
+        result.push(BaseElement::from_u64(self.tree_depth as u64));
+        result
     }
 }
 
 pub struct MerkleAir {
     context: AirContext<BaseElement>,
     tree_root: [BaseElement; 2],
+    index: usize,
+    tree_depth: usize,
 }
 
 impl Air for MerkleAir {
@@ -48,9 +55,16 @@ impl Air for MerkleAir {
             TransitionConstraintDegree::new(2),
         ];
         assert_eq!(TRACE_WIDTH, trace_info.width());
+        assert_eq!(
+            get_trace_length(pub_inputs.tree_depth),
+            trace_info.length(),
+            "trace length is inconsistent with the tree depth"
+        );
         MerkleAir {
-            context: AirContext::new(trace_info, degrees, 4, options),
+            context: AirContext::new(trace_info, degrees, 5, options),
             tree_root: pub_inputs.tree_root,
+            index: pub_inputs.index,
+            tree_depth: pub_inputs.tree_depth,
         }
     }
 
@@ -103,14 +117,28 @@ impl Air for MerkleAir {
     }
 
     fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
-        // assert that Merkle path resolves to the tree root, and that hash capacity
-        // registers (registers 4 and 5) are reset to ZERO every 8 steps
-        let last_step = self.trace_length() - 1;
+        // the bit of the index for the hash cycle at tree level i is inserted into the bit
+        // register at the start of the next cycle; for the first cycle (hashing the leaf value)
+        // and for the cycles padding the trace, the bit is ZERO
+        let num_cycles = self.trace_length() / HASH_CYCLE_LEN;
+        let index_bits = (0..num_cycles)
+            .map(|i| match i {
+                0 => BaseElement::ZERO,
+                i if i > self.tree_depth => BaseElement::ZERO,
+                i => BaseElement::from_u64(((self.index >> (i - 1)) & 1) as u64),
+            })
+            .collect();
+
+        // assert that Merkle path resolves to the tree root at the end of the last hash cycle
+        // for the path, that the path is for the leaf at the specified index, and that hash
+        // capacity registers (registers 4 and 5) are reset to ZERO every 8 steps
+        let root_step = get_root_step(self.tree_depth);
         vec![
-            Assertion::single(0, last_step, self.tree_root[0]),
-            Assertion::single(1, last_step, self.tree_root[1]),
+            Assertion::single(0, root_step, self.tree_root[0]),
+            Assertion::single(1, root_step, self.tree_root[1]),
             Assertion::periodic(4, 0, HASH_CYCLE_LEN, BaseElement::ZERO),
             Assertion::periodic(5, 0, HASH_CYCLE_LEN, BaseElement::ZERO),
+            Assertion::sequence(6, 0, HASH_CYCLE_LEN, index_bits),
         ]
     }
 
@@ -121,6 +149,21 @@ impl Air for MerkleAir {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the length of the execution trace for a Merkle path of the specified depth; the trace
+/// contains one hash cycle for the leaf and for each level of the tree, and is padded with extra
+/// hash cycles to the next power of two.
+pub fn get_trace_length(tree_depth: usize) -> usize {
+    (tree_depth + 1).next_power_of_two() * HASH_CYCLE_LEN
+}
+
+/// Returns the step at which the root of a tree of the specified depth is computed.
+pub fn get_root_step(tree_depth: usize) -> usize {
+    (tree_depth + 1) * HASH_CYCLE_LEN - 1
+}
+
 // MASKS
 // ================================================================================================
 const HASH_CYCLE_MASK: [BaseElement; HASH_CYCLE_LEN] = [
@@ -9,7 +9,7 @@ use std::time::Instant;
 use rand_utils::{rand_value, rand_vector};
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, Digest, ElementHasher, MerkleTree},
+    crypto::{DefaultRandomCoin, Digest, ElementHasher, HashPow, MerkleTree},
     math::{fields::f128::BaseElement, FieldElement, StarkField},
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
@@ -42,7 +42,7 @@ pub fn get_example(
     options: &ExampleOptions,
     tree_depth: usize,
 ) -> Result<Box<dyn Example>, String> {
-    let (options, hash_fn) = options.to_proof_options(28, 8);
+    let (options, hash_fn) = options.to_proof_options(28, 8, 1);
 
     match hash_fn {
         HashFunction::Blake3_192 => {
@@ -67,10 +67,7 @@ pub struct MerkleExample<H: ElementHasher> {
 
 impl<H: ElementHasher> MerkleExample<H> {
     pub fn new(tree_depth: usize, options: ProofOptions) -> Self {
-        assert!(
-            (tree_depth + 1).is_power_of_two(),
-            "tree depth must be one less than a power of 2"
-        );
+        assert!(tree_depth > 0, "tree depth must be greater than zero");
         let value = [BaseElement::new(42), BaseElement::new(43)];
         let index =
             (rand_value::<BaseElement>().as_int() % u128::pow(2, tree_depth as u32)) as usize;
@@ -102,6 +99,12 @@ impl<H: ElementHasher> MerkleExample<H> {
             _hasher: PhantomData,
         }
     }
+
+    /// Returns the depth of the tree; the path also contains the leaf, and thus, has one more
+    /// node than the depth of the tree.
+    fn tree_depth(&self) -> usize {
+        self.path.len() - 1
+    }
 }
 
 // EXAMPLE IMPLEMENTATION
@@ -115,10 +118,10 @@ where
         // generate the execution trace
         println!(
             "Generating proof for proving membership in a Merkle tree of depth {}",
-            self.path.len()
+            self.tree_depth()
         );
         // create the prover
-        let prover = MerkleProver::<H>::new(self.options.clone());
+        let prover = MerkleProver::<H>::new(self.tree_depth(), self.options.clone());
 
         // generate execution trace
         let trace =
@@ -134,10 +137,14 @@ where
     }
 
     fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
-        let pub_inputs = PublicInputs { tree_root: self.tree_root.to_elements() };
+        let pub_inputs = PublicInputs {
+            tree_root: self.tree_root.to_elements(),
+            index: self.index,
+            tree_depth: self.tree_depth(),
+        };
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<MerkleAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<MerkleAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             pub_inputs,
             &acceptable_options,
@@ -146,10 +153,14 @@ where
 
     fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
         let tree_root = self.tree_root.to_elements();
-        let pub_inputs = PublicInputs { tree_root: [tree_root[1], tree_root[0]] };
+        let pub_inputs = PublicInputs {
+            tree_root: [tree_root[1], tree_root[0]],
+            index: self.index,
+            tree_depth: self.tree_depth(),
+        };
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<MerkleAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<MerkleAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             pub_inputs,
             &acceptable_options,
@@ -4,12 +4,15 @@
 // LICENSE file in the root directory of this source tree.
 
 use winterfell::{
-    crypto::MerkleTree, matrix::ColMatrix, AuxRandElements, CompositionPoly, CompositionPolyTrace,
-    ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
-    DefaultTraceLde, PartitionOptions, StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable,
+    crypto::{HashPow, MerkleTree},
+    matrix::ColMatrix,
+    AuxRandElements, CompositionPoly, CompositionPolyTrace, ConstraintCompositionCoefficients,
+    DefaultConstraintCommitment, DefaultConstraintEvaluator, DefaultTraceLde, PartitionOptions,
+    StarkDomain, TraceInfo, TracePolyTable, TraceTable,
 };
 
 use super::{
+    air::{get_root_step, get_trace_length},
     rescue, BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, MerkleAir, PhantomData,
     ProofOptions, Prover, PublicInputs, HASH_CYCLE_LEN, HASH_STATE_WIDTH, NUM_HASH_ROUNDS,
     TRACE_WIDTH,
@@ -19,23 +22,36 @@ use super::{
 // ================================================================================================
 
 pub struct MerkleProver<H: ElementHasher> {
+    tree_depth: usize,
     options: ProofOptions,
     _hasher: PhantomData<H>,
 }
 
 impl<H: ElementHasher> MerkleProver<H> {
-    pub fn new(options: ProofOptions) -> Self {
-        Self { options, _hasher: PhantomData }
+    pub fn new(tree_depth: usize, options: ProofOptions) -> Self {
+        Self {
+            tree_depth,
+            options,
+            _hasher: PhantomData,
+        }
     }
 
+    /// Builds an execution trace attesting that the specified `value` hashes to the root of a
+    /// Merkle tree via the authentication path `branch` for the leaf at the specified `index`.
+    ///
+    /// The first node of the branch is the leaf itself, and is followed by the sibling nodes from
+    /// the bottom of the tree to the top; neither the value nor the branch is revealed by the
+    /// proof.
     pub fn build_trace(
         &self,
         value: [BaseElement; 2],
         branch: &[rescue::Hash],
         index: usize,
     ) -> TraceTable<BaseElement> {
+        assert_eq!(self.tree_depth + 1, branch.len(), "branch length must be tree depth plus one");
+
         // allocate memory to hold the trace table
-        let trace_length = branch.len() * HASH_CYCLE_LEN;
+        let trace_length = get_trace_length(self.tree_depth);
         let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
 
         // skip the first node of the branch because it will be computed in the trace as hash(value)
@@ -63,8 +79,15 @@ impl<H: ElementHasher> MerkleProver<H> {
                 if cycle_pos < NUM_HASH_ROUNDS {
                     rescue::apply_round(&mut state[..HASH_STATE_WIDTH], step);
                 } else {
-                    let branch_node = branch[cycle_num].to_elements();
-                    let index_bit = BaseElement::new(((index >> cycle_num) & 1) as u128);
+                    // once the root is computed, the remaining cycles pad the trace by hashing
+                    // the accumulated hash with ZERO nodes
+                    let (branch_node, index_bit) = match branch.get(cycle_num) {
+                        Some(node) => (
+                            node.to_elements(),
+                            BaseElement::from_u64(((index >> cycle_num) & 1) as u64),
+                        ),
+                        None => ([BaseElement::ZERO; 2], BaseElement::ZERO),
+                    };
                     if index_bit == BaseElement::ZERO {
                         // if index bit is zero, new branch node goes into registers [2, 3]; values
                         // in registers [0, 1] (the accumulated hash) remain unchanged
@@ -107,6 +130,7 @@ where
     type HashFn = H;
     type VC = MerkleTree<H>;
     type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type ProofOfWork = HashPow;
     type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
         DefaultTraceLde<E, Self::HashFn, Self::VC>;
     type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
@@ -115,9 +139,14 @@ where
         DefaultConstraintEvaluator<'a, Self::Air, E>;
 
     fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
-        let last_step = trace.length() - 1;
+        let root_step = get_root_step(self.tree_depth);
+        let index = (0..self.tree_depth)
+            .filter(|&i| trace.get(6, (i + 1) * HASH_CYCLE_LEN) == BaseElement::ONE)
+            .fold(0, |index, i| index | (1 << i));
         PublicInputs {
-            tree_root: [trace.get(0, last_step), trace.get(1, last_step)],
+            tree_root: [trace.get(0, root_step), trace.get(1, root_step)],
+            index,
+            tree_depth: self.tree_depth,
         }
     }
 
@@ -5,7 +5,7 @@
 
 use winterfell::{FieldExtension, ProofOptions};
 
-use super::Blake3_256;
+use super::{BaseElement, Blake3_256, Example, Hash};
 
 #[test]
 fn merkle_test_basic_proof_verification() {
@@ -25,6 +25,34 @@ fn merkle_test_basic_proof_verification_fail() {
     crate::tests::test_basic_proof_verification_fail(merkle);
 }
 
+#[test]
+fn merkle_test_depth_8_proof_verification() {
+    let merkle = Box::new(super::MerkleExample::<Blake3_256>::new(8, build_options(false)));
+    crate::tests::test_basic_proof_verification(merkle);
+}
+
+#[test]
+fn merkle_test_depth_16_proof_verification() {
+    let merkle = Box::new(super::MerkleExample::<Blake3_256>::new(16, build_options(false)));
+    crate::tests::test_basic_proof_verification(merkle);
+}
+
+#[test]
+fn merkle_test_wrong_sibling_fail() {
+    let mut merkle = super::MerkleExample::<Blake3_256>::new(8, build_options(false));
+    merkle.path[3] = Hash::new(BaseElement::new(1), BaseElement::new(2));
+    let proof = merkle.prove();
+    assert!(merkle.verify(proof).is_err());
+}
+
+#[test]
+fn merkle_test_wrong_index_fail() {
+    let mut merkle = super::MerkleExample::<Blake3_256>::new(8, build_options(false));
+    let proof = merkle.prove();
+    merkle.index ^= 1;
+    assert!(merkle.verify(proof).is_err());
+}
+
 fn build_options(use_extension_field: bool) -> ProofOptions {
     let extension = if use_extension_field {
         FieldExtension::Quadratic
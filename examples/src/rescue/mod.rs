@@ -8,7 +8,7 @@ use std::time::Instant;
 
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    crypto::{DefaultRandomCoin, ElementHasher, HashPow, MerkleTree},
     math::{fields::f128::BaseElement, FieldElement},
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
@@ -41,7 +41,7 @@ pub fn get_example(
     options: &ExampleOptions,
     chain_length: usize,
 ) -> Result<Box<dyn Example>, String> {
-    let (options, hash_fn) = options.to_proof_options(42, 4);
+    let (options, hash_fn) = options.to_proof_options(42, 4, 1);
 
     match hash_fn {
         HashFunction::Blake3_192 => {
@@ -120,7 +120,7 @@ where
         let pub_inputs = PublicInputs { seed: self.seed, result: self.result };
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<RescueAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<RescueAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             pub_inputs,
             &acceptable_options,
@@ -134,7 +134,7 @@ where
         };
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-        winterfell::verify::<RescueAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<RescueAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             pub_inputs,
             &acceptable_options,
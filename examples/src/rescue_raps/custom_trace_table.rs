@@ -3,7 +3,6 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use core_utils::uninit_vector;
 use winterfell::{math::StarkField, matrix::ColMatrix, EvaluationFrame, Trace, TraceInfo};
 
 // RAP TRACE TABLE
@@ -35,8 +34,8 @@ impl<B: StarkField> RapTraceTable<B> {
 
     /// Creates a new execution trace of the specified width and length.
     ///
-    /// This allocates all the required memory for the trace, but does not initialize it. It is
-    /// expected that the trace will be filled using one of the data mutator methods.
+    /// This allocates all the required memory for the trace and sets all of its values to zero. It
+    /// is expected that the trace will be filled using one of the data mutator methods.
     ///
     /// # Panics
     /// Panics if:
@@ -50,8 +49,8 @@ impl<B: StarkField> RapTraceTable<B> {
     /// Creates a new execution trace of the specified width and length, and with the specified
     /// metadata.
     ///
-    /// This allocates all the required memory for the trace, but does not initialize it. It is
-    /// expected that the trace will be filled using one of the data mutator methods.
+    /// This allocates all the required memory for the trace and sets all of its values to zero. It
+    /// is expected that the trace will be filled using one of the data mutator methods.
     ///
     /// # Panics
     /// Panics if:
@@ -87,7 +86,7 @@ impl<B: StarkField> RapTraceTable<B> {
             meta.len()
         );
 
-        let columns = unsafe { (0..width).map(|_| uninit_vector(length)).collect() };
+        let columns = (0..width).map(|_| vec![B::ZERO; length]).collect();
         Self {
             info: TraceInfo::new_multi_segment(width, 3, 3, length, meta),
             trace: ColMatrix::new(columns),
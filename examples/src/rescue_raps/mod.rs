@@ -9,7 +9,7 @@ use std::time::Instant;
 use rand_utils::rand_array;
 use tracing::{field, info_span};
 use winterfell::{
-    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    crypto::{DefaultRandomCoin, ElementHasher, HashPow, MerkleTree},
     math::{fields::f128::BaseElement, ExtensionOf, FieldElement},
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
@@ -44,7 +44,7 @@ pub fn get_example(
     options: &ExampleOptions,
     chain_length: usize,
 ) -> Result<Box<dyn Example>, String> {
-    let (options, hash_fn) = options.to_proof_options(42, 4);
+    let (options, hash_fn) = options.to_proof_options(42, 4, 1);
 
     match hash_fn {
         HashFunction::Blake3_192 => {
@@ -134,7 +134,7 @@ where
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
 
-        winterfell::verify::<RescueRapsAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<RescueRapsAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             pub_inputs,
             &acceptable_options,
@@ -146,7 +146,7 @@ where
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
 
-        winterfell::verify::<RescueRapsAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        winterfell::verify::<RescueRapsAir, H, DefaultRandomCoin<H>, MerkleTree<H>, HashPow>(
             proof,
             pub_inputs,
             &acceptable_options,
@@ -157,10 +157,7 @@ impl Hash {
 
 impl Digest for Hash {
     fn as_bytes(&self) -> [u8; 32] {
-        let bytes = BaseElement::elements_as_bytes(&self.0);
-        let mut result = [0; 32];
-        result[..bytes.len()].copy_from_slice(bytes);
-        result
+        self.to_bytes()
     }
 }
 
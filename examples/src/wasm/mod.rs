@@ -0,0 +1,59 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Byte-level verification entry point for the Fibonacci example.
+//!
+//! The function in this module accepts serialized proofs and public inputs, and thus, can be
+//! exposed to JavaScript via a thin `wasm-bindgen` layer for verifying proofs in a browser. For
+//! this, the crate should be built for `wasm32-unknown-unknown` with default features disabled.
+
+use core_utils::{ByteReader, Deserializable, SliceReader};
+use winterfell::{
+    crypto::{DefaultRandomCoin, HashPow, MerkleTree},
+    math::fields::f128::BaseElement,
+    AcceptableOptions, Proof, VerifierError,
+};
+
+use crate::{fibonacci::fib2::FibAir, Blake3_256};
+
+#[cfg(test)]
+mod tests;
+
+// PROOF VERIFICATION
+// ================================================================================================
+
+/// Verifies a proof of computing a Fibonacci sequence (2 terms per step) against the specified
+/// public inputs.
+///
+/// `proof` is expected to contain a proof serialized via [Proof::to_bytes()], and `pub_inputs`
+/// the serialized term of the sequence claimed to be computed. The proof must be generated using
+/// the BLAKE3 hash function with 256-bit output, and must provide at least 80 bits of conjectured
+/// security.
+///
+/// # Errors
+/// Returns an error if:
+/// - The proof or the public inputs could not be deserialized.
+/// - The proof does not attest to the correct computation of the specified term.
+pub fn verify_proof_bytes(proof: &[u8], pub_inputs: &[u8]) -> Result<(), VerifierError> {
+    let proof = Proof::from_bytes(proof)
+        .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+
+    let mut reader = SliceReader::new(pub_inputs);
+    let result = BaseElement::read_from(&mut reader)
+        .map_err(|err| VerifierError::PublicInputsDeserializationError(err.to_string()))?;
+    if reader.has_more_bytes() {
+        return Err(VerifierError::PublicInputsDeserializationError(
+            "public inputs contain trailing bytes".to_string(),
+        ));
+    }
+
+    winterfell::verify::<
+        FibAir,
+        Blake3_256,
+        DefaultRandomCoin<Blake3_256>,
+        MerkleTree<Blake3_256>,
+        HashPow,
+    >(proof, result, &AcceptableOptions::default())
+}
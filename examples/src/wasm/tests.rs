@@ -0,0 +1,53 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core_utils::Serializable;
+use winterfell::{math::FieldElement, FieldExtension, ProofOptions, Prover, VerifierError};
+
+use super::{verify_proof_bytes, BaseElement, Blake3_256};
+use crate::fibonacci::fib2::FibProver;
+
+#[test]
+fn verify_proof_bytes_basic() {
+    let (proof, result) = build_proof();
+    assert_eq!(Ok(()), verify_proof_bytes(&proof, &result.to_bytes()));
+
+    // a proof for a different term of the sequence is rejected
+    let wrong_result = result + BaseElement::ONE;
+    assert!(verify_proof_bytes(&proof, &wrong_result.to_bytes()).is_err());
+}
+
+#[test]
+fn verify_proof_bytes_malformed() {
+    let (proof, result) = build_proof();
+
+    let truncated = &proof[..proof.len() - 1];
+    assert!(matches!(
+        verify_proof_bytes(truncated, &result.to_bytes()),
+        Err(VerifierError::ProofDeserializationError(_))
+    ));
+
+    let mut pub_inputs = result.to_bytes();
+    pub_inputs.push(0);
+    assert!(matches!(
+        verify_proof_bytes(&proof, &pub_inputs),
+        Err(VerifierError::PublicInputsDeserializationError(_))
+    ));
+    assert!(matches!(
+        verify_proof_bytes(&proof, &pub_inputs[..4]),
+        Err(VerifierError::PublicInputsDeserializationError(_))
+    ));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_proof() -> (Vec<u8>, BaseElement) {
+    let options = ProofOptions::new(28, 8, 0, FieldExtension::None, 4, 7);
+    let prover = FibProver::<Blake3_256>::new(options);
+    let trace = prover.build_trace(64);
+    let result = prover.get_pub_inputs(&trace);
+    (prover.prove(trace).unwrap().to_bytes(), result)
+}
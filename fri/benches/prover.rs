@@ -9,10 +9,11 @@ use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criteri
 use crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree};
 use math::{fft, fields::f128::BaseElement, FieldElement};
 use rand_utils::rand_vector;
-use winter_fri::{DefaultProverChannel, FriOptions, FriProver};
+use winter_fri::{DefaultProverChannel, FriFoldingFactor, FriOptions, FriProver};
 
 static BATCH_SIZES: [usize; 3] = [65536, 131072, 262144];
 static BLOWUP_FACTOR: usize = 8;
+static NUM_QUERIES: usize = 32;
 
 pub fn build_layers(c: &mut Criterion) {
     let mut fri_group = c.benchmark_group("FRI prover");
@@ -37,7 +38,7 @@ pub fn build_layers(c: &mut Criterion) {
                             BaseElement,
                             Blake3_256<BaseElement>,
                             DefaultRandomCoin<Blake3_256<BaseElement>>,
-                        >::new(domain_size, 32);
+                        >::new(domain_size, NUM_QUERIES);
                         prover.build_layers(&mut channel, evaluations);
                         prover.reset();
                     },
@@ -48,7 +49,51 @@ pub fn build_layers(c: &mut Criterion) {
     }
 }
 
-criterion_group!(fri_prover_group, build_layers);
+/// Measures the time needed to build FRI layers and a FRI proof for each supported folding
+/// factor, and prints the size of the resulting proof.
+///
+/// Larger folding factors produce fewer layers, and thus, fewer authentication paths per query;
+/// this makes proofs smaller, but folding each layer takes more work.
+pub fn folding_factors(c: &mut Criterion) {
+    let mut fri_group = c.benchmark_group("FRI folding factors");
+    fri_group.sample_size(10);
+    fri_group.measurement_time(Duration::from_secs(10));
+
+    let domain_size = BATCH_SIZES[0];
+    let evaluations = build_evaluations(domain_size);
+
+    for folding_factor in FriFoldingFactor::ALL {
+        let options = FriOptions::new(BLOWUP_FACTOR, folding_factor.as_usize(), 31);
+        let prove = |evaluations: Vec<BaseElement>| {
+            let mut prover =
+                FriProver::<_, _, _, MerkleTree<Blake3_256<BaseElement>>>::new(options.clone());
+            let mut channel = DefaultProverChannel::<
+                BaseElement,
+                Blake3_256<BaseElement>,
+                DefaultRandomCoin<Blake3_256<BaseElement>>,
+            >::new(domain_size, NUM_QUERIES);
+            prover.build_layers(&mut channel, evaluations);
+            let positions = channel.draw_query_positions(0);
+            prover.build_proof(&positions)
+        };
+
+        let proof = prove(evaluations.clone());
+        println!(
+            "folding factor {}: {} FRI layers, proof size {} bytes",
+            folding_factor.as_usize(),
+            options.num_fri_layers(domain_size),
+            proof.size()
+        );
+
+        fri_group.bench_with_input(
+            BenchmarkId::new("prove", folding_factor.as_usize()),
+            &evaluations,
+            |b, e| b.iter_batched(|| e.clone(), prove, BatchSize::LargeInput),
+        );
+    }
+}
+
+criterion_group!(fri_prover_group, build_layers, folding_factors);
 criterion_main!(fri_prover_group);
 
 // HELPER FUNCTIONS
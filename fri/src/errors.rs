@@ -3,6 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::string::String;
 use core::fmt;
 
 use crypto::RandomCoinError;
@@ -34,6 +35,8 @@ pub enum VerifierError {
     RemainderDegreeMismatch(usize),
     /// Polynomial degree at one of the FRI layers could not be divided evenly by the folding factor.
     DegreeTruncation(usize, usize, usize),
+    /// FRI proof is not consistent with the parameters of the protocol or could not be parsed.
+    MalformedProof(FriProofError),
 }
 
 impl fmt::Display for VerifierError {
@@ -70,8 +73,100 @@ impl fmt::Display for VerifierError {
             Self::DegreeTruncation(degree, folding, layer) => {
                 write!(f, "degree reduction from {degree} by {folding} at layer {layer} results in degree truncation")
             }
+            Self::MalformedProof(err) => {
+                write!(f, "FRI proof is malformed: {err}")
+            }
         }
     }
 }
 
 impl core::error::Error for VerifierError {}
+
+// FRI PROOF ERROR
+// ================================================================================================
+
+/// Defines errors which can occur when a FRI proof does not have the structure expected by the
+/// verifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FriProofError {
+    /// Number of layers in the proof is not equal to the number of layers expected by the
+    /// verifier.
+    InvalidNumLayers { expected: usize, actual: usize },
+    /// Number of value bytes at the specified layer cannot be divided into a whole number of
+    /// queries.
+    InvalidLayerValuesLength { layer: usize, num_bytes: usize },
+    /// The specified layer does not contain any queries.
+    EmptyLayer(usize),
+    /// Number of queries at the specified layer is not equal to the number of queries expected by
+    /// the verifier.
+    InvalidNumQueries {
+        layer: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// Query values at the specified layer and query position could not be deserialized.
+    LayerValueDeserializationFailed {
+        layer: usize,
+        position: usize,
+        reason: String,
+    },
+    /// Batch opening proof at the specified layer could not be deserialized.
+    LayerProofDeserializationFailed { layer: usize, reason: String },
+    /// Batch opening proof at the specified layer was built for a domain of an unexpected size.
+    LayerDomainSizeMismatch {
+        layer: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// Not all bytes were consumed when parsing the specified layer.
+    LayerUnconsumedBytes(usize),
+    /// Number of remainder values is not a power of two.
+    RemainderLengthNotPowerOfTwo(usize),
+    /// Remainder value at the specified position could not be deserialized.
+    RemainderValueDeserializationFailed { position: usize, reason: String },
+    /// Not all bytes were consumed when parsing the remainder.
+    RemainderUnconsumedBytes,
+}
+
+impl fmt::Display for FriProofError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumLayers { expected, actual } => {
+                write!(f, "expected {expected} FRI layers, but was {actual}")
+            }
+            Self::InvalidLayerValuesLength { layer, num_bytes } => {
+                write!(f, "number of value bytes ({num_bytes}) at FRI layer {layer} does not divide into whole number of queries")
+            }
+            Self::EmptyLayer(layer) => {
+                write!(f, "FRI layer {layer} does not contain any queries")
+            }
+            Self::InvalidNumQueries { layer, expected, actual } => {
+                write!(f, "expected {expected} queries at FRI layer {layer}, but was {actual}")
+            }
+            Self::LayerValueDeserializationFailed { layer, position, reason } => {
+                write!(f, "failed to deserialize values of query {position} at FRI layer {layer}: {reason}")
+            }
+            Self::LayerProofDeserializationFailed { layer, reason } => {
+                write!(f, "failed to deserialize opening proof at FRI layer {layer}: {reason}")
+            }
+            Self::LayerDomainSizeMismatch { layer, expected, actual } => {
+                write!(f, "expected opening proof at FRI layer {layer} to be for a domain of size {expected}, but was {actual}")
+            }
+            Self::LayerUnconsumedBytes(layer) => {
+                write!(f, "not all bytes were consumed while parsing FRI layer {layer}")
+            }
+            Self::RemainderLengthNotPowerOfTwo(num_elements) => {
+                write!(f, "number of remainder values must be a power of two, but {num_elements} was implied")
+            }
+            Self::RemainderValueDeserializationFailed { position, reason } => {
+                write!(f, "failed to deserialize remainder value {position}: {reason}")
+            }
+            Self::RemainderUnconsumedBytes => {
+                write!(f, "not all bytes were consumed while parsing FRI remainder")
+            }
+        }
+    }
+}
+
+impl core::error::Error for FriProofError {}
@@ -14,9 +14,9 @@ use math::{
     fft::{get_inv_twiddles, serial_fft},
     get_power_series_with_offset, polynom, FieldElement, StarkField,
 };
+use utils::iter;
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
-use utils::{iter_mut, uninit_vector};
 
 // DEGREE-RESPECTING PROJECTION
 // ================================================================================================
@@ -93,28 +93,69 @@ where
     let inv_twiddles = get_inv_twiddles::<B>(N);
     let len_offset = E::inv((N as u32).into());
 
-    let mut result = unsafe { uninit_vector(values.len()) };
-    iter_mut!(result)
-        .zip(values)
+    iter!(values)
         .zip(inv_offsets)
-        .for_each(|((result, values), domain_offset)| {
-            // interpolate the values into a polynomial; this is similar to interpolation with
-            // offset implemented in math::fft module
-            let mut poly = *values;
-            serial_fft(&mut poly, &inv_twiddles);
-
-            let mut offset = len_offset;
-            let domain_offset = E::from(domain_offset);
-            for coeff in poly.iter_mut() {
-                *coeff *= offset;
-                offset *= domain_offset;
-            }
-
-            // evaluate the polynomial at alpha, and save the result
-            *result = polynom::eval(&poly, alpha)
-        });
+        .map(|(values, domain_offset)| {
+            fold_values(*values, &inv_twiddles, len_offset, domain_offset, alpha)
+        })
+        .collect()
+}
 
-    result
+/// Applies degree-respecting projection to evaluations of a polynomial stored in natural order.
+///
+/// This is equivalent to calling [apply_drp()] on the transposed `evaluations`, but reads the `N`
+/// evaluations needed to compute a single evaluation in the folded domain directly from
+/// `evaluations` (these are located `evaluations.len() / N` positions apart). Thus, unlike
+/// [apply_drp()], this function does not require a transposed copy of the evaluations.
+///
+/// ```
+/// # use math::{fields::f128::BaseElement, StarkField};
+/// # use rand_utils::{rand_value, rand_vector};
+/// # use utils::transpose_slice;
+/// # use winter_fri::folding::{apply_drp, apply_drp_strided};
+/// let alpha: BaseElement = rand_value();
+/// let evaluations: Vec<BaseElement> = rand_vector(64);
+/// let offset = BaseElement::GENERATOR;
+///
+/// let transposed_evaluations = transpose_slice::<BaseElement, 4>(&evaluations);
+/// assert_eq!(
+///     apply_drp(&transposed_evaluations, offset, alpha),
+///     apply_drp_strided::<_, _, 4>(&evaluations, offset, alpha)
+/// );
+/// ```
+///
+/// # Panics
+/// Panics if the number of `evaluations` is not divisible by `N`.
+pub fn apply_drp_strided<B, E, const N: usize>(
+    evaluations: &[E],
+    domain_offset: B,
+    alpha: E,
+) -> Vec<E>
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let folded_len = evaluations.len() / N;
+    assert_eq!(
+        folded_len * N,
+        evaluations.len(),
+        "number of evaluations must be divisible by {}, but was {}",
+        N,
+        evaluations.len()
+    );
+
+    // build offset inverses and twiddles used during polynomial interpolation
+    let inv_offsets = get_inv_offsets(folded_len, domain_offset, N);
+    let inv_twiddles = get_inv_twiddles::<B>(N);
+    let len_offset = E::inv((N as u32).into());
+
+    iter!(inv_offsets)
+        .enumerate()
+        .map(|(i, &domain_offset)| {
+            let values: [E; N] = core::array::from_fn(|j| evaluations[i + j * folded_len]);
+            fold_values(values, &inv_twiddles, len_offset, domain_offset, alpha)
+        })
+        .collect()
 }
 
 // POSITION FOLDING
@@ -178,6 +219,36 @@ pub fn fold_positions(
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Interpolates the `N` values needed to compute a single evaluation in the folded domain into a
+/// polynomial, and evaluates this polynomial at `alpha`.
+#[inline(always)]
+fn fold_values<B, E, const N: usize>(
+    values: [E; N],
+    inv_twiddles: &[B],
+    len_offset: E,
+    domain_offset: B,
+    alpha: E,
+) -> E
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    // interpolate the values into a polynomial; this is similar to interpolation with offset
+    // implemented in math::fft module
+    let mut poly = values;
+    serial_fft(&mut poly, inv_twiddles);
+
+    let mut offset = len_offset;
+    let domain_offset = E::from(domain_offset);
+    for coeff in poly.iter_mut() {
+        *coeff *= offset;
+        offset *= domain_offset;
+    }
+
+    // evaluate the polynomial at alpha
+    polynom::eval(&poly, alpha)
+}
+
 fn get_inv_offsets<B>(domain_size: usize, domain_offset: B, folding_factor: usize) -> Vec<B>
 where
     B: StarkField,
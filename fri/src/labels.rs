@@ -0,0 +1,9 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Domain separation labels for data absorbed into the Fiat-Shamir transcript of a FRI proof.
+
+/// Label for commitments to FRI layers, including the commitment to the remainder polynomial.
+pub const LAYER_COMMITMENT: &[u8] = b"winterfell:fri-layer-commit:v1";
@@ -66,7 +66,11 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(test)]
+extern crate std;
+
 pub mod folding;
+pub mod labels;
 
 mod prover;
 pub use prover::{DefaultProverChannel, FriProver, ProverChannel};
@@ -75,12 +79,12 @@ mod verifier;
 pub use verifier::{DefaultVerifierChannel, FriVerifier, VerifierChannel};
 
 mod options;
-pub use options::FriOptions;
+pub use options::{FriFoldingFactor, FriOptions};
 
 mod proof;
 pub use proof::FriProof;
 
 mod errors;
-pub use errors::VerifierError;
+pub use errors::{FriProofError, VerifierError};
 
 pub mod utils;
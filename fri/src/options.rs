@@ -30,10 +30,7 @@ impl FriOptions {
             "blowup factor must be a power of two, but was {blowup_factor}"
         );
         assert!(
-            folding_factor == 2
-                || folding_factor == 4
-                || folding_factor == 8
-                || folding_factor == 16,
+            FriFoldingFactor::from_usize(folding_factor).is_some(),
             "folding factor {folding_factor} is not supported"
         );
         FriOptions {
@@ -92,3 +89,48 @@ impl FriOptions {
         result
     }
 }
+
+// FRI FOLDING FACTOR
+// ================================================================================================
+
+/// Factor by which the degree of a polynomial is reduced with each FRI layer.
+///
+/// Folding by a factor of `N` reduces the size of the evaluation domain `N` times per layer, and
+/// thus, larger folding factors require fewer FRI layers. This shortens FRI proofs as fewer
+/// authentication paths need to be included in a proof (at the cost of including `N` evaluations
+/// per query for each layer), but makes folding of each layer more expensive for the prover. The
+/// `prover` benchmark of this crate reports proving time and proof size for all folding factors.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FriFoldingFactor {
+    /// Each FRI layer halves the size of the evaluation domain.
+    Factor2 = 2,
+    /// Each FRI layer reduces the size of the evaluation domain 4 times.
+    Factor4 = 4,
+    /// Each FRI layer reduces the size of the evaluation domain 8 times.
+    Factor8 = 8,
+    /// Each FRI layer reduces the size of the evaluation domain 16 times.
+    Factor16 = 16,
+}
+
+impl FriFoldingFactor {
+    /// All supported folding factors in ascending order.
+    pub const ALL: [Self; 4] = [Self::Factor2, Self::Factor4, Self::Factor8, Self::Factor16];
+
+    /// Returns the folding factor equal to `value`, or `None` if folding by `value` is not
+    /// supported.
+    pub const fn from_usize(value: usize) -> Option<Self> {
+        match value {
+            2 => Some(Self::Factor2),
+            4 => Some(Self::Factor4),
+            8 => Some(Self::Factor8),
+            16 => Some(Self::Factor16),
+            _ => None,
+        }
+    }
+
+    /// Returns the numeric value of this folding factor.
+    pub const fn as_usize(&self) -> usize {
+        *self as usize
+    }
+}
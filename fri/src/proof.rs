@@ -11,6 +11,8 @@ use utils::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
 };
 
+use crate::FriProofError;
+
 // FRI PROOF
 // ================================================================================================
 
@@ -97,6 +99,17 @@ impl FriProof {
         self.remainder.len() / E::ELEMENT_BYTES
     }
 
+    /// Returns the number of queried positions in each layer of this proof.
+    ///
+    /// Each query in a layer consists of `folding_factor` field elements, and each element is
+    /// assumed to be encoded using `element_bytes` bytes.
+    pub fn num_layer_queries(&self, folding_factor: usize, element_bytes: usize) -> Vec<usize> {
+        self.layers
+            .iter()
+            .map(|layer| layer.values.len() / (folding_factor * element_bytes))
+            .collect()
+    }
+
     /// Returns the number of partitions used during proof generation.
     pub fn num_partitions(&self) -> usize {
         2usize.pow(self.num_partitions as u32)
@@ -123,14 +136,16 @@ impl FriProof {
     ///
     /// # Errors
     /// Returns an error if:
+    /// * The number of layers in this proof is not equal to `num_layers`.
     /// * This proof is not consistent with the specified `domain_size` and `folding_factor`.
     /// * Any of the layers could not be parsed successfully.
     #[allow(clippy::type_complexity)]
     pub fn parse_layers<E, H, V>(
         self,
+        num_layers: usize,
         mut domain_size: usize,
         folding_factor: usize,
-    ) -> Result<(Vec<Vec<E>>, Vec<<V as VectorCommitment<H>>::MultiProof>), DeserializationError>
+    ) -> Result<(Vec<Vec<E>>, Vec<<V as VectorCommitment<H>>::MultiProof>), FriProofError>
     where
         E: FieldElement,
         H: ElementHasher<BaseField = E::BaseField>,
@@ -140,23 +155,29 @@ impl FriProof {
         assert!(folding_factor.is_power_of_two(), "folding factor must be a power of two");
         assert!(folding_factor > 1, "folding factor must be greater than 1");
 
+        if self.layers.len() != num_layers {
+            return Err(FriProofError::InvalidNumLayers {
+                expected: num_layers,
+                actual: self.layers.len(),
+            });
+        }
+
         let mut layer_proofs = Vec::new();
         let mut layer_queries = Vec::new();
 
         // parse all layers
         for (i, layer) in self.layers.into_iter().enumerate() {
             domain_size /= folding_factor;
-            let (qv, op) = layer.parse::<_, H, V>(folding_factor).map_err(|err| {
-                DeserializationError::InvalidValue(format!("failed to parse FRI layer {i}: {err}"))
-            })?;
+            let (qv, op) = layer.parse::<_, H, V>(i, folding_factor)?;
 
             // check that the opening proof matches the domain length
-            if <V as VectorCommitment<H>>::get_multiproof_domain_len(&op) != domain_size {
-                return Err(DeserializationError::InvalidValue(format!(
-                    "expected a domain of size {} but was {}",
-                    domain_size,
-                    <V as VectorCommitment<H>>::get_multiproof_domain_len(&op),
-                )));
+            let proof_domain_size = <V as VectorCommitment<H>>::get_multiproof_domain_len(&op);
+            if proof_domain_size != domain_size {
+                return Err(FriProofError::LayerDomainSizeMismatch {
+                    layer: i,
+                    expected: domain_size,
+                    actual: proof_domain_size,
+                });
             }
 
             layer_proofs.push(op);
@@ -174,19 +195,24 @@ impl FriProof {
     ///   the number of remainder bytes in this proof is not a power of two.
     /// * Any of the remainder values could not be parsed correctly.
     /// * Not all bytes have been consumed while parsing remainder values.
-    pub fn parse_remainder<E: FieldElement>(&self) -> Result<Vec<E>, DeserializationError> {
+    pub fn parse_remainder<E: FieldElement>(&self) -> Result<Vec<E>, FriProofError> {
         let num_elements = self.num_remainder_elements::<E>();
         if !num_elements.is_power_of_two() {
-            return Err(DeserializationError::InvalidValue(format!(
-                "number of remainder values must be a power of two, but {num_elements} was implied"
-            )));
+            return Err(FriProofError::RemainderLengthNotPowerOfTwo(num_elements));
         }
         let mut reader = SliceReader::new(&self.remainder);
-        let remainder = reader.read_many(num_elements).map_err(|err| {
-            DeserializationError::InvalidValue(format!("failed to parse FRI remainder: {err}"))
-        })?;
+        let mut remainder = Vec::with_capacity(num_elements);
+        for position in 0..num_elements {
+            let value = E::read_from(&mut reader).map_err(|err| {
+                FriProofError::RemainderValueDeserializationFailed {
+                    position,
+                    reason: err.to_string(),
+                }
+            })?;
+            remainder.push(value);
+        }
         if reader.has_more_bytes() {
-            return Err(DeserializationError::UnconsumedBytes);
+            return Err(FriProofError::RemainderUnconsumedBytes);
         }
         Ok(remainder)
     }
@@ -227,8 +253,15 @@ impl Deserializable for FriProof {
         let num_remainder_bytes = source.read_u16()? as usize;
         let remainder = source.read_vec(num_remainder_bytes)?;
 
-        // read number of partitions
+        // read number of partitions; this is stored as a power of two, and so it must be small
+        // enough for the number of partitions to fit into usize
         let num_partitions = source.read_u8()?;
+        if num_partitions as u32 >= usize::BITS {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of partitions must be smaller than 2^{}, but was 2^{num_partitions}",
+                usize::BITS
+            )));
+        }
 
         Ok(FriProof { layers, remainder, num_partitions })
     }
@@ -280,7 +313,8 @@ impl FriProofLayer {
     // PARSING
     // --------------------------------------------------------------------------------------------
     /// Decomposes this layer into a combination of query values and corresponding batch opening
-    /// proof.
+    /// proof; `layer_idx` is the index of this layer in the proof and is used only for reporting
+    /// errors.
     ///
     /// # Errors
     /// Returns an error if:
@@ -289,8 +323,9 @@ impl FriProofLayer {
     /// * Not all bytes have been consumed while parsing this layer.
     pub fn parse<E, H, V>(
         self,
+        layer_idx: usize,
         folding_factor: usize,
-    ) -> Result<(Vec<E>, <V as VectorCommitment<H>>::MultiProof), DeserializationError>
+    ) -> Result<(Vec<E>, <V as VectorCommitment<H>>::MultiProof), FriProofError>
     where
         E: FieldElement,
         H: ElementHasher<BaseField = E::BaseField>,
@@ -299,17 +334,15 @@ impl FriProofLayer {
         // make sure the number of value bytes can be parsed into a whole number of queries
         let num_query_bytes = E::ELEMENT_BYTES * folding_factor;
         if self.values.len() % num_query_bytes != 0 {
-            return Err(DeserializationError::InvalidValue(format!(
-                "number of value bytes ({}) does not divide into whole number of queries",
-                self.values.len(),
-            )));
+            return Err(FriProofError::InvalidLayerValuesLength {
+                layer: layer_idx,
+                num_bytes: self.values.len(),
+            });
         }
 
         let num_queries = self.values.len() / num_query_bytes;
         if num_queries == 0 {
-            return Err(DeserializationError::InvalidValue(
-                "a FRI layer must contain at least one query".to_string(),
-            ));
+            return Err(FriProofError::EmptyLayer(layer_idx));
         }
         let mut hashed_queries = vec![H::Digest::default(); num_queries];
         let mut query_values = Vec::with_capacity(num_queries * folding_factor);
@@ -317,20 +350,29 @@ impl FriProofLayer {
         // read bytes corresponding to each query, convert them into field elements,
         // and also hash them to build leaf nodes of the batch opening proof
         let mut reader = SliceReader::new(&self.values);
-        for query_hash in hashed_queries.iter_mut() {
-            let mut qe = reader.read_many(folding_factor)?;
+        for (position, query_hash) in hashed_queries.iter_mut().enumerate() {
+            let mut qe = reader.read_many(folding_factor).map_err(|err| {
+                FriProofError::LayerValueDeserializationFailed {
+                    layer: layer_idx,
+                    position,
+                    reason: err.to_string(),
+                }
+            })?;
             *query_hash = H::hash_elements(&qe);
             query_values.append(&mut qe);
         }
-        if reader.has_more_bytes() {
-            return Err(DeserializationError::UnconsumedBytes);
-        }
 
         // build batch opening proof
         let mut reader = SliceReader::new(&self.paths);
-        let multi_proof = <V::MultiProof as Deserializable>::read_from(&mut reader)?;
+        let multi_proof =
+            <V::MultiProof as Deserializable>::read_from(&mut reader).map_err(|err| {
+                FriProofError::LayerProofDeserializationFailed {
+                    layer: layer_idx,
+                    reason: err.to_string(),
+                }
+            })?;
         if reader.has_more_bytes() {
-            return Err(DeserializationError::UnconsumedBytes);
+            return Err(FriProofError::LayerUnconsumedBytes(layer_idx));
         }
 
         Ok((query_values, multi_proof))
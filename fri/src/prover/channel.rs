@@ -9,6 +9,8 @@ use core::marker::PhantomData;
 use crypto::{ElementHasher, Hasher, RandomCoin};
 use math::FieldElement;
 
+use crate::labels;
+
 // PROVER CHANNEL TRAIT
 // ================================================================================================
 
@@ -126,7 +128,7 @@ where
 
     fn commit_fri_layer(&mut self, layer_root: H::Digest) {
         self.commitments.push(layer_root);
-        self.public_coin.reseed(layer_root);
+        self.public_coin.reseed_with_label(labels::LAYER_COMMITMENT, layer_root);
     }
 
     fn draw_fri_alpha(&mut self) -> E {
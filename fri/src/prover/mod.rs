@@ -4,18 +4,16 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
-use core::marker::PhantomData;
+use core::{marker::PhantomData, mem};
 
 use crypto::{ElementHasher, Hasher, VectorCommitment};
 use math::{fft, FieldElement};
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
-use utils::{
-    flatten_vector_elements, group_slice_elements, iter_mut, transpose_slice, uninit_vector,
-};
+use utils::{assume_init_vector, iter_mut, uninit_vector};
 
 use crate::{
-    folding::{apply_drp, fold_positions},
+    folding::{apply_drp_strided, fold_positions},
     proof::{FriProof, FriProofLayer},
     FriOptions,
 };
@@ -71,7 +69,10 @@ mod tests;
 /// on the values the prover has written into the channel up to that point.
 ///
 /// The prover keeps all FRI layers (consisting of evaluations and corresponding vector
-/// commitments) in its internal state.
+/// commitments) in its internal state. Evaluations of each layer are kept in the order in which
+/// they were received (i.e., without making a transposed copy), and the folded evaluations are
+/// computed directly from them; thus, apart from the layers themselves and their commitments, the
+/// commit phase requires no additional memory proportional to the size of the domain.
 ///
 /// # Query phase
 /// In the query phase, which is executed via [build_proof()](FriProver::build_proof()) function,
@@ -107,6 +108,9 @@ where
 
 struct FriLayer<E: FieldElement, H: Hasher, V: VectorCommitment<H>> {
     commitment: V,
+    // evaluations of the layer in natural order; a leaf (and a query position) i of the layer
+    // commitment refers to evaluations at positions i, i + n / N, i + 2 * n / N etc., where n
+    // is the number of evaluations and N is the folding factor
     evaluations: Vec<E>,
     _h: PhantomData<H>,
 }
@@ -154,6 +158,21 @@ where
         self.layers.len()
     }
 
+    /// Returns evaluations of the FRI layer at the specified index computed during the last
+    /// execution of the [build_layers()](FriProver::build_layers()) method.
+    ///
+    /// # Panics
+    /// Panics if `layer_idx` is greater than or equal to the number of layers.
+    pub fn layer_evaluations(&self, layer_idx: usize) -> &[E] {
+        &self.layers[layer_idx].evaluations
+    }
+
+    /// Returns coefficients of the remainder polynomial computed during the last execution of
+    /// the [build_layers()](FriProver::build_layers()) method.
+    pub fn remainder_poly(&self) -> &[E] {
+        &self.remainder_poly.0
+    }
+
     /// Clears a vector of internally stored layers.
     pub fn reset(&mut self) {
         self.layers.clear();
@@ -176,7 +195,27 @@ where
     ///
     /// # Panics
     /// Panics if the prover state is dirty (the vector of layers is not empty).
-    pub fn build_layers(&mut self, channel: &mut C, mut evaluations: Vec<E>) {
+    pub fn build_layers(&mut self, channel: &mut C, evaluations: Vec<E>) {
+        self.build_layers_with_progress(channel, evaluations, |_, _| {});
+    }
+
+    /// Executes the commit phase of the FRI protocol in the same way as
+    /// [build_layers()](FriProver::build_layers), but also invokes `on_layer` after each FRI layer
+    /// is committed to.
+    ///
+    /// The callback receives the index of the layer which has just been built and the total
+    /// number of layers (excluding the remainder).
+    ///
+    /// # Panics
+    /// Panics if the prover state is dirty (the vector of layers is not empty).
+    pub fn build_layers_with_progress<F>(
+        &mut self,
+        channel: &mut C,
+        mut evaluations: Vec<E>,
+        mut on_layer: F,
+    ) where
+        F: FnMut(usize, usize),
+    {
         assert!(
             self.layers.is_empty(),
             "a prior proof generation request has not been completed yet"
@@ -184,7 +223,16 @@ where
 
         // reduce the degree by folding_factor at each iteration until the remaining polynomial
         // has small enough degree
-        for _ in 0..self.options.num_fri_layers(evaluations.len()) {
+        let num_layers = self.options.num_fri_layers(evaluations.len());
+        for layer in 0..num_layers {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!(
+                "build_fri_layer",
+                current_fri_layer = self.layers.len(),
+                domain_size = evaluations.len()
+            )
+            .entered();
+
             match self.folding_factor() {
                 2 => self.build_layer::<2>(channel, &mut evaluations),
                 4 => self.build_layer::<4>(channel, &mut evaluations),
@@ -192,31 +240,36 @@ where
                 16 => self.build_layer::<16>(channel, &mut evaluations),
                 _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
             }
+            on_layer(layer, num_layers);
         }
 
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::info_span!("build_fri_remainder", domain_size = evaluations.len()).entered();
+
         self.set_remainder(channel, &mut evaluations);
     }
 
     /// Builds a single FRI layer by first committing to the `evaluations`, then drawing a random
     /// alpha from the channel and use it to perform degree-respecting projection.
     fn build_layer<const N: usize>(&mut self, channel: &mut C, evaluations: &mut Vec<E>) {
-        // commit to the evaluations at the current layer; we do this by first transposing the
-        // evaluations into a matrix of N columns, then hashing each row into a digest, and finally
-        // commiting to vector of these digests; we do this so that we could de-commit to N values
-        // with a single opening proof.
-        let transposed_evaluations = transpose_slice(evaluations);
-        let evaluation_vector_commitment =
-            build_layer_commitment::<_, _, V, N>(&transposed_evaluations)
-                .expect("failed to construct FRI layer commitment");
+        // commit to the evaluations at the current layer; we do this by hashing each row of the
+        // evaluations viewed as a matrix of N columns into a digest, and then commiting to vector
+        // of these digests; we do this so that we could de-commit to N values with a single
+        // opening proof.
+        let evaluation_vector_commitment = build_layer_commitment::<_, _, V, N>(evaluations)
+            .expect("failed to construct FRI layer commitment");
         channel.commit_fri_layer(evaluation_vector_commitment.commitment());
 
         // draw a pseudo-random coefficient from the channel, and use it in degree-respecting
-        // projection to reduce the degree of evaluations by N
+        // projection to reduce the degree of evaluations by N; the current evaluations are then
+        // moved into the layer as they are needed to answer queries
         let alpha = channel.draw_fri_alpha();
-        *evaluations = apply_drp(&transposed_evaluations, self.domain_offset(), alpha);
+        let folded_evaluations =
+            apply_drp_strided::<_, _, N>(evaluations, self.domain_offset(), alpha);
         self.layers.push(FriLayer {
             commitment: evaluation_vector_commitment,
-            evaluations: flatten_vector_elements(transposed_evaluations),
+            evaluations: mem::replace(evaluations, folded_evaluations),
             _h: PhantomData,
         });
     }
@@ -297,32 +350,34 @@ fn query_layer<E: FieldElement, H: Hasher, V: VectorCommitment<H>, const N: usiz
         .open_many(positions)
         .expect("failed to generate a batch opening proof for FRI layer queries");
 
-    // build a list of polynomial evaluations at each position; since evaluations in FRI layers
-    // are stored in transposed form, a position refers to N evaluations which are committed
-    // in a single leaf
-    let evaluations: &[[E; N]] = group_slice_elements(&layer.evaluations);
+    // build a list of polynomial evaluations at each position; a position refers to N
+    // evaluations which are committed in a single leaf
+    let row_count = layer.evaluations.len() / N;
     let mut queried_values: Vec<[E; N]> = Vec::with_capacity(positions.len());
     for &position in positions.iter() {
-        queried_values.push(evaluations[position]);
+        queried_values.push(core::array::from_fn(|j| layer.evaluations[position + j * row_count]));
     }
     FriProofLayer::new::<_, _, V, N>(queried_values, proof.1)
 }
 
-/// Hashes each of the arrays in the provided slice and returns a vector commitment to resulting
-/// hashes.
+/// Hashes each row of the provided `evaluations` viewed as a matrix of `N` columns (i.e., the
+/// evaluations at positions i, i + n / N, i + 2 * n / N etc.) and returns a vector commitment to
+/// resulting hashes.
 pub fn build_layer_commitment<E, H, V, const N: usize>(
-    values: &[[E; N]],
+    evaluations: &[E],
 ) -> Result<V, <V as VectorCommitment<H>>::Error>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
 {
-    let mut hashed_evaluations: Vec<H::Digest> = unsafe { uninit_vector(values.len()) };
-    iter_mut!(hashed_evaluations, 1024).zip(values).for_each(|(e, v)| {
-        let digest: H::Digest = H::hash_elements(v);
-        *e = digest
+    let row_count = evaluations.len() / N;
+    let mut hashed_evaluations = uninit_vector::<H::Digest>(row_count);
+    iter_mut!(hashed_evaluations, 1024).enumerate().for_each(|(i, e)| {
+        let row: [E; N] = core::array::from_fn(|j| evaluations[i + j * row_count]);
+        e.write(H::hash_elements(&row));
     });
 
-    V::new(hashed_evaluations)
+    // SAFETY: all hashed evaluations have been initialized above
+    V::new(unsafe { assume_init_vector(hashed_evaluations) })
 }
@@ -3,16 +3,29 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
+use core::{
+    alloc::{GlobalAlloc, Layout},
+    cell::Cell,
+};
+use std::{alloc::System, thread_local};
 
-use crypto::{hashers::Blake3_256, DefaultRandomCoin, Hasher, MerkleTree, RandomCoin};
+use crypto::{
+    hashers::Blake3_256, DefaultRandomCoin, ElementHasher, Hasher, MerkleTree, RandomCoin,
+    VectorCommitment,
+};
 use math::{fft, fields::f128::BaseElement, FieldElement};
-use utils::{Deserializable, Serializable, SliceReader};
+use utils::{
+    transpose_slice, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+    SliceReader,
+};
 
-use super::{DefaultProverChannel, FriProver};
+use super::{DefaultProverChannel, FriProver, ProverChannel};
 use crate::{
+    folding::{apply_drp, fold_positions},
+    proof::FriProofLayer,
     verifier::{DefaultVerifierChannel, FriVerifier},
-    FriOptions, FriProof, VerifierError,
+    FriOptions, FriProof, FriProofError, VerifierError,
 };
 
 type Blake3 = Blake3_256<BaseElement>;
@@ -38,9 +51,472 @@ fn fri_folding_4() {
     fri_prove_verify(trace_length_e, lde_blowup_e, folding_factor_e, max_remainder_degree)
 }
 
+#[test]
+fn fri_folding_8() {
+    let trace_length_e = 12;
+    let lde_blowup_e = 3;
+    let folding_factor_e = 3;
+    let max_remainder_degree = 7;
+    fri_prove_verify(trace_length_e, lde_blowup_e, folding_factor_e, max_remainder_degree)
+}
+
+#[test]
+fn fri_folding_16() {
+    let trace_length_e = 12;
+    let lde_blowup_e = 3;
+    let folding_factor_e = 4;
+    let max_remainder_degree = 15;
+    fri_prove_verify(trace_length_e, lde_blowup_e, folding_factor_e, max_remainder_degree)
+}
+
+// LAYER CONSTRUCTION TESTS
+// ================================================================================================
+
+#[test]
+fn fri_proof_matches_transposed_layers() {
+    let trace_length = 1 << 10;
+    let lde_blowup = 1 << 3;
+    for folding_factor in [2, 4, 8, 16] {
+        let options = FriOptions::new(lde_blowup, folding_factor, 7);
+        let evaluations = build_evaluations(trace_length, lde_blowup);
+
+        let mut channel = build_prover_channel(trace_length, &options);
+        let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+        prover.build_layers(&mut channel, evaluations.clone());
+        let positions = channel.draw_query_positions(0);
+        let proof = prover.build_proof(&positions);
+
+        let mut expected_channel = build_prover_channel(trace_length, &options);
+        let expected_proof = match folding_factor {
+            2 => build_transposed_proof::<2>(&options, &mut expected_channel, evaluations),
+            4 => build_transposed_proof::<4>(&options, &mut expected_channel, evaluations),
+            8 => build_transposed_proof::<8>(&options, &mut expected_channel, evaluations),
+            16 => build_transposed_proof::<16>(&options, &mut expected_channel, evaluations),
+            _ => unreachable!(),
+        };
+
+        assert_eq!(expected_channel.layer_commitments(), channel.layer_commitments());
+        assert_eq!(expected_proof, proof);
+    }
+}
+
+#[test]
+fn fri_layers_progress() {
+    let trace_length = 1 << 10;
+    let lde_blowup = 1 << 3;
+    let options = FriOptions::new(lde_blowup, 4, 7);
+    let evaluations = build_evaluations(trace_length, lde_blowup);
+    let num_layers = options.num_fri_layers(evaluations.len());
+
+    let mut channel = build_prover_channel(trace_length, &options);
+    let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+    let mut layers = Vec::new();
+    prover.build_layers_with_progress(&mut channel, evaluations.clone(), |layer, total| {
+        layers.push((layer, total))
+    });
+    let proof = prover.build_proof(&channel.draw_query_positions(0));
+
+    // the callback is invoked once per layer, in order
+    let expected = (0..num_layers).map(|layer| (layer, num_layers)).collect::<Vec<_>>();
+    assert_eq!(expected, layers);
+
+    // the layers are the same as the layers built without the callback
+    let mut expected_channel = build_prover_channel(trace_length, &options);
+    let mut expected_prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options);
+    expected_prover.build_layers(&mut expected_channel, evaluations);
+    let expected_proof = expected_prover.build_proof(&expected_channel.draw_query_positions(0));
+    assert_eq!(expected_channel.layer_commitments(), channel.layer_commitments());
+    assert_eq!(expected_proof, proof);
+}
+
+/// Layer evaluations are kept without making transposed copies of them, and so the commit phase
+/// should require no memory beyond what is retained in the layers (i.e., evaluations and their
+/// commitments) and small temporary values.
+#[test]
+#[cfg(not(feature = "concurrent"))]
+fn fri_layers_peak_memory() {
+    let trace_length = 1 << 12;
+    let lde_blowup = 1 << 3;
+    let domain_size = trace_length * lde_blowup;
+    let options = FriOptions::new(lde_blowup, 4, 7);
+
+    let evaluations = build_evaluations(trace_length, lde_blowup);
+    let mut channel = build_prover_channel(trace_length, &options);
+    let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+    let stats = track_memory(|| prover.build_layers(&mut channel, evaluations.clone()));
+
+    // all evaluation buffers held by the layers are no larger than the layer domains
+    let mut layer_size = domain_size;
+    for layer in prover.layers.iter() {
+        assert_eq!(layer_size, layer.evaluations.capacity());
+        layer_size /= options.folding_factor();
+    }
+
+    let mut expected_channel = build_prover_channel(trace_length, &options);
+    let expected_stats = track_memory(|| {
+        build_transposed_layers::<4>(&options, &mut expected_channel, evaluations.clone())
+    });
+
+    // the input evaluations are moved into the first layer, and so the live memory at the end
+    // of the commit phase is (almost) the same for both approaches; however, when building layers
+    // from transposed copies of the evaluations, the evaluations of the first layer are held twice
+    let buffer_size = domain_size * size_of::<BaseElement>();
+    assert!(stats.live <= expected_stats.live);
+    assert!(stats.peak - stats.live < buffer_size / 2);
+    assert!(expected_stats.peak - stats.peak > buffer_size / 2);
+}
+
+// PARSING TESTS
+// ================================================================================================
+
+#[test]
+fn fri_proof_serialization() {
+    let trace_length = 1 << 6;
+    let lde_blowup = 1 << 3;
+    let options = FriOptions::new(lde_blowup, 4, 7);
+    let mut channel = build_prover_channel(trace_length, &options);
+    let evaluations = build_evaluations(trace_length, lde_blowup);
+
+    let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+    prover.build_layers(&mut channel, evaluations);
+    let positions = channel.draw_query_positions(0);
+    let proof = prover.build_proof(&positions);
+
+    // the proof should survive a round trip through serialization
+    let bytes = proof.to_bytes();
+    assert_eq!(proof, FriProof::read_from_bytes(&bytes).unwrap());
+
+    // truncated proofs should be rejected
+    let result = FriProof::read_from_bytes(&bytes[..bytes.len() - 1]);
+    assert_eq!(Err(DeserializationError::UnexpectedEOF), result);
+}
+
+#[test]
+fn fri_proof_layer_parsing_errors() {
+    // build a layer with a single query for folding factor 2, but without an opening proof
+    let mut bytes = Vec::new();
+    bytes.write_u32(2 * BaseElement::ELEMENT_BYTES as u32);
+    bytes.write_many([BaseElement::ONE; 2]);
+    bytes.write_u32(0);
+    let layer = FriProofLayer::read_from_bytes(&bytes).unwrap();
+
+    let proof = FriProof::new(vec![layer], vec![BaseElement::ONE], 1);
+    let result = proof.parse_layers::<BaseElement, Blake3, MerkleTree<Blake3>>(1, 4, 2);
+    assert_eq!(
+        Err(FriProofError::LayerProofDeserializationFailed {
+            layer: 0,
+            reason: "unexpected EOF".to_string()
+        }),
+        result
+    );
+}
+
+#[test]
+fn fri_proof_parsing_errors() {
+    let (proof, commitments, options) = build_test_proof();
+    let raw = RawFriProof::from_proof(&proof);
+    let domain_size = build_evaluations(TRACE_LENGTH, options.blowup_factor()).len();
+    let num_layers = proof.num_layers();
+    let query_bytes = BaseElement::ELEMENT_BYTES * options.folding_factor();
+
+    // the unmodified proof can be parsed
+    assert!(parse_proof(raw.to_proof(), num_layers, &options).is_ok());
+
+    // missing and extra layers
+    let mut modified = raw.clone();
+    modified.layers.pop();
+    assert_eq!(
+        Err(FriProofError::InvalidNumLayers {
+            expected: num_layers,
+            actual: num_layers - 1
+        }),
+        parse_proof(modified.to_proof(), num_layers, &options)
+    );
+    let mut modified = raw.clone();
+    modified.layers.push(raw.layers[0].clone());
+    assert_eq!(
+        Err(FriProofError::InvalidNumLayers {
+            expected: num_layers,
+            actual: num_layers + 1
+        }),
+        parse_proof(modified.to_proof(), num_layers, &options)
+    );
+
+    // query values which do not divide into whole queries
+    for layer in 0..num_layers {
+        let mut modified = raw.clone();
+        modified.layers[layer].0.pop();
+        let num_bytes = modified.layers[layer].0.len();
+        assert_eq!(
+            Err(FriProofError::InvalidLayerValuesLength { layer, num_bytes }),
+            parse_proof(modified.to_proof(), num_layers, &options)
+        );
+    }
+
+    // layers without any query values are rejected already when the proof is read
+    for layer in 0..num_layers {
+        let mut modified = raw.clone();
+        modified.layers[layer].0.clear();
+        assert!(FriProof::read_from_bytes(&modified.to_bytes()).is_err());
+    }
+
+    // query values which are not valid field elements
+    let mut modified = raw.clone();
+    modified.layers[1].0[query_bytes..query_bytes + BaseElement::ELEMENT_BYTES].fill(0xff);
+    assert!(matches!(
+        parse_proof(modified.to_proof(), num_layers, &options),
+        Err(FriProofError::LayerValueDeserializationFailed { layer: 1, position: 1, .. })
+    ));
+
+    // truncated and padded opening proofs
+    for layer in 0..num_layers {
+        let mut modified = raw.clone();
+        modified.layers[layer].1.truncate(raw.layers[layer].1.len() / 2);
+        assert!(matches!(
+            parse_proof(modified.to_proof(), num_layers, &options),
+            Err(FriProofError::LayerProofDeserializationFailed { layer: l, .. }) if l == layer
+        ));
+
+        let mut modified = raw.clone();
+        modified.layers[layer].1.push(0);
+        assert_eq!(
+            Err(FriProofError::LayerUnconsumedBytes(layer)),
+            parse_proof(modified.to_proof(), num_layers, &options)
+        );
+    }
+
+    // opening proof for a domain of a different size
+    let mut modified = raw.clone();
+    modified.layers.swap(0, 1);
+    assert_eq!(
+        Err(FriProofError::LayerDomainSizeMismatch {
+            layer: 0,
+            expected: domain_size / options.folding_factor(),
+            actual: domain_size / options.folding_factor().pow(2),
+        }),
+        parse_proof(modified.to_proof(), num_layers, &options)
+    );
+
+    // truncated, padded, and invalid remainders
+    let num_remainder_elements = raw.remainder.len() / BaseElement::ELEMENT_BYTES;
+    let mut modified = raw.clone();
+    modified.remainder.truncate(raw.remainder.len() - BaseElement::ELEMENT_BYTES);
+    assert_eq!(
+        Err(FriProofError::RemainderLengthNotPowerOfTwo(num_remainder_elements - 1)),
+        parse_proof(modified.to_proof(), num_layers, &options)
+    );
+    let mut modified = raw.clone();
+    modified.remainder.clear();
+    assert_eq!(
+        Err(FriProofError::RemainderLengthNotPowerOfTwo(0)),
+        parse_proof(modified.to_proof(), num_layers, &options)
+    );
+    let mut modified = raw.clone();
+    modified.remainder.push(0);
+    assert_eq!(
+        Err(FriProofError::RemainderUnconsumedBytes),
+        parse_proof(modified.to_proof(), num_layers, &options)
+    );
+    let mut modified = raw.clone();
+    modified.remainder[BaseElement::ELEMENT_BYTES..2 * BaseElement::ELEMENT_BYTES].fill(0xff);
+    assert!(matches!(
+        parse_proof(modified.to_proof(), num_layers, &options),
+        Err(FriProofError::RemainderValueDeserializationFailed { position: 1, .. })
+    ));
+
+    // parsing errors are reported by the verifier as malformed proofs
+    let mut modified = raw.clone();
+    modified.layers.pop();
+    let result = DefaultVerifierChannel::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+        modified.to_proof(),
+        commitments,
+        domain_size,
+        options.folding_factor(),
+    );
+    assert!(matches!(result, Err(FriProofError::InvalidNumLayers { .. })));
+}
+
+#[test]
+fn fri_proof_num_queries_errors() {
+    let (proof, commitments, options) = build_test_proof();
+    let raw = RawFriProof::from_proof(&proof);
+    let query_bytes = BaseElement::ELEMENT_BYTES * options.folding_factor();
+
+    for layer in 0..proof.num_layers() {
+        let num_queries = raw.layers[layer].0.len() / query_bytes;
+
+        // a query is missing
+        let mut modified = raw.clone();
+        modified.layers[layer].0.truncate((num_queries - 1) * query_bytes);
+        assert_eq!(
+            Err(VerifierError::MalformedProof(FriProofError::InvalidNumQueries {
+                layer,
+                expected: num_queries,
+                actual: num_queries - 1,
+            })),
+            verify_test_proof(modified.to_proof(), commitments.clone(), &options)
+        );
+
+        // an extra query is present
+        let mut modified = raw.clone();
+        let extra_query = modified.layers[layer].0[..query_bytes].to_vec();
+        modified.layers[layer].0.extend_from_slice(&extra_query);
+        assert_eq!(
+            Err(VerifierError::MalformedProof(FriProofError::InvalidNumQueries {
+                layer,
+                expected: num_queries,
+                actual: num_queries + 1,
+            })),
+            verify_test_proof(modified.to_proof(), commitments.clone(), &options)
+        );
+    }
+
+    // the number of layers in a proof is inferred from the number of layer commitments
+    let mut short_commitments = commitments.clone();
+    short_commitments.remove(0);
+    let num_layers = proof.num_layers();
+    assert_eq!(
+        Err(VerifierError::MalformedProof(FriProofError::InvalidNumLayers {
+            expected: num_layers - 1,
+            actual: num_layers,
+        })),
+        verify_test_proof(proof, short_commitments, &options)
+    );
+}
+
+#[test]
+fn fri_proof_mutations_do_not_panic() {
+    let (proof, commitments, options) = build_test_proof();
+    let bytes = proof.to_bytes();
+
+    // every truncation of the proof is either rejected or fails verification
+    for len in 0..bytes.len() {
+        if let Ok(proof) = FriProof::read_from_bytes(&bytes[..len]) {
+            assert!(verify_test_proof(proof, commitments.clone(), &options).is_err());
+        }
+    }
+
+    // flipping any of the bytes of the proof results in a verification failure
+    for i in (0..bytes.len()).step_by(7) {
+        let mut modified = bytes.clone();
+        modified[i] ^= 0x5a;
+        if let Ok(proof) = FriProof::read_from_bytes(&modified) {
+            assert!(verify_test_proof(proof, commitments.clone(), &options).is_err());
+        }
+    }
+}
+
 // TEST UTILS
 // ================================================================================================
 
+const TRACE_LENGTH: usize = 1 << 6;
+
+/// Components of a serialized FRI proof which can be modified independently.
+#[derive(Clone)]
+struct RawFriProof {
+    layers: Vec<(Vec<u8>, Vec<u8>)>,
+    remainder: Vec<u8>,
+    num_partitions: u8,
+}
+
+impl RawFriProof {
+    fn from_proof(proof: &FriProof) -> Self {
+        let bytes = proof.to_bytes();
+        let mut reader = SliceReader::new(&bytes);
+        let num_layers = reader.read_u8().unwrap();
+        let layers = (0..num_layers)
+            .map(|_| {
+                let num_value_bytes = reader.read_u32().unwrap() as usize;
+                let values = reader.read_vec(num_value_bytes).unwrap();
+                let num_path_bytes = reader.read_u32().unwrap() as usize;
+                let paths = reader.read_vec(num_path_bytes).unwrap();
+                (values, paths)
+            })
+            .collect();
+        let num_remainder_bytes = reader.read_u16().unwrap() as usize;
+        let remainder = reader.read_vec(num_remainder_bytes).unwrap();
+        let num_partitions = reader.read_u8().unwrap();
+        Self { layers, remainder, num_partitions }
+    }
+
+    fn to_proof(&self) -> FriProof {
+        FriProof::read_from_bytes(&self.to_bytes()).unwrap()
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.write_u8(self.layers.len() as u8);
+        for (values, paths) in self.layers.iter() {
+            bytes.write_u32(values.len() as u32);
+            bytes.write_bytes(values);
+            bytes.write_u32(paths.len() as u32);
+            bytes.write_bytes(paths);
+        }
+        bytes.write_u16(self.remainder.len() as u16);
+        bytes.write_bytes(&self.remainder);
+        bytes.write_u8(self.num_partitions);
+        bytes
+    }
+}
+
+/// Builds a proof with 2 FRI layers using folding factor 4, together with layer commitments.
+fn build_test_proof() -> (FriProof, Vec<<Blake3 as Hasher>::Digest>, FriOptions) {
+    let options = FriOptions::new(8, 4, 7);
+    let mut channel = build_prover_channel(TRACE_LENGTH, &options);
+    let evaluations = build_evaluations(TRACE_LENGTH, options.blowup_factor());
+
+    let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+    prover.build_layers(&mut channel, evaluations);
+    let positions = channel.draw_query_positions(0);
+    let proof = prover.build_proof(&positions);
+    assert_eq!(2, proof.num_layers());
+
+    (proof, channel.layer_commitments().to_vec(), options)
+}
+
+fn parse_proof(
+    proof: FriProof,
+    num_layers: usize,
+    options: &FriOptions,
+) -> Result<(), FriProofError> {
+    let domain_size = TRACE_LENGTH * options.blowup_factor();
+    proof.parse_remainder::<BaseElement>()?;
+    proof.parse_layers::<BaseElement, Blake3, MerkleTree<Blake3>>(
+        num_layers,
+        domain_size,
+        options.folding_factor(),
+    )?;
+    Ok(())
+}
+
+/// Verifies a proof built by [build_test_proof()] after it has been modified; parsing errors are
+/// reported as malformed proofs.
+fn verify_test_proof(
+    proof: FriProof,
+    commitments: Vec<<Blake3 as Hasher>::Digest>,
+    options: &FriOptions,
+) -> Result<(), VerifierError> {
+    let evaluations = build_evaluations(TRACE_LENGTH, options.blowup_factor());
+    let domain_size = evaluations.len();
+    let mut prover_channel = build_prover_channel(TRACE_LENGTH, options);
+    for &commitment in commitments.iter() {
+        prover_channel.commit_fri_layer(commitment);
+    }
+    let positions = prover_channel.draw_query_positions(0);
+
+    let mut channel = DefaultVerifierChannel::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+        proof,
+        commitments.clone(),
+        domain_size,
+        options.folding_factor(),
+    )
+    .map_err(VerifierError::MalformedProof)?;
+    let mut coin = DefaultRandomCoin::<Blake3>::new(&[]);
+    let verifier = FriVerifier::new(&mut channel, &mut coin, options.clone(), TRACE_LENGTH - 1)?;
+    let queried_evaluations = positions.iter().map(|&p| evaluations[p]).collect::<Vec<_>>();
+    verifier.verify(&mut channel, &queried_evaluations, &positions)
+}
+
 pub fn build_prover_channel(
     trace_length: usize,
     options: &FriOptions,
@@ -135,3 +611,127 @@ fn fri_prove_verify(
     );
     assert!(result.is_err());
 }
+
+// REFERENCE LAYER CONSTRUCTION
+// ================================================================================================
+
+/// Builds FRI layers by committing to and folding transposed copies of layer evaluations.
+#[allow(clippy::type_complexity)]
+fn build_transposed_layers<const N: usize>(
+    options: &FriOptions,
+    channel: &mut DefaultProverChannel<BaseElement, Blake3, DefaultRandomCoin<Blake3>>,
+    mut evaluations: Vec<BaseElement>,
+) -> (Vec<(MerkleTree<Blake3>, Vec<[BaseElement; N]>)>, Vec<BaseElement>) {
+    let mut layers = Vec::new();
+    for _ in 0..options.num_fri_layers(evaluations.len()) {
+        let transposed_evaluations = transpose_slice::<_, N>(&evaluations);
+        let leaves = transposed_evaluations.iter().map(|row| Blake3::hash_elements(row)).collect();
+        let tree = MerkleTree::<Blake3>::new(leaves).unwrap();
+        channel.commit_fri_layer(*tree.root());
+
+        let alpha = channel.draw_fri_alpha();
+        evaluations = apply_drp(&transposed_evaluations, options.domain_offset(), alpha);
+        layers.push((tree, transposed_evaluations));
+    }
+
+    let inv_twiddles = fft::get_inv_twiddles(evaluations.len());
+    fft::interpolate_poly_with_offset(&mut evaluations, &inv_twiddles, options.domain_offset());
+    evaluations.truncate(evaluations.len() / options.blowup_factor());
+    channel.commit_fri_layer(Blake3::hash_elements(&evaluations));
+
+    (layers, evaluations)
+}
+
+/// Builds a FRI proof from layers built by [build_transposed_layers()].
+fn build_transposed_proof<const N: usize>(
+    options: &FriOptions,
+    channel: &mut DefaultProverChannel<BaseElement, Blake3, DefaultRandomCoin<Blake3>>,
+    evaluations: Vec<BaseElement>,
+) -> FriProof {
+    let mut domain_size = evaluations.len();
+    let (layers, remainder) = build_transposed_layers::<N>(options, channel, evaluations);
+
+    let mut positions = channel.draw_query_positions(0);
+    let mut proof_layers = Vec::new();
+    for (tree, transposed_evaluations) in layers.iter() {
+        positions = fold_positions(&positions, domain_size, N);
+        let (_, opening) = tree.open_many(&positions).unwrap();
+        let queried_values = positions.iter().map(|&p| transposed_evaluations[p]).collect();
+        proof_layers
+            .push(FriProofLayer::new::<_, _, MerkleTree<Blake3>, N>(queried_values, opening));
+        domain_size /= N;
+    }
+
+    FriProof::new(proof_layers, remainder, 1)
+}
+
+// MEMORY TRACKING
+// ================================================================================================
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+thread_local! {
+    static TRACKING: Cell<bool> = const { Cell::new(false) };
+    static LIVE_BYTES: Cell<isize> = const { Cell::new(0) };
+    static PEAK_BYTES: Cell<isize> = const { Cell::new(0) };
+}
+
+/// Memory allocated on the current thread while executing a closure.
+#[cfg(not(feature = "concurrent"))]
+struct MemoryStats {
+    /// Bytes which were still allocated when the closure returned.
+    live: usize,
+    /// Largest number of bytes allocated at any point during the execution of the closure.
+    peak: usize,
+}
+
+/// Executes `f` and returns statistics of memory allocated by it on the current thread; memory
+/// occupied by the value returned from `f` is included in the live memory.
+#[cfg(not(feature = "concurrent"))]
+fn track_memory<R>(f: impl FnOnce() -> R) -> MemoryStats {
+    LIVE_BYTES.set(0);
+    PEAK_BYTES.set(0);
+    TRACKING.set(true);
+    let result = f();
+    TRACKING.set(false);
+    core::mem::forget(result);
+
+    MemoryStats {
+        live: LIVE_BYTES.get().try_into().unwrap(),
+        peak: PEAK_BYTES.get().try_into().unwrap(),
+    }
+}
+
+/// Delegates to the system allocator, and keeps track of the number of bytes allocated on the
+/// current thread while tracking is enabled.
+struct TrackingAllocator;
+
+impl TrackingAllocator {
+    fn record(delta: isize) {
+        let _ = TRACKING.try_with(|tracking| {
+            if tracking.get() {
+                let live = LIVE_BYTES.get() + delta;
+                LIVE_BYTES.set(live);
+                PEAK_BYTES.set(PEAK_BYTES.get().max(live));
+            }
+        });
+    }
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::record(layout.size() as isize);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        Self::record(-(layout.size() as isize));
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::record(new_size as isize - layout.size() as isize);
+        System.realloc(ptr, layout, new_size)
+    }
+}
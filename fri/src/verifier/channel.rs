@@ -8,9 +8,9 @@ use core::marker::PhantomData;
 
 use crypto::{ElementHasher, Hasher, VectorCommitment};
 use math::FieldElement;
-use utils::{group_slice_elements, DeserializationError};
+use utils::group_vector_elements;
 
-use crate::{FriProof, VerifierError};
+use crate::{FriProof, FriProofError, VerifierError};
 
 // VERIFIER CHANNEL TRAIT
 // ================================================================================================
@@ -74,22 +74,35 @@ pub trait VerifierChannel<E: FieldElement> {
     // --------------------------------------------------------------------------------------------
 
     /// Returns FRI query values at the specified positions from the current FRI layer and advances
-    /// layer pointer by one.
+    /// layer pointer by one; `layer_idx` is the index of the current layer and is used only for
+    /// reporting errors.
     ///
     /// This also checks if the values are valid against the provided FRI layer commitment.
     ///
     /// # Errors
-    /// Returns an error if query values did not match layer commitment.
+    /// Returns an error if:
+    /// * The number of query values read from the channel does not match the number of
+    ///   `positions`.
+    /// * Query values did not match layer commitment.
     fn read_layer_queries<const N: usize>(
         &mut self,
+        layer_idx: usize,
         positions: &[usize],
         commitment: &<Self::Hasher as Hasher>::Digest,
     ) -> Result<Vec<[E; N]>, VerifierError> {
         let layer_proof = self.take_next_fri_layer_proof();
         let layer_queries = self.take_next_fri_layer_queries();
+        if layer_queries.len() != positions.len() * N {
+            return Err(VerifierError::MalformedProof(FriProofError::InvalidNumQueries {
+                layer: layer_idx,
+                expected: positions.len(),
+                actual: layer_queries.len() / N,
+            }));
+        }
+
         // build the values (i.e., polynomial evaluations over a coset of a multiplicative subgroup
         // of the current evaluation domain) corresponding to each leaf of the layer commitment
-        let leaf_values = group_slice_elements(&layer_queries);
+        let leaf_values = group_vector_elements(layer_queries);
         // hash the aforementioned values to get the leaves to be verified against the previously
         // received commitment
         let hashed_values: Vec<<Self::Hasher as Hasher>::Digest> = leaf_values
@@ -105,7 +118,7 @@ pub trait VerifierChannel<E: FieldElement> {
         )
         .map_err(|_| VerifierError::LayerCommitmentMismatch)?;
 
-        Ok(leaf_values.to_vec())
+        Ok(leaf_values)
     }
 
     /// Returns FRI remainder polynomial read from this channel.
@@ -146,6 +159,10 @@ where
 {
     /// Builds a new verifier channel from the specified [FriProof].
     ///
+    /// The `layer_commitments` are expected to contain commitments to all FRI layers followed by
+    /// the commitment to the remainder, and thus, the proof is expected to contain one layer
+    /// less than there are commitments.
+    ///
     /// # Errors
     /// Returns an error if the specified `proof` could not be parsed correctly.
     pub fn new(
@@ -153,12 +170,13 @@ where
         layer_commitments: Vec<H::Digest>,
         domain_size: usize,
         folding_factor: usize,
-    ) -> Result<Self, DeserializationError> {
+    ) -> Result<Self, FriProofError> {
         let num_partitions = proof.num_partitions();
+        let num_layers = layer_commitments.len().saturating_sub(1);
 
         let remainder = proof.parse_remainder()?;
         let (layer_queries, layer_proofs) =
-            proof.parse_layers::<E, H, V>(domain_size, folding_factor)?;
+            proof.parse_layers::<E, H, V>(num_layers, domain_size, folding_factor)?;
 
         Ok(DefaultVerifierChannel {
             layer_commitments,
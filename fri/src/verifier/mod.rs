@@ -11,7 +11,10 @@ use core::{marker::PhantomData, mem};
 use crypto::{ElementHasher, RandomCoin, VectorCommitment};
 use math::{polynom, FieldElement, StarkField};
 
-use crate::{folding::fold_positions, utils::map_positions_to_indexes, FriOptions, VerifierError};
+use crate::{
+    folding::fold_positions, labels, utils::map_positions_to_indexes, FriOptions, FriProofError,
+    VerifierError,
+};
 
 mod channel;
 pub use channel::{DefaultVerifierChannel, VerifierChannel};
@@ -116,12 +119,22 @@ where
 
         let num_partitions = channel.read_fri_num_partitions();
 
-        // read layer commitments from the channel and use them to build a list of alphas
+        // read layer commitments from the channel and make sure there is a commitment for each
+        // FRI layer as well as for the remainder
         let layer_commitments = channel.read_fri_layer_commitments();
+        let num_layers = options.num_fri_layers(domain_size);
+        if layer_commitments.len() != num_layers + 1 {
+            return Err(VerifierError::MalformedProof(FriProofError::InvalidNumLayers {
+                expected: num_layers,
+                actual: layer_commitments.len().saturating_sub(1),
+            }));
+        }
+
+        // use layer commitments to build a list of alphas
         let mut layer_alphas = Vec::with_capacity(layer_commitments.len());
         let mut max_degree_plus_1 = max_poly_degree + 1;
         for (depth, commitment) in layer_commitments.iter().enumerate() {
-            public_coin.reseed(*commitment);
+            public_coin.reseed_with_label(labels::LAYER_COMMITMENT, *commitment);
             let alpha = public_coin.draw().map_err(VerifierError::RandomCoinError)?;
             layer_alphas.push(alpha);
 
@@ -252,6 +265,15 @@ where
         let mut evaluations = evaluations.to_vec();
 
         for depth in 0..self.options.num_fri_layers(self.domain_size) {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!(
+                "verify_fri_layer",
+                current_fri_layer = depth,
+                domain_size,
+                num_queries = positions.len()
+            )
+            .entered();
+
             // determine which evaluations were queried in the folded layer
             let mut folded_positions =
                 fold_positions(&positions, domain_size, self.options.folding_factor());
@@ -264,8 +286,8 @@ where
             );
             // read query values from the specified indexes
             let layer_commitment = self.layer_commitments[depth];
-            // TODO: add layer depth to the potential error message
-            let layer_values = channel.read_layer_queries(&position_indexes, &layer_commitment)?;
+            let layer_values =
+                channel.read_layer_queries(depth, &position_indexes, &layer_commitment)?;
             let query_values =
                 get_query_values::<E, N>(&layer_values, &positions, &folded_positions, domain_size);
             if evaluations != query_values {
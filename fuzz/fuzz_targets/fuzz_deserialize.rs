@@ -0,0 +1,26 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Fuzzes deserialization of STARK proofs from arbitrary bytes.
+//!
+//! Deserialization must never panic, run out of memory, or loop forever: for any input it must
+//! either return a proof or an error. Proofs which were successfully parsed must also survive a
+//! serialization round trip.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use winterfell::{ContinuationProof, Proof};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(proof) = Proof::from_bytes(data) {
+        let bytes = proof.to_bytes();
+        let parsed = Proof::from_bytes(&bytes).expect("failed to re-parse a serialized proof");
+        assert_eq!(proof, parsed);
+    }
+
+    let _ = Proof::deserialize_versioned(data);
+    let _ = ContinuationProof::<winterfell::math::fields::f128::BaseElement>::from_bytes(data);
+});
@@ -0,0 +1,226 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Fuzzes the full verification path for a fixed Fibonacci AIR.
+//!
+//! Arbitrary bytes rarely get past proof parsing, and so the target works in two modes selected
+//! by the first byte of the input:
+//! * If the first byte is even, the remaining bytes are parsed as a proof directly.
+//! * Otherwise, the remaining bytes are XOR-ed into a valid proof for the AIR starting at an
+//!   offset taken from the next two bytes; this lets the fuzzer reach deep into the verifier
+//!   with proofs which are well-formed but invalid.
+//!
+//! In both modes, the verifier must never panic: it must either accept the proof or return an
+//! error.
+
+#![no_main]
+
+use std::sync::OnceLock;
+
+use libfuzzer_sys::fuzz_target;
+use winterfell::{
+    crypto::{hashers::Blake3_256, DefaultRandomCoin, ElementHasher, MerkleTree},
+    math::{fields::f128::BaseElement, FieldElement},
+    matrix::ColMatrix,
+    verify, AcceptableOptions, Air, AirContext, Assertion, AuxRandElements, CompositionPoly,
+    CompositionPolyTrace, ConstraintCompositionCoefficients, DefaultConstraintCommitment,
+    DefaultConstraintEvaluator, DefaultTraceLde, EvaluationFrame, FieldExtension, PartitionOptions,
+    Proof, ProofOptions, Prover, StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable,
+    TransitionConstraintDegree,
+};
+
+type Hasher = Blake3_256<BaseElement>;
+type VC = MerkleTree<Hasher>;
+type RandomCoin = DefaultRandomCoin<Hasher>;
+
+/// Number of rows in the execution trace of the proven computation.
+const TRACE_LENGTH: usize = 16;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&mode, data)) = data.split_first() else {
+        return;
+    };
+
+    let (valid_proof, result) = valid_proof();
+    if mode % 2 == 0 {
+        if let Ok(proof) = Proof::from_bytes(data) {
+            let _ = verify_proof(proof, *result);
+        }
+    } else {
+        let Some((offset, mask)) = data.split_first_chunk::<2>() else {
+            return;
+        };
+        let mut bytes = valid_proof.clone();
+        let offset = u16::from_le_bytes(*offset) as usize % bytes.len();
+        for (byte, mask) in bytes[offset..].iter_mut().zip(mask) {
+            *byte ^= mask;
+        }
+
+        if let Ok(proof) = Proof::from_bytes(&bytes) {
+            let _ = verify_proof(proof, *result);
+        }
+    }
+});
+
+/// Returns the bytes of a valid proof for the Fibonacci AIR together with the public inputs of
+/// the proof; the proof is generated once and reused across fuzzing iterations.
+fn valid_proof() -> &'static (Vec<u8>, BaseElement) {
+    static PROOF: OnceLock<(Vec<u8>, BaseElement)> = OnceLock::new();
+    PROOF.get_or_init(|| {
+        let options = ProofOptions::new(28, 8, 0, FieldExtension::None, 4, 7);
+        let prover = FibProver::<Hasher>::new(options);
+        let trace = prover.build_trace();
+        let result = prover.get_pub_inputs(&trace);
+        let proof = prover.prove(trace).expect("failed to generate a valid proof");
+        (proof.to_bytes(), result)
+    })
+}
+
+fn verify_proof(proof: Proof, result: BaseElement) -> Result<(), winterfell::VerifierError> {
+    // proof options are taken from the proof itself to let the fuzzer exercise as many parameter
+    // combinations as possible
+    let acceptable_options = AcceptableOptions::MinConjecturedSecurity(0);
+    verify::<FibAir, Hasher, RandomCoin, VC>(proof, result, &acceptable_options)
+}
+
+// FIBONACCI AIR
+// ================================================================================================
+
+struct FibAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for FibAir {
+    type BaseField = BaseElement;
+    type PublicInputs = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::BaseField, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(1)];
+        FibAir {
+            context: AirContext::new(trace_info, degrees, 3, options),
+            result: pub_inputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        result[0] = next[0] - (current[0] + current[1]);
+        result[1] = next[1] - (current[1] + next[0]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, Self::BaseField::ONE),
+            Assertion::single(1, 0, Self::BaseField::ONE),
+            Assertion::single(1, last_step, self.result),
+        ]
+    }
+}
+
+// FIBONACCI PROVER
+// ================================================================================================
+
+struct FibProver<H: ElementHasher> {
+    options: ProofOptions,
+    _hasher: std::marker::PhantomData<H>,
+}
+
+impl<H: ElementHasher> FibProver<H> {
+    fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _hasher: std::marker::PhantomData,
+        }
+    }
+
+    fn build_trace(&self) -> TraceTable<BaseElement> {
+        let mut trace = TraceTable::new(2, TRACE_LENGTH);
+        trace.fill(
+            |state| {
+                state[0] = BaseElement::ONE;
+                state[1] = BaseElement::ONE;
+            },
+            |_, state| {
+                state[0] += state[1];
+                state[1] += state[0];
+            },
+        );
+        trace
+    }
+}
+
+impl<H> Prover for FibProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = FibAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<H>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+        trace.get(1, trace.length() - 1)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use math::FieldElement;
+
+// GATE
+// ================================================================================================
+
+/// A fan-in 2 gate of a layered arithmetic circuit.
+///
+/// Every gate reads two values from the previous layer of the circuit (or from the circuit inputs
+/// for gates of the first layer); the values are identified by their positions in that layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gate {
+    /// Outputs the sum of the values at the specified positions.
+    Add(usize, usize),
+    /// Outputs the product of the values at the specified positions.
+    Mul(usize, usize),
+}
+
+impl Gate {
+    /// Returns positions of the left and right inputs of this gate.
+    pub fn inputs(&self) -> (usize, usize) {
+        match *self {
+            Self::Add(left, right) | Self::Mul(left, right) => (left, right),
+        }
+    }
+}
+
+// LAYERED CIRCUIT
+// ================================================================================================
+
+/// A layered arithmetic circuit over field `F`.
+///
+/// The circuit consists of a sequence of layers, each of which is a list of [Gate]s. Gates of
+/// the first layer read circuit inputs, and gates of every subsequent layer read outputs of the
+/// previous layer; outputs of the last layer are the outputs of the circuit. The number of inputs
+/// and the number of gates in every layer must be powers of two, so that the values of every
+/// layer can be interpreted as evaluations of a multilinear polynomial over a boolean hypercube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayeredCircuit<F: FieldElement> {
+    num_inputs: usize,
+    layers: Vec<Vec<Gate>>,
+    _field: PhantomData<F>,
+}
+
+impl<F: FieldElement> LayeredCircuit<F> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new circuit with the specified number of inputs and layers of gates.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `layers` is empty.
+    /// * `num_inputs` or the number of gates in any of the layers is smaller than 2 or is not
+    ///   a power of two.
+    /// * Any of the gates reads a value outside of the previous layer.
+    pub fn new(num_inputs: usize, layers: Vec<Vec<Gate>>) -> Self {
+        assert!(!layers.is_empty(), "a circuit must contain at least one layer");
+        assert!(
+            num_inputs >= 2 && num_inputs.is_power_of_two(),
+            "number of circuit inputs must be a power of two greater than 1, but was {num_inputs}"
+        );
+
+        let mut prev_layer_size = num_inputs;
+        for (layer_idx, layer) in layers.iter().enumerate() {
+            assert!(
+                layer.len() >= 2 && layer.len().is_power_of_two(),
+                "number of gates in layer {} must be a power of two greater than 1, but was {}",
+                layer_idx,
+                layer.len()
+            );
+            for (gate_idx, gate) in layer.iter().enumerate() {
+                let (left, right) = gate.inputs();
+                assert!(
+                    left < prev_layer_size && right < prev_layer_size,
+                    "gate {gate_idx} in layer {layer_idx} reads a value outside of the previous layer"
+                );
+            }
+            prev_layer_size = layer.len();
+        }
+
+        Self { num_inputs, layers, _field: PhantomData }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of inputs of this circuit.
+    pub fn num_inputs(&self) -> usize {
+        self.num_inputs
+    }
+
+    /// Returns the number of outputs of this circuit.
+    pub fn num_outputs(&self) -> usize {
+        self.layers[self.layers.len() - 1].len()
+    }
+
+    /// Returns the number of layers in this circuit.
+    pub fn num_layers(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns gates of the layer at the specified index; layer 0 is the layer which reads
+    /// circuit inputs.
+    ///
+    /// # Panics
+    /// Panics if `layer_idx` is greater than or equal to the number of layers in this circuit.
+    pub fn layer(&self, layer_idx: usize) -> &[Gate] {
+        &self.layers[layer_idx]
+    }
+
+    // EVALUATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Evaluates this circuit on the specified inputs and returns the values of all layers,
+    /// starting with the inputs and ending with the outputs of the circuit.
+    ///
+    /// # Panics
+    /// Panics if the number of `inputs` is not equal to the number of inputs of this circuit.
+    pub fn evaluate(&self, inputs: &[F]) -> Vec<Vec<F>> {
+        assert_eq!(
+            self.num_inputs,
+            inputs.len(),
+            "expected {} circuit inputs, but received {}",
+            self.num_inputs,
+            inputs.len()
+        );
+
+        let mut values = Vec::with_capacity(self.layers.len() + 1);
+        values.push(inputs.to_vec());
+        for layer in self.layers.iter() {
+            let prev = &values[values.len() - 1];
+            let next = layer
+                .iter()
+                .map(|gate| match *gate {
+                    Gate::Add(left, right) => prev[left] + prev[right],
+                    Gate::Mul(left, right) => prev[left] * prev[right],
+                })
+                .collect();
+            values.push(next);
+        }
+        values
+    }
+
+    /// Evaluates this circuit on the specified inputs and returns the outputs of the circuit.
+    ///
+    /// # Panics
+    /// Panics if the number of `inputs` is not equal to the number of inputs of this circuit.
+    pub fn evaluate_outputs(&self, inputs: &[F]) -> Vec<F> {
+        self.evaluate(inputs).pop().expect("circuit has at least one layer")
+    }
+
+    // WIRING PREDICATES
+    // --------------------------------------------------------------------------------------------
+
+    /// Evaluates the multilinear extensions of the `add` and `mul` wiring predicates of the
+    /// specified layer, with the output variables fixed to a linear combination of points.
+    ///
+    /// Specifically, for every gate g, `gate_weights[g]` is expected to contain the combination
+    /// of eq(p, g) over the points p at which the layer values are claimed, while `left_eq` and
+    /// `right_eq` contain eq(b, x) and eq(c, x) for all positions x of the previous layer. The
+    /// returned tuple contains sum(weight(g) * eq(b, left(g)) * eq(c, right(g))) taken over add
+    /// and mul gates respectively.
+    pub(crate) fn eval_wiring(
+        &self,
+        layer_idx: usize,
+        gate_weights: &[F],
+        left_eq: &[F],
+        right_eq: &[F],
+    ) -> (F, F) {
+        let mut add = F::ZERO;
+        let mut mul = F::ZERO;
+        for (gate, &weight) in self.layers[layer_idx].iter().zip(gate_weights) {
+            match *gate {
+                Gate::Add(left, right) => add += weight * left_eq[left] * right_eq[right],
+                Gate::Mul(left, right) => mul += weight * left_eq[left] * right_eq[right],
+            }
+        }
+        (add, mul)
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::fmt;
+
+use crypto::RandomCoinError;
+
+// PROVER ERROR
+// ================================================================================================
+
+/// Defines errors which can occur during GKR proof generation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProverError {
+    /// Number of inputs provided to the prover is not equal to the number of circuit inputs.
+    InvalidNumInputs { expected: usize, actual: usize },
+    /// Attempt to draw a random value from a public coin failed.
+    RandomCoinError(RandomCoinError),
+    /// Sumcheck proof for the specified layer could not be generated.
+    SumcheckProofFailed(usize, sumcheck::ProverError),
+}
+
+impl fmt::Display for ProverError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumInputs { expected, actual } => {
+                write!(f, "expected {expected} circuit inputs, but received {actual}")
+            }
+            Self::RandomCoinError(err) => {
+                write!(f, "failed to draw a random value from the public coin: {err}")
+            }
+            Self::SumcheckProofFailed(layer, err) => {
+                write!(f, "failed to generate sumcheck proof for layer {layer}: {err}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ProverError {}
+
+// VERIFIER ERROR
+// ================================================================================================
+
+/// Defines errors which can occur during GKR proof verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifierError {
+    /// Number of claimed outputs is not equal to the number of circuit outputs.
+    InvalidNumOutputs { expected: usize, actual: usize },
+    /// Number of layer proofs is not equal to the number of circuit layers.
+    InvalidNumLayers { expected: usize, actual: usize },
+    /// Attempt to draw a random value from a public coin failed.
+    RandomCoinError(RandomCoinError),
+    /// Sumcheck proof for the specified layer is not valid.
+    SumcheckVerificationFailed(usize, sumcheck::VerifierError),
+    /// Evaluations of the previous layer provided in the proof of the specified layer are not
+    /// consistent with the claim to which the sumcheck protocol reduced the claim about the layer.
+    LayerEvaluationMismatch(usize),
+    /// Polynomial describing circuit inputs over a line has a degree greater than the number of
+    /// variables of the inputs.
+    InputLinePolyDegreeTooHigh {
+        max_degree: usize,
+        num_coefficients: usize,
+    },
+    /// Polynomial describing circuit inputs over a line is not consistent with the claims about
+    /// circuit inputs made in the proof of the first layer.
+    InputLinePolyMismatch,
+}
+
+impl fmt::Display for VerifierError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumOutputs { expected, actual } => {
+                write!(f, "expected {expected} circuit outputs, but received {actual}")
+            }
+            Self::InvalidNumLayers { expected, actual } => {
+                write!(f, "expected GKR proof with {expected} layers, but was {actual}")
+            }
+            Self::RandomCoinError(err) => {
+                write!(f, "failed to draw a random value from the public coin: {err}")
+            }
+            Self::SumcheckVerificationFailed(layer, err) => {
+                write!(f, "sumcheck proof for layer {layer} is not valid: {err}")
+            }
+            Self::LayerEvaluationMismatch(layer) => {
+                write!(f, "evaluations of the inputs of layer {layer} are inconsistent with the sumcheck claim")
+            }
+            Self::InputLinePolyDegreeTooHigh { max_degree, num_coefficients } => write!(f,
+                "input line polynomial must have at most {} coefficients, but had {num_coefficients}", max_degree + 1
+            ),
+            Self::InputLinePolyMismatch => {
+                write!(f, "input line polynomial is inconsistent with the claims about circuit inputs")
+            }
+        }
+    }
+}
+
+impl core::error::Error for VerifierError {}
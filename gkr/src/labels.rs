@@ -0,0 +1,18 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Domain separation labels for data absorbed into the Fiat-Shamir transcript of a GKR proof.
+//!
+//! Sumcheck proofs for individual circuit layers absorb their data using the labels of the
+//! sumcheck crate.
+
+/// Label for the hash of the circuit outputs.
+pub(crate) const OUTPUTS: &[u8] = b"winterfell:gkr-outputs:v1";
+
+/// Label for the hash of the claimed values at the two points to which a layer is reduced.
+pub(crate) const LAYER_CLAIMS: &[u8] = b"winterfell:gkr-layer-claims:v1";
+
+/// Label for the hash of the restriction of circuit inputs to a line.
+pub(crate) const INPUT_LINE_POLY: &[u8] = b"winterfell:gkr-input-line-poly:v1";
@@ -0,0 +1,68 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! This crate contains an implementation of the GKR protocol for layered arithmetic circuits,
+//! compiled into a non-interactive protocol via the Fiat-Shamir transform.
+//!
+//! GKR enables a verifier to check evaluation of a layered circuit of depth *d* and width *n*
+//! with work proportional to *d · log(n)* rounds of interaction, which is much cheaper than
+//! encoding every gate of the circuit as a constraint of an AIR. The protocol proceeds from the
+//! output layer towards the inputs: a claim about the multilinear extension of the values of a
+//! layer is reduced, via the sumcheck protocol over the wiring of the layer, to claims about the
+//! multilinear extension of the values of the previous layer.
+//!
+//! # Circuits
+//! Circuits are described by a [LayeredCircuit] which consists of layers of fan-in 2 [Gate]s.
+//! Gates of the first layer read circuit inputs, and gates of every other layer read outputs of
+//! the previous layer. The number of inputs and the number of gates in every layer must be
+//! powers of two.
+//!
+//! # Proof generation
+//! GKR proofs are generated by a [GkrProver] via [prove()](GkrProver::prove()) function, which
+//! evaluates the circuit on the provided inputs and returns a [GkrProof].
+//!
+//! # Proof verification
+//! GKR proofs are verified by a [GkrVerifier] via [verify()](GkrVerifier::verify()) function
+//! against the claimed circuit outputs. The verification reduces the correctness of the outputs
+//! to an [InputClaim] - a claim about the evaluation of the multilinear extension of circuit
+//! inputs at a single random point. The proof is valid only if this claim holds.
+//!
+//! Checking the input claim is out of scope of this crate, and it is the responsibility of the
+//! caller. When the inputs are known to the verifier, the claim can be checked directly via
+//! [InputClaim::is_satisfied_by()]. When the inputs are committed to, e.g. as a column of a STARK
+//! execution trace, the claim must be proven as an opening of the commitment (for example, using
+//! a Lagrange kernel column and FRI); this crate does not provide such an opening, and it is not
+//! integrated with the STARK prover and verifier.
+//!
+//! # References
+//! * Goldwasser, Kalai, Rothblum: [Delegating Computation: Interactive Proofs for Muggles](https://www.microsoft.com/en-us/research/wp-content/uploads/2016/12/2008-DelegatingComputation.pdf)
+//! * Justin Thaler's [Proofs, Arguments, and Zero-Knowledge](https://people.cs.georgetown.edu/jthaler/ProofsArgsAndZK.pdf),
+//!   section 4.6
+
+#![no_std]
+
+extern crate alloc;
+
+mod labels;
+
+mod circuit;
+pub use circuit::{Gate, LayeredCircuit};
+
+mod prover;
+pub use prover::GkrProver;
+
+mod verifier;
+pub use verifier::{GkrVerifier, InputClaim};
+
+mod proof;
+pub use proof::{GkrProof, LayerProof};
+
+mod errors;
+pub use errors::{ProverError, VerifierError};
+
+mod utils;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,124 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::FieldElement;
+use sumcheck::SumcheckProof;
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// GKR PROOF
+// ================================================================================================
+
+/// A proof generated by the GKR prover.
+///
+/// The proof contains one [LayerProof] per layer of the circuit, ordered from the output layer
+/// to the layer which reads circuit inputs, and a univariate polynomial which reduces the two
+/// claims about the circuit inputs left after the last layer to a single claim.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GkrProof<E: FieldElement> {
+    layer_proofs: Vec<LayerProof<E>>,
+    input_line_poly: Vec<E>,
+}
+
+impl<E: FieldElement> GkrProof<E> {
+    /// Returns a new [GkrProof] instantiated from the provided layer proofs and the coefficients
+    /// of the polynomial describing circuit inputs over a line.
+    pub fn new(layer_proofs: Vec<LayerProof<E>>, input_line_poly: Vec<E>) -> Self {
+        Self { layer_proofs, input_line_poly }
+    }
+
+    /// Returns proofs of all layers ordered from the output layer to the input layer.
+    pub fn layer_proofs(&self) -> &[LayerProof<E>] {
+        &self.layer_proofs
+    }
+
+    /// Returns the coefficients of the polynomial obtained by restricting the multilinear
+    /// extension of circuit inputs to a line through the two points at which the last layer
+    /// proof makes claims about the inputs.
+    pub fn input_line_poly(&self) -> &[E] {
+        &self.input_line_poly
+    }
+}
+
+impl<E: FieldElement> Serializable for GkrProof<E> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.layer_proofs.len());
+        for layer_proof in self.layer_proofs.iter() {
+            layer_proof.write_into(target);
+        }
+        self.input_line_poly.write_into(target);
+    }
+}
+
+impl<E: FieldElement> Deserializable for GkrProof<E> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_layers = source.read_usize()?;
+        let mut layer_proofs = Vec::new();
+        for _ in 0..num_layers {
+            layer_proofs.push(LayerProof::read_from(source)?);
+        }
+        let input_line_poly = Vec::<E>::read_from(source)?;
+        Ok(Self { layer_proofs, input_line_poly })
+    }
+}
+
+// LAYER PROOF
+// ================================================================================================
+
+/// A proof which reduces a claim about the values of a single layer of a circuit to claims
+/// about the values of the previous layer.
+///
+/// The proof consists of a sumcheck proof over the wiring of the layer, and the evaluations of
+/// the multilinear extension of the previous layer at the two points to which the sumcheck
+/// protocol reduces the claim.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerProof<E: FieldElement> {
+    sumcheck_proof: SumcheckProof<E>,
+    left_value: E,
+    right_value: E,
+}
+
+impl<E: FieldElement> LayerProof<E> {
+    /// Returns a new [LayerProof] instantiated from the provided sumcheck proof and evaluations
+    /// of the previous layer.
+    pub fn new(sumcheck_proof: SumcheckProof<E>, left_value: E, right_value: E) -> Self {
+        Self { sumcheck_proof, left_value, right_value }
+    }
+
+    /// Returns the sumcheck proof of this layer.
+    pub fn sumcheck_proof(&self) -> &SumcheckProof<E> {
+        &self.sumcheck_proof
+    }
+
+    /// Returns the evaluation of the previous layer at the point formed by the first half of
+    /// the sumcheck point.
+    pub fn left_value(&self) -> E {
+        self.left_value
+    }
+
+    /// Returns the evaluation of the previous layer at the point formed by the second half of
+    /// the sumcheck point.
+    pub fn right_value(&self) -> E {
+        self.right_value
+    }
+}
+
+impl<E: FieldElement> Serializable for LayerProof<E> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.sumcheck_proof.write_into(target);
+        self.left_value.write_into(target);
+        self.right_value.write_into(target);
+    }
+}
+
+impl<E: FieldElement> Deserializable for LayerProof<E> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let sumcheck_proof = SumcheckProof::read_from(source)?;
+        let left_value = E::read_from(source)?;
+        let right_value = E::read_from(source)?;
+        Ok(Self { sumcheck_proof, left_value, right_value })
+    }
+}
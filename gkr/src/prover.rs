@@ -0,0 +1,144 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use crypto::{ElementHasher, RandomCoin};
+use math::{polynom, FieldElement};
+use sumcheck::SumcheckProver;
+
+use crate::{
+    labels,
+    utils::{
+        combine_eq_tables, draw_point, eq_table, eval_multilinear, eval_with_eq_table, line_point,
+    },
+    GkrProof, LayerProof, LayeredCircuit, ProverError,
+};
+
+// GKR PROVER
+// ================================================================================================
+
+/// Implements the prover component of the GKR protocol.
+///
+/// The prover evaluates the circuit and then, starting with a claim about the multilinear
+/// extension of circuit outputs at a random point, reduces a claim about the values of every
+/// layer to a claim about the values of the previous layer via the sumcheck protocol. The claims
+/// at the two points left after each reduction are combined into one using a random linear
+/// combination. The two claims about circuit inputs left after the last reduction are reduced to
+/// a single claim by restricting the multilinear extension of the inputs to a line through the
+/// two points.
+///
+/// Sumcheck over a layer with `2^k` inputs runs over `2k` variables, and the prover evaluates
+/// the summed polynomial directly at every point of the hypercube. Thus, the prover is practical
+/// for circuits with narrow layers (but arbitrary depth).
+pub struct GkrProver<F: FieldElement> {
+    circuit: LayeredCircuit<F>,
+}
+
+impl<F: FieldElement> GkrProver<F> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new GKR prover for the specified circuit.
+    pub fn new(circuit: LayeredCircuit<F>) -> Self {
+        Self { circuit }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the circuit for which this prover generates proofs.
+    pub fn circuit(&self) -> &LayeredCircuit<F> {
+        &self.circuit
+    }
+
+    // PROVING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a proof of correct evaluation of the circuit on the specified `inputs`.
+    ///
+    /// The public coin is reseeded with the circuit outputs before any random values are drawn
+    /// from it. The verifier must be invoked with the same outputs (which can be computed via
+    /// [LayeredCircuit::evaluate_outputs()]) and a public coin in the same initial state.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The number of `inputs` is not equal to the number of circuit inputs.
+    /// * A random value could not be drawn from the public coin.
+    pub fn prove<R>(&self, inputs: &[F], public_coin: &mut R) -> Result<GkrProof<F>, ProverError>
+    where
+        R: RandomCoin<BaseField = F::BaseField>,
+    {
+        if inputs.len() != self.circuit.num_inputs() {
+            return Err(ProverError::InvalidNumInputs {
+                expected: self.circuit.num_inputs(),
+                actual: inputs.len(),
+            });
+        }
+
+        let values = self.circuit.evaluate(inputs);
+        let outputs = &values[values.len() - 1];
+
+        // draw a random point at which the claim about circuit outputs is made
+        public_coin.reseed_with_label(labels::OUTPUTS, R::Hasher::hash_elements(outputs));
+        let output_point = draw_point(public_coin, outputs.len().ilog2() as usize)
+            .map_err(ProverError::RandomCoinError)?;
+        let mut claim = eval_multilinear(outputs, &output_point);
+        let mut gate_weights = eq_table(&output_point);
+
+        let mut layer_proofs = Vec::with_capacity(self.circuit.num_layers());
+        let mut last_points = (Vec::new(), Vec::new());
+        for layer_idx in (0..self.circuit.num_layers()).rev() {
+            let prev_values = &values[layer_idx];
+            let num_vars = prev_values.len().ilog2() as usize;
+
+            let layer_poly = |point: &[F]| {
+                let (left, right) = point.split_at(num_vars);
+                let left_eq = eq_table(left);
+                let right_eq = eq_table(right);
+                let (add, mul) =
+                    self.circuit.eval_wiring(layer_idx, &gate_weights, &left_eq, &right_eq);
+                let left_value = eval_with_eq_table(prev_values, &left_eq);
+                let right_value = eval_with_eq_table(prev_values, &right_eq);
+                add * (left_value + right_value) + mul * left_value * right_value
+            };
+
+            let (sumcheck_proof, sumcheck_claim) = SumcheckProver::new(2 * num_vars, 2)
+                .prove_and_reduce(layer_poly, claim, public_coin)
+                .map_err(|err| ProverError::SumcheckProofFailed(layer_idx, err))?;
+
+            let mut left_point = sumcheck_claim.point;
+            let right_point = left_point.split_off(num_vars);
+            let left_value = eval_multilinear(prev_values, &left_point);
+            let right_value = eval_multilinear(prev_values, &right_point);
+            layer_proofs.push(LayerProof::new(sumcheck_proof, left_value, right_value));
+
+            // combine the claims at the two points into a single claim for the next layer
+            public_coin.reseed_with_label(
+                labels::LAYER_CLAIMS,
+                R::Hasher::hash_elements(&[left_value, right_value]),
+            );
+            let beta: F = public_coin.draw().map_err(ProverError::RandomCoinError)?;
+            claim = left_value + beta * right_value;
+            gate_weights = combine_eq_tables(&left_point, &right_point, beta);
+            last_points = (left_point, right_point);
+        }
+
+        // restrict the multilinear extension of circuit inputs to the line through the last two
+        // points; evaluations at 0 are skipped because interpolation does not support them
+        let (left_point, right_point) = last_points;
+        let degree = left_point.len();
+        let xs = (1..=degree as u32 + 1).map(F::from).collect::<Vec<_>>();
+        let ys = xs
+            .iter()
+            .map(|&t| eval_multilinear(inputs, &line_point(&left_point, &right_point, t)))
+            .collect::<Vec<_>>();
+        let input_line_poly = polynom::interpolate(&xs, &ys, false);
+        public_coin
+            .reseed_with_label(labels::INPUT_LINE_POLY, R::Hasher::hash_elements(&input_line_poly));
+
+        Ok(GkrProof::new(layer_proofs, input_line_poly))
+    }
+}
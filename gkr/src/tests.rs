@@ -0,0 +1,201 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{vec, vec::Vec};
+
+use crypto::{hashers::Blake3_256, DefaultRandomCoin, RandomCoin};
+use math::{
+    fields::{f128::BaseElement, f64, QuadExtension},
+    FieldElement, StarkField,
+};
+use utils::{Deserializable, Serializable};
+
+use super::{
+    utils::eq_table, Gate, GkrProof, GkrProver, GkrVerifier, LayerProof, LayeredCircuit,
+    ProverError, VerifierError,
+};
+
+const NUM_LAYERS: usize = 20;
+
+// FIBONACCI CIRCUIT
+// ================================================================================================
+
+/// Returns a circuit which computes `num_layers` steps of the Fibonacci sequence.
+///
+/// Every layer has 4 values: (a, b, 0, a * b) which are mapped to (b, a + b, 0, a * b) in the
+/// next layer. The zero value is used to copy b via an addition gate, and the product is not
+/// used by the next layer but ensures that multiplication gates are covered.
+fn fibonacci_circuit<F: FieldElement>(num_layers: usize) -> LayeredCircuit<F> {
+    let layer = vec![Gate::Add(1, 2), Gate::Add(0, 1), Gate::Add(2, 2), Gate::Mul(0, 1)];
+    LayeredCircuit::new(4, vec![layer; num_layers])
+}
+
+fn fibonacci_inputs<F: FieldElement>() -> Vec<F> {
+    vec![F::ONE, F::ONE, F::ZERO, F::ZERO]
+}
+
+fn new_coin<B: StarkField>() -> DefaultRandomCoin<Blake3_256<B>> {
+    DefaultRandomCoin::new(&[B::from(42u32)])
+}
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn fibonacci_circuit_evaluation() {
+    let circuit = fibonacci_circuit::<BaseElement>(NUM_LAYERS);
+    let outputs = circuit.evaluate_outputs(&fibonacci_inputs());
+
+    // starting with F_1 = F_2 = 1, after 20 steps the first two values are F_21 and F_22
+    assert_eq!(BaseElement::new(10946), outputs[0]);
+    assert_eq!(BaseElement::new(17711), outputs[1]);
+    assert_eq!(BaseElement::ZERO, outputs[2]);
+    assert_eq!(BaseElement::new(6765 * 10946), outputs[3]);
+}
+
+#[test]
+fn gkr_fibonacci() {
+    let circuit = fibonacci_circuit::<BaseElement>(NUM_LAYERS);
+    let inputs = fibonacci_inputs();
+    let outputs = circuit.evaluate_outputs(&inputs);
+
+    let prover = GkrProver::new(circuit.clone());
+    let proof = prover.prove(&inputs, &mut new_coin()).unwrap();
+    assert_eq!(NUM_LAYERS, proof.layer_proofs().len());
+
+    let verifier = GkrVerifier::new(circuit);
+    let claim = verifier.verify(&outputs, &proof, &mut new_coin()).unwrap();
+    assert!(claim.is_satisfied_by(&inputs));
+
+    // the claim does not hold for different inputs
+    let mut other_inputs = inputs.clone();
+    other_inputs[0] = BaseElement::new(2);
+    assert!(!claim.is_satisfied_by(&other_inputs));
+
+    // the claim holds if the inner product of the Lagrange kernel column and inputs is equal to
+    // the claimed value
+    let lagrange_col = eq_table(&claim.to_lagrange_kernel_rand_elements());
+    let inner_product = lagrange_col
+        .iter()
+        .zip(inputs.iter())
+        .fold(BaseElement::ZERO, |acc, (&l, &v)| acc + l * v);
+    assert_eq!(claim.value, inner_product);
+
+    // the proof can be serialized and deserialized
+    let bytes = proof.to_bytes();
+    assert_eq!(proof, GkrProof::read_from_bytes(&bytes).unwrap());
+}
+
+#[test]
+fn gkr_fibonacci_extension_field() {
+    type QuadElement = QuadExtension<f64::BaseElement>;
+
+    let circuit = fibonacci_circuit::<QuadElement>(NUM_LAYERS);
+    let inputs = fibonacci_inputs();
+    let outputs = circuit.evaluate_outputs(&inputs);
+
+    let proof = GkrProver::new(circuit.clone()).prove(&inputs, &mut new_coin()).unwrap();
+    let claim = GkrVerifier::new(circuit).verify(&outputs, &proof, &mut new_coin()).unwrap();
+    assert!(claim.is_satisfied_by(&inputs));
+}
+
+#[test]
+fn gkr_invalid_outputs() {
+    let circuit = fibonacci_circuit::<BaseElement>(NUM_LAYERS);
+    let inputs = fibonacci_inputs();
+    let mut outputs = circuit.evaluate_outputs(&inputs);
+    let proof = GkrProver::new(circuit.clone()).prove(&inputs, &mut new_coin()).unwrap();
+    let verifier = GkrVerifier::new(circuit);
+
+    // wrong outputs change both the public coin and the initial claim, and so the proof fails
+    // to verify at the first layer
+    outputs[1] += BaseElement::ONE;
+    let result = verifier.verify(&outputs, &proof, &mut new_coin());
+    assert!(matches!(result, Err(VerifierError::SumcheckVerificationFailed(19, _))));
+
+    assert_eq!(
+        Err(VerifierError::InvalidNumOutputs { expected: 4, actual: 3 }),
+        verifier.verify(&outputs[..3], &proof, &mut new_coin())
+    );
+}
+
+#[test]
+fn gkr_invalid_proofs() {
+    let circuit = fibonacci_circuit::<BaseElement>(NUM_LAYERS);
+    let inputs = fibonacci_inputs();
+    let outputs = circuit.evaluate_outputs(&inputs);
+    let proof = GkrProver::new(circuit.clone()).prove(&inputs, &mut new_coin()).unwrap();
+    let verifier = GkrVerifier::new(circuit);
+
+    // a proof must contain one layer proof per circuit layer
+    let mut layer_proofs = proof.layer_proofs().to_vec();
+    layer_proofs.pop();
+    let truncated = GkrProof::new(layer_proofs, proof.input_line_poly().to_vec());
+    assert_eq!(
+        Err(VerifierError::InvalidNumLayers {
+            expected: NUM_LAYERS,
+            actual: NUM_LAYERS - 1
+        }),
+        verifier.verify(&outputs, &truncated, &mut new_coin())
+    );
+
+    // tampering with the evaluations of the previous layer is detected at the same layer
+    let mut layer_proofs = proof.layer_proofs().to_vec();
+    let layer_proof = &layer_proofs[5];
+    layer_proofs[5] = LayerProof::new(
+        layer_proof.sumcheck_proof().clone(),
+        layer_proof.left_value() + BaseElement::ONE,
+        layer_proof.right_value(),
+    );
+    let tampered = GkrProof::new(layer_proofs, proof.input_line_poly().to_vec());
+    assert_eq!(
+        Err(VerifierError::LayerEvaluationMismatch(NUM_LAYERS - 6)),
+        verifier.verify(&outputs, &tampered, &mut new_coin())
+    );
+
+    // the input line polynomial must pass through the claims about circuit inputs
+    let mut line_poly = proof.input_line_poly().to_vec();
+    line_poly[0] += BaseElement::ONE;
+    let tampered = GkrProof::new(proof.layer_proofs().to_vec(), line_poly);
+    assert_eq!(
+        Err(VerifierError::InputLinePolyMismatch),
+        verifier.verify(&outputs, &tampered, &mut new_coin())
+    );
+
+    let mut line_poly = proof.input_line_poly().to_vec();
+    line_poly.push(BaseElement::ZERO);
+    let tampered = GkrProof::new(proof.layer_proofs().to_vec(), line_poly);
+    assert_eq!(
+        Err(VerifierError::InputLinePolyDegreeTooHigh { max_degree: 2, num_coefficients: 4 }),
+        verifier.verify(&outputs, &tampered, &mut new_coin())
+    );
+
+    // a proof generated for different inputs does not satisfy the claim about the actual inputs
+    let other_inputs =
+        vec![BaseElement::ONE, BaseElement::ONE, BaseElement::ZERO, BaseElement::ONE];
+    let other_outputs = verifier.circuit().evaluate_outputs(&other_inputs);
+    assert_eq!(outputs, other_outputs);
+    let other_proof = GkrProver::new(verifier.circuit().clone())
+        .prove(&other_inputs, &mut new_coin())
+        .unwrap();
+    let claim = verifier.verify(&outputs, &other_proof, &mut new_coin()).unwrap();
+    assert!(!claim.is_satisfied_by(&inputs));
+    assert!(claim.is_satisfied_by(&other_inputs));
+}
+
+#[test]
+fn gkr_invalid_num_inputs() {
+    let prover = GkrProver::new(fibonacci_circuit::<BaseElement>(NUM_LAYERS));
+    assert_eq!(
+        Err(ProverError::InvalidNumInputs { expected: 4, actual: 2 }),
+        prover.prove(&[BaseElement::ONE, BaseElement::ONE], &mut new_coin())
+    );
+}
+
+#[test]
+#[should_panic(expected = "gate 1 in layer 0 reads a value outside of the previous layer")]
+fn invalid_circuit_wiring() {
+    LayeredCircuit::<BaseElement>::new(2, vec![vec![Gate::Add(0, 1), Gate::Mul(1, 2)]]);
+}
@@ -0,0 +1,69 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use crypto::{RandomCoin, RandomCoinError};
+use math::FieldElement;
+
+/// Returns eq(point, x) for all x in the boolean hypercube of dimension `point.len()`, where
+/// bit i of x corresponds to `point[i]`.
+///
+/// This is the same ordering as used by the Lagrange kernel column of the STARK protocol.
+pub(crate) fn eq_table<E: FieldElement>(point: &[E]) -> Vec<E> {
+    let mut table = Vec::with_capacity(1 << point.len());
+    table.push(E::ONE);
+    for (i, &r) in point.iter().enumerate() {
+        let half = 1 << i;
+        for x in 0..half {
+            let value = table[x];
+            table.push(value * r);
+            table[x] = value - table[x + half];
+        }
+    }
+    table
+}
+
+/// Returns the evaluation of the multilinear extension of `values` at `point` given the table
+/// of eq(point, x) values.
+pub(crate) fn eval_with_eq_table<E: FieldElement>(values: &[E], eq: &[E]) -> E {
+    values.iter().zip(eq).fold(E::ZERO, |acc, (&v, &e)| acc + v * e)
+}
+
+/// Returns the evaluation of the multilinear extension of `values` at `point`.
+pub(crate) fn eval_multilinear<E: FieldElement>(values: &[E], point: &[E]) -> E {
+    eval_with_eq_table(values, &eq_table(point))
+}
+
+/// Returns eq(left_point, x) + beta * eq(right_point, x) for all x in the boolean hypercube.
+pub(crate) fn combine_eq_tables<E: FieldElement>(
+    left_point: &[E],
+    right_point: &[E],
+    beta: E,
+) -> Vec<E> {
+    let right_eq = eq_table(right_point);
+    let mut result = eq_table(left_point);
+    for (value, &right) in result.iter_mut().zip(right_eq.iter()) {
+        *value += beta * right;
+    }
+    result
+}
+
+/// Returns the point left_point + t * (right_point - left_point).
+pub(crate) fn line_point<E: FieldElement>(left_point: &[E], right_point: &[E], t: E) -> Vec<E> {
+    left_point.iter().zip(right_point).map(|(&l, &r)| l + t * (r - l)).collect()
+}
+
+/// Draws `num_vars` random field elements from the public coin.
+pub(crate) fn draw_point<E, R>(
+    public_coin: &mut R,
+    num_vars: usize,
+) -> Result<Vec<E>, RandomCoinError>
+where
+    E: FieldElement,
+    R: RandomCoin<BaseField = E::BaseField>,
+{
+    (0..num_vars).map(|_| public_coin.draw()).collect()
+}
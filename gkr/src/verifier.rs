@@ -0,0 +1,191 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::LagrangeKernelRandElements;
+use crypto::{ElementHasher, RandomCoin};
+use math::{polynom, FieldElement};
+use sumcheck::SumcheckVerifier;
+
+use crate::{
+    labels,
+    utils::{combine_eq_tables, draw_point, eq_table, eval_multilinear, line_point},
+    GkrProof, LayeredCircuit, VerifierError,
+};
+
+// GKR VERIFIER
+// ================================================================================================
+
+/// Implements the verifier component of the GKR protocol.
+///
+/// Given claimed circuit outputs, the verifier checks the proofs of all layers and reduces the
+/// claim that the outputs were computed correctly to an [InputClaim], i.e., a claim about the
+/// evaluation of the multilinear extension of circuit inputs at a single random point. The proof
+/// is valid only if the input claim holds. Checking the input claim is the responsibility of the
+/// caller: it can be checked directly against known inputs, but opening it against committed
+/// inputs (e.g., via a Lagrange kernel column of a STARK execution trace) is not provided by this
+/// crate.
+///
+/// Checking a layer requires evaluating the wiring predicates of the layer, which the verifier
+/// does in time linear in the number of gates and inputs of the layer.
+pub struct GkrVerifier<F: FieldElement> {
+    circuit: LayeredCircuit<F>,
+}
+
+impl<F: FieldElement> GkrVerifier<F> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new GKR verifier for the specified circuit.
+    pub fn new(circuit: LayeredCircuit<F>) -> Self {
+        Self { circuit }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the circuit for which this verifier checks proofs.
+    pub fn circuit(&self) -> &LayeredCircuit<F> {
+        &self.circuit
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that `proof` attests to the circuit evaluating to `outputs`, and returns the
+    /// claim about circuit inputs to which the proof reduces.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The number of `outputs` is not equal to the number of circuit outputs.
+    /// * The number of layer proofs is not equal to the number of circuit layers.
+    /// * Proof of any of the layers is not valid.
+    /// * The input line polynomial is not consistent with the proof of the first circuit layer.
+    /// * A random value could not be drawn from the public coin.
+    pub fn verify<R>(
+        &self,
+        outputs: &[F],
+        proof: &GkrProof<F>,
+        public_coin: &mut R,
+    ) -> Result<InputClaim<F>, VerifierError>
+    where
+        R: RandomCoin<BaseField = F::BaseField>,
+    {
+        if outputs.len() != self.circuit.num_outputs() {
+            return Err(VerifierError::InvalidNumOutputs {
+                expected: self.circuit.num_outputs(),
+                actual: outputs.len(),
+            });
+        }
+        if proof.layer_proofs().len() != self.circuit.num_layers() {
+            return Err(VerifierError::InvalidNumLayers {
+                expected: self.circuit.num_layers(),
+                actual: proof.layer_proofs().len(),
+            });
+        }
+
+        public_coin.reseed_with_label(labels::OUTPUTS, R::Hasher::hash_elements(outputs));
+        let output_point = draw_point(public_coin, outputs.len().ilog2() as usize)
+            .map_err(VerifierError::RandomCoinError)?;
+        let mut claim = eval_multilinear(outputs, &output_point);
+        let mut gate_weights = eq_table(&output_point);
+
+        let layer_indexes = (0..self.circuit.num_layers()).rev();
+        let mut last_claims = (Vec::new(), F::ZERO, Vec::new(), F::ZERO);
+        for (layer_idx, layer_proof) in layer_indexes.zip(proof.layer_proofs()) {
+            let num_vars = if layer_idx == 0 {
+                self.circuit.num_inputs().ilog2() as usize
+            } else {
+                self.circuit.layer(layer_idx - 1).len().ilog2() as usize
+            };
+
+            let sumcheck_claim = SumcheckVerifier::new(2 * num_vars, 2)
+                .reduce_claim(claim, layer_proof.sumcheck_proof(), public_coin)
+                .map_err(|err| VerifierError::SumcheckVerificationFailed(layer_idx, err))?;
+
+            let mut left_point = sumcheck_claim.point;
+            let right_point = left_point.split_off(num_vars);
+            let left_value = layer_proof.left_value();
+            let right_value = layer_proof.right_value();
+
+            let (add, mul) = self.circuit.eval_wiring(
+                layer_idx,
+                &gate_weights,
+                &eq_table(&left_point),
+                &eq_table(&right_point),
+            );
+            if add * (left_value + right_value) + mul * left_value * right_value
+                != sumcheck_claim.value
+            {
+                return Err(VerifierError::LayerEvaluationMismatch(layer_idx));
+            }
+
+            public_coin.reseed_with_label(
+                labels::LAYER_CLAIMS,
+                R::Hasher::hash_elements(&[left_value, right_value]),
+            );
+            let beta: F = public_coin.draw().map_err(VerifierError::RandomCoinError)?;
+            claim = left_value + beta * right_value;
+            gate_weights = combine_eq_tables(&left_point, &right_point, beta);
+            last_claims = (left_point, left_value, right_point, right_value);
+        }
+
+        // reduce the two claims about circuit inputs to a single claim using the line polynomial
+        let (left_point, left_value, right_point, right_value) = last_claims;
+        let line_poly = proof.input_line_poly();
+        let max_degree = left_point.len();
+        if line_poly.len() > max_degree + 1 {
+            return Err(VerifierError::InputLinePolyDegreeTooHigh {
+                max_degree,
+                num_coefficients: line_poly.len(),
+            });
+        }
+        if polynom::eval(line_poly, F::ZERO) != left_value
+            || polynom::eval(line_poly, F::ONE) != right_value
+        {
+            return Err(VerifierError::InputLinePolyMismatch);
+        }
+
+        public_coin.reseed_with_label(labels::INPUT_LINE_POLY, R::Hasher::hash_elements(line_poly));
+        let t: F = public_coin.draw().map_err(VerifierError::RandomCoinError)?;
+        Ok(InputClaim {
+            point: line_point(&left_point, &right_point, t),
+            value: polynom::eval(line_poly, t),
+        })
+    }
+}
+
+// INPUT CLAIM
+// ================================================================================================
+
+/// A claim that the multilinear extension of circuit inputs evaluates to `value` at `point`.
+///
+/// Bit i of the position of an input corresponds to coordinate i of `point`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputClaim<F: FieldElement> {
+    /// Random point drawn during the protocol; this point has one coordinate per variable of
+    /// the multilinear extension of circuit inputs.
+    pub point: Vec<F>,
+    /// Expected evaluation of the multilinear extension of circuit inputs at `point`.
+    pub value: F,
+}
+
+impl<F: FieldElement> InputClaim<F> {
+    /// Returns true if the multilinear extension of `inputs` evaluates to the value of this
+    /// claim at the point of this claim.
+    pub fn is_satisfied_by(&self, inputs: &[F]) -> bool {
+        inputs.len() == 1 << self.point.len() && eval_multilinear(inputs, &self.point) == self.value
+    }
+
+    /// Returns the point of this claim as random elements for a Lagrange kernel column.
+    ///
+    /// The value of row x of the Lagrange kernel column built from these elements is
+    /// eq(x, point), and so the claim holds if the inner product of this column and the column
+    /// containing circuit inputs is equal to the value of the claim.
+    pub fn to_lagrange_kernel_rand_elements(&self) -> LagrangeKernelRandElements<F> {
+        LagrangeKernelRandElements::new(self.point.clone())
+    }
+}
@@ -15,6 +15,9 @@ use winter_math::{
 
 const SIZES: [usize; 3] = [262_144, 524_288, 1_048_576];
 
+const BATCH_POLY_SIZE: usize = 65_536;
+const BATCH_SIZES: [usize; 3] = [10, 50, 100];
+
 fn fft_evaluate_poly<B, E>(c: &mut Criterion, field_name: &str)
 where
     B: StarkField,
@@ -88,6 +91,40 @@ where
     group.finish();
 }
 
+fn fft_batch_coset_ntt<B, E>(c: &mut Criterion, field_name: &str)
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let mut group = c.benchmark_group(format!("{field_name}/fft_batch_coset_ntt"));
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    let size = BATCH_POLY_SIZE;
+    let twiddles: Vec<B> = fft::get_twiddles(size);
+
+    for &num_polys in BATCH_SIZES.iter() {
+        let polys: Vec<Vec<E>> = (0..num_polys).map(|_| rand_vector(size)).collect();
+        group.bench_function(BenchmarkId::new("batch", num_polys), |bench| {
+            bench.iter_batched_ref(
+                || polys.clone(),
+                |polys| fft::batch_coset_ntt(polys, &twiddles, B::GENERATOR),
+                BatchSize::LargeInput,
+            );
+        });
+        group.bench_function(BenchmarkId::new("independent", num_polys), |bench| {
+            bench.iter_with_large_drop(|| {
+                polys
+                    .iter()
+                    .map(|p| fft::evaluate_poly_with_offset(p, &twiddles, B::GENERATOR, 1))
+                    .collect::<Vec<_>>()
+            });
+        });
+    }
+
+    group.finish();
+}
+
 fn get_twiddles(c: &mut Criterion) {
     let mut group = c.benchmark_group("fft_get_twiddles");
     group.sample_size(10);
@@ -113,6 +150,9 @@ fn bench_fft(c: &mut Criterion) {
     fft_interpolate_poly::<f62::BaseElement, f62::BaseElement>(c, "f62");
     fft_interpolate_poly::<f64::BaseElement, f64::BaseElement>(c, "f64");
     fft_interpolate_poly::<f128::BaseElement, f128::BaseElement>(c, "f128");
+
+    fft_batch_coset_ntt::<f64::BaseElement, f64::BaseElement>(c, "f64");
+    fft_batch_coset_ntt::<f64::BaseElement, QuadExtension<f64::BaseElement>>(c, "f64_quad");
 }
 
 criterion_group!(fft_group, bench_fft, get_twiddles);
@@ -8,7 +8,7 @@ use std::time::Duration;
 use criterion::{
     black_box, criterion_group, criterion_main,
     measurement::{Measurement, WallTime},
-    BatchSize, BenchmarkGroup, BenchmarkId, Criterion,
+    BatchSize, BenchmarkGroup, BenchmarkId, Criterion, Throughput,
 };
 use rand_utils::{rand_array, rand_value, rand_vector};
 use winter_math::{
@@ -253,6 +253,64 @@ pub fn batch_ops<E: FieldElement, M: Measurement>(group: &mut BenchmarkGroup<M>,
     });
 }
 
+// SLICE OPS
+// ================================================================================================
+
+const SLICE_SIZE: usize = 65_536;
+
+/// Compares element-wise operations over slices against batch operations of [FieldElement];
+/// the latter may be vectorized depending on the field and on the instructions supported by
+/// the CPU.
+pub fn slice_ops<E: FieldElement>(c: &mut Criterion, field_name: &str) {
+    let mut group = c.benchmark_group(format!("slice_ops/{field_name}"));
+    group.throughput(Throughput::Elements(SLICE_SIZE as u64));
+
+    let a = rand_vector::<E>(SLICE_SIZE);
+    let b = rand_vector::<E>(SLICE_SIZE);
+    let s = rand_value::<E>();
+
+    group.bench_function("add/element_wise", |bench| {
+        let mut acc = a.clone();
+        bench.iter(|| {
+            for (acc, &b) in acc.iter_mut().zip(b.iter()) {
+                *acc += b;
+            }
+        });
+    });
+    group.bench_function("add/batch", |bench| {
+        let mut acc = a.clone();
+        bench.iter(|| E::batch_add(&mut acc, &b));
+    });
+
+    group.bench_function("mul/element_wise", |bench| {
+        let mut acc = a.clone();
+        bench.iter(|| {
+            for (acc, &b) in acc.iter_mut().zip(b.iter()) {
+                *acc *= b;
+            }
+        });
+    });
+    group.bench_function("mul/batch", |bench| {
+        let mut acc = a.clone();
+        bench.iter(|| E::batch_mul(&mut acc, &b));
+    });
+
+    group.bench_function("mul_acc/element_wise", |bench| {
+        let mut acc = a.clone();
+        bench.iter(|| {
+            for (acc, &b) in acc.iter_mut().zip(b.iter()) {
+                *acc += b * s;
+            }
+        });
+    });
+    group.bench_function("mul_acc/batch", |bench| {
+        let mut acc = a.clone();
+        bench.iter(|| E::batch_mul_acc(&mut acc, &b, s));
+    });
+
+    group.finish();
+}
+
 // GENERIC BENCHMARK RUNNER
 // ================================================================================================
 
@@ -262,8 +320,13 @@ fn bench_field_ops(c: &mut Criterion) {
     field_ops::<f128::BaseElement>(c, "f128");
 }
 
+fn bench_slice_ops(c: &mut Criterion) {
+    slice_ops::<f62::BaseElement>(c, "f62");
+    slice_ops::<f64::BaseElement>(c, "f64");
+}
+
 // CRITERION BOILERPLATE
 // ================================================================================================
 
-criterion_group!(field_group, batch_inv, bench_field_ops);
+criterion_group!(field_group, batch_inv, bench_field_ops, bench_slice_ops);
 criterion_main!(field_group);
@@ -6,8 +6,12 @@
 use std::time::Duration;
 
 use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
-use rand_utils::rand_vector;
-use winter_math::{fft, fields::f128::BaseElement, polynom, FieldElement};
+use rand_utils::{rand_value, rand_vector};
+use winter_math::{
+    fft,
+    fields::{f128::BaseElement, f64, QuadExtension},
+    polynom, FieldElement,
+};
 
 const SIZES: [usize; 3] = [262_144, 524_288, 1_048_576];
 
@@ -39,5 +43,56 @@ fn syn_div(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(polynom_group, syn_div);
+fn eval_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eval_batch");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    // 64 trace polynomials of length 2^20 evaluated at an out-of-domain point
+    let num_polys = 64;
+    let poly_size = 1 << 20;
+    let polys: Vec<Vec<f64::BaseElement>> =
+        (0..num_polys).map(|_| rand_vector(poly_size)).collect();
+    let poly_refs = polys.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    let z: QuadExtension<f64::BaseElement> = rand_value();
+
+    group.bench_function(BenchmarkId::new("horner", poly_size), |bench| {
+        bench.iter(|| poly_refs.iter().map(|p| polynom::eval(p, z)).collect::<Vec<_>>());
+    });
+
+    group.bench_function(BenchmarkId::new("batch", poly_size), |bench| {
+        bench.iter(|| polynom::eval_batch(&poly_refs, z));
+    });
+
+    group.finish();
+}
+
+fn eval_at_many_points(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eval_at_many_points");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    // a trace polynomial of length 2^20 evaluated at a growing number of points, from the size
+    // of an out-of-domain frame to the number of query positions; the subproduct tree overtakes
+    // Horner's method at about 100 points, and below the threshold, both variants use Horner's
+    // method
+    let poly_size = 1 << 20;
+    let p: Vec<f64::BaseElement> = rand_vector(poly_size);
+
+    for num_points in [8, 64, 128, 256, 1024] {
+        let xs: Vec<f64::BaseElement> = rand_vector(num_points);
+
+        group.bench_function(BenchmarkId::new("horner", num_points), |bench| {
+            bench.iter(|| polynom::eval_many(&p, &xs));
+        });
+
+        group.bench_function(BenchmarkId::new("subproduct_tree", num_points), |bench| {
+            bench.iter(|| polynom::eval_at_many_points(&p, &xs));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(polynom_group, syn_div, eval_batch, eval_at_many_points);
 criterion_main!(polynom_group);
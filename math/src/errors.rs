@@ -0,0 +1,33 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::fmt;
+
+// INTERPOLATION ERROR
+// ================================================================================================
+
+/// Defines errors which can occur during polynomial interpolation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterpolationError {
+    /// Number of X coordinates is not the same as the number of Y coordinates.
+    InconsistentCoordinates { num_xs: usize, num_ys: usize },
+    /// X coordinates at the specified positions have the same value.
+    DuplicateXCoordinate { first: usize, second: usize },
+}
+
+impl fmt::Display for InterpolationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InconsistentCoordinates { num_xs, num_ys } => {
+                write!(f, "number of X coordinates ({num_xs}) differs from the number of Y coordinates ({num_ys})")
+            },
+            Self::DuplicateXCoordinate { first, second } => {
+                write!(f, "X coordinates at positions {first} and {second} are the same")
+            },
+        }
+    }
+}
+
+impl core::error::Error for InterpolationError {}
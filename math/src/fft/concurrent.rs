@@ -4,8 +4,9 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
+use core::mem::MaybeUninit;
 
-use utils::{iterators::*, rayon, uninit_vector};
+use utils::{assume_init_slice_mut, assume_init_vector, iterators::*, rayon, uninit_vector};
 
 use super::fft_inputs::FftInputs;
 use crate::field::{FieldElement, StarkField};
@@ -16,10 +17,20 @@ use crate::field::{FieldElement, StarkField};
 /// Evaluates polynomial `p` using FFT algorithm; the evaluation is done in-place, meaning
 /// `p` is updated with results of the evaluation.
 pub fn evaluate_poly<B: StarkField, E: FieldElement<BaseField = B>>(p: &mut [E], twiddles: &[B]) {
-    split_radix_fft(p, twiddles);
+    evaluate_poly_bitrev(p, twiddles);
     permute(p);
 }
 
+/// Evaluates polynomial `p` using FFT algorithm in-place, and leaves the evaluations in
+/// bit-reversed order.
+pub fn evaluate_poly_bitrev<B, E>(p: &mut [E], twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    split_radix_fft(p, twiddles);
+}
+
 /// Evaluates polynomial `p` using FFT algorithm and returns the result. The polynomial is
 /// evaluated over domain specified by `twiddles`, expanded by the `blowup_factor`, and shifted
 /// by the `domain_offset`.
@@ -31,7 +42,7 @@ pub fn evaluate_poly_with_offset<B: StarkField, E: FieldElement<BaseField = B>>(
 ) -> Vec<E> {
     let domain_size = p.len() * blowup_factor;
     let g = B::get_root_of_unity(domain_size.ilog2());
-    let mut result = unsafe { uninit_vector(domain_size) };
+    let mut result = uninit_vector::<E>(domain_size);
 
     result
         .as_mut_slice()
@@ -41,13 +52,52 @@ pub fn evaluate_poly_with_offset<B: StarkField, E: FieldElement<BaseField = B>>(
             let idx = super::permute_index(blowup_factor, i) as u64;
             let offset = g.exp(idx.into()) * domain_offset;
             clone_and_shift(p, chunk, offset);
-            split_radix_fft(chunk, twiddles);
+            // SAFETY: the chunk has the same length as `p`, and thus, all of its elements have
+            // been initialized by clone_and_shift()
+            split_radix_fft(unsafe { assume_init_slice_mut(chunk) }, twiddles);
         });
 
+    // SAFETY: the chunks cover the whole result, and all of them have been initialized above
+    let mut result = unsafe { assume_init_vector(result) };
     permute(&mut result);
     result
 }
 
+// BATCH EVALUATION
+// ================================================================================================
+
+/// Evaluates all polynomials in `polys` over the domain of length `n` shifted by `coset_offset`,
+/// where `n` is the length of the polynomials. The batch is split into one sub-batch per thread,
+/// and each sub-batch is evaluated in lockstep; if there are fewer polynomials than threads, the
+/// polynomials are evaluated one by one using all threads.
+pub fn batch_coset_ntt<B, E>(polys: &mut [Vec<E>], twiddles: &[B], coset_offset: B)
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let num_threads = rayon::current_num_threads();
+    if polys.len() < num_threads {
+        for p in polys.iter_mut() {
+            let batch_size = p.len().div_ceil(num_threads);
+            p.par_chunks_mut(batch_size).enumerate().for_each(|(i, batch)| {
+                let mut offset = coset_offset.exp(((i * batch_size) as u64).into());
+                for coeff in batch.iter_mut() {
+                    *coeff = coeff.mul_base(offset);
+                    offset *= coset_offset;
+                }
+            });
+            split_radix_fft(p, twiddles);
+            permute(p);
+        }
+        return;
+    }
+
+    let batch_size = polys.len().div_ceil(num_threads);
+    polys
+        .par_chunks_mut(batch_size)
+        .for_each(|batch| super::serial::batch_coset_ntt(batch, twiddles, coset_offset));
+}
+
 // POLYNOMIAL INTERPOLATION
 // ================================================================================================
 
@@ -57,6 +107,20 @@ pub fn evaluate_poly_with_offset<B: StarkField, E: FieldElement<BaseField = B>>(
 /// # Panics
 /// Panics if the length of `values` is greater than [u32::MAX].
 pub fn interpolate_poly<B, E>(values: &mut [E], inv_twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    interpolate_poly_bitrev(values, inv_twiddles);
+    permute(values);
+}
+
+/// Uses FFT algorithm to interpolate a polynomial from provided `values` in-place, and leaves
+/// the coefficients in bit-reversed order.
+///
+/// # Panics
+/// Panics if the length of `values` is greater than [u32::MAX].
+pub fn interpolate_poly_bitrev<B, E>(values: &mut [E], inv_twiddles: &[B])
 where
     B: StarkField,
     E: FieldElement<BaseField = B>,
@@ -66,7 +130,6 @@ where
     split_radix_fft(values, inv_twiddles);
     let inv_length = E::inv((values.len() as u32).into());
     values.par_iter_mut().for_each(|e| *e *= inv_length);
-    permute(values);
 }
 
 /// Uses FFT algorithm to interpolate a polynomial from provided `values` over the domain defined
@@ -220,7 +283,11 @@ fn transpose_square_2<T>(matrix: &mut [T], size: usize) {
 // HELPER FUNCTIONS
 // ================================================================================================
 
-fn clone_and_shift<E: FieldElement>(source: &[E], destination: &mut [E], offset: E::BaseField) {
+fn clone_and_shift<E: FieldElement>(
+    source: &[E],
+    destination: &mut [MaybeUninit<E>],
+    offset: E::BaseField,
+) {
     let batch_size = source.len() / rayon::current_num_threads().next_power_of_two();
     source
         .par_chunks(batch_size)
@@ -229,7 +296,7 @@ fn clone_and_shift<E: FieldElement>(source: &[E], destination: &mut [E], offset:
         .for_each(|(i, (source, destination))| {
             let mut factor = offset.exp(((i * batch_size) as u64).into());
             for (s, d) in source.iter().zip(destination.iter_mut()) {
-                *d = (*s).mul_base(factor);
+                d.write((*s).mul_base(factor));
                 factor *= offset;
             }
         });
@@ -25,6 +25,34 @@ pub trait FftInputs<E: FieldElement> {
     /// twiddle factor into a larger NTT.
     fn butterfly_twiddle(&mut self, twiddle: E::BaseField, offset: usize, stride: usize);
 
+    /// Applies [butterfly()](FftInputs::butterfly) to `count` consecutive offsets starting at
+    /// `offset`.
+    ///
+    /// Implementors may override this method to make use of batch field operations; `count` is
+    /// guaranteed to be no greater than `stride`.
+    fn butterfly_batch(&mut self, offset: usize, stride: usize, count: usize) {
+        for offset in offset..(offset + count) {
+            self.butterfly(offset, stride);
+        }
+    }
+
+    /// Applies [butterfly_twiddle()](FftInputs::butterfly_twiddle) to `count` consecutive
+    /// offsets starting at `offset`.
+    ///
+    /// Implementors may override this method to make use of batch field operations; `count` is
+    /// guaranteed to be no greater than `stride`.
+    fn butterfly_twiddle_batch(
+        &mut self,
+        twiddle: E::BaseField,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) {
+        for offset in offset..(offset + count) {
+            self.butterfly_twiddle(twiddle, offset, stride);
+        }
+    }
+
     /// Swaps the element at index i with the element at index j. Specifically:
     ///
     /// elem_i <-> elem_j
@@ -122,6 +150,22 @@ impl<E: FieldElement> FftInputs<E> for [E] {
         self[j] = temp - self[j];
     }
 
+    fn butterfly_batch(&mut self, offset: usize, stride: usize, count: usize) {
+        let (lo, hi) = self[offset..].split_at_mut(stride);
+        E::batch_butterfly(&mut lo[..count], &mut hi[..count]);
+    }
+
+    fn butterfly_twiddle_batch(
+        &mut self,
+        twiddle: E::BaseField,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) {
+        let (lo, hi) = self[offset..].split_at_mut(stride);
+        E::batch_butterfly_twiddle(&mut lo[..count], &mut hi[..count], twiddle);
+    }
+
     fn swap(&mut self, i: usize, j: usize) {
         self.swap(i, j)
     }
@@ -180,6 +224,26 @@ impl<E: FieldElement, const N: usize> FftInputs<E> for [[E; N]] {
         }
     }
 
+    fn butterfly_batch(&mut self, offset: usize, stride: usize, count: usize) {
+        let (lo, hi) = self[offset..].split_at_mut(stride);
+        E::batch_butterfly(lo[..count].as_flattened_mut(), hi[..count].as_flattened_mut());
+    }
+
+    fn butterfly_twiddle_batch(
+        &mut self,
+        twiddle: E::BaseField,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) {
+        let (lo, hi) = self[offset..].split_at_mut(stride);
+        E::batch_butterfly_twiddle(
+            lo[..count].as_flattened_mut(),
+            hi[..count].as_flattened_mut(),
+            twiddle,
+        );
+    }
+
     fn swap(&mut self, i: usize, j: usize) {
         self.swap(i, j)
     }
@@ -238,15 +302,11 @@ fn fft_in_place<E, I>(
     }
 
     // Apply butterfly operations.
-    for offset in offset..(offset + count) {
-        I::butterfly(values, offset, stride);
-    }
+    I::butterfly_batch(values, offset, stride, count);
 
     // Apply butterfly operations with twiddle factors.
     let last_offset = offset + size * stride;
     for (i, offset) in (offset..last_offset).step_by(2 * stride).enumerate().skip(1) {
-        for j in offset..(offset + count) {
-            I::butterfly_twiddle(values, twiddles[i], j, stride);
-        }
+        I::butterfly_twiddle_batch(values, twiddles[i], offset, stride, count);
     }
 }
@@ -44,6 +44,10 @@ const MIN_CONCURRENT_SIZE: usize = 1024;
 /// memory is allocated and `p` is updated with results of the evaluation. The polynomial `p`
 /// is expected to be in coefficient form.
 ///
+/// The evaluations are written into `p` in natural order: element `i` of `p` is set to the value
+/// of the polynomial at `g`^`i`, where `g` is the generator of the domain. To get the evaluations
+/// in bit-reversed order, use [evaluate_poly_bitrev()] instead.
+///
 /// The complexity of evaluation is O(`n` log(`n`)), where `n` is the size of the domain.
 ///
 /// The size of the domain is assumed to be equal to `p.len()` which must be a power of two. The
@@ -118,6 +122,9 @@ where
 /// by the `blowup_factor`, and shifted by the `domain_offset` in the field specified by the `B`
 /// type parameter. The polynomial `p` is expected to be in coefficient form.
 ///
+/// The evaluations are returned in natural order: element `i` of the result is the value of the
+/// polynomial at `domain_offset` * `g`^`i`, where `g` is the generator of the domain.
+///
 /// The complexity of evaluation is O(`n` log(`n`)), where `n` is the size of the domain.
 ///
 /// The size of the domain is assumed to be equal to `p.len()` * `blowup_factor` both of which must
@@ -210,6 +217,155 @@ where
     result
 }
 
+/// Evaluates a polynomial on all points of the specified domain using the FFT algorithm, and
+/// leaves the evaluations in bit-reversed order.
+///
+/// This function is the same as [evaluate_poly()], except that the final permutation of the
+/// evaluations is skipped: element [permute_index()]`(n, i)` of `p` is set to the value of the
+/// polynomial at `g`^`i`, where `n` is the size of the domain and `g` is its generator. Thus,
+/// applying [permute()] to the result yields the result of [evaluate_poly()].
+///
+/// This is useful when evaluations are consumed in bit-reversed order (e.g., committed to in a
+/// Merkle tree whose leaves are ordered in this way), as it saves a pass over the evaluations.
+///
+/// When `concurrent` feature is enabled, the evaluation is done in multiple threads.
+///
+/// # Panics
+/// Panics if:
+/// * Length of `p` is not a power of two.
+/// * Length of `twiddles` is not `p.len()` / 2.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size `p.len()`.
+///
+/// # Examples
+/// ```
+/// # use winter_math::{fft::*, fields::f128::BaseElement};
+/// # use rand_utils::rand_vector;
+/// let p: Vec<BaseElement> = rand_vector(1024);
+/// let twiddles = get_twiddles::<BaseElement>(p.len());
+///
+/// let mut expected = p.clone();
+/// evaluate_poly(&mut expected, &twiddles);
+///
+/// let mut actual = p.clone();
+/// evaluate_poly_bitrev(&mut actual, &twiddles);
+/// assert_eq!(expected[permute_index(1024, 3)], actual[3]);
+///
+/// permute(&mut actual);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn evaluate_poly_bitrev<B, E>(p: &mut [E], twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    assert!(p.len().is_power_of_two(), "number of coefficients must be a power of 2");
+    assert_eq!(
+        p.len(),
+        twiddles.len() * 2,
+        "invalid number of twiddles: expected {} but received {}",
+        p.len() / 2,
+        twiddles.len()
+    );
+    assert!(
+        p.len().ilog2() <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {} does not exist in the specified base field",
+        p.len()
+    );
+
+    if cfg!(feature = "concurrent") && p.len() >= MIN_CONCURRENT_SIZE {
+        #[cfg(feature = "concurrent")]
+        concurrent::evaluate_poly_bitrev(p, twiddles);
+    } else {
+        serial::evaluate_poly_bitrev(p, twiddles);
+    }
+}
+
+// BATCH EVALUATION
+// ================================================================================================
+
+/// Evaluates a batch of polynomials on all points of the specified shifted domain using the FFT
+/// algorithm.
+///
+/// All polynomials in `polys` must have the same length `n`, and are expected to be in
+/// coefficient form. Each polynomial is evaluated over the domain of size `n` with every element
+/// multiplied by `coset_offset`, and the evaluations replace the coefficients in natural order:
+/// element `i` of each polynomial is set to its value at `coset_offset` * `g`^`i`, where `g` is
+/// the generator of the domain. Thus, the result is the same as that of calling
+/// [evaluate_poly_with_offset()] with blowup factor 1 for each of the polynomials.
+///
+/// Unlike independent FFTs, the FFTs of all polynomials are computed in lockstep: every twiddle
+/// factor is read once and is then applied to the corresponding butterflies of all polynomials.
+/// The innermost FFT layers are computed in blocks which fit into the cache, so that the twiddle
+/// factors of a block are reused by all polynomials before the next block is processed.
+///
+/// The `twiddles` needed for evaluation can be obtained via `fft::get_twiddles()` function using
+/// `n` as the domain size parameter. This implies that `twiddles.len()` must be equal to `n` / 2.
+///
+/// When `concurrent` feature is enabled, the batch is split between multiple threads.
+///
+/// # Panics
+/// Panics if:
+/// * The polynomials do not all have the same length.
+/// * Length of the polynomials is not a power of two.
+/// * Length of `twiddles` is not `n` / 2.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size `n`.
+/// * `coset_offset` is ZERO.
+///
+/// # Examples
+/// ```
+/// # use winter_math::{fft::*, fields::f128::BaseElement, StarkField};
+/// # use rand_utils::rand_vector;
+/// let n = 1024;
+/// let offset = BaseElement::GENERATOR;
+/// let twiddles = get_twiddles::<BaseElement>(n);
+///
+/// // build a batch of random polynomials
+/// let mut polys: Vec<Vec<BaseElement>> = (0..10).map(|_| rand_vector(n)).collect();
+/// let expected: Vec<_> =
+///     polys.iter().map(|p| evaluate_poly_with_offset(p, &twiddles, offset, 1)).collect();
+///
+/// // evaluate all polynomials over the shifted domain at once
+/// batch_coset_ntt(&mut polys, &twiddles, offset);
+///
+/// assert_eq!(expected, polys);
+/// ```
+pub fn batch_coset_ntt<B, E>(polys: &mut [Vec<E>], twiddles: &[B], coset_offset: B)
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let n = match polys.first() {
+        Some(p) => p.len(),
+        None => return,
+    };
+    assert!(
+        polys.iter().all(|p| p.len() == n),
+        "all polynomials must have the same number of coefficients"
+    );
+    assert!(n.is_power_of_two(), "number of coefficients must be a power of 2");
+    assert_eq!(
+        n,
+        twiddles.len() * 2,
+        "invalid number of twiddles: expected {} but received {}",
+        n / 2,
+        twiddles.len()
+    );
+    assert!(
+        n.ilog2() <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {n} does not exist in the specified base field"
+    );
+    assert_ne!(coset_offset, B::ZERO, "coset offset cannot be zero");
+
+    // when `concurrent` feature is enabled, run the concurrent version of the function; unless
+    // the batch is small, then don't bother with the concurrent version
+    if cfg!(feature = "concurrent") && n * polys.len() >= MIN_CONCURRENT_SIZE {
+        #[cfg(feature = "concurrent")]
+        concurrent::batch_coset_ntt(polys, twiddles, coset_offset);
+    } else {
+        serial::batch_coset_ntt(polys, twiddles, coset_offset);
+    }
+}
+
 // POLYNOMIAL INTERPOLATION
 // ================================================================================================
 
@@ -222,6 +378,11 @@ where
 /// is done in-place, meaning no additional memory is allocated and the evaluations contained in
 /// `evaluations` are replaced with polynomial coefficients.
 ///
+/// The evaluations are expected to be in natural order (i.e., element `i` of `evaluations` is the
+/// value of the polynomial at `g`^`i`, where `g` is the generator of the domain), and the
+/// coefficients are written in natural order as well, starting with the constant term. To get
+/// the coefficients in bit-reversed order, use [interpolate_poly_bitrev()] instead.
+///
 /// The complexity of interpolation is O(`n` log(`n`)), where `n` is the size of the domain.
 ///
 /// The size of the domain is assumed to be equal to `evaluations.len()` which must be a power
@@ -296,7 +457,7 @@ where
 }
 
 /// Interpolates evaluations of a polynomial over the specified (shifted) domain into a polynomial
-/// in coefficient from using the FFT algorithm.
+/// in coefficient form using the FFT algorithm.
 ///
 /// Uses the inverse [FFT](https://en.wikipedia.org/wiki/Discrete_Fourier_transform_(general))
 /// algorithm to interpolate a polynomial from its evaluations over a domain defined by the
@@ -304,6 +465,9 @@ where
 /// type parameter. The interpolation is done in-place, meaning no additional memory is allocated
 /// and the evaluations contained in `evaluations` are replaced with polynomial coefficients.
 ///
+/// The evaluations are expected to be in natural order, and the coefficients are written in
+/// natural order as well, starting with the constant term.
+///
 /// The complexity of interpolation is O(`n` log(`n`)), where `n` is the size of the domain.
 ///
 /// The size of the domain is assumed to be equal to `evaluations.len()` which must be a power
@@ -311,7 +475,10 @@ where
 /// to `evaluations.len()`.
 ///
 /// The shifted domain is defined as the original domain with every element multiplied by the
-/// `domain_offset`.
+/// `domain_offset`. This function is the inverse of [evaluate_poly_with_offset()] with the
+/// blowup factor of 1: coefficient `i` of the polynomial interpolated over the original domain
+/// is multiplied by `domain_offset`^(-`i`), and this scaling is folded into the normalization by
+/// 1 / `n` of the inverse FFT, so that no separate pass over the coefficients is needed.
 ///
 /// The `inv_twiddles` needed for interpolation can be obtained via `fft::get_inv_twiddles()`
 /// function using `evaluations.len()` as the domain size parameter. This implies that
@@ -387,13 +554,61 @@ pub fn interpolate_poly_with_offset<B, E>(
     }
 }
 
+/// Interpolates evaluations of a polynomial over the specified domain into a polynomial in
+/// coefficient form using the FFT algorithm, and leaves the coefficients in bit-reversed order.
+///
+/// This function is the same as [interpolate_poly()], except that the final permutation of the
+/// coefficients is skipped: the evaluations are expected to be in natural order, and the
+/// coefficient of degree `i` is written into element [permute_index()]`(n, i)` of `evaluations`,
+/// where `n` is the size of the domain. Thus, applying [permute()] to the result yields the
+/// result of [interpolate_poly()].
+///
+/// When `concurrent` feature is enabled, the interpolation is done in multiple threads.
+///
+/// # Panics
+/// Panics if:
+/// * Length of `evaluations` is not a power of two.
+/// * Length of `inv_twiddles` is not `evaluations.len()` / 2.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size
+///   `evaluations.len()`.
+pub fn interpolate_poly_bitrev<B, E>(evaluations: &mut [E], inv_twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    assert!(
+        evaluations.len().is_power_of_two(),
+        "number of evaluations must be a power of 2, but was {}",
+        evaluations.len()
+    );
+    assert_eq!(
+        evaluations.len(),
+        inv_twiddles.len() * 2,
+        "invalid number of twiddles: expected {} but received {}",
+        evaluations.len() / 2,
+        inv_twiddles.len()
+    );
+    assert!(
+        evaluations.len().ilog2() <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {} does not exist in the specified base field",
+        evaluations.len()
+    );
+
+    if cfg!(feature = "concurrent") && evaluations.len() >= MIN_CONCURRENT_SIZE {
+        #[cfg(feature = "concurrent")]
+        concurrent::interpolate_poly_bitrev(evaluations, inv_twiddles);
+    } else {
+        serial::interpolate_poly_bitrev(evaluations, inv_twiddles);
+    }
+}
+
 // RAW FFT ALGORITHM
 // ================================================================================================
 
 /// Executes a single-threaded version of the FFT algorithm on the provided values.
 ///
 /// The evaluation is done in-place, meaning the function does not allocate any additional memory,
-/// and the results are written back into `values`.
+/// and the results are written back into `values` in natural order.
 ///
 /// The `twiddles` needed for evaluation can be obtained via `fft::get_twiddles()` function using
 /// `values.len()` as the domain size parameter. This implies that `twiddles.len()` must be equal
@@ -584,7 +799,19 @@ pub fn permute_index(size: usize, index: usize) -> usize {
     index.reverse_bits().wrapping_shr(USIZE_BITS - bits)
 }
 
-fn permute<E: FieldElement>(v: &mut [E]) {
+/// Permutes the elements of `v` in place such that the element at index `i` is moved to index
+/// [permute_index()]`(v.len(), i)` (i.e., to the index with the bits of `i` reversed).
+///
+/// The permutation is an involution, and thus, it converts values from natural order into
+/// bit-reversed order and vice versa.
+///
+/// When `concurrent` feature is enabled, the permutation is done in multiple threads.
+///
+/// # Panics
+/// Panics if the length of `v` is not a power of two.
+pub fn permute<E: FieldElement>(v: &mut [E]) {
+    assert!(v.len().is_power_of_two(), "number of values must be a power of 2");
+
     if cfg!(feature = "concurrent") && v.len() >= MIN_CONCURRENT_SIZE {
         #[cfg(feature = "concurrent")]
         concurrent::permute(v);
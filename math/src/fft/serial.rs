@@ -5,11 +5,18 @@
 
 use alloc::vec::Vec;
 
-use utils::uninit_vector;
+use utils::{assume_init_slice_mut, assume_init_vector, uninit_vector};
 
 use super::fft_inputs::FftInputs;
 use crate::{field::StarkField, FieldElement};
 
+// CONSTANTS
+// ================================================================================================
+
+/// Size of the blocks for which all remaining FFT layers are computed at once when evaluating
+/// a batch of polynomials; blocks of this size fit into the L1 cache for 128-bit elements.
+const BATCH_FFT_BLOCK_SIZE: usize = 1024;
+
 // POLYNOMIAL EVALUATION
 // ================================================================================================
 
@@ -20,10 +27,20 @@ where
     B: StarkField,
     E: FieldElement<BaseField = B>,
 {
-    p.fft_in_place(twiddles);
+    evaluate_poly_bitrev(p, twiddles);
     p.permute();
 }
 
+/// Evaluates polynomial `p` in-place over the domain of length `p.len()` in the field specified
+/// by `B` using the FFT algorithm, and leaves the evaluations in bit-reversed order.
+pub fn evaluate_poly_bitrev<B, E>(p: &mut [E], twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    p.fft_in_place(twiddles);
+}
+
 /// Evaluates polynomial `p` over the domain of length `p.len()` * `blowup_factor` shifted by
 /// `domain_offset` in the field specified `B` using the FFT algorithm and returns the result.
 pub fn evaluate_poly_with_offset<B, E>(
@@ -38,23 +55,113 @@ where
 {
     let domain_size = p.len() * blowup_factor;
     let g = B::get_root_of_unity(domain_size.ilog2());
-    let mut result = unsafe { uninit_vector(domain_size) };
+    let mut result = uninit_vector::<E>(domain_size);
 
     result.as_mut_slice().chunks_mut(p.len()).enumerate().for_each(|(i, chunk)| {
         let idx = super::permute_index(blowup_factor, i) as u64;
         let offset = g.exp(idx.into()) * domain_offset;
         let mut factor = E::BaseField::ONE;
         for (d, c) in chunk.iter_mut().zip(p.iter()) {
-            *d = (*c).mul_base(factor);
+            d.write((*c).mul_base(factor));
             factor *= offset;
         }
-        chunk.fft_in_place(twiddles);
+        // SAFETY: the chunk has the same length as `p`, and thus, all of its elements have been
+        // initialized above
+        unsafe { assume_init_slice_mut(chunk) }.fft_in_place(twiddles);
     });
 
+    // SAFETY: the chunks cover the whole result, and all of them have been initialized above
+    let mut result = unsafe { assume_init_vector(result) };
     result.permute();
     result
 }
 
+// BATCH EVALUATION
+// ================================================================================================
+
+/// Evaluates all polynomials in `polys` in-place over the domain of length `n` shifted by
+/// `coset_offset`, where `n` is the length of the polynomials.
+pub fn batch_coset_ntt<B, E>(polys: &mut [Vec<E>], twiddles: &[B], coset_offset: B)
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    for p in polys.iter_mut() {
+        p.shift_by_series(B::ONE, coset_offset);
+    }
+    batch_fft_in_place(polys, twiddles);
+    for p in polys.iter_mut() {
+        p.permute();
+    }
+}
+
+/// Applies the FFT to all polynomials in `polys` in lockstep; the results are left in
+/// bit-reversed order.
+///
+/// The FFT layers are computed starting from the layer with the largest butterflies. For every
+/// layer, the twiddle factor of each block of butterflies is applied to the same block of all
+/// polynomials. Once the blocks become smaller than [BATCH_FFT_BLOCK_SIZE], all remaining layers
+/// of a block are computed for all polynomials before moving on to the next block.
+fn batch_fft_in_place<B, E>(polys: &mut [Vec<E>], twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let n = polys[0].len();
+    let mut num_blocks = 1;
+    let mut block_size = n;
+    while block_size > BATCH_FFT_BLOCK_SIZE {
+        for (block_idx, &twiddle) in twiddles[..num_blocks].iter().enumerate() {
+            for p in polys.iter_mut() {
+                let block = &mut p[block_idx * block_size..(block_idx + 1) * block_size];
+                apply_butterflies(block, twiddle, block_idx);
+            }
+        }
+        num_blocks *= 2;
+        block_size /= 2;
+    }
+
+    for block_idx in 0..num_blocks {
+        for p in polys.iter_mut() {
+            let block = &mut p[block_idx * block_size..(block_idx + 1) * block_size];
+            fft_in_place_block(block, twiddles, block_idx);
+        }
+    }
+}
+
+/// Applies all remaining FFT layers to `block`, which is the block at index `block_idx` of the
+/// layer at which the block size is `block.len()`.
+fn fft_in_place_block<E: FieldElement>(
+    block: &mut [E],
+    twiddles: &[E::BaseField],
+    block_idx: usize,
+) {
+    let mut num_blocks = 1;
+    let mut block_size = block.len();
+    while block_size > 1 {
+        // the children of block `i` of one layer are blocks `2i` and `2i + 1` of the next layer
+        let first_idx = block_idx * num_blocks;
+        let twiddles = &twiddles[first_idx..first_idx + num_blocks];
+        for (i, (chunk, &twiddle)) in block.chunks_mut(block_size).zip(twiddles).enumerate() {
+            apply_butterflies(chunk, twiddle, first_idx + i);
+        }
+        num_blocks *= 2;
+        block_size /= 2;
+    }
+}
+
+/// Applies butterflies with the specified twiddle factor to the two halves of `block`; the
+/// twiddle factor of the first block of every layer is ONE, and thus, it is not applied.
+#[inline(always)]
+fn apply_butterflies<E: FieldElement>(block: &mut [E], twiddle: E::BaseField, block_idx: usize) {
+    let (lo, hi) = block.split_at_mut(block.len() / 2);
+    if block_idx == 0 {
+        E::batch_butterfly(lo, hi);
+    } else {
+        E::batch_butterfly_twiddle(lo, hi, twiddle);
+    }
+}
+
 // POLYNOMIAL INTERPOLATION
 // ================================================================================================
 
@@ -64,6 +171,21 @@ where
 /// # Panics
 /// Panics if the length of `evaluations` is greater than [u32::MAX].
 pub fn interpolate_poly<B, E>(evaluations: &mut [E], inv_twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    interpolate_poly_bitrev(evaluations, inv_twiddles);
+    evaluations.permute();
+}
+
+/// Interpolates `evaluations` over a domain of length `evaluations.len()` in the field specified
+/// `B` into a polynomial in coefficient form using the FFT algorithm, and leaves the coefficients
+/// in bit-reversed order.
+///
+/// # Panics
+/// Panics if the length of `evaluations` is greater than [u32::MAX].
+pub fn interpolate_poly_bitrev<B, E>(evaluations: &mut [E], inv_twiddles: &[B])
 where
     B: StarkField,
     E: FieldElement<BaseField = B>,
@@ -72,7 +194,6 @@ where
     let inv_length = B::inv((evaluations.len() as u32).into());
     evaluations.fft_in_place(inv_twiddles);
     evaluations.shift_by(inv_length);
-    evaluations.permute();
 }
 
 /// Interpolates `evaluations` over a domain of length `evaluations.len()` and shifted by
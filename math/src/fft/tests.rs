@@ -9,9 +9,9 @@ use rand_utils::rand_vector;
 
 use crate::{
     fft::fft_inputs::FftInputs,
-    field::{f128::BaseElement, StarkField},
+    field::{f128::BaseElement, FieldElement, QuadExtension, StarkField},
     polynom,
-    utils::get_power_series,
+    utils::{get_power_series, get_power_series_with_offset},
 };
 
 // CORE ALGORITHMS
@@ -72,6 +72,138 @@ fn fft_get_twiddles() {
     assert_eq!(expected, twiddles);
 }
 
+// POLYNOMIAL EVALUATION AND INTERPOLATION
+// ================================================================================================
+
+#[test]
+fn interpolate_poly_with_offset() {
+    let offsets = [
+        BaseElement::ONE,
+        BaseElement::GENERATOR,
+        BaseElement::new(7),
+        rand_utils::rand_value(),
+    ];
+    for n in [2, 16, super::MIN_CONCURRENT_SIZE * 2] {
+        let twiddles = super::get_twiddles::<BaseElement>(n);
+        let inv_twiddles = super::get_inv_twiddles::<BaseElement>(n);
+        let g = BaseElement::get_root_of_unity(n.ilog2());
+
+        for offset in offsets {
+            let p: Vec<BaseElement> = rand_vector(n);
+
+            // the interpolated polynomial evaluates to the same values over the shifted domain
+            let mut evaluations = super::evaluate_poly_with_offset(&p, &twiddles, offset, 1);
+            let domain = get_power_series_with_offset(g, offset, n);
+            assert_eq!(polynom::eval_many(&p, &domain), evaluations);
+
+            super::interpolate_poly_with_offset(&mut evaluations, &inv_twiddles, offset);
+            assert_eq!(p, evaluations);
+        }
+    }
+}
+
+#[test]
+fn interpolate_poly_with_offset_blowup() {
+    // evaluations over a domain extended by the blowup factor interpolate into the original
+    // polynomial padded with zeros
+    let n = 32;
+    let blowup_factor = 4;
+    let offset = BaseElement::GENERATOR;
+    let p: Vec<BaseElement> = rand_vector(n);
+
+    let twiddles = super::get_twiddles::<BaseElement>(n);
+    let mut evaluations = super::evaluate_poly_with_offset(&p, &twiddles, offset, blowup_factor);
+
+    let inv_twiddles = super::get_inv_twiddles::<BaseElement>(n * blowup_factor);
+    super::interpolate_poly_with_offset(&mut evaluations, &inv_twiddles, offset);
+    assert_eq!(p, evaluations[..n]);
+    assert!(evaluations[n..].iter().all(|&c| c == BaseElement::ZERO));
+}
+
+#[test]
+fn evaluate_and_interpolate_bitrev() {
+    for n in [2, 16, super::MIN_CONCURRENT_SIZE * 2] {
+        let twiddles = super::get_twiddles::<BaseElement>(n);
+        let inv_twiddles = super::get_inv_twiddles::<BaseElement>(n);
+        let p: Vec<BaseElement> = rand_vector(n);
+
+        let mut expected = p.clone();
+        super::evaluate_poly(&mut expected, &twiddles);
+
+        // permuting bit-reversed evaluations yields evaluations in natural order
+        let mut evaluations = p.clone();
+        super::evaluate_poly_bitrev(&mut evaluations, &twiddles);
+        super::permute(&mut evaluations);
+        assert_eq!(expected, evaluations);
+
+        // the same holds for coefficients returned by the bit-reversed interpolation
+        super::interpolate_poly_bitrev(&mut evaluations, &inv_twiddles);
+        super::permute(&mut evaluations);
+        assert_eq!(p, evaluations);
+    }
+}
+
+// PERMUTATIONS
+// ================================================================================================
+
+#[test]
+fn batch_coset_ntt() {
+    let offset = BaseElement::GENERATOR;
+    for n in [2, 16, 1024, 4096] {
+        let twiddles = super::get_twiddles::<BaseElement>(n);
+        for num_polys in [1, 3, 10] {
+            let mut polys: Vec<Vec<BaseElement>> = (0..num_polys).map(|_| rand_vector(n)).collect();
+            let expected: Vec<Vec<BaseElement>> = polys
+                .iter()
+                .map(|p| match n <= 16 {
+                    true => polynom::eval_many(p, &build_shifted_domain(n, offset)),
+                    false => super::evaluate_poly_with_offset(p, &twiddles, offset, 1),
+                })
+                .collect();
+            super::batch_coset_ntt(&mut polys, &twiddles, offset);
+            assert_eq!(expected, polys);
+        }
+    }
+
+    // polynomials over an extension field
+    let n = 2048;
+    let twiddles = super::get_twiddles::<BaseElement>(n);
+    let mut polys: Vec<Vec<QuadExtension<BaseElement>>> = (0..5).map(|_| rand_vector(n)).collect();
+    let expected: Vec<_> = polys
+        .iter()
+        .map(|p| super::evaluate_poly_with_offset(p, &twiddles, offset, 1))
+        .collect();
+    super::batch_coset_ntt(&mut polys, &twiddles, offset);
+    assert_eq!(expected, polys);
+
+    // an empty batch is left unchanged
+    super::batch_coset_ntt::<BaseElement, BaseElement>(&mut [], &twiddles, offset);
+}
+
+#[test]
+#[should_panic(expected = "all polynomials must have the same number of coefficients")]
+fn batch_coset_ntt_different_lengths() {
+    let twiddles = super::get_twiddles::<BaseElement>(16);
+    let mut polys: Vec<Vec<BaseElement>> = vec![rand_vector(16), rand_vector(8)];
+    super::batch_coset_ntt(&mut polys, &twiddles, BaseElement::GENERATOR);
+}
+
+#[test]
+fn permute_index_matches_permute() {
+    for n in [1, 2, 8, 64, super::MIN_CONCURRENT_SIZE * 2] {
+        let values: Vec<BaseElement> = rand_vector(n);
+        let mut permuted = values.clone();
+        super::permute(&mut permuted);
+        for (i, &value) in values.iter().enumerate() {
+            assert_eq!(value, permuted[super::permute_index(n, i)]);
+        }
+
+        // the permutation is an involution
+        super::permute(&mut permuted);
+        assert_eq!(values, permuted);
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -79,3 +211,8 @@ fn build_domain(size: usize) -> Vec<BaseElement> {
     let g = BaseElement::get_root_of_unity(size.ilog2());
     get_power_series(g, size)
 }
+
+fn build_shifted_domain(size: usize, offset: BaseElement) -> Vec<BaseElement> {
+    let g = BaseElement::get_root_of_unity(size.ilog2());
+    get_power_series_with_offset(g, offset, size)
+}
@@ -0,0 +1,61 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Element-wise implementations of batch field operations.
+//!
+//! These are used as the default implementations of batch methods of [FieldElement], and as the
+//! fallback for fields which provide vectorized implementations of these methods.
+
+use super::FieldElement;
+
+/// Computes a[i] + b[i] for all i and saves the result into a[i].
+#[inline(always)]
+pub(crate) fn add<E: FieldElement>(a: &mut [E], b: &[E]) {
+    for (a, &b) in a.iter_mut().zip(b) {
+        *a += b;
+    }
+}
+
+/// Computes a[i] * b[i] for all i and saves the result into a[i].
+#[inline(always)]
+pub(crate) fn mul<E: FieldElement>(a: &mut [E], b: &[E]) {
+    for (a, &b) in a.iter_mut().zip(b) {
+        *a *= b;
+    }
+}
+
+/// Computes acc[i] + a[i] * scalar for all i and saves the result into acc[i].
+#[inline(always)]
+pub(crate) fn mul_acc<E: FieldElement>(acc: &mut [E], a: &[E], scalar: E) {
+    for (acc, &a) in acc.iter_mut().zip(a) {
+        *acc += a * scalar;
+    }
+}
+
+/// Computes (lo[i] + hi[i], lo[i] - hi[i]) for all i and saves the result into (lo[i], hi[i]).
+#[inline(always)]
+pub(crate) fn butterfly<E: FieldElement>(lo: &mut [E], hi: &mut [E]) {
+    for (lo, hi) in lo.iter_mut().zip(hi.iter_mut()) {
+        let temp = *lo;
+        *lo = temp + *hi;
+        *hi = temp - *hi;
+    }
+}
+
+/// Computes (lo[i] + hi[i] * twiddle, lo[i] - hi[i] * twiddle) for all i and saves the result
+/// into (lo[i], hi[i]).
+#[inline(always)]
+pub(crate) fn butterfly_twiddle<E: FieldElement>(
+    lo: &mut [E],
+    hi: &mut [E],
+    twiddle: E::BaseField,
+) {
+    for (lo, hi) in lo.iter_mut().zip(hi.iter_mut()) {
+        let temp = *lo;
+        *hi = hi.mul_base(twiddle);
+        *lo = temp + *hi;
+        *hi = temp - *hi;
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::slice;
+
+use utils::{Deserializable, Serializable};
+
+use super::FieldElement;
+
+// COMPACT FIELD ENCODING
+// ================================================================================================
+
+/// Variable-length encoding of field elements.
+///
+/// Each base field component of an element is encoded as a single length byte followed by the
+/// minimal little-endian representation of the component's canonical integer value (i.e., with
+/// all trailing zero bytes removed). Thus, small values take up only a few bytes (zero is encoded
+/// as a single byte), while large values are written in full width, and take up one byte more
+/// than their fixed-width encoding. Components of extension field elements are encoded one after
+/// another.
+///
+/// The encoding of each element is unique: decoding fails for encodings with trailing zero bytes
+/// and for values which are not smaller than the field modulus.
+pub trait CompactFieldEncoding: Sized {
+    /// Appends the compact encoding of this element to the `target`.
+    fn encode_into(&self, target: &mut Vec<u8>);
+
+    /// Decodes an element from the start of the provided bytes, and returns the element together
+    /// with the number of bytes its encoding occupied; `None` is returned if the bytes do not
+    /// start with a valid encoding of an element.
+    fn try_decode_prefix(bytes: &[u8]) -> Option<(Self, usize)>;
+
+    /// Returns the compact encoding of this element.
+    fn encode(&self) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.encode_into(&mut result);
+        result
+    }
+
+    /// Decodes an element from the provided bytes; `None` is returned if the bytes are not
+    /// exactly a valid encoding of an element.
+    fn try_decode(bytes: &[u8]) -> Option<Self> {
+        match Self::try_decode_prefix(bytes)? {
+            (element, num_bytes) if num_bytes == bytes.len() => Some(element),
+            _ => None,
+        }
+    }
+}
+
+impl<E: FieldElement> CompactFieldEncoding for E {
+    fn encode_into(&self, target: &mut Vec<u8>) {
+        for component in E::slice_as_base_elements(slice::from_ref(self)) {
+            // base field elements serialize into the little-endian bytes of their canonical value
+            let bytes = component.to_bytes();
+            let num_bytes = bytes.iter().rposition(|&byte| byte != 0).map_or(0, |i| i + 1);
+            target.push(num_bytes as u8);
+            target.extend_from_slice(&bytes[..num_bytes]);
+        }
+    }
+
+    fn try_decode_prefix(bytes: &[u8]) -> Option<(Self, usize)> {
+        let element_bytes = <E::BaseField as FieldElement>::ELEMENT_BYTES;
+
+        let mut components = Vec::with_capacity(E::EXTENSION_DEGREE);
+        let mut buffer = vec![0u8; element_bytes];
+        let mut pos = 0;
+        for _ in 0..E::EXTENSION_DEGREE {
+            let num_bytes = *bytes.get(pos)? as usize;
+            if num_bytes > element_bytes {
+                return None;
+            }
+            let value_bytes = bytes.get(pos + 1..pos + 1 + num_bytes)?;
+            if value_bytes.last() == Some(&0) {
+                return None;
+            }
+
+            buffer.fill(0);
+            buffer[..num_bytes].copy_from_slice(value_bytes);
+            components.push(E::BaseField::read_from_bytes(&buffer).ok()?);
+            pos += 1 + num_bytes;
+        }
+
+        Some((E::slice_from_base_elements(&components)[0], pos))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use rand_utils::rand_value;
+
+    use super::CompactFieldEncoding;
+    use crate::{
+        field::{f128, f62, f64, CubeExtension, QuadExtension},
+        FieldElement,
+    };
+
+    #[test]
+    fn small_values() {
+        assert_eq!(vec![0], f64::BaseElement::ZERO.encode());
+        assert_eq!(vec![1, 1], f64::BaseElement::ONE.encode());
+        assert_eq!(vec![1, 255], f64::BaseElement::new(255).encode());
+        assert_eq!(vec![2, 0, 1], f64::BaseElement::new(256).encode());
+
+        // values are encoded in canonical rather than in Montgomery form
+        assert_eq!(vec![1, 7], f62::BaseElement::new(7).encode());
+        assert_eq!(vec![1, 7], f128::BaseElement::new(7).encode());
+
+        // components of extension elements are encoded one after another
+        let element = QuadExtension::new(f64::BaseElement::new(3), f64::BaseElement::ZERO);
+        assert_eq!(vec![1, 3, 0], element.encode());
+    }
+
+    #[test]
+    fn large_values() {
+        let element = -f64::BaseElement::ONE;
+        let encoding = element.encode();
+        assert_eq!(f64::BaseElement::ELEMENT_BYTES + 1, encoding.len());
+        assert_eq!(f64::BaseElement::ELEMENT_BYTES as u8, encoding[0]);
+        assert_eq!(&element.as_int().to_le_bytes()[..], &encoding[1..]);
+
+        let element = -f128::BaseElement::ONE;
+        assert_eq!(f128::BaseElement::ELEMENT_BYTES + 1, element.encode().len());
+    }
+
+    #[test]
+    fn round_trip() {
+        fn check<E: FieldElement>(element: E) {
+            let encoding = element.encode();
+            assert_eq!(Some(element), E::try_decode(&encoding));
+
+            // the prefix decoder reports how many bytes the element occupies
+            let mut bytes = encoding.clone();
+            bytes.extend_from_slice(&[1, 2, 3]);
+            assert_eq!(Some((element, encoding.len())), E::try_decode_prefix(&bytes));
+            assert_eq!(None, E::try_decode(&bytes));
+        }
+
+        for _ in 0..16 {
+            check(rand_value::<f62::BaseElement>());
+            check(rand_value::<f64::BaseElement>());
+            check(rand_value::<f128::BaseElement>());
+            check(rand_value::<QuadExtension<f64::BaseElement>>());
+            check(rand_value::<CubeExtension<f64::BaseElement>>());
+        }
+        for value in [0, 1, 255, 256, u32::MAX as u64] {
+            check(f62::BaseElement::new(value));
+            check(f64::BaseElement::new(value));
+            check(f128::BaseElement::new(value as u128));
+        }
+    }
+
+    #[test]
+    fn invalid_encodings() {
+        type E = f64::BaseElement;
+
+        // empty input and truncated values
+        assert_eq!(None, E::try_decode(&[]));
+        assert_eq!(None, E::try_decode(&[2, 1]));
+
+        // length exceeds the width of the field element
+        assert_eq!(None, E::try_decode(&[9, 1, 1, 1, 1, 1, 1, 1, 1, 1]));
+
+        // encodings with trailing zero bytes are not minimal
+        assert_eq!(None, E::try_decode(&[1, 0]));
+        assert_eq!(None, E::try_decode(&[2, 1, 0]));
+
+        // values must be smaller than the field modulus
+        let mut encoding = vec![8];
+        encoding.extend_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(None, E::try_decode(&encoding));
+
+        // all components of an extension element must be present
+        assert_eq!(None, QuadExtension::<E>::try_decode(&[1, 3]));
+    }
+}
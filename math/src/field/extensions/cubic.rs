@@ -102,6 +102,38 @@ impl<B: ExtensibleField<3>> FieldElement for CubeExtension<B> {
         Self(result[0], result[1], result[2])
     }
 
+    #[inline]
+    fn conditional_select(a: Self, b: Self, choice: bool) -> Self {
+        Self(
+            B::conditional_select(a.0, b.0, choice),
+            B::conditional_select(a.1, b.1, choice),
+            B::conditional_select(a.2, b.2, choice),
+        )
+    }
+
+    fn inv_ct(self) -> Self {
+        // same as inv() but without special handling of ZERO: the norm of ZERO is ZERO, and the
+        // constant-time inverse of ZERO in the base field is ZERO as well
+        let x = [self.0, self.1, self.2];
+        let c1 = <B as ExtensibleField<3>>::frobenius(x);
+        let c2 = <B as ExtensibleField<3>>::frobenius(c1);
+        let numerator = <B as ExtensibleField<3>>::mul(c1, c2);
+
+        let norm = <B as ExtensibleField<3>>::mul(x, numerator);
+        debug_assert_eq!(norm[1], B::ZERO, "norm must be in the base field");
+        debug_assert_eq!(norm[2], B::ZERO, "norm must be in the base field");
+        let denom_inv = norm[0].inv_ct();
+
+        Self(numerator[0] * denom_inv, numerator[1] * denom_inv, numerator[2] * denom_inv)
+    }
+
+    // INTEGER CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn from_u64(value: u64) -> Self {
+        Self::from(B::from_u64(value))
+    }
+
     // BASE ELEMENT CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -99,6 +99,31 @@ impl<B: ExtensibleField<2>> FieldElement for QuadExtension<B> {
         Self(result[0], result[1])
     }
 
+    #[inline]
+    fn conditional_select(a: Self, b: Self, choice: bool) -> Self {
+        Self(B::conditional_select(a.0, b.0, choice), B::conditional_select(a.1, b.1, choice))
+    }
+
+    fn inv_ct(self) -> Self {
+        // same as inv() but without special handling of ZERO: the norm of ZERO is ZERO, and the
+        // constant-time inverse of ZERO in the base field is ZERO as well
+        let x = [self.0, self.1];
+        let numerator = <B as ExtensibleField<2>>::frobenius(x);
+
+        let norm = <B as ExtensibleField<2>>::mul(x, numerator);
+        debug_assert_eq!(norm[1], B::ZERO, "norm must be in the base field");
+        let denom_inv = norm[0].inv_ct();
+
+        Self(numerator[0] * denom_inv, numerator[1] * denom_inv)
+    }
+
+    // INTEGER CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn from_u64(value: u64) -> Self {
+        Self::from(B::from_u64(value))
+    }
+
     // BASE ELEMENT CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -5,10 +5,11 @@
 
 //! An implementation of a 128-bit STARK-friendly prime field with modulus $2^{128} - 45 \cdot 2^{40} + 1$.
 //!
-//! Operations in this field are implemented using Barret reduction and are stored in their
-//! canonical form using `u128` as the backing type. However, this field was not chosen with any
-//! significant thought given to performance, and the implementations of most operations are
-//! sub-optimal as well.
+//! Elements of this field are stored in Montgomery form using `u128` as the backing type, and
+//! multiplication is implemented via Montgomery reduction over two 64-bit limbs (CIOS method).
+//! Conversion to and from the canonical form happens only when elements are created, serialized,
+//! or exposed as integers. However, this field was not chosen with any significant thought given
+//! to performance, and the implementations of some operations (e.g., inversion) are sub-optimal.
 
 use alloc::{
     string::{String, ToString},
@@ -39,6 +40,20 @@ mod tests;
 // Field modulus = 2^128 - 45 * 2^40 + 1
 const M: u128 = 340282366920938463463374557953744961537;
 
+// Lower and upper 64-bit limbs of the field modulus
+const M0: u64 = M as u64;
+const M1: u64 = (M >> 64) as u64;
+
+// -M^{-1} mod 2^64; this is used during Montgomery reduction
+const U: u64 = 0xFFFFD2FFFFFFFFFF;
+
+// 2^256 mod M; this is used for conversion of elements into Montgomery representation
+const R2: u128 = 2448074784719525122733506561;
+
+// 2^384 mod M; this is used to convert the canonical inverse of a Montgomery element back into
+// Montgomery representation
+const R3: u128 = 325660858959960164507291570664667872924;
+
 // 2^40 root of unity
 const G: u128 = 23953097886125630542083529559205016746;
 
@@ -48,21 +63,43 @@ const ELEMENT_BYTES: usize = core::mem::size_of::<u128>();
 // FIELD ELEMENT
 // ================================================================================================
 
-/// Represents a base field element.
+/// Represents a base field element using Montgomery representation.
 ///
-/// Internal values are stored in their canonical form in the range [0, M). The backing type is
-/// `u128`.
+/// Internal values represent x * R mod M where R = 2^128 and x in [0, M). The backing type is
+/// `u128` and the internal values are always in the range [0, M).
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "serde", serde(try_from = "u128", into = "u128"))]
 pub struct BaseElement(u128);
 
 impl BaseElement {
-    /// Creates a new field element from a u128 value. If the value is greater than or equal to
-    /// the field modulus, modular reduction is silently performed. This function can also be used
-    /// to initialize constants.
+    /// Creates a new field element from a u128 value; the value is converted into Montgomery
+    /// representation. If the value is greater than or equal to the field modulus, modular
+    /// reduction is silently performed. This function can also be used to initialize constants.
     pub const fn new(value: u128) -> Self {
-        BaseElement(if value < M { value } else { value - M })
+        let value = if value < M { value } else { value - M };
+        BaseElement(mont_mul(value, R2))
+    }
+
+    /// Creates a new field element from the provided bytes interpreted as a u128 value in
+    /// little-endian byte order. If the value is greater than or equal to the field modulus,
+    /// modular reduction is performed.
+    ///
+    /// Note that element deserialization rejects non-canonical encodings; this function should
+    /// be used only when modular reduction of arbitrary bytes is actually desired.
+    pub const fn from_le_bytes_reduce(bytes: [u8; ELEMENT_BYTES]) -> Self {
+        Self::new(u128::from_le_bytes(bytes))
+    }
+
+    /// Returns a new field element from the provided `value` which is assumed to be in Montgomery
+    /// form. This should only be used as an optimization.
+    pub const fn from_mont(value: u128) -> Self {
+        BaseElement(value)
+    }
+
+    /// Returns the non-canonical u128 inner value, i.e., the value in Montgomery form.
+    pub const fn inner(&self) -> u128 {
+        self.0
     }
 }
 
@@ -72,24 +109,43 @@ impl FieldElement for BaseElement {
 
     const EXTENSION_DEGREE: usize = 1;
 
-    const ZERO: Self = BaseElement(0);
-    const ONE: Self = BaseElement(1);
+    const ZERO: Self = BaseElement::new(0);
+    const ONE: Self = BaseElement::new(1);
 
     const ELEMENT_BYTES: usize = ELEMENT_BYTES;
 
-    const IS_CANONICAL: bool = true;
+    const IS_CANONICAL: bool = false;
 
     // ALGEBRA
     // --------------------------------------------------------------------------------------------
 
     fn inv(self) -> Self {
-        BaseElement(inv(self.0))
+        // for x in Montgomery form (x * R), inv() yields x^{-1} * R^{-1}; multiplying the result
+        // by R^3 in Montgomery form brings it back to x^{-1} * R
+        BaseElement(mont_mul(inv(self.0), R3))
     }
 
     fn conjugate(&self) -> Self {
         BaseElement(self.0)
     }
 
+    #[inline]
+    fn conditional_select(a: Self, b: Self, choice: bool) -> Self {
+        let mask = 0u128.wrapping_sub(choice as u128);
+        Self(a.0 ^ (mask & (a.0 ^ b.0)))
+    }
+
+    fn inv_ct(self) -> Self {
+        self.exp_ct(M - 2)
+    }
+
+    // INTEGER CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn from_u64(value: u64) -> Self {
+        Self::new(value as u128)
+    }
+
     // BASE ELEMENT CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -111,6 +167,11 @@ impl FieldElement for BaseElement {
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the internal (Montgomery) representation of the elements as bytes.
+    ///
+    /// The returned bytes are not the canonical encoding of the elements; use [Serializable]
+    /// (e.g., [Serializable::to_bytes()]) to get the canonical encoding. The returned bytes can be
+    /// converted back into elements via [FieldElement::bytes_as_elements()].
     fn elements_as_bytes(elements: &[Self]) -> &[u8] {
         // TODO: take endianness into account
         let p = elements.as_ptr();
@@ -118,6 +179,9 @@ impl FieldElement for BaseElement {
         unsafe { slice::from_raw_parts(p as *const u8, len) }
     }
 
+    /// Interprets the bytes as elements in the internal (Montgomery) representation, as returned
+    /// by [FieldElement::elements_as_bytes()]; use [Deserializable] to read canonically encoded
+    /// elements.
     unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError> {
         if bytes.len() % Self::ELEMENT_BYTES != 0 {
             return Err(DeserializationError::InvalidValue(format!(
@@ -150,7 +214,7 @@ impl StarkField for BaseElement {
 
     /// sage: GF(MODULUS).primitive_element() \
     /// 3
-    const GENERATOR: Self = BaseElement(3);
+    const GENERATOR: Self = BaseElement::new(3);
 
     /// sage: is_odd((MODULUS - 1) / 2^40) \
     /// True
@@ -159,7 +223,7 @@ impl StarkField for BaseElement {
     /// sage: k = (MODULUS - 1) / 2^40 \
     /// sage: GF(MODULUS).primitive_element()^k \
     /// 23953097886125630542083529559205016746
-    const TWO_ADIC_ROOT_OF_UNITY: Self = BaseElement(G);
+    const TWO_ADIC_ROOT_OF_UNITY: Self = BaseElement::new(G);
 
     fn get_modulus_le_bytes() -> Vec<u8> {
         Self::MODULUS.to_le_bytes().to_vec()
@@ -167,7 +231,7 @@ impl StarkField for BaseElement {
 
     #[inline]
     fn as_int(&self) -> Self::PositiveInteger {
-        self.0
+        mont_mul(self.0, 1)
     }
 }
 
@@ -187,7 +251,7 @@ impl Debug for BaseElement {
 
 impl Display for BaseElement {
     fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.as_int())
     }
 }
 
@@ -226,7 +290,7 @@ impl Mul for BaseElement {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self {
-        Self(mul(self.0, rhs.0))
+        Self(mont_mul(self.0, rhs.0))
     }
 }
 
@@ -240,7 +304,7 @@ impl Div for BaseElement {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self {
-        Self(mul(self.0, inv(rhs.0)))
+        Self(mont_mul(self.0, rhs.inv().0))
     }
 }
 
@@ -310,31 +374,37 @@ impl ExtensibleField<3> for BaseElement {
 // TYPE CONVERSIONS
 // ================================================================================================
 
+impl From<BaseElement> for u128 {
+    fn from(value: BaseElement) -> Self {
+        value.as_int()
+    }
+}
+
 impl From<u64> for BaseElement {
     /// Converts a 64-bit value into a field element.
     fn from(value: u64) -> Self {
-        BaseElement(value as u128)
+        BaseElement::new(value as u128)
     }
 }
 
 impl From<u32> for BaseElement {
     /// Converts a 32-bit value into a field element.
     fn from(value: u32) -> Self {
-        BaseElement(value as u128)
+        BaseElement::new(value as u128)
     }
 }
 
 impl From<u16> for BaseElement {
     /// Converts a 16-bit value into a field element.
     fn from(value: u16) -> Self {
-        BaseElement(value as u128)
+        BaseElement::new(value as u128)
     }
 }
 
 impl From<u8> for BaseElement {
     /// Converts an 8-bit value into a field element.
     fn from(value: u8) -> Self {
-        BaseElement(value as u128)
+        BaseElement::new(value as u128)
     }
 }
 
@@ -366,11 +436,13 @@ impl TryFrom<&'_ [u8]> for BaseElement {
                 value {value} is greater or equal to the field modulus"
             ));
         }
-        Ok(BaseElement(value))
+        Ok(BaseElement::new(value))
     }
 }
 
 impl AsBytes for BaseElement {
+    /// Returns the internal (Montgomery) representation of this element as bytes; use
+    /// [Serializable] to get the canonical encoding.
     fn as_bytes(&self) -> &[u8] {
         // TODO: take endianness into account
         let self_ptr: *const BaseElement = self;
@@ -383,7 +455,8 @@ impl AsBytes for BaseElement {
 
 impl Serializable for BaseElement {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        target.write_bytes(&self.0.to_le_bytes());
+        // convert from Montgomery representation into canonical representation
+        target.write_bytes(&self.as_int().to_le_bytes());
     }
 
     fn get_size_hint(&self) -> usize {
@@ -399,7 +472,7 @@ impl Deserializable for BaseElement {
                 "invalid field element: value {value} is greater than or equal to the field modulus"
             )));
         }
-        Ok(BaseElement(value))
+        Ok(BaseElement::new(value))
     }
 }
 
@@ -407,62 +480,74 @@ impl Deserializable for BaseElement {
 // ================================================================================================
 
 /// Computes (a + b) % m; a and b are assumed to be valid field elements.
+///
+/// This is computed as a - (m - b), adding m back if the subtraction underflows; the addition
+/// is done using a mask rather than a branch so that the function runs in constant time.
 fn add(a: u128, b: u128) -> u128 {
-    let z = M - b;
-    if a < z {
-        M - z + a
-    } else {
-        a - z
-    }
+    let (d, borrow) = a.overflowing_sub(M - b);
+    d.wrapping_add(M & 0u128.wrapping_sub(borrow as u128))
 }
 
 /// Computes (a - b) % m; a and b are assumed to be valid field elements.
 fn sub(a: u128, b: u128) -> u128 {
-    if a < b {
-        M - b + a
-    } else {
-        a - b
-    }
+    let (d, borrow) = a.overflowing_sub(b);
+    d.wrapping_add(M & 0u128.wrapping_sub(borrow as u128))
 }
 
-/// Computes (a * b) % m; a and b are assumed to be valid field elements.
-fn mul(a: u128, b: u128) -> u128 {
-    let (x0, x1, x2) = mul_128x64(a, (b >> 64) as u64); // x = a * b_hi
-    let (mut x0, mut x1, x2) = mul_reduce(x0, x1, x2); // x = x - (x >> 128) * m
-    if x2 == 1 {
-        // if there was an overflow beyond 128 bits, subtract
-        // modulus from the result to make sure it fits into
-        // 128 bits; this can potentially be removed in favor
-        // of checking overflow later
-        let (t0, t1) = sub_modulus(x0, x1); // x = x - m
-        x0 = t0;
-        x1 = t1;
-    }
-
-    let (y0, y1, y2) = mul_128x64(a, b as u64); // y = a * b_lo
-
-    let (mut y1, carry) = add64_with_carry(y1, x0, 0); // y = y + (x << 64)
-    let (mut y2, y3) = add64_with_carry(y2, x1, carry);
-    if y3 == 1 {
-        // if there was an overflow beyond 192 bits, subtract
-        // modulus * 2^64 from the result to make sure it fits
-        // into 192 bits; this can potentially replace the
-        // previous overflow check (but needs to be proven)
-        let (t0, t1) = sub_modulus(y1, y2); // y = y - (m << 64)
-        y1 = t0;
-        y2 = t1;
-    }
-
-    let (mut z0, mut z1, z2) = mul_reduce(y0, y1, y2); // z = y - (y >> 128) * m
-
-    // make sure z is smaller than m
-    if z2 == 1 || (z1 == (M >> 64) as u64 && z0 >= (M as u64)) {
-        let (t0, t1) = sub_modulus(z0, z1); // z = z - m
-        z0 = t0;
-        z1 = t1;
-    }
-
-    ((z1 as u128) << 64) + (z0 as u128)
+/// Computes a * b * R^{-1} % m using the Coarsely Integrated Operand Scanning (CIOS) variant of
+/// Montgomery multiplication with 64-bit limbs; a and b are assumed to be smaller than m.
+///
+/// Each of the two iterations multiplies a by one limb of b, accumulates the product, and then
+/// eliminates the lowest limb of the accumulator by adding a suitable multiple of m. The result
+/// of the loop is smaller than 2m, and so a single conditional subtraction is sufficient to bring
+/// it into the range [0, m).
+#[inline(always)]
+const fn mont_mul(a: u128, b: u128) -> u128 {
+    let a0 = a as u64;
+    let a1 = (a >> 64) as u64;
+    let b0 = b as u64;
+    let b1 = (b >> 64) as u64;
+
+    // t = a * b0
+    let x = (a0 as u128) * (b0 as u128);
+    let t0 = x as u64;
+    let x = (a1 as u128) * (b0 as u128) + (x >> 64);
+    let t1 = x as u64;
+    let t2 = x >> 64;
+
+    // t = (t + q * m) / 2^64, where q is chosen so that the lowest limb of the sum is zero
+    let (t0, t1, t2) = mont_reduce_limb(t0, t1, t2);
+
+    // t = t + a * b1
+    let x = (a0 as u128) * (b1 as u128) + (t0 as u128);
+    let t0 = x as u64;
+    let x = (a1 as u128) * (b1 as u128) + (t1 as u128) + (x >> 64);
+    let t1 = x as u64;
+    // t may exceed 192 bits at this point, and so the top limb is kept in a u128
+    let t2 = (t2 as u128) + (x >> 64);
+
+    // t = (t + q * m) / 2^64
+    let (t0, t1, t2) = mont_reduce_limb(t0, t1, t2);
+
+    // make sure the result is smaller than m; m is subtracted if t >= m (including when the top
+    // limb is set), and this is done using a mask so that multiplication runs in constant time
+    let t = ((t1 as u128) << 64) | (t0 as u128);
+    let (_, borrow) = t.overflowing_sub(M);
+    let reduce = (t2 != 0) | !borrow;
+    t.wrapping_sub(M & 0u128.wrapping_sub(reduce as u128))
+}
+
+/// Adds q * m to the value t0 + t1 * 2^64 + t2 * 2^128, where q = t0 * (-m^{-1}) mod 2^64, and
+/// returns the sum shifted right by 64 bits.
+#[inline(always)]
+const fn mont_reduce_limb(t0: u64, t1: u64, t2: u128) -> (u64, u64, u64) {
+    let q = t0.wrapping_mul(U);
+    // the lowest limb of t0 + q * m0 is zero by construction of q; only the carry is kept
+    let x = (q as u128) * (M0 as u128) + (t0 as u128);
+    let x = (q as u128) * (M1 as u128) + (t1 as u128) + (x >> 64);
+    let r0 = x as u64;
+    let x = t2 + (x >> 64);
+    (r0, x as u64, (x >> 64) as u64)
 }
 
 /// Computes y such that (x * y) % m = 1 except for when when x = 0; in such a case,
@@ -565,36 +650,6 @@ fn inv(x: u128) -> u128 {
 // HELPER FUNCTIONS
 // ================================================================================================
 
-#[inline]
-fn mul_128x64(a: u128, b: u64) -> (u64, u64, u64) {
-    let z_lo = ((a as u64) as u128) * (b as u128);
-    let z_hi = (a >> 64) * (b as u128);
-    let z_hi = z_hi + (z_lo >> 64);
-    (z_lo as u64, z_hi as u64, (z_hi >> 64) as u64)
-}
-
-#[inline]
-fn mul_reduce(z0: u64, z1: u64, z2: u64) -> (u64, u64, u64) {
-    let (q0, q1, q2) = mul_by_modulus(z2);
-    let (z0, z1, z2) = sub_192x192(z0, z1, z2, q0, q1, q2);
-    (z0, z1, z2)
-}
-
-#[inline]
-fn mul_by_modulus(a: u64) -> (u64, u64, u64) {
-    let a_lo = (a as u128).wrapping_mul(M);
-    let a_hi = if a == 0 { 0 } else { a - 1 };
-    (a_lo as u64, (a_lo >> 64) as u64, a_hi)
-}
-
-#[inline]
-fn sub_modulus(a_lo: u64, a_hi: u64) -> (u64, u64) {
-    let mut z = 0u128.wrapping_sub(M);
-    z = z.wrapping_add(a_lo as u128);
-    z = z.wrapping_add((a_hi as u128) << 64);
-    (z as u64, (z >> 64) as u64)
-}
-
 #[inline]
 fn sub_192x192(a0: u64, a1: u64, a2: u64, b0: u64, b1: u64, b2: u64) -> (u64, u64, u64) {
     let z0 = (a0 as u128).wrapping_sub(b0 as u128);
@@ -610,9 +665,3 @@ fn add_192x192(a0: u64, a1: u64, a2: u64, b0: u64, b1: u64, b2: u64) -> (u64, u6
     let z2 = (a2 as u128) + (b2 as u128) + (z1 >> 64);
     (z0 as u64, z1 as u64, z2 as u64)
 }
-
-#[inline]
-const fn add64_with_carry(a: u64, b: u64, carry: u64) -> (u64, u64) {
-    let ret = (a as u128) + (b as u128) + (carry as u128);
-    (ret as u64, (ret >> 64) as u64)
-}
@@ -3,14 +3,14 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 
 use num_bigint::BigUint;
 use rand_utils::{rand_value, rand_vector};
-use utils::SliceReader;
+use utils::{Deserializable, Serializable, SliceReader};
 
-use super::{AsBytes, BaseElement, ByteReader, DeserializationError, FieldElement, StarkField, M};
-use crate::field::{ExtensionOf, QuadExtension};
+use super::{BaseElement, ByteReader, DeserializationError, FieldElement, StarkField, M};
+use crate::field::{tests as field_tests, ExtensionOf, QuadExtension};
 
 // BASIC ALGEBRA
 // ================================================================================================
@@ -69,7 +69,7 @@ fn mul() {
     assert_eq!(BaseElement::new(m - 2), t * BaseElement::from(2u8));
     assert_eq!(BaseElement::new(m - 4), t * BaseElement::from(4u8));
 
-    let t = (m + 1) / 2;
+    let t = m.div_ceil(2);
     assert_eq!(BaseElement::ONE, BaseElement::new(t) * BaseElement::from(2u8));
 
     // test random values
@@ -102,6 +102,53 @@ fn inv() {
     }
 }
 
+#[test]
+fn exp_ct() {
+    // edge cases
+    let r: BaseElement = rand_value();
+    assert_eq!(BaseElement::ONE, r.exp_ct(0));
+    assert_eq!(BaseElement::ONE, BaseElement::ZERO.exp_ct(0));
+    assert_eq!(BaseElement::ZERO, BaseElement::ZERO.exp_ct(5));
+    assert_eq!(r, r.exp_ct(1));
+    assert_eq!(r.exp(u128::MAX), r.exp_ct(u128::MAX));
+
+    // test random values
+    for _ in 0..100 {
+        let (a, b): (BaseElement, u128) = (rand_value(), rand_value());
+        assert_eq!(a.exp(b), a.exp_ct(b));
+
+        let a: QuadExtension<BaseElement> = rand_value();
+        assert_eq!(a.exp(b), a.exp_ct(b));
+    }
+}
+
+#[test]
+fn inv_ct() {
+    // identity
+    assert_eq!(BaseElement::ONE, BaseElement::inv_ct(BaseElement::ONE));
+    assert_eq!(BaseElement::ZERO, BaseElement::inv_ct(BaseElement::ZERO));
+    assert_eq!(QuadExtension::<BaseElement>::ZERO, QuadExtension::<BaseElement>::ZERO.inv_ct());
+
+    // test random values
+    let x: Vec<BaseElement> = rand_vector(1000);
+    for i in x {
+        assert_eq!(i.inv(), i.inv_ct());
+    }
+
+    let x: Vec<QuadExtension<BaseElement>> = rand_vector(1000);
+    for i in x {
+        assert_eq!(i.inv(), i.inv_ct());
+    }
+}
+
+#[test]
+fn conditional_select() {
+    let a: BaseElement = rand_value();
+    let b: BaseElement = rand_value();
+    assert_eq!(a, BaseElement::conditional_select(a, b, false));
+    assert_eq!(b, BaseElement::conditional_select(a, b, true));
+}
+
 #[test]
 fn conjugate() {
     let a: BaseElement = rand_value();
@@ -146,6 +193,53 @@ fn quad_mul_base() {
 // SERIALIZATION / DESERIALIZATION
 // ================================================================================================
 
+#[test]
+fn montgomery_representation() {
+    // constants are stored in Montgomery form but expose their canonical values
+    assert_eq!(0, BaseElement::ZERO.as_int());
+    assert_eq!(1, BaseElement::ONE.as_int());
+    assert_eq!(3, BaseElement::GENERATOR.as_int());
+    assert_eq!(
+        23953097886125630542083529559205016746u128,
+        BaseElement::TWO_ADIC_ROOT_OF_UNITY.as_int()
+    );
+    assert_eq!(0u128.wrapping_sub(M), BaseElement::ONE.inner());
+
+    // conversion into and out of Montgomery form is transparent to integers, bytes and strings
+    let value = rand_value::<u128>() % M;
+    let element = BaseElement::new(value);
+    assert_eq!(value, element.as_int());
+    assert_eq!(value, u128::from(element));
+    assert_eq!(value.to_le_bytes().to_vec(), element.to_bytes());
+    assert_eq!(value.to_string(), element.to_string());
+    assert_eq!(element, BaseElement::read_from_bytes(&value.to_le_bytes()).unwrap());
+    assert_eq!(element, BaseElement::from_mont(element.inner()));
+
+    // non-reduced values are reduced before being converted into Montgomery form
+    assert_eq!(BaseElement::new(7), BaseElement::new(M + 7));
+    assert_eq!(BaseElement::new(u128::MAX - M), BaseElement::new(u128::MAX));
+}
+
+#[test]
+fn read_non_canonical_element() {
+    field_tests::check_non_canonical_encodings::<BaseElement>();
+    field_tests::check_non_canonical_extension_encodings::<QuadExtension<BaseElement>>();
+
+    // values greater than or equal to the modulus can still be reduced explicitly
+    for (value, reduced) in [(M, 0), (M + 5, 5)] {
+        assert_eq!(
+            BaseElement::new(reduced),
+            BaseElement::from_le_bytes_reduce(value.to_le_bytes())
+        );
+    }
+}
+
+#[test]
+fn int_and_byte_conversions() {
+    field_tests::check_int_and_byte_conversions::<BaseElement>();
+    field_tests::check_extension_conversions::<QuadExtension<BaseElement>>();
+}
+
 #[test]
 fn elements_as_bytes() {
     let source = vec![
@@ -155,33 +249,39 @@ fn elements_as_bytes() {
         BaseElement::new(4),
     ];
 
-    let expected: Vec<u8> = vec![
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0,
-    ];
+    let mut expected = vec![];
+    expected.extend_from_slice(&source[0].0.to_le_bytes());
+    expected.extend_from_slice(&source[1].0.to_le_bytes());
+    expected.extend_from_slice(&source[2].0.to_le_bytes());
+    expected.extend_from_slice(&source[3].0.to_le_bytes());
 
     assert_eq!(expected, BaseElement::elements_as_bytes(&source));
+
+    // elements are exposed in Montgomery representation, which differs from their canonical
+    // encoding
+    let canonical: Vec<u8> = source.iter().flat_map(|e| e.to_bytes()).collect();
+    assert_ne!(canonical, BaseElement::elements_as_bytes(&source));
 }
 
 #[test]
 fn bytes_as_elements() {
-    let bytes: Vec<u8> = vec![
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 5,
-    ];
-
-    let expected = vec![
+    let elements = vec![
         BaseElement::new(1),
         BaseElement::new(2),
         BaseElement::new(3),
         BaseElement::new(4),
     ];
 
+    let mut bytes = vec![];
+    bytes.extend_from_slice(&elements[0].0.to_le_bytes());
+    bytes.extend_from_slice(&elements[1].0.to_le_bytes());
+    bytes.extend_from_slice(&elements[2].0.to_le_bytes());
+    bytes.extend_from_slice(&elements[3].0.to_le_bytes());
+    bytes.push(5);
+
     let result = unsafe { BaseElement::bytes_as_elements(&bytes[..64]) };
     assert!(result.is_ok());
-    assert_eq!(expected, result.unwrap());
+    assert_eq!(elements, result.unwrap());
 
     let result = unsafe { BaseElement::bytes_as_elements(&bytes) };
     assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
@@ -232,7 +332,7 @@ fn read_elements_from() {
 
 impl BaseElement {
     pub fn to_big_uint(&self) -> BigUint {
-        BigUint::from_bytes_le(self.as_bytes())
+        BigUint::from_bytes_le(&self.as_int().to_le_bytes())
     }
 
     pub fn from_big_uint(value: BigUint) -> Self {
@@ -0,0 +1,56 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! An implementation of a 252-bit STARK-friendly prime field with modulus
+//! $2^{251} + 17 \cdot 2^{192} + 1$.
+//!
+//! The field is defined via the generic [Fp](super::fp::Fp) implementation, and thus, all
+//! operations in this field are implemented using Montgomery arithmetic over four 64-bit limbs.
+//! Extensions of this field are not supported; since the field is big enough to provide over
+//! 100 bits of security on its own, proofs in this field should be generated without field
+//! extensions.
+
+use super::fp::{Fp, FpConfig};
+
+#[cfg(test)]
+mod tests;
+
+// FIELD CONFIGURATION
+// ================================================================================================
+
+/// Parameters of the 252-bit field with modulus 2^251 + 17 * 2^192 + 1.
+pub struct Stark252Config;
+
+impl FpConfig<4> for Stark252Config {
+    /// sage: MODULUS = 2^251 + 17 * 2^192 + 1 \
+    /// sage: GF(MODULUS).is_prime_field() \
+    /// True \
+    /// sage: GF(MODULUS).order() \
+    /// 3618502788666131213697322783095070105623107215331596699973092056135872020481
+    const MODULUS: [u64; 4] = [1, 0, 0, 0x0800000000000011];
+
+    /// sage: GF(MODULUS).primitive_element() \
+    /// 3
+    const GENERATOR: [u64; 4] = [3, 0, 0, 0];
+
+    /// sage: is_odd((MODULUS - 1) / 2^192) \
+    /// True
+    const TWO_ADICITY: u32 = 192;
+
+    /// sage: k = (MODULUS - 1) / 2^192 \
+    /// sage: GF(MODULUS).primitive_element()^k \
+    /// 145784604816374866144131285430889962727208297722245411306711449302875041684
+    const TWO_ADIC_ROOT_OF_UNITY: [u64; 4] =
+        [0x6070024f42f8ef94, 0xad187148e11a6161, 0x3f0464519c8b0fa5, 0x005282db87529cfa];
+}
+
+// FIELD ELEMENT
+// ================================================================================================
+
+/// Represents base field element in the field.
+///
+/// Internal values are stored in Montgomery representation and are always in the range [0; M).
+/// The backing type is `[u64; 4]`.
+pub type BaseElement = Fp<Stark252Config, 4>;
@@ -0,0 +1,353 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{string::ToString, vec::Vec};
+
+use num_bigint::BigUint;
+use proptest::prelude::*;
+use rand_utils::{rand_value, rand_vector};
+use utils::{AsBytes, Deserializable, DeserializationError, Serializable};
+
+use super::BaseElement;
+use crate::{
+    field::{fp::BigInt, tests as field_tests},
+    ExtensibleField, FieldElement, StarkField,
+};
+
+// MANUAL TESTS
+// ================================================================================================
+
+#[test]
+fn add() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(r, r + BaseElement::ZERO);
+
+    // test addition within bounds
+    assert_eq!(BaseElement::from(5u8), BaseElement::from(2u8) + BaseElement::from(3u8));
+
+    // test overflow
+    let t = BaseElement::new(m_minus(1));
+    assert_eq!(BaseElement::ZERO, t + BaseElement::ONE);
+    assert_eq!(BaseElement::ONE, t + BaseElement::from(2u8));
+}
+
+#[test]
+fn sub() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(r, r - BaseElement::ZERO);
+
+    // test subtraction within bounds
+    assert_eq!(BaseElement::from(2u8), BaseElement::from(5u8) - BaseElement::from(3u8));
+
+    // test underflow
+    let expected = BaseElement::new(m_minus(2));
+    assert_eq!(expected, BaseElement::from(3u8) - BaseElement::from(5u8));
+}
+
+#[test]
+fn mul() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(BaseElement::ZERO, r * BaseElement::ZERO);
+    assert_eq!(r, r * BaseElement::ONE);
+
+    // test multiplication within bounds
+    assert_eq!(BaseElement::from(15u8), BaseElement::from(5u8) * BaseElement::from(3u8));
+
+    // test overflow
+    let t = BaseElement::new(m_minus(1));
+    assert_eq!(BaseElement::ONE, t * t);
+    assert_eq!(BaseElement::new(m_minus(2)), t * BaseElement::from(2u8));
+    assert_eq!(BaseElement::new(m_minus(4)), t * BaseElement::from(4u8));
+
+    // (M + 1) / 2 is the inverse of 2
+    let half = BaseElement::MODULUS >> 1;
+    assert_eq!(
+        BaseElement::ONE,
+        (BaseElement::new(half) + BaseElement::ONE) * BaseElement::from(2u8)
+    );
+}
+
+#[test]
+fn exp() {
+    let a = BaseElement::ZERO;
+    assert_eq!(a.exp(BigInt::ZERO), BaseElement::ONE);
+    assert_eq!(a.exp(BigInt::ONE), BaseElement::ZERO);
+
+    let a = BaseElement::ONE;
+    assert_eq!(a.exp(BigInt::ZERO), BaseElement::ONE);
+    assert_eq!(a.exp(BigInt::ONE), BaseElement::ONE);
+    assert_eq!(a.exp(BigInt::from(3u32)), BaseElement::ONE);
+
+    let a: BaseElement = rand_value();
+    assert_eq!(a.exp(BigInt::from(3u32)), a * a * a);
+
+    // by Fermat's little theorem, a^(M - 1) = 1
+    assert_eq!(BaseElement::ONE, a.exp(m_minus(1)));
+}
+
+#[test]
+fn inv() {
+    // identity
+    assert_eq!(BaseElement::ONE, BaseElement::inv(BaseElement::ONE));
+    assert_eq!(BaseElement::ZERO, BaseElement::inv(BaseElement::ZERO));
+
+    let a: BaseElement = rand_value();
+    assert_eq!(BaseElement::ONE, a * a.inv());
+    assert_eq!(a, BaseElement::ONE / a.inv());
+}
+
+#[test]
+fn constant_time_ops() {
+    // exponentiation edge cases
+    let r: BaseElement = rand_value();
+    assert_eq!(BaseElement::ONE, r.exp_ct(BigInt::ZERO));
+    assert_eq!(BaseElement::ONE, BaseElement::ZERO.exp_ct(BigInt::ZERO));
+    assert_eq!(BaseElement::ZERO, BaseElement::ZERO.exp_ct(BigInt::from(5u32)));
+    assert_eq!(r, r.exp_ct(BigInt::ONE));
+    let max = BigInt([u64::MAX; 4]);
+    assert_eq!(r.exp(max), r.exp_ct(max));
+
+    // inversion of ZERO
+    assert_eq!(BaseElement::ZERO, BaseElement::ZERO.inv_ct());
+
+    // conditional selection
+    let s: BaseElement = rand_value();
+    assert_eq!(r, BaseElement::conditional_select(r, s, false));
+    assert_eq!(s, BaseElement::conditional_select(r, s, true));
+}
+
+#[test]
+fn element_as_int() {
+    let v = BigInt([u64::MAX; 4]);
+    let e = BaseElement::new(v);
+    assert_eq!(to_biguint(v) % modulus(), to_biguint(e.as_int()));
+}
+
+#[test]
+fn equals() {
+    let a = BaseElement::ONE;
+    let b = BaseElement::new(m_minus(1)) * BaseElement::new(m_minus(1));
+
+    // elements are equal
+    assert_eq!(a, b);
+    assert_eq!(a.as_int(), b.as_int());
+    assert_eq!(a.to_bytes(), b.to_bytes());
+
+    // the internal representation is not canonical
+    assert_ne!(a.to_bytes(), a.as_bytes());
+}
+
+#[test]
+fn extensions_not_supported() {
+    assert!(!<BaseElement as ExtensibleField<2>>::is_supported());
+    assert!(!<BaseElement as ExtensibleField<3>>::is_supported());
+}
+
+// ROOTS OF UNITY
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn get_root_of_unity() {
+    let root_192 = BaseElement::get_root_of_unity(192);
+    assert_eq!(BaseElement::TWO_ADIC_ROOT_OF_UNITY, root_192);
+    assert_eq!(BaseElement::ONE, root_192.exp(BigInt::ONE << 192));
+    assert_ne!(BaseElement::ONE, root_192.exp(BigInt::ONE << 191));
+
+    let root_191 = BaseElement::get_root_of_unity(191);
+    let expected = root_192.exp(BigInt::from(2u32));
+    assert_eq!(expected, root_191);
+    assert_eq!(BaseElement::ONE, root_191.exp(BigInt::ONE << 191));
+
+    // the generator is a quadratic non-residue
+    let power = BaseElement::MODULUS >> 1;
+    assert_eq!(-BaseElement::ONE, BaseElement::GENERATOR.exp(power));
+}
+
+// SERIALIZATION AND DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn serialization_round_trip() {
+    let elements: Vec<BaseElement> = rand_vector(16);
+    for element in elements {
+        let bytes = element.to_bytes();
+        assert_eq!(BaseElement::ELEMENT_BYTES, bytes.len());
+        assert_eq!(element.as_int().to_le_bytes(), bytes);
+        assert_eq!(element, BaseElement::read_from_bytes(&bytes).unwrap());
+    }
+}
+
+#[test]
+fn read_non_canonical_element() {
+    field_tests::check_non_canonical_encodings::<BaseElement>();
+
+    // values greater than or equal to the modulus must be rejected by integer conversions too
+    for value in [
+        BaseElement::MODULUS,
+        BigInt([5, 0, 0, 0x0800000000000011]),
+        BigInt([u64::MAX; 4]),
+    ] {
+        assert!(BaseElement::try_from(value).is_err());
+    }
+}
+
+#[test]
+fn try_from_slice() {
+    let mut bytes = vec![0; 32];
+    bytes[0] = 1;
+    let result = BaseElement::try_from(bytes.as_slice());
+    assert!(result.is_ok());
+    assert_eq!(BigInt::ONE, result.unwrap().as_int());
+
+    let result = BaseElement::try_from(&bytes[..31]);
+    assert!(result.is_err());
+
+    bytes.push(0);
+    let result = BaseElement::try_from(bytes.as_slice());
+    assert!(result.is_err());
+
+    let bytes = vec![255; 32];
+    let result = BaseElement::try_from(bytes.as_slice());
+    assert!(result.is_err());
+}
+
+#[test]
+fn try_from_int() {
+    assert_eq!(BaseElement::from(7u32), BaseElement::try_from(7u64).unwrap());
+    let value = u128::MAX;
+    let expected = BaseElement::new(BigInt([u64::MAX, u64::MAX, 0, 0]));
+    assert_eq!(expected, BaseElement::try_from(value).unwrap());
+}
+
+#[test]
+fn int_and_byte_conversions() {
+    field_tests::check_int_and_byte_conversions::<BaseElement>();
+}
+
+#[test]
+fn elements_as_bytes() {
+    let source = vec![
+        BaseElement::from(1u8),
+        BaseElement::from(2u8),
+        BaseElement::from(3u8),
+        BaseElement::from(4u8),
+    ];
+
+    let mut expected = vec![];
+    for element in source.iter() {
+        expected.extend_from_slice(element.as_bytes());
+    }
+
+    assert_eq!(expected, BaseElement::elements_as_bytes(&source));
+}
+
+#[test]
+fn bytes_as_elements() {
+    let elements: Vec<BaseElement> = rand_vector(5);
+    let bytes = BaseElement::elements_as_bytes(&elements).to_vec();
+
+    let result = unsafe { BaseElement::bytes_as_elements(&bytes[..128]) };
+    assert!(result.is_ok());
+    assert_eq!(elements[..4], *result.unwrap());
+
+    let result = unsafe { BaseElement::bytes_as_elements(&bytes[..129]) };
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+}
+
+#[test]
+fn display() {
+    assert_eq!("0", BaseElement::ZERO.to_string());
+    assert_eq!((modulus() - 1u32).to_string(), BaseElement::new(m_minus(1)).to_string());
+    assert_eq!(format!("{}", BaseElement::from(42u8)), format!("{:?}", BaseElement::from(42u8)));
+}
+
+// RANDOMIZED TESTS
+// ================================================================================================
+
+proptest! {
+    #[test]
+    fn add_proptest(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+        let result = BaseElement::new(BigInt(a)) + BaseElement::new(BigInt(b));
+        let expected = (to_biguint(BigInt(a)) + to_biguint(BigInt(b))) % modulus();
+        prop_assert_eq!(expected, to_biguint(result.as_int()));
+    }
+
+    #[test]
+    fn sub_proptest(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+        let result = BaseElement::new(BigInt(a)) - BaseElement::new(BigInt(b));
+        let m = modulus();
+        let expected = (to_biguint(BigInt(a)) % &m + &m - to_biguint(BigInt(b)) % &m) % &m;
+        prop_assert_eq!(expected, to_biguint(result.as_int()));
+    }
+
+    #[test]
+    fn mul_proptest(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+        let result = BaseElement::new(BigInt(a)) * BaseElement::new(BigInt(b));
+        let expected = (to_biguint(BigInt(a)) * to_biguint(BigInt(b))) % modulus();
+        prop_assert_eq!(expected, to_biguint(result.as_int()));
+    }
+
+    #[test]
+    fn exp_proptest(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+        let result = BaseElement::new(BigInt(a)).exp(BigInt(b));
+        let expected = to_biguint(BigInt(a)).modpow(&to_biguint(BigInt(b)), &modulus());
+        prop_assert_eq!(expected, to_biguint(result.as_int()));
+    }
+
+    #[test]
+    fn inv_proptest(a in any::<[u64; 4]>()) {
+        let a = BaseElement::new(BigInt(a));
+        let b = a.inv();
+
+        let expected = if a == BaseElement::ZERO { BaseElement::ZERO } else { BaseElement::ONE };
+        prop_assert_eq!(expected, a * b);
+    }
+
+    #[test]
+    fn element_as_int_proptest(a in any::<[u64; 4]>()) {
+        let e = BaseElement::new(BigInt(a));
+        prop_assert_eq!(to_biguint(BigInt(a)) % modulus(), to_biguint(e.as_int()));
+    }
+
+    // CONSTANT-TIME OPERATIONS
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn exp_ct_proptest(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+        let a = BaseElement::new(BigInt(a));
+        prop_assert_eq!(a.exp(BigInt(b)), a.exp_ct(BigInt(b)));
+    }
+
+    #[test]
+    fn inv_ct_proptest(a in any::<[u64; 4]>()) {
+        let a = BaseElement::new(BigInt(a));
+        prop_assert_eq!(a.inv(), a.inv_ct());
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn modulus() -> BigUint {
+    to_biguint(BaseElement::MODULUS)
+}
+
+fn to_biguint(value: BigInt<4>) -> BigUint {
+    BigUint::from_bytes_le(&value.to_le_bytes())
+}
+
+/// Returns M - value.
+fn m_minus(value: u64) -> BigInt<4> {
+    let bytes = (modulus() - value).to_bytes_le();
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+        let mut buf = [0u8; 8];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        *limb = u64::from_le_bytes(buf);
+    }
+    BigInt(limbs)
+}
@@ -0,0 +1,243 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! AVX2 implementations of batch operations for the 62-bit field.
+//!
+//! Each function processes 4 elements at a time and falls back onto element-wise operations for
+//! the remaining elements. The vectorized arithmetic mirrors the scalar arithmetic of the field
+//! exactly, and so the outputs (including non-canonical internal representations in the range
+//! [0, 2M)) are identical to the outputs of the element-wise implementation.
+
+use core::arch::x86_64::*;
+
+use super::{BaseElement, M};
+use crate::field::batch;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of field elements processed by a single vector operation.
+pub const LANES: usize = 4;
+
+/// Upper 32 bits of the field modulus; the lower 32 bits of the modulus are equal to 1.
+const M_HI: u64 = M >> 32;
+
+// FEATURE DETECTION
+// ================================================================================================
+
+/// Returns true if AVX2 instructions can be used on the current CPU.
+///
+/// When the `std` feature is enabled, this is determined at runtime; otherwise, AVX2 is used only
+/// if it was enabled at compile time.
+#[inline(always)]
+pub fn is_available() -> bool {
+    #[cfg(target_feature = "avx2")]
+    {
+        true
+    }
+
+    #[cfg(all(not(target_feature = "avx2"), feature = "std"))]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+
+    #[cfg(all(not(target_feature = "avx2"), not(feature = "std")))]
+    {
+        false
+    }
+}
+
+// BATCH OPERATIONS
+// ================================================================================================
+
+/// Computes a[i] + b[i] for all i and saves the result into a[i].
+///
+/// # Safety
+/// The caller must ensure that AVX2 instructions are available.
+#[target_feature(enable = "avx2")]
+pub unsafe fn add(a: &mut [BaseElement], b: &[BaseElement]) {
+    let n = a.len().min(b.len());
+    let num_chunks = n / LANES;
+    for i in 0..num_chunks {
+        let x = load(a, i);
+        let y = load(b, i);
+        store(a, i, add_vec(x, y));
+    }
+    let tail = num_chunks * LANES;
+    batch::add(&mut a[tail..n], &b[tail..n]);
+}
+
+/// Computes a[i] * b[i] for all i and saves the result into a[i].
+///
+/// # Safety
+/// The caller must ensure that AVX2 instructions are available.
+#[target_feature(enable = "avx2")]
+pub unsafe fn mul(a: &mut [BaseElement], b: &[BaseElement]) {
+    let n = a.len().min(b.len());
+    let num_chunks = n / LANES;
+    for i in 0..num_chunks {
+        let x = load(a, i);
+        let y = load(b, i);
+        store(a, i, mul_vec(x, y));
+    }
+    let tail = num_chunks * LANES;
+    batch::mul(&mut a[tail..n], &b[tail..n]);
+}
+
+/// Computes acc[i] + a[i] * scalar for all i and saves the result into acc[i].
+///
+/// # Safety
+/// The caller must ensure that AVX2 instructions are available.
+#[target_feature(enable = "avx2")]
+pub unsafe fn mul_acc(acc: &mut [BaseElement], a: &[BaseElement], scalar: BaseElement) {
+    let n = acc.len().min(a.len());
+    let num_chunks = n / LANES;
+    let s = _mm256_set1_epi64x(scalar.0 as i64);
+    for i in 0..num_chunks {
+        let x = load(acc, i);
+        let y = load(a, i);
+        store(acc, i, add_vec(x, mul_vec(y, s)));
+    }
+    let tail = num_chunks * LANES;
+    batch::mul_acc(&mut acc[tail..n], &a[tail..n], scalar);
+}
+
+/// Computes (lo[i] + hi[i], lo[i] - hi[i]) for all i and saves the result into (lo[i], hi[i]).
+///
+/// # Safety
+/// The caller must ensure that AVX2 instructions are available.
+#[target_feature(enable = "avx2")]
+pub unsafe fn butterfly(lo: &mut [BaseElement], hi: &mut [BaseElement]) {
+    let n = lo.len().min(hi.len());
+    let num_chunks = n / LANES;
+    for i in 0..num_chunks {
+        let x = load(lo, i);
+        let y = load(hi, i);
+        store(lo, i, add_vec(x, y));
+        store(hi, i, sub_vec(x, y));
+    }
+    let tail = num_chunks * LANES;
+    batch::butterfly(&mut lo[tail..n], &mut hi[tail..n]);
+}
+
+/// Computes (lo[i] + hi[i] * twiddle, lo[i] - hi[i] * twiddle) for all i and saves the result
+/// into (lo[i], hi[i]).
+///
+/// # Safety
+/// The caller must ensure that AVX2 instructions are available.
+#[target_feature(enable = "avx2")]
+pub unsafe fn butterfly_twiddle(
+    lo: &mut [BaseElement],
+    hi: &mut [BaseElement],
+    twiddle: BaseElement,
+) {
+    let n = lo.len().min(hi.len());
+    let num_chunks = n / LANES;
+    let t = _mm256_set1_epi64x(twiddle.0 as i64);
+    for i in 0..num_chunks {
+        let x = load(lo, i);
+        let y = mul_vec(load(hi, i), t);
+        store(lo, i, add_vec(x, y));
+        store(hi, i, sub_vec(x, y));
+    }
+    let tail = num_chunks * LANES;
+    batch::butterfly_twiddle(&mut lo[tail..n], &mut hi[tail..n], twiddle);
+}
+
+// VECTOR ARITHMETIC
+// ================================================================================================
+
+/// Computes (a + b) reduced by M such that the output is in [0, 2M) range; this is the same as
+/// the scalar `add()` function applied to each lane.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn add_vec(a: __m256i, b: __m256i) -> __m256i {
+    // z = a + b; since a, b < 2M, z < 4M < 2^64 and so z >> 62 is in [0, 4)
+    let z = _mm256_add_epi64(a, b);
+    let k = _mm256_srli_epi64(z, 62);
+
+    // q = k * M; since M = M_HI * 2^32 + 1, this is computed as k + ((k * M_HI) << 32)
+    let m_hi = _mm256_set1_epi64x(M_HI as i64);
+    let q = _mm256_add_epi64(k, _mm256_slli_epi64(_mm256_mul_epu32(k, m_hi), 32));
+
+    _mm256_sub_epi64(z, q)
+}
+
+/// Computes (a - b) reduced by M such that the output is in [0, 2M) range; this is the same as
+/// the scalar `sub()` function applied to each lane.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn sub_vec(a: __m256i, b: __m256i) -> __m256i {
+    // values are smaller than 2^63, and so signed comparison can be used to check if a < b
+    let two_m = _mm256_set1_epi64x((2 * M) as i64);
+    let d = _mm256_sub_epi64(a, b);
+    let borrow = _mm256_cmpgt_epi64(b, a);
+    _mm256_add_epi64(d, _mm256_and_si256(borrow, two_m))
+}
+
+/// Computes (a * b) using Montgomery reduction such that the output is in [0, 2M) range; this is
+/// the same as the scalar `mul()` function applied to each lane.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mul_vec(a: __m256i, b: __m256i) -> __m256i {
+    let m_hi = _mm256_set1_epi64x(M_HI as i64);
+
+    // z = a * b = z_hi * 2^64 + z_lo, computed from four 32-bit partial products; since
+    // a, b < 2^63, the sum of the middle products fits into 64 bits
+    let a_hi = _mm256_srli_epi64(a, 32);
+    let b_hi = _mm256_srli_epi64(b, 32);
+    let ll = _mm256_mul_epu32(a, b);
+    let lh = _mm256_mul_epu32(a, b_hi);
+    let hl = _mm256_mul_epu32(a_hi, b);
+    let hh = _mm256_mul_epu32(a_hi, b_hi);
+    let mid = _mm256_add_epi64(lh, hl);
+    let z_lo = _mm256_add_epi64(ll, _mm256_slli_epi64(mid, 32));
+    let z_hi = _mm256_add_epi64(hh, _mm256_srli_epi64(mid, 32));
+    let z_hi = _mm256_sub_epi64(z_hi, lt_u64(z_lo, ll));
+
+    // q = z_lo * U mod 2^64; since U = M_HI * 2^32 - 1, this is ((z_lo * M_HI) << 32) - z_lo
+    let q = _mm256_sub_epi64(_mm256_slli_epi64(_mm256_mul_epu32(z_lo, m_hi), 32), z_lo);
+
+    // compute the upper 64 bits of q * M = q + ((q * M_HI) << 32)
+    let p0 = _mm256_mul_epu32(q, m_hi);
+    let p1 = _mm256_mul_epu32(_mm256_srli_epi64(q, 32), m_hi);
+    let s = _mm256_add_epi64(q, _mm256_slli_epi64(p0, 32));
+    let qm_hi = _mm256_add_epi64(p1, _mm256_srli_epi64(p0, 32));
+    let qm_hi = _mm256_sub_epi64(qm_hi, lt_u64(s, q));
+
+    // (z + q * M) >> 64; the lower 64 bits of the sum are zero by construction of q, and they
+    // produce a carry whenever z_lo is not zero
+    let r = _mm256_add_epi64(z_hi, qm_hi);
+    let z_lo_is_zero = _mm256_cmpeq_epi64(z_lo, _mm256_setzero_si256());
+    let r = _mm256_add_epi64(r, _mm256_set1_epi64x(1));
+    _mm256_add_epi64(r, z_lo_is_zero)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a mask with all bits set in lanes where a < b (as unsigned integers).
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn lt_u64(a: __m256i, b: __m256i) -> __m256i {
+    let sign = _mm256_set1_epi64x(i64::MIN);
+    _mm256_cmpgt_epi64(_mm256_xor_si256(b, sign), _mm256_xor_si256(a, sign))
+}
+
+/// Loads the chunk of 4 elements at the specified chunk index.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn load(values: &[BaseElement], chunk_idx: usize) -> __m256i {
+    debug_assert!((chunk_idx + 1) * LANES <= values.len());
+    _mm256_loadu_si256(values.as_ptr().add(chunk_idx * LANES) as *const __m256i)
+}
+
+/// Stores the vector into the chunk of 4 elements at the specified chunk index.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn store(values: &mut [BaseElement], chunk_idx: usize, value: __m256i) {
+    debug_assert!((chunk_idx + 1) * LANES <= values.len());
+    _mm256_storeu_si256(values.as_mut_ptr().add(chunk_idx * LANES) as *mut __m256i, value)
+}
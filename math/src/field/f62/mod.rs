@@ -27,7 +27,10 @@ use utils::{
     Serializable,
 };
 
-use super::{ExtensibleField, FieldElement, StarkField};
+use super::{batch, ExtensibleField, FieldElement, StarkField};
+
+#[cfg(target_arch = "x86_64")]
+mod avx2;
 
 #[cfg(test)]
 mod tests;
@@ -75,6 +78,16 @@ impl BaseElement {
         let z = mul(value, R2);
         BaseElement(z)
     }
+
+    /// Creates a new field element from the provided bytes interpreted as a u64 value in
+    /// little-endian byte order. If the value is greater than or equal to the field modulus,
+    /// modular reduction is performed.
+    ///
+    /// Note that element deserialization rejects non-canonical encodings; this function should
+    /// be used only when modular reduction of arbitrary bytes is actually desired.
+    pub const fn from_le_bytes_reduce(bytes: [u8; ELEMENT_BYTES]) -> Self {
+        Self::new(u64::from_le_bytes(bytes))
+    }
 }
 
 impl FieldElement for BaseElement {
@@ -127,6 +140,23 @@ impl FieldElement for BaseElement {
         BaseElement(self.0)
     }
 
+    #[inline]
+    fn conditional_select(a: Self, b: Self, choice: bool) -> Self {
+        let mask = 0u64.wrapping_sub(choice as u64);
+        Self(a.0 ^ (mask & (a.0 ^ b.0)))
+    }
+
+    fn inv_ct(self) -> Self {
+        self.exp_ct(M - 2)
+    }
+
+    // INTEGER CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn from_u64(value: u64) -> Self {
+        Self::new(value)
+    }
+
     // BASE ELEMENT CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -174,6 +204,50 @@ impl FieldElement for BaseElement {
 
         Ok(slice::from_raw_parts(p as *const Self, len))
     }
+
+    // BATCH OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn batch_add(a: &mut [Self], b: &[Self]) {
+        #[cfg(target_arch = "x86_64")]
+        if a.len() >= avx2::LANES && avx2::is_available() {
+            // SAFETY: availability of AVX2 instructions was checked above
+            return unsafe { avx2::add(a, b) };
+        }
+        batch::add(a, b)
+    }
+
+    fn batch_mul(a: &mut [Self], b: &[Self]) {
+        #[cfg(target_arch = "x86_64")]
+        if a.len() >= avx2::LANES && avx2::is_available() {
+            return unsafe { avx2::mul(a, b) };
+        }
+        batch::mul(a, b)
+    }
+
+    fn batch_mul_acc(acc: &mut [Self], a: &[Self], scalar: Self) {
+        #[cfg(target_arch = "x86_64")]
+        if acc.len() >= avx2::LANES && avx2::is_available() {
+            return unsafe { avx2::mul_acc(acc, a, scalar) };
+        }
+        batch::mul_acc(acc, a, scalar)
+    }
+
+    fn batch_butterfly(lo: &mut [Self], hi: &mut [Self]) {
+        #[cfg(target_arch = "x86_64")]
+        if lo.len() >= avx2::LANES && avx2::is_available() {
+            return unsafe { avx2::butterfly(lo, hi) };
+        }
+        batch::butterfly(lo, hi)
+    }
+
+    fn batch_butterfly_twiddle(lo: &mut [Self], hi: &mut [Self], twiddle: Self) {
+        #[cfg(target_arch = "x86_64")]
+        if lo.len() >= avx2::LANES && avx2::is_available() {
+            return unsafe { avx2::butterfly_twiddle(lo, hi, twiddle) };
+        }
+        batch::butterfly_twiddle(lo, hi, twiddle)
+    }
 }
 
 impl StarkField for BaseElement {
@@ -546,11 +620,10 @@ fn add(a: u64, b: u64) -> u64 {
 /// be in [0, 2M).
 #[inline(always)]
 fn sub(a: u64, b: u64) -> u64 {
-    if a < b {
-        2 * M - b + a
-    } else {
-        a - b
-    }
+    // add 2M to the difference if a < b; this is done without branching so that subtraction
+    // runs in constant time
+    let (d, borrow) = a.overflowing_sub(b);
+    d.wrapping_add(0u64.wrapping_sub(borrow as u64) & (2 * M))
 }
 
 /// Computes (a * b) reduced by M such that the output is in [0, 2M) range; a and b are assumed to
@@ -3,12 +3,16 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::vec::Vec;
+
 use num_bigint::BigUint;
 use proptest::prelude::*;
 use rand_utils::rand_value;
 
-use super::{AsBytes, BaseElement, DeserializationError, FieldElement, Serializable, StarkField};
-use crate::field::{CubeExtension, ExtensionOf, QuadExtension};
+use super::{
+    AsBytes, BaseElement, DeserializationError, FieldElement, Serializable, StarkField, M,
+};
+use crate::field::{batch, tests as field_tests, CubeExtension, ExtensionOf, QuadExtension};
 
 // MANUAL TESTS
 // ================================================================================================
@@ -59,7 +63,7 @@ fn mul() {
     assert_eq!(BaseElement::new(m - 2), t * BaseElement::from(2u8));
     assert_eq!(BaseElement::new(m - 4), t * BaseElement::from(4u8));
 
-    let t = (m + 1) / 2;
+    let t = m.div_ceil(2);
     assert_eq!(BaseElement::ONE, BaseElement::new(t) * BaseElement::from(2u8));
 }
 
@@ -85,6 +89,27 @@ fn inv() {
     assert_eq!(BaseElement::ZERO, BaseElement::inv(BaseElement::ZERO));
 }
 
+#[test]
+fn constant_time_ops() {
+    // exponentiation edge cases
+    let r: BaseElement = rand_value();
+    assert_eq!(BaseElement::ONE, r.exp_ct(0));
+    assert_eq!(BaseElement::ONE, BaseElement::ZERO.exp_ct(0));
+    assert_eq!(BaseElement::ZERO, BaseElement::ZERO.exp_ct(5));
+    assert_eq!(r, r.exp_ct(1));
+    assert_eq!(r.exp(u64::MAX), r.exp_ct(u64::MAX));
+
+    // inversion of ZERO
+    assert_eq!(BaseElement::ZERO, BaseElement::ZERO.inv_ct());
+    assert_eq!(QuadExtension::<BaseElement>::ZERO, QuadExtension::<BaseElement>::ZERO.inv_ct());
+    assert_eq!(CubeExtension::<BaseElement>::ZERO, CubeExtension::<BaseElement>::ZERO.inv_ct());
+
+    // conditional selection
+    let s: BaseElement = rand_value();
+    assert_eq!(r, BaseElement::conditional_select(r, s, false));
+    assert_eq!(s, BaseElement::conditional_select(r, s, true));
+}
+
 #[test]
 fn element_as_int() {
     let v = u64::MAX;
@@ -212,6 +237,20 @@ fn get_root_of_unity() {
 // SERIALIZATION AND DESERIALIZATION
 // ------------------------------------------------------------------------------------------------
 
+#[test]
+fn read_non_canonical_element() {
+    field_tests::check_non_canonical_encodings::<BaseElement>();
+    field_tests::check_non_canonical_extension_encodings::<QuadExtension<BaseElement>>();
+
+    // values greater than or equal to the modulus can still be reduced explicitly
+    for (value, reduced) in [(M, 0), (M + 5, 5)] {
+        assert_eq!(
+            BaseElement::new(reduced),
+            BaseElement::from_le_bytes_reduce(value.to_le_bytes())
+        );
+    }
+}
+
 #[test]
 fn try_from_slice() {
     let bytes = vec![1, 0, 0, 0, 0, 0, 0, 0];
@@ -232,6 +271,13 @@ fn try_from_slice() {
     assert!(result.is_err());
 }
 
+#[test]
+fn int_and_byte_conversions() {
+    field_tests::check_int_and_byte_conversions::<BaseElement>();
+    field_tests::check_extension_conversions::<QuadExtension<BaseElement>>();
+    field_tests::check_extension_conversions::<CubeExtension<BaseElement>>();
+}
+
 #[test]
 fn elements_as_bytes() {
     let source = vec![
@@ -277,10 +323,81 @@ fn bytes_as_elements() {
     assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
 }
 
+// BATCH OPERATIONS
+// ================================================================================================
+
+#[test]
+fn batch_ops_edge_values() {
+    // internal values can be anywhere in [0, 2M); use all pairs of boundary values so that every
+    // combination ends up in some vector lane
+    let edges = [0, 1, 2, M - 1, M, M + 1, 2 * M - 2, 2 * M - 1];
+    let mut a = Vec::new();
+    let mut b = Vec::new();
+    for &x in edges.iter() {
+        for &y in edges.iter() {
+            a.push(BaseElement(x));
+            b.push(BaseElement(y));
+        }
+    }
+    check_batch_ops(&a, &b, BaseElement(2 * M - 1));
+    check_batch_ops(&a, &b, BaseElement(0));
+}
+
+/// Asserts that batch operations produce exactly the same internal values as element-wise
+/// operations.
+fn check_batch_ops(a: &[BaseElement], b: &[BaseElement], scalar: BaseElement) {
+    let raw = |values: &[BaseElement]| values.iter().map(|v| v.0).collect::<Vec<_>>();
+
+    let (mut actual, mut expected) = (a.to_vec(), a.to_vec());
+    BaseElement::batch_add(&mut actual, b);
+    batch::add(&mut expected, b);
+    assert_eq!(raw(&expected), raw(&actual), "batch_add");
+
+    let (mut actual, mut expected) = (a.to_vec(), a.to_vec());
+    BaseElement::batch_mul(&mut actual, b);
+    batch::mul(&mut expected, b);
+    assert_eq!(raw(&expected), raw(&actual), "batch_mul");
+
+    let (mut actual, mut expected) = (a.to_vec(), a.to_vec());
+    BaseElement::batch_mul_acc(&mut actual, b, scalar);
+    batch::mul_acc(&mut expected, b, scalar);
+    assert_eq!(raw(&expected), raw(&actual), "batch_mul_acc");
+
+    let (mut actual_lo, mut expected_lo) = (a.to_vec(), a.to_vec());
+    let (mut actual_hi, mut expected_hi) = (b.to_vec(), b.to_vec());
+    BaseElement::batch_butterfly(&mut actual_lo, &mut actual_hi);
+    batch::butterfly(&mut expected_lo, &mut expected_hi);
+    assert_eq!(raw(&expected_lo), raw(&actual_lo), "batch_butterfly");
+    assert_eq!(raw(&expected_hi), raw(&actual_hi), "batch_butterfly");
+
+    let (mut actual_lo, mut expected_lo) = (a.to_vec(), a.to_vec());
+    let (mut actual_hi, mut expected_hi) = (b.to_vec(), b.to_vec());
+    BaseElement::batch_butterfly_twiddle(&mut actual_lo, &mut actual_hi, scalar);
+    batch::butterfly_twiddle(&mut expected_lo, &mut expected_hi, scalar);
+    assert_eq!(raw(&expected_lo), raw(&actual_lo), "batch_butterfly_twiddle");
+    assert_eq!(raw(&expected_hi), raw(&actual_hi), "batch_butterfly_twiddle");
+}
+
+/// Returns a strategy for generating pairs of vectors of the same length with internal values
+/// in [0, 2M); lengths are chosen so that most of them are not multiples of the vector width.
+fn raw_vector_pairs() -> impl Strategy<Value = (Vec<u64>, Vec<u64>)> {
+    (0usize..71)
+        .prop_flat_map(|n| (prop::collection::vec(0..2 * M, n), prop::collection::vec(0..2 * M, n)))
+}
+
 // RANDOMIZED TESTS
 // ================================================================================================
 
 proptest! {
+    // BATCH OPERATIONS
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn batch_ops_proptest((a, b) in raw_vector_pairs(), scalar in 0..2 * M) {
+        let a = a.into_iter().map(BaseElement).collect::<Vec<_>>();
+        let b = b.into_iter().map(BaseElement).collect::<Vec<_>>();
+        check_batch_ops(&a, &b, BaseElement(scalar));
+    }
+
 
     #[test]
     fn add_proptest(a in any::<u64>(), b in any::<u64>()) {
@@ -369,4 +486,27 @@ proptest! {
         };
         prop_assert_eq!(expected, a * b);
     }
+
+    // CONSTANT-TIME OPERATIONS
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn exp_ct_proptest(a in any::<u64>(), b in any::<u64>()) {
+        let a = BaseElement::new(a);
+        prop_assert_eq!(a.exp(b), a.exp_ct(b));
+
+        let a = QuadExtension::<BaseElement>::new(a, BaseElement::new(b));
+        prop_assert_eq!(a.exp(b), a.exp_ct(b));
+    }
+
+    #[test]
+    fn inv_ct_proptest(a0 in any::<u64>(), a1 in any::<u64>(), a2 in any::<u64>()) {
+        let a = BaseElement::new(a0);
+        prop_assert_eq!(a.inv(), a.inv_ct());
+
+        let a = QuadExtension::<BaseElement>::new(BaseElement::new(a0), BaseElement::new(a1));
+        prop_assert_eq!(a.inv(), a.inv_ct());
+
+        let a = CubeExtension::<BaseElement>::new(BaseElement::new(a0), BaseElement::new(a1), BaseElement::new(a2));
+        prop_assert_eq!(a.inv(), a.inv_ct());
+    }
 }
@@ -73,6 +73,16 @@ impl BaseElement {
         Self(mont_red_cst((value as u128) * (R2 as u128)))
     }
 
+    /// Creates a new field element from the provided bytes interpreted as a u64 value in
+    /// little-endian byte order. If the value is greater than or equal to the field modulus,
+    /// modular reduction is performed.
+    ///
+    /// Note that element deserialization rejects non-canonical encodings; this function should
+    /// be used only when modular reduction of arbitrary bytes is actually desired.
+    pub const fn from_le_bytes_reduce(bytes: [u8; ELEMENT_BYTES]) -> Self {
+        Self::new(u64::from_le_bytes(bytes))
+    }
+
     /// Returns a new field element from the provided 'value'. Assumes that 'value' is already
     /// in canonical Montgomery form.
     pub const fn from_mont(value: u64) -> BaseElement {
@@ -188,6 +198,32 @@ impl FieldElement for BaseElement {
         Self(self.0)
     }
 
+    #[inline]
+    fn conditional_select(a: Self, b: Self, choice: bool) -> Self {
+        let mask = 0u64.wrapping_sub(choice as u64);
+        Self(a.0 ^ (mask & (a.0 ^ b.0)))
+    }
+
+    #[inline]
+    fn exp_ct(self, power: Self::PositiveInteger) -> Self {
+        // exp() processes all bits of the power and selects intermediate results using masks,
+        // and thus, it already runs in constant time
+        self.exp(power)
+    }
+
+    #[inline]
+    fn inv_ct(self) -> Self {
+        // inv() computes base^(M - 2) using a fixed addition chain
+        self.inv()
+    }
+
+    // INTEGER CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn from_u64(value: u64) -> Self {
+        Self::new(value)
+    }
+
     // BASE ELEMENT CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -8,7 +8,7 @@ use proptest::prelude::*;
 use rand_utils::rand_value;
 
 use super::{BaseElement, DeserializationError, FieldElement, Serializable, StarkField, M};
-use crate::field::{CubeExtension, ExtensionOf, QuadExtension};
+use crate::field::{tests as field_tests, CubeExtension, ExtensionOf, QuadExtension, ToElements};
 
 // MANUAL TESTS
 // ================================================================================================
@@ -68,7 +68,7 @@ fn mul() {
     assert_eq!(BaseElement::new(m - 2), t * BaseElement::from(2u8));
     assert_eq!(BaseElement::new(m - 4), t * BaseElement::from(4u8));
 
-    let t = (m + 1) / 2;
+    let t = m.div_ceil(2);
     assert_eq!(BaseElement::ONE, BaseElement::new(t) * BaseElement::from(2u8));
 }
 
@@ -108,6 +108,27 @@ fn inv() {
     assert_eq!(BaseElement::ZERO, BaseElement::inv(BaseElement::ZERO));
 }
 
+#[test]
+fn constant_time_ops() {
+    // exponentiation edge cases
+    let r: BaseElement = rand_value();
+    assert_eq!(BaseElement::ONE, r.exp_ct(0));
+    assert_eq!(BaseElement::ONE, BaseElement::ZERO.exp_ct(0));
+    assert_eq!(BaseElement::ZERO, BaseElement::ZERO.exp_ct(5));
+    assert_eq!(r, r.exp_ct(1));
+    assert_eq!(r.exp(u64::MAX), r.exp_ct(u64::MAX));
+
+    // inversion of ZERO
+    assert_eq!(BaseElement::ZERO, BaseElement::ZERO.inv_ct());
+    assert_eq!(QuadExtension::<BaseElement>::ZERO, QuadExtension::<BaseElement>::ZERO.inv_ct());
+    assert_eq!(CubeExtension::<BaseElement>::ZERO, CubeExtension::<BaseElement>::ZERO.inv_ct());
+
+    // conditional selection
+    let s: BaseElement = rand_value();
+    assert_eq!(r, BaseElement::conditional_select(r, s, false));
+    assert_eq!(s, BaseElement::conditional_select(r, s, true));
+}
+
 #[test]
 fn element_as_int() {
     let v = u64::MAX;
@@ -149,6 +170,20 @@ fn get_root_of_unity() {
 // SERIALIZATION AND DESERIALIZATION
 // ------------------------------------------------------------------------------------------------
 
+#[test]
+fn read_non_canonical_element() {
+    field_tests::check_non_canonical_encodings::<BaseElement>();
+    field_tests::check_non_canonical_extension_encodings::<QuadExtension<BaseElement>>();
+
+    // values greater than or equal to the modulus can still be reduced explicitly
+    for (value, reduced) in [(M, 0), (M + 5, 5)] {
+        assert_eq!(
+            BaseElement::new(reduced),
+            BaseElement::from_le_bytes_reduce(value.to_le_bytes())
+        );
+    }
+}
+
 #[test]
 fn try_from_slice() {
     let bytes = vec![1, 0, 0, 0, 0, 0, 0, 0];
@@ -169,6 +204,13 @@ fn try_from_slice() {
     assert!(result.is_err());
 }
 
+#[test]
+fn int_and_byte_conversions() {
+    field_tests::check_int_and_byte_conversions::<BaseElement>();
+    field_tests::check_extension_conversions::<QuadExtension<BaseElement>>();
+    field_tests::check_extension_conversions::<CubeExtension<BaseElement>>();
+}
+
 #[test]
 fn elements_as_bytes() {
     let source = vec![
@@ -214,6 +256,35 @@ fn bytes_as_elements() {
     assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
 }
 
+#[test]
+fn integers_to_elements() {
+    assert_eq!(vec![BaseElement::new(7)], ToElements::<BaseElement>::to_elements(&7u8));
+    assert_eq!(vec![BaseElement::new(7)], ToElements::<BaseElement>::to_elements(&7u16));
+    assert_eq!(vec![BaseElement::new(7)], ToElements::<BaseElement>::to_elements(&7u32));
+
+    let value = 0x0000_0003_ffff_ffff_u64;
+    let expected = vec![BaseElement::new(0xffff_ffff), BaseElement::new(3)];
+    assert_eq!(expected, ToElements::<BaseElement>::to_elements(&value));
+
+    let value = (4u128 << 96) | (3u128 << 64) | (2u128 << 32) | 1;
+    let expected = vec![
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+        BaseElement::new(4),
+    ];
+    assert_eq!(expected, ToElements::<BaseElement>::to_elements(&value));
+}
+
+#[test]
+fn tuples_to_elements() {
+    let a = BaseElement::new(1);
+    let b = BaseElement::new(2);
+    assert_eq!(vec![a, b], (a, b).to_elements());
+    assert_eq!(vec![a, b, BaseElement::new(3)], (a, b, 3u32).to_elements());
+    assert_eq!(vec![b, a, BaseElement::new(3), b], (b, a, 3u8, (b,)).to_elements());
+}
+
 // QUADRATIC EXTENSION
 // ------------------------------------------------------------------------------------------------
 #[test]
@@ -489,4 +560,27 @@ proptest! {
 
         prop_assert_eq!(expected, a.square());
     }
+
+    // CONSTANT-TIME OPERATIONS
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn exp_ct_proptest(a in any::<u64>(), b in any::<u64>()) {
+        let a = BaseElement::new(a);
+        prop_assert_eq!(a.exp(b), a.exp_ct(b));
+
+        let a = QuadExtension::<BaseElement>::new(a, BaseElement::new(b));
+        prop_assert_eq!(a.exp(b), a.exp_ct(b));
+    }
+
+    #[test]
+    fn inv_ct_proptest(a0 in any::<u64>(), a1 in any::<u64>(), a2 in any::<u64>()) {
+        let a = BaseElement::new(a0);
+        prop_assert_eq!(a.inv(), a.inv_ct());
+
+        let a = QuadExtension::<BaseElement>::new(BaseElement::new(a0), BaseElement::new(a1));
+        prop_assert_eq!(a.inv(), a.inv_ct());
+
+        let a = CubeExtension::<BaseElement>::new(BaseElement::new(a0), BaseElement::new(a1), BaseElement::new(a2));
+        prop_assert_eq!(a.inv(), a.inv_ct());
+    }
 }
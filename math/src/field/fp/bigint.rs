@@ -0,0 +1,208 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter},
+    ops::{BitAnd, Shl, Shr, ShrAssign},
+};
+
+// BIG INTEGER
+// ================================================================================================
+
+/// An unsigned integer consisting of `N` 64-bit limbs in little-endian order.
+///
+/// This type is used as [FieldElement::PositiveInteger](crate::FieldElement::PositiveInteger)
+/// for fields defined via [Fp](super::Fp), and thus, it implements only the operations required
+/// for exponentiation and for describing the field modulus.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BigInt<const N: usize>(pub [u64; N]);
+
+impl<const N: usize> BigInt<N> {
+    /// Integer zero.
+    pub const ZERO: Self = Self([0; N]);
+
+    /// Integer one.
+    pub const ONE: Self = Self::from_u64(1);
+
+    /// Returns a new integer consisting of the provided limbs in little-endian order.
+    pub const fn new(limbs: [u64; N]) -> Self {
+        Self(limbs)
+    }
+
+    /// Returns a new integer with the specified value.
+    pub const fn from_u64(value: u64) -> Self {
+        let mut limbs = [0; N];
+        limbs[0] = value;
+        Self(limbs)
+    }
+
+    /// Returns the number of bits needed to represent this integer.
+    pub const fn num_bits(&self) -> u32 {
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            if self.0[i] != 0 {
+                return (i as u32 + 1) * 64 - self.0[i].leading_zeros();
+            }
+        }
+        0
+    }
+
+    /// Returns true if the bit at the specified position is set.
+    pub const fn bit(&self, i: u32) -> bool {
+        let limb = (i / 64) as usize;
+        limb < N && (self.0[limb] >> (i % 64)) & 1 == 1
+    }
+
+    /// Returns the little-endian byte representation of this integer.
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        self.0.iter().flat_map(|limb| limb.to_le_bytes()).collect()
+    }
+
+    /// Returns an integer encoded in the provided bytes in little-endian order, or None if the
+    /// number of bytes is not `8 * N`.
+    pub fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != N * 8 {
+            return None;
+        }
+        let mut limbs = [0; N];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().expect("chunk of 8 bytes"));
+        }
+        Some(Self(limbs))
+    }
+}
+
+// COMPARISONS
+// ================================================================================================
+
+impl<const N: usize> Ord for BigInt<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl<const N: usize> PartialOrd for BigInt<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// BIT OPERATIONS
+// ================================================================================================
+
+impl<const N: usize> Shl<u32> for BigInt<N> {
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Self {
+        let (limb_shift, bit_shift) = ((rhs / 64) as usize, rhs % 64);
+        let mut result = [0; N];
+        for (i, limb) in result.iter_mut().enumerate().skip(limb_shift) {
+            *limb = self.0[i - limb_shift] << bit_shift;
+            if bit_shift != 0 && i > limb_shift {
+                *limb |= self.0[i - limb_shift - 1] >> (64 - bit_shift);
+            }
+        }
+        Self(result)
+    }
+}
+
+impl<const N: usize> Shr<u32> for BigInt<N> {
+    type Output = Self;
+
+    fn shr(self, rhs: u32) -> Self {
+        let (limb_shift, bit_shift) = ((rhs / 64) as usize, rhs % 64);
+        let mut result = [0; N];
+        for (i, limb) in result.iter_mut().enumerate().take(N.saturating_sub(limb_shift)) {
+            *limb = self.0[i + limb_shift] >> bit_shift;
+            if bit_shift != 0 && i + limb_shift + 1 < N {
+                *limb |= self.0[i + limb_shift + 1] << (64 - bit_shift);
+            }
+        }
+        Self(result)
+    }
+}
+
+impl<const N: usize> ShrAssign for BigInt<N> {
+    fn shr_assign(&mut self, rhs: Self) {
+        // shifting by 2^32 or more bits clears all limbs, same as shifting by u32::MAX bits
+        let shift = match rhs.0[1..].iter().all(|&limb| limb == 0) {
+            true => u32::try_from(rhs.0[0]).unwrap_or(u32::MAX),
+            false => u32::MAX,
+        };
+        *self = *self >> shift;
+    }
+}
+
+impl<const N: usize> BitAnd for BigInt<N> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        let mut result = self.0;
+        for (a, b) in result.iter_mut().zip(rhs.0) {
+            *a &= b;
+        }
+        Self(result)
+    }
+}
+
+// TYPE CONVERSIONS
+// ================================================================================================
+
+impl<const N: usize> From<u32> for BigInt<N> {
+    fn from(value: u32) -> Self {
+        Self::from_u64(value as u64)
+    }
+}
+
+impl<const N: usize> From<u64> for BigInt<N> {
+    fn from(value: u64) -> Self {
+        Self::from_u64(value)
+    }
+}
+
+// FORMATTING
+// ================================================================================================
+
+impl<const N: usize> Debug for BigInt<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl<const N: usize> Display for BigInt<N> {
+    /// Formats this integer in decimal notation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        // repeatedly divide the integer by 10^19 (the largest power of 10 which fits into u64)
+        // and collect the remainders as chunks of decimal digits from the least significant one
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+        let mut value = self.0;
+        let mut chunks = Vec::new();
+        loop {
+            let mut remainder = 0u128;
+            for limb in value.iter_mut().rev() {
+                let acc = (remainder << 64) | *limb as u128;
+                *limb = (acc / CHUNK as u128) as u64;
+                remainder = acc % CHUNK as u128;
+            }
+            chunks.push(remainder as u64);
+            if value.iter().all(|&limb| limb == 0) {
+                break;
+            }
+        }
+
+        let mut result = String::new();
+        for (i, chunk) in chunks.iter().rev().enumerate() {
+            match i {
+                0 => result.push_str(&format!("{chunk}")),
+                _ => result.push_str(&format!("{chunk:019}")),
+            }
+        }
+        f.pad_integral(true, "", &result)
+    }
+}
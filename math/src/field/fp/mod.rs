@@ -0,0 +1,705 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! A generic implementation of prime fields parameterized by their modulus.
+//!
+//! A prime field is defined by implementing [FpConfig] trait for a marker type; the field
+//! element type is then [Fp] instantiated with this marker type and the number of 64-bit limbs
+//! needed to represent the modulus. For example, the [f252](crate::fields::f252) field is defined
+//! as `Fp<Stark252Config, 4>`.
+//!
+//! All operations are implemented using Montgomery arithmetic over `N` 64-bit limbs, and all
+//! constants needed for this (e.g., R^2 mod p) are derived from the modulus at compile time.
+//! Addition, subtraction, multiplication, and conditional selection do not branch on the values
+//! of their operands.
+//!
+//! The generic implementation has the following limitations:
+//! * The modulus must be an odd prime, and its most significant limb must not be zero (i.e., `N`
+//!   must be the smallest number of limbs which can hold the modulus).
+//! * Arithmetic is implemented without any field-specific optimizations, and thus, fields with
+//!   moduli of 128 bits or less are likely to be much faster when implemented directly.
+//! * Quadratic and cubic extensions of fields defined this way are not supported; the fields
+//!   should therefore be big enough to provide the desired security level on their own.
+//! * Random elements can be drawn via [Randomizable] only for fields with at most 4 limbs.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{Debug, Display, Formatter},
+    marker::PhantomData,
+    mem,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    slice,
+};
+
+use utils::{
+    AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError, Randomizable,
+    Serializable,
+};
+
+use super::{ExtensibleField, FieldElement, StarkField};
+
+mod bigint;
+pub use bigint::BigInt;
+
+#[cfg(test)]
+mod tests;
+
+// FIELD CONFIGURATION
+// ================================================================================================
+
+/// Defines parameters of a prime field with a modulus consisting of `N` 64-bit limbs.
+///
+/// All values are specified in canonical (i.e., non-Montgomery) form as limbs in little-endian
+/// order.
+pub trait FpConfig<const N: usize>: 'static {
+    /// Field modulus; it must be an odd prime with a non-zero most significant limb.
+    const MODULUS: [u64; N];
+
+    /// A generator of the multiplicative group of the field.
+    const GENERATOR: [u64; N];
+
+    /// The largest `s` such that 2^s divides `MODULUS - 1`.
+    const TWO_ADICITY: u32;
+
+    /// A root of unity of order 2^`TWO_ADICITY`; this is usually computed as
+    /// `GENERATOR^((MODULUS - 1) / 2^TWO_ADICITY)`.
+    const TWO_ADIC_ROOT_OF_UNITY: [u64; N];
+}
+
+// FIELD ELEMENT
+// ================================================================================================
+
+/// Represents an element of the prime field defined by `P`.
+///
+/// Internal values are stored in Montgomery representation as `N` 64-bit limbs in little-endian
+/// order and are always in the range [0, p).
+#[repr(transparent)]
+pub struct Fp<P: FpConfig<N>, const N: usize> {
+    limbs: [u64; N],
+    _config: PhantomData<fn() -> P>,
+}
+
+impl<P: FpConfig<N>, const N: usize> Fp<P, N> {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// -p^{-1} mod 2^64; this is used during element multiplication.
+    const INV: u64 = compute_inv(P::MODULUS[0]);
+
+    /// 2^(64 * N) mod p; this is the Montgomery representation of one.
+    const R: [u64; N] = compute_r(&P::MODULUS, Self::MODULUS_BITS);
+
+    /// 2^(128 * N) mod p; this is used for conversion of elements into Montgomery representation.
+    const R2: [u64; N] = double_n(Self::R, &P::MODULUS, 64 * N as u32);
+
+    /// p - 2; this is the exponent used for element inversion.
+    const INV_POWER: BigInt<N> = BigInt(sub_limbs(&P::MODULUS, &BigInt::<N>::from_u64(2).0).0);
+
+    /// Number of bits in the field modulus.
+    const MODULUS_BITS: u32 = {
+        assert!(P::MODULUS[N - 1] != 0, "most significant limb of the modulus must not be zero");
+        BigInt(P::MODULUS).num_bits()
+    };
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new field element from the provided `value`; if the value is greater than or
+    /// equal to the field modulus, modular reduction is performed. The value is converted into
+    /// Montgomery representation.
+    pub const fn new(value: BigInt<N>) -> Self {
+        // the product of any value smaller than 2^(64 * N) and R2 reduces to [0, p) range
+        Self::from_mont(mont_mul(&value.0, &Self::R2, &P::MODULUS, Self::INV))
+    }
+
+    /// Creates a new field element from the provided `value` interpreted as an integer.
+    pub const fn from_u64(value: u64) -> Self {
+        Self::new(BigInt::from_u64(value))
+    }
+
+    const fn from_mont(limbs: [u64; N]) -> Self {
+        Self { limbs, _config: PhantomData }
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> FieldElement for Fp<P, N> {
+    type PositiveInteger = BigInt<N>;
+    type BaseField = Self;
+
+    const EXTENSION_DEGREE: usize = 1;
+
+    const ZERO: Self = Self::from_mont([0; N]);
+    const ONE: Self = Self::from_mont(Self::R);
+
+    const ELEMENT_BYTES: usize = N * 8;
+    const IS_CANONICAL: bool = false;
+
+    // ALGEBRA
+    // --------------------------------------------------------------------------------------------
+
+    #[inline]
+    fn double(self) -> Self {
+        self + self
+    }
+
+    fn exp(self, power: Self::PositiveInteger) -> Self {
+        if power == BigInt::ZERO {
+            return Self::ONE;
+        } else if self == Self::ZERO {
+            return Self::ZERO;
+        }
+
+        let mut r = Self::ONE;
+        for i in (0..power.num_bits()).rev() {
+            r = r.square();
+            if power.bit(i) {
+                r *= self;
+            }
+        }
+
+        r
+    }
+
+    fn inv(self) -> Self {
+        // by Fermat's little theorem, x^(p - 2) = x^{-1}; this also maps zero to zero
+        self.exp(Self::INV_POWER)
+    }
+
+    fn conjugate(&self) -> Self {
+        *self
+    }
+
+    #[inline]
+    fn conditional_select(a: Self, b: Self, choice: bool) -> Self {
+        Self::from_mont(select(&a.limbs, &b.limbs, choice))
+    }
+
+    fn inv_ct(self) -> Self {
+        self.exp_ct(Self::INV_POWER)
+    }
+
+    // INTEGER CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn from_u64(value: u64) -> Self {
+        Self::new(BigInt::from_u64(value))
+    }
+
+    // BASE ELEMENT CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn base_element(&self, i: usize) -> Self::BaseField {
+        match i {
+            0 => *self,
+            _ => panic!("element index must be 0, but was {i}"),
+        }
+    }
+
+    fn slice_as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
+        elements
+    }
+
+    fn slice_from_base_elements(elements: &[Self::BaseField]) -> &[Self] {
+        elements
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    fn elements_as_bytes(elements: &[Self]) -> &[u8] {
+        // TODO: take endianness into account
+        let p = elements.as_ptr();
+        let len = elements.len() * Self::ELEMENT_BYTES;
+        unsafe { slice::from_raw_parts(p as *const u8, len) }
+    }
+
+    unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError> {
+        if bytes.len() % Self::ELEMENT_BYTES != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of bytes ({}) does not divide into whole number of field elements",
+                bytes.len(),
+            )));
+        }
+
+        let p = bytes.as_ptr();
+        let len = bytes.len() / Self::ELEMENT_BYTES;
+
+        if (p as usize) % mem::align_of::<u64>() != 0 {
+            return Err(DeserializationError::InvalidValue(
+                "slice memory alignment is not valid for this field element type".to_string(),
+            ));
+        }
+
+        Ok(slice::from_raw_parts(p as *const Self, len))
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> StarkField for Fp<P, N> {
+    const MODULUS: Self::PositiveInteger = BigInt(P::MODULUS);
+    const MODULUS_BITS: u32 = Self::MODULUS_BITS;
+    const GENERATOR: Self = Self::new(BigInt(P::GENERATOR));
+    const TWO_ADICITY: u32 = P::TWO_ADICITY;
+    const TWO_ADIC_ROOT_OF_UNITY: Self = Self::new(BigInt(P::TWO_ADIC_ROOT_OF_UNITY));
+
+    fn get_modulus_le_bytes() -> Vec<u8> {
+        Self::MODULUS.to_le_bytes()
+    }
+
+    #[inline]
+    fn as_int(&self) -> Self::PositiveInteger {
+        // convert from Montgomery representation by multiplying by 1
+        BigInt(mont_mul(&self.limbs, &BigInt::<N>::ONE.0, &P::MODULUS, Self::INV))
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Randomizable for Fp<P, N> {
+    const VALUE_SIZE: usize = Self::ELEMENT_BYTES;
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        // bits above the size of the modulus are cleared so that at least half of all random
+        // byte strings map to a valid element; the result remains uniformly distributed
+        let mut value = BigInt::from_le_bytes(bytes)?;
+        let num_bits = Self::MODULUS_BITS - 64 * (N as u32 - 1);
+        value.0[N - 1] &= u64::MAX >> (64 - num_bits);
+        Self::try_from(value).ok()
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Debug for Fp<P, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Display for Fp<P, N> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.as_int())
+    }
+}
+
+// TRAIT IMPLEMENTATIONS
+// ================================================================================================
+
+impl<P: FpConfig<N>, const N: usize> Clone for Fp<P, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Copy for Fp<P, N> {}
+
+impl<P: FpConfig<N>, const N: usize> Default for Fp<P, N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> PartialEq for Fp<P, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // internal values are always in [0, p) range, and thus, can be compared directly
+        self.limbs == other.limbs
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Eq for Fp<P, N> {}
+
+// OVERLOADED OPERATORS
+// ================================================================================================
+
+impl<P: FpConfig<N>, const N: usize> Add for Fp<P, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_mont(add(&self.limbs, &rhs.limbs, &P::MODULUS))
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> AddAssign for Fp<P, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Sub for Fp<P, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_mont(sub(&self.limbs, &rhs.limbs, &P::MODULUS))
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> SubAssign for Fp<P, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Mul for Fp<P, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_mont(mont_mul(&self.limbs, &rhs.limbs, &P::MODULUS, Self::INV))
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> MulAssign for Fp<P, N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Div for Fp<P, N> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> DivAssign for Fp<P, N> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Neg for Fp<P, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+// QUADRATIC EXTENSION
+// ================================================================================================
+
+/// Quadratic extensions of generic prime fields are not supported.
+impl<P: FpConfig<N>, const N: usize> ExtensibleField<2> for Fp<P, N> {
+    fn mul(_a: [Self; 2], _b: [Self; 2]) -> [Self; 2] {
+        unimplemented!()
+    }
+
+    fn mul_base(_a: [Self; 2], _b: Self) -> [Self; 2] {
+        unimplemented!()
+    }
+
+    fn frobenius(_x: [Self; 2]) -> [Self; 2] {
+        unimplemented!()
+    }
+
+    fn is_supported() -> bool {
+        false
+    }
+}
+
+// CUBIC EXTENSION
+// ================================================================================================
+
+/// Cubic extensions of generic prime fields are not supported.
+impl<P: FpConfig<N>, const N: usize> ExtensibleField<3> for Fp<P, N> {
+    fn mul(_a: [Self; 3], _b: [Self; 3]) -> [Self; 3] {
+        unimplemented!()
+    }
+
+    fn mul_base(_a: [Self; 3], _b: Self) -> [Self; 3] {
+        unimplemented!()
+    }
+
+    fn frobenius(_x: [Self; 3]) -> [Self; 3] {
+        unimplemented!()
+    }
+
+    fn is_supported() -> bool {
+        false
+    }
+}
+
+// TYPE CONVERSIONS
+// ================================================================================================
+
+impl<P: FpConfig<N>, const N: usize> From<u32> for Fp<P, N> {
+    /// Converts a 32-bit value into a field element; if the value is greater than or equal to
+    /// the field modulus, modular reduction is performed.
+    fn from(value: u32) -> Self {
+        Self::from_u64(value as u64)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> From<u16> for Fp<P, N> {
+    /// Converts a 16-bit value into a field element; if the value is greater than or equal to
+    /// the field modulus, modular reduction is performed.
+    fn from(value: u16) -> Self {
+        Self::from_u64(value as u64)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> From<u8> for Fp<P, N> {
+    /// Converts an 8-bit value into a field element; if the value is greater than or equal to
+    /// the field modulus, modular reduction is performed.
+    fn from(value: u8) -> Self {
+        Self::from_u64(value as u64)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> From<Fp<P, N>> for BigInt<N> {
+    fn from(value: Fp<P, N>) -> Self {
+        value.as_int()
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> TryFrom<BigInt<N>> for Fp<P, N> {
+    type Error = String;
+
+    fn try_from(value: BigInt<N>) -> Result<Self, Self::Error> {
+        if value >= Self::MODULUS {
+            Err(format!(
+                "invalid field element: value {value} is greater than or equal to the field modulus"
+            ))
+        } else {
+            Ok(Self::new(value))
+        }
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> TryFrom<u64> for Fp<P, N> {
+    type Error = String;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Self::try_from(BigInt::from_u64(value))
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> TryFrom<u128> for Fp<P, N> {
+    type Error = String;
+
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        let mut limbs = [0; N];
+        limbs[0] = value as u64;
+        match limbs.get_mut(1) {
+            Some(limb) => *limb = (value >> 64) as u64,
+            None if value >> 64 != 0 => {
+                return Err(format!(
+                "invalid field element: value {value} is greater than or equal to the field modulus"
+            ))
+            },
+            None => {},
+        }
+        Self::try_from(BigInt(limbs))
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> TryFrom<&'_ [u8]> for Fp<P, N> {
+    type Error = DeserializationError;
+
+    /// Converts a slice of bytes into a field element; returns error if the value encoded in bytes
+    /// is not a valid field element. The bytes are assumed to encode the element in the canonical
+    /// representation in little-endian byte order.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let value = BigInt::from_le_bytes(bytes).ok_or_else(|| {
+            DeserializationError::InvalidValue(format!(
+                "invalid number of bytes for a field element; expected {} bytes, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len(),
+            ))
+        })?;
+        Self::try_from(value).map_err(DeserializationError::InvalidValue)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> AsBytes for Fp<P, N> {
+    fn as_bytes(&self) -> &[u8] {
+        // TODO: take endianness into account
+        let self_ptr: *const Self = self;
+        unsafe { slice::from_raw_parts(self_ptr as *const u8, Self::ELEMENT_BYTES) }
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl<P: FpConfig<N>, const N: usize> Serializable for Fp<P, N> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // convert from Montgomery representation into canonical representation
+        target.write_bytes(&self.as_int().to_le_bytes());
+    }
+
+    fn get_size_hint(&self) -> usize {
+        Self::ELEMENT_BYTES
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Deserializable for Fp<P, N> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let bytes = source.read_slice(Self::ELEMENT_BYTES)?;
+        Self::try_from(bytes)
+    }
+}
+
+// FINITE FIELD ARITHMETIC
+// ================================================================================================
+
+/// Computes (a + b) mod m; a and b are assumed to be in [0, m).
+#[inline(always)]
+const fn add<const N: usize>(a: &[u64; N], b: &[u64; N], m: &[u64; N]) -> [u64; N] {
+    // subtract m from the sum unless this underflows; the sum itself may overflow N limbs, in
+    // which case the subtraction is always needed
+    let (sum, carry) = add_limbs(a, b);
+    let (diff, borrow) = sub_limbs(&sum, m);
+    select(&diff, &sum, borrow & !carry)
+}
+
+/// Computes (a - b) mod m; a and b are assumed to be in [0, m).
+#[inline(always)]
+const fn sub<const N: usize>(a: &[u64; N], b: &[u64; N], m: &[u64; N]) -> [u64; N] {
+    // add m to the difference if a < b; this is done without branching so that subtraction
+    // runs in constant time
+    let (diff, borrow) = sub_limbs(a, b);
+    let (sum, _) = add_limbs(&diff, m);
+    select(&diff, &sum, borrow)
+}
+
+/// Computes a * b * 2^(-64 * N) mod m using the coarsely integrated operand scanning (CIOS)
+/// method; b is assumed to be in [0, m), while a can be any value smaller than 2^(64 * N). The
+/// output is in [0, m) range.
+#[inline(always)]
+const fn mont_mul<const N: usize>(a: &[u64; N], b: &[u64; N], m: &[u64; N], inv: u64) -> [u64; N] {
+    let mut t = [0u64; N];
+    let mut t_hi = 0u64;
+
+    let mut i = 0;
+    while i < N {
+        // t = t + a * b[i]
+        let mut carry = 0;
+        let mut j = 0;
+        while j < N {
+            (t[j], carry) = mac(t[j], a[j], b[i], carry);
+            j += 1;
+        }
+        let (t_n, t_n1) = adc(t_hi, carry, 0);
+
+        // t = (t + q * m) / 2^64, where q is chosen such that the division is exact
+        let q = t[0].wrapping_mul(inv);
+        let (_, mut carry) = mac(t[0], q, m[0], 0);
+        let mut j = 1;
+        while j < N {
+            (t[j - 1], carry) = mac(t[j], q, m[j], carry);
+            j += 1;
+        }
+        let (t_last, carry) = adc(t_n, carry, 0);
+        t[N - 1] = t_last;
+        t_hi = t_n1 + carry;
+
+        i += 1;
+    }
+
+    // at this point t < 2m, and thus, at most one subtraction of m is needed
+    let (diff, borrow) = sub_limbs(&t, m);
+    select(&diff, &t, borrow & (t_hi == 0))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns `b` if `choice` is true and `a` otherwise, without branching on `choice`.
+#[inline(always)]
+const fn select<const N: usize>(a: &[u64; N], b: &[u64; N], choice: bool) -> [u64; N] {
+    let mask = 0u64.wrapping_sub(choice as u64);
+    let mut result = [0u64; N];
+    let mut i = 0;
+    while i < N {
+        result[i] = a[i] ^ (mask & (a[i] ^ b[i]));
+        i += 1;
+    }
+    result
+}
+
+/// Computes a + b and returns the sum together with a flag indicating whether it overflowed.
+#[inline(always)]
+const fn add_limbs<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut result = [0u64; N];
+    let mut carry = 0;
+    let mut i = 0;
+    while i < N {
+        (result[i], carry) = adc(a[i], b[i], carry);
+        i += 1;
+    }
+    (result, carry != 0)
+}
+
+/// Computes a - b and returns the difference together with a flag indicating whether it
+/// underflowed.
+#[inline(always)]
+const fn sub_limbs<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut result = [0u64; N];
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < N {
+        (result[i], borrow) = sbb(a[i], b[i], borrow);
+        i += 1;
+    }
+    (result, borrow != 0)
+}
+
+/// Computes a + b + carry and returns the low and the high 64 bits of the result.
+#[inline(always)]
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Computes a - b - borrow and returns the result together with the borrow (0 or 1).
+#[inline(always)]
+const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub((b as u128) + (borrow as u128));
+    (t as u64, (t >> 127) as u64)
+}
+
+/// Computes acc + a * b + carry and returns the low and the high 64 bits of the result.
+#[inline(always)]
+const fn mac(acc: u64, a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = (acc as u128) + (a as u128) * (b as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Computes (value * 2^n) mod m via repeated doubling; value is assumed to be in [0, m).
+const fn double_n<const N: usize>(value: [u64; N], m: &[u64; N], n: u32) -> [u64; N] {
+    let mut result = value;
+    let mut i = 0;
+    while i < n {
+        result = add(&result, &result, m);
+        i += 1;
+    }
+    result
+}
+
+/// Computes 2^(64 * N) mod m; `num_bits` is the number of bits in m.
+const fn compute_r<const N: usize>(m: &[u64; N], num_bits: u32) -> [u64; N] {
+    // 2^(num_bits - 1) is smaller than m, and thus, can serve as the starting point
+    let start = num_bits - 1;
+    let mut value = [0u64; N];
+    value[(start / 64) as usize] = 1 << (start % 64);
+    double_n(value, m, 64 * N as u32 - start)
+}
+
+/// Computes -m0^{-1} mod 2^64 for an odd m0.
+const fn compute_inv(m0: u64) -> u64 {
+    assert!(m0 & 1 == 1, "field modulus must be odd");
+
+    // the multiplicative group of odd integers modulo 2^64 has order 2^63, and thus,
+    // m0^(2^63 - 1) = m0^{-1} mod 2^64
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 63 {
+        inv = inv.wrapping_mul(inv);
+        inv = inv.wrapping_mul(m0);
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
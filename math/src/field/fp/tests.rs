@@ -0,0 +1,194 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::string::ToString;
+
+use num_bigint::BigUint;
+use proptest::prelude::*;
+
+use super::{BigInt, Fp, FpConfig};
+use crate::{
+    field::{f128, f62},
+    FieldElement, StarkField,
+};
+
+// TEST FIELDS
+// ================================================================================================
+
+/// The 62-bit field redefined via the generic implementation.
+struct F62Config;
+
+impl FpConfig<1> for F62Config {
+    const MODULUS: [u64; 1] = [f62::BaseElement::MODULUS];
+    const GENERATOR: [u64; 1] = [3];
+    const TWO_ADICITY: u32 = 39;
+    const TWO_ADIC_ROOT_OF_UNITY: [u64; 1] = [4421547261963328785];
+}
+
+type F62 = Fp<F62Config, 1>;
+
+/// The 128-bit field redefined via the generic implementation; the modulus of this field uses
+/// all 128 bits, and thus, sums of elements can overflow two limbs.
+struct F128Config;
+
+impl FpConfig<2> for F128Config {
+    const MODULUS: [u64; 2] = [0xffffd30000000001, 0xffffffffffffffff];
+    const GENERATOR: [u64; 2] = [3, 0];
+    const TWO_ADICITY: u32 = 40;
+    const TWO_ADIC_ROOT_OF_UNITY: [u64; 2] = [0x86b8723e1920f4aa, 0x120532e7b364080a];
+}
+
+type F128 = Fp<F128Config, 2>;
+
+// BIG INTEGER TESTS
+// ================================================================================================
+
+#[test]
+fn bigint_shifts() {
+    let value = BigInt([0x8000_0000_0000_0001, 0x1, 0x0]);
+    assert_eq!(BigInt([0x2, 0x3, 0x0]), value << 1);
+    assert_eq!(BigInt([0x0, 0x8000_0000_0000_0001, 0x1]), value << 64);
+    assert_eq!(BigInt([0x0, 0x0, 0x0]), value << 192);
+    assert_eq!(BigInt([0xc000_0000_0000_0000, 0x0, 0x0]), value >> 1);
+    assert_eq!(BigInt([0x1, 0x0, 0x0]), value >> 64);
+    assert_eq!(BigInt([0x0, 0x0, 0x0]), value >> 65);
+    assert_eq!(BigInt([0x0, 0x0, 0x0]), value >> 1000);
+
+    let mut shifted = value;
+    shifted >>= BigInt::from(64u32);
+    assert_eq!(value >> 64, shifted);
+    shifted >>= BigInt([0, 1, 0]);
+    assert_eq!(BigInt::ZERO, shifted);
+}
+
+#[test]
+fn bigint_bits() {
+    let value = BigInt([0x0, 0x5, 0x0]);
+    assert_eq!(67, value.num_bits());
+    assert_eq!(0, BigInt::<3>::ZERO.num_bits());
+    assert!(value.bit(64));
+    assert!(!value.bit(65));
+    assert!(value.bit(66));
+    assert!(!value.bit(1000));
+    assert_eq!(BigInt([0x0, 0x4, 0x0]), value & BigInt([u64::MAX, 0x6, u64::MAX]));
+}
+
+#[test]
+fn bigint_ordering() {
+    let a = BigInt([u64::MAX, 0x1]);
+    let b = BigInt([0x0, 0x2]);
+    assert!(a < b);
+    assert!(b > a);
+    assert!(a >= a);
+    assert!(BigInt::<2>::ZERO < BigInt::ONE);
+}
+
+#[test]
+fn bigint_display() {
+    let value = BigInt([0x0, 0x0, 0x0, 0x0800000000000011]);
+    let expected = BigUint::from_bytes_le(&value.to_le_bytes()).to_string();
+    assert_eq!(expected, value.to_string());
+    assert_eq!("0", BigInt::<4>::ZERO.to_string());
+    assert_eq!(
+        "10000000000000000000",
+        BigInt::<2>::from(10_000_000_000_000_000_000u64).to_string()
+    );
+}
+
+#[test]
+fn bigint_bytes() {
+    let value = BigInt([0x0102030405060708, 0x1112131415161718]);
+    let bytes = value.to_le_bytes();
+    assert_eq!(16, bytes.len());
+    assert_eq!(0x08, bytes[0]);
+    assert_eq!(0x11, bytes[15]);
+    assert_eq!(Some(value), BigInt::from_le_bytes(&bytes));
+    assert_eq!(None, BigInt::<2>::from_le_bytes(&bytes[..15]));
+}
+
+// MONTGOMERY CONSTANTS TESTS
+// ================================================================================================
+
+#[test]
+fn montgomery_constants() {
+    fn check<P: FpConfig<N>, const N: usize>() {
+        let m = BigUint::from_bytes_le(&Fp::<P, N>::get_modulus_le_bytes());
+        let to_int = |limbs: &[u64; N]| BigUint::from_bytes_le(&BigInt(*limbs).to_le_bytes());
+
+        let r = BigUint::from(2u32).pow(64 * N as u32) % &m;
+        assert_eq!(r, to_int(&Fp::<P, N>::R));
+        assert_eq!((&r * &r) % &m, to_int(&Fp::<P, N>::R2));
+
+        let inv = Fp::<P, N>::INV.wrapping_mul(P::MODULUS[0]);
+        assert_eq!(u64::MAX, inv);
+    }
+
+    check::<F62Config, 1>();
+    check::<F128Config, 2>();
+    check::<crate::fields::f252::Stark252Config, 4>();
+}
+
+#[test]
+fn modulus_bits() {
+    assert_eq!(f62::BaseElement::MODULUS_BITS, F62::MODULUS_BITS);
+    assert_eq!(f128::BaseElement::MODULUS_BITS, F128::MODULUS_BITS);
+    assert_eq!(f62::BaseElement::get_modulus_le_bytes(), F62::get_modulus_le_bytes());
+    assert_eq!(f128::BaseElement::get_modulus_le_bytes(), F128::get_modulus_le_bytes());
+}
+
+#[test]
+fn roots_of_unity() {
+    assert_eq!(
+        f62::BaseElement::TWO_ADIC_ROOT_OF_UNITY.as_int(),
+        F62::TWO_ADIC_ROOT_OF_UNITY.as_int().0[0]
+    );
+    assert_eq!(
+        f128::BaseElement::TWO_ADIC_ROOT_OF_UNITY.as_int(),
+        u128::from(F128::TWO_ADIC_ROOT_OF_UNITY.as_int().0[1]) << 64
+            | u128::from(F128::TWO_ADIC_ROOT_OF_UNITY.as_int().0[0])
+    );
+    assert_eq!(F128::ONE, F128::get_root_of_unity(40).exp(BigInt::ONE << 40));
+}
+
+// RANDOMIZED TESTS
+// ================================================================================================
+
+proptest! {
+    // 62-BIT FIELD
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn f62_ops_proptest(a in any::<u64>(), b in any::<u64>()) {
+        let (x, y) = (f62::BaseElement::new(a), f62::BaseElement::new(b));
+        let (u, v) = (F62::from_u64(a), F62::from_u64(b));
+        prop_assert_eq!(x.as_int(), u.as_int().0[0]);
+
+        prop_assert_eq!((x + y).as_int(), (u + v).as_int().0[0]);
+        prop_assert_eq!((x - y).as_int(), (u - v).as_int().0[0]);
+        prop_assert_eq!((x * y).as_int(), (u * v).as_int().0[0]);
+        prop_assert_eq!((-x).as_int(), (-u).as_int().0[0]);
+        prop_assert_eq!(x.inv().as_int(), u.inv().as_int().0[0]);
+        prop_assert_eq!(x.exp(b).as_int(), u.exp(BigInt::from(b)).as_int().0[0]);
+        prop_assert_eq!(x.exp_ct(b).as_int(), u.exp_ct(BigInt::from(b)).as_int().0[0]);
+    }
+
+    // 128-BIT FIELD
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn f128_ops_proptest(a in any::<u128>(), b in any::<u128>()) {
+        let to_u128 = |value: F128| {
+            let limbs = value.as_int().0;
+            u128::from(limbs[1]) << 64 | u128::from(limbs[0])
+        };
+        let (x, y) = (f128::BaseElement::new(a), f128::BaseElement::new(b));
+        let (u, v) = (F128::new(BigInt([a as u64, (a >> 64) as u64])), F128::new(BigInt([b as u64, (b >> 64) as u64])));
+        prop_assert_eq!(x.as_int(), to_u128(u));
+
+        prop_assert_eq!((x + y).as_int(), to_u128(u + v));
+        prop_assert_eq!((x - y).as_int(), to_u128(u - v));
+        prop_assert_eq!((x * y).as_int(), to_u128(u * v));
+        prop_assert_eq!((-x).as_int(), to_u128(-u));
+        prop_assert_eq!(x.inv().as_int(), to_u128(u.inv()));
+    }
+}
@@ -3,12 +3,23 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+mod batch;
+
 mod traits;
 pub use traits::{ExtensibleField, ExtensionOf, FieldElement, StarkField, ToElements};
 
+mod compact;
+pub use compact::CompactFieldEncoding;
+
+pub mod fp;
+
 pub mod f128;
+pub mod f252;
 pub mod f62;
 pub mod f64;
 
 mod extensions;
 pub use extensions::{CubeExtension, QuadExtension};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,116 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Field-generic test helpers shared by the tests of individual fields.
+
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+use rand_utils::rand_value;
+use utils::DeserializationError;
+
+use super::{FieldElement, StarkField};
+
+// BASE FIELD CHECKS
+// ================================================================================================
+
+/// Checks integer and byte conversions of field `B` at the boundary values 0, 1, p - 1, p, and
+/// u64::MAX, as well as for malformed byte encodings.
+pub fn check_int_and_byte_conversions<B: StarkField>() {
+    let m = modulus::<B>();
+
+    // from_u64() reduces the value by the modulus
+    for value in [0, 1, u64::MAX] {
+        assert_eq!(BigUint::from(value) % &m, to_biguint(B::from_u64(value)));
+    }
+
+    // try_from_u128() rejects values greater than or equal to the modulus
+    let mut values = vec![0, 1, u64::MAX as u128, u128::MAX];
+    if let Ok(m) = u128::try_from(&m) {
+        values.extend([m - 1, m]);
+    }
+    for value in values {
+        let result = B::try_from_u128(value);
+        assert_eq!(BigUint::from(value) < m, result.is_ok());
+        if let Ok(element) = result {
+            assert_eq!(BigUint::from(value), to_biguint(element));
+        }
+    }
+
+    // byte conversions round trip for canonical values
+    for value in [BigUint::from(0u8), BigUint::from(1u8), &m - 1u8] {
+        let bytes = to_le_bytes::<B>(&value);
+        let element = B::try_from_le_bytes(&bytes).unwrap();
+        assert_eq!(bytes, element.to_le_bytes());
+        assert_eq!(value, to_biguint(element));
+    }
+
+    // encodings of the wrong length are rejected
+    assert!(B::try_from_le_bytes(&vec![1; B::ELEMENT_BYTES - 1]).is_err());
+    assert!(B::try_from_le_bytes(&vec![1; B::ELEMENT_BYTES + 1]).is_err());
+
+    check_non_canonical_encodings::<B>();
+}
+
+/// Checks that encodings of values greater than or equal to the modulus of field `B` are
+/// rejected instead of being reduced.
+pub fn check_non_canonical_encodings<B: StarkField>() {
+    let m = modulus::<B>();
+    let max = (BigUint::from(1u8) << (8 * B::ELEMENT_BYTES)) - 1u8;
+    for value in [m.clone(), &m + 5u8, max] {
+        let bytes = to_le_bytes::<B>(&value);
+        assert!(B::try_from_le_bytes(&bytes).is_err());
+        let result = B::read_from_bytes(&bytes);
+        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+    }
+}
+
+// EXTENSION FIELD CHECKS
+// ================================================================================================
+
+/// Checks byte and integer conversions of extension field `E`, which must be performed
+/// coordinate-wise on the base field.
+pub fn check_extension_conversions<E: FieldElement>() {
+    let value = E::from_u64(u64::MAX);
+    assert_eq!(E::from(E::BaseField::from_u64(u64::MAX)), value);
+
+    // byte conversions round trip
+    let element: E = rand_value();
+    let bytes = element.to_le_bytes();
+    assert_eq!(E::ELEMENT_BYTES, bytes.len());
+    assert_eq!(Ok(element), E::try_from_le_bytes(&bytes));
+    assert!(E::try_from_le_bytes(&bytes[..E::ELEMENT_BYTES - 1]).is_err());
+
+    check_non_canonical_extension_encodings::<E>();
+}
+
+/// Checks that encodings of extension field elements with a coordinate greater than or equal to
+/// the modulus of the base field are rejected.
+pub fn check_non_canonical_extension_encodings<E: FieldElement>() {
+    let base_bytes = E::BaseField::ELEMENT_BYTES;
+    let mut bytes = E::ONE.to_le_bytes();
+    bytes[E::ELEMENT_BYTES - base_bytes..]
+        .copy_from_slice(&to_le_bytes::<E::BaseField>(&modulus::<E::BaseField>()));
+    assert!(E::try_from_le_bytes(&bytes).is_err());
+    assert!(matches!(E::read_from_bytes(&bytes), Err(DeserializationError::InvalidValue(_))));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn modulus<B: StarkField>() -> BigUint {
+    BigUint::from_bytes_le(&B::get_modulus_le_bytes())
+}
+
+fn to_biguint<B: StarkField>(element: B) -> BigUint {
+    BigUint::from_bytes_le(&element.to_le_bytes())
+}
+
+fn to_le_bytes<B: StarkField>(value: &BigUint) -> Vec<u8> {
+    let mut bytes = value.to_bytes_le();
+    assert!(bytes.len() <= B::ELEMENT_BYTES, "value does not fit into an element encoding");
+    bytes.resize(B::ELEMENT_BYTES, 0);
+    bytes
+}
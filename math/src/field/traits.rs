@@ -14,6 +14,8 @@ use core::{
 
 use utils::{AsBytes, Deserializable, DeserializationError, Randomizable, Serializable};
 
+use super::batch;
+
 // FIELD ELEMENT
 // ================================================================================================
 /// Defines an element in a finite field.
@@ -157,6 +159,75 @@ pub trait FieldElement:
     #[must_use]
     fn conjugate(&self) -> Self;
 
+    // CONSTANT-TIME OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns `b` if `choice` is true and `a` otherwise, without branching on `choice`.
+    ///
+    /// The default implementation selects the result arithmetically; fields are expected to
+    /// override it with a selection based on bit masks over their internal representation.
+    #[must_use]
+    fn conditional_select(a: Self, b: Self, choice: bool) -> Self {
+        a + (b - a) * Self::from(choice as u8)
+    }
+
+    /// Exponentiates this field element by `power` parameter in constant time.
+    ///
+    /// The exponentiation is performed using a Montgomery ladder over all bits of
+    /// `Self::PositiveInteger`, and thus, the sequence of field operations does not depend on
+    /// either this element or `power`. The time taken by this method is constant as long as basic
+    /// field operations (addition, subtraction, multiplication) and
+    /// [conditional_select()](FieldElement::conditional_select) are constant time, which is the
+    /// case for all fields provided by this crate and their extensions.
+    #[must_use]
+    fn exp_ct(self, power: Self::PositiveInteger) -> Self {
+        let int_one = Self::PositiveInteger::from(1u32);
+        let num_bits = (core::mem::size_of::<Self::PositiveInteger>() * 8) as u32;
+
+        // invariant: r1 = r0 * self
+        let mut r0 = Self::ONE;
+        let mut r1 = self;
+        for i in (0..num_bits).rev() {
+            let bit = (power >> i) & int_one == int_one;
+
+            // if the bit is set, r0 = r0 * r1 and r1 = r1^2; otherwise, r1 = r0 * r1 and
+            // r0 = r0^2; this is done by conditionally swapping r0 and r1 before and after
+            let (a, b) =
+                (Self::conditional_select(r0, r1, bit), Self::conditional_select(r1, r0, bit));
+            let (a, b) = (a.square(), a * b);
+            r0 = Self::conditional_select(a, b, bit);
+            r1 = Self::conditional_select(b, a, bit);
+        }
+
+        r0
+    }
+
+    /// Returns a multiplicative inverse of this field element computed in constant time. If this
+    /// element is ZERO, ZERO is returned.
+    ///
+    /// For prime fields, the inverse is computed via Fermat's little theorem as x^(p - 2) using a
+    /// fixed sequence of field operations; for extension fields, the inversion is reduced to a
+    /// constant-time inversion in the base field. Unlike [inv()](FieldElement::inv), this method
+    /// does not branch on the value of this element, including when it is ZERO.
+    ///
+    /// The default implementation works for any field: the inverse of x is computed as the
+    /// product of the conjugates x^p, ..., x^(p^(k - 1)) of x divided by the norm of x (which is
+    /// in the base field and is inverted as norm^(p - 2)), where k is the extension degree of the
+    /// field. All exponents are derived from the modulus of the base field only; fields provided
+    /// by this crate override this method with faster implementations.
+    #[must_use]
+    fn inv_ct(self) -> Self {
+        let mut conjugates = Self::ONE;
+        let mut conjugate = self;
+        for _ in 1..Self::EXTENSION_DEGREE {
+            conjugate = exp_by_modulus_minus(conjugate, 0);
+            conjugates *= conjugate;
+        }
+
+        let norm = self * conjugates;
+        conjugates * exp_by_modulus_minus(norm, 2)
+    }
+
     // BASE ELEMENT CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -183,6 +254,49 @@ pub trait FieldElement:
     /// Panics if the the length of the provided slice is not divisible by `Self::EXTENSION_DEGREE`.
     fn slice_from_base_elements(elements: &[Self::BaseField]) -> &[Self];
 
+    // INTEGER AND BYTE CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a field element corresponding to the provided integer.
+    ///
+    /// The value is reduced by the modulus of the base field (thus, values greater than or equal
+    /// to the modulus wrap around), and for extension fields, the value is mapped to the base
+    /// field component of the element.
+    fn from_u64(value: u64) -> Self {
+        let hi = Self::from((value >> 32) as u32);
+        let lo = Self::from(value as u32);
+        hi * Self::from(1u32 << 16).square() + lo
+    }
+
+    /// Returns the canonical representation of this element in little-endian byte order.
+    ///
+    /// The returned vector always contains exactly `Self::ELEMENT_BYTES` bytes, and it is the
+    /// same as the serialized form of this element.
+    fn to_le_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+
+    /// Returns a field element encoded in the provided bytes.
+    ///
+    /// The bytes are assumed to encode the element in the canonical representation in
+    /// little-endian byte order (i.e., as returned by [to_le_bytes()](FieldElement::to_le_bytes)).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The length of `bytes` is not equal to `Self::ELEMENT_BYTES`.
+    /// * The value encoded in `bytes` is not a valid field element (e.g., a base field component
+    ///   is greater than or equal to the field modulus).
+    fn try_from_le_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        if bytes.len() != Self::ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "a field element must be encoded in {} bytes, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len()
+            )));
+        }
+        Self::read_from_bytes(bytes)
+    }
+
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
@@ -208,6 +322,41 @@ pub trait FieldElement:
     /// This function is unsafe because it does not check whether underlying bytes represent valid
     /// field elements according to their internal representation.
     unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError>;
+
+    // BATCH OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Computes `a[i] + b[i]` for all `i` and saves the result into `a[i]`.
+    ///
+    /// Fields may override this method (as well as other batch methods) with a vectorized
+    /// implementation; the results must be identical to those of the element-wise computation,
+    /// including the internal representation of the elements. If the slices have different
+    /// lengths, only the elements of the shorter slice are processed.
+    fn batch_add(a: &mut [Self], b: &[Self]) {
+        batch::add(a, b)
+    }
+
+    /// Computes `a[i] * b[i]` for all `i` and saves the result into `a[i]`.
+    fn batch_mul(a: &mut [Self], b: &[Self]) {
+        batch::mul(a, b)
+    }
+
+    /// Computes `acc[i] + a[i] * scalar` for all `i` and saves the result into `acc[i]`.
+    fn batch_mul_acc(acc: &mut [Self], a: &[Self], scalar: Self) {
+        batch::mul_acc(acc, a, scalar)
+    }
+
+    /// Computes `(lo[i] + hi[i], lo[i] - hi[i])` for all `i` and saves the result into
+    /// `(lo[i], hi[i])`.
+    fn batch_butterfly(lo: &mut [Self], hi: &mut [Self]) {
+        batch::butterfly(lo, hi)
+    }
+
+    /// Computes `(lo[i] + hi[i] * twiddle, lo[i] - hi[i] * twiddle)` for all `i` and saves the
+    /// result into `(lo[i], hi[i])`.
+    fn batch_butterfly_twiddle(lo: &mut [Self], hi: &mut [Self], twiddle: Self::BaseField) {
+        batch::butterfly_twiddle(lo, hi, twiddle)
+    }
 }
 
 // STARK FIELD
@@ -262,6 +411,20 @@ pub trait StarkField: FieldElement<BaseField = Self> {
         Self::TWO_ADIC_ROOT_OF_UNITY.exp(power)
     }
 
+    /// Returns a field element corresponding to the provided integer.
+    ///
+    /// Unlike [from_u64()](FieldElement::from_u64), this method does not reduce the value.
+    ///
+    /// # Errors
+    /// Returns an error if the value is greater than or equal to the field modulus.
+    fn try_from_u128(value: u128) -> Result<Self, DeserializationError> {
+        Self::try_from(value).map_err(|_| {
+            DeserializationError::InvalidValue(format!(
+                "invalid field element: value {value} is greater than or equal to the field modulus"
+            ))
+        })
+    }
+
     /// Converts a slice of bytes into a field element. Pads the slice if it is smaller than the number
     /// of bytes needed to represent an element.
     ///
@@ -343,6 +506,10 @@ impl<E: FieldElement> ExtensionOf<E> for E {
 // ================================================================================================
 
 /// Defines how to convert a struct to a vector of field elements.
+///
+/// This is used, among other things, to bind public inputs of a computation to the proof
+/// transcript. Thus, the returned elements should be a canonical encoding of the struct: two
+/// equal values must always map to the same sequence of elements.
 pub trait ToElements<E: FieldElement> {
     fn to_elements(&self) -> Vec<E>;
 }
@@ -358,3 +525,85 @@ impl<E: FieldElement> ToElements<E> for E {
         vec![*self]
     }
 }
+
+impl<E: FieldElement> ToElements<E> for u8 {
+    fn to_elements(&self) -> Vec<E> {
+        vec![E::from(*self)]
+    }
+}
+
+impl<E: FieldElement> ToElements<E> for u16 {
+    fn to_elements(&self) -> Vec<E> {
+        vec![E::from(*self)]
+    }
+}
+
+impl<E: FieldElement> ToElements<E> for u32 {
+    fn to_elements(&self) -> Vec<E> {
+        vec![E::from(*self)]
+    }
+}
+
+/// Values are split into 32-bit limbs (least significant limb first) so that the encoding is the
+/// same regardless of the size of the field modulus.
+impl<E: FieldElement> ToElements<E> for u64 {
+    fn to_elements(&self) -> Vec<E> {
+        vec![E::from(*self as u32), E::from((*self >> 32) as u32)]
+    }
+}
+
+/// Values are split into 32-bit limbs (least significant limb first) so that the encoding is the
+/// same regardless of the size of the field modulus.
+impl<E: FieldElement> ToElements<E> for u128 {
+    fn to_elements(&self) -> Vec<E> {
+        (0..4).map(|i| E::from((*self >> (32 * i)) as u32)).collect()
+    }
+}
+
+/// Implements [ToElements] for tuples by concatenating the elements of each member in order.
+macro_rules! impl_to_elements_for_tuple {
+    ($($name:ident),+) => {
+        impl<E: FieldElement, $($name: ToElements<E>),+> ToElements<E> for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn to_elements(&self) -> Vec<E> {
+                let ($($name,)+) = self;
+                let mut result = Vec::new();
+                $(result.extend($name.to_elements());)+
+                result
+            }
+        }
+    };
+}
+
+impl_to_elements_for_tuple!(A);
+impl_to_elements_for_tuple!(A, B);
+impl_to_elements_for_tuple!(A, B, C);
+impl_to_elements_for_tuple!(A, B, C, D);
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns `base` raised to the power p - `subtrahend`, where p is the modulus of the base field
+/// and `subtrahend` is smaller than p.
+///
+/// The bits of the exponent are computed from the bits of p on the fly, and since the exponent
+/// does not depend on `base`, the sequence of field operations does not depend on `base` either.
+fn exp_by_modulus_minus<E: FieldElement>(base: E, subtrahend: u32) -> E {
+    let modulus = E::BaseField::MODULUS;
+    let int_one = <E::BaseField as FieldElement>::PositiveInteger::from(1u32);
+
+    let mut result = E::ONE;
+    let mut power = base;
+    let mut borrow = false;
+    for i in 0..E::BaseField::MODULUS_BITS {
+        let modulus_bit = (modulus >> i) & int_one == int_one;
+        let subtrahend_bit = i < u32::BITS && (subtrahend >> i) & 1 == 1;
+        if modulus_bit ^ subtrahend_bit ^ borrow {
+            result *= power;
+        }
+        borrow = (!modulus_bit & (subtrahend_bit | borrow)) | (subtrahend_bit & borrow);
+        power = power.square();
+    }
+
+    result
+}
@@ -16,7 +16,7 @@
 //! * Drawing random and pseudo-random elements from the field.
 //! * Computing roots of unity of a given order.
 //!
-//! Currently, there are two implementations of finite fields:
+//! Currently, there are four implementations of finite fields:
 //!
 //! * A 128-bit field with modulus 2<sup>128</sup> - 45 * 2<sup>40</sup> + 1. This field was not
 //!   chosen with any significant thought given to performance, and the implementation of most
@@ -32,6 +32,36 @@
 //!   properties. To achieve adequate security (i.e. ~100 bits), proofs must be generated in a
 //!   quadratic extension of this field. For higher levels of security, a cubic extension field
 //!   should be used.
+//! * A 252-bit field with modulus 2<sup>251</sup> + 17 * 2<sup>192</sup> + 1. This field is
+//!   defined via a generic implementation of prime fields (see [fp](crate::fields::fp)), which
+//!   can be used to define other prime fields with moduli of arbitrary size. Proofs generated in
+//!   this field can support security above 100 bits without field extensions; extensions of this
+//!   field are not supported.
+//!
+//! ## Batch operations
+//!
+//! [FieldElement] trait exposes batch operations over slices of field elements (e.g.,
+//! [FieldElement::batch_mul()]). By default these are evaluated element-by-element, but fields
+//! may provide vectorized implementations. For the 62-bit field, AVX2 instructions are used on
+//! x86-64 CPUs which support them (this is detected at runtime when `std` feature is enabled).
+//! Vectorized implementations always produce the same results as element-wise evaluation.
+//!
+//! ## Constant-time operations
+//!
+//! Addition, subtraction, negation, multiplication, and squaring in all fields provided by this
+//! crate (as well as in their quadratic and cubic extensions) do not branch on the values of
+//! their operands. In addition, [FieldElement] trait exposes the following operations which are
+//! intended for computations on secret values:
+//!
+//! * [FieldElement::conditional_select()] selects one of two elements without branching.
+//! * [FieldElement::exp_ct()] computes exponentiation using a Montgomery ladder over all bits of
+//!   the exponent type.
+//! * [FieldElement::inv_ct()] computes inversion via Fermat's little theorem (for extension
+//!   fields, the inversion is reduced to a constant-time inversion in the base field); unlike
+//!   [FieldElement::inv()], ZERO is not handled as a special case.
+//!
+//! Other operations, such as [FieldElement::exp()] and [FieldElement::inv()], may run in variable
+//! time, as may conversions to and from bytes.
 //!
 //! ## Extension fields
 //!
@@ -49,12 +79,17 @@
 //! * For [f64](crate::fields::f64) field, the polynomial is x<sup>3</sup> - x - 1.
 //! * For [f128](crate::fields::f128) field, cubic extensions are not supported.
 //!
+//! Extensions of fields defined via [fp](crate::fields::fp) (including
+//! [f252](crate::fields::f252)) are not supported.
+//!
 //! # Polynomials
 //! [Polynomials](polynom) module implements basic polynomial operations such as:
 //!
 //! * Evaluation of a polynomial at a single or multiple point.
 //! * Interpolation of a polynomial from a set of points (using
 //!   [Lagrange](https://en.wikipedia.org/wiki/Lagrange_polynomial) interpolation).
+//! * Interpolation and evaluation over arbitrary point sets which fall back onto FFT when the
+//!   points form a multiplicative subgroup.
 //! * Addition, multiplication, subtraction, and division of polynomials.
 //! * Synthetic polynomial division (using
 //!   [Ruffini's](https://en.wikipedia.org/wiki/Ruffini%27s_rule) method).
@@ -76,6 +111,7 @@
 //!   - [get_power_series_with_offset()]
 //!   - [add_in_place()]
 //!   - [mul_acc()]
+//!   - [batch_mul_acc()]
 //!   - [batch_inversion()]
 //! * `fft` module:
 //!   - [evaluate_poly()](fft::evaluate_poly())
@@ -92,21 +128,30 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod fft;
 pub mod polynom;
 
+mod errors;
+pub use errors::InterpolationError;
+
 mod field;
-pub use field::{ExtensibleField, ExtensionOf, FieldElement, StarkField, ToElements};
+pub use field::{
+    CompactFieldEncoding, ExtensibleField, ExtensionOf, FieldElement, StarkField, ToElements,
+};
 pub mod fields {
     //! Finite field implementations.
     //!
     //! This module contains concrete implementations of base STARK fields as well as extensions
     //! of these field.
 
-    pub use super::field::{f128, f62, f64, CubeExtension, QuadExtension};
+    pub use super::field::{f128, f252, f62, f64, fp, CubeExtension, QuadExtension};
 }
 
 mod utils;
 pub use crate::utils::{
-    add_in_place, batch_inversion, get_power_series, get_power_series_with_offset, mul_acc,
+    add_in_place, batch_inversion, batch_mul_acc, bytes_as_elements, get_power_series,
+    get_power_series_with_offset, mul_acc,
 };
@@ -7,7 +7,10 @@
 //!
 //! This module provides a set of function for basic polynomial operations, including:
 //! - Polynomial evaluation using Horner method.
+//! - Multipoint polynomial evaluation using subproduct trees.
 //! - Polynomial interpolation using Lagrange method.
+//! - Polynomial interpolation and evaluation over arbitrary point sets, using FFT when the points
+//!   form a multiplicative subgroup.
 //! - Polynomial addition, subtraction, multiplication, and division.
 //! - Synthetic polynomial division for efficient division by polynomials of the form
 //!   `x`^`a` - `b`.
@@ -25,15 +28,29 @@
 //! ```
 
 use alloc::vec::Vec;
-use core::mem;
+use core::{cmp, mem};
 
 use utils::group_slice_elements;
 
-use crate::{field::FieldElement, utils::batch_inversion};
+use crate::{
+    fft,
+    field::{FieldElement, StarkField},
+    utils::batch_inversion,
+    InterpolationError,
+};
+
+mod multipoint;
+use multipoint::{SubproductTree, MULTIPOINT_EVAL_THRESHOLD};
 
 #[cfg(test)]
 mod tests;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Number of powers of the evaluation point computed at a time by [eval_batch()].
+const EVAL_BATCH_CHUNK_SIZE: usize = 1024;
+
 // POLYNOMIAL EVALUATION
 // ================================================================================================
 
@@ -86,6 +103,142 @@ where
     xs.iter().map(|x| eval(p, *x)).collect()
 }
 
+/// Evaluates a batch of polynomials at a single point and returns a vector of results.
+///
+/// The result is the same as evaluating each polynomial individually via [eval()], but the
+/// powers of `x` are computed only once for all polynomials: the powers are computed in chunks,
+/// and each chunk is applied to the corresponding coefficients of all polynomials before the
+/// next chunk is computed. This way, coefficients of each polynomial are read sequentially, and
+/// the dependency chain of Horner's method (one multiplication per coefficient) is replaced with
+/// independent multiply-add operations. The polynomials may be of different lengths.
+///
+/// # Examples
+/// ```
+/// # use winter_math::polynom::*;
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement};
+/// # use rand_utils::{rand_value, rand_vector};
+/// let p1: Vec<BaseElement> = rand_vector(16);
+/// let p2: Vec<BaseElement> = rand_vector(5);
+/// let x: BaseElement = rand_value();
+///
+/// assert_eq!(vec![eval(&p1, x), eval(&p2, x)], eval_batch(&[&p1, &p2], x));
+/// ```
+pub fn eval_batch<B, E>(polys: &[&[B]], x: E) -> Vec<E>
+where
+    B: FieldElement,
+    E: FieldElement + From<B>,
+{
+    let max_len = polys.iter().map(|p| p.len()).max().unwrap_or(0);
+    let mut result = vec![E::ZERO; polys.len()];
+    let mut powers = Vec::with_capacity(cmp::min(max_len, EVAL_BATCH_CHUNK_SIZE));
+    let mut next_power = E::ONE;
+
+    for chunk_start in (0..max_len).step_by(EVAL_BATCH_CHUNK_SIZE) {
+        let chunk_end = cmp::min(chunk_start + EVAL_BATCH_CHUNK_SIZE, max_len);
+
+        // compute x^i for all i in the current chunk
+        powers.clear();
+        for _ in chunk_start..chunk_end {
+            powers.push(next_power);
+            next_power *= x;
+        }
+
+        // add contributions of the coefficients in the chunk to evaluations of all polynomials
+        for (value, poly) in result.iter_mut().zip(polys) {
+            if chunk_start >= poly.len() {
+                continue;
+            }
+            let coeffs = &poly[chunk_start..cmp::min(chunk_end, poly.len())];
+            *value += coeffs
+                .iter()
+                .zip(powers.iter())
+                .fold(E::ZERO, |acc, (&coeff, &power)| acc + E::from(coeff) * power);
+        }
+    }
+
+    result
+}
+
+/// Evaluates a polynomial at an arbitrary set of points and returns a vector of results.
+///
+/// If `xs` is a multiplicative subgroup of size 2^k listed in natural order (i.e., `xs[i] = g^i`
+/// where `g` is the root of unity of order 2^k in the base field), the polynomial is evaluated
+/// using FFT; coefficients of polynomials with more than 2^k coefficients are first reduced
+/// modulo `x^(2^k) - 1`. Otherwise, the polynomial is evaluated at each point individually using
+/// Horner's method.
+///
+/// # Examples
+/// ```
+/// # use winter_math::polynom::*;
+/// # use winter_math::{fields::{f128::BaseElement}, get_power_series, FieldElement, StarkField};
+/// # use rand_utils::rand_vector;
+/// let p: Vec<BaseElement> = rand_vector(12);
+///
+/// // arbitrary points are evaluated one by one
+/// let xs: Vec<BaseElement> = rand_vector(5);
+/// assert_eq!(eval_many(&p, &xs), eval_at_points(&p, &xs));
+///
+/// // points of a multiplicative subgroup are evaluated via FFT
+/// let xs = get_power_series(BaseElement::get_root_of_unity(3), 8);
+/// assert_eq!(eval_many(&p, &xs), eval_at_points(&p, &xs));
+/// ```
+pub fn eval_at_points<B, E>(p: &[B], xs: &[E]) -> Vec<E>
+where
+    B: FieldElement,
+    E: FieldElement + From<B>,
+{
+    if !is_subgroup(xs) {
+        return eval_many(p, xs);
+    }
+
+    // x^n = 1 for all x in a subgroup of size n, and thus, coefficient i contributes to the
+    // same evaluations as coefficient i % n
+    let mut result = vec![E::ZERO; xs.len()];
+    for (i, &coeff) in p.iter().enumerate() {
+        result[i % xs.len()] += E::from(coeff);
+    }
+
+    let twiddles = fft::get_twiddles::<E::BaseField>(xs.len());
+    fft::evaluate_poly(&mut result, &twiddles);
+    result
+}
+
+/// Evaluates a polynomial at many arbitrary points and returns a vector of results.
+///
+/// The result is the same as [eval_many()], but when the number of points reaches an internal
+/// threshold, the polynomial is evaluated using a subproduct tree: a binary tree of vanishing
+/// polynomials over the points is built, and the polynomial is reduced modulo the nodes of this
+/// tree from the root down to the leaves, where the remainders have low degree and are evaluated
+/// directly. Long divisions and multiplications are done via FFT, and thus, the evaluation
+/// requires O(n log n + m log^2 m) operations, where n is the length of the polynomial and m is
+/// the number of points, instead of O(n * m) operations needed by Horner's method.
+///
+/// The evaluation is exact, and the points may repeat.
+///
+/// # Examples
+/// ```
+/// # use winter_math::polynom::*;
+/// # use winter_math::fields::f64::BaseElement;
+/// # use rand_utils::rand_vector;
+/// let p: Vec<BaseElement> = rand_vector(2048);
+/// let mut xs: Vec<BaseElement> = rand_vector(300);
+/// xs[7] = xs[3];
+///
+/// assert_eq!(eval_many(&p, &xs), eval_at_many_points(&p, &xs));
+/// ```
+pub fn eval_at_many_points<B, E>(p: &[B], xs: &[E]) -> Vec<E>
+where
+    B: FieldElement,
+    E: FieldElement + From<B>,
+{
+    if xs.len() < MULTIPOINT_EVAL_THRESHOLD || p.len() < MULTIPOINT_EVAL_THRESHOLD {
+        return eval_many(p, xs);
+    }
+
+    let p: Vec<E> = p.iter().map(|&coeff| E::from(coeff)).collect();
+    SubproductTree::new(xs).evaluate(&p)
+}
+
 // POLYNOMIAL INTERPOLATION
 // ================================================================================================
 
@@ -215,6 +368,100 @@ where
     result
 }
 
+/// Returns a polynomial in coefficient form interpolated from an arbitrary set of X and Y
+/// coordinates.
+///
+/// If `xs` is a multiplicative subgroup of size 2^k listed in natural order (i.e., `xs[i] = g^i`
+/// where `g` is the root of unity of order 2^k in the base field), the polynomial is
+/// interpolated using FFT. Otherwise, the polynomial is built from the Lagrange basis
+/// polynomials scaled by barycentric weights, which are computed using a single batch
+/// inversion. In both cases, the length of the result is equal to the number of X coordinates.
+///
+/// Unlike [interpolate()], this function does not need to keep all Lagrange basis polynomials
+/// in memory at the same time.
+///
+/// # Errors
+/// Returns an error if:
+/// * The number of X and Y coordinates is not the same.
+/// * Any two X coordinates are the same.
+///
+/// # Examples
+/// ```
+/// # use winter_math::polynom::*;
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement, InterpolationError};
+/// # use rand_utils::rand_vector;
+/// let xs: Vec<BaseElement> = rand_vector(16);
+/// let ys: Vec<BaseElement> = rand_vector(16);
+///
+/// let p = interpolate_points(&xs, &ys).unwrap();
+/// assert_eq!(ys, eval_many(&p, &xs));
+///
+/// // a polynomial cannot pass through two different points with the same X coordinate
+/// let xs = [BaseElement::new(1), BaseElement::new(2), BaseElement::new(1)];
+/// assert_eq!(
+///     Err(InterpolationError::DuplicateXCoordinate { first: 0, second: 2 }),
+///     interpolate_points(&xs, &ys[..3])
+/// );
+/// ```
+pub fn interpolate_points<E>(xs: &[E], ys: &[E]) -> Result<Vec<E>, InterpolationError>
+where
+    E: FieldElement,
+{
+    if xs.len() != ys.len() {
+        return Err(InterpolationError::InconsistentCoordinates {
+            num_xs: xs.len(),
+            num_ys: ys.len(),
+        });
+    }
+
+    if is_subgroup(xs) {
+        let mut result = ys.to_vec();
+        let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(xs.len());
+        fft::interpolate_poly(&mut result, &inv_twiddles);
+        return Ok(result);
+    }
+
+    // compute barycentric weights w_i = 1 / prod_{j != i} (x_i - x_j); a zero difference means
+    // that the same X coordinate appears more than once
+    let mut weights = Vec::with_capacity(xs.len());
+    for (i, &x_i) in xs.iter().enumerate() {
+        let mut weight = E::ONE;
+        for (j, &x_j) in xs.iter().enumerate().filter(|&(j, _)| j != i) {
+            let diff = x_i - x_j;
+            if diff == E::ZERO {
+                return Err(InterpolationError::DuplicateXCoordinate { first: i, second: j });
+            }
+            weight *= diff;
+        }
+        weights.push(weight);
+    }
+    let weights = batch_inversion(&weights);
+
+    // accumulate y_i * w_i * Z(x) / (x - x_i), where Z(x) = prod_i (x - x_i); the quotient is
+    // computed via synthetic division into a single reusable buffer
+    let n = xs.len();
+    let roots = poly_from_roots(xs);
+    let mut basis = vec![E::ZERO; n];
+    let mut result = vec![E::ZERO; n];
+    for ((&x, &y), &weight) in xs.iter().zip(ys).zip(weights.iter()) {
+        if y == E::ZERO {
+            continue;
+        }
+
+        basis[n - 1] = roots[n];
+        for k in (0..n - 1).rev() {
+            basis[k] = roots[k + 1] + basis[k + 1] * x;
+        }
+
+        let scale = y * weight;
+        for (res, &coeff) in result.iter_mut().zip(basis.iter()) {
+            *res += coeff * scale;
+        }
+    }
+
+    Ok(result)
+}
+
 // POLYNOMIAL MATH OPERATIONS
 // ================================================================================================
 
@@ -703,7 +950,7 @@ where
 /// assert_eq!(expected_poly, poly);
 /// ```
 pub fn poly_from_roots<E: FieldElement>(xs: &[E]) -> Vec<E> {
-    let mut result = unsafe { utils::uninit_vector(xs.len() + 1) };
+    let mut result = vec![E::ZERO; xs.len() + 1];
     fill_zero_roots(xs, &mut result);
     result
 }
@@ -711,6 +958,18 @@ pub fn poly_from_roots<E: FieldElement>(xs: &[E]) -> Vec<E> {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns true if `xs` is a multiplicative subgroup of the base field with at least two elements
+/// listed in natural order.
+fn is_subgroup<E: FieldElement>(xs: &[E]) -> bool {
+    let n = xs.len();
+    if n < 2 || !n.is_power_of_two() || n.ilog2() > E::BaseField::TWO_ADICITY {
+        return false;
+    }
+
+    let g = E::from(E::BaseField::get_root_of_unity(n.ilog2()));
+    xs[0] == E::ONE && xs.windows(2).all(|pair| pair[1] == pair[0] * g)
+}
+
 fn fill_zero_roots<E: FieldElement>(xs: &[E], result: &mut [E]) {
     let mut n = result.len();
     n -= 1;
@@ -0,0 +1,216 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use super::{eval_many, mul, poly_from_roots};
+use crate::{fft, field::FieldElement, StarkField};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of points below which polynomials are evaluated using Horner's method; for polynomials
+/// of length 2^20 over the 64-bit field, subproduct trees become faster at about 100 points.
+pub(super) const MULTIPOINT_EVAL_THRESHOLD: usize = 128;
+
+/// Number of points covered by a leaf of the subproduct tree; the remainders at the leaves are
+/// evaluated using Horner's method.
+const LEAF_SIZE: usize = 16;
+
+/// Length of the shorter polynomial below which polynomials are multiplied using the schoolbook
+/// method rather than via FFT.
+const FFT_MUL_THRESHOLD: usize = 64;
+
+// SUBPRODUCT TREE
+// ================================================================================================
+
+/// A binary tree of vanishing polynomials over a set of points.
+///
+/// Each leaf is the vanishing polynomial of [LEAF_SIZE] consecutive points (the last leaf may
+/// cover fewer points), and each internal node is the product of its children; if a level has an
+/// odd number of nodes, the last node is carried over to the next level unchanged. All nodes are
+/// monic polynomials in reverse coefficient form.
+pub(super) struct SubproductTree<'a, E: FieldElement> {
+    points: &'a [E],
+    levels: Vec<Vec<Vec<E>>>,
+}
+
+impl<'a, E: FieldElement> SubproductTree<'a, E> {
+    /// Builds a subproduct tree over the specified points; the points may repeat.
+    pub fn new(points: &'a [E]) -> Self {
+        let mut levels = vec![points.chunks(LEAF_SIZE).map(poly_from_roots).collect::<Vec<_>>()];
+        while levels[levels.len() - 1].len() > 1 {
+            let next_level = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => fast_mul(left, right),
+                    [node] => node.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next_level);
+        }
+        Self { points, levels }
+    }
+
+    /// Evaluates polynomial `p` at all points of the tree by reducing `p` modulo the nodes of
+    /// the tree from the root down to the leaves.
+    pub fn evaluate(&self, p: &[E]) -> Vec<E> {
+        let mut result = Vec::with_capacity(self.points.len());
+        self.reduce(p, self.levels.len() - 1, 0, &mut result);
+        result
+    }
+
+    fn reduce(&self, p: &[E], level: usize, index: usize, result: &mut Vec<E>) {
+        let remainder = rem(p, &self.levels[level][index]);
+        if level == 0 {
+            let points = &self.points[index * LEAF_SIZE..];
+            let points = &points[..points.len().min(LEAF_SIZE)];
+            result.extend(eval_many(&remainder, points));
+            return;
+        }
+
+        // a node without a sibling was carried over from the level below
+        self.reduce(&remainder, level - 1, 2 * index, result);
+        if 2 * index + 1 < self.levels[level - 1].len() {
+            self.reduce(&remainder, level - 1, 2 * index + 1, result);
+        }
+    }
+}
+
+// POLYNOMIAL ARITHMETIC
+// ================================================================================================
+
+/// Returns the remainder of dividing `a` by a monic polynomial `b`.
+///
+/// When `b` is long enough, the dividend is reduced in blocks of `deg(b)` coefficients starting
+/// from the leading ones: for each block, the quotient is computed by multiplying the reversed
+/// block by the inverse of the reversed divisor, and the product of the quotient and the divisor
+/// is subtracted from the dividend. Both multiplications are done via FFT over a domain which is
+/// shared by all blocks, and thus, the division requires O(n log m) operations, where n is the
+/// length of `a` and m is the degree of `b`. Otherwise, the schoolbook method is used.
+fn rem<E: FieldElement>(a: &[E], b: &[E]) -> Vec<E> {
+    let m = b.len() - 1;
+    if a.len() <= m {
+        return a.to_vec();
+    }
+    let domain_size = (2 * m).next_power_of_two();
+    if m < FFT_MUL_THRESHOLD || domain_size.ilog2() > E::BaseField::TWO_ADICITY {
+        return schoolbook_rem(a, b);
+    }
+
+    let twiddles = fft::get_twiddles::<E::BaseField>(domain_size);
+    let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(domain_size);
+    let evaluate = |p: &[E]| {
+        let mut evaluations = p.to_vec();
+        evaluations.resize(domain_size, E::ZERO);
+        fft::evaluate_poly(&mut evaluations, &twiddles);
+        evaluations
+    };
+
+    // the reversed quotient of dividing a block of t leading coefficients is the product of the
+    // reversed block and the inverse of the reversed divisor modulo x^t; the inverse modulo x^t
+    // is the same as the inverse modulo x^m truncated to t coefficients
+    let rev_b: Vec<E> = b.iter().rev().copied().collect();
+    let b_evaluations = evaluate(b);
+    let rev_b_inv = inv_mod_xn(&rev_b, m);
+    let rev_b_inv_evaluations = evaluate(&rev_b_inv);
+
+    let mut r = a.to_vec();
+    while r.len() > m {
+        let t = m.min(r.len() - m);
+        let rev_block: Vec<E> = r.iter().rev().take(t).copied().collect();
+        let mut rev_q = match t == m {
+            true => {
+                multiply_evaluations(evaluate(&rev_block), &rev_b_inv_evaluations, &inv_twiddles)
+            },
+            false => multiply_evaluations(
+                evaluate(&rev_block),
+                &evaluate(&rev_b_inv[..t]),
+                &inv_twiddles,
+            ),
+        };
+        rev_q.truncate(t);
+        rev_q.reverse();
+
+        let qb = multiply_evaluations(evaluate(&rev_q), &b_evaluations, &inv_twiddles);
+        let offset = r.len() - t - m;
+        for (r, &qb) in r[offset..].iter_mut().zip(qb.iter()) {
+            *r -= qb;
+        }
+        r.truncate(r.len() - t);
+    }
+    r
+}
+
+/// Multiplies two polynomials given by their evaluations and returns the coefficients of the
+/// product.
+fn multiply_evaluations<E: FieldElement>(
+    mut a_evaluations: Vec<E>,
+    b_evaluations: &[E],
+    inv_twiddles: &[E::BaseField],
+) -> Vec<E> {
+    a_evaluations.iter_mut().zip(b_evaluations).for_each(|(a, &b)| *a *= b);
+    fft::interpolate_poly(&mut a_evaluations, inv_twiddles);
+    a_evaluations
+}
+
+/// Returns the remainder of dividing `a` by a monic polynomial `b` using long division.
+fn schoolbook_rem<E: FieldElement>(a: &[E], b: &[E]) -> Vec<E> {
+    let m = b.len() - 1;
+    let mut r = a.to_vec();
+    for i in (m..r.len()).rev() {
+        let coeff = r[i];
+        if coeff != E::ZERO {
+            for (r, &b) in r[i - m..i].iter_mut().zip(b.iter()) {
+                *r -= coeff * b;
+            }
+        }
+    }
+    r.truncate(m);
+    r
+}
+
+/// Returns the inverse of polynomial `f` modulo x^n; `f(0)` is expected to be one.
+fn inv_mod_xn<E: FieldElement>(f: &[E], n: usize) -> Vec<E> {
+    debug_assert_eq!(E::ONE, f[0]);
+
+    // each iteration of g <- g * (2 - f * g) doubles the number of correct coefficients of g
+    let mut g = vec![E::ONE];
+    let mut len = 1;
+    while len < n {
+        len = (2 * len).min(n);
+        let mut t = fast_mul(&f[..len.min(f.len())], &g);
+        t.truncate(len);
+        t.iter_mut().for_each(|c| *c = -*c);
+        t[0] += E::from(2u32);
+        g = fast_mul(&g, &t);
+        g.truncate(len);
+    }
+    g
+}
+
+/// Multiplies two polynomials, using FFT when both polynomials are long enough and the base
+/// field has a multiplicative subgroup of a sufficient size.
+fn fast_mul<E: FieldElement>(a: &[E], b: &[E]) -> Vec<E> {
+    let result_len = a.len() + b.len() - 1;
+    let n = result_len.next_power_of_two();
+    if a.len().min(b.len()) < FFT_MUL_THRESHOLD || n.ilog2() > E::BaseField::TWO_ADICITY {
+        return mul(a, b);
+    }
+
+    let twiddles = fft::get_twiddles::<E::BaseField>(n);
+    let mut a_evals = a.to_vec();
+    a_evals.resize(n, E::ZERO);
+    fft::evaluate_poly(&mut a_evals, &twiddles);
+    let mut b_evals = b.to_vec();
+    b_evals.resize(n, E::ZERO);
+    fft::evaluate_poly(&mut b_evals, &twiddles);
+
+    a_evals.iter_mut().zip(b_evals).for_each(|(a, b)| *a *= b);
+    fft::interpolate_poly(&mut a_evals, &fft::get_inv_twiddles::<E::BaseField>(n));
+    a_evals.truncate(result_len);
+    a_evals
+}
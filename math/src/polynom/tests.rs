@@ -5,10 +5,15 @@
 
 use alloc::vec::Vec;
 
+use proptest::prelude::*;
+use rand_utils::rand_vector;
+
 use super::remove_leading_zeros;
 use crate::{
-    field::{f128::BaseElement, FieldElement, StarkField},
+    fft,
+    field::{f128::BaseElement, FieldElement, QuadExtension, StarkField},
     utils::get_power_series,
+    InterpolationError,
 };
 
 #[test]
@@ -236,3 +241,193 @@ fn syn_div() {
     let result = super::syn_div(&poly, 4, root.exp(4));
     assert_eq!(poly, remove_leading_zeros(&super::mul(&result, &z_poly)));
 }
+
+#[test]
+fn interpolate_points() {
+    // ----- arbitrary points ---------------------------------------------------------------------
+    let xs: Vec<BaseElement> = rand_vector(12);
+    let ys: Vec<BaseElement> = rand_vector(12);
+    let poly = super::interpolate_points(&xs, &ys).unwrap();
+    assert_eq!(super::interpolate(&xs, &ys, false), poly);
+    assert_eq!(ys, super::eval_many(&poly, &xs));
+
+    // ----- multiplicative subgroup --------------------------------------------------------------
+    let domain = get_power_series(BaseElement::get_root_of_unity(4), 16);
+    let ys: Vec<BaseElement> = rand_vector(16);
+    let poly = super::interpolate_points(&domain, &ys).unwrap();
+    assert_eq!(super::interpolate(&domain, &ys, false), poly);
+
+    // ----- degenerate inputs --------------------------------------------------------------------
+    assert_eq!(Ok(Vec::new()), super::interpolate_points::<BaseElement>(&[], &[]));
+    assert_eq!(Ok(vec![ys[0]]), super::interpolate_points(&xs[..1], &ys[..1]));
+}
+
+#[test]
+fn interpolate_points_errors() {
+    let xs: Vec<BaseElement> = rand_vector(8);
+    let ys: Vec<BaseElement> = rand_vector(8);
+
+    assert_eq!(
+        Err(InterpolationError::InconsistentCoordinates { num_xs: 8, num_ys: 7 }),
+        super::interpolate_points(&xs, &ys[..7])
+    );
+
+    let mut duplicate_xs = xs.clone();
+    duplicate_xs[5] = duplicate_xs[2];
+    assert_eq!(
+        Err(InterpolationError::DuplicateXCoordinate { first: 2, second: 5 }),
+        super::interpolate_points(&duplicate_xs, &ys)
+    );
+
+    // a subgroup with a repeated element is not a subgroup, and must be rejected as well
+    let mut domain = get_power_series(BaseElement::get_root_of_unity(3), 8);
+    domain[7] = domain[0];
+    assert_eq!(
+        Err(InterpolationError::DuplicateXCoordinate { first: 0, second: 7 }),
+        super::interpolate_points(&domain, &ys)
+    );
+}
+
+#[test]
+fn eval_at_points() {
+    // polynomials both shorter and longer than the subgroup are evaluated correctly
+    let domain = get_power_series(BaseElement::get_root_of_unity(3), 8);
+    for num_coeffs in [3, 8, 21] {
+        let poly: Vec<BaseElement> = rand_vector(num_coeffs);
+        assert_eq!(super::eval_many(&poly, &domain), super::eval_at_points(&poly, &domain));
+    }
+    assert_eq!(
+        vec![BaseElement::ZERO; 8],
+        super::eval_at_points::<BaseElement, _>(&[], &domain)
+    );
+
+    // a subgroup listed out of order falls back onto regular evaluation
+    let poly: Vec<BaseElement> = rand_vector(5);
+    let mut domain = domain.clone();
+    domain.swap(1, 2);
+    assert_eq!(super::eval_many(&poly, &domain), super::eval_at_points(&poly, &domain));
+
+    // base field polynomials are evaluated over subgroups in an extension field
+    let root = QuadExtension::<BaseElement>::from(BaseElement::get_root_of_unity(2));
+    let domain = get_power_series(root, 4);
+    assert_eq!(super::eval_many(&poly, &domain), super::eval_at_points(&poly, &domain));
+}
+
+#[test]
+fn eval_at_many_points() {
+    let threshold = super::MULTIPOINT_EVAL_THRESHOLD;
+
+    // polynomials both shorter and much longer than the number of points, below and above the
+    // threshold
+    for (num_coeffs, num_points) in [
+        (10, 5),
+        (threshold, threshold - 1),
+        (threshold, threshold),
+        (5000, 300),
+        (300, 1000),
+    ] {
+        let poly: Vec<BaseElement> = rand_vector(num_coeffs);
+        let xs: Vec<BaseElement> = rand_vector(num_points);
+        assert_eq!(super::eval_many(&poly, &xs), super::eval_at_many_points(&poly, &xs));
+    }
+
+    // repeated points, including a point repeated across many leaves of the tree
+    let poly: Vec<BaseElement> = rand_vector(1024);
+    let mut xs: Vec<BaseElement> = rand_vector(400);
+    for i in (0..xs.len()).step_by(3) {
+        xs[i] = xs[1];
+    }
+    xs[2] = BaseElement::ZERO;
+    assert_eq!(super::eval_many(&poly, &xs), super::eval_at_many_points(&poly, &xs));
+
+    // base field polynomials are evaluated at extension field points
+    let xs: Vec<QuadExtension<BaseElement>> = rand_vector(300);
+    assert_eq!(super::eval_many(&poly, &xs), super::eval_at_many_points(&poly, &xs));
+
+    let xs: Vec<BaseElement> = rand_vector(300);
+    assert_eq!(
+        vec![BaseElement::ZERO; 300],
+        super::eval_at_many_points::<BaseElement, _>(&[], &xs)
+    );
+}
+
+#[test]
+fn eval_batch() {
+    let x: BaseElement = rand_utils::rand_value();
+
+    // polynomials of different lengths, including lengths which span multiple power chunks
+    let polys: Vec<Vec<BaseElement>> =
+        [1, 7, super::EVAL_BATCH_CHUNK_SIZE, 2 * super::EVAL_BATCH_CHUNK_SIZE + 3]
+            .into_iter()
+            .map(rand_vector)
+            .collect();
+    let mut poly_refs = polys.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    poly_refs.push(&[]);
+
+    let expected = poly_refs.iter().map(|p| super::eval(p, x)).collect::<Vec<_>>();
+    assert_eq!(expected, super::eval_batch(&poly_refs, x));
+
+    // base field polynomials are evaluated at an extension field point
+    let x: QuadExtension<BaseElement> = rand_utils::rand_value();
+    let expected = poly_refs.iter().map(|p| super::eval(p, x)).collect::<Vec<_>>();
+    assert_eq!(expected, super::eval_batch(&poly_refs, x));
+
+    assert!(super::eval_batch::<BaseElement, BaseElement>(&[], x.base_element(0)).is_empty());
+}
+
+proptest! {
+    #[test]
+    fn eval_batch_proptest(
+        polys in prop::collection::vec(prop::collection::vec(any::<u128>(), 0..2100), 1..6),
+        x in any::<u128>(),
+    ) {
+        let polys: Vec<Vec<BaseElement>> =
+            polys.into_iter().map(|p| p.into_iter().map(BaseElement::new).collect()).collect();
+        let poly_refs = polys.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        let x = BaseElement::new(x);
+
+        let expected = poly_refs.iter().map(|p| super::eval(p, x)).collect::<Vec<_>>();
+        prop_assert_eq!(expected, super::eval_batch(&poly_refs, x));
+    }
+
+    #[test]
+    fn eval_at_many_points_proptest(
+        poly in prop::collection::vec(any::<u128>(), 0..3000),
+        xs in prop::collection::vec(any::<u128>(), 250..400),
+    ) {
+        let poly: Vec<BaseElement> = poly.into_iter().map(BaseElement::new).collect();
+        let xs: Vec<BaseElement> = xs.into_iter().map(BaseElement::new).collect();
+        prop_assert_eq!(super::eval_many(&poly, &xs), super::eval_at_many_points(&poly, &xs));
+    }
+
+    #[test]
+    fn interpolate_points_proptest(points in prop::collection::vec((any::<u128>(), any::<u128>()), 1..24)) {
+        let (xs, ys): (Vec<BaseElement>, Vec<BaseElement>) =
+            points.into_iter().map(|(x, y)| (BaseElement::new(x), BaseElement::new(y))).unzip();
+
+        let poly = super::interpolate_points(&xs, &ys).unwrap();
+        prop_assert_eq!(xs.len(), poly.len());
+        prop_assert_eq!(&ys, &super::eval_at_points(&poly, &xs));
+    }
+
+    #[test]
+    fn interpolate_points_subgroup_proptest(log_n in 1_u32..8, seed in any::<u128>()) {
+        let n = 1 << log_n;
+        let domain = get_power_series(BaseElement::get_root_of_unity(log_n), n);
+        let ys = get_power_series(BaseElement::new(seed), n);
+
+        let mut expected = ys.clone();
+        fft::interpolate_poly(&mut expected, &fft::get_inv_twiddles::<BaseElement>(n));
+
+        let poly = super::interpolate_points(&domain, &ys).unwrap();
+        prop_assert_eq!(&expected, &poly);
+
+        // the barycentric path must agree with the FFT path on the same points
+        let mut shuffled_domain = domain.clone();
+        let mut shuffled_ys = ys.clone();
+        shuffled_domain.reverse();
+        shuffled_ys.reverse();
+        prop_assert_eq!(&expected, &super::interpolate_points(&shuffled_domain, &shuffled_ys).unwrap());
+        prop_assert_eq!(&ys, &super::eval_at_points(&poly, &domain));
+    }
+}
@@ -4,13 +4,20 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
+use core::mem::MaybeUninit;
 
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
-use utils::{batch_iter_mut, iter_mut, uninit_vector};
+use utils::{
+    assume_init_slice_mut, assume_init_vector, batch_iter_mut, iter_mut, uninit_vector,
+    DeserializationError,
+};
 
 use crate::{field::FieldElement, ExtensionOf};
 
+#[cfg(test)]
+mod tests;
+
 // MATH FUNCTIONS
 // ================================================================================================
 
@@ -39,12 +46,14 @@ pub fn get_power_series<E>(b: E, n: usize) -> Vec<E>
 where
     E: FieldElement,
 {
-    let mut result = unsafe { uninit_vector(n) };
-    batch_iter_mut!(&mut result, 1024, |batch: &mut [E], batch_offset: usize| {
+    let mut result = uninit_vector::<E>(n);
+    batch_iter_mut!(&mut result, 1024, |batch: &mut [MaybeUninit<E>], batch_offset: usize| {
         let start = b.exp((batch_offset as u64).into());
         fill_power_series(batch, b, start);
     });
-    result
+
+    // SAFETY: all elements of the result have been initialized by fill_power_series()
+    unsafe { assume_init_vector(result) }
 }
 
 /// Returns a vector containing successive powers of a given base offset by the specified value.
@@ -74,12 +83,14 @@ pub fn get_power_series_with_offset<E>(b: E, s: E, n: usize) -> Vec<E>
 where
     E: FieldElement,
 {
-    let mut result = unsafe { uninit_vector(n) };
-    batch_iter_mut!(&mut result, 1024, |batch: &mut [E], batch_offset: usize| {
+    let mut result = uninit_vector::<E>(n);
+    batch_iter_mut!(&mut result, 1024, |batch: &mut [MaybeUninit<E>], batch_offset: usize| {
         let start = s * b.exp((batch_offset as u64).into());
         fill_power_series(batch, b, start);
     });
-    result
+
+    // SAFETY: all elements of the result have been initialized by fill_power_series()
+    unsafe { assume_init_vector(result) }
 }
 
 /// Computes element-wise sum of the provided vectors, and stores the result in the first vector.
@@ -110,7 +121,9 @@ where
     E: FieldElement,
 {
     assert!(a.len() == b.len(), "number of values must be the same for both operands");
-    iter_mut!(a).zip(b).for_each(|(a, &b)| *a += b);
+    batch_iter_mut!(a, 1024, |batch: &mut [E], batch_offset: usize| {
+        E::batch_add(batch, &b[batch_offset..batch_offset + batch.len()]);
+    });
 }
 
 /// Multiplies a sequence of values by a scalar and accumulates the results.
@@ -148,6 +161,45 @@ where
     iter_mut!(a).zip(b).for_each(|(a, &b)| *a += c.mul_base(b));
 }
 
+/// Multiplies a sequence of values by a scalar and accumulates the results using batch field
+/// operations.
+///
+/// More precisely, computes `acc[i]` + `a[i]` * `scalar` for all `i` and saves result into
+/// `acc[i]`. Unlike [mul_acc()], all values must be in the same field; this enables the use of
+/// vectorized implementations of [FieldElement::batch_mul_acc()].
+///
+/// When `concurrent` feature is enabled, the computation is performed concurrently in multiple
+/// threads.
+///
+/// # Panics
+/// Panics if lengths of `acc` and `a` slices are not the same.
+///
+/// # Examples
+/// ```
+/// # use winter_math::batch_mul_acc;
+/// # use winter_math::{fields::{f62::BaseElement}, FieldElement};
+/// # use rand_utils::rand_vector;
+/// let a: Vec<BaseElement> = rand_vector(2045);
+/// let b: Vec<BaseElement> = rand_vector(2045);
+/// let c = BaseElement::new(12345);
+///
+/// let mut d = a.clone();
+/// batch_mul_acc(&mut d, &b, c);
+///
+/// for ((a, b), d) in a.into_iter().zip(b).zip(d) {
+///     assert_eq!(a + b * c, d);
+/// }
+/// ```
+pub fn batch_mul_acc<E>(acc: &mut [E], a: &[E], scalar: E)
+where
+    E: FieldElement,
+{
+    assert!(acc.len() == a.len(), "number of values must be the same for both slices");
+    batch_iter_mut!(acc, 1024, |batch: &mut [E], batch_offset: usize| {
+        E::batch_mul_acc(batch, &a[batch_offset..batch_offset + batch.len()], scalar);
+    });
+}
+
 /// Computes a multiplicative inverse of a sequence of elements using batch inversion method.
 ///
 /// Any ZEROs in the provided sequence are ignored.
@@ -174,34 +226,86 @@ pub fn batch_inversion<E>(values: &[E]) -> Vec<E>
 where
     E: FieldElement,
 {
-    let mut result: Vec<E> = unsafe { uninit_vector(values.len()) };
-    batch_iter_mut!(&mut result, 1024, |batch: &mut [E], batch_offset: usize| {
+    let mut result = uninit_vector::<E>(values.len());
+    batch_iter_mut!(&mut result, 1024, |batch: &mut [MaybeUninit<E>], batch_offset: usize| {
         let start = batch_offset;
         let end = start + batch.len();
         serial_batch_inversion(&values[start..end], batch);
     });
-    result
+
+    // SAFETY: all elements of the result have been initialized by serial_batch_inversion()
+    unsafe { assume_init_vector(result) }
+}
+
+// BYTE CONVERSIONS
+// ================================================================================================
+
+/// Re-interprets the provided bytes as a slice of field elements without copying them.
+///
+/// The bytes are assumed to encode the elements in their internal representation, i.e., in the
+/// same way as [FieldElement::elements_as_bytes()] does. For fields for which
+/// [FieldElement::IS_CANONICAL] is true, this is the same as the canonical little-endian encoding
+/// of the elements.
+///
+/// Unlike [FieldElement::bytes_as_elements()], this function is safe to call on untrusted bytes:
+/// every element is checked to be in a valid internal representation (i.e., the representation
+/// must be the one obtained by deserializing the canonical value of the element) before the slice
+/// is returned. Thus, the validation takes time linear in the number of elements, but no memory
+/// is allocated for the elements themselves.
+///
+/// # Errors
+/// Returns an error if:
+/// * The length of `bytes` does not divide into a whole number of elements.
+/// * Memory alignment of `bytes` does not match memory alignment of elements of type `E`.
+/// * Any of the elements is not in a valid internal representation.
+///
+/// # Examples
+/// ```
+/// # use winter_math::{bytes_as_elements, fields::f64::BaseElement, FieldElement};
+/// let elements = [BaseElement::new(1), BaseElement::new(2)];
+/// let bytes = BaseElement::elements_as_bytes(&elements);
+/// assert_eq!(Ok(&elements[..]), bytes_as_elements::<BaseElement>(bytes));
+/// assert!(bytes_as_elements::<BaseElement>(&bytes[..15]).is_err());
+/// ```
+pub fn bytes_as_elements<E: FieldElement>(bytes: &[u8]) -> Result<&[E], DeserializationError> {
+    // SAFETY: length and alignment of the bytes are checked by E::bytes_as_elements(), and the
+    // re-interpreted elements are validated below before they are returned
+    let elements = unsafe { E::bytes_as_elements(bytes)? };
+
+    for (i, element) in elements.iter().enumerate() {
+        let canonical = E::try_from_le_bytes(&element.to_le_bytes())?;
+        if E::elements_as_bytes(&[canonical]) != E::elements_as_bytes(&elements[i..i + 1]) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "element at position {i} is not in a valid internal representation"
+            )));
+        }
+    }
+
+    Ok(elements)
 }
 
 // HELPER FUNCTIONS
 // ------------------------------------------------------------------------------------------------
 
 #[inline(always)]
-fn fill_power_series<E: FieldElement>(result: &mut [E], base: E, start: E) {
-    result[0] = start;
-    for i in 1..result.len() {
-        result[i] = result[i - 1] * base;
+fn fill_power_series<E: FieldElement>(result: &mut [MaybeUninit<E>], base: E, start: E) {
+    let mut value = start;
+    for result in result.iter_mut() {
+        result.write(value);
+        value *= base;
     }
 }
 
-fn serial_batch_inversion<E: FieldElement>(values: &[E], result: &mut [E]) {
+fn serial_batch_inversion<E: FieldElement>(values: &[E], result: &mut [MaybeUninit<E>]) {
     let mut last = E::ONE;
     for (result, &value) in result.iter_mut().zip(values.iter()) {
-        *result = last;
+        result.write(last);
         if value != E::ZERO {
             last *= value;
         }
     }
+    // SAFETY: all elements of the result have been initialized above
+    let result = unsafe { assume_init_slice_mut(result) };
 
     last = last.inv();
 
@@ -0,0 +1,94 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use rand_utils::rand_vector;
+use utils::DeserializationError;
+
+use super::bytes_as_elements;
+use crate::{
+    fields::{f128, f252, f64, QuadExtension},
+    FieldElement,
+};
+
+// BYTE CONVERSION TESTS
+// ================================================================================================
+
+#[test]
+fn bytes_as_elements_round_trip() {
+    fn check<E: FieldElement>() {
+        let elements: Vec<E> = rand_vector(16);
+        let bytes = E::elements_as_bytes(&elements);
+        assert_eq!(Ok(elements.as_slice()), bytes_as_elements::<E>(bytes));
+        let bytes = E::elements_as_bytes(&elements[..0]);
+        assert_eq!(Ok(&[][..]), bytes_as_elements::<E>(bytes));
+    }
+
+    check::<f64::BaseElement>();
+    check::<f128::BaseElement>();
+    check::<f252::BaseElement>();
+    check::<QuadExtension<f64::BaseElement>>();
+}
+
+#[test]
+fn bytes_as_elements_wrong_length() {
+    let elements: Vec<f64::BaseElement> = rand_vector(4);
+    let bytes = f64::BaseElement::elements_as_bytes(&elements);
+    for len in [1, 7, 9, 31] {
+        let result = bytes_as_elements::<f64::BaseElement>(&bytes[..len]);
+        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+    }
+
+    let elements: Vec<QuadExtension<f64::BaseElement>> = rand_vector(2);
+    let bytes = QuadExtension::<f64::BaseElement>::elements_as_bytes(&elements);
+    let result = bytes_as_elements::<QuadExtension<f64::BaseElement>>(&bytes[..24]);
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+}
+
+#[test]
+fn bytes_as_elements_misaligned() {
+    let elements: Vec<f64::BaseElement> = rand_vector(4);
+    let mut bytes = vec![0u8];
+    bytes.extend_from_slice(f64::BaseElement::elements_as_bytes(&elements));
+
+    // exactly one of the two offsets is aligned for a u64
+    let results = [
+        bytes_as_elements::<f64::BaseElement>(&bytes[..32]),
+        bytes_as_elements::<f64::BaseElement>(&bytes[1..]),
+    ];
+    assert_eq!(1, results.iter().filter(|result| result.is_err()).count());
+}
+
+#[test]
+fn bytes_as_elements_invalid_values() {
+    // internal representations which are not obtained from any canonical value must be rejected
+    let mut elements: Vec<f64::BaseElement> = rand_vector(4);
+    let mut bytes = f64::BaseElement::elements_as_bytes(&elements).to_vec();
+    bytes[8..16].copy_from_slice(&u64::MAX.to_le_bytes());
+    let bytes = aligned_copy::<f64::BaseElement>(&bytes, &mut elements);
+    let result = bytes_as_elements::<f64::BaseElement>(bytes);
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+    let mut elements: Vec<f252::BaseElement> = rand_vector(2);
+    let bytes = vec![0xff; 64];
+    let bytes = aligned_copy::<f252::BaseElement>(&bytes, &mut elements);
+    let result = bytes_as_elements::<f252::BaseElement>(bytes);
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Copies the provided bytes into the memory of `buffer` so that they are properly aligned for
+/// elements of type `E`, and returns a view of this memory as bytes.
+fn aligned_copy<'a, E: FieldElement>(bytes: &[u8], buffer: &'a mut [E]) -> &'a [u8] {
+    assert_eq!(bytes.len(), buffer.len() * E::ELEMENT_BYTES);
+    // SAFETY: the buffer holds exactly bytes.len() bytes, and field elements are plain data
+    unsafe {
+        core::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer.as_mut_ptr() as *mut u8, bytes.len());
+    }
+    E::elements_as_bytes(buffer)
+}
@@ -11,7 +11,7 @@ use air::{
     ProofOptions, TraceInfo, TransitionConstraintDegree,
 };
 use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
-use crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree, RandomCoin};
+use crypto::{hashers::Blake3_256, DefaultRandomCoin, HashPow, MerkleTree, RandomCoin};
 use math::{fields::f64::BaseElement, ExtensionOf, FieldElement};
 use winter_prover::{
     matrix::ColMatrix, CompositionPoly, CompositionPolyTrace, DefaultConstraintCommitment,
@@ -174,7 +174,7 @@ impl LagrangeProver {
     fn new(aux_trace_width: usize) -> Self {
         Self {
             aux_trace_width,
-            options: ProofOptions::new(1, 2, 0, FieldExtension::None, 2, 1),
+            options: ProofOptions::new(8, 2, 0, FieldExtension::None, 2, 1),
         }
     }
 }
@@ -186,6 +186,7 @@ impl Prover for LagrangeProver {
     type HashFn = Blake3_256<BaseElement>;
     type VC = MerkleTree<Blake3_256<BaseElement>>;
     type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type ProofOfWork = HashPow;
     type TraceLde<E: FieldElement<BaseField = BaseElement>> =
         DefaultTraceLde<E, Self::HashFn, Self::VC>;
     type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
@@ -200,6 +201,11 @@ impl Prover for LagrangeProver {
         &self.options
     }
 
+    fn allow_insecure(&self) -> bool {
+        // the benchmark measures proving time with minimal parameters
+        true
+    }
+
     fn new_trace_lde<E>(
         &self,
         trace_info: &TraceInfo,
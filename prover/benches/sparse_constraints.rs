@@ -0,0 +1,133 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::time::Duration;
+
+use air::{
+    Air, AirContext, Assertion, EvaluationFrame, FieldExtension, PartitionOptions, ProofOptions,
+    TraceInfo, TransitionConstraintDegree,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree, RandomCoin};
+use math::{fields::f64::BaseElement, FieldElement};
+use rand_utils::rand_vector;
+use winter_prover::{
+    matrix::ColMatrix, ConstraintEvaluator, DefaultConstraintEvaluator, DefaultTraceLde,
+    StarkDomain,
+};
+
+type Blake3 = Blake3_256<BaseElement>;
+
+const TRACE_LEN: usize = 2_usize.pow(14);
+const TRACE_WIDTH: usize = 100;
+const NUM_CONSTRAINTS: usize = 4;
+
+/// Columns read by the transition constraints; each constraint reads 3 of these columns.
+const USED_COLUMNS: [usize; NUM_CONSTRAINTS + 2] = [0, 1, 2, 3, 4, 5];
+
+fn evaluate_sparse_constraints(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sparse constraint evaluation");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    let trace_info = TraceInfo::new(TRACE_WIDTH, TRACE_LEN);
+    let main_trace = ColMatrix::new((0..TRACE_WIDTH).map(|_| rand_vector(TRACE_LEN)).collect());
+
+    for declare_used_columns in [false, true] {
+        let air = SparseAir::with_used_columns(trace_info.clone(), declare_used_columns);
+        let domain = StarkDomain::new(&air);
+        let (trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+            &trace_info,
+            &main_trace,
+            &domain,
+            PartitionOptions::default(),
+        );
+        let mut public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+        let coefficients = air
+            .get_constraint_composition_coefficients::<BaseElement, _>(&mut public_coin)
+            .unwrap();
+
+        let name = if declare_used_columns {
+            "used_columns"
+        } else {
+            "all_columns"
+        };
+        group.bench_function(BenchmarkId::new(name, TRACE_LEN), |b| {
+            b.iter(|| {
+                DefaultConstraintEvaluator::new(&air, None, coefficients.clone())
+                    .evaluate(&trace_lde, &domain)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(sparse_constraints_group, evaluate_sparse_constraints);
+criterion_main!(sparse_constraints_group);
+
+// AIR
+// =================================================================================================
+
+/// AIR with a wide main trace in which every transition constraint reads only 3 columns, and all
+/// transition constraints together read only the columns listed in [USED_COLUMNS].
+struct SparseAir {
+    context: AirContext<BaseElement>,
+    declare_used_columns: bool,
+}
+
+impl SparseAir {
+    fn with_used_columns(trace_info: TraceInfo, declare_used_columns: bool) -> Self {
+        let options = ProofOptions::new(28, 8, 0, FieldExtension::None, 4, 7);
+        let mut air = Self::new(trace_info, (), options);
+        air.declare_used_columns = declare_used_columns;
+        air
+    }
+}
+
+impl Air for SparseAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    type PublicInputs = ();
+
+    fn new(trace_info: TraceInfo, _pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(2); NUM_CONSTRAINTS];
+        Self {
+            context: AirContext::new(trace_info, degrees, 1, options),
+            declare_used_columns: true,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        for (i, result) in result.iter_mut().enumerate() {
+            *result = next[i + 2] - current[i] * current[i + 1];
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![Assertion::single(0, 0, BaseElement::ZERO)]
+    }
+
+    fn used_columns(&self) -> Option<&[usize]> {
+        if self.declare_used_columns {
+            Some(&USED_COLUMNS)
+        } else {
+            None
+        }
+    }
+}
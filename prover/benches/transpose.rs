@@ -0,0 +1,63 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use math::fields::f64::BaseElement;
+use rand_utils::rand_vector;
+use utils::{transpose, transpose_in_place};
+
+// CONSTANTS
+// ================================================================================================
+
+const NUM_ROWS: usize = 262_144;
+const NUM_COLS: [usize; 3] = [16, 64, 96];
+const SQUARE_SIZE: usize = 2048;
+
+fn transpose_matrix(c: &mut Criterion) {
+    let mut group = c.benchmark_group("transpose");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    for &num_cols in NUM_COLS.iter() {
+        let rows: Vec<BaseElement> = rand_vector(NUM_ROWS * num_cols);
+        let id = format!("{NUM_ROWS}x{num_cols}");
+
+        group.bench_function(BenchmarkId::new("naive", &id), |bench| {
+            bench.iter_with_large_drop(|| naive_transpose(&rows, num_cols));
+        });
+
+        group.bench_function(BenchmarkId::new("blocked", &id), |bench| {
+            bench.iter_with_large_drop(|| transpose(&rows, num_cols));
+        });
+    }
+
+    let mut matrix: Vec<BaseElement> = rand_vector(SQUARE_SIZE * SQUARE_SIZE);
+    let id = format!("{SQUARE_SIZE}x{SQUARE_SIZE}");
+    group.bench_function(BenchmarkId::new("in_place", id), |bench| {
+        bench.iter(|| transpose_in_place(black_box(&mut matrix), SQUARE_SIZE));
+    });
+
+    group.finish();
+}
+
+criterion_group!(transpose_group, transpose_matrix);
+criterion_main!(transpose_group);
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Transposes a row-major matrix by writing each row into the columns of the result.
+fn naive_transpose(rows: &[BaseElement], num_cols: usize) -> Vec<Vec<BaseElement>> {
+    let num_rows = rows.len() / num_cols;
+    let mut columns = vec![vec![BaseElement::default(); num_rows]; num_cols];
+    for (i, row) in rows.chunks(num_cols).enumerate() {
+        for (column, &value) in columns.iter_mut().zip(row) {
+            column[i] = value;
+        }
+    }
+    columns
+}
@@ -7,15 +7,18 @@ use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use air::{
+    labels,
     proof::{Commitments, Context, OodFrame, Proof, Queries, TraceOodFrame},
     Air, ConstraintCompositionCoefficients, DeepCompositionCoefficients,
 };
-use crypto::{ElementHasher, RandomCoin, VectorCommitment};
-use fri::FriProof;
+use crypto::{ElementHasher, ProofOfWork, RandomCoin, VectorCommitment};
+use fri::{FriProof, FriProver};
 use math::{FieldElement, ToElements};
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
 
+use crate::ProvingDebugTrace;
+
 // TYPES AND INTERFACES
 // ================================================================================================
 
@@ -30,9 +33,12 @@ where
     air: &'a A,
     public_coin: R,
     context: Context,
-    commitments: Commitments,
+    trace_roots: Vec<H::Digest>,
+    constraint_root: Option<H::Digest>,
+    fri_roots: Vec<H::Digest>,
     ood_frame: OodFrame,
     pow_nonce: u64,
+    debug_trace: Option<ProvingDebugTrace<A::BaseField, H::Digest>>,
     _field_element: PhantomData<E>,
     _vector_commitment: PhantomData<V>,
 }
@@ -50,23 +56,51 @@ where
 {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Creates a new prover channel for the specified `air` and public inputs.
-    pub fn new(air: &'a A, mut pub_inputs_elements: Vec<A::BaseField>) -> Self {
-        let context = Context::new::<A::BaseField>(air.trace_info().clone(), air.options().clone());
+    /// Creates a new prover channel for the specified `air`, public inputs, and application
+    /// context data; empty context data is not absorbed into the public coin.
+    ///
+    /// If `debug` is true, all interactions with the public coin are recorded into a
+    /// [ProvingDebugTrace] which can be retrieved via [take_debug_trace()](Self::take_debug_trace).
+    pub fn new(
+        air: &'a A,
+        mut pub_inputs_elements: Vec<A::BaseField>,
+        context_data: &[u8],
+        debug: bool,
+    ) -> Self {
+        let context = Context::new::<A::BaseField>(
+            air.trace_info().clone(),
+            air.options().clone(),
+            air.context().num_constraint_composition_columns(),
+        );
 
         // build a seed for the public coin; the initial seed is a hash of the proof context and
         // the public inputs, but as the protocol progresses, the coin will be reseeded with the
         // info sent to the verifier
         let mut coin_seed_elements = context.to_elements();
         coin_seed_elements.append(&mut pub_inputs_elements);
+        let mut debug_trace = debug.then(|| ProvingDebugTrace::new(coin_seed_elements.clone()));
+
+        // bind the transcript to the application context data (if any) before anything else is
+        // absorbed into the public coin
+        let mut public_coin = R::new(&coin_seed_elements);
+        if !context_data.is_empty() {
+            let digest = H::hash(context_data);
+            public_coin.reseed_with_label(labels::CONTEXT_DATA, digest);
+            if let Some(debug) = debug_trace.as_mut() {
+                debug.record_reseed("context data", labels::CONTEXT_DATA, digest);
+            }
+        }
 
         ProverChannel {
             air,
-            public_coin: RandomCoin::new(&coin_seed_elements),
+            public_coin,
             context,
-            commitments: Commitments::default(),
+            trace_roots: Vec::new(),
+            constraint_root: None,
+            fri_roots: Vec::new(),
             ood_frame: OodFrame::default(),
             pow_nonce: 0,
+            debug_trace,
             _field_element: PhantomData,
             _vector_commitment: PhantomData,
         }
@@ -76,29 +110,52 @@ where
     // --------------------------------------------------------------------------------------------
 
     /// Commits the prover the extended execution trace.
+    ///
+    /// The first commitment is a commitment to the main trace segment; all subsequent
+    /// commitments are commitments to the auxiliary trace segment.
     pub fn commit_trace(&mut self, trace_root: H::Digest) {
-        self.commitments.add::<H>(&trace_root);
-        self.public_coin.reseed(trace_root);
+        let label = if self.trace_roots.is_empty() {
+            labels::MAIN_TRACE_COMMITMENT
+        } else {
+            labels::AUX_TRACE_COMMITMENT
+        };
+        self.trace_roots.push(trace_root);
+        self.public_coin.reseed_with_label(label, trace_root);
+        self.record(|debug| debug.record_trace_root(label, trace_root));
     }
 
     /// Commits the prover to the evaluations of the constraint composition polynomial.
     pub fn commit_constraints(&mut self, constraint_root: H::Digest) {
-        self.commitments.add::<H>(&constraint_root);
-        self.public_coin.reseed(constraint_root);
+        self.constraint_root = Some(constraint_root);
+        self.public_coin
+            .reseed_with_label(labels::CONSTRAINT_COMMITMENT, constraint_root);
+        self.record(|debug| debug.record_constraint_root(constraint_root));
     }
 
     /// Saves the evaluations of trace polynomials over the out-of-domain evaluation frame. This
     /// also reseeds the public coin with the hashes of the evaluation frame states.
     pub fn send_ood_trace_states(&mut self, trace_ood_frame: &TraceOodFrame<E>) {
         let trace_states_hash = self.ood_frame.set_trace_states::<E, H>(trace_ood_frame);
-        self.public_coin.reseed(trace_states_hash);
+        self.public_coin.reseed_with_label(labels::OOD_TRACE_STATES, trace_states_hash);
+        self.record(|debug| {
+            debug.record_reseed("OOD trace states", labels::OOD_TRACE_STATES, trace_states_hash)
+        });
     }
 
     /// Saves the evaluations of constraint composition polynomial columns at the out-of-domain
     /// point. This also reseeds the public coin wit the hash of the evaluations.
     pub fn send_ood_constraint_evaluations(&mut self, evaluations: &[E]) {
         self.ood_frame.set_constraint_evaluations(evaluations);
-        self.public_coin.reseed(H::hash_elements(evaluations));
+        let evaluations_hash = H::hash_elements(evaluations);
+        self.public_coin
+            .reseed_with_label(labels::OOD_CONSTRAINT_EVALUATIONS, evaluations_hash);
+        self.record(|debug| {
+            debug.record_reseed(
+                "OOD constraint evaluations",
+                labels::OOD_CONSTRAINT_EVALUATIONS,
+                evaluations_hash,
+            )
+        });
     }
 
     // PUBLIC COIN METHODS
@@ -113,23 +170,46 @@ where
     ///
     /// The coefficients are drawn from the public coin uniformly at random.
     pub fn get_constraint_composition_coeffs(&mut self) -> ConstraintCompositionCoefficients<E> {
-        self.air
+        let coefficients = self
+            .air
             .get_constraint_composition_coefficients(&mut self.public_coin)
-            .expect("failed to draw composition coefficients")
+            .expect("failed to draw composition coefficients");
+        self.record(|debug| {
+            let mut values =
+                [coefficients.transition.clone(), coefficients.boundary.clone()].concat();
+            if let Some(lagrange) = &coefficients.lagrange {
+                values.extend_from_slice(&lagrange.transition);
+                values.push(lagrange.boundary);
+            }
+            debug.record_draw("constraint composition coefficients", &values)
+        });
+        coefficients
     }
 
     /// Returns an out-of-domain point drawn uniformly at random from the public coin.
     pub fn get_ood_point(&mut self) -> E {
-        self.public_coin.draw().expect("failed to draw OOD point")
+        let z = self.public_coin.draw().expect("failed to draw OOD point");
+        #[cfg(feature = "tracing")]
+        tracing::event!(tracing::Level::DEBUG, %z, "drew out-of-domain point");
+        self.record(|debug| debug.record_ood_point(z));
+        z
     }
 
     /// Returns a set of coefficients for constructing a DEEP composition polynomial.
     ///
     /// The coefficients are drawn from the public coin uniformly at random.
     pub fn get_deep_composition_coeffs(&mut self) -> DeepCompositionCoefficients<E> {
-        self.air
+        let coefficients = self
+            .air
             .get_deep_composition_coefficients(&mut self.public_coin)
-            .expect("failed to draw DEEP composition coefficients")
+            .expect("failed to draw DEEP composition coefficients");
+        self.record(|debug| {
+            let mut values =
+                [coefficients.trace.clone(), coefficients.constraints.clone()].concat();
+            values.extend(coefficients.lagrange);
+            debug.record_draw("DEEP composition coefficients", &values)
+        });
+        coefficients
     }
 
     /// Returns a set of positions in the LDE domain against which the evaluations of trace and
@@ -148,28 +228,68 @@ where
         // remove any duplicate positions from the list
         positions.sort_unstable();
         positions.dedup();
+        self.record(|debug| debug.record_query_positions(&positions));
 
         positions
     }
 
-    /// Determines a nonce, which when hashed with the current seed of the public coin results
-    /// in a new seed with the number of leading zeros equal to the grinding_factor specified
-    /// in the proof options.
-    pub fn grind_query_seed(&mut self) {
+    /// Determines a nonce which, together with the current seed of the public coin, satisfies
+    /// the proof-of-work scheme `P` with the difficulty equal to the grinding_factor specified in
+    /// the proof options.
+    ///
+    /// # Panics
+    /// Panics if the scheme `P` cannot be used with the public coin of this channel.
+    pub fn grind_query_seed<P: ProofOfWork<H>>(&mut self) {
         let grinding_factor = self.context.options().grinding_factor();
+        let public_coin = &self.public_coin;
+        assert!(
+            P::supports_coin(public_coin),
+            "{:?} proof-of-work requires a random coin which exposes its seed",
+            P::SCHEME
+        );
 
-        #[cfg(not(feature = "concurrent"))]
-        let nonce = (1..u64::MAX)
-            .find(|&nonce| self.public_coin.check_leading_zeros(nonce) >= grinding_factor)
-            .expect("nonce not found");
+        self.pow_nonce =
+            find_nonce(|nonce| P::verify_coin_nonce(public_coin, nonce, grinding_factor));
+        let nonce = self.pow_nonce;
+        #[cfg(feature = "tracing")]
+        tracing::event!(tracing::Level::DEBUG, nonce, grinding_factor, "found grinding nonce");
+        self.record(|debug| debug.record_grind(nonce));
+    }
 
-        #[cfg(feature = "concurrent")]
-        let nonce = (1..u64::MAX)
-            .into_par_iter()
-            .find_any(|&nonce| self.public_coin.check_leading_zeros(nonce) >= grinding_factor)
-            .expect("nonce not found");
+    // DEBUG METHODS
+    // --------------------------------------------------------------------------------------------
 
-        self.pow_nonce = nonce;
+    /// Records that the specified values were drawn from the public coin outside of this channel
+    /// (e.g., random elements for building the auxiliary trace segment).
+    pub fn record_draw(&mut self, label: &'static str, values: &[E]) {
+        self.record(|debug| debug.record_draw(label, values));
+    }
+
+    /// Records the FRI layers computed by the specified FRI prover.
+    pub fn record_fri_layers<C>(&mut self, fri_prover: &FriProver<E, C, H, V>)
+    where
+        C: fri::ProverChannel<E, Hasher = H>,
+    {
+        self.record(|debug| {
+            debug.record_fri_layers(
+                (0..fri_prover.num_layers()).map(|idx| fri_prover.layer_evaluations(idx)),
+                fri_prover.remainder_poly(),
+            )
+        });
+    }
+
+    /// Returns the debug trace recorded by this channel, or `None` if the channel was not
+    /// created in debug mode.
+    pub fn take_debug_trace(&mut self) -> Option<ProvingDebugTrace<A::BaseField, H::Digest>> {
+        self.debug_trace.take()
+    }
+
+    /// Applies the specified recording function to the debug trace if the channel was created
+    /// in debug mode.
+    fn record(&mut self, f: impl FnOnce(&mut ProvingDebugTrace<A::BaseField, H::Digest>)) {
+        if let Some(debug_trace) = self.debug_trace.as_mut() {
+            f(debug_trace);
+        }
     }
 
     // PROOF BUILDER
@@ -188,7 +308,11 @@ where
 
         Proof {
             context: self.context,
-            commitments: self.commitments,
+            commitments: Commitments::new::<H>(
+                self.trace_roots,
+                self.constraint_root.expect("constraint commitment has not been made"),
+                self.fri_roots,
+            ),
             ood_frame: self.ood_frame,
             trace_queries,
             constraint_queries,
@@ -215,12 +339,34 @@ where
 
     /// Commits the prover to a FRI layer.
     fn commit_fri_layer(&mut self, layer_root: H::Digest) {
-        self.commitments.add::<H>(&layer_root);
-        self.public_coin.reseed(layer_root);
+        self.fri_roots.push(layer_root);
+        self.public_coin.reseed_with_label(fri::labels::LAYER_COMMITMENT, layer_root);
+        self.record(|debug| debug.record_fri_layer_root(layer_root));
     }
 
     /// Returns a new alpha drawn from the public coin.
     fn draw_fri_alpha(&mut self) -> E {
-        self.public_coin.draw().expect("failed to draw FRI alpha")
+        let alpha = self.public_coin.draw().expect("failed to draw FRI alpha");
+        self.record(|debug| debug.record_draw("FRI alpha", &[alpha]));
+        alpha
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the smallest positive nonce which satisfies the specified proof-of-work predicate;
+/// when the `concurrent` feature is enabled, the search is performed in parallel and any valid
+/// nonce may be returned.
+fn find_nonce(is_valid: impl Fn(u64) -> bool + Sync) -> u64 {
+    #[cfg(not(feature = "concurrent"))]
+    let nonce = (1..u64::MAX).find(|&nonce| is_valid(nonce)).expect("nonce not found");
+
+    #[cfg(feature = "concurrent")]
+    let nonce = (1..u64::MAX)
+        .into_par_iter()
+        .find_any(|&nonce| is_valid(nonce))
+        .expect("nonce not found");
+
+    nonce
+}
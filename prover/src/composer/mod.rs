@@ -6,7 +6,7 @@ use alloc::vec::Vec;
 
 use air::{proof::TraceOodFrame, DeepCompositionCoefficients};
 use math::{
-    add_in_place, fft, mul_acc,
+    add_in_place, batch_mul_acc, fft, mul_acc,
     polynom::{self, syn_div_roots_in_place},
     ExtensionOf, FieldElement, StarkField,
 };
@@ -54,10 +54,11 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
     ///
     /// - Compute polynomials T'_i(x) = (T_i(x) - T_i(z)) / (x - z) and
     ///   T''_i(x) = (T_i(x) - T_i(z * g)) / (x - z * g) for all i, where T_i(x) is a trace
-    ///   polynomial for column i.
-    /// - Then, combine together all T'_i(x) and T''_i(x) polynomials using a random linear
-    ///   combination as T(x) = sum((T'_i(x) + T''_i(x)) * cc_i) for all i, where cc_i is
-    ///   the coefficient for the random linear combination drawn from the public coin.
+    ///   polynomial for column i. If the evaluation frame of the AIR contains k > 2 rows, also
+    ///   compute (T_i(x) - T_i(z * g^j)) / (x - z * g^j) for all 2 <= j < k.
+    /// - Then, combine together all of these polynomials using a random linear combination as
+    ///   T(x) = sum((T'_i(x) + T''_i(x) + ...) * cc_i) for all i, where cc_i is the coefficient
+    ///   for the random linear combination drawn from the public coin.
     /// - If a Lagrange kernel is present, combine one additional term defined as
     ///   (T_l(x) - p_S(x)) / Z_S(x), where:
     ///
@@ -68,7 +69,7 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
     ///    ${(a, T_l(a)): a \in S}$.
     /// 4. $Z_S(X)$ is the polynomial of minimal degree vanishing over the set $S$.
     ///
-    /// Note that evaluations of T_i(z) and T_i(z * g) are passed in via the `ood_trace_state`
+    /// Note that evaluations of T_i(z), T_i(z * g) etc. are passed in via the `ood_trace_state`
     /// parameter.
     /// If a Lagrange kernel is present, the evaluations of $T_l$ over the set $S$ are provided
     /// separately via `ood_trace_state`.
@@ -79,15 +80,21 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
     ) {
         assert!(self.coefficients.is_empty());
 
-        // compute a second out-of-domain point offset from z by exactly trace generator; this
-        // point defines the "next" computation state in relation to point z
+        // compute out-of-domain points offset from z by powers of the trace generator; the j-th
+        // point defines the computation state j steps after the state at point z
         let trace_length = trace_polys.poly_size();
         let g = E::from(E::BaseField::get_root_of_unity(trace_length.ilog2()));
-        let next_z = self.z * g;
+        let frame_size = ood_trace_states.frame_size();
+        let mut points = Vec::with_capacity(frame_size);
+        let mut x = self.z;
+        for _ in 0..frame_size {
+            points.push(x);
+            x *= g;
+        }
 
-        // combine trace polynomials into 2 composition polynomials T'(x) and T''(x)
-        let mut t1_composition = vec![E::ZERO; trace_length];
-        let mut t2_composition = vec![E::ZERO; trace_length];
+        // combine trace polynomials into one composition polynomial per frame row (e.g., T'(x)
+        // and T''(x) for the frame of two rows)
+        let mut compositions = vec![vec![E::ZERO; trace_length]; frame_size];
 
         // index of a trace polynomial; we declare it here so that we can maintain index continuity
         // across all trace segments
@@ -95,55 +102,40 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
 
         // --- merge polynomials of the main trace segment ----------------------------------------
         for poly in trace_polys.main_trace_polys() {
-            // compute T'(x) = T(x) - T(z), multiply it by a pseudo-random coefficient,
-            // and add the result into composition polynomial
-            acc_trace_poly::<E::BaseField, E>(
-                &mut t1_composition,
-                poly,
-                ood_trace_states.current_row()[i],
-                self.cc.trace[i],
-            );
-
-            // compute T''(x) = T(x) - T(z * g), multiply it by a pseudo-random coefficient,
-            // and add the result into composition polynomial
-            acc_trace_poly::<E::BaseField, E>(
-                &mut t2_composition,
-                poly,
-                ood_trace_states.next_row()[i],
-                self.cc.trace[i],
-            );
+            // compute T(x) - T(z * g^j), multiply it by a pseudo-random coefficient, and add the
+            // result into the j-th composition polynomial
+            for (j, composition) in compositions.iter_mut().enumerate() {
+                acc_trace_poly::<E::BaseField, E>(
+                    composition,
+                    poly,
+                    ood_trace_states.row(j)[i],
+                    self.cc.trace[i],
+                );
+            }
 
             i += 1;
         }
 
         // --- merge polynomials of the auxiliary trace segment ----------------------------------
         for poly in trace_polys.aux_trace_polys() {
-            // compute T'(x) = T(x) - T(z), multiply it by a pseudo-random coefficient,
-            // and add the result into composition polynomial
-            acc_trace_poly::<E, E>(
-                &mut t1_composition,
-                poly,
-                ood_trace_states.current_row()[i],
-                self.cc.trace[i],
-            );
-
-            // compute T''(x) = T(x) - T(z * g), multiply it by a pseudo-random coefficient,
-            // and add the result into composition polynomial
-            acc_trace_poly::<E, E>(
-                &mut t2_composition,
-                poly,
-                ood_trace_states.next_row()[i],
-                self.cc.trace[i],
-            );
+            // compute T(x) - T(z * g^j), multiply it by a pseudo-random coefficient, and add the
+            // result into the j-th composition polynomial
+            for (j, composition) in compositions.iter_mut().enumerate() {
+                acc_trace_poly::<E, E>(
+                    composition,
+                    poly,
+                    ood_trace_states.row(j)[i],
+                    self.cc.trace[i],
+                );
+            }
 
             i += 1;
         }
 
-        // divide the composition polynomials by (x - z) and (x - z * g), respectively,
-        // and add the resulting polynomials together; the output of this step
-        // is a single trace polynomial T(x) and deg(T(x)) = trace_length - 2.
-        let mut trace_poly =
-            merge_trace_compositions(vec![t1_composition, t2_composition], vec![self.z, next_z]);
+        // divide the composition polynomials by (x - z), (x - z * g) etc., respectively, and add
+        // the resulting polynomials together; the output of this step is a single trace
+        // polynomial T(x) and deg(T(x)) = trace_length - 2.
+        let mut trace_poly = merge_trace_compositions(compositions, points);
 
         // finally compose the final term associated to the Lagrange kernel trace polynomial if
         // there is one present.
@@ -221,7 +213,7 @@ impl<E: FieldElement> DeepCompositionPoly<E> {
 
         // add H'_i(x) * cc_i for all i into the DEEP composition polynomial
         for (i, poly) in column_polys.into_iter().enumerate() {
-            mul_acc::<E, E>(&mut self.coefficients, &poly, self.cc.constraints[i]);
+            batch_mul_acc(&mut self.coefficients, &poly, self.cc.constraints[i]);
         }
         assert_eq!(self.poly_size() - 2, self.degree());
     }
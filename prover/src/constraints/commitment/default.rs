@@ -9,7 +9,6 @@ use core::marker::PhantomData;
 use air::{proof::Queries, PartitionOptions};
 use crypto::{ElementHasher, VectorCommitment};
 use math::FieldElement;
-use tracing::info_span;
 
 use super::{ConstraintCommitment, RowMatrix};
 use crate::{CompositionPoly, CompositionPolyTrace, StarkDomain, DEFAULT_SEGMENT_WIDTH};
@@ -121,30 +120,38 @@ where
     // - interpolate the trace into a polynomial in coefficient form
     // - "break" the polynomial into a set of column polynomials each of degree equal to
     //   trace_length - 1
-    let composition_poly = info_span!(
-        "build_composition_poly_columns",
-        num_columns = num_constraint_composition_columns
-    )
-    .in_scope(|| {
+    let composition_poly = {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "build_composition_poly_columns",
+            num_columns = num_constraint_composition_columns
+        )
+        .entered();
         CompositionPoly::new(composition_poly_trace, domain, num_constraint_composition_columns)
-    });
+    };
     assert_eq!(composition_poly.num_columns(), num_constraint_composition_columns);
     assert_eq!(composition_poly.column_degree(), domain.trace_length() - 1);
 
     // then, evaluate composition polynomial columns over the LDE domain
     let domain_size = domain.lde_domain_size();
-    let composed_evaluations = info_span!("evaluate_composition_poly_columns").in_scope(|| {
+    let composed_evaluations = {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("evaluate_composition_poly_columns").entered();
         RowMatrix::evaluate_polys_over::<DEFAULT_SEGMENT_WIDTH>(composition_poly.data(), domain)
-    });
+    };
     assert_eq!(composed_evaluations.num_cols(), num_constraint_composition_columns);
     assert_eq!(composed_evaluations.num_rows(), domain_size);
 
     // finally, build constraint evaluation commitment
-    let commitment = info_span!(
-        "compute_constraint_evaluation_commitment",
-        log_domain_size = domain_size.ilog2()
-    )
-    .in_scope(|| composed_evaluations.commit_to_rows::<H, V>(partition_options));
+    let commitment = {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "compute_constraint_evaluation_commitment",
+            log_domain_size = domain_size.ilog2()
+        )
+        .entered();
+        composed_evaluations.commit_to_rows::<H, V>(partition_options)
+    };
 
     (composed_evaluations, commitment, composition_poly)
 }
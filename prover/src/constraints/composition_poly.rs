@@ -118,18 +118,29 @@ impl<E: FieldElement> CompositionPoly<E> {
 /// in such a way that each resulting column has the same degree. For example, a polynomial
 /// a * x^3 + b * x^2 + c * x + d, can be rewritten as: (c * x + d) + x^2 * (a * x + b), and then
 /// the two columns will be: (c * x + d) and (a * x + b).
+///
+/// The first column reuses the memory of the coefficient vector.
 fn segment<E: FieldElement>(
-    coefficients: Vec<E>,
+    mut coefficients: Vec<E>,
     trace_len: usize,
     num_cols: usize,
 ) -> Vec<Vec<E>> {
     debug_assert!(degree_of(&coefficients) < trace_len * num_cols);
 
-    coefficients
-        .chunks(trace_len)
-        .take(num_cols)
-        .map(|slice| slice.to_vec())
-        .collect()
+    // when more columns are requested than the composition polynomial trace can fill, the
+    // remaining columns are zero polynomials
+    coefficients.resize(trace_len * num_cols, E::ZERO);
+
+    // split the columns off the end of the coefficient vector so that only the last columns are
+    // copied into new allocations
+    let mut columns = Vec::with_capacity(num_cols);
+    for i in (1..num_cols).rev() {
+        columns.push(coefficients.split_off(i * trace_len));
+    }
+    coefficients.shrink_to_fit();
+    columns.push(coefficients);
+    columns.reverse();
+    columns
 }
 
 // TESTS
@@ -4,6 +4,7 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
+use core::mem::MaybeUninit;
 
 #[cfg(debug_assertions)]
 use air::TransitionConstraints;
@@ -12,7 +13,7 @@ use math::fft;
 use math::{batch_inversion, FieldElement, StarkField};
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
-use utils::{batch_iter_mut, iter_mut, uninit_vector};
+use utils::{assume_init_vector, batch_iter_mut, iter_mut, uninit_vector};
 
 use super::{ConstraintDivisor, StarkDomain};
 
@@ -24,17 +25,26 @@ const MIN_FRAGMENT_SIZE: usize = 16;
 // CONSTRAINT EVALUATION TABLE
 // ================================================================================================
 
+/// A table of constraint evaluations grouped by common divisors.
+///
+/// Evaluations of all constraint groups are kept in a single contiguous allocation: the group at
+/// index `i` occupies `num_rows` elements starting at offset `i * num_rows`. When the table is
+/// combined, the groups are divided by their divisors and merged in place, and the allocation
+/// is reused for the combined evaluations.
 pub struct ConstraintEvaluationTable<'a, E: FieldElement> {
-    evaluations: Vec<Vec<E>>,
+    evaluations: Vec<E>,
+    num_rows: usize,
     divisors: Vec<ConstraintDivisor<E::BaseField>>,
     domain: &'a StarkDomain<E::BaseField>,
 
     #[cfg(debug_assertions)]
-    main_transition_evaluations: Vec<Vec<E::BaseField>>,
+    main_transition_evaluations: Vec<E::BaseField>,
     #[cfg(debug_assertions)]
-    aux_transition_evaluations: Vec<Vec<E>>,
+    aux_transition_evaluations: Vec<E>,
     #[cfg(debug_assertions)]
     expected_transition_degrees: Vec<usize>,
+    #[cfg(debug_assertions)]
+    transition_divisor_indexes: Vec<usize>,
 }
 
 impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
@@ -50,7 +60,8 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
         let num_columns = divisors.len();
         let num_rows = domain.ce_domain_size();
         ConstraintEvaluationTable {
-            evaluations: uninit_matrix(num_columns, num_rows),
+            evaluations: vec![E::ZERO; num_columns * num_rows],
+            num_rows,
             divisors,
             domain,
         }
@@ -75,13 +86,24 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
         let expected_transition_degrees =
             build_transition_constraint_degrees(transition_constraints, domain.trace_length());
 
+        // transition constraint divisors are at the front of the divisor list; for each
+        // transition constraint, record the index of its divisor in this list
+        let transition_divisor_indexes = (0..num_tm_columns + num_ta_columns)
+            .map(|i| {
+                let divisor = transition_constraints.constraint_divisor(i);
+                divisors.iter().position(|d| d == divisor).expect("divisor not found")
+            })
+            .collect();
+
         ConstraintEvaluationTable {
-            evaluations: uninit_matrix(num_columns, num_rows),
+            evaluations: vec![E::ZERO; num_columns * num_rows],
+            num_rows,
             divisors,
             domain,
-            main_transition_evaluations: uninit_matrix(num_tm_columns, num_rows),
-            aux_transition_evaluations: uninit_matrix(num_ta_columns, num_rows),
+            main_transition_evaluations: vec![E::BaseField::ZERO; num_tm_columns * num_rows],
+            aux_transition_evaluations: vec![E::ZERO; num_ta_columns * num_rows],
             expected_transition_degrees,
+            transition_divisor_indexes,
         }
     }
 
@@ -91,17 +113,17 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
     /// Returns the number of rows in this table. This is the same as the size of the constraint
     /// evaluation domain.
     pub fn num_rows(&self) -> usize {
-        self.evaluations[0].len()
+        self.num_rows
     }
 
     /// Returns number of columns in this table.
     ///
-    /// The first column always contains the value of combined transition constraint evaluations;
-    /// the remaining columns contain values of assertion constraint evaluations combined based on
-    /// common divisors.
+    /// The first columns always contain values of transition constraint evaluations combined
+    /// based on common divisors (usually, there is only one such column); the remaining columns
+    /// contain values of assertion constraint evaluations combined based on common divisors.
     #[allow(dead_code)]
     pub fn num_columns(&self) -> usize {
-        self.evaluations.len()
+        self.divisors.len()
     }
 
     // TABLE FRAGMENTS
@@ -109,7 +131,7 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
 
     /// Break the table into the number of specified fragments. All fragments can be updated
     /// independently - e.g. in different threads.
-    pub fn fragments(&mut self, num_fragments: usize) -> Vec<EvaluationTableFragment<E>> {
+    pub fn fragments(&mut self, num_fragments: usize) -> Vec<EvaluationTableFragment<'_, E>> {
         let fragment_size = self.num_rows() / num_fragments;
         assert!(
             fragment_size >= MIN_FRAGMENT_SIZE,
@@ -117,15 +139,16 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
         );
 
         // break evaluations into fragments
-        let evaluation_data = make_fragments(&mut self.evaluations, num_fragments);
+        let num_rows = self.num_rows;
+        let evaluation_data = make_fragments(&mut self.evaluations, num_rows, num_fragments);
 
         #[cfg(debug_assertions)]
         let result = {
             // in debug mode, also break individual transition evaluations into fragments
             let tm_evaluation_data =
-                make_fragments(&mut self.main_transition_evaluations, num_fragments);
+                make_fragments(&mut self.main_transition_evaluations, num_rows, num_fragments);
             let ta_evaluation_data =
-                make_fragments(&mut self.aux_transition_evaluations, num_fragments);
+                make_fragments(&mut self.aux_transition_evaluations, num_rows, num_fragments);
 
             evaluation_data
                 .into_iter()
@@ -160,19 +183,33 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
     // --------------------------------------------------------------------------------------------
     /// Divides constraint evaluation columns by their respective divisor (in evaluation form) and
     /// combines the results into a single column.
+    ///
+    /// The division and the merging are done in place: the combined column is written into the
+    /// slot of the first column, and the memory occupied by the remaining columns is released.
     pub fn combine(self) -> Vec<E> {
-        // allocate memory for the combined polynomial
-        let mut combined_poly = vec![E::ZERO; self.num_rows()];
-
-        // iterate over all columns of the constraint evaluation table, divide each column
-        // by the evaluations of its corresponding divisor, and add all resulting evaluations
-        // together into a single vector
-        for (column, divisor) in self.evaluations.into_iter().zip(self.divisors.iter()) {
-            // divide the column by the divisor and accumulate the result into combined_poly
-            acc_column(column, divisor, self.domain, &mut combined_poly);
+        let num_rows = self.num_rows;
+        let mut evaluations = self.evaluations;
+        if evaluations.is_empty() {
+            return vec![E::ZERO; num_rows];
         }
 
-        combined_poly
+        // divide each column of the constraint evaluation table by the evaluations of its
+        // corresponding divisor
+        for (column, divisor) in evaluations.chunks_mut(num_rows).zip(self.divisors.iter()) {
+            divide_column(column, divisor, self.domain);
+        }
+
+        // add the remaining columns to the first column
+        let (combined_poly, columns) = evaluations.split_at_mut(num_rows);
+        for column in columns.chunks(num_rows) {
+            iter_mut!(combined_poly, 1024)
+                .zip(column)
+                .for_each(|(acc_value, &value)| *acc_value += value);
+        }
+
+        evaluations.truncate(num_rows);
+        evaluations.shrink_to_fit();
+        evaluations
     }
 
     // DEBUG HELPERS
@@ -180,46 +217,60 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
 
     #[cfg(debug_assertions)]
     pub fn validate_transition_degrees(&mut self) {
-        // evaluate transition constraint divisor (which is assumed to be the first one in the
-        // divisor list) over the constraint evaluation domain. this is used later to compute
-        // actual degrees of transition constraint evaluations.
-        let div_values = evaluate_divisor::<E::BaseField>(
-            &self.divisors[0],
-            self.num_rows(),
-            self.domain.offset(),
-        );
+        // evaluate transition constraint divisors (which are at the front of the divisor list)
+        // over the constraint evaluation domain. these are used later to compute actual degrees
+        // of transition constraint evaluations.
+        let num_t_divisors = self.transition_divisor_indexes.iter().max().map_or(0, |&i| i + 1);
+        let div_values: Vec<Vec<E::BaseField>> = self.divisors[..num_t_divisors]
+            .iter()
+            .map(|divisor| evaluate_divisor(divisor, self.num_rows(), self.domain.offset()))
+            .collect();
 
         // collect actual degrees for all transition constraints by interpolating saved
-        // constraint evaluations into polynomials and checking their degree; also
-        // determine max transition constraint degree
+        // constraint evaluations into polynomials and checking their degree
         let mut actual_degrees = Vec::with_capacity(self.expected_transition_degrees.len());
-        let mut max_degree = 0;
         let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(self.num_rows());
 
         // first process transition constraint evaluations for the main trace segment
-        for evaluations in self.main_transition_evaluations.iter() {
-            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, &div_values);
+        let num_rows = self.num_rows;
+        let num_main_constraints = self.main_transition_evaluations.len() / num_rows;
+        for (i, evaluations) in self.main_transition_evaluations.chunks(num_rows).enumerate() {
+            let div_values = &div_values[self.transition_divisor_indexes[i]];
+            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, div_values);
             actual_degrees.push(degree);
-            max_degree = core::cmp::max(max_degree, degree);
         }
 
         // then process transition constraint evaluations for the auxiliary trace segment
-        for evaluations in self.aux_transition_evaluations.iter() {
-            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, &div_values);
+        for (i, evaluations) in self.aux_transition_evaluations.chunks(num_rows).enumerate() {
+            let div_values = &div_values[self.transition_divisor_indexes[num_main_constraints + i]];
+            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, div_values);
             actual_degrees.push(degree);
-            max_degree = core::cmp::max(max_degree, degree);
         }
 
-        // make sure expected and actual degrees are equal
-        assert_eq!(
-            self.expected_transition_degrees, actual_degrees,
+        // make sure expected and actual degrees are equal; for constraints with periodic
+        // exemptions, the actual degree depends on the values the constraints evaluate to at the
+        // exempt steps, and thus, the expected degree is only an upper bound
+        let degrees_match = actual_degrees
+            .iter()
+            .zip(self.expected_transition_degrees.iter())
+            .zip(self.transition_divisor_indexes.iter())
+            .all(|((&actual, &expected), &divisor_idx)| {
+                if self.divisors[divisor_idx].denominator().is_empty() {
+                    actual == expected
+                } else {
+                    actual <= expected
+                }
+            });
+        assert!(
+            degrees_match,
             "transition constraint degrees didn't match\nexpected: {:>3?}\nactual:   {:>3?}",
             self.expected_transition_degrees, actual_degrees
         );
 
         // make sure evaluation domain size does not exceed the size required by max degree
+        let max_degree = self.expected_transition_degrees.iter().copied().max().unwrap_or(0);
         let expected_domain_size =
-            core::cmp::max(max_degree, self.domain.trace_length() + 1).next_power_of_two();
+            core::cmp::max(max_degree + 1, self.domain.trace_length() + 1).next_power_of_two();
         assert_eq!(
             expected_domain_size,
             self.num_rows(),
@@ -286,39 +337,32 @@ impl<E: FieldElement> EvaluationTableFragment<'_, E> {
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Allocates memory for a two-dimensional data structure without initializing it.
-fn uninit_matrix<E: FieldElement>(num_cols: usize, num_rows: usize) -> Vec<Vec<E>> {
-    unsafe { (0..num_cols).map(|_| uninit_vector(num_rows)).collect() }
-}
-
-/// Breaks the source data into a mutable set of fragments such that each fragment has the same
-/// number of columns as the source data, and the number of rows equal to `num_fragments`
-/// parameter.
+/// Breaks the source data, which consists of columns of `num_rows` values laid out one after
+/// another, into a mutable set of fragments such that each fragment has the same number of
+/// columns as the source data, and the number of rows equal to `num_rows / num_fragments`.
 ///
 /// If the source data is empty, the returned vector will contain number of empty vectors equal
 /// to `num_fragments` parameter.
 fn make_fragments<E: FieldElement>(
-    source: &mut [Vec<E>],
+    source: &mut [E],
+    num_rows: usize,
     num_fragments: usize,
 ) -> Vec<Vec<&mut [E]>> {
     let mut result = (0..num_fragments).map(|_| Vec::new()).collect::<Vec<_>>();
-    if !source.is_empty() {
-        let fragment_size = source[0].len() / num_fragments;
-        source.iter_mut().for_each(|column| {
-            for (i, fragment) in column.chunks_mut(fragment_size).enumerate() {
-                result[i].push(fragment);
-            }
-        });
-    }
+    let fragment_size = num_rows / num_fragments;
+    source.chunks_mut(num_rows).for_each(|column| {
+        for (i, fragment) in column.chunks_mut(fragment_size).enumerate() {
+            result[i].push(fragment);
+        }
+    });
     result
 }
 
-#[allow(clippy::many_single_char_names)]
-fn acc_column<E: FieldElement>(
-    column: Vec<E>,
+/// Divides the column values by the evaluations of the divisor in place.
+fn divide_column<E: FieldElement>(
+    column: &mut [E],
     divisor: &ConstraintDivisor<E::BaseField>,
     domain: &StarkDomain<E::BaseField>,
-    result: &mut [E],
 ) {
     let numerator = divisor.numerator();
     assert_eq!(numerator.len(), 1, "complex divisors are not yet supported");
@@ -329,37 +373,34 @@ fn acc_column<E: FieldElement>(
     // divide column values by the divisor; for boundary constraints this computed simply as
     // multiplication of column value by the inverse of divisor numerator; for transition
     // constraints, it is computed similarly, but the result is also multiplied by the divisor's
-    // denominator (exclusion point).
-    if divisor.exemptions().is_empty() {
+    // denominator (exclusion points).
+    if !divisor.has_exemptions() {
         // the column represents merged evaluations of boundary constraints, and divisor has the
         // form of (x^a - b); thus to divide the column by the divisor, we compute: value * z,
         // where z = 1 / (x^a - 1) and has already been computed above.
-        iter_mut!(result, 1024)
-            .zip(column)
-            .enumerate()
-            .for_each(|(i, (acc_value, value))| {
-                // determine which value of z corresponds to the current domain point
-                let z = z[i % z.len()];
-                // compute value * z and add it to the result
-                *acc_value += value.mul_base(z);
-            });
+        iter_mut!(column, 1024).enumerate().for_each(|(i, value)| {
+            // determine which value of z corresponds to the current domain point
+            let z = z[i % z.len()];
+            // compute value * z
+            *value = value.mul_base(z);
+        });
     } else {
         // the column represents merged evaluations of transition constraints, and divisor has the
-        // form of (x^a - 1) / e(x), where e(x) describes the exemption points; thus, to divide
+        // form of (x^a - 1) / e(x), where e(x) describes the exempt steps; thus, to divide
         // the column by the divisor, we compute: value * e(x) * z, where z = 1 / (x^a - 1) and has
         // already been computed above.
         batch_iter_mut!(
-            result,
+            column,
             128, // min batch size
             |batch: &mut [E], batch_offset: usize| {
-                for (i, acc_value) in batch.iter_mut().enumerate() {
+                for (i, value) in batch.iter_mut().enumerate() {
                     // compute value of e(x) and compute next value of x
                     let x = domain.get_ce_x_at(batch_offset + i);
                     let e = divisor.evaluate_exemptions_at(x);
                     // determine which value of z corresponds to the current domain point
-                    let z = z[i % z.len()];
-                    // compute value * e(x) * z and add it to the result
-                    *acc_value += column[batch_offset + i].mul_base(z * e);
+                    let z = z[(batch_offset + i) % z.len()];
+                    // compute value * e(x) * z
+                    *value = value.mul_base(z * e);
                 }
             }
         );
@@ -389,17 +430,19 @@ fn get_inv_evaluation<B: StarkField>(
     let domain_offset_exp = domain.offset().exp(a.into());
 
     // compute x^a - b for all x
-    let mut evaluations = unsafe { uninit_vector(n) };
+    let mut evaluations = uninit_vector::<B>(n);
     batch_iter_mut!(
         &mut evaluations,
         128, // min batch size
-        |batch: &mut [B], batch_offset: usize| {
+        |batch: &mut [MaybeUninit<B>], batch_offset: usize| {
             for (i, evaluation) in batch.iter_mut().enumerate() {
                 let x = domain.get_ce_x_power_at(batch_offset + i, a, domain_offset_exp);
-                *evaluation = x - b;
+                evaluation.write(x - b);
             }
         }
     );
+    // SAFETY: all evaluations have been initialized above
+    let evaluations = unsafe { assume_init_vector(evaluations) };
 
     // compute 1 / (x^a - b)
     batch_inversion(&evaluations)
@@ -422,17 +465,15 @@ fn build_transition_constraint_degrees<E: FieldElement>(
     constraints: &TransitionConstraints<E>,
     trace_length: usize,
 ) -> Vec<usize> {
-    let mut result = Vec::new();
-
-    for degree in constraints.main_constraint_degrees() {
-        result.push(degree.get_evaluation_degree(trace_length) - constraints.divisor().degree())
-    }
-
-    for degree in constraints.aux_constraint_degrees() {
-        result.push(degree.get_evaluation_degree(trace_length) - constraints.divisor().degree())
-    }
-
-    result
+    let degrees = constraints.main_constraint_degrees().iter();
+    degrees
+        .chain(constraints.aux_constraint_degrees())
+        .enumerate()
+        .map(|(i, degree)| {
+            let divisor_degree = constraints.constraint_divisor(i).degree();
+            degree.get_evaluation_degree(trace_length) - divisor_degree
+        })
+        .collect()
 }
 
 /// Computes the actual degree of a transition polynomial described by the provided evaluations.
@@ -474,3 +515,138 @@ fn evaluate_divisor<E: FieldElement>(
     let domain = math::get_power_series_with_offset(g, domain_offset, domain_size);
     domain.into_iter().map(|x| E::from(divisor.evaluate_at(x))).collect()
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec::Vec;
+    #[cfg(not(feature = "concurrent"))]
+    use core::mem::size_of;
+
+    use air::{Air, Assertion, ConstraintDivisor, TransitionConstraints};
+    use math::{
+        fields::{f128::BaseElement, QuadExtension},
+        FieldElement,
+    };
+    use rand_utils::rand_vector;
+
+    use super::ConstraintEvaluationTable;
+    #[cfg(not(feature = "concurrent"))]
+    use crate::tests::count_allocated_bytes;
+    use crate::{tests::MockAir, StarkDomain};
+
+    type QuadElement = QuadExtension<BaseElement>;
+
+    const TRACE_LENGTH: usize = 64;
+
+    #[test]
+    fn combine_constraint_evaluations() {
+        let air = build_air();
+        let domain = StarkDomain::new(&air);
+        let divisors = build_divisors(&air);
+        let num_rows = domain.ce_domain_size();
+
+        let columns: Vec<Vec<QuadElement>> =
+            divisors.iter().map(|_| rand_vector(num_rows)).collect();
+        let transition_constraints = air.get_transition_constraints(&[QuadElement::ONE]);
+        let mut table = build_table(&domain, divisors.clone(), &transition_constraints);
+        for fragment in table.fragments(2).iter_mut() {
+            for i in 0..fragment.num_rows() {
+                let row: Vec<_> =
+                    columns.iter().map(|column| column[fragment.offset() + i]).collect();
+                fragment.update_row(i, &row);
+            }
+        }
+        let combined = table.combine();
+
+        // the combined column is the sum of all columns divided by their divisors
+        let expected: Vec<QuadElement> = (0..num_rows)
+            .map(|i| {
+                let x = domain.get_ce_x_at(i);
+                columns.iter().zip(divisors.iter()).fold(
+                    QuadElement::ZERO,
+                    |acc, (column, divisor)| {
+                        acc + column[i] / QuadElement::from(divisor.evaluate_at(x))
+                    },
+                )
+            })
+            .collect();
+        assert_eq!(expected, combined);
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    #[test]
+    fn evaluation_table_allocations() {
+        let air = build_air();
+        let domain = StarkDomain::new(&air);
+        let divisors = build_divisors(&air);
+        let num_rows = domain.ce_domain_size();
+        let num_columns = divisors.len();
+        let inv_divisor_lengths: Vec<usize> =
+            divisors.iter().map(|divisor| num_rows / divisor.numerator()[0].0).collect();
+
+        // evaluations of all constraint groups are kept in a single allocation; in debug mode,
+        // evaluations of individual transition constraints are kept in a separate allocation,
+        // together with their expected degrees and divisor indexes
+        let transition_constraints = air.get_transition_constraints(&[QuadElement::ONE]);
+        let (table, num_bytes) =
+            count_allocated_bytes(|| build_table(&domain, divisors, &transition_constraints));
+        let mut expected_bytes = num_columns * num_rows * size_of::<QuadElement>();
+        #[cfg(debug_assertions)]
+        {
+            expected_bytes += num_rows * size_of::<BaseElement>() + 2 * size_of::<usize>();
+        }
+        assert_eq!(expected_bytes, num_bytes);
+
+        // combining the table allocates memory only for the inverse evaluations of the divisors,
+        // and reuses the memory of the first column for the combined column
+        let (combined, num_bytes) = count_allocated_bytes(|| table.combine());
+        let expected_bytes: usize = inv_divisor_lengths
+            .iter()
+            .map(|&length| 2 * length * size_of::<BaseElement>())
+            .sum();
+        assert_eq!(expected_bytes, num_bytes);
+        assert_eq!(num_rows, combined.len());
+        assert_eq!(num_rows, combined.capacity());
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn build_air() -> MockAir {
+        let assertions = vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::single(1, TRACE_LENGTH - 1, BaseElement::ONE),
+            Assertion::periodic(2, 0, 8, BaseElement::ONE),
+        ];
+        MockAir::with_assertions(assertions, TRACE_LENGTH)
+    }
+
+    fn build_divisors(air: &MockAir) -> Vec<ConstraintDivisor<BaseElement>> {
+        let mut divisors = vec![ConstraintDivisor::from_transition(TRACE_LENGTH, 1)];
+        for assertion in air.get_assertions() {
+            divisors.push(ConstraintDivisor::from_assertion(&assertion, TRACE_LENGTH));
+        }
+        divisors
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn build_table<'a>(
+        domain: &'a StarkDomain<BaseElement>,
+        divisors: Vec<ConstraintDivisor<BaseElement>>,
+        _transition_constraints: &TransitionConstraints<QuadElement>,
+    ) -> ConstraintEvaluationTable<'a, QuadElement> {
+        ConstraintEvaluationTable::new(domain, divisors)
+    }
+
+    #[cfg(debug_assertions)]
+    fn build_table<'a>(
+        domain: &'a StarkDomain<BaseElement>,
+        divisors: Vec<ConstraintDivisor<BaseElement>>,
+        transition_constraints: &TransitionConstraints<QuadElement>,
+    ) -> ConstraintEvaluationTable<'a, QuadElement> {
+        ConstraintEvaluationTable::new(domain, divisors, transition_constraints)
+    }
+}
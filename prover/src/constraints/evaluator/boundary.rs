@@ -123,7 +123,8 @@ impl<E: FieldElement> BoundaryConstraints<E> {
 // ================================================================================================
 
 /// Contains constraints all having the same divisor. The constraints are separated into single
-/// value constraints, small polynomial constraints, and large polynomial constraints.
+/// value constraints, linear combination constraints, small polynomial constraints, and large
+/// polynomial constraints.
 ///
 /// The constraints are also separated into constraints against the main segment of the execution
 /// and the constraints against auxiliary segments of the execution trace (if any).
@@ -131,10 +132,12 @@ pub struct BoundaryConstraintGroup<E: FieldElement> {
     divisor: ConstraintDivisor<E::BaseField>,
     // main trace constraints
     main_single_value: Vec<SingleValueConstraint<E::BaseField, E>>,
+    main_linear_combination: Vec<LinearCombinationConstraint<E::BaseField, E>>,
     main_small_poly: Vec<SmallPolyConstraint<E::BaseField, E>>,
     main_large_poly: Vec<LargePolyConstraint<E::BaseField, E>>,
     // auxiliary trace constraints
     aux_single_value: Vec<SingleValueConstraint<E, E>>,
+    aux_linear_combination: Vec<LinearCombinationConstraint<E, E>>,
     aux_small_poly: Vec<SmallPolyConstraint<E, E>>,
     aux_large_poly: Vec<LargePolyConstraint<E, E>>,
 }
@@ -149,9 +152,11 @@ impl<E: FieldElement> BoundaryConstraintGroup<E> {
         Self {
             divisor,
             main_single_value: Vec::new(),
+            main_linear_combination: Vec::new(),
             main_small_poly: Vec::new(),
             main_large_poly: Vec::new(),
             aux_single_value: Vec::new(),
+            aux_linear_combination: Vec::new(),
             aux_small_poly: Vec::new(),
             aux_large_poly: Vec::new(),
         }
@@ -171,7 +176,10 @@ impl<E: FieldElement> BoundaryConstraintGroup<E> {
         let mut result = Self::new(source.divisor().clone());
 
         for constraint in source.constraints() {
-            if constraint.poly().len() == 1 {
+            if !constraint.linear_terms().is_empty() {
+                let constraint = LinearCombinationConstraint::new(constraint);
+                result.main_linear_combination.push(constraint);
+            } else if constraint.poly().len() == 1 {
                 let constraint = SingleValueConstraint::new(constraint);
                 result.main_single_value.push(constraint);
             } else if constraint.poly().len() < SMALL_POLY_DEGREE {
@@ -222,7 +230,10 @@ impl<E: FieldElement> BoundaryConstraintGroup<E> {
         assert_eq!(group.divisor(), &self.divisor, "inconsistent constraint divisor");
 
         for constraint in group.constraints() {
-            if constraint.poly().len() == 1 {
+            if !constraint.linear_terms().is_empty() {
+                let constraint = LinearCombinationConstraint::new(constraint);
+                self.aux_linear_combination.push(constraint);
+            } else if constraint.poly().len() == 1 {
                 let constraint = SingleValueConstraint::new(constraint);
                 self.aux_single_value.push(constraint);
             } else if constraint.poly().len() < SMALL_POLY_DEGREE {
@@ -248,6 +259,11 @@ impl<E: FieldElement> BoundaryConstraintGroup<E> {
             result += constraint.evaluate(state);
         }
 
+        // evaluate all linear combination constraints
+        for constraint in self.main_linear_combination.iter() {
+            result += constraint.evaluate(state);
+        }
+
         // evaluate all small polynomial constraints
         for constraint in self.main_small_poly.iter() {
             result += constraint.evaluate(state, x);
@@ -277,6 +293,11 @@ impl<E: FieldElement> BoundaryConstraintGroup<E> {
             result += constraint.evaluate(aux_state);
         }
 
+        // evaluate all linear combination constraints
+        for constraint in self.aux_linear_combination.iter() {
+            result += constraint.evaluate(aux_state);
+        }
+
         // evaluate all small polynomial constraints
         for constraint in self.aux_small_poly.iter() {
             result += constraint.evaluate(aux_state, x);
@@ -331,6 +352,47 @@ where
     }
 }
 
+/// A constraint where the numerator can be represented by sum(k_i * p_i(x)) - v, where v is the
+/// asserted value, and p_i(x) are the trace polynomials for the columns referenced by the linear
+/// combination with coefficients k_i.
+struct LinearCombinationConstraint<F, E>
+where
+    F: FieldElement,
+    E: FieldElement<BaseField = F::BaseField> + ExtensionOf<F>,
+{
+    terms: Vec<(usize, F)>,
+    value: F,
+    coefficients: E,
+}
+
+impl<F, E> LinearCombinationConstraint<F, E>
+where
+    F: FieldElement,
+    E: FieldElement<BaseField = F::BaseField> + ExtensionOf<F>,
+{
+    /// Returns an new instance of [LinearCombinationConstraint] created from the specified source
+    /// boundary constraint.
+    pub fn new(source: &air::BoundaryConstraint<F, E>) -> Self {
+        debug_assert!(!source.linear_terms().is_empty(), "not a linear combination constraint");
+        Self {
+            terms: source.linear_terms().to_vec(),
+            value: source.poly()[0],
+            coefficients: *source.cc(),
+        }
+    }
+
+    /// Evaluates this constraint over the specified state and returns the result.
+    ///
+    /// This also multiplies by the composition coefficient.
+    pub fn evaluate(&self, state: &[F]) -> E {
+        let combination = self
+            .terms
+            .iter()
+            .fold(F::ZERO, |acc, &(column, coeff)| acc + coeff * state[column]);
+        self.coefficients.mul_base(combination - self.value)
+    }
+}
+
 /// A constraint where the numerator can be represented by p(x) - c(x), where b(x) is the
 /// polynomial describing a set of asserted values. This specialization is useful when the
 /// degree of b(x) is relatively small, and thus, is cheap to evaluate on the fly.
@@ -452,3 +514,67 @@ where
         (self.coefficients).mul_base(evaluation)
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use air::{Air, Assertion};
+    use math::{fields::f128::BaseElement, FieldElement};
+    use rand_utils::rand_vector;
+
+    use super::BoundaryConstraints;
+    use crate::{tests::MockAir, StarkDomain};
+
+    #[test]
+    fn boundary_constraints_grouped_by_divisor() {
+        // 16 assertions placed on 4 distinct steps
+        let trace_length = 16;
+        let steps = [0, 3, 7, 15];
+        let assertions = steps
+            .iter()
+            .flat_map(|&step| {
+                (0..4).map(move |column| {
+                    Assertion::single(column, step, BaseElement::new((column * step) as u128))
+                })
+            })
+            .collect::<Vec<_>>();
+        let air = MockAir::with_assertions(assertions, trace_length);
+        let domain = StarkDomain::new(&air);
+        let coefficients = rand_vector::<BaseElement>(16);
+
+        // constraints sharing a step share a divisor, and thus, are evaluated in the same group
+        let constraints = BoundaryConstraints::<BaseElement>::new(&air, None, &coefficients);
+        let divisors = constraints.get_divisors();
+        assert_eq!(steps.len(), divisors.len());
+
+        // dividing the merged evaluation of each group by its divisor is the same as dividing
+        // the evaluation of every constraint by its own divisor
+        let source = air.get_boundary_constraints::<BaseElement>(None, &coefficients);
+        let mut result = vec![BaseElement::ZERO; divisors.len()];
+        for step in [0, 1, 17, domain.ce_domain_size() - 1] {
+            let state = rand_vector::<BaseElement>(4);
+            let x = domain.get_ce_x_at(step);
+
+            constraints.evaluate_main(&state, &domain, step, &mut result);
+            let grouped = result
+                .iter()
+                .zip(divisors.iter())
+                .fold(BaseElement::ZERO, |acc, (&value, divisor)| {
+                    acc + value / divisor.evaluate_at(x)
+                });
+
+            let mut individual = BaseElement::ZERO;
+            for group in source.main_constraints() {
+                for constraint in group.constraints() {
+                    let evaluation = constraint.evaluate_at(x, constraint.trace_value(&state));
+                    individual += evaluation * *constraint.cc() / group.divisor().evaluate_at(x);
+                }
+            }
+            assert_eq!(individual, grouped);
+        }
+    }
+}
@@ -3,11 +3,12 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::vec::Vec;
+
 use air::{
     Air, AuxRandElements, ConstraintCompositionCoefficients, EvaluationFrame, TransitionConstraints,
 };
 use math::FieldElement;
-use tracing::instrument;
 use utils::iter_mut;
 #[cfg(feature = "concurrent")]
 use utils::{iterators::*, rayon};
@@ -43,6 +44,7 @@ pub struct DefaultConstraintEvaluator<'a, A: Air, E: FieldElement<BaseField = A:
     lagrange_constraints_evaluator: Option<LagrangeKernelConstraintsBatchEvaluator<E>>,
     aux_rand_elements: Option<AuxRandElements<E>>,
     periodic_values: PeriodicValueTable<E::BaseField>,
+    main_columns: Option<Vec<usize>>,
 }
 
 impl<A, E> ConstraintEvaluator<E> for DefaultConstraintEvaluator<'_, A, E>
@@ -52,11 +54,14 @@ where
 {
     type Air = A;
 
-    #[instrument(
-        skip_all,
-        name = "evaluate_constraints",
-        fields(
-            ce_domain_size = %domain.ce_domain_size()
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            name = "evaluate_constraints",
+            fields(
+                ce_domain_size = %domain.ce_domain_size()
+            )
         )
     )]
     fn evaluate<T: TraceLde<E>>(
@@ -70,10 +75,10 @@ where
             "extended trace length is not consistent with evaluation domain"
         );
 
-        // build a list of constraint divisors; currently, all transition constraints have the same
-        // divisor which we put at the front of the list; boundary constraint divisors are appended
-        // after that
-        let mut divisors = vec![self.transition_constraints.divisor().clone()];
+        // build a list of constraint divisors; transition constraint divisors are put at the front
+        // of the list (usually, all transition constraints have the same divisor); boundary
+        // constraint divisors are appended after that
+        let mut divisors = self.transition_constraints.divisors().to_vec();
         divisors.append(&mut self.boundary_constraints.get_divisors());
 
         // allocate space for constraint evaluations; when we are in debug mode, we also allocate
@@ -175,6 +180,9 @@ where
             None
         };
 
+        // determine which columns of the main trace need to be read into evaluation frames
+        let main_columns = get_main_columns(air);
+
         DefaultConstraintEvaluator {
             air,
             boundary_constraints,
@@ -182,6 +190,7 @@ where
             lagrange_constraints_evaluator,
             aux_rand_elements,
             periodic_values,
+            main_columns,
         }
     }
 
@@ -198,9 +207,12 @@ where
         fragment: &mut EvaluationTableFragment<E>,
     ) {
         // initialize buffers to hold trace values and evaluation results at each step;
-        let mut main_frame = EvaluationFrame::new(trace.trace_info().main_trace_width());
+        let frame_size = self.air.frame_size();
+        let mut main_frame =
+            EvaluationFrame::with_num_rows(trace.trace_info().main_trace_width(), frame_size);
         let mut evaluations = vec![E::ZERO; fragment.num_columns()];
         let mut t_evaluations = vec![E::BaseField::ZERO; self.num_main_transition_constraints()];
+        let num_t_divisors = self.transition_constraints.divisors().len();
 
         // this will be used to convert steps in constraint evaluation domain to steps in
         // LDE domain
@@ -213,11 +225,16 @@ where
             // read current and next rows from the trace into the buffer; data in the trace
             // table is extended over the LDE domain, so, we need to convert step in constraint
             // evaluation domain, into a step in LDE domain, in case these domains are different
-            trace.read_main_trace_frame_into(step << lde_shift, &mut main_frame);
-
-            // evaluate transition constraints and save the merged result the first slot of the
-            // evaluations buffer
-            evaluations[0] = self.evaluate_main_transition(&main_frame, step, &mut t_evaluations);
+            self.read_main_frame(trace, step << lde_shift, &mut main_frame);
+
+            // evaluate transition constraints and save the results merged by divisor into the
+            // first slots of the evaluations buffer
+            self.evaluate_main_transition(&main_frame, step, &mut t_evaluations);
+            self.transition_constraints.merge_evaluations(
+                &t_evaluations,
+                &[],
+                &mut evaluations[..num_t_divisors],
+            );
 
             // when in debug mode, save transition constraint evaluations
             #[cfg(debug_assertions)]
@@ -230,7 +247,7 @@ where
                 main_state,
                 domain,
                 step,
-                &mut evaluations[1..],
+                &mut evaluations[num_t_divisors..],
             );
 
             // record the result in the evaluation table
@@ -249,11 +266,15 @@ where
         fragment: &mut EvaluationTableFragment<E>,
     ) {
         // initialize buffers to hold trace values and evaluation results at each step
-        let mut main_frame = EvaluationFrame::new(trace.trace_info().main_trace_width());
-        let mut aux_frame = EvaluationFrame::new(trace.trace_info().aux_segment_width());
+        let frame_size = self.air.frame_size();
+        let mut main_frame =
+            EvaluationFrame::with_num_rows(trace.trace_info().main_trace_width(), frame_size);
+        let mut aux_frame =
+            EvaluationFrame::with_num_rows(trace.trace_info().aux_segment_width(), frame_size);
         let mut tm_evaluations = vec![E::BaseField::ZERO; self.num_main_transition_constraints()];
         let mut ta_evaluations = vec![E::ZERO; self.num_aux_transition_constraints()];
         let mut evaluations = vec![E::ZERO; fragment.num_columns()];
+        let num_t_divisors = self.transition_constraints.divisors().len();
 
         // this will be used to convert steps in constraint evaluation domain to steps in
         // LDE domain
@@ -263,16 +284,19 @@ where
             let step = i + fragment.offset();
 
             // read both the main and the auxiliary evaluation frames from the trace
-            trace.read_main_trace_frame_into(step << lde_shift, &mut main_frame);
+            self.read_main_frame(trace, step << lde_shift, &mut main_frame);
             trace.read_aux_trace_frame_into(step << lde_shift, &mut aux_frame);
 
-            // evaluate transition constraints and save the merged result the first slot of the
-            // evaluations buffer; we evaluate and compose constraints in the same function, we
-            // can just add up the results of evaluating main and auxiliary constraints.
-            evaluations[0] = self.evaluate_main_transition(&main_frame, step, &mut tm_evaluations);
-
-            evaluations[0] +=
-                self.evaluate_aux_transition(&main_frame, &aux_frame, step, &mut ta_evaluations);
+            // evaluate transition constraints and save the results merged by divisor into the
+            // first slots of the evaluations buffer; evaluations of main and auxiliary constraints
+            // sharing a divisor are added up.
+            self.evaluate_main_transition(&main_frame, step, &mut tm_evaluations);
+            self.evaluate_aux_transition(&main_frame, &aux_frame, step, &mut ta_evaluations);
+            self.transition_constraints.merge_evaluations(
+                &tm_evaluations,
+                &ta_evaluations,
+                &mut evaluations[..num_t_divisors],
+            );
 
             // when in debug mode, save transition constraint evaluations
             #[cfg(debug_assertions)]
@@ -287,7 +311,7 @@ where
                 aux_state,
                 domain,
                 step,
-                &mut evaluations[1..],
+                &mut evaluations[num_t_divisors..],
             );
 
             // record the result in the evaluation table
@@ -295,6 +319,20 @@ where
         }
     }
 
+    /// Reads the main trace frame at the specified step of the LDE domain; if the AIR specifies
+    /// which columns its constraints use, only these columns are read.
+    fn read_main_frame<T: TraceLde<E>>(
+        &self,
+        trace: &T,
+        lde_step: usize,
+        frame: &mut EvaluationFrame<E::BaseField>,
+    ) {
+        match self.main_columns {
+            Some(ref columns) => trace.read_main_trace_frame_columns_into(lde_step, columns, frame),
+            None => trace.read_main_trace_frame_into(lde_step, frame),
+        }
+    }
+
     /// If present, evaluates the Lagrange kernel constraints over the constraint evaluation domain.
     /// The evaluation of each constraint (both boundary and transition) is divided by its divisor,
     /// multiplied by its composition coefficient, the result of which is added to
@@ -322,16 +360,13 @@ where
     // --------------------------------------------------------------------------------------------
 
     /// Evaluates transition constraints of the main execution trace at the specified step of the
-    /// constraint evaluation domain.
-    ///
-    /// `x` is the corresponding domain value at the specified step. That is, x = s * g^step,
-    /// where g is the generator of the constraint evaluation domain, and s is the domain offset.
+    /// constraint evaluation domain and saves the results into `evaluations`.
     fn evaluate_main_transition(
         &self,
         main_frame: &EvaluationFrame<E::BaseField>,
         step: usize,
         evaluations: &mut [E::BaseField],
-    ) -> E {
+    ) {
         // TODO: use a more efficient way to zero out memory
         evaluations.fill(E::BaseField::ZERO);
 
@@ -341,27 +376,17 @@ where
         // evaluate transition constraints over the main segment of the execution trace and save
         // the results into evaluations buffer
         self.air.evaluate_transition(main_frame, periodic_values, evaluations);
-
-        // merge transition constraint evaluations into a single value and return it;
-        // we can do this here because all transition constraints have the same divisor.
-        evaluations
-            .iter()
-            .zip(self.transition_constraints.main_constraint_coef().iter())
-            .fold(E::ZERO, |acc, (&const_eval, &coef)| acc + coef.mul_base(const_eval))
     }
 
-    /// Evaluates all transition constraints (i.e., for main and the auxiliary trace segment) at the
-    /// specified step of the constraint evaluation domain.
-    ///
-    /// `x` is the corresponding domain value at the specified step. That is, x = s * g^step,
-    /// where g is the generator of the constraint evaluation domain, and s is the domain offset.
+    /// Evaluates transition constraints of the auxiliary trace segment at the specified step of
+    /// the constraint evaluation domain and saves the results into `evaluations`.
     fn evaluate_aux_transition(
         &self,
         main_frame: &EvaluationFrame<E::BaseField>,
         aux_frame: &EvaluationFrame<E>,
         step: usize,
         evaluations: &mut [E],
-    ) -> E {
+    ) {
         // TODO: use a more efficient way to zero out memory
         evaluations.fill(E::ZERO);
 
@@ -379,13 +404,6 @@ where
                 .expect("expected aux rand elements to be present"),
             evaluations,
         );
-
-        // merge transition constraint evaluations into a single value and return it;
-        // we can do this here because all transition constraints have the same divisor.
-        evaluations
-            .iter()
-            .zip(self.transition_constraints.aux_constraint_coef().iter())
-            .fold(E::ZERO, |acc, (&const_eval, &coef)| acc + coef * const_eval)
     }
 
     // ACCESSORS
@@ -402,3 +420,142 @@ where
         self.transition_constraints.num_aux_constraints()
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the main trace columns referenced by transition constraints and assertions of the
+/// specified AIR sorted in ascending order, or `None` if the AIR does not specify which columns
+/// its transition constraints use.
+///
+/// # Panics
+/// Panics if any of the columns specified by the AIR is out of bounds for the main trace.
+fn get_main_columns<A: Air>(air: &A) -> Option<Vec<usize>> {
+    let main_trace_width = air.trace_info().main_trace_width();
+    let mut columns = air.used_columns()?.to_vec();
+    for &column in columns.iter() {
+        assert!(
+            column < main_trace_width,
+            "used column {column} is out of bounds for main trace of width {main_trace_width}"
+        );
+    }
+
+    // boundary constraints are evaluated against the current row of the main frame, and thus,
+    // columns referenced by assertions need to be read as well
+    for assertion in air.get_assertions() {
+        if assertion.is_linear_combination() {
+            columns.extend(assertion.linear_terms().iter().map(|&(column, _)| column));
+        } else {
+            columns.push(assertion.column());
+        }
+    }
+    columns.sort_unstable();
+    columns.dedup();
+
+    Some(columns)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "std", not(feature = "concurrent")))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use air::{
+        Air, AirContext, Assertion, ConstraintCompositionCoefficients, EvaluationFrame,
+        FieldExtension, PartitionOptions, ProofOptions, TraceInfo, TransitionConstraintDegree,
+    };
+    use crypto::{hashers::Blake3_256, MerkleTree};
+    use math::{fields::f128::BaseElement, FieldElement};
+    use rand_utils::rand_vector;
+
+    use super::DefaultConstraintEvaluator;
+    use crate::{
+        tests::count_allocations, ColMatrix, ConstraintEvaluator, DefaultTraceLde, StarkDomain,
+    };
+
+    type Blake3 = Blake3_256<BaseElement>;
+
+    #[test]
+    fn evaluation_frames_are_reused_across_steps() {
+        // the number of allocations made while evaluating constraints does not depend on the
+        // size of the constraint evaluation domain because a single evaluation frame is allocated
+        // per fragment and then re-filled at every step
+        let num_allocations = [64, 1024].map(|trace_length| {
+            let air = SquareAir::new(TraceInfo::new(4, trace_length), (), SquareAir::options());
+            let domain = StarkDomain::new(&air);
+            let mut columns =
+                (0..4).map(|_| rand_vector::<BaseElement>(trace_length)).collect::<Vec<_>>();
+            for i in 1..trace_length {
+                columns[0][i] = columns[0][i - 1].square();
+            }
+            let main_trace = ColMatrix::new(columns);
+            let (trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+                air.trace_info(),
+                &main_trace,
+                &domain,
+                PartitionOptions::default(),
+            );
+
+            let coefficients = ConstraintCompositionCoefficients {
+                transition: rand_vector(1),
+                boundary: rand_vector(1),
+                lagrange: None,
+            };
+            let evaluator = DefaultConstraintEvaluator::new(&air, None, coefficients);
+            let (evaluations, num_allocations) =
+                count_allocations(|| evaluator.evaluate(&trace_lde, &domain));
+            assert_eq!(domain.ce_domain_size(), evaluations.num_rows());
+            num_allocations
+        });
+        assert_ne!(0, num_allocations[0]);
+        assert_eq!(num_allocations[0], num_allocations[1]);
+    }
+
+    // SQUARE AIR
+    // --------------------------------------------------------------------------------------------
+
+    /// An AIR with a single degree 2 transition constraint: the first column of the next row is
+    /// the square of the first column of the current row.
+    struct SquareAir {
+        context: AirContext<BaseElement>,
+    }
+
+    impl SquareAir {
+        fn options() -> ProofOptions {
+            ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31)
+        }
+    }
+
+    impl Air for SquareAir {
+        type BaseField = BaseElement;
+        type PublicInputs = ();
+        type GkrProof = ();
+        type GkrVerifier = ();
+
+        fn new(trace_info: TraceInfo, _pub_inputs: (), options: ProofOptions) -> Self {
+            let degrees = vec![TransitionConstraintDegree::new(2)];
+            Self {
+                context: AirContext::new(trace_info, degrees, 1, options),
+            }
+        }
+
+        fn context(&self) -> &AirContext<Self::BaseField> {
+            &self.context
+        }
+
+        fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+            &self,
+            frame: &EvaluationFrame<E>,
+            _periodic_values: &[E],
+            result: &mut [E],
+        ) {
+            result[0] = frame.next()[0] - frame.current()[0].square();
+        }
+
+        fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+            vec![Assertion::single(1, 0, BaseElement::ONE)]
+        }
+    }
+}
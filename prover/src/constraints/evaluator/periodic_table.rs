@@ -7,7 +7,6 @@ use alloc::{collections::BTreeMap, vec::Vec};
 
 use air::Air;
 use math::{fft, StarkField};
-use utils::uninit_vector;
 
 pub struct PeriodicValueTable<B: StarkField> {
     values: Vec<B>,
@@ -54,7 +53,7 @@ impl<B: StarkField> PeriodicValueTable<B> {
         // table in such a way that values for the same row are adjacent to each other.
         let row_width = polys.len();
         let column_length = max_poly_size * air.ce_blowup_factor();
-        let mut values = unsafe { uninit_vector(row_width * column_length) };
+        let mut values = vec![B::ZERO; row_width * column_length];
         for i in 0..column_length {
             for (j, column) in evaluations.iter().enumerate() {
                 values[i * row_width + j] = column[i % column.len()];
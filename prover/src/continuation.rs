@@ -0,0 +1,147 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::{
+    proof::{ContinuationProof, ContinuationState},
+    Air, TraceInfo,
+};
+use math::ToElements;
+use maybe_async::{maybe_async, maybe_await};
+
+use super::{Proof, Prover, ProverError, Trace};
+
+// CONTINUATION PROVER
+// ================================================================================================
+
+/// Generates proofs for computations which are split into multiple segments of the same length.
+///
+/// Each segment is proven independently using the underlying [Prover], and thus, only the trace
+/// of a single segment needs to be kept in memory at any given time. This makes it possible to
+/// prove computations with traces which are too large to fit into memory.
+///
+/// The AIR of the prover must support continuations: its public inputs must be derivable from
+/// a [ContinuationState], and it must enforce that the trace of a segment starts in the initial
+/// state and ends in the final state of the segment. The resulting [ContinuationProof] contains
+/// the states and the proofs of all segments; the verifier checks all segment proofs as well as
+/// that the final state of every segment is equal to the initial state of the next segment.
+pub struct ContinuationProver<P: Prover> {
+    prover: P,
+    segment_length: usize,
+    segments: Vec<(ContinuationState<P::BaseField>, Proof)>,
+}
+
+impl<P> ContinuationProver<P>
+where
+    P: Prover,
+    <P::Air as Air>::PublicInputs: From<ContinuationState<P::BaseField>>,
+{
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new continuation prover which proves segments of `segment_length` steps using
+    /// the specified `prover`.
+    ///
+    /// # Panics
+    /// Panics if `segment_length` is not a power of two or is smaller than 8.
+    pub fn new(prover: P, segment_length: usize) -> Self {
+        assert!(
+            segment_length >= TraceInfo::MIN_TRACE_LENGTH,
+            "segment length must be at least {}, but was {}",
+            TraceInfo::MIN_TRACE_LENGTH,
+            segment_length
+        );
+        assert!(
+            segment_length.is_power_of_two(),
+            "segment length must be a power of two, but was {segment_length}"
+        );
+        Self {
+            prover,
+            segment_length,
+            segments: Vec::new(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of steps in each segment.
+    pub fn segment_length(&self) -> usize {
+        self.segment_length
+    }
+
+    /// Returns the number of segments proven so far.
+    pub fn num_segments(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns the final state of the last segment proven so far, or `None` if no segments
+    /// have been proven yet.
+    ///
+    /// The initial state of the next segment must be equal to this state.
+    pub fn last_state(&self) -> Option<&[P::BaseField]> {
+        self.segments.last().map(|(state, _)| state.final_state())
+    }
+
+    // SEGMENT PROVING
+    // --------------------------------------------------------------------------------------------
+
+    /// Generates a proof for the next segment of the computation and appends it to the list of
+    /// segment proofs; the trace of the segment is dropped once the proof is generated.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The length of `trace_segment` is not equal to the segment length of this prover.
+    /// * The initial state of `continuation_state` is not equal to the final state of the
+    ///   previous segment.
+    /// * The public inputs which the underlying prover derives from `trace_segment` are not the
+    ///   same as the public inputs derived from `continuation_state`.
+    /// * The underlying prover fails to generate a proof for the segment.
+    #[maybe_async]
+    pub fn add_segment(
+        &mut self,
+        trace_segment: P::Trace,
+        continuation_state: ContinuationState<P::BaseField>,
+    ) -> Result<(), ProverError>
+    where
+        <P::Air as Air>::PublicInputs: Send,
+        <P::Air as Air>::GkrProof: Send,
+    {
+        let segment_idx = self.segments.len();
+
+        if trace_segment.length() != self.segment_length {
+            return Err(ProverError::ContinuationSegmentLengthMismatch {
+                expected: self.segment_length,
+                actual: trace_segment.length(),
+            });
+        }
+
+        if let Some(last_state) = self.last_state() {
+            if last_state != continuation_state.initial_state() {
+                return Err(ProverError::ContinuationStateMismatch(segment_idx));
+            }
+        }
+
+        // the verifier derives public inputs of the segment from the continuation state, and
+        // thus, the proof would not verify unless these are the same as the public inputs used
+        // by the prover
+        let pub_inputs = self.prover.get_pub_inputs(&trace_segment).to_elements();
+        let expected_inputs =
+            <P::Air as Air>::PublicInputs::from(continuation_state.clone()).to_elements();
+        if pub_inputs != expected_inputs {
+            return Err(ProverError::ContinuationPublicInputsMismatch(segment_idx));
+        }
+
+        let proof = maybe_await!(self.prover.prove(trace_segment))?;
+        self.segments.push((continuation_state, proof));
+        Ok(())
+    }
+
+    /// Returns a proof for the sequence of all segments added to this prover.
+    pub fn finalize(self) -> ContinuationProof<P::BaseField> {
+        ContinuationProof::new(self.segments)
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::labels;
+use crypto::Digest;
+use math::{FieldElement, StarkField};
+
+// TRANSCRIPT EVENT
+// ================================================================================================
+
+/// An interaction between the prover and the public coin recorded during proof generation.
+///
+/// Values drawn from the public coin are recorded as elements of the base field; values drawn
+/// from an extension field are recorded as their base field coefficients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscriptEvent<B: StarkField, D: Digest> {
+    /// The public coin was reseeded with the specified digest tagged with the specified domain
+    /// separation label (see [RandomCoin::reseed_with_label()](crypto::RandomCoin::reseed_with_label())).
+    Reseed {
+        label: &'static str,
+        domain_label: &'static [u8],
+        digest: D,
+    },
+    /// The specified values were drawn from the public coin.
+    Draw { label: &'static str, values: Vec<B> },
+    /// The specified proof-of-work nonce was found for the current seed of the public coin.
+    Grind { nonce: u64 },
+    /// The specified query positions were drawn from the public coin; duplicate positions are
+    /// removed.
+    DrawQueryPositions { positions: Vec<usize> },
+}
+
+// PROVING DEBUG TRACE
+// ================================================================================================
+
+/// Intermediate state of the prover recorded by [Prover::prove_debug()](crate::Prover::prove_debug).
+///
+/// The trace contains the seed of the public coin followed by every event of the Fiat-Shamir
+/// transcript in the order in which it occurred, as well as the commitments, the out-of-domain
+/// point, and the FRI layers computed by the prover. When a proof fails to verify, this can be
+/// used to replay the transcript on the verifier side (or to compare it with the trace of
+/// another proof via [first_divergence()](Self::first_divergence)) to find the first place where
+/// the prover and the verifier disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvingDebugTrace<B: StarkField, D: Digest> {
+    coin_seed: Vec<B>,
+    events: Vec<TranscriptEvent<B, D>>,
+    trace_roots: Vec<D>,
+    constraint_root: Option<D>,
+    fri_layer_roots: Vec<D>,
+    ood_point: Vec<B>,
+    fri_layers: Vec<Vec<B>>,
+    fri_remainder: Vec<B>,
+}
+
+impl<B: StarkField, D: Digest> ProvingDebugTrace<B, D> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new debug trace for a public coin instantiated with the specified seed.
+    pub(crate) fn new(coin_seed: Vec<B>) -> Self {
+        Self {
+            coin_seed,
+            events: Vec::new(),
+            trace_roots: Vec::new(),
+            constraint_root: None,
+            fri_layer_roots: Vec::new(),
+            ood_point: Vec::new(),
+            fri_layers: Vec::new(),
+            fri_remainder: Vec::new(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the elements from which the public coin was instantiated; these are the elements
+    /// of the proof context followed by the elements of the public inputs.
+    pub fn coin_seed(&self) -> &[B] {
+        &self.coin_seed
+    }
+
+    /// Returns all transcript events in the order in which they occurred.
+    pub fn events(&self) -> &[TranscriptEvent<B, D>] {
+        &self.events
+    }
+
+    /// Returns commitments to the trace segments; the first commitment is the commitment to the
+    /// main trace segment.
+    pub fn trace_roots(&self) -> &[D] {
+        &self.trace_roots
+    }
+
+    /// Returns the commitment to the evaluations of the constraint composition polynomial.
+    pub fn constraint_root(&self) -> Option<D> {
+        self.constraint_root
+    }
+
+    /// Returns commitments to the FRI layers; the last commitment is the commitment to the FRI
+    /// remainder polynomial.
+    pub fn fri_layer_roots(&self) -> &[D] {
+        &self.fri_layer_roots
+    }
+
+    /// Returns the out-of-domain point as a list of base field elements.
+    pub fn ood_point(&self) -> &[B] {
+        &self.ood_point
+    }
+
+    /// Returns evaluations of the FRI layer polynomials (excluding the remainder) over their
+    /// respective domains; evaluations of extension field elements are flattened into base field
+    /// elements.
+    pub fn fri_layers(&self) -> &[Vec<B>] {
+        &self.fri_layers
+    }
+
+    /// Returns coefficients of the FRI remainder polynomial.
+    pub fn fri_remainder(&self) -> &[B] {
+        &self.fri_remainder
+    }
+
+    /// Returns query positions drawn by the prover, or `None` if query positions have not been
+    /// drawn.
+    pub fn query_positions(&self) -> Option<&[usize]> {
+        self.events.iter().find_map(|event| match event {
+            TranscriptEvent::DrawQueryPositions { positions } => Some(positions.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Returns the index of the first transcript event which differs between this and the
+    /// `other` trace, or `None` if the transcripts are the same.
+    ///
+    /// If one transcript is a prefix of the other, the index of the first event missing from
+    /// the shorter transcript is returned.
+    pub fn first_divergence(&self, other: &Self) -> Option<usize> {
+        let common_prefix =
+            self.events.iter().zip(other.events.iter()).take_while(|(a, b)| a == b).count();
+        if common_prefix == self.events.len() && common_prefix == other.events.len() {
+            None
+        } else {
+            Some(common_prefix)
+        }
+    }
+
+    // RECORDING METHODS
+    // --------------------------------------------------------------------------------------------
+
+    pub(crate) fn record_trace_root(&mut self, domain_label: &'static [u8], root: D) {
+        self.trace_roots.push(root);
+        self.record_reseed("trace commitment", domain_label, root);
+    }
+
+    pub(crate) fn record_constraint_root(&mut self, root: D) {
+        self.constraint_root = Some(root);
+        self.record_reseed("constraint commitment", labels::CONSTRAINT_COMMITMENT, root);
+    }
+
+    pub(crate) fn record_fri_layer_root(&mut self, root: D) {
+        self.fri_layer_roots.push(root);
+        self.record_reseed("FRI layer commitment", fri::labels::LAYER_COMMITMENT, root);
+    }
+
+    pub(crate) fn record_ood_point<E: FieldElement<BaseField = B>>(&mut self, z: E) {
+        self.ood_point = E::slice_as_base_elements(&[z]).to_vec();
+        self.record_draw("OOD point", &[z]);
+    }
+
+    pub(crate) fn record_reseed(
+        &mut self,
+        label: &'static str,
+        domain_label: &'static [u8],
+        digest: D,
+    ) {
+        self.events.push(TranscriptEvent::Reseed { label, domain_label, digest });
+    }
+
+    pub(crate) fn record_draw<E: FieldElement<BaseField = B>>(
+        &mut self,
+        label: &'static str,
+        values: &[E],
+    ) {
+        let values = E::slice_as_base_elements(values).to_vec();
+        self.events.push(TranscriptEvent::Draw { label, values });
+    }
+
+    pub(crate) fn record_grind(&mut self, nonce: u64) {
+        self.events.push(TranscriptEvent::Grind { nonce });
+    }
+
+    pub(crate) fn record_query_positions(&mut self, positions: &[usize]) {
+        self.events
+            .push(TranscriptEvent::DrawQueryPositions { positions: positions.to_vec() });
+    }
+
+    pub(crate) fn record_fri_layers<E: FieldElement<BaseField = B>>(
+        &mut self,
+        layers: impl IntoIterator<Item = impl AsRef<[E]>>,
+        remainder: &[E],
+    ) {
+        self.fri_layers = layers
+            .into_iter()
+            .map(|layer| E::slice_as_base_elements(layer.as_ref()).to_vec())
+            .collect();
+        self.fri_remainder = E::slice_as_base_elements(remainder).to_vec();
+    }
+}
@@ -5,8 +5,13 @@
 
 //! Contains common error types for prover and verifier.
 
+use alloc::{string::String, vec::Vec};
 use core::fmt;
 
+use air::{PowScheme, TransitionConstraintDegree};
+#[cfg(feature = "std")]
+use utils::DeserializationError;
+
 // PROVER ERROR
 // ================================================================================================
 /// Represents an error returned by the prover during an execution of the protocol.
@@ -21,6 +26,42 @@ pub enum ProverError {
     /// This error occurs when the base field specified by the AIR does not support field extension
     /// of degree specified by proof options.
     UnsupportedFieldExtension(usize),
+    /// This error occurs when the field extension specified by proof options provides fewer bits
+    /// of security than the other components of the protocol, and the prover was configured to
+    /// reject such options (see [Prover::enforce_field_security()](crate::Prover::enforce_field_security)).
+    InsufficientFieldSecurity { field_security: u32, other_security: u32 },
+    /// This error occurs when the conjectured security level of the proof is below
+    /// [ProofOptions::SECURITY_FLOOR](air::ProofOptions::SECURITY_FLOOR), and the prover was not
+    /// configured to allow insecure proofs (see
+    /// [Prover::allow_insecure()](crate::Prover::allow_insecure)).
+    InsufficientSecurity { estimated: u32, required: u32 },
+    /// This error occurs when the proof-of-work scheme specified in proof options is different
+    /// from the proof-of-work scheme of the prover (see
+    /// [Prover::ProofOfWork](crate::Prover::ProofOfWork)).
+    PowSchemeMismatch { expected: PowScheme, actual: PowScheme },
+    /// This error occurs when the blowup factor specified in proof options is smaller than the
+    /// blowup factor of the constraint evaluation domain (see
+    /// [AirContext::ce_blowup_factor()](air::AirContext::ce_blowup_factor)); the error identifies
+    /// the transition constraint of the highest degree.
+    BlowupFactorTooSmall {
+        blowup_factor: usize,
+        ce_blowup_factor: usize,
+        constraint_idx: usize,
+        constraint_degree: TransitionConstraintDegree,
+    },
+    /// This error occurs when the execution trace does not satisfy assertions or transition
+    /// constraints of the AIR against which the proof is generated.
+    InvalidTrace(TraceValidationError),
+    /// This error occurs when the length of a trace segment added to a continuation prover is
+    /// different from the segment length of the prover.
+    ContinuationSegmentLengthMismatch { expected: usize, actual: usize },
+    /// This error occurs when the initial state of a trace segment added to a continuation prover
+    /// is not equal to the final state of the previous segment.
+    ContinuationStateMismatch(usize),
+    /// This error occurs when the public inputs derived from a trace segment added to a
+    /// continuation prover do not match the public inputs derived from the continuation state
+    /// of the segment.
+    ContinuationPublicInputsMismatch(usize),
 }
 
 impl fmt::Display for ProverError {
@@ -36,8 +77,225 @@ impl fmt::Display for ProverError {
             Self::UnsupportedFieldExtension(degree) => {
                 write!(f, "field extension of degree {degree} is not supported for the specified base field")
             }
+            Self::InsufficientFieldSecurity { field_security, other_security } => {
+                write!(f, "field extension provides only {field_security} bits of security, but other protocol components provide {other_security} bits")
+            }
+            Self::InsufficientSecurity { estimated, required } => {
+                write!(f, "conjectured security of the proof is {estimated} bits, but at least {required} bits are required")
+            }
+            Self::PowSchemeMismatch { expected, actual } => {
+                write!(f, "proof options specify {actual:?} proof-of-work scheme, but the prover uses {expected:?} scheme")
+            }
+            Self::BlowupFactorTooSmall { blowup_factor, ce_blowup_factor, constraint_idx, constraint_degree } => {
+                write!(f, "blowup factor {blowup_factor} is too small for transition constraint {constraint_idx} of degree {constraint_degree}; the blowup factor must be at least {ce_blowup_factor}")
+            }
+            Self::InvalidTrace(err) => {
+                write!(f, "execution trace is not valid against the AIR: {err}")
+            }
+            Self::ContinuationSegmentLengthMismatch { expected, actual } => {
+                write!(f, "trace segment must have length {expected}, but had length {actual}")
+            }
+            Self::ContinuationStateMismatch(segment) => {
+                write!(f, "initial state of trace segment {segment} does not match the final state of the previous segment")
+            }
+            Self::ContinuationPublicInputsMismatch(segment) => {
+                write!(f, "public inputs of trace segment {segment} do not match its continuation state")
+            }
         }
     }
 }
 
+impl From<TraceValidationError> for ProverError {
+    fn from(err: TraceValidationError) -> Self {
+        Self::InvalidTrace(err)
+    }
+}
+
 impl core::error::Error for ProverError {}
+
+// TRACE VALIDATION ERROR
+// ================================================================================================
+/// Represents an error returned when an execution trace is checked against an AIR and is found
+/// to be invalid.
+///
+/// Field element values (e.g., constraint evaluations and trace cells) are recorded in their
+/// string representation so that the error type is independent of the field the trace is in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceValidationError {
+    /// This error occurs when the width of the main trace segment is different from the width
+    /// expected by the AIR.
+    InconsistentTraceWidth { expected: usize, actual: usize },
+    /// This error occurs when the unpadded trace length reported by the AIR is zero or greater
+    /// than the length of the trace.
+    InvalidUnpaddedTraceLength {
+        unpadded_length: usize,
+        trace_length: usize,
+    },
+    /// This error occurs when a value in the main trace segment does not match the value
+    /// specified by an assertion.
+    MainAssertionNotSatisfied {
+        column: usize,
+        step: usize,
+        expected: String,
+        actual: String,
+    },
+    /// This error occurs when a value in the auxiliary trace segment does not match the value
+    /// specified by an assertion.
+    AuxAssertionNotSatisfied {
+        column: usize,
+        step: usize,
+        expected: String,
+        actual: String,
+    },
+    /// This error occurs when the first value of the Lagrange kernel column does not match the
+    /// value implied by the Lagrange kernel random elements.
+    LagrangeKernelAssertionNotSatisfied { expected: String, actual: String },
+    /// This error occurs when a main transition constraint does not evaluate to zero at the
+    /// specified step. `row` contains the values of the main trace segment at that step.
+    MainTransitionNotSatisfied {
+        constraint_idx: usize,
+        step: usize,
+        evaluation: String,
+        row: Vec<String>,
+    },
+    /// This error occurs when an auxiliary transition constraint does not evaluate to zero at
+    /// the specified step. `row` contains the values of the auxiliary trace segment at that step.
+    AuxTransitionNotSatisfied {
+        constraint_idx: usize,
+        step: usize,
+        evaluation: String,
+        row: Vec<String>,
+    },
+    /// This error occurs when a Lagrange kernel transition constraint does not evaluate to zero
+    /// at the specified step.
+    LagrangeKernelTransitionNotSatisfied {
+        constraint_idx: usize,
+        step: usize,
+        evaluation: String,
+    },
+}
+
+impl fmt::Display for TraceValidationError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InconsistentTraceWidth { expected, actual } => {
+                write!(f, "inconsistent trace width: expected {expected}, but was {actual}")
+            }
+            Self::InvalidUnpaddedTraceLength { unpadded_length, trace_length } => {
+                write!(f, "unpadded trace length must be between 1 and {trace_length}, but was {unpadded_length}")
+            }
+            Self::MainAssertionNotSatisfied { column, step, expected, actual } => {
+                write!(f, "trace does not satisfy assertion main_trace({column}, {step}) == {expected}; actual value was {actual}")
+            }
+            Self::AuxAssertionNotSatisfied { column, step, expected, actual } => {
+                write!(f, "trace does not satisfy assertion aux_trace({column}, {step}) == {expected}; actual value was {actual}")
+            }
+            Self::LagrangeKernelAssertionNotSatisfied { expected, actual } => {
+                write!(f, "Lagrange kernel column does not start with {expected}; actual value was {actual}")
+            }
+            Self::MainTransitionNotSatisfied { constraint_idx, step, evaluation, row } => {
+                write!(f, "main transition constraint {constraint_idx} evaluated to {evaluation} instead of ZERO at step {step}; main trace row: [{}]", row.join(", "))
+            }
+            Self::AuxTransitionNotSatisfied { constraint_idx, step, evaluation, row } => {
+                write!(f, "auxiliary transition constraint {constraint_idx} evaluated to {evaluation} instead of ZERO at step {step}; auxiliary trace row: [{}]", row.join(", "))
+            }
+            Self::LagrangeKernelTransitionNotSatisfied { constraint_idx, step, evaluation } => {
+                write!(f, "Lagrange transition constraint {constraint_idx} evaluated to {evaluation} instead of ZERO at step {step}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for TraceValidationError {}
+
+// TRACE ERROR
+// ================================================================================================
+/// Represents an error returned when an execution trace could not be read from a stream written
+/// by [TraceTable::write_to()](crate::TraceTable::write_to).
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum TraceError {
+    /// This error occurs when reading from the underlying stream fails (including when the
+    /// stream ends before the entire trace could be read).
+    Io(std::io::Error),
+    /// This error occurs when the stream does not start with the trace file magic bytes.
+    InvalidMagic,
+    /// This error occurs when the trace was written using a trace file format version which is
+    /// not supported by this library.
+    UnsupportedVersion(u8),
+    /// This error occurs when the trace was written for a field different from the base field
+    /// of the trace being read.
+    FieldMismatch,
+    /// This error occurs when the width of the trace is zero or greater than 255.
+    InvalidWidth(u64),
+    /// This error occurs when the length of the trace is smaller than 8, is not a power of two,
+    /// or exceeds the biggest multiplicative subgroup of the base field.
+    InvalidLength(u64),
+    /// This error occurs when the length of the trace metadata is greater than 65535 bytes.
+    InvalidMetaLength(u64),
+    /// This error occurs when a trace value is not a valid encoding of a field element.
+    InvalidElement(DeserializationError),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for TraceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read execution trace: {err}"),
+            Self::InvalidMagic => write!(f, "stream does not contain an execution trace"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "trace file format version {version} is not supported")
+            },
+            Self::FieldMismatch => {
+                write!(f, "execution trace was written for a different base field")
+            },
+            Self::InvalidWidth(width) => {
+                write!(f, "trace width must be between 1 and 255, but was {width}")
+            },
+            Self::InvalidLength(length) => {
+                write!(f, "trace length {length} is not supported by the base field")
+            },
+            Self::InvalidMetaLength(length) => {
+                write!(f, "trace metadata cannot be longer than 65535 bytes, but was {length}")
+            },
+            Self::InvalidElement(err) => write!(f, "trace contains an invalid value: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for TraceError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for TraceError {}
+
+// MPC ERROR
+// ================================================================================================
+/// Represents an error returned when shares of an execution trace held by different parties
+/// cannot be combined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MpcError {
+    /// This error occurs when no trace shares were provided.
+    NoShares,
+    /// This error occurs when the share at the specified index does not have the same number of
+    /// columns and rows as the first share (or as the trace being split into shares).
+    InconsistentShare(usize),
+}
+
+impl fmt::Display for MpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoShares => write!(f, "at least one trace share must be provided"),
+            Self::InconsistentShare(index) => {
+                write!(f, "trace share {index} has different dimensions than the trace")
+            },
+        }
+    }
+}
+
+impl core::error::Error for MpcError {}
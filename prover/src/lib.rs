@@ -42,15 +42,22 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::boxed::Box;
+use core::cmp;
+
 pub use air::{
-    proof, proof::Proof, Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
-    ConstraintCompositionCoefficients, ConstraintDivisor, DeepCompositionCoefficients,
-    EvaluationFrame, FieldExtension, LagrangeKernelRandElements, ProofOptions, TraceInfo,
-    TransitionConstraintDegree,
+    constraint, proof, proof::Proof, Air, AirContext, Assertion, BoundaryConstraint,
+    BoundaryConstraintGroup, ConstraintCompositionCoefficients, ConstraintDivisor,
+    ConstraintExpression, ConstraintType, ConstraintViolation, DeepCompositionCoefficients,
+    EvaluationFrame, FieldExtension, FriFoldingFactor, LagrangeKernelRandElements, PowScheme,
+    ProofOptions, ProofOptionsError, TraceInfo, TransitionConstraintDegree, TransitionExemption,
 };
 use air::{AuxRandElements, GkrRandElements, PartitionOptions};
 pub use crypto;
-use crypto::{ElementHasher, RandomCoin, VectorCommitment};
+use crypto::{ElementHasher, Hasher, ProofOfWork, RandomCoin, VectorCommitment};
 use fri::FriProver;
 pub use math;
 use math::{
@@ -58,7 +65,6 @@ use math::{
     fields::{CubeExtension, QuadExtension},
     ExtensibleField, FieldElement, StarkField, ToElements,
 };
-use tracing::{event, info_span, instrument, Level};
 pub use utils::{
     iterators, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
     SliceReader,
@@ -82,15 +88,36 @@ use composer::DeepCompositionPoly;
 mod trace;
 use maybe_async::{maybe_async, maybe_await};
 pub use trace::{
-    AuxTraceWithMetadata, DefaultTraceLde, Trace, TraceLde, TracePolyTable, TraceTable,
-    TraceTableFragment,
+    validate_trace, AuxTraceWithMetadata, ChunkedTraceLde, DefaultTraceLde, DynamicTraceTable,
+    RowRef, Trace, TraceIterator, TraceLde, TracePadding, TracePolyTable, TraceSegmentRows,
+    TraceSegmentView, TraceTable, TraceTableFragment,
 };
 
 mod channel;
 use channel::ProverChannel;
 
+mod continuation;
+pub use continuation::ContinuationProver;
+
+pub mod mpc;
+
+mod debug;
+pub use debug::{ProvingDebugTrace, TranscriptEvent};
+
+mod profile;
+use profile::PhaseTimer;
+#[cfg(feature = "profile")]
+pub use profile::{ProfilingProver, ProvingProfile};
+
+mod progress;
+#[cfg(feature = "std")]
+pub use progress::StderrProgressListener;
+pub use progress::{ProgressListener, ProvingPhase};
+
 mod errors;
-pub use errors::ProverError;
+#[cfg(feature = "std")]
+pub use errors::TraceError;
+pub use errors::{MpcError, ProverError, TraceValidationError};
 
 #[cfg(test)]
 pub mod tests;
@@ -104,6 +131,20 @@ const DEFAULT_SEGMENT_WIDTH: usize = 8;
 /// Accesses the `GkrProof` type in a [`Prover`].
 pub type ProverGkrProof<P> = <<P as Prover>::Air as Air>::GkrProof;
 
+/// Accesses the [`ProvingDebugTrace`] type recorded by a [`Prover`].
+pub type ProverDebugTrace<P> =
+    ProvingDebugTrace<<P as Prover>::BaseField, <<P as Prover>::HashFn as Hasher>::Digest>;
+
+/// Proof generated by [Prover::generate_proof()] together with the intermediate state recorded
+/// during proof generation.
+#[doc(hidden)]
+pub struct ProvingOutput<P: Prover + ?Sized> {
+    pub proof: Proof,
+    pub debug_trace: Option<ProverDebugTrace<P>>,
+    #[cfg(feature = "profile")]
+    pub profile: Option<ProvingProfile>,
+}
+
 /// Defines a STARK prover for a computation.
 ///
 /// A STARK prover can be used to generate STARK proofs. The prover contains definitions of a
@@ -121,6 +162,11 @@ pub type ProverGkrProof<P> = <<P as Prover>::Air as Air>::GkrProof;
 /// The generated proof is built using protocol parameters defined by the [ProofOptions] struct
 /// return from [Prover::options] method.
 ///
+/// Since [Prover::prove()] takes the prover by reference, a single prover instance can be used
+/// to generate proofs for any number of traces of the same computation. Thus, any state which
+/// is expensive to set up (e.g., precomputed twiddles or handles to hardware accelerators) can
+/// be kept in the prover and reused across proofs.
+///
 /// To further customize the prover, implementers can specify custom implementations of the
 /// [RandomCoin], [TraceLde], and [ConstraintEvaluator] associated types (default implementations
 /// of these types are provided with the prover). For example, providing custom implementations
@@ -145,6 +191,12 @@ pub trait Prover {
     /// PRNG to be used for generating random field elements.
     type RandomCoin: RandomCoin<BaseField = Self::BaseField, Hasher = Self::HashFn>;
 
+    /// Proof-of-work scheme to be used for query seed grinding.
+    ///
+    /// The scheme must be the same as the scheme specified in the proof options (see
+    /// [ProofOptions::pow_scheme()]).
+    type ProofOfWork: ProofOfWork<Self::HashFn>;
+
     /// Trace low-degree extension for building the LDEs of trace segments and their commitments.
     type TraceLde<E>: TraceLde<E, HashFn = Self::HashFn, VC = Self::VC>
     where
@@ -229,6 +281,64 @@ pub trait Prover {
     // PROVIDED METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns true if the execution trace should be validated against the AIR before proof
+    /// generation begins.
+    ///
+    /// In debug builds the trace is always validated; this option enables validation in release
+    /// builds as well. Validation is a very expensive operation, but it can save a lot of time
+    /// when debugging AIR implementations as otherwise an invalid trace is not detected until
+    /// the generated proof fails to verify.
+    ///
+    /// The default implementation returns false.
+    fn validate_before_prove(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the prover should refuse to generate proofs for which the field extension
+    /// is the security bottleneck.
+    ///
+    /// Before generating a proof, the prover compares the number of security bits provided by the
+    /// field (see [ProofOptions::field_security()]) against the security provided by the query
+    /// phase and by the collision resistance of the hash function. If the field provides fewer
+    /// bits, a higher extension degree should be used (see
+    /// [ProofOptions::required_extension_degree()]). When this option is enabled, the prover
+    /// returns [ProverError::InsufficientFieldSecurity] in this case; otherwise, a warning is
+    /// emitted in debug builds.
+    ///
+    /// The default implementation returns false.
+    fn enforce_field_security(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the prover should generate proofs with conjectured security below
+    /// [ProofOptions::SECURITY_FLOOR].
+    ///
+    /// Before doing any work, the prover estimates the conjectured security of the proof for the
+    /// actual trace length, base field, and hash function (see
+    /// [ProofOptions::conjectured_security()]). If the estimate is below the floor, the prover
+    /// returns [ProverError::InsufficientSecurity] unless this option is enabled. Insecure proofs
+    /// should be generated only in tests and benchmarks which rely on small parameters.
+    ///
+    /// The default implementation returns false.
+    fn allow_insecure(&self) -> bool {
+        false
+    }
+
+    /// Returns application context data the generated proofs should be bound to.
+    ///
+    /// Right after the public coin is seeded with the proof context and public inputs (and before
+    /// it is reseeded with the commitment to the main trace segment), the coin is reseeded with
+    /// the hash of these bytes. Thus, a proof bound to some context data verifies only if the
+    /// verifier is given the same data (see `verify_with_context_data()` in the verifier crate).
+    /// This can be used to bind proofs to data which the AIR does not use (e.g., a commitment
+    /// produced by another system) without including the data in the public inputs.
+    ///
+    /// If the returned slice is empty, nothing is absorbed into the public coin. The default
+    /// implementation returns an empty slice.
+    fn context_data(&self) -> &[u8] {
+        &[]
+    }
+
     /// Builds the GKR proof. If the [`Air`] doesn't use a GKR proof, leave unimplemented.
     #[allow(unused_variables)]
     #[maybe_async]
@@ -246,7 +356,7 @@ pub trait Prover {
     /// Builds and returns the auxiliary trace.
     #[allow(unused_variables)]
     #[maybe_async]
-    #[instrument(skip_all)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn build_aux_trace<E>(
         &self,
         main_trace: &Self::Trace,
@@ -266,41 +376,147 @@ pub trait Prover {
     /// public inputs. It may also contain a GKR proof, further documented in [`Proof`].
     /// Public inputs must match the value returned from
     /// [Self::get_pub_inputs()](Prover::get_pub_inputs) for the provided trace.
+    ///
+    /// # Panics
+    /// Panics if the blowup factor specified by the proof options is not greater than the
+    /// maximum effective degree of the AIR constraints (see
+    /// [AirContext::max_effective_constraint_degree()](air::AirContext::max_effective_constraint_degree)).
     #[maybe_async]
     fn prove(&self, trace: Self::Trace) -> Result<Proof, ProverError>
     where
         <Self::Air as Air>::PublicInputs: Send,
         <Self::Air as Air>::GkrProof: Send,
     {
+        let output = maybe_await!(self.dispatch_proof_generation(trace, false, false, None))?;
+        Ok(output.proof)
+    }
+
+    /// Returns a STARK proof attesting to a correct execution of a computation defined by the
+    /// provided trace, and reports progress of proof generation to the specified `listener`.
+    ///
+    /// The returned proof is the same as the proof returned by [Prover::prove()] for the same
+    /// trace. If `listener` is provided, it is notified when each [ProvingPhase] starts and ends,
+    /// and when each FRI layer is committed to (see [ProgressListener]). A
+    /// [StderrProgressListener] can be used to print progress to stderr.
+    ///
+    /// # Panics
+    /// Panics under the same conditions as [Prover::prove()].
+    #[maybe_async]
+    fn prove_with_progress(
+        &self,
+        trace: Self::Trace,
+        listener: Option<Box<dyn ProgressListener>>,
+    ) -> Result<Proof, ProverError>
+    where
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        let output =
+            maybe_await!(self.dispatch_proof_generation(trace, false, false, listener.as_deref()))?;
+        Ok(output.proof)
+    }
+
+    /// Returns a STARK proof for the provided trace together with a [ProvingDebugTrace] which
+    /// records the intermediate state of proof generation.
+    ///
+    /// The returned proof is the same as the proof returned by [Prover::prove()] for the same
+    /// trace. The debug trace records every interaction of the prover with the public coin (i.e.,
+    /// every value the coin was reseeded with and every challenge drawn from it), commitments to
+    /// the trace, constraint, and FRI layers, the out-of-domain point, and evaluations of all FRI
+    /// layers. When a proof unexpectedly fails to verify, this can be used to replay the
+    /// transcript and find where the prover and the verifier diverge.
+    ///
+    /// Recording the debug trace requires keeping copies of all FRI layers in memory, and thus,
+    /// this method should be used for debugging purposes only.
+    #[maybe_async]
+    fn prove_debug(
+        &self,
+        trace: Self::Trace,
+    ) -> Result<(Proof, ProverDebugTrace<Self>), ProverError>
+    where
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        let output = maybe_await!(self.dispatch_proof_generation(trace, true, false, None))?;
+        Ok((output.proof, output.debug_trace.expect("debug trace was not recorded")))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Runs the version of the proof generation procedure which corresponds to the field extension
+    /// specified in the proof options of this prover.
+    #[doc(hidden)]
+    #[maybe_async]
+    fn dispatch_proof_generation(
+        &self,
+        trace: Self::Trace,
+        debug: bool,
+        profile: bool,
+        listener: Option<&dyn ProgressListener>,
+    ) -> Result<ProvingOutput<Self>, ProverError>
+    where
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        // make sure the base field supports the field extension specified in the proof options
+        if let Err(ProofOptionsError::IncompatibleFieldExtension { degree }) =
+            self.options().validate_field_extension::<Self::BaseField>()
+        {
+            return Err(ProverError::UnsupportedFieldExtension(degree as usize));
+        }
+
         // figure out which version of the generic proof generation procedure to run. this is a sort
         // of static dispatch for selecting two generic parameter: extension field and hash
         // function.
         match self.options().field_extension() {
-            FieldExtension::None => maybe_await!(self.generate_proof::<Self::BaseField>(trace)),
+            FieldExtension::None => {
+                maybe_await!(self.generate_proof::<Self::BaseField>(trace, debug, profile, listener))
+            },
             FieldExtension::Quadratic => {
-                if !<QuadExtension<Self::BaseField>>::is_supported() {
-                    return Err(ProverError::UnsupportedFieldExtension(2));
-                }
-                maybe_await!(self.generate_proof::<QuadExtension<Self::BaseField>>(trace))
+                maybe_await!(self.generate_proof::<QuadExtension<Self::BaseField>>(
+                    trace, debug, profile, listener
+                ))
             },
             FieldExtension::Cubic => {
-                if !<CubeExtension<Self::BaseField>>::is_supported() {
-                    return Err(ProverError::UnsupportedFieldExtension(3));
-                }
-                maybe_await!(self.generate_proof::<CubeExtension<Self::BaseField>>(trace))
+                maybe_await!(self.generate_proof::<CubeExtension<Self::BaseField>>(
+                    trace, debug, profile, listener
+                ))
             },
         }
     }
 
-    // HELPER METHODS
-    // --------------------------------------------------------------------------------------------
-
     /// Performs the actual proof generation procedure, generating the proof that the provided
-    /// execution `trace` is valid against this prover's AIR.
+    /// execution `trace` is valid against this prover's AIR. If `debug` is true, the intermediate
+    /// state of proof generation is recorded and returned together with the proof; if `profile`
+    /// is true (and the `profile` feature is enabled), the time spent in each phase of proof
+    /// generation is measured; if `listener` is provided, it is notified about the progress of
+    /// proof generation.
     /// TODO: make this function un-callable externally?
     #[doc(hidden)]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                trace_length = trace.info().length(),
+                trace_width = trace.info().width(),
+                lde_domain_size = trace.info().length() * self.options().blowup_factor(),
+                num_fri_layers = self
+                    .options()
+                    .to_fri_options()
+                    .num_fri_layers(trace.info().length() * self.options().blowup_factor()),
+            )
+        )
+    )]
     #[maybe_async]
-    fn generate_proof<E>(&self, trace: Self::Trace) -> Result<Proof, ProverError>
+    fn generate_proof<E>(
+        &self,
+        trace: Self::Trace,
+        debug: bool,
+        profile: bool,
+        listener: Option<&dyn ProgressListener>,
+    ) -> Result<ProvingOutput<Self>, ProverError>
     where
         E: FieldElement<BaseField = Self::BaseField>,
         <Self::Air as Air>::PublicInputs: Send,
@@ -317,6 +533,113 @@ pub trait Prover {
         // execution of the computation for the provided public inputs.
         let air = Self::Air::new(trace.info().clone(), pub_inputs, self.options().clone());
 
+        // make sure the blowup factor is large enough for the degrees of the constraints; the
+        // constraint evaluation domain cannot be larger than the LDE domain
+        let blowup_factor = air.options().blowup_factor();
+        let ce_blowup_factor = air.ce_blowup_factor();
+        if blowup_factor < ce_blowup_factor {
+            let max_constraint_degree = air.context().max_effective_constraint_degree();
+            let constraint_idx = (0..air.context().num_transition_constraints())
+                .find(|&i| air.context().effective_constraint_degree(i) == max_constraint_degree)
+                .expect("no transition constraint of maximum effective degree");
+            return Err(ProverError::BlowupFactorTooSmall {
+                blowup_factor,
+                ce_blowup_factor,
+                constraint_idx,
+                constraint_degree: air
+                    .context()
+                    .transition_constraint_degree(constraint_idx)
+                    .clone(),
+            });
+        }
+
+        // make sure the proof options identify the proof-of-work scheme of this prover
+        let pow_scheme = air.options().pow_scheme();
+        if pow_scheme != <Self::ProofOfWork as ProofOfWork<Self::HashFn>>::SCHEME {
+            return Err(ProverError::PowSchemeMismatch {
+                expected: <Self::ProofOfWork as ProofOfWork<Self::HashFn>>::SCHEME,
+                actual: pow_scheme,
+            });
+        }
+
+        // make sure the proof is not trivially forgeable; the security floor can be lowered only
+        // explicitly
+        let estimated_security = air.options().conjectured_security(
+            Self::BaseField::MODULUS_BITS,
+            air.trace_length(),
+            Self::HashFn::COLLISION_RESISTANCE,
+        );
+        if estimated_security < ProofOptions::SECURITY_FLOOR && !self.allow_insecure() {
+            return Err(ProverError::InsufficientSecurity {
+                estimated: estimated_security,
+                required: ProofOptions::SECURITY_FLOOR,
+            });
+        }
+
+        // make sure the field extension does not limit the security of the proof; the other
+        // components are the query phase and the collision resistance of the hash function
+        let field_security = air
+            .options()
+            .field_security(Self::BaseField::MODULUS_BITS, air.lde_domain_size());
+        let other_security =
+            cmp::min(air.options().query_security(), Self::HashFn::COLLISION_RESISTANCE);
+        if field_security < other_security && self.enforce_field_security() {
+            return Err(ProverError::InsufficientFieldSecurity { field_security, other_security });
+        }
+        #[cfg(all(debug_assertions, feature = "tracing"))]
+        if field_security < other_security {
+            tracing::event!(
+                tracing::Level::WARN,
+                "field extension provides only {field_security} bits of security, but other \
+                protocol components provide {other_security} bits; consider using {:?} field \
+                extension",
+                ProofOptions::required_extension_degree(
+                    Self::BaseField::MODULUS_BITS,
+                    other_security,
+                    air.lde_domain_size()
+                )
+            );
+        }
+
+        // the prover draws a single out-of-domain point; warn if the analysis recommends drawing
+        // it from a larger field for the constraints of this computation
+        #[cfg(all(debug_assertions, feature = "tracing"))]
+        {
+            let max_constraint_degree = air.context().max_effective_constraint_degree();
+            let ood_field_bits =
+                Self::BaseField::MODULUS_BITS * air.options().field_extension().degree();
+            let ood_security = air::proof::OodSampleAnalyzer::security(
+                ood_field_bits,
+                max_constraint_degree,
+                air.trace_length(),
+                air.trace_info().width(),
+            );
+            if ood_security < air::proof::OodSampleAnalyzer::TARGET_SECURITY {
+                tracing::event!(
+                    tracing::Level::WARN,
+                    "out-of-domain sample from a {ood_field_bits}-bit field provides only \
+                    {ood_security} bits of security for constraints of degree \
+                    {max_constraint_degree} over {} columns; consider using {:?} field extension",
+                    air.trace_info().width(),
+                    air::proof::OodSampleAnalyzer::required_extension(
+                        Self::BaseField::MODULUS_BITS,
+                        max_constraint_degree,
+                        air.trace_length(),
+                        air.trace_info().width()
+                    )
+                );
+            }
+        }
+
+        // make sure the main segment of the specified trace is valid against the AIR before
+        // doing any expensive work. This checks validity of both, assertions and state
+        // transitions. Unless explicitly requested, we do this in debug mode only because this
+        // is a very expensive operation.
+        let validate_trace = cfg!(debug_assertions) || self.validate_before_prove();
+        if validate_trace {
+            trace::validate_trace(&trace, &air)?;
+        }
+
         // create a channel which is used to simulate interaction between the prover and the
         // verifier; the channel will be used to commit to values and to draw randomness that
         // should come from the verifier.
@@ -324,21 +647,33 @@ pub trait Prover {
             ProverChannel::<Self::Air, E, Self::HashFn, Self::RandomCoin, Self::VC>::new(
                 &air,
                 pub_inputs_elements,
+                self.context_data(),
+                debug,
             );
 
+        // start measuring time spent in each phase of proof generation; the time is recorded only
+        // if proof generation is being profiled, and is reported to the listener if one is
+        // provided
+        let mut timer = PhaseTimer::new(profile, listener);
+
         // 1 ----- Commit to the execution trace --------------------------------------------------
 
         // build computation domain; this is used later for polynomial evaluations
         let lde_domain_size = air.lde_domain_size();
         let trace_length = air.trace_length();
-        let domain = info_span!("build_domain", trace_length, lde_domain_size)
-            .in_scope(|| StarkDomain::new(&air));
+        let domain = {
+            #[cfg(feature = "tracing")]
+            let _span =
+                tracing::info_span!("build_domain", trace_length, lde_domain_size).entered();
+            StarkDomain::new(&air)
+        };
         assert_eq!(domain.lde_domain_size(), lde_domain_size);
         assert_eq!(domain.trace_length(), trace_length);
 
         // commit to the main trace segment
         let (mut trace_lde, mut trace_polys) =
             maybe_await!(self.commit_to_main_trace_segment(&trace, &domain, &mut channel));
+        timer.end_phase(ProvingPhase::TraceCommit);
 
         // build the auxiliary trace segment, and append the resulting segments to trace commitment
         // and trace polynomial table structs
@@ -363,12 +698,22 @@ pub trait Prover {
                 (None, AuxRandElements::new(rand_elements))
             };
 
+            if let Some(lagrange) = aux_rand_elements.lagrange() {
+                channel.record_draw("Lagrange kernel random elements", lagrange);
+            }
+            if let Some(randomness) = aux_rand_elements.gkr_openings_combining_randomness() {
+                channel.record_draw("GKR openings combining randomness", randomness);
+            }
+            channel
+                .record_draw("auxiliary trace random elements", aux_rand_elements.rand_elements());
+
             let aux_trace = maybe_await!(self.build_aux_trace(&trace, &aux_rand_elements));
 
             // commit to the auxiliary trace segment
             let aux_segment_polys = {
                 // extend the auxiliary trace segment and commit to the extended trace
-                let span = info_span!("commit_to_aux_trace_segment").entered();
+                #[cfg(feature = "tracing")]
+                let _span = tracing::info_span!("commit_to_aux_trace_segment").entered();
                 let (aux_segment_polys, aux_segment_commitment) =
                     trace_lde.set_aux_trace(&aux_trace, &domain);
 
@@ -376,7 +721,6 @@ pub trait Prover {
                 // commitment into the channel
                 channel.commit_trace(aux_segment_commitment);
 
-                drop(span);
                 aux_segment_polys
             };
 
@@ -388,11 +732,13 @@ pub trait Prover {
             None
         };
 
-        // make sure the specified trace (including auxiliary segment) is valid against the AIR.
-        // This checks validity of both, assertions and state transitions. We do this in debug
-        // mode only because this is a very expensive operation.
-        #[cfg(debug_assertions)]
-        trace.validate(&air, aux_trace_with_metadata.as_ref());
+        // once the auxiliary trace segment is built, make sure it is valid against the AIR as
+        // well; the main segment has already been validated above
+        if let (true, Some(aux_trace_with_metadata)) =
+            (validate_trace, aux_trace_with_metadata.as_ref())
+        {
+            trace::validate_aux_segment(&trace, &air, aux_trace_with_metadata)?;
+        }
 
         // Destructure `aux_trace_with_metadata`.
         let (aux_trace, aux_rand_elements, gkr_proof) = match aux_trace_with_metadata {
@@ -403,6 +749,7 @@ pub trait Prover {
         // drop the main trace and aux trace segment as they are no longer needed
         drop(trace);
         drop(aux_trace);
+        timer.end_phase(ProvingPhase::AuxTraceCommit);
 
         // 2 ----- evaluate constraints -----------------------------------------------------------
         // evaluate constraints specified by the AIR over the constraint evaluation domain, and
@@ -416,14 +763,17 @@ pub trait Prover {
         ))
         .evaluate(&trace_lde, &domain);
         assert_eq!(composition_poly_trace.num_rows(), ce_domain_size);
+        timer.end_phase(ProvingPhase::ConstraintEval);
 
         // 3 ----- commit to constraint evaluations -----------------------------------------------
         let (constraint_commitment, composition_poly) = maybe_await!(self
             .commit_to_constraint_evaluations(&air, composition_poly_trace, &domain, &mut channel));
+        timer.end_phase(ProvingPhase::ConstraintCommit);
 
         // 4 ----- build DEEP composition polynomial ----------------------------------------------
         let deep_composition_poly = {
-            let span = info_span!("build_deep_composition_poly").entered();
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!("build_deep_composition_poly").entered();
             // draw an out-of-domain point z. Depending on the type of E, the point is drawn either
             // from the base field or from an extension field defined by E.
             //
@@ -438,11 +788,16 @@ pub trait Prover {
             // g, where g is the generator of the trace domain. Additionally, if the Lagrange kernel
             // auxiliary column is present, we also evaluate that column over the points: z, z * g,
             // z * g^2, z * g^4, ..., z * g^(2^(v-1)), where v = log(trace_len).
-            let ood_trace_states = trace_polys.get_ood_frame(z);
-            channel.send_ood_trace_states(&ood_trace_states);
-
-            let ood_evaluations = composition_poly.evaluate_at(z);
-            channel.send_ood_constraint_evaluations(&ood_evaluations);
+            let (ood_trace_states, ood_evaluations) = {
+                #[cfg(feature = "tracing")]
+                let _span = tracing::info_span!("evaluate_ood_frame").entered();
+                let ood_trace_states = trace_polys.get_ood_frame(z, air.frame_size());
+                channel.send_ood_trace_states(&ood_trace_states);
+
+                let ood_evaluations = composition_poly.evaluate_at(z);
+                channel.send_ood_constraint_evaluations(&ood_evaluations);
+                (ood_trace_states, ood_evaluations)
+            };
 
             // draw random coefficients to use during DEEP polynomial composition, and use them to
             // initialize the DEEP composition polynomial
@@ -457,9 +812,9 @@ pub trait Prover {
             // polynomial
             deep_composition_poly.add_composition_poly(composition_poly, ood_evaluations);
 
-            event!(Level::DEBUG, "degree: {}", deep_composition_poly.degree());
+            #[cfg(feature = "tracing")]
+            tracing::event!(tracing::Level::DEBUG, "degree: {}", deep_composition_poly.degree());
 
-            drop(span);
             deep_composition_poly
         };
 
@@ -469,44 +824,68 @@ pub trait Prover {
 
         // 5 ----- evaluate DEEP composition polynomial over LDE domain ---------------------------
         let deep_evaluations = {
-            let span = info_span!("evaluate_deep_composition_poly").entered();
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!("evaluate_deep_composition_poly").entered();
             let deep_evaluations = deep_composition_poly.evaluate(&domain);
             // we check the following condition in debug mode only because infer_degree is an
             // expensive operation
             debug_assert_eq!(trace_length - 2, infer_degree(&deep_evaluations, domain.offset()));
 
-            drop(span);
             deep_evaluations
         };
+        timer.end_phase(ProvingPhase::DeepComposition);
 
         // 6 ----- compute FRI layers for the composition polynomial ------------------------------
         let fri_options = air.options().to_fri_options();
-        let num_layers = fri_options.num_fri_layers(lde_domain_size);
-        let mut fri_prover = FriProver::<_, _, _, Self::VC>::new(fri_options);
-        info_span!("compute_fri_layers", num_layers)
-            .in_scope(|| fri_prover.build_layers(&mut channel, deep_evaluations));
+        let mut fri_prover = FriProver::<_, _, _, Self::VC>::new(fri_options.clone());
+        {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!(
+                "compute_fri_layers",
+                num_fri_layers = fri_options.num_fri_layers(lde_domain_size)
+            )
+            .entered();
+            fri_prover.build_layers_with_progress(&mut channel, deep_evaluations, |layer, total| {
+                timer.fri_layer(layer, total)
+            })
+        }
+        channel.record_fri_layers(&fri_prover);
+        timer.end_phase(ProvingPhase::Fri);
 
         // 7 ----- determine query positions ------------------------------------------------------
         let query_positions = {
-            let grinding_factor = air.options().grinding_factor();
-            let num_positions = air.options().num_queries();
-            let span =
-                info_span!("determine_query_positions", grinding_factor, num_positions,).entered();
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!(
+                "determine_query_positions",
+                grinding_factor = air.options().grinding_factor(),
+                num_positions = air.options().num_queries()
+            )
+            .entered();
 
             // apply proof-of-work to the query seed
-            channel.grind_query_seed();
+            {
+                #[cfg(feature = "tracing")]
+                let _span = tracing::info_span!("grind_query_seed").entered();
+                channel.grind_query_seed::<Self::ProofOfWork>();
+            }
 
             // generate pseudo-random query positions
             let query_positions = channel.get_query_positions();
-            event!(Level::DEBUG, "query_positions_len: {}", query_positions.len());
+            #[cfg(feature = "tracing")]
+            tracing::event!(
+                tracing::Level::DEBUG,
+                "query_positions_len: {}",
+                query_positions.len()
+            );
 
-            drop(span);
             query_positions
         };
+        timer.end_phase(ProvingPhase::Pow);
 
         // 8 ----- build proof object -------------------------------------------------------------
-        let proof = {
-            let span = info_span!("build_proof_object").entered();
+        let (proof, debug_trace) = {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!("build_proof_object").entered();
             // generate FRI proof
             let fri_proof = fri_prover.build_proof(&query_positions);
 
@@ -520,6 +899,7 @@ pub trait Prover {
             let constraint_queries = constraint_commitment.query(&query_positions);
 
             // build the proof object
+            let debug_trace = channel.take_debug_trace();
             let proof = channel.build_proof(
                 trace_queries,
                 constraint_queries,
@@ -528,15 +908,21 @@ pub trait Prover {
                 gkr_proof.map(|gkr_proof| gkr_proof.to_bytes()),
             );
 
-            drop(span);
-            proof
+            (proof, debug_trace)
         };
 
-        Ok(proof)
+        timer.end_phase(ProvingPhase::Queries);
+
+        Ok(ProvingOutput {
+            proof,
+            debug_trace,
+            #[cfg(feature = "profile")]
+            profile: timer.finish(),
+        })
     }
 
     #[doc(hidden)]
-    #[instrument(skip_all)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     #[maybe_async]
     fn commit_to_main_trace_segment<E>(
         &self,
@@ -566,7 +952,7 @@ pub trait Prover {
     }
 
     #[doc(hidden)]
-    #[instrument(skip_all)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     #[maybe_async]
     fn commit_to_constraint_evaluations<E>(
         &self,
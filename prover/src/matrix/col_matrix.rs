@@ -4,13 +4,13 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
-use core::{iter::FusedIterator, slice};
+use core::{iter::FusedIterator, mem::MaybeUninit, slice};
 
 use crypto::{ElementHasher, VectorCommitment};
 use math::{fft, polynom, FieldElement};
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
-use utils::{batch_iter_mut, iter, iter_mut, uninit_vector};
+use utils::{assume_init_vector, batch_iter_mut, iter, iter_mut, uninit_vector};
 
 use crate::StarkDomain;
 
@@ -167,12 +167,12 @@ impl<E: FieldElement> ColMatrix<E> {
     // --------------------------------------------------------------------------------------------
 
     /// Returns an iterator over the columns of this matrix.
-    pub fn columns(&self) -> ColumnIter<E> {
+    pub fn columns(&self) -> ColumnIter<'_, E> {
         ColumnIter::new(self)
     }
 
     /// Returns a mutable iterator over the columns of this matrix.
-    pub fn columns_mut(&mut self) -> ColumnIterMut<E> {
+    pub fn columns_mut(&mut self) -> ColumnIterMut<'_, E> {
         ColumnIterMut::new(self)
     }
 
@@ -242,11 +242,30 @@ impl<E: FieldElement> ColMatrix<E> {
     }
 
     /// Evaluates polynomials contained in the columns of this matrix at a single point `x`.
+    ///
+    /// The columns are evaluated in batches via [polynom::eval_batch()], so that the powers of
+    /// `x` are computed once per batch rather than once per column. When `concurrent` feature is
+    /// enabled, the batches are evaluated in multiple threads.
     pub fn evaluate_columns_at<F>(&self, x: F) -> Vec<F>
     where
         F: FieldElement + From<E>,
     {
-        iter!(self.columns).map(|p| polynom::eval(p, x)).collect()
+        let columns = self.columns.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+        #[cfg(not(feature = "concurrent"))]
+        let result = polynom::eval_batch(&columns, x);
+
+        #[cfg(feature = "concurrent")]
+        let result = {
+            // the batch size must be positive even if the matrix has no columns
+            let batch_size = columns.len().div_ceil(rayon_num_threads()).max(1);
+            columns
+                .par_chunks(batch_size)
+                .flat_map_iter(|batch| polynom::eval_batch(batch, x))
+                .collect()
+        };
+
+        result
     }
 
     // COMMITMENTS
@@ -265,7 +284,7 @@ impl<E: FieldElement> ColMatrix<E> {
         V: VectorCommitment<H>,
     {
         // allocate vector to store row hashes
-        let mut row_hashes = unsafe { uninit_vector::<H::Digest>(self.num_rows()) };
+        let mut row_hashes = uninit_vector::<H::Digest>(self.num_rows());
 
         // iterate though matrix rows, hashing each row; the hashing is done by first copying a
         // row into row_buf to avoid heap allocations, and then by applying the hash function to
@@ -273,15 +292,17 @@ impl<E: FieldElement> ColMatrix<E> {
         batch_iter_mut!(
             &mut row_hashes,
             128, // min batch size
-            |batch: &mut [H::Digest], batch_offset: usize| {
+            |batch: &mut [MaybeUninit<H::Digest>], batch_offset: usize| {
                 let mut row_buf = vec![E::ZERO; self.num_cols()];
                 for (i, row_hash) in batch.iter_mut().enumerate() {
                     self.read_row_into(i + batch_offset, &mut row_buf);
-                    *row_hash = H::hash_elements(&row_buf);
+                    row_hash.write(H::hash_elements(&row_buf));
                 }
             }
         );
 
+        // SAFETY: all row hashes have been initialized above
+        let row_hashes = unsafe { assume_init_vector(row_hashes) };
         V::new(row_hashes).expect("failed to construct trace vector commitment")
     }
 
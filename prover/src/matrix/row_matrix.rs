@@ -4,13 +4,14 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
+use core::mem::MaybeUninit;
 
 use air::PartitionOptions;
 use crypto::{ElementHasher, VectorCommitment};
 use math::{fft, FieldElement, StarkField};
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
-use utils::{batch_iter_mut, flatten_vector_elements, uninit_vector};
+use utils::{assume_init_vector, batch_iter_mut, flatten_vector_elements, uninit_vector};
 
 use super::{ColMatrix, Segment};
 use crate::StarkDomain;
@@ -170,13 +171,28 @@ impl<E: FieldElement> RowMatrix<E> {
         &self.data
     }
 
+    // ARITHMETIC OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds every element of the `other` matrix to the corresponding element of this matrix.
+    ///
+    /// # Panics
+    /// Panics if the two matrices do not have the same layout (i.e., the same number of rows and
+    /// the same row width).
+    pub fn add_in_place(&mut self, other: &Self) {
+        assert_eq!(self.row_width, other.row_width, "matrices must have the same row width");
+        assert_eq!(self.data.len(), other.data.len(), "matrices must have the same number of rows");
+        math::add_in_place(&mut self.data, &other.data);
+    }
+
     // COMMITMENTS
     // --------------------------------------------------------------------------------------------
 
     /// Returns a commitment to this matrix.
     ///
     /// The commitment is built as follows:
-    /// * Each row of the matrix is hashed into a single digest of the specified hash function.
+    /// * Each row of the matrix is hashed into a single digest of the specified hash function;
+    ///   rows are hashed in batches via [ElementHasher::hash_element_rows()].
     ///   The result is a vector of digests of length equal to the number of matrix rows.
     /// * A vector commitment is computed for the resulting vector using the specified vector
     ///   commitment scheme.
@@ -187,7 +203,7 @@ impl<E: FieldElement> RowMatrix<E> {
         V: VectorCommitment<H>,
     {
         // allocate vector to store row hashes
-        let mut row_hashes = unsafe { uninit_vector::<H::Digest>(self.num_rows()) };
+        let mut row_hashes = uninit_vector::<H::Digest>(self.num_rows());
         let partition_size = partition_options.partition_size::<E>(self.num_cols());
 
         if partition_size == self.num_cols() {
@@ -195,9 +211,12 @@ impl<E: FieldElement> RowMatrix<E> {
             batch_iter_mut!(
                 &mut row_hashes,
                 128, // min batch size
-                |batch: &mut [H::Digest], batch_offset: usize| {
-                    for (i, row_hash) in batch.iter_mut().enumerate() {
-                        *row_hash = H::hash_elements(self.row(batch_offset + i));
+                |batch: &mut [MaybeUninit<H::Digest>], batch_offset: usize| {
+                    let rows = (batch_offset..batch_offset + batch.len())
+                        .map(|row_idx| self.row(row_idx))
+                        .collect::<Vec<_>>();
+                    for (row_hash, digest) in batch.iter_mut().zip(H::hash_element_rows(&rows)) {
+                        row_hash.write(digest);
                     }
                 }
             );
@@ -208,7 +227,7 @@ impl<E: FieldElement> RowMatrix<E> {
             batch_iter_mut!(
                 &mut row_hashes,
                 128, // min batch size
-                |batch: &mut [H::Digest], batch_offset: usize| {
+                |batch: &mut [MaybeUninit<H::Digest>], batch_offset: usize| {
                     let mut buffer = vec![H::Digest::default(); num_partitions];
                     for (i, row_hash) in batch.iter_mut().enumerate() {
                         self.row(batch_offset + i)
@@ -217,12 +236,15 @@ impl<E: FieldElement> RowMatrix<E> {
                             .for_each(|(chunk, buf)| {
                                 *buf = H::hash_elements(chunk);
                             });
-                        *row_hash = H::merge_many(&buffer);
+                        row_hash.write(H::merge_many(&buffer));
                     }
                 }
             );
         }
 
+        // SAFETY: all row hashes have been initialized above
+        let row_hashes = unsafe { assume_init_vector(row_hashes) };
+
         // build the vector commitment to the hashed rows
         V::new(row_hashes).expect("failed to construct trace vector commitment")
     }
@@ -244,17 +266,17 @@ pub fn get_evaluation_offsets<E: FieldElement>(
     let g = E::BaseField::get_root_of_unity(domain_size.ilog2());
 
     // allocate memory to hold the offsets
-    let mut offsets = unsafe { uninit_vector(domain_size) };
+    let mut offsets = uninit_vector::<E::BaseField>(domain_size);
 
     // define a closure to compute offsets for a given chunk of the result; the number of chunks
     // is defined by the blowup factor. for example, for blowup factor = 2, the number of chunks
     // will be 2, for blowup factor = 8, the number of chunks will be 8 etc.
-    let compute_offsets = |(chunk_idx, chunk): (usize, &mut [E::BaseField])| {
+    let compute_offsets = |(chunk_idx, chunk): (usize, &mut [MaybeUninit<E::BaseField>])| {
         let idx = fft::permute_index(blowup_factor, chunk_idx) as u64;
         let offset = g.exp_vartime(idx.into()) * domain_offset;
         let mut factor = E::BaseField::ONE;
         for res in chunk.iter_mut() {
-            *res = factor;
+            res.write(factor);
             factor *= offset;
         }
     };
@@ -267,7 +289,8 @@ pub fn get_evaluation_offsets<E: FieldElement>(
     #[cfg(feature = "concurrent")]
     offsets.par_chunks_mut(poly_size).enumerate().for_each(compute_offsets);
 
-    offsets
+    // SAFETY: the chunks cover all offsets, and all of them have been initialized above
+    unsafe { assume_init_vector(offsets) }
 }
 
 /// Returns matrix segments constructed by evaluating polynomials in the specified matrix over the
@@ -308,7 +331,7 @@ fn transpose<B: StarkField, const N: usize>(mut segments: Vec<Segment<B, N>>) ->
 
     // allocate memory to hold the transposed result;
     // TODO: investigate transposing in-place
-    let mut result = unsafe { uninit_vector::<[B; N]>(result_len) };
+    let mut result = uninit_vector::<[B; N]>(result_len);
 
     // determine number of batches in which transposition will be preformed; if `concurrent`
     // feature is not enabled, the number of batches will always be 1
@@ -316,13 +339,12 @@ fn transpose<B: StarkField, const N: usize>(mut segments: Vec<Segment<B, N>>) ->
     let rows_per_batch = num_rows / num_batches;
 
     // define a closure for transposing a given batch
-    let transpose_batch = |(batch_idx, batch): (usize, &mut [[B; N]])| {
+    let transpose_batch = |(batch_idx, batch): (usize, &mut [MaybeUninit<[B; N]>])| {
         let row_offset = batch_idx * rows_per_batch;
         for i in 0..rows_per_batch {
             let row_idx = i + row_offset;
             for j in 0..num_segs {
-                let v = &segments[j][row_idx];
-                batch[i * num_segs + j].copy_from_slice(v);
+                batch[i * num_segs + j].write(segments[j][row_idx]);
             }
         }
     };
@@ -339,7 +361,8 @@ fn transpose<B: StarkField, const N: usize>(mut segments: Vec<Segment<B, N>>) ->
         .enumerate()
         .for_each(transpose_batch);
 
-    result
+    // SAFETY: the batches cover the whole result, and all of them have been initialized above
+    unsafe { assume_init_vector(result) }
 }
 
 #[cfg(not(feature = "concurrent"))]
@@ -4,12 +4,15 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
-use core::ops::Deref;
+use core::{
+    mem::{self, MaybeUninit},
+    ops::Deref,
+};
 
 use math::{fft::fft_inputs::FftInputs, FieldElement, StarkField};
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
-use utils::uninit_vector;
+use utils::{assume_init_slice_mut, assume_init_vector, uninit_vector};
 
 use super::ColMatrix;
 
@@ -63,17 +66,10 @@ impl<B: StarkField, const N: usize> Segment<B, N> {
         assert_eq!(poly_size, twiddles.len() * 2);
         assert!(poly_offset < polys.num_base_cols());
 
-        // allocate memory for the segment
-        let data = if polys.num_base_cols() - poly_offset >= N {
-            // if we will fill the entire segment, we allocate uninitialized memory
-            unsafe { uninit_vector::<[B; N]>(domain_size) }
-        } else {
-            // but if some columns in the segment will remain unfilled, we allocate memory initialized
-            // to zeros to make sure we don't end up with memory with undefined values
-            vec![[B::ZERO; N]; domain_size]
-        };
+        // allocate memory for the segment; all of it will be overwritten during evaluation
+        let data = uninit_vector::<[B; N]>(domain_size);
 
-        Self::new_with_buffer(data, polys, poly_offset, offsets, twiddles)
+        Self::evaluate_into(data, polys, poly_offset, offsets, twiddles)
     }
 
     /// Instantiates a new [Segment] using the provided data buffer by evaluating polynomials in
@@ -84,6 +80,8 @@ impl<B: StarkField, const N: usize> Segment<B, N> {
     /// values supplied into [Matrix::get_base_element()] method.
     ///
     /// Evaluation is performed over the domain specified by the provided twiddles and offsets.
+    /// All values in the data buffer are overwritten; if fewer than `N` polynomials remain to be
+    /// evaluated starting at `poly_offset`, the remaining columns of the segment are set to zero.
     ///
     /// # Panics
     /// Panics if:
@@ -99,12 +97,36 @@ impl<B: StarkField, const N: usize> Segment<B, N> {
         offsets: &[B],
         twiddles: &[B],
     ) -> Self
+    where
+        E: FieldElement<BaseField = B>,
+    {
+        let mut data_buffer = mem::ManuallyDrop::new(data_buffer);
+        let (ptr, len, capacity) =
+            (data_buffer.as_mut_ptr(), data_buffer.len(), data_buffer.capacity());
+        // SAFETY: `MaybeUninit<[B; N]>` has the same size and alignment as `[B; N]`, and any
+        // initialized value is a valid `MaybeUninit` value
+        let data = unsafe { Vec::from_raw_parts(ptr as *mut MaybeUninit<[B; N]>, len, capacity) };
+
+        Self::evaluate_into(data, polys, poly_offset, offsets, twiddles)
+    }
+
+    /// Evaluates polynomials in the [ColMatrix] starting at the specified offset into the
+    /// provided data buffer, and returns the resulting [Segment].
+    ///
+    /// All values in the data buffer are overwritten before they are read, and thus, the buffer
+    /// does not need to be initialized.
+    fn evaluate_into<E>(
+        mut data: Vec<MaybeUninit<[B; N]>>,
+        polys: &ColMatrix<E>,
+        poly_offset: usize,
+        offsets: &[B],
+        twiddles: &[B],
+    ) -> Self
     where
         E: FieldElement<BaseField = B>,
     {
         let poly_size = polys.num_rows();
         let domain_size = offsets.len();
-        let mut data = data_buffer;
 
         assert!(domain_size.is_power_of_two());
         assert!(domain_size > poly_size);
@@ -134,11 +156,11 @@ impl<B: StarkField, const N: usize> Segment<B, N> {
                     } else {
                         Self::copy_polys_partial(d_chunk, polys, poly_offset, num_polys, o_chunk);
                     }
+                    // SAFETY: all rows of the chunk have been initialized above
+                    let d_chunk = unsafe { assume_init_slice_mut(d_chunk) };
                     concurrent::split_radix_fft(d_chunk, twiddles);
                 },
             );
-            #[cfg(feature = "concurrent")]
-            concurrent::permute(&mut data);
         } else {
             data.chunks_mut(poly_size).zip(offsets.chunks(poly_size)).for_each(
                 |(d_chunk, o_chunk)| {
@@ -147,12 +169,26 @@ impl<B: StarkField, const N: usize> Segment<B, N> {
                     } else {
                         Self::copy_polys_partial(d_chunk, polys, poly_offset, num_polys, o_chunk);
                     }
+                    // SAFETY: all rows of the chunk have been initialized above
+                    let d_chunk = unsafe { assume_init_slice_mut(d_chunk) };
                     d_chunk.fft_in_place(twiddles);
                 },
             );
+        }
+
+        // SAFETY: the chunks cover the whole buffer, and all of them have been initialized above
+        let mut data = unsafe { assume_init_vector(data) };
+
+        #[cfg(feature = "concurrent")]
+        if domain_size >= MIN_CONCURRENT_SIZE {
+            concurrent::permute(&mut data);
+        } else {
             data.permute();
         }
 
+        #[cfg(not(feature = "concurrent"))]
+        data.permute();
+
         Segment { data }
     }
 
@@ -175,36 +211,38 @@ impl<B: StarkField, const N: usize> Segment<B, N> {
     /// Copies N polynomials starting at the specified base column offset (`poly_offset`) into the
     /// specified destination. Each polynomial coefficient is offset by the specified offset.
     fn copy_polys<E: FieldElement<BaseField = B>>(
-        dest: &mut [[B; N]],
+        dest: &mut [MaybeUninit<[B; N]>],
         polys: &ColMatrix<E>,
         poly_offset: usize,
         offsets: &[B],
     ) {
-        for row_idx in 0..dest.len() {
-            for i in 0..N {
+        for (row_idx, row) in dest.iter_mut().enumerate() {
+            row.write(core::array::from_fn(|i| {
                 let coeff = polys.get_base_element(poly_offset + i, row_idx);
-                dest[row_idx][i] = coeff * offsets[row_idx];
-            }
+                coeff * offsets[row_idx]
+            }));
         }
     }
 
     /// Similar to `clone_and_shift` method above, but copies `num_polys` polynomials instead of
-    /// `N` polynomials.
+    /// `N` polynomials; the remaining values of each row are set to zero.
     ///
     /// Assumes that `num_polys` is smaller than `N`.
     fn copy_polys_partial<E: FieldElement<BaseField = B>>(
-        dest: &mut [[B; N]],
+        dest: &mut [MaybeUninit<[B; N]>],
         polys: &ColMatrix<E>,
         poly_offset: usize,
         num_polys: usize,
         offsets: &[B],
     ) {
         debug_assert!(num_polys < N);
-        for row_idx in 0..dest.len() {
-            for i in 0..num_polys {
+        for (row_idx, row) in dest.iter_mut().enumerate() {
+            let mut values = [B::ZERO; N];
+            for (i, value) in values.iter_mut().take(num_polys).enumerate() {
                 let coeff = polys.get_base_element(poly_offset + i, row_idx);
-                dest[row_idx][i] = coeff * offsets[row_idx];
+                *value = coeff * offsets[row_idx];
             }
+            row.write(values);
         }
     }
 }
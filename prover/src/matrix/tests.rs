@@ -41,6 +41,23 @@ fn test_eval_poly_with_offset_matrix() {
     }
 }
 
+#[test]
+fn test_evaluate_columns_at() {
+    let n = 16;
+    let columns: Vec<Vec<BaseElement>> = (0..5).map(|_| rand_vector(n)).collect();
+    let mut matrix = ColMatrix::new(columns.clone());
+
+    let x = BaseElement::new(11);
+    let expected = columns.iter().map(|p| polynom::eval(p, x)).collect::<Vec<_>>();
+    assert_eq!(expected, matrix.evaluate_columns_at(x));
+
+    // a matrix from which all columns have been removed evaluates to an empty vector
+    for _ in 0..columns.len() {
+        matrix.remove_column(0);
+    }
+    assert!(matrix.evaluate_columns_at(x).is_empty());
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
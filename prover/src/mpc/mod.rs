@@ -0,0 +1,203 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Skeleton of a protocol for proving computations whose execution trace is held in additively
+//! secret-shared form by several parties.
+//!
+//! Every party holds a [TraceShare]: a matrix of the same shape as the main segment of the
+//! execution trace such that the sum of all shares is equal to the trace. No single share (nor
+//! any strict subset of the shares, if the shares were generated from uniformly random masks)
+//! reveals anything about the trace.
+//!
+//! The protocol relies on the fact that trace interpolation and low-degree extension are linear
+//! operations. Thus, every party can extend its share locally (see [TraceShare::extend()]), and
+//! the resulting [ExtendedTraceShare]s are additive shares of the low-degree extension of the
+//! trace (and of the trace polynomials). The extended shares are then combined by the
+//! [MpcProver], which commits to the sum of the shares; the commitment is identical to the one
+//! which a regular prover would compute from the full trace, and the subsequent stages of the
+//! protocol (constraint evaluation and FRI) are performed on the combined polynomials.
+//!
+//! Note that this module only defines the interface of the protocol: in this skeleton, the
+//! combination of the shares is simulated by summing them in the clear. A real deployment would
+//! replace the combination steps with an MPC sub-protocol (e.g., hashing the summed rows with an
+//! MPC-friendly hash function inside the MPC), so that the parties learn only the commitment and
+//! the values which are eventually opened to the verifier.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use air::PartitionOptions;
+use crypto::{ElementHasher, VectorCommitment};
+use math::{add_in_place, StarkField};
+
+use crate::{
+    errors::MpcError,
+    matrix::{ColMatrix, RowMatrix},
+    StarkDomain, DEFAULT_SEGMENT_WIDTH,
+};
+
+#[cfg(test)]
+mod tests;
+
+// TRACE SHARE
+// ================================================================================================
+
+/// An additive share of the main segment of an execution trace held by a single party.
+#[derive(Debug, Clone)]
+pub struct TraceShare<B: StarkField> {
+    columns: ColMatrix<B>,
+}
+
+impl<B: StarkField> TraceShare<B> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new trace share consisting of the specified columns.
+    pub fn new(columns: ColMatrix<B>) -> Self {
+        Self { columns }
+    }
+
+    /// Splits the specified `trace` into `masks.len() + 1` additive shares.
+    ///
+    /// The first shares are the provided masks, and the last share is the difference between the
+    /// trace and the sum of the masks. For the shares to hide the trace, the masks must be drawn
+    /// uniformly at random by the caller.
+    ///
+    /// # Errors
+    /// Returns an error if any of the masks does not have the same number of columns and rows as
+    /// the `trace`.
+    pub fn split(trace: &ColMatrix<B>, masks: Vec<ColMatrix<B>>) -> Result<Vec<Self>, MpcError> {
+        let mut last_share = trace.clone();
+        for (i, mask) in masks.iter().enumerate() {
+            if !same_shape(mask, trace) {
+                return Err(MpcError::InconsistentShare(i));
+            }
+            for (column, mask_column) in last_share.columns_mut().zip(mask.columns()) {
+                column.iter_mut().zip(mask_column).for_each(|(value, &mask)| *value -= mask);
+            }
+        }
+
+        let mut shares: Vec<Self> = masks.into_iter().map(Self::new).collect();
+        shares.push(Self::new(last_share));
+        Ok(shares)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the columns of this share.
+    pub fn columns(&self) -> &ColMatrix<B> {
+        &self.columns
+    }
+
+    // LOW-DEGREE EXTENSION
+    // --------------------------------------------------------------------------------------------
+
+    /// Interpolates the columns of this share into polynomials and evaluates the polynomials over
+    /// the LDE domain.
+    ///
+    /// This step is performed by each party locally; since both operations are linear, the
+    /// results are additive shares of the trace polynomials and of the extended trace.
+    pub fn extend(&self, domain: &StarkDomain<B>) -> ExtendedTraceShare<B> {
+        let polys = self.columns.interpolate_columns();
+        let lde = RowMatrix::evaluate_polys_over::<DEFAULT_SEGMENT_WIDTH>(&polys, domain);
+        ExtendedTraceShare { polys, lde }
+    }
+}
+
+// EXTENDED TRACE SHARE
+// ================================================================================================
+
+/// An additive share of the trace polynomials and of the low-degree extension of the trace held
+/// by a single party.
+#[derive(Debug, Clone)]
+pub struct ExtendedTraceShare<B: StarkField> {
+    polys: ColMatrix<B>,
+    lde: RowMatrix<B>,
+}
+
+impl<B: StarkField> ExtendedTraceShare<B> {
+    /// Returns this party's share of the trace polynomials in coefficient form.
+    pub fn polys(&self) -> &ColMatrix<B> {
+        &self.polys
+    }
+
+    /// Returns this party's share of the trace evaluated over the LDE domain.
+    pub fn lde(&self) -> &RowMatrix<B> {
+        &self.lde
+    }
+}
+
+// MPC PROVER
+// ================================================================================================
+
+/// Combines extended trace shares of several parties and commits to the combined trace.
+///
+/// The commitment is built in the same way as the commitment to the main trace segment built by
+/// [DefaultTraceLde](crate::DefaultTraceLde), and thus, it can be used in place of the trace
+/// commitment of a regular proof.
+pub struct MpcProver<H, V> {
+    partition_options: PartitionOptions,
+    _commitment: PhantomData<(H, V)>,
+}
+
+impl<H, V> MpcProver<H, V> {
+    /// Returns a new MPC prover which hashes rows of the trace according to the specified
+    /// partition options.
+    pub fn new(partition_options: PartitionOptions) -> Self {
+        Self {
+            partition_options,
+            _commitment: PhantomData,
+        }
+    }
+
+    /// Combines the provided extended trace shares and commits to the rows of the combined
+    /// extended trace.
+    ///
+    /// Returns a tuple containing the combined extended trace, the commitment to it, and the
+    /// combined trace polynomials in coefficient form; the latter are used in the subsequent
+    /// stages of the protocol (i.e., for constraint evaluation and for generating the FRI proof).
+    ///
+    /// # Errors
+    /// Returns an error if no shares were provided, or if the shares do not all have the same
+    /// dimensions.
+    pub fn commit_to_trace<B>(
+        &self,
+        shares: &[ExtendedTraceShare<B>],
+    ) -> Result<(RowMatrix<B>, V, ColMatrix<B>), MpcError>
+    where
+        B: StarkField,
+        H: ElementHasher<BaseField = B>,
+        V: VectorCommitment<H>,
+    {
+        let (first, others) = shares.split_first().ok_or(MpcError::NoShares)?;
+        let mut lde = first.lde.clone();
+        let mut polys = first.polys.clone();
+        for (i, share) in others.iter().enumerate() {
+            if !same_shape(&share.polys, &polys)
+                || share.lde.num_rows() != lde.num_rows()
+                || share.lde.num_cols() != lde.num_cols()
+            {
+                return Err(MpcError::InconsistentShare(i + 1));
+            }
+
+            // in a real deployment, this is the step which is performed inside the MPC
+            lde.add_in_place(&share.lde);
+            for (column, share_column) in polys.columns_mut().zip(share.polys.columns()) {
+                add_in_place(column, share_column);
+            }
+        }
+
+        let commitment = lde.commit_to_rows::<H, V>(self.partition_options);
+        Ok((lde, commitment, polys))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn same_shape<B: StarkField>(a: &ColMatrix<B>, b: &ColMatrix<B>) -> bool {
+    a.num_cols() == b.num_cols() && a.num_rows() == b.num_rows()
+}
@@ -0,0 +1,110 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::PartitionOptions;
+use crypto::{hashers::Blake3_256, MerkleTree, VectorCommitment};
+use math::{fields::f128::BaseElement, FieldElement};
+use rand_utils::rand_vector;
+
+use super::{MpcProver, TraceShare};
+use crate::{
+    matrix::ColMatrix,
+    tests::{build_fib_trace, MockAir},
+    DefaultTraceLde, MpcError, StarkDomain, Trace, TraceLde,
+};
+
+type Blake3 = Blake3_256<BaseElement>;
+type Blake3Prover = MpcProver<Blake3, MerkleTree<Blake3>>;
+
+// MPC PROVER TESTS
+// ================================================================================================
+
+#[test]
+fn two_party_commitment_matches_full_trace() {
+    let trace_length = 8;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let partition_options = PartitionOptions::default();
+
+    // commit to the full trace the same way as a regular prover does
+    let (trace_lde, trace_polys) = DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+        trace.info(),
+        trace.main_segment(),
+        &domain,
+        partition_options,
+    );
+
+    // split the trace into two shares; neither of the shares is equal to the trace
+    let shares =
+        TraceShare::split(trace.main_segment(), vec![random_mask(trace.main_segment())]).unwrap();
+    assert_eq!(2, shares.len());
+    for share in shares.iter() {
+        assert_ne!(trace.main_segment().get_column(0), share.columns().get_column(0));
+    }
+
+    // each party extends its share locally, and the extended shares are then combined
+    let extended_shares: Vec<_> = shares.iter().map(|share| share.extend(&domain)).collect();
+    let prover = Blake3Prover::new(partition_options);
+    let (lde, commitment, polys) = prover.commit_to_trace(&extended_shares).unwrap();
+
+    assert_eq!(trace_lde.get_main_trace_commitment(), commitment.commitment());
+    assert_eq!(trace_lde.get_main_segment().data(), lde.data());
+    for i in 0..trace.main_trace_width() {
+        assert_eq!(trace_polys.get_main_trace_poly(i), polys.get_column(i));
+    }
+}
+
+#[test]
+fn shares_sum_to_trace() {
+    let trace = build_fib_trace(16);
+    let columns = trace.main_segment();
+    let masks = vec![random_mask(columns), random_mask(columns)];
+    let shares = TraceShare::split(columns, masks).unwrap();
+    assert_eq!(3, shares.len());
+
+    for col_idx in 0..columns.num_cols() {
+        for row_idx in 0..columns.num_rows() {
+            let sum = shares
+                .iter()
+                .fold(BaseElement::ZERO, |acc, share| acc + share.columns().get(col_idx, row_idx));
+            assert_eq!(columns.get(col_idx, row_idx), sum);
+        }
+    }
+}
+
+#[test]
+fn invalid_shares() {
+    let trace_length = 8;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let prover = Blake3Prover::new(PartitionOptions::default());
+
+    // masks of the wrong shape are rejected
+    let columns = trace.main_segment();
+    let mask = ColMatrix::new(vec![rand_vector(columns.num_rows())]);
+    let result = TraceShare::split(columns, vec![random_mask(columns), mask]);
+    assert_eq!(Some(MpcError::InconsistentShare(1)), result.err());
+
+    // extended shares of different shapes cannot be combined
+    let share = TraceShare::new(columns.clone()).extend(&domain);
+    let narrow_share = TraceShare::new(ColMatrix::new(vec![columns.get_column(0).to_vec()]));
+    let shares = [share, narrow_share.extend(&domain)];
+    let result = prover.commit_to_trace(&shares);
+    assert_eq!(Some(MpcError::InconsistentShare(1)), result.err());
+
+    let result = prover.commit_to_trace::<BaseElement>(&[]);
+    assert_eq!(Some(MpcError::NoShares), result.err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn random_mask(columns: &ColMatrix<BaseElement>) -> ColMatrix<BaseElement> {
+    ColMatrix::new((0..columns.num_cols()).map(|_| rand_vector(columns.num_rows())).collect())
+}
@@ -0,0 +1,199 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+#[cfg(feature = "profile")]
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+#[cfg(feature = "profile")]
+use air::Air;
+#[cfg(feature = "profile")]
+use maybe_async::{maybe_async, maybe_await};
+
+use super::{ProgressListener, ProvingPhase};
+#[cfg(feature = "profile")]
+use super::{Proof, Prover, ProverError};
+
+// PROVING PROFILE
+// ================================================================================================
+
+/// Wall-clock time spent in each phase of proof generation, as measured by a [ProfilingProver].
+///
+/// The phases are listed in the order in which they are executed by the prover; `total` is the
+/// time it took to generate the entire proof, and thus, is slightly larger than the sum of all
+/// phases.
+#[cfg(feature = "profile")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProvingProfile {
+    /// Time spent on extending the main trace segment and committing to the extended trace. This
+    /// includes interpolation of trace polynomials and their low-degree extension.
+    pub trace_commit: Duration,
+    /// Time spent on building the auxiliary trace segment (including generation of the GKR
+    /// proof), extending it, and committing to it; this is zero for single-segment traces.
+    pub aux_trace_commit: Duration,
+    /// Time spent on evaluating AIR constraints over the constraint evaluation domain.
+    pub constraint_eval: Duration,
+    /// Time spent on interpolating the constraint composition polynomial, extending its columns,
+    /// and committing to the extended columns.
+    pub constraint_commit: Duration,
+    /// Time spent on out-of-domain evaluation, and on building and evaluating the DEEP
+    /// composition polynomial over the LDE domain.
+    pub deep_composition: Duration,
+    /// Time spent on the commit phase of the FRI protocol.
+    pub fri: Duration,
+    /// Time spent on grinding the query seed and drawing query positions.
+    pub pow: Duration,
+    /// Time spent on building the proof object, including opening all commitments at the query
+    /// positions.
+    pub queries: Duration,
+    /// Total time spent on generating the proof.
+    pub total: Duration,
+}
+
+// PROFILING PROVER
+// ================================================================================================
+
+/// Wraps a [Prover] and measures time spent in each phase of proof generation.
+///
+/// Proofs generated by this prover are the same as the proofs generated by the wrapped prover;
+/// however, [prove()](ProfilingProver::prove) also returns a [ProvingProfile] which can be used
+/// to identify the phase dominating the proving time. For example, the time of the trace and
+/// constraint commitment phases grows with the blowup factor, while the time of the
+/// proof-of-work phase grows exponentially with the grinding factor.
+#[cfg(feature = "profile")]
+pub struct ProfilingProver<P: Prover> {
+    prover: P,
+}
+
+#[cfg(feature = "profile")]
+impl<P: Prover> ProfilingProver<P> {
+    /// Returns a new profiling prover which wraps the specified `prover`.
+    pub fn new(prover: P) -> Self {
+        Self { prover }
+    }
+
+    /// Returns a reference to the wrapped prover.
+    pub fn inner(&self) -> &P {
+        &self.prover
+    }
+
+    /// Returns the wrapped prover.
+    pub fn into_inner(self) -> P {
+        self.prover
+    }
+
+    /// Returns a STARK proof for the provided trace generated by the wrapped prover together
+    /// with the time spent in each phase of proof generation.
+    ///
+    /// # Errors
+    /// Returns an error if the wrapped prover fails to generate a proof.
+    #[maybe_async]
+    pub fn prove(&self, trace: P::Trace) -> Result<(Proof, ProvingProfile), ProverError>
+    where
+        <P::Air as Air>::PublicInputs: Send,
+        <P::Air as Air>::GkrProof: Send,
+    {
+        let output = maybe_await!(self.prover.dispatch_proof_generation(trace, false, true, None))?;
+        Ok((output.proof, output.profile.expect("proving profile was not recorded")))
+    }
+}
+
+// PHASE TIMER
+// ================================================================================================
+
+/// Measures time between consecutive phases of proof generation, and reports the phases to a
+/// [ProgressListener] (if one is provided).
+///
+/// When the `profile` feature is disabled, or profiling was not requested, the time spent in each
+/// phase is not recorded in a [ProvingProfile].
+pub(crate) struct PhaseTimer<'a> {
+    listener: Option<&'a dyn ProgressListener>,
+    #[cfg(feature = "std")]
+    last: Instant,
+    #[cfg(feature = "profile")]
+    profile: Option<(Instant, ProvingProfile)>,
+}
+
+impl<'a> PhaseTimer<'a> {
+    /// Returns a new timer; the timer starts measuring the first phase immediately.
+    pub fn new(profile: bool, listener: Option<&'a dyn ProgressListener>) -> Self {
+        #[cfg(feature = "profile")]
+        let timer = Self {
+            listener,
+            last: Instant::now(),
+            profile: profile.then(|| (Instant::now(), ProvingProfile::default())),
+        };
+
+        #[cfg(not(feature = "profile"))]
+        let timer = {
+            let _ = profile;
+            Self {
+                listener,
+                #[cfg(feature = "std")]
+                last: Instant::now(),
+            }
+        };
+
+        if let Some(listener) = timer.listener {
+            listener.on_phase_start(ProvingPhase::TraceCommit);
+        }
+        timer
+    }
+
+    /// Attributes the time elapsed since the end of the previous phase to the specified phase,
+    /// and notifies the listener that the next phase has started.
+    pub fn end_phase(&mut self, phase: ProvingPhase) {
+        #[cfg(feature = "std")]
+        let elapsed = {
+            let now = Instant::now();
+            let elapsed = now - self.last;
+            self.last = now;
+            elapsed
+        };
+
+        #[cfg(feature = "profile")]
+        if let Some((_, profile)) = self.profile.as_mut() {
+            match phase {
+                ProvingPhase::TraceCommit => profile.trace_commit += elapsed,
+                ProvingPhase::AuxTraceCommit => profile.aux_trace_commit += elapsed,
+                ProvingPhase::ConstraintEval => profile.constraint_eval += elapsed,
+                ProvingPhase::ConstraintCommit => profile.constraint_commit += elapsed,
+                ProvingPhase::DeepComposition => profile.deep_composition += elapsed,
+                ProvingPhase::Fri => profile.fri += elapsed,
+                ProvingPhase::Pow => profile.pow += elapsed,
+                ProvingPhase::Queries => profile.queries += elapsed,
+            }
+        }
+
+        if let Some(listener) = self.listener {
+            #[cfg(feature = "std")]
+            let elapsed_ms = elapsed.as_millis() as u64;
+            #[cfg(not(feature = "std"))]
+            let elapsed_ms = 0;
+
+            listener.on_phase_end(phase, elapsed_ms);
+            if let Some(next) = phase.next() {
+                listener.on_phase_start(next);
+            }
+        }
+    }
+
+    /// Notifies the listener that FRI layer `layer` (out of `total_layers`) has been committed to.
+    pub fn fri_layer(&self, layer: usize, total_layers: usize) {
+        if let Some(listener) = self.listener {
+            listener.on_fri_layer(layer, total_layers);
+        }
+    }
+
+    /// Returns the measured profile, or `None` if profiling was not requested.
+    #[cfg(feature = "profile")]
+    pub fn finish(self) -> Option<ProvingProfile> {
+        self.profile.map(|(start, mut profile)| {
+            profile.total = start.elapsed();
+            profile
+        })
+    }
+}
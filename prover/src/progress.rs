@@ -0,0 +1,114 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::fmt;
+
+// PROVING PHASE
+// ================================================================================================
+
+/// Phases of proof generation, listed in the order in which they are executed by the prover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProvingPhase {
+    /// Extending the main trace segment and committing to the extended trace.
+    TraceCommit,
+    /// Building, extending, and committing to the auxiliary trace segment; this phase does no
+    /// work for single-segment traces.
+    AuxTraceCommit,
+    /// Evaluating AIR constraints over the constraint evaluation domain.
+    ConstraintEval,
+    /// Extending the constraint composition polynomial and committing to its columns.
+    ConstraintCommit,
+    /// Building the DEEP composition polynomial and evaluating it over the LDE domain.
+    DeepComposition,
+    /// The commit phase of the FRI protocol.
+    Fri,
+    /// Grinding the query seed and drawing query positions.
+    Pow,
+    /// Building the proof object by opening all commitments at the query positions.
+    Queries,
+}
+
+impl ProvingPhase {
+    /// Returns the phase executed immediately after this phase, or `None` if this is the last
+    /// phase of proof generation.
+    pub fn next(&self) -> Option<Self> {
+        match self {
+            Self::TraceCommit => Some(Self::AuxTraceCommit),
+            Self::AuxTraceCommit => Some(Self::ConstraintEval),
+            Self::ConstraintEval => Some(Self::ConstraintCommit),
+            Self::ConstraintCommit => Some(Self::DeepComposition),
+            Self::DeepComposition => Some(Self::Fri),
+            Self::Fri => Some(Self::Pow),
+            Self::Pow => Some(Self::Queries),
+            Self::Queries => None,
+        }
+    }
+}
+
+impl fmt::Display for ProvingPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TraceCommit => write!(f, "main trace commitment"),
+            Self::AuxTraceCommit => write!(f, "auxiliary trace commitment"),
+            Self::ConstraintEval => write!(f, "constraint evaluation"),
+            Self::ConstraintCommit => write!(f, "constraint commitment"),
+            Self::DeepComposition => write!(f, "DEEP composition"),
+            Self::Fri => write!(f, "FRI commitment"),
+            Self::Pow => write!(f, "proof-of-work"),
+            Self::Queries => write!(f, "query openings"),
+        }
+    }
+}
+
+// PROGRESS LISTENER
+// ================================================================================================
+
+/// Receives notifications about the progress of proof generation.
+///
+/// A listener can be passed to [Prover::prove_with_progress()](crate::Prover::prove_with_progress)
+/// to report progress while a proof is being generated (e.g., for large traces which may take
+/// minutes to prove). All methods have empty default implementations, and so, implementers need
+/// to override only the notifications they are interested in.
+///
+/// The listener is invoked from the thread which generates the proof; notifications should be
+/// handled quickly as they delay proof generation.
+pub trait ProgressListener: Send + Sync {
+    /// Invoked when the prover starts executing the specified phase.
+    fn on_phase_start(&self, _phase: ProvingPhase) {}
+
+    /// Invoked when the prover finishes executing the specified phase; `elapsed_ms` is the
+    /// wall-clock time spent in the phase in milliseconds.
+    ///
+    /// Time is measured only when the `std` feature is enabled; otherwise, `elapsed_ms` is
+    /// always zero.
+    fn on_phase_end(&self, _phase: ProvingPhase, _elapsed_ms: u64) {}
+
+    /// Invoked during the [ProvingPhase::Fri] phase once FRI layer `layer` (out of
+    /// `total_layers`) has been committed to.
+    fn on_fri_layer(&self, _layer: usize, _total_layers: usize) {}
+}
+
+// STDERR PROGRESS LISTENER
+// ================================================================================================
+
+/// A [ProgressListener] which prints progress of proof generation to stderr.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StderrProgressListener;
+
+#[cfg(feature = "std")]
+impl ProgressListener for StderrProgressListener {
+    fn on_phase_start(&self, phase: ProvingPhase) {
+        std::eprintln!("started {phase}");
+    }
+
+    fn on_phase_end(&self, phase: ProvingPhase, elapsed_ms: u64) {
+        std::eprintln!("finished {phase} in {elapsed_ms} ms");
+    }
+
+    fn on_fri_layer(&self, layer: usize, total_layers: usize) {
+        std::eprintln!("  committed to FRI layer {} of {total_layers}", layer + 1);
+    }
+}
@@ -67,6 +67,7 @@ impl MockAir {
             (),
             ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31),
         );
+        result.context = build_context(TraceInfo::new(4, trace_length), 8, assertions.len());
         result.assertions = assertions;
         result
     }
@@ -108,6 +109,69 @@ impl Air for MockAir {
     }
 }
 
+// ALLOCATION COUNTER
+// ================================================================================================
+
+// allocations are counted per thread, and thus, allocations made by worker threads cannot be
+// attributed to the tested code when the `concurrent` feature is enabled
+#[cfg(all(feature = "std", not(feature = "concurrent")))]
+mod allocations {
+    use core::cell::Cell;
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    std::thread_local! {
+        static NUM_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+        static NUM_ALLOCATED_BYTES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// A global allocator which counts allocations made by each thread, as well as the number of
+    /// bytes allocated by them; the bytes released by shrinking allocations are not subtracted.
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            record_allocation(layout.size());
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            record_allocation(new_size.saturating_sub(layout.size()));
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    fn record_allocation(num_bytes: usize) {
+        let _ = NUM_ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        let _ = NUM_ALLOCATED_BYTES.try_with(|count| count.set(count.get() + num_bytes));
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Executes `f` and returns its result together with the number of heap allocations made by
+    /// the current thread during the execution.
+    pub fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+        let start = NUM_ALLOCATIONS.with(Cell::get);
+        let result = f();
+        (result, NUM_ALLOCATIONS.with(Cell::get) - start)
+    }
+
+    /// Executes `f` and returns its result together with the number of bytes allocated on the
+    /// heap by the current thread during the execution.
+    pub fn count_allocated_bytes<R>(f: impl FnOnce() -> R) -> (R, usize) {
+        let start = NUM_ALLOCATED_BYTES.with(Cell::get);
+        let result = f();
+        (result, NUM_ALLOCATED_BYTES.with(Cell::get) - start)
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "concurrent")))]
+pub use allocations::{count_allocated_bytes, count_allocations};
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -0,0 +1,134 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::TraceInfo;
+use math::StarkField;
+
+use super::TraceTable;
+
+// DYNAMIC TRACE TABLE
+// ================================================================================================
+
+/// An execution trace of a yet unknown length built one row at a time.
+///
+/// Unlike [TraceTable], the length of this trace does not need to be known in advance, and does
+/// not need to be a power of two. Once all rows have been added via [push_row()](Self::push_row),
+/// the trace is converted into a [TraceTable] via [finalize()](Self::finalize), which pads the
+/// trace to the next power of two (but to at least 8 rows) according to the specified
+/// [TracePadding] strategy.
+///
+/// Padding rows are part of the execution trace, and thus, are subject to the constraints of the
+/// AIR. AIRs for padded traces should report the number of rows before padding via
+/// `Air::unpadded_trace_length()`, place their assertions within these rows, and, unless the
+/// padding rows satisfy the transition constraints, exempt the padding rows from the transition
+/// constraints.
+#[derive(Debug, Clone)]
+pub struct DynamicTraceTable<B: StarkField> {
+    columns: Vec<Vec<B>>,
+}
+
+impl<B: StarkField> DynamicTraceTable<B> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty execution trace of the specified width.
+    ///
+    /// # Panics
+    /// Panics if `width` is zero or greater than 255.
+    pub fn new(width: usize) -> Self {
+        assert!(width > 0, "execution trace must consist of at least one column");
+        assert!(
+            width <= TraceInfo::MAX_TRACE_WIDTH,
+            "execution trace width cannot be greater than {}, but was {}",
+            TraceInfo::MAX_TRACE_WIDTH,
+            width
+        );
+        Self { columns: vec![Vec::new(); width] }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of columns in this execution trace.
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns the number of rows which have been added to this execution trace.
+    pub fn num_rows(&self) -> usize {
+        self.columns[0].len()
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Appends the specified row to the end of this execution trace.
+    ///
+    /// # Panics
+    /// Panics if the number of values in the row is not equal to the width of the trace.
+    pub fn push_row(&mut self, row: Vec<B>) {
+        assert_eq!(
+            self.width(),
+            row.len(),
+            "expected a row of {} values, but received {}",
+            self.width(),
+            row.len()
+        );
+        for (column, value) in self.columns.iter_mut().zip(row) {
+            column.push(value);
+        }
+    }
+
+    // FINALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Pads this execution trace to the next power of two (but to at least 8 rows) using the
+    /// specified padding strategy, and returns the resulting trace together with the number of
+    /// rows in this trace before padding.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * No rows have been added to this trace.
+    /// * The padding row of [TracePadding::Row] does not have the same width as the trace.
+    /// * The padded trace length exceeds the biggest multiplicative subgroup in the field `B`.
+    pub fn finalize(mut self, padding: TracePadding<B>) -> (TraceTable<B>, usize) {
+        let num_rows = self.num_rows();
+        assert!(num_rows > 0, "execution trace must contain at least one row");
+
+        let padded_length = num_rows.next_power_of_two().max(TraceInfo::MIN_TRACE_LENGTH);
+        let pad_row = match padding {
+            TracePadding::RepeatLastRow => self.columns.iter().map(|c| c[num_rows - 1]).collect(),
+            TracePadding::Row(row) => {
+                assert_eq!(
+                    self.width(),
+                    row.len(),
+                    "expected a padding row of {} values, but received {}",
+                    self.width(),
+                    row.len()
+                );
+                row
+            },
+        };
+        for (column, value) in self.columns.iter_mut().zip(pad_row) {
+            column.resize(padded_length, value);
+        }
+
+        (TraceTable::init(self.columns), num_rows)
+    }
+}
+
+// TRACE PADDING
+// ================================================================================================
+
+/// Defines how a [DynamicTraceTable] is padded to a power of two length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TracePadding<B: StarkField> {
+    /// The last row of the trace is repeated in all padding rows.
+    RepeatLastRow,
+    /// The specified row is used for all padding rows.
+    Row(Vec<B>),
+}
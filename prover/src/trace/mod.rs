@@ -3,13 +3,18 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use air::{Air, AuxRandElements, EvaluationFrame, LagrangeKernelBoundaryConstraint, TraceInfo};
+use alloc::{string::ToString, vec::Vec};
+
+use air::{
+    Air, Assertion, AuxRandElements, EvaluationFrame, LagrangeKernelBoundaryConstraint, TraceInfo,
+    TransitionExemption,
+};
 use math::{polynom, FieldElement, StarkField};
 
-use super::ColMatrix;
+use super::{ColMatrix, TraceValidationError};
 
 mod trace_lde;
-pub use trace_lde::{DefaultTraceLde, TraceLde};
+pub use trace_lde::{ChunkedTraceLde, DefaultTraceLde, TraceLde};
 
 mod poly_table;
 pub use poly_table::TracePolyTable;
@@ -17,6 +22,12 @@ pub use poly_table::TracePolyTable;
 mod trace_table;
 pub use trace_table::{TraceTable, TraceTableFragment};
 
+mod dynamic_table;
+pub use dynamic_table::{DynamicTraceTable, TracePadding};
+
+mod segment_view;
+pub use segment_view::{RowRef, TraceIterator, TraceSegmentRows, TraceSegmentView};
+
 #[cfg(test)]
 mod tests;
 
@@ -67,6 +78,9 @@ pub trait Trace: Sized {
     fn main_segment(&self) -> &ColMatrix<Self::BaseField>;
 
     /// Reads an evaluation frame from the main trace segment at the specified row.
+    ///
+    /// The frame must be filled with `frame.num_rows()` consecutive rows starting at `row_idx`;
+    /// at the end of the trace, rows wrap around to the start of the trace.
     fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>);
 
     // PROVIDED METHODS
@@ -87,200 +101,325 @@ pub trait Trace: Sized {
         self.info().aux_segment_width()
     }
 
-    /// Checks if this trace is valid against the specified AIR, and panics if not.
+    /// Checks if this trace is valid against the specified AIR.
+    ///
+    /// If `aux_trace_with_metadata` is `None`, only the main segment of the trace is validated.
     ///
-    /// NOTE: this is a very expensive operation and is intended for use only in debug mode.
+    /// NOTE: this is a very expensive operation and is intended for use only in debug mode or for
+    /// debugging AIR implementations.
+    ///
+    /// # Errors
+    /// Returns an error if the trace does not satisfy any of the assertions or transition
+    /// constraints defined by the AIR.
     fn validate<A, E>(
         &self,
         air: &A,
         aux_trace_with_metadata: Option<&AirAuxTraceWithMetadata<A, E>>,
-    ) where
+    ) -> Result<(), TraceValidationError>
+    where
         A: Air<BaseField = Self::BaseField>,
         E: FieldElement<BaseField = Self::BaseField>,
     {
-        // make sure the width align; if they don't something went terribly wrong
-        assert_eq!(
-            self.main_trace_width(),
-            air.trace_info().main_trace_width(),
-            "inconsistent trace width: expected {}, but was {}",
-            self.main_trace_width(),
-            air.trace_info().main_trace_width(),
-        );
-
-        // --- 1. make sure the assertions are valid ----------------------------------------------
-
-        // first, check assertions against the main segment of the execution trace
-        for assertion in air.get_assertions() {
-            assertion.apply(self.length(), |step, value| {
-                assert!(
-                    value == self.main_segment().get(assertion.column(), step),
-                    "trace does not satisfy assertion main_trace({}, {}) == {}",
-                    assertion.column(),
+        validate_segments(self, air, aux_trace_with_metadata, true)
+    }
+}
+
+// TRACE VALIDATION
+// ================================================================================================
+
+/// Checks if the main segment of the specified `trace` is valid against the specified `air`.
+///
+/// This checks that the main segment of the trace satisfies all main trace assertions and main
+/// transition constraints of the AIR. Since the auxiliary trace segment (if any) depends on the
+/// randomness drawn during proof generation, it is not validated by this function.
+///
+/// This can be used to catch bugs in the trace generation logic or in AIR definitions before
+/// starting (potentially very expensive) proof generation.
+///
+/// # Errors
+/// Returns an error describing the first assertion or transition constraint which the trace
+/// does not satisfy.
+pub fn validate_trace<A, T>(trace: &T, air: &A) -> Result<(), TraceValidationError>
+where
+    A: Air<BaseField = T::BaseField>,
+    T: Trace,
+{
+    trace.validate::<A, A::BaseField>(air, None)
+}
+
+/// Checks the auxiliary segment of the specified `trace` against the specified `air`, assuming
+/// the main segment has already been validated via [validate_trace()].
+pub(crate) fn validate_aux_segment<A, E, T>(
+    trace: &T,
+    air: &A,
+    aux_trace_with_metadata: &AirAuxTraceWithMetadata<A, E>,
+) -> Result<(), TraceValidationError>
+where
+    A: Air<BaseField = T::BaseField>,
+    E: FieldElement<BaseField = T::BaseField>,
+    T: Trace,
+{
+    validate_segments(trace, air, Some(aux_trace_with_metadata), false)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks the specified trace against the AIR; this is the implementation of [Trace::validate()].
+///
+/// If `check_main` is false, assertions and transition constraints which depend only on the main
+/// trace segment are skipped; this is used to avoid validating the main segment twice once the
+/// auxiliary segment becomes available.
+fn validate_segments<A, E, T>(
+    trace: &T,
+    air: &A,
+    aux_trace_with_metadata: Option<&AirAuxTraceWithMetadata<A, E>>,
+    check_main: bool,
+) -> Result<(), TraceValidationError>
+where
+    A: Air<BaseField = T::BaseField>,
+    E: FieldElement<BaseField = T::BaseField>,
+    T: Trace,
+{
+    // make sure the width align; if they don't something went terribly wrong
+    if trace.main_trace_width() != air.trace_info().main_trace_width() {
+        return Err(TraceValidationError::InconsistentTraceWidth {
+            expected: air.trace_info().main_trace_width(),
+            actual: trace.main_trace_width(),
+        });
+    }
+
+    // make sure the computation described by the trace fits into the trace
+    let unpadded_length = air.unpadded_trace_length();
+    if unpadded_length == 0 || unpadded_length > trace.length() {
+        return Err(TraceValidationError::InvalidUnpaddedTraceLength {
+            unpadded_length,
+            trace_length: trace.length(),
+        });
+    }
+
+    // --- 1. make sure the assertions are valid ---------------------------------------------------
+
+    // first, check assertions against the main segment of the execution trace
+    for assertion in air.get_assertions().into_iter().filter(|_| check_main) {
+        check_assertion(&assertion, trace.length(), |column, step, expected| {
+            let actual =
+                assertion.evaluate_columns(|column| trace.main_segment().get(column, step));
+            (expected != actual).then(|| TraceValidationError::MainAssertionNotSatisfied {
+                column,
+                step,
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            })
+        })?;
+    }
+
+    // then, check assertions against the auxiliary trace segment
+    if let Some(aux_trace_with_metadata) = aux_trace_with_metadata {
+        let aux_trace = &aux_trace_with_metadata.aux_trace;
+        let aux_rand_elements = &aux_trace_with_metadata.aux_rand_elements;
+
+        for assertion in air.get_aux_assertions(aux_rand_elements) {
+            // get the matrix and verify the assertion against it
+            check_assertion(&assertion, trace.length(), |column, step, expected| {
+                let actual = assertion.evaluate_columns(|column| aux_trace.get(column, step));
+                (expected != actual).then(|| TraceValidationError::AuxAssertionNotSatisfied {
+                    column,
                     step,
-                    value
-                );
-            });
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                })
+            })?;
         }
 
-        // then, check assertions against the auxiliary trace segment
-        if let Some(aux_trace_with_metadata) = aux_trace_with_metadata {
-            let aux_trace = &aux_trace_with_metadata.aux_trace;
-            let aux_rand_elements = &aux_trace_with_metadata.aux_rand_elements;
+        // then, check the Lagrange kernel assertion, if any
+        if let Some(lagrange_kernel_col_idx) = air.context().lagrange_kernel_aux_column_idx() {
+            let boundary_constraint_assertion_value =
+                LagrangeKernelBoundaryConstraint::assertion_value(
+                    aux_rand_elements
+                        .lagrange()
+                        .expect("expected Lagrange kernel rand elements to be present"),
+                );
 
-            for assertion in air.get_aux_assertions(aux_rand_elements) {
-                // get the matrix and verify the assertion against it
-                assertion.apply(self.length(), |step, value| {
-                    assert!(
-                        value == aux_trace.get(assertion.column(), step),
-                        "trace does not satisfy assertion aux_trace({}, {}) == {}",
-                        assertion.column(),
-                        step,
-                        value
-                    );
+            let actual = aux_trace.get(lagrange_kernel_col_idx, 0);
+            if boundary_constraint_assertion_value != actual {
+                return Err(TraceValidationError::LagrangeKernelAssertionNotSatisfied {
+                    expected: boundary_constraint_assertion_value.to_string(),
+                    actual: actual.to_string(),
                 });
             }
+        }
+    }
 
-            // then, check the Lagrange kernel assertion, if any
-            if let Some(lagrange_kernel_col_idx) = air.context().lagrange_kernel_aux_column_idx() {
-                let boundary_constraint_assertion_value =
-                    LagrangeKernelBoundaryConstraint::assertion_value(
-                        aux_rand_elements
-                            .lagrange()
-                            .expect("expected Lagrange kernel rand elements to be present"),
-                    );
-
-                assert_eq!(
-                    boundary_constraint_assertion_value,
-                    aux_trace.get(lagrange_kernel_col_idx, 0)
-                );
-            }
+    // --- 2. make sure this trace satisfies all transition constraints ----------------------------
+
+    // collect the info needed to build periodic values for a specific step
+    let g = air.trace_domain_generator();
+    let periodic_values_polys = air.get_periodic_column_polys();
+    let mut periodic_values = vec![T::BaseField::ZERO; periodic_values_polys.len()];
+
+    // initialize buffers to hold evaluation frames and results of constraint evaluations
+    let mut x = T::BaseField::ONE;
+    let frame_size = air.frame_size();
+    let mut main_frame = EvaluationFrame::with_num_rows(trace.main_trace_width(), frame_size);
+    let mut aux_frame = aux_trace_with_metadata.map(|aux_trace_with_metadata| {
+        let aux_frame = EvaluationFrame::<E>::with_num_rows(trace.aux_trace_width(), frame_size);
+        (aux_trace_with_metadata, aux_frame)
+    });
+    let mut main_evaluations =
+        vec![T::BaseField::ZERO; air.context().num_main_transition_constraints()];
+    let mut aux_evaluations = vec![E::ZERO; air.context().num_aux_transition_constraints()];
+
+    // we check each transition constraint on all steps except for the steps exempt from the
+    // constraint; by default, these are the last k steps, where k is the number of transition
+    // exemptions (guaranteed to be at least 1)
+    let num_main_constraints = main_evaluations.len();
+    let exemptions: Vec<TransitionExemption> = (0..air.context().num_transition_constraints())
+        .map(|constraint_idx| air.context().transition_exemption(constraint_idx))
+        .collect();
+    for step in 0..trace.length() {
+        // skip steps on which no transition constraint needs to hold; these include the last
+        // steps of the trace, for which evaluation frames may not be readable
+        if exemptions.iter().all(|exemption| exemption.is_exempt(step, trace.length())) {
+            x *= g;
+            continue;
         }
 
-        // --- 2. make sure this trace satisfies all transition constraints -----------------------
-
-        // collect the info needed to build periodic values for a specific step
-        let g = air.trace_domain_generator();
-        let periodic_values_polys = air.get_periodic_column_polys();
-        let mut periodic_values = vec![Self::BaseField::ZERO; periodic_values_polys.len()];
-
-        // initialize buffers to hold evaluation frames and results of constraint evaluations
-        let mut x = Self::BaseField::ONE;
-        let mut main_frame = EvaluationFrame::new(self.main_trace_width());
-        let mut aux_frame = if air.trace_info().is_multi_segment() {
-            Some(EvaluationFrame::<E>::new(self.aux_trace_width()))
-        } else {
-            None
-        };
-        let mut main_evaluations =
-            vec![Self::BaseField::ZERO; air.context().num_main_transition_constraints()];
-        let mut aux_evaluations = vec![E::ZERO; air.context().num_aux_transition_constraints()];
-
-        // we check transition constraints on all steps except the last k steps, where k is the
-        // number of steps exempt from transition constraints (guaranteed to be at least 1)
-        for step in 0..self.length() - air.context().num_transition_exemptions() {
-            // build periodic values
-            for (p, v) in periodic_values_polys.iter().zip(periodic_values.iter_mut()) {
-                let num_cycles = air.trace_length() / p.len();
-                let x = x.exp((num_cycles as u32).into());
-                *v = polynom::eval(p, x);
-            }
+        // build periodic values
+        for (p, v) in periodic_values_polys.iter().zip(periodic_values.iter_mut()) {
+            let num_cycles = air.trace_length() / p.len();
+            let x = x.exp((num_cycles as u32).into());
+            *v = polynom::eval(p, x);
+        }
 
-            // evaluate transition constraints for the main trace segment and make sure they all
-            // evaluate to zeros
-            self.read_main_frame(step, &mut main_frame);
+        // evaluate transition constraints for the main trace segment and make sure they all
+        // evaluate to zeros; the main frame is needed by auxiliary constraints as well
+        trace.read_main_frame(step, &mut main_frame);
+        if check_main {
             air.evaluate_transition(&main_frame, &periodic_values, &mut main_evaluations);
-            for (i, &evaluation) in main_evaluations.iter().enumerate() {
-                assert!(
-                    evaluation == Self::BaseField::ZERO,
-                    "main transition constraint {i} did not evaluate to ZERO at step {step}"
-                );
-            }
-
-            // evaluate transition constraints for the auxiliary trace segment (if any) and make
-            // sure they all evaluate to zeros
-            if let Some(ref mut aux_frame) = aux_frame {
-                let aux_trace_with_metadata =
-                    aux_trace_with_metadata.expect("expected aux trace to be present");
-                let aux_trace = &aux_trace_with_metadata.aux_trace;
-                let aux_rand_elements = &aux_trace_with_metadata.aux_rand_elements;
-
-                read_aux_frame(aux_trace, step, aux_frame);
-                air.evaluate_aux_transition(
-                    &main_frame,
-                    aux_frame,
-                    &periodic_values,
-                    aux_rand_elements,
-                    &mut aux_evaluations,
-                );
-                for (i, &evaluation) in aux_evaluations.iter().enumerate() {
-                    assert!(
-                        evaluation == E::ZERO,
-                        "auxiliary transition constraint {i} did not evaluate to ZERO at step {step}"
-                    );
-                }
+        }
+        for (constraint_idx, &evaluation) in main_evaluations.iter().enumerate() {
+            if check_main
+                && evaluation != T::BaseField::ZERO
+                && !exemptions[constraint_idx].is_exempt(step, trace.length())
+            {
+                return Err(TraceValidationError::MainTransitionNotSatisfied {
+                    constraint_idx,
+                    step,
+                    evaluation: evaluation.to_string(),
+                    row: main_frame.current().iter().map(|v| v.to_string()).collect(),
+                });
             }
-
-            // update x coordinate of the domain
-            x *= g;
         }
 
-        // evaluate transition constraints for Lagrange kernel column (if any) and make sure
-        // they all evaluate to zeros
-        if let Some(col_idx) = air.context().lagrange_kernel_aux_column_idx() {
-            let aux_trace_with_metadata =
-                aux_trace_with_metadata.expect("expected aux trace to be present");
+        // evaluate transition constraints for the auxiliary trace segment (if any) and make
+        // sure they all evaluate to zeros
+        if let Some((aux_trace_with_metadata, ref mut aux_frame)) = aux_frame {
             let aux_trace = &aux_trace_with_metadata.aux_trace;
             let aux_rand_elements = &aux_trace_with_metadata.aux_rand_elements;
 
-            let c = aux_trace.get_column(col_idx);
-            let v = self.length().ilog2() as usize;
-            let r = aux_rand_elements.lagrange().expect("expected Lagrange column to be present");
-
-            // Loop over every constraint
-            for constraint_idx in 1..v + 1 {
-                let domain_step = 2_usize.pow((v - constraint_idx + 1) as u32);
-                let domain_half_step = 2_usize.pow((v - constraint_idx) as u32);
-
-                // Every transition constraint has a different enforcement domain (i.e. the rows to which it applies).
-                let enforcement_dom_len = self.length() / domain_step;
-                for dom_idx in 0..enforcement_dom_len {
-                    let x_current = dom_idx * domain_step;
-                    let x_next = x_current + domain_half_step;
+            read_aux_frame(aux_trace, step, aux_frame);
+            air.evaluate_aux_transition(
+                &main_frame,
+                aux_frame,
+                &periodic_values,
+                aux_rand_elements,
+                &mut aux_evaluations,
+            );
+            for (constraint_idx, &evaluation) in aux_evaluations.iter().enumerate() {
+                let exemption = exemptions[num_main_constraints + constraint_idx];
+                if evaluation != E::ZERO && !exemption.is_exempt(step, trace.length()) {
+                    return Err(TraceValidationError::AuxTransitionNotSatisfied {
+                        constraint_idx,
+                        step,
+                        evaluation: evaluation.to_string(),
+                        row: aux_frame.current().iter().map(|v| v.to_string()).collect(),
+                    });
+                }
+            }
+        }
 
-                    let evaluation = (r[v - constraint_idx] * c[x_current])
-                        - ((E::ONE - r[v - constraint_idx]) * c[x_next]);
+        // update x coordinate of the domain
+        x *= g;
+    }
 
-                    assert!(
-                        evaluation == E::ZERO,
-                        "Lagrange transition constraint {constraint_idx} did not evaluate to ZERO at step {x_current}"
-                    );
+    // evaluate transition constraints for Lagrange kernel column (if any) and make sure
+    // they all evaluate to zeros
+    if let (Some(col_idx), Some(aux_trace_with_metadata)) =
+        (air.context().lagrange_kernel_aux_column_idx(), aux_trace_with_metadata)
+    {
+        let aux_trace = &aux_trace_with_metadata.aux_trace;
+        let aux_rand_elements = &aux_trace_with_metadata.aux_rand_elements;
+
+        let c = aux_trace.get_column(col_idx);
+        let v = trace.length().ilog2() as usize;
+        let r = aux_rand_elements.lagrange().expect("expected Lagrange column to be present");
+
+        // Loop over every constraint
+        for constraint_idx in 1..v + 1 {
+            let domain_step = 2_usize.pow((v - constraint_idx + 1) as u32);
+            let domain_half_step = 2_usize.pow((v - constraint_idx) as u32);
+
+            // Every transition constraint has a different enforcement domain (i.e. the rows to which it applies).
+            let enforcement_dom_len = trace.length() / domain_step;
+            for dom_idx in 0..enforcement_dom_len {
+                let x_current = dom_idx * domain_step;
+                let x_next = x_current + domain_half_step;
+
+                let evaluation = (r[v - constraint_idx] * c[x_current])
+                    - ((E::ONE - r[v - constraint_idx]) * c[x_next]);
+
+                if evaluation != E::ZERO {
+                    return Err(TraceValidationError::LagrangeKernelTransitionNotSatisfied {
+                        constraint_idx,
+                        step: x_current,
+                        evaluation: evaluation.to_string(),
+                    });
                 }
             }
         }
     }
+
+    Ok(())
 }
 
-// HELPER FUNCTIONS
-// ================================================================================================
+/// Checks the specified assertion against a trace of the specified length using the provided
+/// `check` function, and returns the first error reported by `check` (if any).
+fn check_assertion<E, F>(
+    assertion: &Assertion<E>,
+    trace_length: usize,
+    mut check: F,
+) -> Result<(), TraceValidationError>
+where
+    E: FieldElement,
+    F: FnMut(usize, usize, E) -> Option<TraceValidationError>,
+{
+    let mut result = Ok(());
+    assertion.apply(trace_length, |step, value| {
+        if result.is_ok() {
+            if let Some(err) = check(assertion.column(), step, value) {
+                result = Err(err);
+            }
+        }
+    });
+    result
+}
 
 /// Reads an evaluation frame from the provided auxiliary segment.
 ///
 /// This is probably not the most efficient implementation, but since we call this function only
-/// for trace validation purposes (which is done in debug mode only), we don't care all that much
-/// about its performance.
+/// for trace validation purposes (which is usually done in debug mode only), we don't care all
+/// that much about its performance.
 fn read_aux_frame<E>(aux_segment: &ColMatrix<E>, row_idx: usize, frame: &mut EvaluationFrame<E>)
 where
     E: FieldElement,
 {
-    for (current_frame_cell, aux_segment_col) in
-        frame.current_mut().iter_mut().zip(aux_segment.columns())
-    {
-        *current_frame_cell = aux_segment_col[row_idx];
-    }
-
-    let next_row_idx = (row_idx + 1) % aux_segment.num_rows();
-    for (next_frame_cell, aux_segment_col) in frame.next_mut().iter_mut().zip(aux_segment.columns())
-    {
-        *next_frame_cell = aux_segment_col[next_row_idx];
+    for offset in 0..frame.num_rows() {
+        let frame_row_idx = (row_idx + offset) % aux_segment.num_rows();
+        for (frame_cell, aux_segment_col) in
+            frame.row_mut(offset).iter_mut().zip(aux_segment.columns())
+        {
+            *frame_cell = aux_segment_col[frame_row_idx];
+        }
     }
 }
@@ -78,15 +78,20 @@ impl<E: FieldElement> TracePolyTable<E> {
     }
 
     /// Returns an out-of-domain evaluation frame constructed by evaluating trace polynomials for
-    /// all columns at points z and z * g, where g is the generator of the trace domain.
+    /// all columns at points z, z * g, ..., z * g^(k-1), where g is the generator of the trace
+    /// domain and k is the specified `frame_size`.
     /// Additionally, if the Lagrange kernel auxiliary column is present, we also evaluate that
     /// column over the points: z, z * g, z * g^2, z * g^4, ..., z * g^(2^(v-1)), where v =
     /// log(trace_len).
-    pub fn get_ood_frame(&self, z: E) -> TraceOodFrame<E> {
+    pub fn get_ood_frame(&self, z: E, frame_size: usize) -> TraceOodFrame<E> {
         let log_trace_len = self.poly_size().ilog2();
         let g = E::from(E::BaseField::get_root_of_unity(log_trace_len));
-        let current_row = self.evaluate_at(z);
-        let next_row = self.evaluate_at(z * g);
+        let mut rows = Vec::with_capacity(frame_size);
+        let mut x = z;
+        for _ in 0..frame_size {
+            rows.push(self.evaluate_at(x));
+            x *= g;
+        }
 
         let lagrange_kernel_frame =
             self.lagrange_kernel_poly.as_ref().map(|lagrange_kernel_col_poly| {
@@ -98,7 +103,7 @@ impl<E: FieldElement> TracePolyTable<E> {
 
         let main_trace_width = self.main_trace_polys.num_cols();
 
-        TraceOodFrame::new(current_row, next_row, main_trace_width, lagrange_kernel_frame)
+        TraceOodFrame::new(rows, main_trace_width, lagrange_kernel_frame)
     }
 
     /// Returns an iterator over the polynomials of the main trace segment.
@@ -0,0 +1,174 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use math::StarkField;
+
+use super::TraceTable;
+
+// TRACE ITERATOR
+// ================================================================================================
+
+/// A source of execution trace rows which are produced one at a time.
+///
+/// Computations which generate the trace row-by-row can implement this trait directly; for
+/// traces which are already stored in a [TraceTable], an iterator can be obtained via
+/// [TraceSegmentView::rows()].
+///
+/// Note that the prover cannot consume a trace iterator: the prover commits to the low-degree
+/// extension of the trace, and building it requires interpolating every column of the trace over
+/// the entire trace domain. Thus, the full trace must be available to the prover (e.g., via a
+/// [TraceTable]).
+///
+/// Rows returned by [TraceIterator::next_row()] borrow from the iterator, and thus, the iterator
+/// needs to buffer only the row it returned most recently.
+pub trait TraceIterator<B: StarkField> {
+    /// Returns the number of columns in each of the rows returned by this iterator.
+    fn width(&self) -> usize;
+
+    /// Returns the next row of the trace, or `None` if all rows have already been returned.
+    fn next_row(&mut self) -> Option<RowRef<'_, B>>;
+}
+
+// ROW REFERENCE
+// ================================================================================================
+
+/// A reference to a single row of an execution trace returned by a [TraceIterator].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowRef<'a, B: StarkField> {
+    step: usize,
+    values: &'a [B],
+}
+
+impl<'a, B: StarkField> RowRef<'a, B> {
+    /// Returns a new row reference for the row at the specified step of an execution trace.
+    pub fn new(step: usize, values: &'a [B]) -> Self {
+        Self { step, values }
+    }
+
+    /// Returns the step of the execution trace at which this row is located.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Returns the values of all columns in this row.
+    pub fn values(&self) -> &'a [B] {
+        self.values
+    }
+}
+
+impl<B: StarkField> Deref for RowRef<'_, B> {
+    type Target = [B];
+
+    fn deref(&self) -> &Self::Target {
+        self.values
+    }
+}
+
+// TRACE SEGMENT VIEW
+// ================================================================================================
+
+/// An immutable view of a contiguous range of rows in a [TraceTable].
+///
+/// A view cannot be instantiated directly but is created by executing the
+/// [TraceTable::segment_view()] method. Steps passed to the accessors of the view are relative to
+/// the start of the view.
+#[derive(Debug, Clone, Copy)]
+pub struct TraceSegmentView<'a, B: StarkField> {
+    trace: &'a TraceTable<B>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, B: StarkField> TraceSegmentView<'a, B> {
+    /// Returns a view of rows `start..end` of the specified trace.
+    pub(super) fn new(trace: &'a TraceTable<B>, start: usize, end: usize) -> Self {
+        Self { trace, start, end }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the step at which this view starts in the context of the underlying trace.
+    pub fn offset(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the number of rows in this view.
+    pub fn num_rows(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns the number of columns in this view (same as the width of the underlying trace).
+    pub fn width(&self) -> usize {
+        self.trace.width()
+    }
+
+    /// Returns the values of the specified column at all rows of this view.
+    pub fn get_column(&self, col_idx: usize) -> &'a [B] {
+        &self.trace.get_column(col_idx)[self.start..self.end]
+    }
+
+    /// Returns value of the cell in the specified column at the specified row of this view.
+    ///
+    /// # Panics
+    /// Panics if `step` is outside of this view.
+    pub fn get(&self, column: usize, step: usize) -> B {
+        assert!(step < self.num_rows(), "step {step} is outside of the trace segment view");
+        self.trace.get(column, self.start + step)
+    }
+
+    /// Reads a single row of this view into the provided target.
+    ///
+    /// # Panics
+    /// Panics if `step` is outside of this view.
+    pub fn read_row_into(&self, step: usize, target: &mut [B]) {
+        assert!(step < self.num_rows(), "step {step} is outside of the trace segment view");
+        self.trace.read_row_into(self.start + step, target);
+    }
+
+    /// Returns a [TraceIterator] over the rows of this view.
+    ///
+    /// Steps of the rows returned by the iterator are given in the context of the underlying
+    /// trace.
+    pub fn rows(&self) -> TraceSegmentRows<'a, B> {
+        TraceSegmentRows {
+            view: *self,
+            next_step: self.start,
+            row: vec![B::ZERO; self.width()],
+        }
+    }
+}
+
+// TRACE SEGMENT ROWS
+// ================================================================================================
+
+/// A [TraceIterator] over the rows of a [TraceSegmentView].
+///
+/// Since the underlying trace is stored in column-major order, each row is copied into an
+/// internal buffer before being returned.
+pub struct TraceSegmentRows<'a, B: StarkField> {
+    view: TraceSegmentView<'a, B>,
+    next_step: usize,
+    row: Vec<B>,
+}
+
+impl<B: StarkField> TraceIterator<B> for TraceSegmentRows<'_, B> {
+    fn width(&self) -> usize {
+        self.view.width()
+    }
+
+    fn next_row(&mut self) -> Option<RowRef<'_, B>> {
+        if self.next_step == self.view.end {
+            return None;
+        }
+        let step = self.next_step;
+        self.view.trace.read_row_into(step, &mut self.row);
+        self.next_step += 1;
+        Some(RowRef::new(step, &self.row))
+    }
+}
@@ -3,11 +3,20 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 
-use math::fields::f128::BaseElement;
+use air::Assertion;
+use math::{
+    fields::{f128::BaseElement, f64},
+    FieldElement,
+};
+use rand_utils::rand_vector;
 
-use crate::{tests::build_fib_trace, Trace};
+use crate::{
+    tests::{build_fib_trace, MockAir},
+    validate_trace, DynamicTraceTable, Trace, TraceError, TraceIterator, TracePadding, TraceTable,
+    TraceValidationError,
+};
 
 #[test]
 fn new_trace_table() {
@@ -29,3 +38,239 @@ fn new_trace_table() {
         .collect();
     assert_eq!(expected, trace.get_column(1));
 }
+
+#[test]
+fn trace_table_from_rows() {
+    let trace = build_fib_trace(16);
+
+    // a trace built from the rows of another trace is the same as the original trace
+    let mut rows = Vec::new();
+    let mut row = vec![BaseElement::ZERO; trace.main_trace_width()];
+    for step in 0..trace.length() {
+        trace.read_row_into(step, &mut row);
+        rows.extend_from_slice(&row);
+    }
+    let result = TraceTable::from_rows(&rows, trace.main_trace_width());
+    assert_eq!(trace.main_trace_width(), result.main_trace_width());
+    assert_eq!(trace.length(), result.length());
+    for col_idx in 0..trace.main_trace_width() {
+        assert_eq!(trace.get_column(col_idx), result.get_column(col_idx));
+    }
+}
+
+#[test]
+fn dynamic_trace_table_padding() {
+    let rows = (0..10u128).map(|i| vec![BaseElement::new(i), BaseElement::new(i * i)]);
+
+    // the last row is repeated until the next power of two
+    let mut dynamic_trace = DynamicTraceTable::new(2);
+    rows.clone().for_each(|row| dynamic_trace.push_row(row));
+    assert_eq!(10, dynamic_trace.num_rows());
+    let (trace, num_rows) = dynamic_trace.finalize(TracePadding::RepeatLastRow);
+    assert_eq!(10, num_rows);
+    assert_eq!(16, trace.length());
+    for step in 0..16 {
+        let i = step.min(9) as u128;
+        assert_eq!(BaseElement::new(i), trace.get(0, step));
+        assert_eq!(BaseElement::new(i * i), trace.get(1, step));
+    }
+
+    // the specified row is used for padding
+    let mut dynamic_trace = DynamicTraceTable::new(2);
+    rows.for_each(|row| dynamic_trace.push_row(row));
+    let pad_row = vec![BaseElement::ZERO, BaseElement::ONE];
+    let (trace, num_rows) = dynamic_trace.finalize(TracePadding::Row(pad_row));
+    assert_eq!(10, num_rows);
+    assert_eq!(BaseElement::new(81), trace.get(1, 9));
+    for step in 10..16 {
+        assert_eq!(BaseElement::ZERO, trace.get(0, step));
+        assert_eq!(BaseElement::ONE, trace.get(1, step));
+    }
+}
+
+#[test]
+fn dynamic_trace_table_length() {
+    // traces are padded to at least 8 rows
+    let mut dynamic_trace = DynamicTraceTable::new(1);
+    dynamic_trace.push_row(vec![BaseElement::ONE]);
+    let (trace, num_rows) = dynamic_trace.finalize(TracePadding::RepeatLastRow);
+    assert_eq!((8, 1), (trace.length(), num_rows));
+
+    // traces with a power of two length are not padded
+    let mut dynamic_trace = DynamicTraceTable::new(1);
+    (0..32u128).for_each(|i| dynamic_trace.push_row(vec![BaseElement::new(i)]));
+    let (trace, num_rows) = dynamic_trace.finalize(TracePadding::RepeatLastRow);
+    assert_eq!((32, 32), (trace.length(), num_rows));
+    assert_eq!(BaseElement::new(31), trace.get(0, 31));
+}
+
+#[test]
+#[should_panic(expected = "expected a row of 2 values, but received 3")]
+fn dynamic_trace_table_invalid_row() {
+    let mut dynamic_trace = DynamicTraceTable::new(2);
+    dynamic_trace.push_row(vec![BaseElement::ONE; 3]);
+}
+
+#[test]
+fn trace_table_io_round_trip() {
+    // a trace with metadata spanning several I/O buffers per column
+    let columns: Vec<Vec<BaseElement>> = (0..3).map(|_| rand_vector(1 << 14)).collect();
+    let mut trace_with_meta = TraceTable::with_meta(3, 1 << 14, vec![1, 2, 3]);
+    for (col_idx, column) in columns.iter().enumerate() {
+        for (step, &value) in column.iter().enumerate() {
+            trace_with_meta.set(col_idx, step, value);
+        }
+    }
+    let trace = TraceTable::init(columns);
+
+    for trace in [build_fib_trace(16), trace, trace_with_meta] {
+        let mut bytes = Vec::new();
+        trace.write_to(&mut bytes).unwrap();
+
+        let mut reader = bytes.as_slice();
+        let result = TraceTable::<BaseElement>::read_from(&mut reader).unwrap();
+        assert!(reader.is_empty());
+        assert_eq!(trace.info(), result.info());
+        for col_idx in 0..trace.main_trace_width() {
+            assert_eq!(trace.get_column(col_idx), result.get_column(col_idx));
+        }
+    }
+}
+
+#[test]
+fn trace_table_io_errors() {
+    let mut bytes = Vec::new();
+    build_fib_trace(16).write_to(&mut bytes).unwrap();
+    let read = |bytes: &[u8]| TraceTable::<BaseElement>::read_from(&mut &bytes[..]);
+
+    // header offsets: 4 magic bytes, version, modulus length, 16 bytes of the modulus, width,
+    // length, and metadata length
+    let mut invalid = bytes.clone();
+    invalid[0] = b'X';
+    assert!(matches!(read(&invalid), Err(TraceError::InvalidMagic)));
+
+    let mut invalid = bytes.clone();
+    invalid[4] = 2;
+    assert!(matches!(read(&invalid), Err(TraceError::UnsupportedVersion(2))));
+
+    let result = TraceTable::<f64::BaseElement>::read_from(&mut bytes.as_slice());
+    assert!(matches!(result, Err(TraceError::FieldMismatch)));
+
+    for width in [0u32, 256] {
+        let mut invalid = bytes.clone();
+        invalid[22..26].copy_from_slice(&width.to_le_bytes());
+        assert!(matches!(read(&invalid), Err(TraceError::InvalidWidth(w)) if w == width as u64));
+    }
+
+    for length in [4u64, 12, 1 << 41] {
+        let mut invalid = bytes.clone();
+        invalid[26..34].copy_from_slice(&length.to_le_bytes());
+        assert!(matches!(read(&invalid), Err(TraceError::InvalidLength(l)) if l == length));
+    }
+
+    let mut invalid = bytes.clone();
+    invalid[34..38].copy_from_slice(&65536u32.to_le_bytes());
+    assert!(matches!(read(&invalid), Err(TraceError::InvalidMetaLength(65536))));
+
+    // the first value of the trace is replaced with a value greater than the modulus
+    let mut invalid = bytes.clone();
+    invalid[38..54].copy_from_slice(&[0xff; 16]);
+    assert!(matches!(read(&invalid), Err(TraceError::InvalidElement(_))));
+
+    let result = read(&bytes[..bytes.len() - 1]);
+    assert!(
+        matches!(result, Err(TraceError::Io(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof)
+    );
+
+    // a valid, but huge, length does not result in memory being allocated for values which are
+    // not present in the input
+    let mut invalid = bytes.clone();
+    invalid[26..34].copy_from_slice(&(1u64 << 40).to_le_bytes());
+    let result = read(&invalid);
+    assert!(
+        matches!(result, Err(TraceError::Io(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof)
+    );
+}
+
+#[test]
+fn trace_segment_view() {
+    let trace = build_fib_trace(32);
+    let view = trace.segment_view(4, 12);
+    assert_eq!(4, view.offset());
+    assert_eq!(8, view.num_rows());
+    assert_eq!(2, view.width());
+    assert_eq!(&trace.get_column(1)[4..12], view.get_column(1));
+    assert_eq!(trace.get(0, 7), view.get(0, 3));
+
+    let mut row = [BaseElement::ZERO; 2];
+    view.read_row_into(7, &mut row);
+    assert_eq!([trace.get(0, 11), trace.get(1, 11)], row);
+
+    // rows are returned in order, with steps given in the context of the entire trace
+    let mut rows = view.rows();
+    assert_eq!(2, rows.width());
+    for step in 4..12 {
+        let row = rows.next_row().unwrap();
+        assert_eq!(step, row.step());
+        assert_eq!(&[trace.get(0, step), trace.get(1, step)], row.values());
+    }
+    assert!(rows.next_row().is_none());
+
+    assert!(trace.segment_view(16, 16).rows().next_row().is_none());
+}
+
+#[test]
+#[should_panic(expected = "segment end 17 is greater than trace length 16")]
+fn trace_segment_view_out_of_bounds() {
+    build_fib_trace(32).segment_view(8, 17);
+}
+
+#[test]
+fn validate_trace_width_mismatch() {
+    let trace = build_fib_trace(16);
+    let air = MockAir::with_trace_length(8);
+    assert_eq!(
+        Err(TraceValidationError::InconsistentTraceWidth { expected: 4, actual: 2 }),
+        validate_trace(&trace, &air)
+    );
+}
+
+#[test]
+fn validate_trace_assertions() {
+    let trace = TraceTable::init(vec![vec![BaseElement::ONE; 8]; 4]);
+
+    let air = MockAir::with_assertions(vec![Assertion::single(2, 3, BaseElement::ONE)], 8);
+    assert_eq!(Ok(()), validate_trace(&trace, &air));
+
+    let air = MockAir::with_assertions(vec![Assertion::single(2, 3, BaseElement::ZERO)], 8);
+    assert_eq!(
+        Err(TraceValidationError::MainAssertionNotSatisfied {
+            column: 2,
+            step: 3,
+            expected: BaseElement::ZERO.to_string(),
+            actual: BaseElement::ONE.to_string(),
+        }),
+        validate_trace(&trace, &air)
+    );
+
+    // when only the auxiliary segment is validated, main trace assertions are not checked again
+    assert_eq!(Ok(()), super::validate_segments::<_, BaseElement, _>(&trace, &air, None, false));
+}
+
+#[cfg(all(feature = "std", not(feature = "concurrent")))]
+#[test]
+fn validate_trace_reuses_evaluation_frames() {
+    use crate::tests::count_allocations;
+
+    // the number of allocations made while validating a trace does not depend on the trace
+    // length because evaluation frames are allocated once and then re-filled at every step
+    let num_allocations = [64, 1024].map(|trace_length| {
+        let trace = TraceTable::init(vec![vec![BaseElement::ONE; trace_length]; 4]);
+        let air =
+            MockAir::with_assertions(vec![Assertion::single(2, 3, BaseElement::ONE)], trace_length);
+        let (result, num_allocations) = count_allocations(|| validate_trace(&trace, &air));
+        assert_eq!(Ok(()), result);
+        num_allocations
+    });
+    assert_eq!(num_allocations[0], num_allocations[1]);
+}
@@ -0,0 +1,452 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use air::{proof::Queries, LagrangeKernelEvaluationFrame, PartitionOptions, TraceInfo};
+use crypto::VectorCommitment;
+use math::{fft, polynom, StarkField};
+#[cfg(feature = "concurrent")]
+use utils::iterators::*;
+use utils::{batch_iter_mut, iter};
+
+use super::{
+    ColMatrix, ElementHasher, EvaluationFrame, FieldElement, StarkDomain, TraceLde, TracePolyTable,
+};
+
+#[cfg(test)]
+mod tests;
+
+// CHUNKED TRACE LOW DEGREE EXTENSION
+// ================================================================================================
+/// A [TraceLde] which commits to the extended execution trace without keeping the entire trace
+/// LDE in memory.
+///
+/// The LDE domain of size `lde_blowup * n` (where `n` is the trace length) is the union of
+/// `lde_blowup` cosets of the trace domain: the `j`-th coset contains rows `j`, `j + lde_blowup`,
+/// `j + 2 * lde_blowup`, etc. Instead of evaluating trace polynomials over the entire LDE domain
+/// at once, the trace is extended one coset at a time: the polynomials are evaluated over the
+/// coset, the rows of the coset are hashed into the corresponding leaves of the vector
+/// commitment, and the evaluations are discarded. The only exception are the rows of the
+/// constraint evaluation domain, which are retained because they are read by the constraint
+/// evaluator. Rows opened at query positions outside of the constraint evaluation domain are
+/// re-derived from trace polynomials once the query positions are known.
+///
+/// The leaves of the vector commitment are identical to the leaves built by [DefaultTraceLde]
+/// (crate::DefaultTraceLde), and so, the commitments and the resulting proofs are identical as
+/// well.
+///
+/// # Memory estimate
+/// For a trace segment with `w` columns and `n` rows, [DefaultTraceLde](crate::DefaultTraceLde)
+/// holds `lde_blowup * n * w` field elements of the trace LDE. This implementation instead holds:
+/// * `ce_blowup * n * w` elements of the trace evaluated over the constraint evaluation domain;
+/// * `n * w` elements of trace polynomials (in addition to the polynomials returned to the
+///   prover), which are needed to re-derive the rows opened at query positions;
+/// * `n * w` elements of the coset being extended during the commitment.
+///
+/// Both implementations additionally hold the vector commitment itself (e.g., `2 * lde_blowup * n`
+/// digests for a Merkle tree). For example, for a trace with blowup factor 8 and constraints of
+/// degree 2 (i.e., `ce_blowup` = 2), peak memory for trace values is reduced from `8 * n * w` to
+/// `4 * n * w` elements. The price is extending the trace one coset at a time, and re-deriving
+/// each queried row outside of the constraint evaluation domain via `w` polynomial evaluations
+/// of degree `n - 1`.
+pub struct ChunkedTraceLde<
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+> {
+    // main segment of the trace and its commitment
+    main_segment: ChunkedSegment<E::BaseField>,
+    main_segment_oracles: V,
+    // auxiliary segment of the trace and its commitment
+    aux_segment: Option<ChunkedSegment<E>>,
+    aux_segment_oracles: Option<V>,
+    blowup: usize,
+    ce_to_lde_blowup: usize,
+    lde_domain_offset: E::BaseField,
+    trace_info: TraceInfo,
+    partition_options: PartitionOptions,
+    _h: PhantomData<H>,
+}
+
+impl<E, H, V> ChunkedTraceLde<E, H, V>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+{
+    /// Takes the main trace segment columns as input, interpolates them into polynomials in
+    /// coefficient form, commits to the evaluations of these polynomials over the LDE domain
+    /// (extending the trace one coset of the trace domain at a time), and creates a new
+    /// [ChunkedTraceLde] with the commitment.
+    ///
+    /// Returns a tuple containing a [TracePolyTable] with the trace polynomials for the main trace
+    /// segment and the new [ChunkedTraceLde].
+    pub fn new(
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<E::BaseField>,
+        domain: &StarkDomain<E::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self, TracePolyTable<E>) {
+        let (main_segment, main_segment_oracles) =
+            build_trace_commitment::<E::BaseField, H, V>(main_trace, domain, partition_options);
+
+        let trace_poly_table = TracePolyTable::new(main_segment.polys.clone());
+        let trace_lde = ChunkedTraceLde {
+            main_segment,
+            main_segment_oracles,
+            aux_segment: None,
+            aux_segment_oracles: None,
+            blowup: domain.trace_to_lde_blowup(),
+            ce_to_lde_blowup: domain.ce_to_lde_blowup(),
+            lde_domain_offset: domain.offset(),
+            trace_info: trace_info.clone(),
+            partition_options,
+            _h: PhantomData,
+        };
+
+        (trace_lde, trace_poly_table)
+    }
+
+    /// Maps a step in the LDE domain to a step in the constraint evaluation domain.
+    fn ce_step(&self, lde_step: usize) -> usize {
+        debug_assert_eq!(
+            lde_step % self.ce_to_lde_blowup,
+            0,
+            "step {lde_step} is not in the constraint evaluation domain"
+        );
+        (lde_step % (self.main_segment.polys.num_rows() * self.blowup)) / self.ce_to_lde_blowup
+    }
+}
+
+impl<E, H, V> TraceLde<E> for ChunkedTraceLde<E, H, V>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField> + core::marker::Sync,
+    V: VectorCommitment<H> + core::marker::Sync,
+{
+    type HashFn = H;
+    type VC = V;
+
+    /// Returns the commitment to the low-degree extension of the main trace segment.
+    fn get_main_trace_commitment(&self) -> H::Digest {
+        self.main_segment_oracles.commitment()
+    }
+
+    /// Takes auxiliary trace segment columns as input, interpolates them into polynomials in
+    /// coefficient form, and commits to the evaluations of these polynomials over the LDE domain
+    /// (extending the trace one coset of the trace domain at a time).
+    ///
+    /// Returns a tuple containing the column polynomials in coefficient from and the commitment
+    /// to the polynomial evaluations over the LDE domain.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the following are true:
+    /// - the number of rows in the provided `aux_trace` does not match the main trace.
+    /// - the auxiliary trace has been previously set already.
+    fn set_aux_trace(
+        &mut self,
+        aux_trace: &ColMatrix<E>,
+        domain: &StarkDomain<E::BaseField>,
+    ) -> (ColMatrix<E>, H::Digest) {
+        // check errors
+        assert!(
+            usize::from(self.aux_segment.is_some()) < self.trace_info.num_aux_segments(),
+            "the auxiliary trace has already been added"
+        );
+        assert_eq!(
+            self.main_segment.polys.num_rows(),
+            aux_trace.num_rows(),
+            "the number of rows in the auxiliary segment must be the same as in the main segment"
+        );
+
+        // extend the auxiliary trace segment and build a commitment to the extended trace
+        let (aux_segment, aux_segment_oracles) =
+            build_trace_commitment::<E, H, V>(aux_trace, domain, self.partition_options);
+
+        // save the segment and commitment
+        let aux_segment_polys = aux_segment.polys.clone();
+        self.aux_segment = Some(aux_segment);
+        let commitment_string = aux_segment_oracles.commitment();
+        self.aux_segment_oracles = Some(aux_segment_oracles);
+
+        (aux_segment_polys, commitment_string)
+    }
+
+    /// Reads all rows of the specified frame from the main trace segment.
+    ///
+    /// `lde_step` must be a step of the constraint evaluation domain.
+    fn read_main_trace_frame_into(
+        &self,
+        lde_step: usize,
+        frame: &mut EvaluationFrame<E::BaseField>,
+    ) {
+        // at the end of the trace, next states wrap around and we read the first steps again
+        for offset in 0..frame.num_rows() {
+            let ce_step = self.ce_step(lde_step + offset * self.blowup());
+            frame.row_mut(offset).copy_from_slice(self.main_segment.ce_row(ce_step));
+        }
+    }
+
+    /// Reads all rows of the specified frame from the auxiliary trace segment.
+    ///
+    /// `lde_step` must be a step of the constraint evaluation domain.
+    ///
+    /// # Panics
+    /// This currently assumes that there is exactly one auxiliary trace segment, and will panic
+    /// otherwise.
+    fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
+        // copy auxiliary trace segment values into the frame; at the end of the trace, next states
+        // wrap around and we read the first steps again
+        let segment = self.aux_segment.as_ref().expect("expected aux segment to be present");
+        for offset in 0..frame.num_rows() {
+            let ce_step = self.ce_step(lde_step + offset * self.blowup());
+            frame.row_mut(offset).copy_from_slice(segment.ce_row(ce_step));
+        }
+    }
+
+    fn read_lagrange_kernel_frame_into(
+        &self,
+        lde_step: usize,
+        lagrange_kernel_aux_column_idx: usize,
+        frame: &mut LagrangeKernelEvaluationFrame<E>,
+    ) {
+        let frame = frame.frame_mut();
+        frame.truncate(0);
+
+        let aux_segment = self.aux_segment.as_ref().expect("expected aux segment to be present");
+
+        frame.push(aux_segment.ce_row(self.ce_step(lde_step))[lagrange_kernel_aux_column_idx]);
+
+        let frame_length = self.trace_info.length().ilog2() as usize + 1;
+        for i in 0..frame_length - 1 {
+            let shift = self.blowup() * (1 << i);
+            let ce_step = self.ce_step(lde_step + shift);
+
+            frame.push(aux_segment.ce_row(ce_step)[lagrange_kernel_aux_column_idx]);
+        }
+    }
+
+    /// Returns trace table rows at the specified positions along with an opening proof to these
+    /// rows against the already computed commitment.
+    ///
+    /// Rows outside of the constraint evaluation domain are re-derived by evaluating trace
+    /// polynomials at the corresponding points of the LDE domain.
+    fn query(&self, positions: &[usize]) -> Vec<Queries> {
+        let lde_domain_size = self.trace_len();
+        let g = E::BaseField::get_root_of_unity(lde_domain_size.ilog2());
+        let points = positions
+            .iter()
+            .map(|&pos| self.lde_domain_offset * g.exp_vartime((pos as u64).into()))
+            .collect::<Vec<_>>();
+
+        // build queries for the main trace segment
+        let mut result = vec![build_segment_queries::<E::BaseField, H, V>(
+            &self.main_segment,
+            &self.main_segment_oracles,
+            positions,
+            &points,
+            self.ce_to_lde_blowup,
+        )];
+
+        // build queries for the auxiliary trace segment
+        if let Some(ref segment_oracles) = self.aux_segment_oracles {
+            let segment = self.aux_segment.as_ref().expect("expected aux segment to be present");
+            result.push(build_segment_queries::<E, H, V>(
+                segment,
+                segment_oracles,
+                positions,
+                &points,
+                self.ce_to_lde_blowup,
+            ));
+        }
+
+        result
+    }
+
+    /// Returns the number of rows in the LDE of the execution trace.
+    fn trace_len(&self) -> usize {
+        self.main_segment.polys.num_rows() * self.blowup
+    }
+
+    /// Returns blowup factor which was used to extend original execution trace into trace LDE.
+    fn blowup(&self) -> usize {
+        self.blowup
+    }
+
+    /// Returns the trace info of the execution trace.
+    fn trace_info(&self) -> &TraceInfo {
+        &self.trace_info
+    }
+}
+
+// CHUNKED SEGMENT
+// ================================================================================================
+
+/// A trace segment of which only the polynomials and the evaluations over the constraint
+/// evaluation domain are retained.
+struct ChunkedSegment<F: FieldElement> {
+    /// Trace polynomials in coefficient form.
+    polys: ColMatrix<F>,
+    /// Rows of the trace evaluated over the constraint evaluation domain in row-major order.
+    ce_rows: Vec<F>,
+}
+
+impl<F: FieldElement> ChunkedSegment<F> {
+    /// Returns the row of this segment at the specified step of the constraint evaluation domain.
+    fn ce_row(&self, ce_step: usize) -> &[F] {
+        let width = self.polys.num_cols();
+        &self.ce_rows[ce_step * width..(ce_step + 1) * width]
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Interpolates the provided execution trace segment into polynomials and builds a commitment to
+/// the evaluations of these polynomials over the LDE domain.
+///
+/// The LDE is computed one coset of the trace domain at a time; rows of each coset are hashed
+/// into their positions in the LDE domain, and only the rows which belong to the constraint
+/// evaluation domain are retained.
+fn build_trace_commitment<F, H, V>(
+    trace: &ColMatrix<F>,
+    domain: &StarkDomain<F::BaseField>,
+    partition_options: PartitionOptions,
+) -> (ChunkedSegment<F>, V)
+where
+    F: FieldElement,
+    H: ElementHasher<BaseField = F::BaseField>,
+    V: VectorCommitment<H>,
+{
+    let trace_polys = {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("interpolate_execution_trace", num_cols = trace.num_cols())
+            .entered();
+        trace.interpolate_columns()
+    };
+
+    let trace_len = trace.num_rows();
+    let width = trace.num_cols();
+    let blowup = domain.trace_to_lde_blowup();
+    let ce_to_lde_blowup = domain.ce_to_lde_blowup();
+    let lde_domain_size = domain.lde_domain_size();
+    let g = F::BaseField::get_root_of_unity(lde_domain_size.ilog2());
+    let partition_size = partition_options.partition_size::<F>(width);
+
+    let mut row_hashes = vec![H::Digest::default(); lde_domain_size];
+    let mut ce_rows = vec![F::ZERO; domain.ce_domain_size() * width];
+
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!(
+        "commit_to_execution_trace_in_chunks",
+        num_cols = width,
+        blowup,
+        commitment_domain_size = lde_domain_size
+    )
+    .entered();
+    let polys = trace_polys.columns().collect::<Vec<_>>();
+    for coset_idx in 0..blowup {
+        // evaluate all polynomials over the coset of the trace domain which contains rows
+        // coset_idx, coset_idx + blowup, coset_idx + 2 * blowup, etc. of the LDE domain
+        let coset_offset = domain.offset() * g.exp_vartime((coset_idx as u64).into());
+        let coset_columns: Vec<Vec<F>> = iter!(polys)
+            .map(|poly| {
+                fft::evaluate_poly_with_offset(poly, domain.trace_twiddles(), coset_offset, 1)
+            })
+            .collect();
+
+        // hash the rows of the coset into the leaves of the commitment
+        let mut coset_hashes = vec![H::Digest::default(); trace_len];
+        batch_iter_mut!(
+            &mut coset_hashes,
+            128, // min batch size
+            |batch: &mut [H::Digest], batch_offset: usize| {
+                let mut row = vec![F::ZERO; width];
+                let mut buffer = Vec::new();
+                for (i, row_hash) in batch.iter_mut().enumerate() {
+                    for (value, column) in row.iter_mut().zip(coset_columns.iter()) {
+                        *value = column[batch_offset + i];
+                    }
+                    *row_hash = hash_row::<F, H>(&row, partition_size, &mut buffer);
+                }
+            }
+        );
+        for (row_idx, hash) in coset_hashes.into_iter().enumerate() {
+            row_hashes[coset_idx + row_idx * blowup] = hash;
+        }
+
+        // retain the rows which belong to the constraint evaluation domain
+        if coset_idx % ce_to_lde_blowup == 0 {
+            let trace_to_ce_blowup = blowup / ce_to_lde_blowup;
+            for row_idx in 0..trace_len {
+                let ce_step = coset_idx / ce_to_lde_blowup + row_idx * trace_to_ce_blowup;
+                let ce_row = &mut ce_rows[ce_step * width..(ce_step + 1) * width];
+                for (value, column) in ce_row.iter_mut().zip(coset_columns.iter()) {
+                    *value = column[row_idx];
+                }
+            }
+        }
+    }
+    let trace_vector_com = V::new(row_hashes).expect("failed to construct trace vector commitment");
+    #[cfg(feature = "tracing")]
+    drop(span);
+    assert_eq!(trace_vector_com.domain_len(), lde_domain_size);
+
+    (ChunkedSegment { polys: trace_polys, ce_rows }, trace_vector_com)
+}
+
+/// Hashes a single row of a trace segment in the same way as
+/// [RowMatrix::commit_to_rows()](crate::RowMatrix::commit_to_rows) does.
+fn hash_row<F, H>(row: &[F], partition_size: usize, buffer: &mut Vec<H::Digest>) -> H::Digest
+where
+    F: FieldElement,
+    H: ElementHasher<BaseField = F::BaseField>,
+{
+    if partition_size == row.len() {
+        H::hash_elements(row)
+    } else {
+        buffer.clear();
+        buffer.extend(row.chunks(partition_size).map(H::hash_elements));
+        H::merge_many(buffer)
+    }
+}
+
+fn build_segment_queries<F, H, V>(
+    segment: &ChunkedSegment<F>,
+    segment_vector_com: &V,
+    positions: &[usize],
+    points: &[F::BaseField],
+    ce_to_lde_blowup: usize,
+) -> Queries
+where
+    F: FieldElement,
+    H: ElementHasher<BaseField = F::BaseField>,
+    V: VectorCommitment<H>,
+{
+    // for each position, get the corresponding row either from the rows retained for the
+    // constraint evaluation domain, or by re-evaluating the trace polynomials
+    let trace_states = positions
+        .iter()
+        .zip(points)
+        .map(|(&pos, &x)| {
+            if pos % ce_to_lde_blowup == 0 {
+                segment.ce_row(pos / ce_to_lde_blowup).to_vec()
+            } else {
+                let x = F::from(x);
+                segment.polys.columns().map(|poly| polynom::eval(poly, x)).collect()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // build a batch opening proof to the leaves specified by positions
+    let trace_proof = segment_vector_com
+        .open_many(positions)
+        .expect("failed to generate a batch opening proof for trace queries");
+
+    Queries::new::<H, F, V>(trace_proof.1, trace_states)
+}
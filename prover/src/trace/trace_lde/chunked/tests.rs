@@ -0,0 +1,86 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::{EvaluationFrame, LagrangeKernelEvaluationFrame, PartitionOptions, TraceInfo};
+use crypto::{hashers::Blake3_256, MerkleTree};
+use math::fields::f128::BaseElement;
+use rand_utils::rand_vector;
+
+use crate::{tests::MockAir, ChunkedTraceLde, ColMatrix, DefaultTraceLde, StarkDomain, TraceLde};
+
+type Blake3 = Blake3_256<BaseElement>;
+type DefaultLde = DefaultTraceLde<BaseElement, Blake3, MerkleTree<Blake3>>;
+type ChunkedLde = ChunkedTraceLde<BaseElement, Blake3, MerkleTree<Blake3>>;
+
+#[test]
+fn chunked_trace_lde_matches_default() {
+    // with blowup factor 8 and constraints of degree 2, every 4th row of the LDE belongs to the
+    // constraint evaluation domain
+    let trace_length = 64;
+    let air = MockAir::with_trace_length(trace_length);
+    let domain = StarkDomain::new(&air);
+    assert_eq!(4, domain.ce_to_lde_blowup());
+
+    let trace_info = TraceInfo::new_multi_segment(5, 3, 1, trace_length, Vec::new());
+    let main_trace = ColMatrix::new((0..5).map(|_| rand_vector(trace_length)).collect());
+    let aux_trace = ColMatrix::new((0..3).map(|_| rand_vector(trace_length)).collect());
+
+    for partition_options in [PartitionOptions::default(), PartitionOptions::new(2, 1)] {
+        let (mut default_lde, default_polys) =
+            DefaultLde::new(&trace_info, &main_trace, &domain, partition_options);
+        let (mut chunked_lde, chunked_polys) =
+            ChunkedLde::new(&trace_info, &main_trace, &domain, partition_options);
+
+        // commitments and trace polynomials are identical for both segments
+        assert_eq!(
+            default_lde.get_main_trace_commitment(),
+            chunked_lde.get_main_trace_commitment()
+        );
+        assert_eq!(
+            default_polys.main_trace_polys().collect::<Vec<_>>(),
+            chunked_polys.main_trace_polys().collect::<Vec<_>>()
+        );
+        let (default_aux_polys, default_aux_commitment) =
+            default_lde.set_aux_trace(&aux_trace, &domain);
+        let (chunked_aux_polys, chunked_aux_commitment) =
+            chunked_lde.set_aux_trace(&aux_trace, &domain);
+        assert_eq!(default_aux_commitment, chunked_aux_commitment);
+        assert_eq!(
+            default_aux_polys.columns().collect::<Vec<_>>(),
+            chunked_aux_polys.columns().collect::<Vec<_>>()
+        );
+        assert_eq!(default_lde.trace_len(), chunked_lde.trace_len());
+
+        // frames read over the constraint evaluation domain are identical
+        let mut default_main_frame = EvaluationFrame::new(5);
+        let mut chunked_main_frame = EvaluationFrame::new(5);
+        let mut default_aux_frame = EvaluationFrame::new(3);
+        let mut chunked_aux_frame = EvaluationFrame::new(3);
+        let mut default_lagrange_frame = LagrangeKernelEvaluationFrame::new_empty();
+        let mut chunked_lagrange_frame = LagrangeKernelEvaluationFrame::new_empty();
+        for step in (0..domain.lde_domain_size()).step_by(domain.ce_to_lde_blowup()) {
+            default_lde.read_main_trace_frame_into(step, &mut default_main_frame);
+            chunked_lde.read_main_trace_frame_into(step, &mut chunked_main_frame);
+            assert_eq!(default_main_frame.current(), chunked_main_frame.current());
+            assert_eq!(default_main_frame.next(), chunked_main_frame.next());
+
+            default_lde.read_aux_trace_frame_into(step, &mut default_aux_frame);
+            chunked_lde.read_aux_trace_frame_into(step, &mut chunked_aux_frame);
+            assert_eq!(default_aux_frame.current(), chunked_aux_frame.current());
+            assert_eq!(default_aux_frame.next(), chunked_aux_frame.next());
+
+            default_lde.read_lagrange_kernel_frame_into(step, 2, &mut default_lagrange_frame);
+            chunked_lde.read_lagrange_kernel_frame_into(step, 2, &mut chunked_lagrange_frame);
+            assert_eq!(default_lagrange_frame.inner(), chunked_lagrange_frame.inner());
+        }
+
+        // rows opened at positions inside and outside of the constraint evaluation domain are
+        // identical, and so are their opening proofs
+        let positions = [0, 1, 3, 4, 17, 255, 510, 511];
+        assert_eq!(default_lde.query(&positions), chunked_lde.query(&positions));
+    }
+}
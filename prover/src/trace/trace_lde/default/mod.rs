@@ -8,7 +8,6 @@ use core::marker::PhantomData;
 
 use air::{proof::Queries, LagrangeKernelEvaluationFrame, PartitionOptions, TraceInfo};
 use crypto::VectorCommitment;
-use tracing::info_span;
 
 use super::{
     ColMatrix, ElementHasher, EvaluationFrame, FieldElement, StarkDomain, TraceLde, TracePolyTable,
@@ -165,33 +164,50 @@ where
         (aux_segment_polys, commitment_string)
     }
 
-    /// Reads current and next rows from the main trace segment into the specified frame.
+    /// Reads all rows of the specified frame from the main trace segment.
     fn read_main_trace_frame_into(
         &self,
         lde_step: usize,
         frame: &mut EvaluationFrame<E::BaseField>,
     ) {
-        // at the end of the trace, next state wraps around and we read the first step again
-        let next_lde_step = (lde_step + self.blowup()) % self.trace_len();
+        // at the end of the trace, next states wrap around and we read the first steps again
+        for offset in 0..frame.num_rows() {
+            let frame_lde_step = (lde_step + offset * self.blowup()) % self.trace_len();
+            frame.row_mut(offset).copy_from_slice(self.main_segment_lde.row(frame_lde_step));
+        }
+    }
 
-        // copy main trace segment values into the frame
-        frame.current_mut().copy_from_slice(self.main_segment_lde.row(lde_step));
-        frame.next_mut().copy_from_slice(self.main_segment_lde.row(next_lde_step));
+    /// Reads the specified columns of all rows of the specified frame from the main trace
+    /// segment; values of other columns in the frame are left unchanged.
+    fn read_main_trace_frame_columns_into(
+        &self,
+        lde_step: usize,
+        columns: &[usize],
+        frame: &mut EvaluationFrame<E::BaseField>,
+    ) {
+        for offset in 0..frame.num_rows() {
+            let frame_lde_step = (lde_step + offset * self.blowup()) % self.trace_len();
+            let row = self.main_segment_lde.row(frame_lde_step);
+            let frame_row = frame.row_mut(offset);
+            for &column in columns {
+                frame_row[column] = row[column];
+            }
+        }
     }
 
-    /// Reads current and next rows from the auxiliary trace segment into the specified frame.
+    /// Reads all rows of the specified frame from the auxiliary trace segment.
     ///
     /// # Panics
     /// This currently assumes that there is exactly one auxiliary trace segment, and will panic
     /// otherwise.
     fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
-        // at the end of the trace, next state wraps around and we read the first step again
-        let next_lde_step = (lde_step + self.blowup()) % self.trace_len();
-
-        // copy auxiliary trace segment values into the frame
+        // copy auxiliary trace segment values into the frame; at the end of the trace, next states
+        // wrap around and we read the first steps again
         let segment = self.aux_segment_lde.as_ref().expect("expected aux segment to be present");
-        frame.current_mut().copy_from_slice(segment.row(lde_step));
-        frame.next_mut().copy_from_slice(segment.row(next_lde_step));
+        for offset in 0..frame.num_rows() {
+            let frame_lde_step = (lde_step + offset * self.blowup()) % self.trace_len();
+            frame.row_mut(offset).copy_from_slice(segment.row(frame_lde_step));
+        }
     }
 
     fn read_lagrange_kernel_frame_into(
@@ -278,7 +294,8 @@ where
 {
     // extend the execution trace
     let (trace_lde, trace_polys) = {
-        let span = info_span!(
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
             "extend_execution_trace",
             num_cols = trace.num_cols(),
             blowup = domain.trace_to_lde_blowup()
@@ -287,7 +304,6 @@ where
         let trace_polys = trace.interpolate_columns();
         let trace_lde =
             RowMatrix::evaluate_polys_over::<DEFAULT_SEGMENT_WIDTH>(&trace_polys, domain);
-        drop(span);
 
         (trace_lde, trace_polys)
     };
@@ -297,8 +313,13 @@ where
 
     // build trace commitment
     let commitment_domain_size = trace_lde.num_rows();
-    let trace_vector_com = info_span!("compute_execution_trace_commitment", commitment_domain_size)
-        .in_scope(|| trace_lde.commit_to_rows::<H, V>(partition_options));
+    let trace_vector_com = {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::info_span!("compute_execution_trace_commitment", commitment_domain_size)
+                .entered();
+        trace_lde.commit_to_rows::<H, V>(partition_options)
+    };
     assert_eq!(trace_vector_com.domain_len(), commitment_domain_size);
 
     (trace_lde, trace_vector_com, trace_polys)
@@ -14,6 +14,9 @@ use crate::StarkDomain;
 mod default;
 pub use default::DefaultTraceLde;
 
+mod chunked;
+pub use chunked::ChunkedTraceLde;
+
 // TRACE LOW DEGREE EXTENSION
 // ================================================================================================
 /// Contains all segments of the extended execution trace and their commitments.
@@ -52,13 +55,34 @@ pub trait TraceLde<E: FieldElement>: Sync {
     ) -> (ColMatrix<E>, <Self::HashFn as Hasher>::Digest);
 
     /// Reads current and next rows from the main trace segment into the specified frame.
+    ///
+    /// If the frame contains more than two rows, `frame.num_rows()` consecutive rows of the trace
+    /// (i.e., rows which are `blowup` steps apart in the LDE) are read into the frame.
     fn read_main_trace_frame_into(
         &self,
         lde_step: usize,
         frame: &mut EvaluationFrame<E::BaseField>,
     );
 
+    /// Reads the specified columns of current and next rows from the main trace segment into the
+    /// specified frame.
+    ///
+    /// Values of other columns in the frame may be left unchanged. The default implementation
+    /// reads all columns via [TraceLde::read_main_trace_frame_into()].
+    #[allow(unused_variables)]
+    fn read_main_trace_frame_columns_into(
+        &self,
+        lde_step: usize,
+        columns: &[usize],
+        frame: &mut EvaluationFrame<E::BaseField>,
+    ) {
+        self.read_main_trace_frame_into(lde_step, frame);
+    }
+
     /// Reads current and next rows from the auxiliary trace segment into the specified frame.
+    ///
+    /// If the frame contains more than two rows, `frame.num_rows()` consecutive rows of the trace
+    /// (i.e., rows which are `blowup` steps apart in the LDE) are read into the frame.
     fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>);
 
     /// Populates the provided Lagrange kernel frame starting at the current row (as defined by
@@ -4,20 +4,44 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cmp;
 
 use air::{EvaluationFrame, TraceInfo};
 use math::StarkField;
-use utils::uninit_vector;
+use utils::transpose;
+#[cfg(feature = "std")]
+use utils::ByteWriter;
 #[cfg(feature = "concurrent")]
 use utils::{iterators::*, rayon};
 
-use super::{ColMatrix, Trace};
+use super::{ColMatrix, Trace, TraceSegmentView};
+#[cfg(feature = "std")]
+use crate::TraceError;
 
 // CONSTANTS
 // ================================================================================================
 
 const MIN_FRAGMENT_LENGTH: usize = 2;
 
+/// Bytes with which every execution trace written by [TraceTable::write_to()] starts.
+#[cfg(feature = "std")]
+const TRACE_FILE_MAGIC: [u8; 4] = *b"WTRC";
+
+/// Version of the format in which execution traces are written by [TraceTable::write_to()].
+#[cfg(feature = "std")]
+const TRACE_FILE_VERSION: u8 = 1;
+
+/// Size (in bytes) of the buffer through which trace values are written and read.
+#[cfg(feature = "std")]
+const TRACE_IO_BUFFER_SIZE: usize = 1 << 16;
+
+/// Maximum number of values preallocated for a column when reading a trace; the width and the
+/// length of a trace are read from an untrusted source, and thus, columns longer than this grow
+/// as their values are read.
+#[cfg(feature = "std")]
+const MAX_PREALLOCATED_COLUMN_LENGTH: usize = 1 << 20;
+
 // TRACE TABLE
 // ================================================================================================
 /// A concrete implementation of the [Trace] trait.
@@ -70,8 +94,8 @@ impl<B: StarkField> TraceTable<B> {
 
     /// Creates a new execution trace of the specified width and length.
     ///
-    /// This allocates all the required memory for the trace, but does not initialize it. It is
-    /// expected that the trace will be filled using one of the data mutator methods.
+    /// This allocates all the required memory for the trace and sets all of its values to zero. It
+    /// is expected that the trace will be filled using one of the data mutator methods.
     ///
     /// # Panics
     /// Panics if:
@@ -85,8 +109,8 @@ impl<B: StarkField> TraceTable<B> {
     /// Creates a new execution trace of the specified width and length, and with the specified
     /// metadata.
     ///
-    /// This allocates all the required memory for the trace, but does not initialize it. It is
-    /// expected that the trace will be filled using one of the data mutator methods.
+    /// This allocates all the required memory for the trace and sets all of its values to zero. It
+    /// is expected that the trace will be filled using one of the data mutator methods.
     ///
     /// # Panics
     /// Panics if:
@@ -103,7 +127,7 @@ impl<B: StarkField> TraceTable<B> {
             length.ilog2()
         );
 
-        let columns = unsafe { (0..width).map(|_| uninit_vector(length)).collect() };
+        let columns = (0..width).map(|_| vec![B::ZERO; length]).collect();
 
         Self { info, trace: ColMatrix::new(columns) }
     }
@@ -136,6 +160,25 @@ impl<B: StarkField> TraceTable<B> {
         Self { info, trace: ColMatrix::new(columns) }
     }
 
+    /// Creates a new execution trace with the specified `width` from trace rows provided in
+    /// row-major order, i.e., the first `width` elements of `rows` form the first row of the trace.
+    ///
+    /// The rows are converted into the columns of the trace using a cache-friendly transposition
+    /// (see [transpose()]).
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `width` is zero or greater than 255.
+    /// * The length of `rows` is not divisible by `width`.
+    /// * The number of rows is smaller than 8, greater than the biggest multiplicative subgroup in
+    ///   the field `B`, or is not a power of two.
+    pub fn from_rows(rows: &[B], width: usize) -> Self {
+        assert!(!rows.is_empty(), "execution trace must contain at least one row");
+        let columns = transpose(rows, width);
+        let length = columns.len() / width;
+        Self::init(columns.chunks(length).map(|column| column.to_vec()).collect())
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -196,7 +239,7 @@ impl<B: StarkField> TraceTable<B> {
     pub fn fragments(
         &mut self,
         fragment_length: usize,
-    ) -> alloc::vec::IntoIter<TraceTableFragment<B>> {
+    ) -> alloc::vec::IntoIter<TraceTableFragment<'_, B>> {
         self.build_fragments(fragment_length).into_iter()
     }
 
@@ -212,13 +255,13 @@ impl<B: StarkField> TraceTable<B> {
     pub fn fragments(
         &mut self,
         fragment_length: usize,
-    ) -> rayon::vec::IntoIter<TraceTableFragment<B>> {
+    ) -> rayon::vec::IntoIter<TraceTableFragment<'_, B>> {
         self.build_fragments(fragment_length).into_par_iter()
     }
 
     /// Returns a vector of trace fragments each covering the number of steps specified by the
     /// `fragment_length` parameter.
-    fn build_fragments(&mut self, fragment_length: usize) -> Vec<TraceTableFragment<B>> {
+    fn build_fragments(&mut self, fragment_length: usize) -> Vec<TraceTableFragment<'_, B>> {
         assert!(
             fragment_length >= MIN_FRAGMENT_LENGTH,
             "fragment length must be at least {MIN_FRAGMENT_LENGTH}, but was {fragment_length}"
@@ -272,6 +315,145 @@ impl<B: StarkField> TraceTable<B> {
     pub fn read_row_into(&self, step: usize, target: &mut [B]) {
         self.trace.read_row_into(step, target);
     }
+
+    /// Returns an immutable view of rows `start..end` of this execution trace.
+    ///
+    /// # Panics
+    /// Panics if `start` is greater than `end`, or if `end` is greater than the length of this
+    /// trace.
+    pub fn segment_view(&self, start: usize, end: usize) -> TraceSegmentView<'_, B> {
+        assert!(start <= end, "segment start {start} is greater than segment end {end}");
+        assert!(
+            end <= self.info.length(),
+            "segment end {end} is greater than trace length {}",
+            self.info.length()
+        );
+        TraceSegmentView::new(self, start, end)
+    }
+
+    // TRACE FILE I/O
+    // --------------------------------------------------------------------------------------------
+
+    /// Writes this execution trace into the specified `writer`.
+    ///
+    /// The trace is written as a header followed by the values of the trace in column-major
+    /// order. The header consists of:
+    /// * 4 magic bytes `WTRC` followed by a single byte of the format version.
+    /// * The field identifier: the number of bytes in the modulus of the base field followed by
+    ///   the little-endian bytes of the modulus.
+    /// * Width of the trace as `u32` and length of the trace as `u64`, both little-endian.
+    /// * Length of the trace metadata as little-endian `u32` followed by the metadata bytes.
+    ///
+    /// Each value is serialized in the canonical encoding of the base field. Values are written
+    /// through a small fixed-size buffer, and thus, the trace is never copied in its entirety.
+    ///
+    /// # Errors
+    /// Returns the first error returned by the `writer`; in this case, a prefix of the trace may
+    /// have already been written.
+    #[cfg(feature = "std")]
+    pub fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        // write the header
+        let modulus = B::get_modulus_le_bytes();
+        let meta = self.info.meta();
+        let mut header = Vec::with_capacity(32 + modulus.len() + meta.len());
+        header.extend_from_slice(&TRACE_FILE_MAGIC);
+        header.push(TRACE_FILE_VERSION);
+        header.push(modulus.len() as u8);
+        header.extend_from_slice(&modulus);
+        header.extend_from_slice(&(self.width() as u32).to_le_bytes());
+        header.extend_from_slice(&(self.info.length() as u64).to_le_bytes());
+        header.extend_from_slice(&(meta.len() as u32).to_le_bytes());
+        header.extend_from_slice(meta);
+        writer.write_all(&header)?;
+
+        // write the values one column at a time
+        let mut buffer = Vec::with_capacity(TRACE_IO_BUFFER_SIZE);
+        for column in self.trace.columns() {
+            for chunk in column.chunks(TRACE_IO_BUFFER_SIZE / B::ELEMENT_BYTES) {
+                buffer.clear();
+                buffer.write_many(chunk);
+                writer.write_all(&buffer)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads an execution trace written by [TraceTable::write_to()] from the specified `reader`.
+    ///
+    /// Columns of the trace are allocated once the header has been validated and are then filled
+    /// directly from the `reader`, and thus, the peak memory used while reading a trace is close
+    /// to the size of the trace itself. The `reader` is not read past the end of the trace.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Reading from the `reader` fails, or the `reader` is exhausted before the entire trace
+    ///   is read.
+    /// * The header of the trace is not valid, or was written using an unsupported version of
+    ///   the format.
+    /// * The trace was written for a base field other than `B`.
+    /// * Width, length, or metadata of the trace are not valid for an execution trace over `B`.
+    /// * Any of the values is not a valid field element.
+    #[cfg(feature = "std")]
+    pub fn read_from<R: std::io::Read>(reader: &mut R) -> Result<Self, TraceError> {
+        // read and validate the header
+        let mut preamble = [0; 6];
+        reader.read_exact(&mut preamble)?;
+        if preamble[..4] != TRACE_FILE_MAGIC {
+            return Err(TraceError::InvalidMagic);
+        }
+        if preamble[4] != TRACE_FILE_VERSION {
+            return Err(TraceError::UnsupportedVersion(preamble[4]));
+        }
+
+        let mut modulus = vec![0; preamble[5] as usize];
+        reader.read_exact(&mut modulus)?;
+        if modulus != B::get_modulus_le_bytes() {
+            return Err(TraceError::FieldMismatch);
+        }
+
+        let width = read_u32(reader)? as u64;
+        if width == 0 || width > TraceInfo::MAX_TRACE_WIDTH as u64 {
+            return Err(TraceError::InvalidWidth(width));
+        }
+        let length = read_u64(reader)?;
+        if length < TraceInfo::MIN_TRACE_LENGTH as u64
+            || !length.is_power_of_two()
+            || length.ilog2() > B::TWO_ADICITY
+            || usize::try_from(length).is_err()
+        {
+            return Err(TraceError::InvalidLength(length));
+        }
+        let meta_length = read_u32(reader)? as u64;
+        if meta_length > TraceInfo::MAX_META_LENGTH as u64 {
+            return Err(TraceError::InvalidMetaLength(meta_length));
+        }
+        let mut meta = vec![0; meta_length as usize];
+        reader.read_exact(&mut meta)?;
+
+        // read the values one column at a time
+        let (width, length) = (width as usize, length as usize);
+        let mut buffer = vec![0; TRACE_IO_BUFFER_SIZE / B::ELEMENT_BYTES * B::ELEMENT_BYTES];
+        let mut columns = Vec::with_capacity(width);
+        for _ in 0..width {
+            let mut column = Vec::with_capacity(cmp::min(length, MAX_PREALLOCATED_COLUMN_LENGTH));
+            while column.len() < length {
+                let chunk_len = cmp::min(length - column.len(), buffer.len() / B::ELEMENT_BYTES);
+                let bytes = &mut buffer[..chunk_len * B::ELEMENT_BYTES];
+                reader.read_exact(bytes)?;
+                for element_bytes in bytes.chunks_exact(B::ELEMENT_BYTES) {
+                    let value = B::try_from_le_bytes(element_bytes);
+                    column.push(value.map_err(TraceError::InvalidElement)?);
+                }
+            }
+            columns.push(column);
+        }
+
+        Ok(Self {
+            info: TraceInfo::with_meta(width, length, meta),
+            trace: ColMatrix::new(columns),
+        })
+    }
 }
 
 // TRACE TRAIT IMPLEMENTATION
@@ -285,9 +467,10 @@ impl<B: StarkField> Trace for TraceTable<B> {
     }
 
     fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>) {
-        let next_row_idx = (row_idx + 1) % self.info.length();
-        self.trace.read_row_into(row_idx, frame.current_mut());
-        self.trace.read_row_into(next_row_idx, frame.next_mut());
+        for offset in 0..frame.num_rows() {
+            let frame_row_idx = (row_idx + offset) % self.info.length();
+            self.trace.read_row_into(frame_row_idx, frame.row_mut(offset));
+        }
     }
 
     fn main_segment(&self) -> &ColMatrix<B> {
@@ -374,3 +557,20 @@ impl<B: StarkField> TraceTableFragment<'_, B> {
         }
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+#[cfg(feature = "std")]
+fn read_u32<R: std::io::Read>(reader: &mut R) -> std::io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+#[cfg(feature = "std")]
+fn read_u64<R: std::io::Read>(reader: &mut R) -> std::io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
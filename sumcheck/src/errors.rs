@@ -0,0 +1,87 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::fmt;
+
+use crypto::RandomCoinError;
+
+// PROVER ERROR
+// ================================================================================================
+
+/// Defines errors which can occur during sumcheck proof generation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProverError {
+    /// Attempt to draw a random value from a public coin failed.
+    RandomCoinError(RandomCoinError),
+    /// Sum of the polynomial over the boolean hypercube is not equal to the claimed sum.
+    InvalidClaimedSum,
+}
+
+impl fmt::Display for ProverError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RandomCoinError(err) => {
+                write!(f, "failed to draw a random value from the public coin: {err}")
+            }
+            Self::InvalidClaimedSum => {
+                write!(f, "sum of the polynomial over the boolean hypercube is not equal to the claimed sum")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ProverError {}
+
+// VERIFIER ERROR
+// ================================================================================================
+
+/// Defines errors which can occur during sumcheck proof verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifierError {
+    /// Attempt to draw a random value from a public coin failed.
+    RandomCoinError(RandomCoinError),
+    /// Number of round polynomials in the proof is not equal to the number of variables of the
+    /// polynomial.
+    InvalidNumRounds { expected: usize, actual: usize },
+    /// Round polynomial at the specified round has a degree greater than the maximum degree of
+    /// the polynomial in a single variable.
+    RoundPolyDegreeTooHigh {
+        round: usize,
+        max_degree: usize,
+        num_coefficients: usize,
+    },
+    /// Sum of the round polynomial over {0, 1} at the specified round is not equal to the claim
+    /// reduced from the previous round.
+    RoundSumMismatch(usize),
+    /// Evaluation of the polynomial at the random point drawn during the protocol is not equal to
+    /// the claim reduced from the last round.
+    FinalEvaluationMismatch,
+}
+
+impl fmt::Display for VerifierError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RandomCoinError(err) => {
+                write!(f, "failed to draw a random value from the public coin: {err}")
+            }
+            Self::InvalidNumRounds { expected, actual } => {
+                write!(f, "expected sumcheck proof with {expected} rounds, but was {actual}")
+            }
+            Self::RoundPolyDegreeTooHigh { round, max_degree, num_coefficients } => write!(f,
+                "round polynomial at round {round} must have at most {} coefficients, but had {num_coefficients}", max_degree + 1
+            ),
+            Self::RoundSumMismatch(round) => {
+                write!(f, "round polynomial at round {round} is not consistent with the claim from the previous round")
+            }
+            Self::FinalEvaluationMismatch => {
+                write!(f, "polynomial evaluation at the random point does not match the claim from the last round")
+            }
+        }
+    }
+}
+
+impl core::error::Error for VerifierError {}
@@ -0,0 +1,13 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Domain separation labels for data absorbed into the Fiat-Shamir transcript of a sumcheck
+//! proof.
+
+/// Label for the hash of the claimed sum.
+pub(crate) const CLAIMED_SUM: &[u8] = b"winterfell:sumcheck-claimed-sum:v1";
+
+/// Label for the hash of a round polynomial.
+pub(crate) const ROUND_POLY: &[u8] = b"winterfell:sumcheck-round-poly:v1";
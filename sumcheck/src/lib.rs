@@ -0,0 +1,63 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! This crate contains an implementation of the sumcheck protocol compiled into a
+//! non-interactive protocol via the Fiat-Shamir transform.
+//!
+//! The sumcheck protocol allows a prover to convince a verifier that the sum of a multivariate
+//! polynomial *f(x<sub>1</sub>, ..., x<sub>n</sub>)* over all points of the boolean hypercube
+//! *{0, 1}<sup>n</sup>* is equal to some claimed value *C*. The protocol proceeds in *n* rounds.
+//! In round *i*, the prover sends a univariate polynomial
+//!
+//! *g<sub>i</sub>(X) = Σ f(r<sub>1</sub>, ..., r<sub>i-1</sub>, X, x<sub>i+1</sub>, ...,
+//! x<sub>n</sub>)*
+//!
+//! where the sum is taken over all boolean values of *x<sub>i+1</sub>, ..., x<sub>n</sub>*, and
+//! *r<sub>1</sub>, ..., r<sub>i-1</sub>* are random values drawn in the previous rounds. The
+//! verifier checks that *g<sub>i</sub>(0) + g<sub>i</sub>(1)* is equal to *g<sub>i-1</sub>(r<sub>
+//! i-1</sub>)* (or to *C* in the first round), and draws the next random value *r<sub>i</sub>*.
+//! After the last round, the original claim is reduced to the claim that
+//! *f(r<sub>1</sub>, ..., r<sub>n</sub>) = g<sub>n</sub>(r<sub>n</sub>)*.
+//!
+//! # Proof generation
+//! Sumcheck proofs are generated by a [SumcheckProver]. The polynomial is provided to the prover
+//! via [prove()](SumcheckProver::prove()) function as a closure which evaluates the polynomial at
+//! an arbitrary point. Random values are drawn from a [RandomCoin](crypto::RandomCoin) which is
+//! reseeded with the claimed sum at the start of the protocol, and with a hash of each round
+//! polynomial as the round polynomial is generated.
+//!
+//! # Proof verification
+//! Sumcheck proofs are verified by a [SumcheckVerifier]. The verifier executes the rounds of the
+//! protocol against a public coin instantiated in the same way as the prover's coin, and can
+//! either check the final claim directly via [verify()](SumcheckVerifier::verify()) function, or
+//! return it via [reduce_claim()](SumcheckVerifier::reduce_claim()) function so that it could be
+//! checked by some other means (e.g., by opening a polynomial commitment).
+//!
+//! # References
+//! * Lund, Fortnow, Karloff, Nisan: [Algebraic Methods for Interactive Proof Systems](https://dl.acm.org/doi/10.1145/146585.146605)
+//! * Justin Thaler's [Proofs, Arguments, and Zero-Knowledge](https://people.cs.georgetown.edu/jthaler/ProofsArgsAndZK.pdf),
+//!   section 4.1
+
+#![no_std]
+
+#[macro_use]
+extern crate alloc;
+
+mod labels;
+
+mod prover;
+pub use prover::SumcheckProver;
+
+mod verifier;
+pub use verifier::{FinalClaim, SumcheckVerifier};
+
+mod proof;
+pub use proof::SumcheckProof;
+
+mod errors;
+pub use errors::{ProverError, VerifierError};
+
+#[cfg(test)]
+mod tests;
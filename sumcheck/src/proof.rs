@@ -0,0 +1,63 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::FieldElement;
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// SUMCHECK PROOF
+// ================================================================================================
+
+/// A proof generated by the sumcheck prover.
+///
+/// The proof consists of one univariate polynomial per round of the protocol (i.e., per variable
+/// of the polynomial being summed). Each polynomial is stored in coefficient form, starting with
+/// the constant term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SumcheckProof<E: FieldElement> {
+    round_polys: Vec<Vec<E>>,
+}
+
+impl<E: FieldElement> SumcheckProof<E> {
+    /// Returns a new [SumcheckProof] instantiated from the provided round polynomials.
+    pub fn new(round_polys: Vec<Vec<E>>) -> Self {
+        Self { round_polys }
+    }
+
+    /// Returns the number of rounds in this proof.
+    pub fn num_rounds(&self) -> usize {
+        self.round_polys.len()
+    }
+
+    /// Returns the coefficients of the round polynomials in the order in which they were
+    /// generated.
+    pub fn round_polys(&self) -> &[Vec<E>] {
+        &self.round_polys
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl<E: FieldElement> Serializable for SumcheckProof<E> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.round_polys.len());
+        for poly in self.round_polys.iter() {
+            poly.write_into(target);
+        }
+    }
+}
+
+impl<E: FieldElement> Deserializable for SumcheckProof<E> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_rounds = source.read_usize()?;
+        let mut round_polys = Vec::new();
+        for _ in 0..num_rounds {
+            round_polys.push(Vec::<E>::read_from(source)?);
+        }
+        Ok(Self { round_polys })
+    }
+}
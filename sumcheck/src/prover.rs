@@ -0,0 +1,176 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crypto::{ElementHasher, RandomCoin};
+use math::{polynom, FieldElement};
+
+use crate::{labels, FinalClaim, ProverError, SumcheckProof};
+
+// SUMCHECK PROVER
+// ================================================================================================
+
+/// Implements the prover component of the sumcheck protocol.
+///
+/// A prover is instantiated for polynomials with a given number of variables and a given maximum
+/// degree in each of the variables; the same prover can be used to generate proofs for any number
+/// of such polynomials. The polynomial itself is passed to [prove()](SumcheckProver::prove()) as
+/// a closure which evaluates the polynomial at the specified point.
+///
+/// The prover evaluates the polynomial `(max_degree + 1) * 2^num_vars` times in total, and thus,
+/// it is practical only for polynomials with a moderate number of variables.
+pub struct SumcheckProver<E: FieldElement> {
+    num_vars: usize,
+    max_degree: usize,
+    _field: PhantomData<E>,
+}
+
+impl<E: FieldElement> SumcheckProver<E> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new sumcheck prover for polynomials in `num_vars` variables with degree at most
+    /// `max_degree` in each of the variables.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `num_vars` is zero or is greater than or equal to the number of bits in `usize`.
+    /// * `max_degree` is zero.
+    pub fn new(num_vars: usize, max_degree: usize) -> Self {
+        assert!(num_vars > 0, "number of variables must be greater than zero");
+        assert!(
+            num_vars < usize::BITS as usize,
+            "number of variables must be smaller than {}, but was {}",
+            usize::BITS,
+            num_vars
+        );
+        assert!(max_degree > 0, "maximum degree must be greater than zero");
+        Self {
+            num_vars,
+            max_degree,
+            _field: PhantomData,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of variables of polynomials handled by this prover.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns the maximum degree in a single variable of polynomials handled by this prover.
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    // PROVING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a proof that the sum of `poly` over the boolean hypercube is equal to
+    /// `claimed_sum`.
+    ///
+    /// `poly` is invoked with slices of `num_vars` field elements. The public coin is reseeded
+    /// with `claimed_sum` before the first round, and is left in the state after the last round;
+    /// thus, the caller can keep drawing values from the coin to continue the protocol.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The sum of `poly` over the boolean hypercube is not equal to `claimed_sum`.
+    /// * A random value could not be drawn from the public coin.
+    pub fn prove<F, R>(
+        &self,
+        poly: F,
+        claimed_sum: E,
+        public_coin: &mut R,
+    ) -> Result<SumcheckProof<E>, ProverError>
+    where
+        F: Fn(&[E]) -> E,
+        R: RandomCoin<BaseField = E::BaseField>,
+    {
+        self.prove_and_reduce(poly, claimed_sum, public_coin).map(|(proof, _)| proof)
+    }
+
+    /// Returns a proof that the sum of `poly` over the boolean hypercube is equal to
+    /// `claimed_sum` together with the claim to which the verifier reduces the original claim.
+    ///
+    /// This is useful when the sumcheck protocol is a part of a larger protocol in which the
+    /// prover needs to know the random point drawn during the protocol; the returned claim is
+    /// the same as the one returned by [SumcheckVerifier::reduce_claim()](crate::SumcheckVerifier::reduce_claim())
+    /// for the generated proof.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The sum of `poly` over the boolean hypercube is not equal to `claimed_sum`.
+    /// * A random value could not be drawn from the public coin.
+    pub fn prove_and_reduce<F, R>(
+        &self,
+        poly: F,
+        claimed_sum: E,
+        public_coin: &mut R,
+    ) -> Result<(SumcheckProof<E>, FinalClaim<E>), ProverError>
+    where
+        F: Fn(&[E]) -> E,
+        R: RandomCoin<BaseField = E::BaseField>,
+    {
+        public_coin
+            .reseed_with_label(labels::CLAIMED_SUM, R::Hasher::hash_elements(&[claimed_sum]));
+
+        // round polynomials are interpolated from evaluations at 1, ..., max_degree + 1 rather
+        // than at 0, ..., max_degree because interpolation does not support zero X coordinates
+        let eval_points = (1..=self.max_degree as u32 + 1).map(E::from).collect::<Vec<_>>();
+        let mut point = vec![E::ZERO; self.num_vars];
+        let mut round_polys = Vec::with_capacity(self.num_vars);
+        let mut claim = claimed_sum;
+
+        for round in 0..self.num_vars {
+            let evaluations = eval_points
+                .iter()
+                .map(|&x| {
+                    point[round] = x;
+                    sum_over_hypercube(&poly, &mut point, round + 1)
+                })
+                .collect::<Vec<_>>();
+
+            let round_poly = polynom::interpolate(&eval_points, &evaluations, false);
+            if round == 0 && round_poly[0] + evaluations[0] != claimed_sum {
+                return Err(ProverError::InvalidClaimedSum);
+            }
+
+            public_coin
+                .reseed_with_label(labels::ROUND_POLY, R::Hasher::hash_elements(&round_poly));
+            point[round] = public_coin.draw().map_err(ProverError::RandomCoinError)?;
+            claim = polynom::eval(&round_poly, point[round]);
+            round_polys.push(round_poly);
+        }
+
+        let final_claim = FinalClaim { point, value: claim };
+        Ok((SumcheckProof::new(round_polys), final_claim))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the sum of `poly` over all boolean assignments to the variables of `point` starting
+/// at index `start`; the variables before `start` are left unchanged.
+fn sum_over_hypercube<E, F>(poly: &F, point: &mut [E], start: usize) -> E
+where
+    E: FieldElement,
+    F: Fn(&[E]) -> E,
+{
+    let num_free_vars = point.len() - start;
+    let mut result = E::ZERO;
+    for mask in 0..1usize << num_free_vars {
+        for (i, var) in point[start..].iter_mut().enumerate() {
+            *var = if (mask >> i) & 1 == 1 { E::ONE } else { E::ZERO };
+        }
+        result += poly(point);
+    }
+    result
+}
@@ -0,0 +1,211 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use crypto::{hashers::Blake3_256, DefaultRandomCoin, RandomCoin};
+use math::{
+    fields::{f128::BaseElement, QuadExtension},
+    FieldElement,
+};
+use rand_utils::rand_vector;
+use utils::{Deserializable, Serializable};
+
+use super::{
+    FinalClaim, ProverError, SumcheckProof, SumcheckProver, SumcheckVerifier, VerifierError,
+};
+
+type Blake3 = Blake3_256<BaseElement>;
+type Coin = DefaultRandomCoin<Blake3>;
+
+const NUM_VARS: usize = 5;
+
+// TEST POLYNOMIALS
+// ================================================================================================
+
+/// Returns a closure evaluating the multilinear extension of `values` at a given point.
+fn multilinear<E: FieldElement>(values: Vec<E>) -> impl Fn(&[E]) -> E {
+    move |point: &[E]| {
+        let mut evals = values.clone();
+        for &r in point.iter().rev() {
+            let half = evals.len() / 2;
+            for i in 0..half {
+                evals[i] = evals[2 * i] + r * (evals[2 * i + 1] - evals[2 * i]);
+            }
+            evals.truncate(half);
+        }
+        evals[0]
+    }
+}
+
+/// Returns a closure evaluating x_1 * x_2 * ... * x_n + x_1^3 + 7 at a given point; the degree
+/// of this polynomial in x_1 is 3.
+fn product_poly<E: FieldElement>() -> impl Fn(&[E]) -> E {
+    |point: &[E]| point.iter().fold(E::ONE, |acc, &x| acc * x) + point[0].cube() + E::from(7u32)
+}
+
+fn hypercube_sum<E: FieldElement>(poly: impl Fn(&[E]) -> E, num_vars: usize) -> E {
+    let mut result = E::ZERO;
+    for mask in 0..1usize << num_vars {
+        let point = (0..num_vars)
+            .map(|i| if (mask >> i) & 1 == 1 { E::ONE } else { E::ZERO })
+            .collect::<Vec<_>>();
+        result += poly(&point);
+    }
+    result
+}
+
+fn new_coin() -> Coin {
+    Coin::new(&[BaseElement::new(42)])
+}
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn sumcheck_multilinear() {
+    let values: Vec<BaseElement> = rand_vector(1 << NUM_VARS);
+    let claimed_sum = values.iter().fold(BaseElement::ZERO, |acc, &v| acc + v);
+
+    let prover = SumcheckProver::new(NUM_VARS, 1);
+    let proof = prover.prove(multilinear(values.clone()), claimed_sum, &mut new_coin()).unwrap();
+    assert_eq!(NUM_VARS, proof.num_rounds());
+
+    let verifier = SumcheckVerifier::new(NUM_VARS, 1);
+    let point = verifier
+        .verify(multilinear(values.clone()), claimed_sum, &proof, &mut new_coin())
+        .unwrap();
+    assert_eq!(NUM_VARS, point.len());
+
+    // the final claim is the evaluation of the polynomial at the returned point
+    let claim = verifier.reduce_claim(claimed_sum, &proof, &mut new_coin()).unwrap();
+    assert_eq!(
+        FinalClaim {
+            point: point.clone(),
+            value: multilinear(values.clone())(&point)
+        },
+        claim
+    );
+
+    // the prover arrives at the same claim as the verifier
+    let (prover_proof, prover_claim) = prover
+        .prove_and_reduce(multilinear(values), claimed_sum, &mut new_coin())
+        .unwrap();
+    assert_eq!(proof, prover_proof);
+    assert_eq!(claim, prover_claim);
+}
+
+#[test]
+fn sumcheck_higher_degree() {
+    let claimed_sum = hypercube_sum(product_poly::<BaseElement>(), NUM_VARS);
+
+    let prover = SumcheckProver::new(NUM_VARS, 3);
+    let proof = prover.prove(product_poly(), claimed_sum, &mut new_coin()).unwrap();
+    assert!(proof.round_polys().iter().all(|poly| poly.len() == 4));
+
+    let verifier = SumcheckVerifier::new(NUM_VARS, 3);
+    assert!(verifier.verify(product_poly(), claimed_sum, &proof, &mut new_coin()).is_ok());
+
+    // a verifier expecting a lower degree rejects the proof
+    let verifier = SumcheckVerifier::new(NUM_VARS, 2);
+    assert_eq!(
+        Err(VerifierError::RoundPolyDegreeTooHigh {
+            round: 0,
+            max_degree: 2,
+            num_coefficients: 4
+        }),
+        verifier.verify(product_poly(), claimed_sum, &proof, &mut new_coin())
+    );
+}
+
+#[test]
+fn sumcheck_extension_field() {
+    type QuadElement = QuadExtension<BaseElement>;
+    let values: Vec<QuadElement> = rand_vector(1 << NUM_VARS);
+    let claimed_sum = values.iter().fold(QuadElement::ZERO, |acc, &v| acc + v);
+
+    let prover = SumcheckProver::new(NUM_VARS, 1);
+    let proof = prover.prove(multilinear(values.clone()), claimed_sum, &mut new_coin()).unwrap();
+
+    let verifier = SumcheckVerifier::new(NUM_VARS, 1);
+    assert!(verifier
+        .verify(multilinear(values), claimed_sum, &proof, &mut new_coin())
+        .is_ok());
+}
+
+#[test]
+fn sumcheck_invalid_claimed_sum() {
+    let values: Vec<BaseElement> = rand_vector(1 << NUM_VARS);
+    let claimed_sum = values.iter().fold(BaseElement::ONE, |acc, &v| acc + v);
+
+    let prover = SumcheckProver::new(NUM_VARS, 1);
+    assert_eq!(
+        Err(ProverError::InvalidClaimedSum),
+        prover.prove(multilinear(values.clone()), claimed_sum, &mut new_coin())
+    );
+
+    // a proof for the correct sum does not verify against an incorrect sum
+    let proof = prover
+        .prove(multilinear(values.clone()), claimed_sum - BaseElement::ONE, &mut new_coin())
+        .unwrap();
+    let verifier = SumcheckVerifier::new(NUM_VARS, 1);
+    assert_eq!(
+        Err(VerifierError::RoundSumMismatch(0)),
+        verifier.verify(multilinear(values), claimed_sum, &proof, &mut new_coin())
+    );
+}
+
+#[test]
+fn sumcheck_invalid_proofs() {
+    let values: Vec<BaseElement> = rand_vector(1 << NUM_VARS);
+    let claimed_sum = values.iter().fold(BaseElement::ZERO, |acc, &v| acc + v);
+    let prover = SumcheckProver::new(NUM_VARS, 1);
+    let proof = prover.prove(multilinear(values.clone()), claimed_sum, &mut new_coin()).unwrap();
+    let verifier = SumcheckVerifier::new(NUM_VARS, 1);
+
+    // tampering with a round polynomial breaks consistency with the previous round
+    let mut round_polys = proof.round_polys().to_vec();
+    round_polys[2][1] += BaseElement::ONE;
+    let tampered = SumcheckProof::new(round_polys);
+    assert_eq!(
+        Err(VerifierError::RoundSumMismatch(2)),
+        verifier.verify(multilinear(values.clone()), claimed_sum, &tampered, &mut new_coin())
+    );
+
+    // a proof must contain one round polynomial per variable
+    let truncated = SumcheckProof::new(proof.round_polys()[1..].to_vec());
+    assert_eq!(
+        Err(VerifierError::InvalidNumRounds { expected: NUM_VARS, actual: NUM_VARS - 1 }),
+        verifier.verify(multilinear(values.clone()), claimed_sum, &truncated, &mut new_coin())
+    );
+
+    // the proof does not verify for a different polynomial with the same sum
+    let mut other_values = values.clone();
+    other_values[0] += BaseElement::ONE;
+    other_values[1] -= BaseElement::ONE;
+    assert_eq!(
+        Err(VerifierError::FinalEvaluationMismatch),
+        verifier.verify(multilinear(other_values), claimed_sum, &proof, &mut new_coin())
+    );
+
+    // with a public coin seeded differently, the verifier draws different challenges, and so
+    // the second round polynomial is not consistent with the first one
+    let mut other_coin = Coin::new(&[BaseElement::new(43)]);
+    assert_eq!(
+        Err(VerifierError::RoundSumMismatch(1)),
+        verifier.verify(multilinear(values), claimed_sum, &proof, &mut other_coin)
+    );
+}
+
+#[test]
+fn sumcheck_proof_serialization() {
+    let claimed_sum = hypercube_sum(product_poly::<BaseElement>(), NUM_VARS);
+    let prover = SumcheckProver::new(NUM_VARS, 3);
+    let proof = prover.prove(product_poly(), claimed_sum, &mut new_coin()).unwrap();
+
+    let bytes = proof.to_bytes();
+    assert_eq!(proof, SumcheckProof::read_from_bytes(&bytes).unwrap());
+    assert!(SumcheckProof::<BaseElement>::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
@@ -0,0 +1,156 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crypto::{ElementHasher, RandomCoin};
+use math::{polynom, FieldElement};
+
+use crate::{labels, SumcheckProof, VerifierError};
+
+// SUMCHECK VERIFIER
+// ================================================================================================
+
+/// Implements the verifier component of the sumcheck protocol.
+///
+/// The verifier checks the round polynomials of a [SumcheckProof] and reduces the claim about the
+/// sum of a polynomial over the boolean hypercube to a claim about the evaluation of the
+/// polynomial at a single random point. The public coin used by the verifier must be in the same
+/// state as the coin used by the prover at the start of proof generation.
+pub struct SumcheckVerifier<E: FieldElement> {
+    num_vars: usize,
+    max_degree: usize,
+    _field: PhantomData<E>,
+}
+
+impl<E: FieldElement> SumcheckVerifier<E> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new sumcheck verifier for polynomials in `num_vars` variables with degree at
+    /// most `max_degree` in each of the variables.
+    ///
+    /// # Panics
+    /// Panics if either `num_vars` or `max_degree` is zero.
+    pub fn new(num_vars: usize, max_degree: usize) -> Self {
+        assert!(num_vars > 0, "number of variables must be greater than zero");
+        assert!(max_degree > 0, "maximum degree must be greater than zero");
+        Self {
+            num_vars,
+            max_degree,
+            _field: PhantomData,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of variables of polynomials handled by this verifier.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns the maximum degree in a single variable of polynomials handled by this verifier.
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that the sum of `poly` over the boolean hypercube is equal to `claimed_sum`, and
+    /// returns the random point at which the polynomial was evaluated at the end of the protocol.
+    ///
+    /// # Errors
+    /// Returns an error if the proof is not valid for the specified polynomial and claimed sum.
+    pub fn verify<F, R>(
+        &self,
+        poly: F,
+        claimed_sum: E,
+        proof: &SumcheckProof<E>,
+        public_coin: &mut R,
+    ) -> Result<Vec<E>, VerifierError>
+    where
+        F: Fn(&[E]) -> E,
+        R: RandomCoin<BaseField = E::BaseField>,
+    {
+        let claim = self.reduce_claim(claimed_sum, proof, public_coin)?;
+        if poly(&claim.point) != claim.value {
+            return Err(VerifierError::FinalEvaluationMismatch);
+        }
+        Ok(claim.point)
+    }
+
+    /// Checks the round polynomials of `proof` against `claimed_sum` and returns the claim about
+    /// the evaluation of the polynomial at a random point to which the original claim reduces.
+    ///
+    /// The proof is valid only if the returned claim holds; checking it is left to the caller.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The number of rounds in the proof is not equal to the number of variables.
+    /// * Any of the round polynomials has more than `max_degree + 1` coefficients.
+    /// * Any of the round polynomials is not consistent with the claim from the previous round.
+    /// * A random value could not be drawn from the public coin.
+    pub fn reduce_claim<R>(
+        &self,
+        claimed_sum: E,
+        proof: &SumcheckProof<E>,
+        public_coin: &mut R,
+    ) -> Result<FinalClaim<E>, VerifierError>
+    where
+        R: RandomCoin<BaseField = E::BaseField>,
+    {
+        if proof.num_rounds() != self.num_vars {
+            return Err(VerifierError::InvalidNumRounds {
+                expected: self.num_vars,
+                actual: proof.num_rounds(),
+            });
+        }
+
+        public_coin
+            .reseed_with_label(labels::CLAIMED_SUM, R::Hasher::hash_elements(&[claimed_sum]));
+
+        let mut claim = claimed_sum;
+        let mut point = Vec::with_capacity(self.num_vars);
+        for (round, round_poly) in proof.round_polys().iter().enumerate() {
+            if round_poly.len() > self.max_degree + 1 {
+                return Err(VerifierError::RoundPolyDegreeTooHigh {
+                    round,
+                    max_degree: self.max_degree,
+                    num_coefficients: round_poly.len(),
+                });
+            }
+
+            // g(0) is the constant term and g(1) is the sum of all coefficients
+            let at_zero = round_poly.first().copied().unwrap_or(E::ZERO);
+            let at_one = round_poly.iter().fold(E::ZERO, |acc, &c| acc + c);
+            if at_zero + at_one != claim {
+                return Err(VerifierError::RoundSumMismatch(round));
+            }
+
+            public_coin.reseed_with_label(labels::ROUND_POLY, R::Hasher::hash_elements(round_poly));
+            let r: E = public_coin.draw().map_err(VerifierError::RandomCoinError)?;
+            claim = polynom::eval(round_poly, r);
+            point.push(r);
+        }
+
+        Ok(FinalClaim { point, value: claim })
+    }
+}
+
+// FINAL CLAIM
+// ================================================================================================
+
+/// A claim that a polynomial evaluates to `value` at `point`, to which the sumcheck protocol
+/// reduces the claim about the sum of the polynomial over the boolean hypercube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinalClaim<E: FieldElement> {
+    /// Random point drawn during the protocol; this point has one coordinate per variable.
+    pub point: Vec<E>,
+    /// Expected evaluation of the polynomial at `point`.
+    pub value: E,
+}
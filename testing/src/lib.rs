@@ -0,0 +1,177 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! This crate contains utilities for testing AIRs of computations proven with Winterfell.
+//!
+//! Testing a new AIR usually involves the same steps: generating a valid execution trace for
+//! some public inputs, making sure that a proof generated from this trace is accepted by the
+//! verifier (completeness), and making sure that proofs generated from invalid traces are rejected
+//! by the verifier (soundness). This crate implements these steps generically for any AIR which
+//! implements the [TestableAir] trait:
+//!
+//! * [gen_satisfying_trace()] generates an execution trace using the AIR-provided trace generator
+//!   and checks that the trace satisfies all constraints of the AIR.
+//! * [completeness_test()] generates a proof for a satisfying trace and verifies it.
+//! * [soundness_test()] corrupts random cells of satisfying traces and checks that proofs for
+//!   the corrupted traces are rejected.
+//!
+//! All functions of this crate panic when a check fails, and thus, are intended to be called
+//! from tests.
+
+use rand::RngCore;
+use winterfell::{
+    math::ToElements, validate_trace, verify, AcceptableOptions, Air, Prover, Trace, TraceTable,
+};
+
+#[cfg(test)]
+mod tests;
+
+// TESTABLE AIR
+// ================================================================================================
+
+/// Defines how execution traces and proofs are generated for an AIR under test.
+pub trait TestableAir: Air {
+    /// Prover used to generate proofs for this AIR.
+    type Prover: Prover<
+        Air = Self,
+        BaseField = Self::BaseField,
+        Trace = TraceTable<Self::BaseField>,
+    >;
+
+    /// Returns an execution trace of the computation described by this AIR for the specified
+    /// public inputs.
+    ///
+    /// The trace must be of the same shape as described by the trace info of this AIR, and must
+    /// satisfy all constraints of this AIR. Any values which are not determined by the public
+    /// inputs (e.g., secret inputs of the computation) can be drawn from `rng`.
+    fn generate_trace(
+        &self,
+        pub_inputs: &Self::PublicInputs,
+        rng: &mut dyn RngCore,
+    ) -> TraceTable<Self::BaseField>;
+
+    /// Returns a prover which generates proofs for this AIR using the proof options of this AIR.
+    fn new_prover(&self) -> Self::Prover;
+}
+
+// TEST UTILITIES
+// ================================================================================================
+
+/// Returns an execution trace generated by the specified `air` for the specified public inputs.
+///
+/// # Panics
+/// Panics if the generated trace does not match the trace info of the `air`, or if the trace
+/// does not satisfy all constraints of the `air`.
+pub fn gen_satisfying_trace<A: TestableAir>(
+    air: &A,
+    pub_inputs: &A::PublicInputs,
+    rng: &mut dyn RngCore,
+) -> TraceTable<A::BaseField> {
+    let trace = air.generate_trace(pub_inputs, rng);
+    assert_eq!(
+        air.trace_info().main_trace_width(),
+        trace.main_trace_width(),
+        "generated trace has an unexpected number of columns"
+    );
+    assert_eq!(air.trace_length(), trace.length(), "generated trace has an unexpected length");
+    if let Err(err) = validate_trace(&trace, air) {
+        panic!("generated trace does not satisfy the AIR: {err}");
+    }
+    trace
+}
+
+/// Generates a proof for a satisfying execution trace of the specified `air` and checks that the
+/// proof is accepted by the verifier against the specified public inputs.
+///
+/// Values of the trace which are not determined by the public inputs are drawn from `rng`.
+///
+/// # Panics
+/// Panics if a satisfying trace could not be generated (see [gen_satisfying_trace()]), if the
+/// public inputs derived from the trace by the prover are not equal to `pub_inputs`, if the
+/// prover fails to generate a proof, or if the verifier rejects the proof.
+pub fn completeness_test<A>(air: &A, pub_inputs: &A::PublicInputs, rng: &mut impl RngCore)
+where
+    A: TestableAir,
+    A::PublicInputs: Clone + Send,
+    A::GkrProof: Send,
+{
+    let trace = gen_satisfying_trace(air, pub_inputs, rng);
+    let prover = air.new_prover();
+    assert_eq!(
+        pub_inputs.to_elements(),
+        prover.get_pub_inputs(&trace).to_elements(),
+        "public inputs derived from the trace by the prover do not match the expected inputs"
+    );
+
+    let proof = match prover.prove(trace) {
+        Ok(proof) => proof,
+        Err(err) => panic!("failed to generate a proof for a satisfying trace: {err}"),
+    };
+    if let Err(err) = verify_proof::<A>(proof, pub_inputs.clone()) {
+        panic!("verifier rejected a proof for a satisfying trace: {err}");
+    }
+}
+
+/// Checks that corrupting a single cell of a satisfying execution trace of the specified `air`
+/// results in a proof which is rejected by the verifier against the specified public inputs.
+///
+/// The check is repeated `num_corruptions` times; each time, a new trace is generated and a
+/// random cell of the trace is changed to a different random value. If the prover refuses to
+/// generate a proof for the corrupted trace (e.g., because the prover validates traces against
+/// the AIR in debug mode), the corrupted trace is considered rejected. Both the traces and the
+/// corrupted cells are drawn from `rng`.
+///
+/// # Panics
+/// Panics if a satisfying trace could not be generated (see [gen_satisfying_trace()]), or if the
+/// verifier accepts a proof for any of the corrupted traces. The latter usually means that some
+/// cells of the trace are not constrained by the AIR.
+pub fn soundness_test<A>(
+    air: &A,
+    pub_inputs: &A::PublicInputs,
+    num_corruptions: usize,
+    rng: &mut impl RngCore,
+) where
+    A: TestableAir,
+    A::PublicInputs: Clone + Send,
+    A::GkrProof: Send,
+{
+    let prover = air.new_prover();
+    for _ in 0..num_corruptions {
+        let mut trace = gen_satisfying_trace(air, pub_inputs, rng);
+        let column = rng.next_u32() as usize % trace.main_trace_width();
+        let step = rng.next_u32() as usize % trace.length();
+
+        // the delta is a non-zero integer smaller than the field modulus, and thus, the value of
+        // the cell is guaranteed to change
+        let delta = A::BaseField::from(rng.next_u32().max(1));
+        trace.set(column, step, trace.get(column, step) + delta);
+
+        if let Ok(proof) = prover.prove(trace) {
+            assert!(
+                verify_proof::<A>(proof, pub_inputs.clone()).is_err(),
+                "verifier accepted a proof for a trace with a corrupted cell at column {column}, \
+                step {step}"
+            );
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Verifies the specified proof using the hash function, random coin, and vector commitment
+/// scheme of the prover for the AIR.
+fn verify_proof<A: TestableAir>(
+    proof: winterfell::Proof,
+    pub_inputs: A::PublicInputs,
+) -> Result<(), winterfell::VerifierError> {
+    verify::<
+        A,
+        <A::Prover as Prover>::HashFn,
+        <A::Prover as Prover>::RandomCoin,
+        <A::Prover as Prover>::VC,
+        <A::Prover as Prover>::ProofOfWork,
+    >(proof, pub_inputs, &AcceptableOptions::MinConjecturedSecurity(0))
+}
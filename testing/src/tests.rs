@@ -0,0 +1,221 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::marker::PhantomData;
+
+use winterfell::{
+    crypto::{hashers::Blake3_256, DefaultRandomCoin, HashPow, MerkleTree},
+    math::{fields::f128::BaseElement, FieldElement},
+    matrix::ColMatrix,
+    AirContext, Assertion, AuxRandElements, CompositionPoly, CompositionPolyTrace,
+    ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
+    DefaultTraceLde, EvaluationFrame, FieldExtension, PartitionOptions, ProofOptions, StarkDomain,
+    TraceInfo, TracePolyTable, TransitionConstraintDegree,
+};
+
+use super::*;
+
+const TRACE_LENGTH: usize = 16;
+
+#[test]
+fn completeness() {
+    let air = FibAir::<false>::with_result(fib_result(TRACE_LENGTH));
+    completeness_test(&air, &fib_result(TRACE_LENGTH), &mut rand::thread_rng());
+}
+
+#[test]
+fn soundness() {
+    let air = FibAir::<false>::with_result(fib_result(TRACE_LENGTH));
+    soundness_test(&air, &fib_result(TRACE_LENGTH), 8, &mut rand::thread_rng());
+}
+
+#[test]
+#[should_panic(expected = "verifier accepted a proof for a trace with a corrupted cell")]
+fn soundness_unconstrained_column() {
+    // the last column of the trace is not constrained by the AIR, and thus, corrupting it results
+    // in a valid proof; the probability that none of the corruptions hits this column is
+    // (2/3)^64 < 2^-37
+    let air = FibAir::<true>::with_result(fib_result(TRACE_LENGTH));
+    soundness_test(&air, &fib_result(TRACE_LENGTH), 64, &mut rand::thread_rng());
+}
+
+#[test]
+#[should_panic(expected = "generated trace does not satisfy the AIR")]
+fn unsatisfying_trace() {
+    let wrong_result = fib_result(TRACE_LENGTH) + BaseElement::ONE;
+    let air = FibAir::<false>::with_result(wrong_result);
+    gen_satisfying_trace(&air, &wrong_result, &mut rand::thread_rng());
+}
+
+// FIBONACCI AIR
+// ================================================================================================
+
+/// Returns the second column of the last row of a Fibonacci trace of the specified length.
+fn fib_result(trace_length: usize) -> BaseElement {
+    let mut state = [BaseElement::ONE, BaseElement::ONE];
+    for _ in 1..trace_length {
+        state[0] += state[1];
+        state[1] += state[0];
+    }
+    state[1]
+}
+
+/// AIR of a Fibonacci sequence computed two terms per step; if `WITH_UNCONSTRAINED_COLUMN` is
+/// true, the trace contains an extra column which is not constrained by the AIR.
+struct FibAir<const WITH_UNCONSTRAINED_COLUMN: bool> {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl<const WITH_UNCONSTRAINED_COLUMN: bool> FibAir<WITH_UNCONSTRAINED_COLUMN> {
+    const TRACE_WIDTH: usize = if WITH_UNCONSTRAINED_COLUMN { 3 } else { 2 };
+
+    fn with_result(result: BaseElement) -> Self {
+        let trace_info = TraceInfo::new(Self::TRACE_WIDTH, TRACE_LENGTH);
+        let options = ProofOptions::new(28, 8, 0, FieldExtension::None, 4, 7);
+        Self::new(trace_info, result, options)
+    }
+}
+
+impl<const WITH_UNCONSTRAINED_COLUMN: bool> Air for FibAir<WITH_UNCONSTRAINED_COLUMN> {
+    type BaseField = BaseElement;
+    type PublicInputs = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    fn new(trace_info: TraceInfo, pub_inputs: BaseElement, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(1)];
+        Self {
+            context: AirContext::new(trace_info, degrees, 3, options),
+            result: pub_inputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        result[0] = next[0] - (current[0] + current[1]);
+        result[1] = next[1] - (current[1] + next[0]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::single(1, 0, BaseElement::ONE),
+            Assertion::single(1, last_step, self.result),
+        ]
+    }
+}
+
+impl<const WITH_UNCONSTRAINED_COLUMN: bool> TestableAir for FibAir<WITH_UNCONSTRAINED_COLUMN> {
+    type Prover = FibProver<WITH_UNCONSTRAINED_COLUMN>;
+
+    fn generate_trace(
+        &self,
+        _pub_inputs: &BaseElement,
+        rng: &mut dyn RngCore,
+    ) -> TraceTable<BaseElement> {
+        let mut trace = TraceTable::new(Self::TRACE_WIDTH, self.trace_length());
+        trace.fill(
+            |state| {
+                state[0] = BaseElement::ONE;
+                state[1] = BaseElement::ONE;
+            },
+            |_, state| {
+                state[0] += state[1];
+                state[1] += state[0];
+            },
+        );
+        if WITH_UNCONSTRAINED_COLUMN {
+            for step in 0..self.trace_length() {
+                trace.set(2, step, BaseElement::from(rng.next_u32()));
+            }
+        }
+        trace
+    }
+
+    fn new_prover(&self) -> Self::Prover {
+        FibProver {
+            options: self.options().clone(),
+            _air: PhantomData,
+        }
+    }
+}
+
+// FIBONACCI PROVER
+// ================================================================================================
+
+struct FibProver<const WITH_UNCONSTRAINED_COLUMN: bool> {
+    options: ProofOptions,
+    _air: PhantomData<FibAir<WITH_UNCONSTRAINED_COLUMN>>,
+}
+
+impl<const WITH_UNCONSTRAINED_COLUMN: bool> Prover for FibProver<WITH_UNCONSTRAINED_COLUMN> {
+    type BaseField = BaseElement;
+    type Air = FibAir<WITH_UNCONSTRAINED_COLUMN>;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = Blake3_256<BaseElement>;
+    type VC = MerkleTree<Self::HashFn>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type ProofOfWork = HashPow;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, Self::HashFn, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+        trace.get(1, trace.length() - 1)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+}
@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::string::String;
+use alloc::{boxed::Box, string::String};
 use core::fmt;
 
 // DESERIALIZATION ERROR
@@ -20,6 +20,46 @@ pub enum DeserializationError {
     UnconsumedBytes,
     /// An unknown error has occurred.
     UnknownError(String),
+    /// The `source` error occurred at the specified byte `offset` while reading `context`.
+    AtOffset {
+        offset: usize,
+        context: String,
+        source: Box<DeserializationError>,
+    },
+}
+
+impl DeserializationError {
+    /// Returns an [AtOffset](DeserializationError::AtOffset) error which records that this error
+    /// occurred at the specified byte `offset` while reading `context`.
+    ///
+    /// For example, an unexpected EOF error at offset 1432 while reading "FRI layer 3" would be
+    /// described as "unexpected EOF at offset 1432 while reading FRI layer 3".
+    pub fn at_offset(self, offset: usize, context: &str) -> Self {
+        Self::AtOffset {
+            offset,
+            context: context.into(),
+            source: Box::new(self),
+        }
+    }
+
+    /// Returns the underlying error with all offset information stripped.
+    ///
+    /// This makes it possible to match on the kind of the error regardless of whether it was
+    /// annotated via [at_offset()](DeserializationError::at_offset).
+    pub fn kind(&self) -> &Self {
+        match self {
+            Self::AtOffset { source, .. } => source.kind(),
+            _ => self,
+        }
+    }
+
+    /// Returns the byte offset at which this error occurred, if known.
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Self::AtOffset { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for DeserializationError {
@@ -29,8 +69,18 @@ impl fmt::Display for DeserializationError {
             Self::UnexpectedEOF => write!(f, "unexpected EOF"),
             Self::UnconsumedBytes => write!(f, "not all bytes were consumed"),
             Self::UnknownError(err_msg) => write!(f, "unknown error: {err_msg}"),
+            Self::AtOffset { offset, context, source } => {
+                write!(f, "{source} at offset {offset} while reading {context}")
+            },
         }
     }
 }
 
-impl core::error::Error for DeserializationError {}
+impl core::error::Error for DeserializationError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::AtOffset { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
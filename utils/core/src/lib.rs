@@ -16,12 +16,14 @@ extern crate std;
 pub mod iterators;
 
 use alloc::vec::Vec;
-use core::{mem, slice};
+use core::{cmp, mem, mem::MaybeUninit, slice};
 
 mod serde;
 #[cfg(feature = "std")]
 pub use serde::ReadAdapter;
-pub use serde::{ByteReader, ByteWriter, Deserializable, Serializable, SliceReader};
+pub use serde::{
+    ByteReader, ByteWriter, Deserializable, PositionedReader, Serializable, SliceReader,
+};
 
 mod errors;
 pub use errors::DeserializationError;
@@ -29,6 +31,26 @@ pub use errors::DeserializationError;
 #[cfg(test)]
 mod tests;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Size of a cache line (in bytes) assumed when transposing matrices.
+const CACHE_LINE_BYTES: usize = 64;
+
+/// Number of cache lines spanned by a row of a tile when a matrix is transposed into a new
+/// vector.
+const TRANSPOSE_TILE_LINES: usize = 4;
+
+/// Number of cache lines spanned by a row of a tile when a matrix is transposed in place; swapped
+/// tiles of power-of-two sized matrices map onto the same cache sets, and thus, smaller tiles
+/// work better.
+const TRANSPOSE_IN_PLACE_TILE_LINES: usize = 1;
+
+/// Number of elements in a matrix below which the matrix is transposed in a single thread, even
+/// when `concurrent` feature is enabled.
+#[cfg(feature = "concurrent")]
+const MIN_CONCURRENT_TRANSPOSE_SIZE: usize = 1 << 16;
+
 // FEATURE-BASED RE-EXPORTS
 // ================================================================================================
 
@@ -70,29 +92,71 @@ impl<const N: usize> AsBytes for [[u8; N]] {
 // VECTOR FUNCTIONS
 // ================================================================================================
 
-/// Returns a vector of the specified length with un-initialized memory.
+/// Returns a vector of the specified length with uninitialized elements.
 ///
 /// This is usually faster than requesting a vector with initialized memory and is useful when we
-/// overwrite all contents of the vector immediately after memory allocation.
+/// overwrite all contents of the vector immediately after memory allocation. Once every element
+/// has been written, the vector can be converted into a vector of initialized elements via
+/// [assume_init_vector()].
 ///
-/// # Safety
-/// Using values from the returned vector before initializing them will lead to undefined behavior.
-#[allow(clippy::uninit_vec)]
-pub unsafe fn uninit_vector<T>(length: usize) -> Vec<T> {
+/// # Example
+/// ```
+/// # use winter_utils::{assume_init_vector, uninit_vector};
+/// let mut v = uninit_vector::<u32>(4);
+/// for (i, e) in v.iter_mut().enumerate() {
+///     e.write(i as u32);
+/// }
+///
+/// // SAFETY: all elements of `v` have been initialized above
+/// let v = unsafe { assume_init_vector(v) };
+/// assert_eq!(vec![0, 1, 2, 3], v);
+/// ```
+pub fn uninit_vector<T>(length: usize) -> Vec<MaybeUninit<T>> {
     let mut vector = Vec::with_capacity(length);
-    vector.set_len(length);
+    // SAFETY: the capacity of the vector is `length`, and `MaybeUninit` elements do not need to
+    // be initialized
+    unsafe { vector.set_len(length) };
     vector
 }
 
+/// Converts a vector of [MaybeUninit] elements into a vector of initialized elements.
+///
+/// This function just re-interprets the underlying memory and is thus zero-copy.
+///
+/// # Safety
+/// All elements of the vector must be initialized; otherwise, the behavior is undefined.
+pub unsafe fn assume_init_vector<T>(vector: Vec<MaybeUninit<T>>) -> Vec<T> {
+    let mut vector = mem::ManuallyDrop::new(vector);
+    let (ptr, len, capacity) = (vector.as_mut_ptr(), vector.len(), vector.capacity());
+    // SAFETY: `MaybeUninit<T>` has the same size and alignment as `T`, and the caller guarantees
+    // that all elements are initialized
+    Vec::from_raw_parts(ptr as *mut T, len, capacity)
+}
+
+/// Converts a mutable slice of [MaybeUninit] elements into a mutable slice of initialized
+/// elements.
+///
+/// This function just re-interprets the underlying memory and is thus zero-copy.
+///
+/// # Safety
+/// All elements of the slice must be initialized; otherwise, the behavior is undefined.
+pub unsafe fn assume_init_slice_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+    // SAFETY: `MaybeUninit<T>` has the same size and alignment as `T`, and the caller guarantees
+    // that all elements are initialized
+    slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut T, slice.len())
+}
+
 // GROUPING / UN-GROUPING FUNCTIONS
 // ================================================================================================
 
 /// Transmutes a slice of `n` elements into a slice of `n` / `N` elements, each of which is
 /// an array of `N` elements.
 ///
-/// This function just re-interprets the underlying memory and is thus zero-copy.
+/// This function just re-interprets the underlying memory and is thus zero-copy. This is always
+/// possible because an array `[T; N]` has the same alignment as `T`.
+///
 /// # Panics
-/// Panics if `n` is not divisible by `N`.
+/// Panics if `N` is zero or `n` is not divisible by `N`.
 ///
 /// # Example
 /// ```
@@ -103,12 +167,60 @@ pub unsafe fn uninit_vector<T>(length: usize) -> Vec<T> {
 /// assert_eq!(&[[0, 1], [2, 3], [4, 5], [6, 7]], b);
 /// ```
 pub fn group_slice_elements<T, const N: usize>(source: &[T]) -> &[[T; N]] {
+    assert!(N > 0, "group size must be greater than zero");
     assert_eq!(source.len() % N, 0, "source length must be divisible by {N}");
     let p = source.as_ptr();
     let len = source.len() / N;
+    // SAFETY: `[T; N]` has the same alignment as `T`, and `len` arrays of `N` elements span
+    // exactly the elements of `source`
     unsafe { slice::from_raw_parts(p as *const [T; N], len) }
 }
 
+/// Transmutes a vector of `n` elements into a vector of `n` / `N` elements, each of which is
+/// an array of `N` elements.
+///
+/// If the capacity of the vector is divisible by `N`, this function just re-interprets the
+/// underlying memory and is thus zero-copy; otherwise, the elements are moved into a newly
+/// allocated vector. This is the inverse of [flatten_vector_elements()].
+///
+/// # Panics
+/// Panics if `N` is zero or `n` is not divisible by `N`.
+///
+/// # Example
+/// ```
+/// # use winter_utils::group_vector_elements;
+/// let a = vec![0_u32, 1, 2, 3, 4, 5, 6, 7];
+/// let b: Vec<[u32; 4]> = group_vector_elements(a);
+///
+/// assert_eq!(vec![[0, 1, 2, 3], [4, 5, 6, 7]], b);
+/// ```
+pub fn group_vector_elements<T, const N: usize>(source: Vec<T>) -> Vec<[T; N]> {
+    assert!(N > 0, "group size must be greater than zero");
+    assert_eq!(
+        source.len() % N,
+        0,
+        "source length must be divisible by {}, but was {}",
+        N,
+        source.len()
+    );
+
+    let len = source.len() / N;
+    if source.capacity() % N != 0 {
+        let mut elements = source.into_iter();
+        return (0..len)
+            .map(|_| core::array::from_fn(|_| elements.next().expect("missing source element")))
+            .collect();
+    }
+
+    let mut v = mem::ManuallyDrop::new(source);
+    let p = v.as_mut_ptr();
+    let cap = v.capacity() / N;
+    // SAFETY: `[T; N]` has the same alignment as `T` and its size is `N` times the size of `T`;
+    // because the capacity is divisible by `N`, the allocation has the same layout for both
+    // element types
+    unsafe { Vec::from_raw_parts(p as *mut [T; N], len, cap) }
+}
+
 /// Transmutes a slice of `n` arrays each of length `N`, into a slice of `N` * `n` elements.
 ///
 /// This function just re-interprets the underlying memory and is thus zero-copy.
@@ -128,7 +240,8 @@ pub fn flatten_slice_elements<T, const N: usize>(source: &[[T; N]]) -> &[T] {
 
 /// Transmutes a vector of `n` arrays each of length `N`, into a vector of `N` * `n` elements.
 ///
-/// This function just re-interprets the underlying memory and is thus zero-copy.
+/// This function just re-interprets the underlying memory and is thus zero-copy. This is the
+/// inverse of [group_vector_elements()].
 /// # Example
 /// ```
 /// # use winter_utils::flatten_vector_elements;
@@ -173,15 +286,168 @@ pub fn transpose_slice<T: Copy + Send + Sync, const N: usize>(source: &[T]) -> V
         source.len()
     );
 
-    let mut result: Vec<[T; N]> = unsafe { uninit_vector(row_count) };
+    let mut result = uninit_vector::<[T; N]>(row_count);
     iter_mut!(result, 1024).enumerate().for_each(|(i, element)| {
-        for j in 0..N {
-            element[j] = source[i + j * row_count]
-        }
+        element.write(core::array::from_fn(|j| source[i + j * row_count]));
     });
-    result
+
+    // SAFETY: every element of the result has been initialized above
+    unsafe { assume_init_vector(result) }
+}
+
+/// Transposes a row-major matrix with `num_cols` columns into a row-major matrix with `num_cols`
+/// rows.
+///
+/// This is equivalent to converting a row-major matrix into a column-major one (and vice versa):
+/// the element at row `r` and column `c` of the `source` is placed at position
+/// `c * num_rows + r` in the result. The matrix is transposed in square tiles sized in multiples
+/// of the 64-byte cache line, so that neither the reads nor the writes stride through memory one
+/// element at a time.
+///
+/// When `concurrent` feature is enabled, large matrices are transposed using multiple threads.
+///
+/// # Panics
+/// Panics if `num_cols` is zero or the length of `source` is not divisible by `num_cols`.
+///
+/// # Example
+/// ```
+/// # use winter_utils::transpose;
+/// let a = [0_u32, 1, 2, 3, 4, 5];
+///
+/// assert_eq!(vec![0, 3, 1, 4, 2, 5], transpose(&a, 3));
+/// assert_eq!(vec![0, 2, 4, 1, 3, 5], transpose(&a, 2));
+/// ```
+pub fn transpose<T: Copy + Send + Sync>(source: &[T], num_cols: usize) -> Vec<T> {
+    assert!(num_cols > 0, "number of columns must be greater than zero");
+    let num_rows = source.len() / num_cols;
+    assert_eq!(
+        num_rows * num_cols,
+        source.len(),
+        "source length must be divisible by {}, but was {}",
+        num_cols,
+        source.len()
+    );
+    if source.is_empty() {
+        return Vec::new();
+    }
+
+    let tile_size = transpose_tile_size::<T>(TRANSPOSE_TILE_LINES);
+    let mut result = uninit_vector::<T>(source.len());
+
+    // each batch of the result contains `tile_size` rows of the transposed matrix (i.e., it is
+    // built from `tile_size` columns of the source); the batch is filled one tile at a time
+    let transpose_batch = |(batch_idx, batch): (usize, &mut [MaybeUninit<T>])| {
+        let col_offset = batch_idx * tile_size;
+        let batch_cols = batch.len() / num_rows;
+        for row_start in (0..num_rows).step_by(tile_size) {
+            let row_end = cmp::min(row_start + tile_size, num_rows);
+            for c in 0..batch_cols {
+                let row = &mut batch[c * num_rows..(c + 1) * num_rows];
+                for r in row_start..row_end {
+                    row[r].write(source[r * num_cols + col_offset + c]);
+                }
+            }
+        }
+    };
+
+    #[cfg(not(feature = "concurrent"))]
+    result.chunks_mut(tile_size * num_rows).enumerate().for_each(transpose_batch);
+
+    #[cfg(feature = "concurrent")]
+    if source.len() < MIN_CONCURRENT_TRANSPOSE_SIZE {
+        result.chunks_mut(tile_size * num_rows).enumerate().for_each(transpose_batch);
+    } else {
+        result
+            .par_chunks_mut(tile_size * num_rows)
+            .enumerate()
+            .for_each(transpose_batch);
+    }
+
+    // SAFETY: the batches cover the whole result, and every element of each batch has been
+    // initialized above
+    unsafe { assume_init_vector(result) }
+}
+
+/// Transposes a square row-major matrix with `size` rows and `size` columns in place.
+///
+/// The matrix is transposed in square tiles with rows spanning a single 64-byte cache line: each
+/// tile above the main diagonal is swapped with the transposition of the corresponding tile below
+/// the diagonal.
+///
+/// When `concurrent` feature is enabled, large matrices are transposed using multiple threads.
+///
+/// # Panics
+/// Panics if the length of `matrix` is not equal to `size * size`.
+///
+/// # Example
+/// ```
+/// # use winter_utils::transpose_in_place;
+/// let mut a = [0_u32, 1, 2, 3, 4, 5, 6, 7, 8];
+/// transpose_in_place(&mut a, 3);
+///
+/// assert_eq!([0, 3, 6, 1, 4, 7, 2, 5, 8], a);
+/// ```
+pub fn transpose_in_place<T: Copy + Send>(matrix: &mut [T], size: usize) {
+    assert_eq!(
+        size * size,
+        matrix.len(),
+        "matrix must contain {} elements, but was {}",
+        size * size,
+        matrix.len()
+    );
+
+    let tile_size = transpose_tile_size::<T>(TRANSPOSE_IN_PLACE_TILE_LINES);
+    let num_tiles = size.div_ceil(tile_size);
+    let ptr = SendPtr(matrix.as_mut_ptr());
+
+    // the closure processes tiles (i, j) for j >= i in a single row of tiles; tiles touched by
+    // different rows of tiles are disjoint, and thus, the rows can be processed in parallel
+    let transpose_tile_row = |i: usize| {
+        let ptr = ptr.get();
+        let row_start = i * tile_size;
+        let row_end = cmp::min(row_start + tile_size, size);
+        for j in i..num_tiles {
+            let col_start = j * tile_size;
+            let col_end = cmp::min(col_start + tile_size, size);
+            for r in row_start..row_end {
+                for c in cmp::max(col_start, r + 1)..col_end {
+                    // SAFETY: r, c < size and (r, c) with r < c is visited by exactly one tile
+                    unsafe { core::ptr::swap(ptr.add(r * size + c), ptr.add(c * size + r)) };
+                }
+            }
+        }
+    };
+
+    #[cfg(not(feature = "concurrent"))]
+    (0..num_tiles).for_each(transpose_tile_row);
+
+    #[cfg(feature = "concurrent")]
+    if matrix.len() < MIN_CONCURRENT_TRANSPOSE_SIZE {
+        (0..num_tiles).for_each(transpose_tile_row);
+    } else {
+        (0..num_tiles).into_par_iter().for_each(transpose_tile_row);
+    }
+}
+
+/// Returns the side length of the square tiles in which matrices are transposed such that a row
+/// of a tile spans `num_lines` cache lines; a tile always contains at least 4 x 4 elements.
+fn transpose_tile_size<T>(num_lines: usize) -> usize {
+    cmp::max(num_lines * CACHE_LINE_BYTES / cmp::max(mem::size_of::<T>(), 1), 4)
+}
+
+/// Pointer to the elements of a matrix which is shared across all threads transposing the matrix.
+#[derive(Clone, Copy)]
+struct SendPtr<T>(*mut T);
+
+impl<T> SendPtr<T> {
+    fn get(self) -> *mut T {
+        self.0
+    }
 }
 
+unsafe impl<T: Send> Send for SendPtr<T> {}
+unsafe impl<T: Send> Sync for SendPtr<T> {}
+
 // RANDOMNESS
 // ================================================================================================
 
@@ -13,6 +13,12 @@ use std::io::BufRead;
 
 use super::{Deserializable, DeserializationError};
 
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of bytes which `read_many()` allocates before reading any elements.
+const MAX_PREALLOCATED_BYTES: usize = 1 << 20;
+
 // BYTE READER TRAIT
 // ================================================================================================
 
@@ -183,6 +189,9 @@ pub trait ByteReader {
     /// Reads a sequence of bytes from `self`, attempts to deserialize these bytes into a vector
     /// with the specified number of `D` elements, and returns the result.
     ///
+    /// The number of elements usually comes from untrusted input, and thus, at most 1 MB of memory
+    /// is allocated upfront; the vector grows as elements are actually read.
+    ///
     /// # Errors
     /// Returns a [DeserializationError] if the specified number elements could not be read from
     /// `self`.
@@ -191,7 +200,8 @@ pub trait ByteReader {
         Self: Sized,
         D: Deserializable,
     {
-        let mut result = Vec::with_capacity(num_elements);
+        let max_capacity = MAX_PREALLOCATED_BYTES / core::mem::size_of::<D>().max(1);
+        let mut result = Vec::with_capacity(num_elements.min(max_capacity));
         for _ in 0..num_elements {
             let element = D::read_from(self)?;
             result.push(element)
@@ -336,102 +346,43 @@ impl<'a> ReadAdapter<'a> {
 
     /// Takes the next `N` bytes from the input as an array, returning an error if the operation fails
     fn read_exact<const N: usize>(&mut self) -> Result<[u8; N], DeserializationError> {
-        let buf = self.buffer();
         let mut output = [0; N];
-        match buf.len() {
-            0 => {
-                let buf = self.non_empty_reader_buffer_mut()?;
-                if buf.len() < N {
-                    return Err(DeserializationError::UnexpectedEOF);
-                }
-                // SAFETY: This copy is guaranteed to be safe, as we have validated above
-                // that `buf` has at least N bytes, and `output` is defined to be exactly
-                // N bytes.
-                unsafe {
-                    core::ptr::copy_nonoverlapping(buf.as_ptr(), output.as_mut_ptr(), N);
-                }
-                self.reader.get_mut().consume(N);
-            },
-            n if n >= N => {
-                // SAFETY: This copy is guaranteed to be safe, as we have validated above
-                // that `buf` has at least N bytes, and `output` is defined to be exactly
-                // N bytes.
-                unsafe {
-                    core::ptr::copy_nonoverlapping(buf.as_ptr(), output.as_mut_ptr(), N);
-                }
-                self.pos += N;
-            },
-            n => {
-                // We have to fill from both the local and reader buffers
-                self.non_empty_reader_buffer_mut()?;
-                let reader_buf = self.reader_buffer();
-                match reader_buf.len() {
-                    #[cfg(debug_assertions)]
-                    0 => unreachable!("expected reader buffer to be non-empty to reach here"),
-                    #[cfg(not(debug_assertions))]
-                    // SAFETY: The call to `non_empty_reader_buffer_mut` will return an error
-                    // if `reader_buffer` is non-empty, as a result is is impossible to reach
-                    // here with a length of 0.
-                    0 => unsafe { core::hint::unreachable_unchecked() },
-                    // We got enough in one request
-                    m if m + n >= N => {
-                        let needed = N - n;
-                        let dst = output.as_mut_ptr();
-                        // SAFETY: Both copies are guaranteed to be in-bounds:
-                        //
-                        // * `output` is defined to be exactly N bytes
-                        // * `buf` is guaranteed to be < N bytes
-                        // * `reader_buf` is guaranteed to have the remaining bytes needed,
-                        // and we only copy exactly that many bytes
-                        unsafe {
-                            core::ptr::copy_nonoverlapping(self.buffer().as_ptr(), dst, n);
-                            core::ptr::copy_nonoverlapping(reader_buf.as_ptr(), dst.add(n), needed);
-                            drop(reader_buf);
-                        }
-                        self.pos += n;
-                        self.reader.get_mut().consume(needed);
-                    },
-                    // We didn't get enough, but haven't necessarily reached eof yet, so fall back
-                    // to filling `self.buf`
-                    m => {
-                        let needed = N - (m + n);
-                        drop(reader_buf);
-                        self.buffer_at_least(needed)?;
-                        debug_assert!(self.buffer().len() >= N, "expected buffer to be at least {N} bytes after call to buffer_at_least");
-                        // SAFETY: This is guaranteed to be an in-bounds copy
-                        unsafe {
-                            core::ptr::copy_nonoverlapping(
-                                self.buffer().as_ptr(),
-                                output.as_mut_ptr(),
-                                N,
-                            );
-                        }
-                        self.pos += N;
-                        return Ok(output);
-                    },
-                }
-            },
+        let n = self.buffer().len();
+        if n >= N {
+            // We have enough in the local buffer
+            output.copy_from_slice(&self.buffer()[..N]);
+            self.pos += N;
+        } else if n == 0 && self.non_empty_reader_buffer_mut()?.len() >= N {
+            // We can read directly from the reader buffer
+            output.copy_from_slice(&self.reader_buffer()[..N]);
+            self.reader.get_mut().consume(N);
+        } else {
+            // The bytes are split between the local buffer and the reader (or the reader returned
+            // less than `N` bytes), so fall back to filling `self.buf`
+            self.buffer_at_least(N)?;
+            output.copy_from_slice(&self.buffer()[..N]);
+            self.pos += N;
         }
 
         // Check if we should reset our internal buffer
         if self.buffer().is_empty() && self.pos > 0 {
-            unsafe {
-                self.buf.set_len(0);
-            }
+            self.buf.clear();
+            self.pos = 0;
         }
 
         Ok(output)
     }
 
-    /// Fill `self.buf` with `count` bytes
+    /// Fill `self.buf` until at least `count` unconsumed bytes are available in it
     ///
     /// This should only be called when we can't read from the reader directly
-    fn buffer_at_least(&mut self, mut count: usize) -> Result<(), DeserializationError> {
+    fn buffer_at_least(&mut self, count: usize) -> Result<(), DeserializationError> {
         // Read until we have at least `count` bytes, or until we reach end-of-file,
         // which ever comes first.
         loop {
-            // If we have succesfully read `count` bytes, we're done
-            if count == 0 || self.buf.len() >= count {
+            // If we have succesfully buffered `count` bytes, we're done; note that bytes in
+            // `self.buf` before `self.pos` have already been consumed and must not be counted
+            if self.buffer().len() >= count {
                 break Ok(());
             }
 
@@ -447,7 +398,6 @@ impl<'a> ReadAdapter<'a> {
             let consumed = buf.len();
             self.buf.extend_from_slice(buf);
             reader.consume(consumed);
-            count = count.saturating_sub(consumed);
         }
     }
 }
@@ -638,6 +588,24 @@ impl<'a> SliceReader<'a> {
     pub fn new(source: &'a [u8]) -> Self {
         SliceReader { source, pos: 0 }
     }
+
+    /// Returns the offset of the next byte to be read from the underlying slice.
+    ///
+    /// This is useful for reporting where in the source deserialization failed, e.g., via
+    /// [DeserializationError::at_offset()].
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns a reader which annotates all errors with the offset at which they occurred and
+    /// the specified `context`.
+    ///
+    /// For example, an attempt to read past the end of the source via
+    /// `reader.context("FRI layer 3").read_u32()` results in an error described as
+    /// "unexpected EOF at offset 1432 while reading FRI layer 3".
+    pub fn context<'r>(&'r mut self, context: &'r str) -> PositionedReader<'r, 'a> {
+        PositionedReader { reader: self, context }
+    }
 }
 
 impl ByteReader for SliceReader<'_> {
@@ -680,6 +648,93 @@ impl ByteReader for SliceReader<'_> {
     }
 }
 
+// POSITIONED READER
+// ================================================================================================
+
+/// A wrapper around a [SliceReader] which annotates all deserialization errors with the byte
+/// offset at which they occurred and a description of the value being read.
+///
+/// Errors are returned as [DeserializationError::AtOffset]; the original error kind can be
+/// retrieved via [DeserializationError::kind()]. A failed read of a primitive value does not
+/// advance the underlying reader, and thus, the reported offset is the offset of the first byte
+/// of such value.
+pub struct PositionedReader<'r, 'a> {
+    reader: &'r mut SliceReader<'a>,
+    context: &'r str,
+}
+
+impl PositionedReader<'_, '_> {
+    /// Returns the offset of the next byte to be read from the underlying slice.
+    pub fn position(&self) -> usize {
+        self.reader.position()
+    }
+
+    /// Returns a single byte read from the underlying slice.
+    pub fn read_u8(&mut self) -> Result<u8, DeserializationError> {
+        let result = self.reader.read_u8();
+        self.annotate(result)
+    }
+
+    /// Returns a u16 value read from the underlying slice in little-endian byte order.
+    pub fn read_u16(&mut self) -> Result<u16, DeserializationError> {
+        let result = self.reader.read_u16();
+        self.annotate(result)
+    }
+
+    /// Returns a u32 value read from the underlying slice in little-endian byte order.
+    pub fn read_u32(&mut self) -> Result<u32, DeserializationError> {
+        let result = self.reader.read_u32();
+        self.annotate(result)
+    }
+
+    /// Returns a u64 value read from the underlying slice in little-endian byte order.
+    pub fn read_u64(&mut self) -> Result<u64, DeserializationError> {
+        let result = self.reader.read_u64();
+        self.annotate(result)
+    }
+
+    /// Returns a usize value read from the underlying slice using vint64 encoding.
+    pub fn read_usize(&mut self) -> Result<usize, DeserializationError> {
+        let result = self.reader.read_usize();
+        self.annotate(result)
+    }
+
+    /// Returns a byte vector of the specified length read from the underlying slice.
+    pub fn read_vec(&mut self, len: usize) -> Result<Vec<u8>, DeserializationError> {
+        let result = self.reader.read_vec(len);
+        self.annotate(result)
+    }
+
+    /// Returns a byte array of length `N` read from the underlying slice.
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], DeserializationError> {
+        let result = self.reader.read_array::<N>();
+        self.annotate(result)
+    }
+
+    /// Reads a deserializable value, e.g., a field element, from the underlying slice.
+    pub fn read_element<D: Deserializable>(&mut self) -> Result<D, DeserializationError> {
+        let result = self.reader.read::<D>();
+        self.annotate(result)
+    }
+
+    /// Reads a sequence of `num_elements` deserializable values from the underlying slice.
+    pub fn read_many<D: Deserializable>(
+        &mut self,
+        num_elements: usize,
+    ) -> Result<Vec<D>, DeserializationError> {
+        let result = self.reader.read_many::<D>(num_elements);
+        self.annotate(result)
+    }
+
+    /// Attaches the current position of the reader and the context to the error, if any.
+    fn annotate<T>(
+        &self,
+        result: Result<T, DeserializationError>,
+    ) -> Result<T, DeserializationError> {
+        result.map_err(|err| err.at_offset(self.reader.position(), self.context))
+    }
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use std::io::Cursor;
@@ -743,6 +798,34 @@ mod tests {
         assert_eq!(adapter.read_usize(), Ok(VALUE));
     }
 
+    #[test]
+    fn read_adapter_chunked_input() {
+        /// A reader which returns at most 3 bytes per read.
+        struct ChunkedReader(Cursor<Vec<u8>>);
+
+        impl std::io::Read for ChunkedReader {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let len = buf.len().min(3);
+                std::io::Read::read(&mut self.0, &mut buf[..len])
+            }
+        }
+
+        let bytes = (0..=255u8).cycle().take(2000).collect::<Vec<_>>();
+        let mut inner = ChunkedReader(Cursor::new(bytes.clone()));
+        let mut adapter = ReadAdapter::new(&mut inner);
+        let mut expected = SliceReader::new(&bytes);
+
+        // reads which span the boundaries of the chunks returned by the underlying reader, as
+        // well as reads larger than the internal buffer of the adapter
+        for len in [1, 5, 2, 300, 7, 600, 3, 1000] {
+            assert_eq!(expected.read_u32(), adapter.read_u32());
+            assert_eq!(expected.read_slice(len), adapter.read_slice(len));
+            assert_eq!(expected.read_array::<9>(), adapter.read_array::<9>());
+            assert_eq!(expected.read_u8(), adapter.read_u8());
+        }
+        assert_eq!(expected.has_more_bytes(), adapter.has_more_bytes());
+    }
+
     #[test]
     fn read_adapter_for_file() {
         use std::fs::File;
@@ -774,4 +857,72 @@ mod tests {
         assert_eq!(reader.read_u16().unwrap(), 0x5);
         assert!(!reader.has_more_bytes(), "expected there to be no more data in the input");
     }
+
+    #[test]
+    fn slice_reader_position() {
+        let bytes = [1u8, 2, 3, 4, 5];
+        let mut reader = SliceReader::new(&bytes);
+        assert_eq!(reader.position(), 0);
+        assert_eq!(reader.read_u8(), Ok(1));
+        assert_eq!(reader.position(), 1);
+        assert_eq!(reader.read_u16(), Ok(0x0302));
+        assert_eq!(reader.position(), 3);
+
+        // failed reads do not advance the reader
+        let err = reader.read_u32().unwrap_err();
+        assert_eq!(err, DeserializationError::UnexpectedEOF);
+        assert_eq!(reader.position(), 3);
+
+        let err = err.at_offset(reader.position(), "FRI layer 3");
+        assert_eq!(err.kind(), &DeserializationError::UnexpectedEOF);
+        assert_eq!(err.offset(), Some(3));
+        assert_eq!(err.to_string(), "unexpected EOF at offset 3 while reading FRI layer 3");
+    }
+
+    #[test]
+    fn positioned_reader() {
+        let mut bytes = vec![7u8];
+        bytes.write_u16(0x0102);
+        bytes.write_u32(0x03040506);
+        bytes.write_u64(0x0708090a0b0c0d0e);
+        bytes.write_usize(300);
+        bytes.write_bytes(&[1, 2, 3, 4, 5]);
+        bytes.write_many([11u64, 12]);
+
+        let mut reader = SliceReader::new(&bytes);
+        let mut positioned = reader.context("test values");
+        assert_eq!(positioned.read_u8(), Ok(7));
+        assert_eq!(positioned.read_u16(), Ok(0x0102));
+        assert_eq!(positioned.read_u32(), Ok(0x03040506));
+        assert_eq!(positioned.read_u64(), Ok(0x0708090a0b0c0d0e));
+        assert_eq!(positioned.read_usize(), Ok(300));
+        assert_eq!(positioned.read_vec(2), Ok(vec![1, 2]));
+        assert_eq!(positioned.read_array::<3>(), Ok([3, 4, 5]));
+        assert_eq!(positioned.read_element::<u64>(), Ok(11));
+        assert_eq!(positioned.position(), bytes.len() - 8);
+
+        // the failing read is reported at the offset of its first byte, and the error kind is
+        // preserved
+        let offset = positioned.position();
+        let err = positioned.read_many::<u64>(2).unwrap_err();
+        assert_eq!(err.kind(), &DeserializationError::UnexpectedEOF);
+        assert_eq!(err.offset(), Some(offset + 8));
+        assert_eq!(
+            err.to_string(),
+            format!("unexpected EOF at offset {} while reading test values", offset + 8)
+        );
+
+        for err in [
+            reader.context("a").read_u8().unwrap_err(),
+            reader.context("a").read_u16().unwrap_err(),
+            reader.context("a").read_u32().unwrap_err(),
+            reader.context("a").read_u64().unwrap_err(),
+            reader.context("a").read_vec(1).unwrap_err(),
+            reader.context("a").read_array::<1>().unwrap_err(),
+            reader.context("a").read_element::<u8>().unwrap_err(),
+        ] {
+            assert_eq!(err.kind(), &DeserializationError::UnexpectedEOF);
+            assert_eq!(err.offset(), Some(bytes.len()));
+        }
+    }
 }
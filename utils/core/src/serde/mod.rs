@@ -14,7 +14,7 @@ use super::DeserializationError;
 mod byte_reader;
 #[cfg(feature = "std")]
 pub use byte_reader::ReadAdapter;
-pub use byte_reader::{ByteReader, SliceReader};
+pub use byte_reader::{ByteReader, PositionedReader, SliceReader};
 
 mod byte_writer;
 pub use byte_writer::ByteWriter;
@@ -6,12 +6,16 @@
 use alloc::{
     borrow::ToOwned,
     collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
     vec::Vec,
 };
 
-use proptest::prelude::{any, proptest};
+use proptest::prelude::{any, prop, prop_assert_eq, proptest};
 
-use super::{ByteReader, ByteWriter, Serializable, SliceReader};
+use super::{
+    flatten_slice_elements, flatten_vector_elements, group_slice_elements, group_vector_elements,
+    transpose, transpose_in_place, ByteReader, ByteWriter, Serializable, SliceReader,
+};
 
 // SLICE READER TESTS
 // ================================================================================================
@@ -238,6 +242,162 @@ fn size_hint_btree() {
     size_hint_matches_serialized_len(set);
 }
 
+// GROUPING
+// ================================================================================================
+
+#[test]
+fn group_elements_round_trip() {
+    fn round_trip<const N: usize>() {
+        let source = (0..8 * N as u64).collect::<Vec<_>>();
+
+        let grouped: &[[u64; N]] = group_slice_elements(&source);
+        assert_eq!(8, grouped.len());
+        for (i, group) in grouped.iter().enumerate() {
+            assert_eq!(&source[i * N..(i + 1) * N], group);
+        }
+        assert_eq!(source, flatten_slice_elements(grouped));
+
+        let grouped = group_vector_elements::<_, N>(source.clone());
+        assert_eq!(group_slice_elements::<_, N>(&source), grouped);
+        assert_eq!(source, flatten_vector_elements(grouped));
+    }
+
+    round_trip::<2>();
+    round_trip::<4>();
+    round_trip::<8>();
+    round_trip::<16>();
+}
+
+#[test]
+fn group_vector_elements_reuses_allocation() {
+    // the allocation is reused when the capacity is divisible by the group size
+    let mut source = Vec::<u32>::with_capacity(16);
+    source.extend(0..8);
+    let ptr = source.as_ptr();
+    let grouped = group_vector_elements::<_, 4>(source);
+    assert_eq!(ptr, grouped.as_ptr() as *const u32);
+    assert_eq!(4, grouped.capacity());
+
+    // otherwise, the elements are moved into a new vector
+    let mut source = Vec::<String>::with_capacity(9);
+    source.extend((0..8).map(|i| i.to_string()));
+    let grouped = group_vector_elements::<_, 4>(source);
+    assert_eq!(vec![["0", "1", "2", "3"], ["4", "5", "6", "7"]], grouped);
+}
+
+#[test]
+fn group_slice_elements_alignment() {
+    // grouped elements have the same alignment as the source elements, and thus, grouping never
+    // needs to copy the slice
+    fn check<T: Default + Clone, const N: usize>() {
+        assert_eq!(core::mem::align_of::<T>(), core::mem::align_of::<[T; N]>());
+        let source = vec![T::default(); 4 * N];
+        let grouped = group_slice_elements::<_, N>(&source[N..]);
+        assert_eq!(source[N..].as_ptr(), grouped.as_ptr() as *const T);
+        assert_eq!(0, grouped.as_ptr().align_offset(core::mem::align_of::<[T; N]>()));
+    }
+
+    check::<u8, 2>();
+    check::<u16, 4>();
+    check::<u64, 8>();
+    check::<u128, 16>();
+}
+
+#[test]
+#[should_panic(expected = "source length must be divisible by 4")]
+fn group_slice_elements_invalid_length() {
+    group_slice_elements::<_, 4>(&[1_u32, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+#[should_panic(expected = "source length must be divisible by 8, but was 12")]
+fn group_vector_elements_invalid_length() {
+    group_vector_elements::<_, 8>(vec![0_u32; 12]);
+}
+
+#[test]
+#[should_panic(expected = "group size must be greater than zero")]
+fn group_vector_elements_zero_group_size() {
+    group_vector_elements::<_, 0>(vec![0_u32; 4]);
+}
+
+// TRANSPOSING
+// ================================================================================================
+
+#[test]
+fn transpose_odd_shapes() {
+    // shapes smaller than, equal to, and not aligned with the transposition tiles; the last
+    // shape is large enough to be transposed in multiple threads
+    let shapes = [(1, 1), (1, 7), (7, 1), (3, 5), (17, 33), (64, 3), (100, 129), (1025, 67)];
+    for (num_rows, num_cols) in shapes {
+        let source = (0..num_rows * num_cols).map(|i| i as u64).collect::<Vec<_>>();
+        let result = transpose(&source, num_cols);
+        assert_eq!(naive_transpose(&source, num_cols), result);
+
+        // transposing twice returns the original matrix
+        assert_eq!(source, transpose(&result, num_rows));
+    }
+
+    // elements wider than a cache line are transposed in tiles of the minimum size
+    let source = (0..35_u128).map(|i| [i; 5]).collect::<Vec<_>>();
+    assert_eq!(naive_transpose(&source, 5), transpose(&source, 5));
+
+    assert!(transpose::<u64>(&[], 4).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "source length must be divisible by 4, but was 6")]
+fn transpose_invalid_shape() {
+    transpose(&[0_u64; 6], 4);
+}
+
+#[test]
+fn transpose_square_in_place() {
+    for size in [1, 2, 5, 16, 31, 64, 100, 300] {
+        let source = (0..size * size).map(|i| i as u32).collect::<Vec<_>>();
+        let mut result = source.clone();
+        transpose_in_place(&mut result, size);
+        assert_eq!(naive_transpose(&source, size), result);
+    }
+}
+
+#[test]
+#[should_panic(expected = "matrix must contain 16 elements, but was 12")]
+fn transpose_in_place_invalid_shape() {
+    transpose_in_place(&mut [0_u64; 12], 4);
+}
+
+fn naive_transpose<T: Copy>(source: &[T], num_cols: usize) -> Vec<T> {
+    let num_rows = source.len() / num_cols;
+    let mut result = Vec::with_capacity(source.len());
+    for c in 0..num_cols {
+        for r in 0..num_rows {
+            result.push(source[r * num_cols + c]);
+        }
+    }
+    result
+}
+
+proptest! {
+    #[test]
+    fn transpose_proptest(
+        num_cols in 1_usize..80,
+        data in prop::collection::vec(any::<u64>(), 0..4000),
+    ) {
+        let num_rows = data.len() / num_cols;
+        let source = &data[..num_rows * num_cols];
+        prop_assert_eq!(naive_transpose(source, num_cols), transpose(source, num_cols));
+    }
+
+    #[test]
+    fn transpose_in_place_proptest(size in 1_usize..70) {
+        let source = (0..size * size).map(|i| i as u64).collect::<Vec<_>>();
+        let mut result = source.clone();
+        transpose_in_place(&mut result, size);
+        prop_assert_eq!(naive_transpose(&source, size), result);
+    }
+}
+
 // UTILS - RANDOMIZED - UINT SERIALIZATION AND DESERIALIZATION
 // ================================================================================================
 proptest! {
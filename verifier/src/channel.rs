@@ -7,7 +7,7 @@ use alloc::{string::ToString, vec::Vec};
 use core::marker::PhantomData;
 
 use air::{
-    proof::{Proof, Queries, Table, TraceOodFrame},
+    proof::{Proof, Queries, TableRef, TraceOodFrame},
     Air,
 };
 use crypto::{ElementHasher, VectorCommitment};
@@ -24,17 +24,22 @@ use crate::VerifierError;
 /// A channel is instantiated for a specific proof, which is parsed into structs over the
 /// appropriate field (specified by type parameter `E`). This also validates that the proof is
 /// well-formed in the context of the computation for the specified [Air].
+///
+/// Trace and constraint queries are parsed into tables which borrow query values from the bytes
+/// of the proof whenever the layout of field elements permits it (see [TableRef]), and thus, the
+/// queries must outlive the channel.
 pub struct VerifierChannel<
+    'a,
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
 > {
     // trace queries
     trace_commitments: Vec<H::Digest>,
-    trace_queries: Option<TraceQueries<E, H, V>>,
+    trace_queries: Option<TraceQueries<'a, E, H, V>>,
     // constraint queries
     constraint_commitment: H::Digest,
-    constraint_queries: Option<ConstraintQueries<E, H, V>>,
+    constraint_queries: Option<ConstraintQueries<'a, E, H, V>>,
     // partition sizes for the rows of main, auxiliary and constraint traces rows
     partition_size_main: usize,
     partition_size_aux: usize,
@@ -53,7 +58,7 @@ pub struct VerifierChannel<
     gkr_proof: Option<Vec<u8>>,
 }
 
-impl<E, H, V> VerifierChannel<E, H, V>
+impl<'a, E, H, V> VerifierChannel<'a, E, H, V>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
@@ -62,27 +67,43 @@ where
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Creates and returns a new [VerifierChannel] initialized from the specified `proof`.
+    ///
+    /// Trace and constraint queries are read from `trace_queries` and `constraint_queries` rather
+    /// than from the `proof`; the caller is expected to take them out of the proof beforehand.
     pub fn new<A: Air<BaseField = E::BaseField>>(
         air: &A,
         proof: Proof,
+        trace_queries: &'a [Queries],
+        constraint_queries: &'a Queries,
     ) -> Result<Self, VerifierError> {
         let Proof {
             context,
             num_unique_queries,
             commitments,
-            trace_queries,
-            constraint_queries,
             ood_frame,
             fri_proof,
             pow_nonce,
             gkr_proof,
+            ..
         } = proof;
 
         // make sure AIR and proof base fields are the same
         if E::BaseField::get_modulus_le_bytes() != context.field_modulus_bytes() {
             return Err(VerifierError::InconsistentBaseField);
         }
+        // make sure the prover split the constraint composition polynomial into the number of
+        // columns required by the AIR; otherwise, constraint queries and the out-of-domain frame
+        // cannot be parsed correctly. Legacy proofs do not record the number of columns, and
+        // thus, it is taken from the AIR.
         let constraint_frame_width = air.context().num_constraint_composition_columns();
+        if let Some(num_columns) = context.num_constraint_composition_columns() {
+            if num_columns != constraint_frame_width {
+                return Err(VerifierError::InconsistentConstraintCompositionColumns(
+                    constraint_frame_width,
+                    num_columns,
+                ));
+            }
+        }
 
         let num_trace_segments = air.trace_info().num_segments();
         let main_trace_width = air.trace_info().main_trace_width();
@@ -94,7 +115,8 @@ where
         // --- parse commitments ------------------------------------------------------------------
         let (trace_commitments, constraint_commitment, fri_commitments) = commitments
             .parse::<H>(num_trace_segments, fri_options.num_fri_layers(lde_domain_size))
-            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?
+            .into_parts();
 
         // --- parse trace and constraint queries -------------------------------------------------
         let trace_queries =
@@ -107,16 +129,18 @@ where
 
         // --- parse FRI proofs -------------------------------------------------------------------
         let fri_num_partitions = fri_proof.num_partitions();
-        let fri_remainder = fri_proof
-            .parse_remainder()
-            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+        let fri_remainder = fri_proof.parse_remainder().map_err(malformed_fri_proof)?;
         let (fri_layer_queries, fri_layer_proofs) = fri_proof
-            .parse_layers::<E, H, V>(lde_domain_size, fri_options.folding_factor())
-            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+            .parse_layers::<E, H, V>(
+                fri_options.num_fri_layers(lde_domain_size),
+                lde_domain_size,
+                fri_options.folding_factor(),
+            )
+            .map_err(malformed_fri_proof)?;
 
         // --- parse out-of-domain evaluation frame -----------------------------------------------
         let (ood_trace_frame, ood_constraint_evaluations) = ood_frame
-            .parse(main_trace_width, aux_trace_width, constraint_frame_width)
+            .parse(main_trace_width, aux_trace_width, air.frame_size(), constraint_frame_width)
             .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
 
         // --- compute the partition size for each trace ------------------------------------------
@@ -204,7 +228,7 @@ where
     pub fn read_queried_trace_states(
         &mut self,
         positions: &[usize],
-    ) -> Result<(Table<E::BaseField>, Option<Table<E>>), VerifierError> {
+    ) -> Result<(TableRef<'a, E::BaseField>, Option<TableRef<'a, E>>), VerifierError> {
         let queries = self.trace_queries.take().expect("already read");
 
         // make sure the states included in the proof correspond to the trace commitment
@@ -246,7 +270,7 @@ where
     pub fn read_constraint_evaluations(
         &mut self,
         positions: &[usize],
-    ) -> Result<Table<E>, VerifierError> {
+    ) -> Result<TableRef<'a, E>, VerifierError> {
         let queries = self.constraint_queries.take().expect("already read");
 
         let items: Vec<H::Digest> = queries
@@ -270,7 +294,7 @@ where
 // FRI VERIFIER CHANNEL IMPLEMENTATION
 // ================================================================================================
 
-impl<E, H, V> FriVerifierChannel<E> for VerifierChannel<E, H, V>
+impl<E, H, V> FriVerifierChannel<E> for VerifierChannel<'_, E, H, V>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
@@ -309,17 +333,18 @@ where
 ///
 /// Trace states for all auxiliary segments are stored in a single table.
 struct TraceQueries<
+    'a,
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
 > {
     query_proofs: Vec<V::MultiProof>,
-    main_states: Table<E::BaseField>,
-    aux_states: Option<Table<E>>,
+    main_states: TableRef<'a, E::BaseField>,
+    aux_states: Option<TableRef<'a, E>>,
     _h: PhantomData<H>,
 }
 
-impl<E, H, V> TraceQueries<E, H, V>
+impl<'a, E, H, V> TraceQueries<'a, E, H, V>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
@@ -328,7 +353,7 @@ where
     /// Parses the provided trace queries into trace states in the specified field and
     /// corresponding batch opening proof.
     pub fn new<A: Air<BaseField = E::BaseField>>(
-        mut queries: Vec<Queries>,
+        queries: &'a [Queries],
         air: &A,
         num_queries: usize,
     ) -> Result<Self, VerifierError> {
@@ -342,9 +367,8 @@ where
 
         // parse main trace segment queries
         let main_segment_width = air.trace_info().main_trace_width();
-        let main_segment_queries = queries.remove(0);
-        let (main_segment_query_proofs, main_segment_states) = main_segment_queries
-            .parse::<E::BaseField, H, V>(air.lde_domain_size(), num_queries, main_segment_width)
+        let (main_segment_query_proofs, main_segment_states) = queries[0]
+            .parse_ref::<E::BaseField, H, V>(air.lde_domain_size(), num_queries, main_segment_width)
             .map_err(|err| {
                 VerifierError::ProofDeserializationError(format!(
                     "main trace segment query deserialization failed: {err}"
@@ -357,11 +381,9 @@ where
         // parse auxiliary trace segment queries (if any), and merge resulting tables into a
         // single table
         let aux_trace_states = if air.trace_info().is_multi_segment() {
-            let mut aux_trace_states = Vec::new();
-            let segment_queries = queries.remove(0);
             let segment_width = air.trace_info().get_aux_segment_width();
-            let (segment_query_proof, segment_trace_states) = segment_queries
-                .parse::<E, H, V>(air.lde_domain_size(), num_queries, segment_width)
+            let (segment_query_proof, segment_trace_states) = queries[1]
+                .parse_ref::<E, H, V>(air.lde_domain_size(), num_queries, segment_width)
                 .map_err(|err| {
                     VerifierError::ProofDeserializationError(format!(
                         "auxiliary trace segment query deserialization failed: {err}"
@@ -369,10 +391,7 @@ where
                 })?;
 
             query_proofs.push(segment_query_proof);
-            aux_trace_states.push(segment_trace_states);
-
-            // merge tables for each auxiliary segment into a single table
-            Some(Table::merge(aux_trace_states))
+            Some(segment_trace_states)
         } else {
             None
         };
@@ -393,16 +412,17 @@ where
 /// * Queried constraint evaluation values.
 /// * Batch opening proof for all queries.
 struct ConstraintQueries<
+    'a,
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
 > {
     query_proofs: V::MultiProof,
-    evaluations: Table<E>,
+    evaluations: TableRef<'a, E>,
     _h: PhantomData<H>,
 }
 
-impl<E, H, V> ConstraintQueries<E, H, V>
+impl<'a, E, H, V> ConstraintQueries<'a, E, H, V>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
@@ -411,14 +431,14 @@ where
     /// Parses the provided constraint queries into evaluations in the specified field and
     /// corresponding batch opening proof.
     pub fn new<A: Air<BaseField = E::BaseField>>(
-        queries: Queries,
+        queries: &'a Queries,
         air: &A,
         num_queries: usize,
     ) -> Result<Self, VerifierError> {
         let constraint_frame_width = air.context().num_constraint_composition_columns();
 
         let (query_proofs, evaluations) = queries
-            .parse::<E, H, V>(air.lde_domain_size(), num_queries, constraint_frame_width)
+            .parse_ref::<E, H, V>(air.lde_domain_size(), num_queries, constraint_frame_width)
             .map_err(|err| {
                 VerifierError::ProofDeserializationError(format!(
                     "constraint evaluation query deserialization failed: {err}"
@@ -455,3 +475,8 @@ where
         H::merge_many(&buffer)
     }
 }
+
+/// Converts an error encountered while parsing a FRI proof into a FRI verification error.
+fn malformed_fri_proof(err: fri::FriProofError) -> VerifierError {
+    VerifierError::FriVerificationFailed(fri::VerifierError::MalformedProof(err))
+}
@@ -6,7 +6,8 @@
 use alloc::vec::Vec;
 
 use air::{
-    proof::Table, Air, DeepCompositionCoefficients, EvaluationFrame, LagrangeKernelEvaluationFrame,
+    proof::TableRef, Air, DeepCompositionCoefficients, EvaluationFrame,
+    LagrangeKernelEvaluationFrame,
 };
 use math::{batch_inversion, polynom, FieldElement};
 
@@ -16,7 +17,7 @@ use math::{batch_inversion, polynom, FieldElement};
 pub struct DeepComposer<E: FieldElement> {
     cc: DeepCompositionCoefficients<E>,
     x_coordinates: Vec<E>,
-    z: [E; 2],
+    z: Vec<E>,
     g_trace: E::BaseField,
     lagrange_kernel_column_idx: Option<usize>,
 }
@@ -38,10 +39,19 @@ impl<E: FieldElement> DeepComposer<E> {
             .collect();
         let g_trace = air.trace_domain_generator();
 
+        // compute the out-of-domain points z, z * g, ..., z * g^(k-1), where k is the number of
+        // rows in the evaluation frame
+        let mut ood_points = Vec::with_capacity(air.frame_size());
+        let mut ood_point = z;
+        for _ in 0..air.frame_size() {
+            ood_points.push(ood_point);
+            ood_point = ood_point.mul_base(g_trace);
+        }
+
         DeepComposer {
             cc,
             x_coordinates,
-            z: [z, z * E::from(g_trace)],
+            z: ood_points,
             g_trace,
             lagrange_kernel_column_idx: air.context().lagrange_kernel_aux_column_idx(),
         }
@@ -53,9 +63,10 @@ impl<E: FieldElement> DeepComposer<E> {
     /// - Assume each column value is an evaluation of a trace polynomial T_i(x).
     /// - For each T_i(x) compute T'_i(x) = (T_i(x) - T_i(z)) / (x - z) and
     ///   T''_i = (T_i(x) - T_i(z * g)) / (x - z * g), where z is the out-of-domain point and
-    ///   g is the the LDE domain generator.
-    /// - Then, combine all T'_i(x) and T''_i(x) values together by computing
-    ///   T(x) = sum((T'_i(x) + T''_i(x)) * cc_i) for all i, where cc_i is the coefficient for
+    ///   g is the the LDE domain generator. If the evaluation frame of the AIR contains k > 2
+    ///   rows, also compute (T_i(x) - T_i(z * g^j)) / (x - z * g^j) for all 2 <= j < k.
+    /// - Then, combine all of these values together by computing
+    ///   T(x) = sum((T'_i(x) + T''_i(x) + ...) * cc_i) for all i, where cc_i is the coefficient
     ///   for the random linear combination drawn from the public coin.
     /// - If a Lagrange kernel is present, combine one additional term defined as
     ///   (T_l(x) - p_S(x)) / Z_S(x), where:
@@ -67,20 +78,22 @@ impl<E: FieldElement> DeepComposer<E> {
     ///    ${(a, T_l(a)): a \in S}$.
     /// 4. $Z_S(X)$ is the polynomial of minimal degree vanishing over the set $S$.
     ///
-    /// Note that values of T_i(z) and T_i(z * g) are received from the prover and passed into
+    /// Note that values of T_i(z), T_i(z * g) etc. are received from the prover and passed into
     /// this function via the `ood_main_frame` and `ood_aux_frame` parameters.
     ///
     /// If a Lagrange kernel is present, the evaluations of $T_l$ over the set $S$ are received
     /// from the prover and passed separately via `ood_lagrange_kernel_frame`.
     pub fn compose_trace_columns(
         &self,
-        queried_main_trace_states: Table<E::BaseField>,
-        queried_aux_trace_states: Option<Table<E>>,
+        queried_main_trace_states: TableRef<'_, E::BaseField>,
+        queried_aux_trace_states: Option<TableRef<'_, E>>,
         ood_main_frame: EvaluationFrame<E>,
         ood_aux_frame: Option<EvaluationFrame<E>>,
         ood_lagrange_kernel_frame: Option<&LagrangeKernelEvaluationFrame<E>>,
     ) -> Vec<E> {
-        let ood_main_trace_states = [ood_main_frame.current(), ood_main_frame.next()];
+        let frame_size = self.z.len();
+        let ood_main_trace_states: Vec<&[E]> =
+            (0..frame_size).map(|offset| ood_main_frame.row(offset)).collect();
 
         // compose columns of of the main trace segment; we do this separately for numerators of
         // each query; we also track common denominator for each query separately; this way we can
@@ -88,37 +101,36 @@ impl<E: FieldElement> DeepComposer<E> {
         let n = queried_main_trace_states.num_rows();
         let mut result_num = Vec::<E>::with_capacity(n);
         let mut result_den = Vec::<E>::with_capacity(n);
+        let mut t_nums = vec![E::ZERO; frame_size];
 
         for ((_, row), &x) in (0..n).zip(queried_main_trace_states.rows()).zip(&self.x_coordinates)
         {
-            let mut t1_num = E::ZERO;
-            let mut t2_num = E::ZERO;
+            t_nums.fill(E::ZERO);
 
             for (i, &value) in row.iter().enumerate() {
                 let value = E::from(value);
-                // compute the numerator of T'_i(x) as (T_i(x) - T_i(z)), multiply it by a
-                // composition coefficient, and add the result to the numerator aggregator
-                t1_num += (value - ood_main_trace_states[0][i]) * self.cc.trace[i];
-
-                // compute the numerator of T''_i(x) as (T_i(x) - T_i(z * g)), multiply it by a
-                // composition coefficient, and add the result to the numerator aggregator
-                t2_num += (value - ood_main_trace_states[1][i]) * self.cc.trace[i];
+                // compute the numerators of T'_i(x), T''_i(x) etc. as (T_i(x) - T_i(z * g^j)),
+                // multiply them by a composition coefficient, and add the results to the
+                // numerator aggregators
+                for (t_num, ood_states) in t_nums.iter_mut().zip(&ood_main_trace_states) {
+                    *t_num += (value - ood_states[i]) * self.cc.trace[i];
+                }
             }
-            // compute the common denominator as (x - z) * (x - z * g)
-            let t1_den = x - self.z[0];
-            let t2_den = x - self.z[1];
-            result_den.push(t1_den * t2_den);
-
-            // add the numerators of T'_i(x) and T''_i(x) together; we can do this because later on
-            // we'll use the common denominator computed above.
-            result_num.push(t1_num * t2_den + t2_num * t1_den);
+
+            // compute the common denominator as (x - z) * (x - z * g) * ..., and add the
+            // numerators of T'_i(x), T''_i(x) etc. together; we can do this because later on
+            // we'll use the common denominator computed here.
+            let (num, den) = self.combine_with_common_denominator(x, &t_nums);
+            result_den.push(den);
+            result_num.push(num);
         }
 
         // if the trace has auxiliary segments, compose columns from these segments as well; we
         // also do this separately for numerators and denominators.
         if let Some(queried_aux_trace_states) = queried_aux_trace_states {
             let ood_aux_frame = ood_aux_frame.expect("missing auxiliary OOD frame");
-            let ood_aux_trace_states = [ood_aux_frame.current(), ood_aux_frame.next()];
+            let ood_aux_trace_states: Vec<&[E]> =
+                (0..frame_size).map(|offset| ood_aux_frame.row(offset)).collect();
 
             // we define this offset here because composition of the main trace columns has
             // consumed some number of composition coefficients already.
@@ -131,31 +143,30 @@ impl<E: FieldElement> DeepComposer<E> {
             for ((j, row), &x) in
                 (0..n).zip(queried_aux_trace_states.rows()).zip(&self.x_coordinates)
             {
-                let mut t1_num = E::ZERO;
-                let mut t2_num = E::ZERO;
+                t_nums.fill(E::ZERO);
 
                 let row = &row[..lagrange_ker_col_idx];
                 for (i, &value) in row.iter().enumerate() {
-                    // compute the numerator of T'_i(x) as (T_i(x) - T_i(z)), multiply it by a
-                    // composition coefficient, and add the result to the numerator aggregator
-                    t1_num += (value - ood_aux_trace_states[0][i]) * self.cc.trace[cc_offset + i];
-
-                    // compute the numerator of T''_i(x) as (T_i(x) - T_i(z * g)), multiply it by a
-                    // composition coefficient, and add the result to the numerator aggregator
-                    t2_num += (value - ood_aux_trace_states[1][i]) * self.cc.trace[cc_offset + i];
+                    // compute the numerators of T'_i(x), T''_i(x) etc. as (T_i(x) - T_i(z * g^j)),
+                    // multiply them by a composition coefficient, and add the results to the
+                    // numerator aggregators
+                    for (t_num, ood_states) in t_nums.iter_mut().zip(&ood_aux_trace_states) {
+                        *t_num += (value - ood_states[i]) * self.cc.trace[cc_offset + i];
+                    }
                 }
 
-                // compute the common denominators (x - z) and (x - z * g), and use the to aggregate
-                // numerators into the common numerator computed for the main trace of this query
-                let t1_den = x - self.z[0];
-                let t2_den = x - self.z[1];
-                result_num[j] += t1_num * t2_den + t2_num * t1_den;
+                // use the common denominator (x - z) * (x - z * g) * ... to aggregate numerators
+                // into the common numerator computed for the main trace of this query
+                let (num, _) = self.combine_with_common_denominator(x, &t_nums);
+                result_num[j] += num;
             }
 
             // if a Lagrange kernel trace polynomial is present, we include its associated term
             // separately. Note that, for performance reasons, we divide by Z_{S^{'}} instead of
             // Z_S, where  $S^{'} := {z.g^2, ..., z.g^{2^{log_2(\nu) - 1}}}$. This is done as
-            // the final linear combination is divided by `(x - z) . (x - z.g)`.
+            // the final linear combination is divided by `(x - z) . (x - z.g)`; if the evaluation
+            // frame contains more than two rows, the final linear combination is also divided by
+            // `(x - z.g^j)` for all `2 <= j < k`, and thus, we multiply the term by these factors.
             if let Some(ood_lagrange_kernel_frame) = ood_lagrange_kernel_frame {
                 let mut result_lag_num = Vec::<E>::with_capacity(n);
                 let mut result_lag_den = Vec::<E>::with_capacity(n);
@@ -185,7 +196,8 @@ impl<E: FieldElement> DeepComposer<E> {
                     let value = row[lagrange_ker_col_idx];
                     let cc = self.cc.lagrange.unwrap();
 
-                    result_lag_num.push((value - polynom::eval(&p_s, x)) * cc);
+                    let extra_factors = self.z[2..].iter().fold(E::ONE, |acc, &z| acc * (x - z));
+                    result_lag_num.push((value - polynom::eval(&p_s, x)) * cc * extra_factors);
                     result_lag_den.push(polynom::eval(&z_s_prime, x));
                 }
 
@@ -200,6 +212,19 @@ impl<E: FieldElement> DeepComposer<E> {
         result_num.iter().zip(result_den).map(|(n, d)| *n * d).collect()
     }
 
+    /// Returns the numerator and the denominator of sum(t_nums[j] / (x - z * g^j)) computed over
+    /// the common denominator (x - z) * (x - z * g) * ... * (x - z * g^(k-1)).
+    fn combine_with_common_denominator(&self, x: E, t_nums: &[E]) -> (E, E) {
+        let mut num = E::ZERO;
+        let mut den = E::ONE;
+        for (&t_num, &z) in t_nums.iter().zip(&self.z) {
+            let t_den = x - z;
+            num = num * t_den + t_num * den;
+            den *= t_den;
+        }
+        (num, den)
+    }
+
     /// For each queried set of composition polynomial column evaluations, combine evaluations
     /// into a single value by computing their random linear combination as follows:
     ///
@@ -213,7 +238,7 @@ impl<E: FieldElement> DeepComposer<E> {
     /// via the `ood_evaluations` parameter.
     pub fn compose_constraint_evaluations(
         &self,
-        queried_evaluations: Table<E>,
+        queried_evaluations: TableRef<'_, E>,
         ood_evaluations: Vec<E>,
     ) -> Vec<E> {
         assert_eq!(queried_evaluations.num_rows(), self.x_coordinates.len());
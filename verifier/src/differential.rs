@@ -0,0 +1,214 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::{fmt, marker::PhantomData};
+
+use air::{proof::Proof, Air};
+use crypto::{ElementHasher, ProofOfWork, RandomCoin, VectorCommitment};
+
+use crate::{verify, AcceptableOptions, VerifierError};
+
+// DIFFERENTIAL VERIFIER
+// ================================================================================================
+
+/// Verifies proofs using both this verifier and a reference verifier, and reports whether the
+/// two agree.
+///
+/// The reference verifier is a closure which receives a proof serialized via [Proof::to_bytes()]
+/// and returns either a `bool` (true if the proof was accepted) or a
+/// `Result<(), VerificationPhase>` (if the reference verifier can report in which phase of the
+/// protocol the proof was rejected). This is intended to be used as a
+/// testing tool to make sure proofs generated by Winterfell are accepted (and malformed proofs
+/// are rejected) by an alternative implementation of the verifier.
+pub struct DifferentialVerifier<'a, AIR, HashFn, RandCoin, VC, PowFn> {
+    acceptable_options: &'a AcceptableOptions,
+    _phantom: PhantomData<(AIR, HashFn, RandCoin, VC, PowFn)>,
+}
+
+impl<'a, AIR, HashFn, RandCoin, VC, PowFn>
+    DifferentialVerifier<'a, AIR, HashFn, RandCoin, VC, PowFn>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    /// Returns a new differential verifier which verifies proofs against the specified
+    /// acceptable options.
+    pub fn new(acceptable_options: &'a AcceptableOptions) -> Self {
+        Self {
+            acceptable_options,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Verifies the specified proof against the specified public inputs using both this verifier
+    /// (via [verify()]) and the `reference` verifier, and returns the results of both.
+    pub fn verify<R: Into<ReferenceOutcome>>(
+        &self,
+        proof: &Proof,
+        pub_inputs: AIR::PublicInputs,
+        reference: impl Fn(&[u8]) -> R,
+    ) -> DifferentialResult {
+        let reference = reference(&proof.to_bytes()).into();
+        let result = verify::<AIR, HashFn, RandCoin, VC, PowFn>(
+            proof.clone(),
+            pub_inputs,
+            self.acceptable_options,
+        );
+
+        match (result, reference) {
+            (Ok(()), ReferenceOutcome::Accepted) => DifferentialResult::BothAccepted,
+            (Ok(()), ReferenceOutcome::Rejected(phase)) => {
+                DifferentialResult::ReferenceRejected { phase }
+            },
+            (Err(error), ReferenceOutcome::Accepted) => {
+                DifferentialResult::WinterfellRejected { error }
+            },
+            (Err(error), ReferenceOutcome::Rejected(reference_phase)) => {
+                DifferentialResult::BothRejected { error, reference_phase }
+            },
+        }
+    }
+}
+
+// DIFFERENTIAL RESULT
+// ================================================================================================
+
+/// Outcome of verifying a proof via [DifferentialVerifier::verify()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DifferentialResult {
+    /// Both verifiers accepted the proof.
+    BothAccepted,
+    /// Both verifiers rejected the proof; the phase in which the reference verifier rejected the
+    /// proof is included if the reference verifier reported it.
+    BothRejected {
+        error: VerifierError,
+        reference_phase: Option<VerificationPhase>,
+    },
+    /// Only the reference verifier rejected the proof; the phase in which the proof was rejected
+    /// is included if the reference verifier reported it.
+    ReferenceRejected { phase: Option<VerificationPhase> },
+    /// Only this verifier rejected the proof with the specified error.
+    WinterfellRejected { error: VerifierError },
+}
+
+impl DifferentialResult {
+    /// Returns true if both verifiers either accepted or rejected the proof.
+    ///
+    /// Note that two verifiers rejecting a proof in different phases are still considered to
+    /// agree; phases can be compared via [winterfell_phase()](Self::winterfell_phase) and
+    /// [reference_phase()](Self::reference_phase).
+    pub fn agree(&self) -> bool {
+        matches!(self, Self::BothAccepted | Self::BothRejected { .. })
+    }
+
+    /// Returns the phase in which this verifier rejected the proof, or `None` if the proof was
+    /// accepted.
+    pub fn winterfell_phase(&self) -> Option<VerificationPhase> {
+        match self {
+            Self::BothRejected { error, .. } | Self::WinterfellRejected { error } => {
+                Some(VerificationPhase::from(error))
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the phase in which the reference verifier rejected the proof, or `None` if the
+    /// proof was accepted or the reference verifier did not report the phase.
+    pub fn reference_phase(&self) -> Option<VerificationPhase> {
+        match self {
+            Self::BothRejected { reference_phase: phase, .. }
+            | Self::ReferenceRejected { phase } => *phase,
+            _ => None,
+        }
+    }
+}
+
+// REFERENCE OUTCOME
+// ================================================================================================
+
+/// Outcome of verifying a proof using a reference verifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceOutcome {
+    /// The proof was accepted.
+    Accepted,
+    /// The proof was rejected, optionally in the specified phase of the protocol.
+    Rejected(Option<VerificationPhase>),
+}
+
+impl From<bool> for ReferenceOutcome {
+    fn from(accepted: bool) -> Self {
+        if accepted {
+            Self::Accepted
+        } else {
+            Self::Rejected(None)
+        }
+    }
+}
+
+impl From<Result<(), VerificationPhase>> for ReferenceOutcome {
+    fn from(result: Result<(), VerificationPhase>) -> Self {
+        match result {
+            Ok(()) => Self::Accepted,
+            Err(phase) => Self::Rejected(Some(phase)),
+        }
+    }
+}
+
+// VERIFICATION PHASE
+// ================================================================================================
+
+/// A phase of the verification protocol in which a proof can be rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationPhase {
+    /// The proof could not be parsed, or its parameters are not acceptable or do not match the
+    /// AIR.
+    Setup,
+    /// A value could not be drawn from the public coin.
+    Transcript,
+    /// The GKR proof for the auxiliary trace segment is invalid.
+    Gkr,
+    /// Constraint evaluations at the out-of-domain point are inconsistent.
+    OutOfDomain,
+    /// The proof-of-work for the query seed is invalid.
+    ProofOfWork,
+    /// Trace or constraint queries do not match their commitments.
+    Queries,
+    /// The FRI proof is invalid.
+    Fri,
+}
+
+impl From<&VerifierError> for VerificationPhase {
+    fn from(error: &VerifierError) -> Self {
+        match error {
+            VerifierError::RandomCoinError => Self::Transcript,
+            VerifierError::GkrProofVerificationFailed(_) => Self::Gkr,
+            VerifierError::InconsistentOodConstraintEvaluations => Self::OutOfDomain,
+            VerifierError::QuerySeedProofOfWorkVerificationFailed => Self::ProofOfWork,
+            VerifierError::TraceQueryDoesNotMatchCommitment
+            | VerifierError::ConstraintQueryDoesNotMatchCommitment => Self::Queries,
+            VerifierError::FriVerificationFailed(_) => Self::Fri,
+            VerifierError::ContinuationSegmentVerificationFailed(_, error)
+            | VerifierError::ChainedProofVerificationFailed(_, error) => Self::from(&**error),
+            _ => Self::Setup,
+        }
+    }
+}
+
+impl fmt::Display for VerificationPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Setup => write!(f, "proof setup"),
+            Self::Transcript => write!(f, "transcript"),
+            Self::Gkr => write!(f, "GKR proof"),
+            Self::OutOfDomain => write!(f, "out-of-domain consistency check"),
+            Self::ProofOfWork => write!(f, "proof-of-work"),
+            Self::Queries => write!(f, "query openings"),
+            Self::Fri => write!(f, "FRI"),
+        }
+    }
+}
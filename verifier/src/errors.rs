@@ -5,9 +5,11 @@
 
 //! Contains common error types for prover and verifier.
 
-use alloc::string::String;
+use alloc::{boxed::Box, string::String};
 use core::fmt;
 
+use air::{proof::PROOF_VERSION, PowScheme};
+
 // VERIFIER ERROR
 // ================================================================================================
 /// Represents an error returned by the verifier during an execution of the protocol.
@@ -21,8 +23,23 @@ pub enum VerifierError {
     /// This error occurs when the base field in which the proof was generated does not support
     /// field extension of degree specified by the proof.
     UnsupportedFieldExtension(usize),
+    /// This error occurs when the proof was generated using an older proof format, and the
+    /// verifier was not instructed to accept proofs of older formats.
+    UnsupportedProofVersion(u8),
+    /// This error occurs when the proof-of-work scheme specified in the proof options does not
+    /// match the proof-of-work function with which the verifier was instantiated.
+    UnsupportedPowScheme(PowScheme),
     /// This error occurs when a verifier cannot deserialize the specified proof.
     ProofDeserializationError(String),
+    /// This error occurs when a verifier cannot read the specified proof from a file.
+    ProofReadFailed(String),
+    /// This error occurs when a verifier cannot deserialize the public inputs against which a
+    /// proof is to be verified.
+    PublicInputsDeserializationError(String),
+    /// This error occurs when the number of constraint composition columns specified in the
+    /// proof context does not match the number of columns required by the AIR with which the
+    /// verifier was instantiated.
+    InconsistentConstraintCompositionColumns(usize, usize),
     /// This error occurs when a verifier fails to draw a random value from a random coin
     /// within a specified number of tries.
     RandomCoinError,
@@ -53,6 +70,23 @@ pub enum VerifierError {
     /// This error occurs when the parameters, that were used to generate the proof, do not match
     /// any of the set of parameters expected by the verifier.
     UnacceptableProofOptions,
+    /// This error occurs when a continuation proof does not contain any segments.
+    EmptyContinuationProof,
+    /// This error occurs when the initial state of the first segment or the final state of the
+    /// last segment of a continuation proof does not match the state expected by the verifier.
+    ContinuationBoundaryMismatch,
+    /// This error occurs when the final state of a segment of a continuation proof is not equal
+    /// to the initial state of the next segment.
+    ContinuationStateMismatch(usize),
+    /// This error occurs when the proof of a segment of a continuation proof fails to verify.
+    ContinuationSegmentVerificationFailed(usize, Box<VerifierError>),
+    /// This error occurs when public inputs of the first proof of a chained proof cannot be
+    /// deserialized.
+    ChainedPubInputsDeserializationError(String),
+    /// This error occurs when one of the proofs of a chained proof fails to verify; for the
+    /// second proof, this includes the case when the proof was generated for an input other than
+    /// the output of the first computation.
+    ChainedProofVerificationFailed(usize, Box<VerifierError>),
 }
 
 impl fmt::Display for VerifierError {
@@ -68,9 +102,24 @@ impl fmt::Display for VerifierError {
             Self::UnsupportedFieldExtension(degree) => {
                 write!(f, "field extension of degree {degree} is not supported for the proof base field")
             }
+            Self::UnsupportedProofVersion(version) => {
+                write!(f, "proof format version {version} is not accepted; only proofs of version {PROOF_VERSION} are verified by default")
+            }
+            Self::UnsupportedPowScheme(scheme) => {
+                write!(f, "{scheme:?} proof-of-work scheme specified in the proof options is not supported by the verifier")
+            }
             Self::ProofDeserializationError(msg) => {
                 write!(f, "proof deserialization failed: {msg}")
             }
+            Self::ProofReadFailed(msg) => {
+                write!(f, "failed to read proof file: {msg}")
+            }
+            Self::PublicInputsDeserializationError(msg) => {
+                write!(f, "public inputs deserialization failed: {msg}")
+            }
+            Self::InconsistentConstraintCompositionColumns(expected, actual) => {
+                write!(f, "number of constraint composition columns is inconsistent: expected {expected}, but the proof contains {actual}")
+            }
             Self::RandomCoinError => {
                 write!(f, "failed to draw a random value from a random coin")
             }
@@ -96,6 +145,24 @@ impl fmt::Display for VerifierError {
                 write!(f, "insufficient proof security level: expected at least {minimal_security} bits of proven security, but was {proof_security} bits")
             }
             Self::UnacceptableProofOptions => {write!(f, "invalid proof options: security parameters do not match the acceptable parameter set")}
+            Self::EmptyContinuationProof => {
+                write!(f, "continuation proof does not contain any segments")
+            }
+            Self::ContinuationBoundaryMismatch => {
+                write!(f, "boundary states of the continuation proof do not match the expected states")
+            }
+            Self::ContinuationStateMismatch(segment) => {
+                write!(f, "final state of segment {segment} does not match the initial state of segment {}", segment + 1)
+            }
+            Self::ContinuationSegmentVerificationFailed(segment, err) => {
+                write!(f, "verification of segment {segment} failed: {err}")
+            }
+            Self::ChainedPubInputsDeserializationError(msg) => {
+                write!(f, "failed to deserialize public inputs of the first chained proof: {msg}")
+            }
+            Self::ChainedProofVerificationFailed(idx, err) => {
+                write!(f, "verification of chained proof {idx} failed: {err}")
+            }
         }
     }
 }
@@ -31,21 +31,41 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 use alloc::{string::ToString, vec::Vec};
+use core::mem;
 
+#[cfg(feature = "std")]
+pub use air::proof::{ProofReader, ProofWriter};
 pub use air::{
-    proof::Proof, Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
-    ConstraintCompositionCoefficients, ConstraintDivisor, DeepCompositionCoefficients,
-    EvaluationFrame, FieldExtension, ProofOptions, TraceInfo, TransitionConstraintDegree,
+    constraint,
+    proof::{
+        AirMetadata, AnnotatedProof, ChainedProof, ContinuationProof, ContinuationState, Proof,
+    },
+    Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
+    ConstraintCompositionCoefficients, ConstraintDivisor, ConstraintExpression,
+    DeepCompositionCoefficients, EvaluationFrame, FieldExtension, FriFoldingFactor, PowScheme,
+    ProofOptions, ProofOptionsError, TraceInfo, TransitionConstraintDegree, TransitionExemption,
+};
+use air::{
+    labels,
+    proof::{
+        MetadataAir, MetadataInputs, LEGACY_PROOF_VERSION, PROOF_VERSION, UNLABELED_PROOF_VERSION,
+    },
+    AuxRandElements, GkrVerifier,
 };
-use air::{AuxRandElements, GkrVerifier};
 pub use crypto;
-use crypto::{ElementHasher, Hasher, RandomCoin, VectorCommitment};
+use crypto::{
+    ElementHasher, Hasher, LegacyRandomCoin, ProofOfWork, RandomCoin, UnlabeledRandomCoin,
+    VectorCommitment,
+};
 use fri::FriVerifier;
 pub use math;
 use math::{
     fields::{CubeExtension, QuadExtension},
-    FieldElement, ToElements,
+    ExtensibleField, FieldElement, StarkField, ToElements,
 };
 pub use utils::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
@@ -63,6 +83,14 @@ use composer::DeepComposer;
 mod errors;
 pub use errors::VerifierError;
 
+mod report;
+pub use report::VerificationReport;
+
+mod differential;
+pub use differential::{
+    DifferentialResult, DifferentialVerifier, ReferenceOutcome, VerificationPhase,
+};
+
 // VERIFIER
 // ================================================================================================
 
@@ -72,15 +100,162 @@ pub use errors::VerifierError;
 /// the provided `proof` attests to the correct execution of the computation against public inputs
 /// specified by `pub_inputs`. If the verification is successful, `Ok(())` is returned.
 ///
+/// The Fiat-Shamir transcript of the proof is reproduced using the `RandCoin` random coin, and the
+/// query seed proof-of-work is checked using the `PowFn` proof-of-work function. Only
+/// proofs of the current proof format (see [PROOF_VERSION]) are accepted; proofs of older formats
+/// can be verified via [verify_older_version()].
+///
 /// # Errors
 /// Returns an error if combination of the provided proof and public inputs does not attest to
 /// a correct execution of the computation. This could happen for many various reasons, including:
+/// - The specified proof was generated using an older proof format.
 /// - The specified proof was generated for a different computation.
 /// - The specified proof was generated for this computation but for different public inputs.
 /// - The specified proof was generated with parameters not providing an acceptable security level.
-pub fn verify<AIR, HashFn, RandCoin, VC>(
+/// - The specified proof was generated using a proof-of-work scheme other than `PowFn`.
+pub fn verify<AIR, HashFn, RandCoin, VC, PowFn>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    verify_with_report::<AIR, HashFn, RandCoin, VC, PowFn>(proof, pub_inputs, acceptable_options)
+        .map(|_| ())
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs,
+/// accepting proofs of older proof formats.
+///
+/// This is the same as [verify()], but the Fiat-Shamir transcript of a proof generated using an
+/// older proof format is reproduced in the same way as it was generated:
+/// - For the legacy proof format (see [LEGACY_PROOF_VERSION]), the transcript is reproduced using
+///   [LegacyRandomCoin] instantiated with `HashFn`.
+/// - For both the legacy format and the format preceding domain separation labels (see
+///   [UNLABELED_PROOF_VERSION]), data is absorbed into the transcript without labels (see
+///   [UnlabeledRandomCoin]).
+///
+/// The proof format version is chosen by the prover, and thus, this function lets the prover
+/// choose any of the above transcripts. It should be used only when proofs of older formats need
+/// to be accepted (e.g., for proofs generated by older versions of this library).
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()], except that proofs of older proof
+/// formats are not rejected.
+pub fn verify_older_version<AIR, HashFn, RandCoin, VC, PowFn>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    let settings = VerificationSettings {
+        check_constraints: true,
+        accept_older_versions: true,
+    };
+    verify_proof::<AIR, HashFn, RandCoin, VC, PowFn>(
+        proof,
+        pub_inputs,
+        &[],
+        acceptable_options,
+        settings,
+    )
+    .map(|_| ())
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs,
+/// reading the proof from the file at the specified `proof_path`.
+///
+/// The proof is read via [ProofReader::read()] and is then
+/// verified in the same way as by [verify()]. This is useful for tools in which proofs are
+/// generated and verified by separate processes.
+///
+/// # Errors
+/// Returns an error if:
+/// - The proof file could not be read.
+/// - The file does not contain a valid proof.
+/// - Any of the conditions under which [verify()] returns an error hold.
+#[cfg(feature = "std")]
+pub fn verify_from_file<AIR, HashFn, RandCoin, VC, PowFn>(
+    proof_path: &std::path::Path,
+    pub_inputs: AIR::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    let proof = ProofReader::read(proof_path).map_err(|err| match err.kind() {
+        std::io::ErrorKind::InvalidData => {
+            VerifierError::ProofDeserializationError(err.to_string())
+        },
+        _ => VerifierError::ProofReadFailed(err.to_string()),
+    })?;
+    verify::<AIR, HashFn, RandCoin, VC, PowFn>(proof, pub_inputs, acceptable_options)
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs,
+/// and returns a [VerificationReport] describing the verified proof.
+///
+/// This is the same as [verify()], but on success, the returned report contains the conjectured
+/// security level of the proof, the number of unique query positions and FRI layers checked by
+/// the verifier, and (when the `std` feature is enabled and the target has a system clock) the
+/// time it took to verify the proof.
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()].
+pub fn verify_with_report<AIR, HashFn, RandCoin, VC, PowFn>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+) -> Result<VerificationReport, VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    verify_proof::<AIR, HashFn, RandCoin, VC, PowFn>(
+        proof,
+        pub_inputs,
+        &[],
+        acceptable_options,
+        VerificationSettings::default(),
+    )
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs,
+/// and that the proof is bound to the specified application context data.
+///
+/// This is the same as [verify()], but the public coin is additionally reseeded with the hash of
+/// `context_data` right after it is seeded with the proof context and public inputs, in the same
+/// way as the prover does for the data returned from `Prover::context_data()`. This can be used
+/// to bind a proof to data which the AIR does not use (e.g., a commitment produced by another
+/// system) without including the data in the public inputs. Empty context data is not absorbed
+/// into the transcript, and thus, verifying with empty context data is the same as verifying via
+/// [verify()].
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()]; in addition, an error is returned
+/// if the proof was bound to different context data.
+pub fn verify_with_context_data<AIR, HashFn, RandCoin, VC, PowFn>(
     proof: Proof,
     pub_inputs: AIR::PublicInputs,
+    context_data: &[u8],
     acceptable_options: &AcceptableOptions,
 ) -> Result<(), VerifierError>
 where
@@ -88,11 +263,133 @@ where
     HashFn: ElementHasher<BaseField = AIR::BaseField>,
     RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
     VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    verify_proof::<AIR, HashFn, RandCoin, VC, PowFn>(
+        proof,
+        pub_inputs,
+        context_data,
+        acceptable_options,
+        VerificationSettings::default(),
+    )
+    .map(|_| ())
+}
+
+/// Verifies that a proof is structurally valid, using only the AIR metadata embedded in the
+/// proof.
+///
+/// Structural verification reproduces the Fiat-Shamir transcript of the proof, and checks the
+/// proof-of-work, the openings of all trace and constraint composition values against the
+/// commitments in the proof, and the FRI proof of the DEEP composition polynomial. However, the
+/// constraints of the AIR are not evaluated, and thus, this does not attest to the correct
+/// execution of the computation; this is intended for archival use cases where the AIR for which
+/// the proof was generated may no longer be available.
+///
+/// `pub_inputs_bytes` must contain the public inputs of the original AIR converted into field
+/// elements (via [ToElements]) and serialized as a vector of elements.
+///
+/// Proofs for AIRs with a Lagrange kernel column cannot be annotated with metadata, and thus,
+/// cannot be verified structurally.
+///
+/// # Errors
+/// Returns an error if:
+/// - The public inputs could not be deserialized.
+/// - The proof was generated with parameters not providing an acceptable security level.
+/// - The proof was generated for different public inputs, or any of its openings or its FRI
+///   proof is invalid.
+pub fn verify_self_contained<B, HashFn, RandCoin, VC, PowFn>(
+    proof: AnnotatedProof,
+    pub_inputs_bytes: &[u8],
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3>,
+    HashFn: ElementHasher<BaseField = B>,
+    RandCoin: RandomCoin<BaseField = B, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    let mut reader = SliceReader::new(pub_inputs_bytes);
+    let elements = Vec::<B>::read_from(&mut reader)
+        .map_err(|err| VerifierError::PublicInputsDeserializationError(err.to_string()))?;
+    if reader.has_more_bytes() {
+        return Err(VerifierError::PublicInputsDeserializationError(
+            "public inputs contain trailing bytes".to_string(),
+        ));
+    }
+
+    let pub_inputs = MetadataInputs::new(&proof, elements);
+    let (proof, _) = proof.into_parts();
+    verify_proof::<MetadataAir<B>, HashFn, RandCoin, VC, PowFn>(
+        proof,
+        pub_inputs,
+        &[],
+        acceptable_options,
+        VerificationSettings {
+            check_constraints: false,
+            accept_older_versions: false,
+        },
+    )
+    .map(|_| ())
+}
+
+/// Specifies which checks are performed by [verify_proof()].
+struct VerificationSettings {
+    /// Constraints of the AIR are evaluated at the out-of-domain point only if this is true;
+    /// otherwise, only the structure of the proof is verified.
+    check_constraints: bool,
+    /// Proofs of older proof formats are rejected unless this is true.
+    accept_older_versions: bool,
+}
+
+impl Default for VerificationSettings {
+    fn default() -> Self {
+        Self {
+            check_constraints: true,
+            accept_older_versions: false,
+        }
+    }
+}
+
+/// Verifies the proof against the specified inputs and application context data, and returns a
+/// [VerificationReport] describing the verified proof.
+fn verify_proof<AIR, HashFn, RandCoin, VC, PowFn>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    context_data: &[u8],
+    acceptable_options: &AcceptableOptions,
+    settings: VerificationSettings,
+) -> Result<VerificationReport, VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
 {
+    // the system clock is not available on wasm32-unknown-unknown, and reading it there panics
+    #[cfg(all(feature = "std", not(all(target_arch = "wasm32", target_os = "unknown"))))]
+    let now = std::time::Instant::now();
+
+    // the proof format version is chosen by the prover; unless explicitly requested, proofs of
+    // older formats are rejected so that the prover cannot downgrade the transcript to the
+    // legacy coin or to a transcript without domain separation labels
+    let proof_version = proof.context.proof_version();
+    if proof_version != PROOF_VERSION && !settings.accept_older_versions {
+        return Err(VerifierError::UnsupportedProofVersion(proof_version));
+    }
+
     // check that `proof` was generated with an acceptable set of parameters from the point of view
     // of the verifier
     acceptable_options.validate::<HashFn>(&proof)?;
 
+    // the proof-of-work scheme in the proof options is only a tag; make sure it matches the
+    // proof-of-work function the verifier was instantiated with
+    let pow_scheme = proof.options().pow_scheme();
+    if pow_scheme != PowFn::SCHEME {
+        return Err(VerifierError::UnsupportedPowScheme(pow_scheme));
+    }
+
     // build a seed for the public coin; the initial seed is a hash of the proof context and the
     // public inputs, but as the protocol progresses, the coin will be reseeded with the info
     // received from the prover
@@ -102,61 +399,322 @@ where
     // create AIR instance for the computation specified in the proof
     let air = AIR::new(proof.trace_info().clone(), pub_inputs, proof.options().clone());
 
+    let conjectured_security = proof.security_level::<HashFn>(true);
+    let num_fri_layers = air.options().to_fri_options().num_fri_layers(air.lde_domain_size());
+
+    // proofs of the legacy format were generated using the hash-chain based random coin, and
+    // thus, their transcripts need to be reproduced using the same coin; also, transcripts of
+    // proofs of older formats do not include domain separation labels
+    let check_constraints = settings.check_constraints;
+    let num_unique_queries = match proof_version {
+        LEGACY_PROOF_VERSION => {
+            verify_with_coin::<AIR, HashFn, UnlabeledRandomCoin<LegacyRandomCoin<HashFn>>, VC, PowFn>(
+                air,
+                proof,
+                &public_coin_seed,
+                context_data,
+                check_constraints,
+            )
+        },
+        UNLABELED_PROOF_VERSION => {
+            verify_with_coin::<AIR, HashFn, UnlabeledRandomCoin<RandCoin>, VC, PowFn>(
+                air,
+                proof,
+                &public_coin_seed,
+                context_data,
+                check_constraints,
+            )
+        },
+        _ => verify_with_coin::<AIR, HashFn, RandCoin, VC, PowFn>(
+            air,
+            proof,
+            &public_coin_seed,
+            context_data,
+            check_constraints,
+        ),
+    }?;
+
+    #[cfg(all(feature = "std", not(all(target_arch = "wasm32", target_os = "unknown"))))]
+    let verification_time = Some(now.elapsed());
+    #[cfg(not(all(feature = "std", not(all(target_arch = "wasm32", target_os = "unknown")))))]
+    let verification_time = None;
+
+    Ok(VerificationReport {
+        conjectured_security,
+        num_unique_queries,
+        num_fri_layers,
+        verification_time,
+    })
+}
+
+/// Verifies that a computation split into multiple segments was executed correctly from the
+/// initial state to the final state specified by `state`.
+///
+/// Specifically, for a computation specified by `AIR` and `HashFn` type parameter, verifies that:
+/// - The proof of every segment in `proof` attests to the correct execution of the computation
+///   against public inputs derived from the continuation state of the segment.
+/// - The final state of every segment is equal to the initial state of the next segment.
+/// - The initial state of the first segment and the final state of the last segment are equal
+///   to the initial and final states specified by `state` respectively.
+///
+/// # Errors
+/// Returns an error if the continuation proof is empty, if any pair of consecutive segments is
+/// not linked correctly, if the boundary states of the proof do not match `state`, or if the
+/// proof of any of the segments fails to verify (see [verify()] for details).
+pub fn verify_continuation<AIR, HashFn, RandCoin, VC, PowFn>(
+    proof: ContinuationProof<AIR::BaseField>,
+    state: ContinuationState<AIR::BaseField>,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    AIR::PublicInputs: From<ContinuationState<AIR::BaseField>>,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    // check the claims linking the segments together before verifying any of the segment proofs
+    let segments = proof.segments();
+    let (first, last) = match (segments.first(), segments.last()) {
+        (Some(first), Some(last)) => (&first.0, &last.0),
+        _ => return Err(VerifierError::EmptyContinuationProof),
+    };
+    if first.initial_state() != state.initial_state() || last.final_state() != state.final_state() {
+        return Err(VerifierError::ContinuationBoundaryMismatch);
+    }
+    if let Some(segment) = proof.find_unlinked_segment() {
+        return Err(VerifierError::ContinuationStateMismatch(segment));
+    }
+
+    for (i, (segment_state, segment_proof)) in proof.into_segments().into_iter().enumerate() {
+        verify::<AIR, HashFn, RandCoin, VC, PowFn>(
+            segment_proof,
+            segment_state.into(),
+            acceptable_options,
+        )
+        .map_err(|err| VerifierError::ContinuationSegmentVerificationFailed(i, err.into()))?;
+    }
+
+    Ok(())
+}
+
+/// Verifies proofs of two chained computations and bundles them into a [ChainedProof].
+///
+/// Specifically, verifies that `first_proof` attests to the correct execution of the computation
+/// specified by `A1` against `first_pub_inputs`, and that `second_proof` attests to the correct
+/// execution of the computation specified by `A2` against public inputs which `output_extractor`
+/// derives from `first_pub_inputs`. Since the second proof is verified against the extracted
+/// public inputs, this also asserts that the extracted output of the first computation matches
+/// the public inputs of the second computation.
+///
+/// The returned proof can be verified via [verify_chained()] without knowing the output of the
+/// first computation.
+///
+/// # Errors
+/// Returns an error if either of the proofs fails to verify (see [verify()] for details).
+pub fn chain<A1, A2, HashFn, RandCoin, VC, PowFn>(
+    first_proof: Proof,
+    first_pub_inputs: A1::PublicInputs,
+    output_extractor: impl Fn(&A1::PublicInputs) -> A2::PublicInputs,
+    second_proof: Proof,
+    acceptable_options: &AcceptableOptions,
+) -> Result<ChainedProof, VerifierError>
+where
+    A1: Air,
+    A1::PublicInputs: Serializable,
+    A2: Air<BaseField = A1::BaseField>,
+    HashFn: ElementHasher<BaseField = A1::BaseField>,
+    RandCoin: RandomCoin<BaseField = A1::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    let chained_proof = ChainedProof::new(first_proof, first_pub_inputs.to_bytes(), second_proof);
+    verify_chained_proofs::<A1, A2, HashFn, RandCoin, VC, PowFn>(
+        chained_proof.clone(),
+        first_pub_inputs,
+        output_extractor,
+        acceptable_options,
+    )?;
+    Ok(chained_proof)
+}
+
+/// Verifies that two chained computations were executed correctly such that the output of the
+/// first computation is the input of the second computation.
+///
+/// Specifically, verifies that the first proof in `proof` attests to the correct execution of the
+/// computation specified by `A1` against the public inputs contained in `proof`, and that the
+/// second proof attests to the correct execution of the computation specified by `A2` against
+/// public inputs which `output_extractor` derives from public inputs of the first computation.
+///
+/// If the verification is successful, public inputs of the first computation are returned. These
+/// are taken from the proof, and thus, the caller is responsible for checking that they describe
+/// the expected input of the pipeline.
+///
+/// # Errors
+/// Returns an error if public inputs of the first computation cannot be deserialized, or if
+/// either of the proofs fails to verify (see [verify()] for details).
+pub fn verify_chained<A1, A2, HashFn, RandCoin, VC, PowFn>(
+    proof: ChainedProof,
+    output_extractor: impl Fn(&A1::PublicInputs) -> A2::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+) -> Result<A1::PublicInputs, VerifierError>
+where
+    A1: Air,
+    A1::PublicInputs: Deserializable + Clone,
+    A2: Air<BaseField = A1::BaseField>,
+    HashFn: ElementHasher<BaseField = A1::BaseField>,
+    RandCoin: RandomCoin<BaseField = A1::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    let first_pub_inputs = A1::PublicInputs::read_from_bytes(proof.first_pub_inputs())
+        .map_err(|err| VerifierError::ChainedPubInputsDeserializationError(err.to_string()))?;
+    verify_chained_proofs::<A1, A2, HashFn, RandCoin, VC, PowFn>(
+        proof,
+        first_pub_inputs.clone(),
+        output_extractor,
+        acceptable_options,
+    )?;
+    Ok(first_pub_inputs)
+}
+
+/// Verifies both proofs of a chained proof against the specified public inputs of the first
+/// computation.
+fn verify_chained_proofs<A1, A2, HashFn, RandCoin, VC, PowFn>(
+    proof: ChainedProof,
+    first_pub_inputs: A1::PublicInputs,
+    output_extractor: impl Fn(&A1::PublicInputs) -> A2::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    A1: Air,
+    A2: Air<BaseField = A1::BaseField>,
+    HashFn: ElementHasher<BaseField = A1::BaseField>,
+    RandCoin: RandomCoin<BaseField = A1::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    // the second proof is verified against the output of the first computation; if the second
+    // proof was generated for any other input, its verification fails
+    let second_pub_inputs = output_extractor(&first_pub_inputs);
+    let (first_proof, _, second_proof) = proof.into_parts();
+
+    verify::<A1, HashFn, RandCoin, VC, PowFn>(first_proof, first_pub_inputs, acceptable_options)
+        .map_err(|err| VerifierError::ChainedProofVerificationFailed(0, err.into()))?;
+    verify::<A2, HashFn, RandCoin, VC, PowFn>(second_proof, second_pub_inputs, acceptable_options)
+        .map_err(|err| VerifierError::ChainedProofVerificationFailed(1, err.into()))
+}
+
+/// Creates a public coin of the specified type and runs the generic proof verification procedure
+/// for the extension field specified by the proof options.
+///
+/// If the verification is successful, the number of unique query positions checked by the
+/// verifier is returned.
+fn verify_with_coin<AIR, HashFn, RandCoin, VC, PowFn>(
+    air: AIR,
+    mut proof: Proof,
+    public_coin_seed: &[AIR::BaseField],
+    context_data: &[u8],
+    check_constraints: bool,
+) -> Result<usize, VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    PowFn: ProofOfWork<HashFn>,
+{
+    // take trace and constraint queries out of the proof; the channel parses them into tables
+    // which may borrow query values directly from the proof bytes
+    let trace_queries = mem::take(&mut proof.trace_queries);
+    let constraint_queries = mem::take(&mut proof.constraint_queries);
+
+    // make sure the base field supports the field extension specified in the proof options
+    if let Err(ProofOptionsError::IncompatibleFieldExtension { degree }) =
+        air.options().validate_field_extension::<AIR::BaseField>()
+    {
+        return Err(VerifierError::UnsupportedFieldExtension(degree as usize));
+    }
+
     // figure out which version of the generic proof verification procedure to run. this is a sort
     // of static dispatch for selecting two generic parameter: extension field and hash function.
     match air.options().field_extension() {
         FieldExtension::None => {
-            let public_coin = RandCoin::new(&public_coin_seed);
-            let channel = VerifierChannel::new(&air, proof)?;
-            perform_verification::<AIR, AIR::BaseField, HashFn, RandCoin, VC>(
+            let public_coin = build_public_coin::<RandCoin>(public_coin_seed, context_data);
+            let channel = VerifierChannel::new(&air, proof, &trace_queries, &constraint_queries)?;
+            perform_verification::<AIR, AIR::BaseField, HashFn, RandCoin, VC, PowFn>(
                 air,
                 channel,
                 public_coin,
+                check_constraints,
             )
         },
         FieldExtension::Quadratic => {
-            if !<QuadExtension<AIR::BaseField>>::is_supported() {
-                return Err(VerifierError::UnsupportedFieldExtension(2));
-            }
-            let public_coin = RandCoin::new(&public_coin_seed);
-            let channel = VerifierChannel::new(&air, proof)?;
-            perform_verification::<AIR, QuadExtension<AIR::BaseField>, HashFn, RandCoin, VC>(
+            let public_coin = build_public_coin::<RandCoin>(public_coin_seed, context_data);
+            let channel = VerifierChannel::new(&air, proof, &trace_queries, &constraint_queries)?;
+            perform_verification::<AIR, QuadExtension<AIR::BaseField>, HashFn, RandCoin, VC, PowFn>(
                 air,
                 channel,
                 public_coin,
+                check_constraints,
             )
         },
         FieldExtension::Cubic => {
-            if !<CubeExtension<AIR::BaseField>>::is_supported() {
-                return Err(VerifierError::UnsupportedFieldExtension(3));
-            }
-            let public_coin = RandCoin::new(&public_coin_seed);
-            let channel = VerifierChannel::new(&air, proof)?;
-            perform_verification::<AIR, CubeExtension<AIR::BaseField>, HashFn, RandCoin, VC>(
+            let public_coin = build_public_coin::<RandCoin>(public_coin_seed, context_data);
+            let channel = VerifierChannel::new(&air, proof, &trace_queries, &constraint_queries)?;
+            perform_verification::<AIR, CubeExtension<AIR::BaseField>, HashFn, RandCoin, VC, PowFn>(
                 air,
                 channel,
                 public_coin,
+                check_constraints,
             )
         },
     }
 }
 
+/// Returns a public coin seeded with the specified seed and reseeded with the hash of the
+/// specified application context data; empty context data is not absorbed into the coin.
+fn build_public_coin<R: RandomCoin>(seed: &[R::BaseField], context_data: &[u8]) -> R {
+    let mut public_coin = R::new(seed);
+    if !context_data.is_empty() {
+        public_coin.reseed_with_label(labels::CONTEXT_DATA, R::Hasher::hash(context_data));
+    }
+    public_coin
+}
+
 // VERIFICATION PROCEDURE
 // ================================================================================================
 /// Performs the actual verification by reading the data from the `channel` and making sure it
 /// attests to a correct execution of the computation specified by the provided `air`.
-fn perform_verification<A, E, H, R, V>(
+///
+/// If the verification is successful, the number of unique query positions checked by the
+/// verifier is returned.
+fn perform_verification<A, E, H, R, V, P>(
     air: A,
-    mut channel: VerifierChannel<E, H, V>,
+    mut channel: VerifierChannel<'_, E, H, V>,
     mut public_coin: R,
-) -> Result<(), VerifierError>
+    check_constraints: bool,
+) -> Result<usize, VerifierError>
 where
     E: FieldElement<BaseField = A::BaseField>,
     A: Air,
     H: ElementHasher<BaseField = A::BaseField>,
     R: RandomCoin<BaseField = A::BaseField, Hasher = H>,
     V: VectorCommitment<H>,
+    P: ProofOfWork<H>,
 {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::info_span!(
+        "verify_proof",
+        trace_length = air.trace_length(),
+        trace_width = air.trace_info().width(),
+        lde_domain_size = air.lde_domain_size(),
+        num_fri_layers = air.options().to_fri_options().num_fri_layers(air.lde_domain_size()),
+        num_queries = air.options().num_queries()
+    )
+    .entered();
+
     // 1 ----- trace commitment -------------------------------------------------------------------
     // Read the commitments to evaluations of the trace polynomials over the LDE domain sent by the
     // prover. The commitments are used to update the public coin, and draw sets of random elements
@@ -171,7 +729,7 @@ where
     let trace_commitments = channel.read_trace_commitments();
 
     // reseed the coin with the commitment to the main trace segment
-    public_coin.reseed(trace_commitments[MAIN_TRACE_IDX]);
+    public_coin.reseed_with_label(labels::MAIN_TRACE_COMMITMENT, trace_commitments[MAIN_TRACE_IDX]);
 
     // process auxiliary trace segments (if any), to build a set of random elements for each segment
     let aux_trace_rand_elements = if air.trace_info().is_multi_segment() {
@@ -193,7 +751,8 @@ where
                 "failed to generate the random elements needed to build the auxiliary trace",
             );
 
-            public_coin.reseed(trace_commitments[AUX_TRACE_IDX]);
+            public_coin
+                .reseed_with_label(labels::AUX_TRACE_COMMITMENT, trace_commitments[AUX_TRACE_IDX]);
 
             Some(AuxRandElements::new_with_gkr(rand_elements, gkr_rand_elements))
         } else {
@@ -201,7 +760,8 @@ where
                 "failed to generate the random elements needed to build the auxiliary trace",
             );
 
-            public_coin.reseed(trace_commitments[AUX_TRACE_IDX]);
+            public_coin
+                .reseed_with_label(labels::AUX_TRACE_COMMITMENT, trace_commitments[AUX_TRACE_IDX]);
 
             Some(AuxRandElements::new(rand_elements))
         }
@@ -221,10 +781,15 @@ where
     // to the prover, and the prover evaluates trace and constraint composition polynomials at z,
     // and sends the results back to the verifier.
     let constraint_commitment = channel.read_constraint_commitment();
-    public_coin.reseed(constraint_commitment);
+    public_coin.reseed_with_label(labels::CONSTRAINT_COMMITMENT, constraint_commitment);
     let z = public_coin.draw::<E>().map_err(|_| VerifierError::RandomCoinError)?;
+    #[cfg(feature = "tracing")]
+    tracing::event!(tracing::Level::DEBUG, %z, "drew out-of-domain point");
 
     // 3 ----- OOD consistency check --------------------------------------------------------------
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!("check_ood_consistency").entered();
+
     // make sure that evaluations obtained by evaluating constraints over the out-of-domain frame
     // are consistent with the evaluations of composition polynomial columns sent by the prover
 
@@ -235,16 +800,18 @@ where
     let ood_main_trace_frame = ood_trace_frame.main_frame();
     let ood_aux_trace_frame = ood_trace_frame.aux_frame();
     let ood_lagrange_kernel_frame = ood_trace_frame.lagrange_kernel_frame();
-    let ood_constraint_evaluation_1 = evaluate_constraints(
-        &air,
-        constraint_coeffs,
-        &ood_main_trace_frame,
-        &ood_aux_trace_frame,
-        ood_lagrange_kernel_frame,
-        aux_trace_rand_elements.as_ref(),
-        z,
-    );
-    public_coin.reseed(ood_trace_frame.hash::<H>());
+    let ood_constraint_evaluation_1 = check_constraints.then(|| {
+        evaluate_constraints(
+            &air,
+            constraint_coeffs,
+            &ood_main_trace_frame,
+            &ood_aux_trace_frame,
+            ood_lagrange_kernel_frame,
+            aux_trace_rand_elements.as_ref(),
+            z,
+        )
+    });
+    public_coin.reseed_with_label(labels::OOD_TRACE_STATES, ood_trace_frame.hash::<H>());
 
     // read evaluations of composition polynomial columns sent by the prover, and reduce them into
     // a single value by computing \sum_{i=0}^{m-1}(z^(i * l) * value_i), where value_i is the
@@ -261,13 +828,19 @@ where
             .fold(E::ZERO, |result, (i, &value)| {
                 result + z.exp_vartime(((i * (air.trace_length())) as u32).into()) * value
             });
-    public_coin.reseed(H::hash_elements(&ood_constraint_evaluations));
+    public_coin.reseed_with_label(
+        labels::OOD_CONSTRAINT_EVALUATIONS,
+        H::hash_elements(&ood_constraint_evaluations),
+    );
 
     // finally, make sure the values are the same
-    if ood_constraint_evaluation_1 != ood_constraint_evaluation_2 {
+    if ood_constraint_evaluation_1.is_some_and(|value| value != ood_constraint_evaluation_2) {
         return Err(VerifierError::InconsistentOodConstraintEvaluations);
     }
 
+    #[cfg(feature = "tracing")]
+    drop(span);
+
     // 4 ----- FRI commitments --------------------------------------------------------------------
     // draw coefficients for computing DEEP composition polynomial from the public coin; in the
     // interactive version of the protocol, the verifier sends these coefficients to the prover
@@ -282,6 +855,8 @@ where
     // The verifier uses these commitments to update the public coin and draw random points alpha
     // from them; in the interactive version of the protocol, the verifier sends these alphas to
     // the prover, and the prover uses them to compute and commit to the subsequent FRI layers.
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!("read_fri_commitments").entered();
     let fri_verifier = FriVerifier::new(
         &mut channel,
         &mut public_coin,
@@ -290,15 +865,24 @@ where
     )
     .map_err(VerifierError::FriVerificationFailed)?;
     // TODO: make sure air.lde_domain_size() == fri_verifier.domain_size()
+    #[cfg(feature = "tracing")]
+    drop(span);
 
     // 5 ----- trace and constraint queries -------------------------------------------------------
     // read proof-of-work nonce sent by the prover
     let pow_nonce = channel.read_pow_nonce();
 
     // make sure the proof-of-work specified by the grinding factor is satisfied
-    if public_coin.check_leading_zeros(pow_nonce) < air.options().grinding_factor() {
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!("verify_grinding_nonce").entered();
+    let grinding_factor = air.options().grinding_factor();
+    if !P::verify_coin_nonce(&public_coin, pow_nonce, grinding_factor) {
         return Err(VerifierError::QuerySeedProofOfWorkVerificationFailed);
     }
+    #[cfg(feature = "tracing")]
+    tracing::event!(tracing::Level::DEBUG, pow_nonce, grinding_factor, "verified grinding nonce");
+    #[cfg(feature = "tracing")]
+    drop(span);
 
     // draw pseudo-random query positions for the LDE domain from the public coin; in the
     // interactive version of the protocol, the verifier sends these query positions to the prover,
@@ -315,11 +899,18 @@ where
 
     // read evaluations of trace and constraint composition polynomials at the queried positions;
     // this also checks that the read values are valid against trace and constraint commitments
+    #[cfg(feature = "tracing")]
+    let span =
+        tracing::info_span!("read_queried_states", num_queries = query_positions.len()).entered();
     let (queried_main_trace_states, queried_aux_trace_states) =
         channel.read_queried_trace_states(&query_positions)?;
     let queried_constraint_evaluations = channel.read_constraint_evaluations(&query_positions)?;
+    #[cfg(feature = "tracing")]
+    drop(span);
 
     // 6 ----- DEEP composition -------------------------------------------------------------------
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!("compose_deep_evaluations").entered();
     // compute evaluations of the DEEP composition polynomial at the queried positions
     let composer = DeepComposer::new(&air, &query_positions, z, deep_coefficients);
     let t_composition = composer.compose_trace_columns(
@@ -332,13 +923,19 @@ where
     let c_composition = composer
         .compose_constraint_evaluations(queried_constraint_evaluations, ood_constraint_evaluations);
     let deep_evaluations = composer.combine_compositions(t_composition, c_composition);
+    #[cfg(feature = "tracing")]
+    drop(span);
 
     // 7 ----- Verify low-degree proof -------------------------------------------------------------
     // make sure that evaluations of the DEEP composition polynomial we computed in the previous
     // step are in fact evaluations of a polynomial of degree equal to trace polynomial degree
+    #[cfg(feature = "tracing")]
+    let _span = tracing::info_span!("verify_fri_proof").entered();
     fri_verifier
         .verify(&mut channel, &deep_evaluations, &query_positions)
-        .map_err(VerifierError::FriVerificationFailed)
+        .map_err(VerifierError::FriVerificationFailed)?;
+
+    Ok(query_positions.len())
 }
 
 // ACCEPTABLE OPTIONS
@@ -354,6 +951,15 @@ pub enum AcceptableOptions {
     OptionSet(Vec<ProofOptions>),
 }
 
+impl Default for AcceptableOptions {
+    /// Returns acceptable options which require proofs to have conjectured security of at least
+    /// [ProofOptions::SECURITY_FLOOR] bits; this is the same level below which the prover refuses
+    /// to generate proofs by default.
+    fn default() -> Self {
+        Self::MinConjecturedSecurity(ProofOptions::SECURITY_FLOOR)
+    }
+}
+
 impl AcceptableOptions {
     /// Checks that a proof was generated using an acceptable set of parameters.
     pub fn validate<H: Hasher>(&self, proof: &Proof) -> Result<(), VerifierError> {
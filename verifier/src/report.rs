@@ -0,0 +1,76 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::time::Duration;
+
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// VERIFICATION REPORT
+// ================================================================================================
+
+/// Summary of a successful proof verification.
+///
+/// A report is returned by [verify_with_report()](crate::verify_with_report) for every accepted
+/// proof, and describes the parameters the proof was actually verified with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VerificationReport {
+    /// Conjectured security level (in bits) of the proof, computed from the proof context in the
+    /// same way as by [Proof::security_level()](crate::Proof::security_level).
+    pub conjectured_security: u32,
+    /// Number of unique query positions checked by the verifier. This can be smaller than the
+    /// number of queries specified by the proof options if some of the drawn positions were the
+    /// same.
+    pub num_unique_queries: usize,
+    /// Number of FRI layers checked by the verifier, excluding the remainder.
+    pub num_fri_layers: usize,
+    /// Time it took to verify the proof; this is available only when the `std` feature is
+    /// enabled, and the target is not `wasm32-unknown-unknown` (which has no system clock).
+    pub verification_time: Option<Duration>,
+}
+
+impl Serializable for VerificationReport {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.conjectured_security);
+        target.write_usize(self.num_unique_queries);
+        target.write_usize(self.num_fri_layers);
+        match self.verification_time {
+            Some(time) => {
+                target.write_bool(true);
+                target.write_u64(time.as_secs());
+                target.write_u32(time.subsec_nanos());
+            },
+            None => target.write_bool(false),
+        }
+    }
+}
+
+impl Deserializable for VerificationReport {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let conjectured_security = source.read_u32()?;
+        let num_unique_queries = source.read_usize()?;
+        let num_fri_layers = source.read_usize()?;
+        let verification_time = if source.read_bool()? {
+            let secs = source.read_u64()?;
+            let nanos = source.read_u32()?;
+            if nanos >= 1_000_000_000 {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "sub-second part of the verification time must be smaller than 10^9 \
+                    nanoseconds, but was {nanos}"
+                )));
+            }
+            Some(Duration::new(secs, nanos))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            conjectured_security,
+            num_unique_queries,
+            num_fri_layers,
+            verification_time,
+        })
+    }
+}
@@ -39,6 +39,18 @@
 //! machine). The number of threads can be configured via `RAYON_NUM_THREADS` environment
 //! variable.
 //!
+//! To find out which phase of proof generation dominates the proving time, compile the crate
+//! with the `profile` feature enabled and generate proofs via `ProfilingProver`; this returns a
+//! `ProvingProfile` with the time spent in each phase together with the proof.
+//!
+//! When the crate is compiled with the `tracing` feature enabled, the prover and the verifier
+//! emit [tracing](https://docs.rs/tracing) spans for each phase of proof generation and
+//! verification, as well as for each FRI layer. The spans of the entire proof generation and
+//! verification record the trace length and width, the LDE domain size, and the number of FRI
+//! layers. Key events, such as drawing the out-of-domain point and finding (or verifying) the
+//! proof-of-work nonce, are emitted at the DEBUG level. Without this feature, the `tracing` crate
+//! is not a dependency of the prover or the verifier.
+//!
 //! ## Prof verification
 //! To verify a [Proof] generated as described in the previous sections, you'll need to
 //! do the following:
@@ -258,7 +270,7 @@
 //!
 //! ```no_run
 //! use winterfell::{
-//!     crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree},
+//!     crypto::{hashers::Blake3_256, DefaultRandomCoin, HashPow, MerkleTree},
 //!     math::{fields::f128::BaseElement, FieldElement, ToElements},
 //!     matrix::ColMatrix,
 //!     CompositionPoly, CompositionPolyTrace, DefaultConstraintCommitment,
@@ -351,6 +363,7 @@
 //!     type HashFn = Blake3_256<Self::BaseField>;
 //!     type VC = MerkleTree<Self::HashFn>;
 //!     type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+//!     type ProofOfWork = HashPow;
 //!     type TraceLde<E: FieldElement<BaseField = Self::BaseField>> = DefaultTraceLde<E, Self::HashFn, Self::VC>;
 //!     type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
 //!         DefaultConstraintCommitment<E, Self::HashFn, Self::VC>;
@@ -415,7 +428,7 @@
 //!
 //! ```
 //! # use winterfell::{
-//! #    crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree},
+//! #    crypto::{hashers::Blake3_256, DefaultRandomCoin, HashPow, MerkleTree},
 //! #    math::{fields::f128::BaseElement, FieldElement, ToElements},
 //! #    matrix::ColMatrix,
 //! #    Air, AirContext, Assertion, AuxRandElements, ByteWriter, CompositionPoly, CompositionPolyTrace,
@@ -513,6 +526,7 @@
 //! #    type HashFn = Blake3_256<Self::BaseField>;
 //! #    type VC = MerkleTree<Self::HashFn>;
 //! #    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+//! #    type ProofOfWork = HashPow;
 //! #    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> = DefaultTraceLde<E, Self::HashFn, Self::VC>;
 //! #    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
 //! #        DefaultConstraintCommitment<E, Self::HashFn, Self::VC>;
@@ -600,7 +614,8 @@
 //! assert!(winterfell::verify::<WorkAir,
 //!                              Blake3_256<BaseElement>,
 //!                              DefaultRandomCoin<Blake3_256<BaseElement>>,
-//!                              MerkleTree<Blake3_256<BaseElement>>
+//!                              MerkleTree<Blake3_256<BaseElement>>,
+//!                              HashPow
 //!                             >(proof, pub_inputs, &min_opts).is_ok());
 //! ```
 //!
@@ -632,17 +647,40 @@
 #[cfg(test)]
 extern crate std;
 
-pub use air::{AuxRandElements, GkrVerifier, PartitionOptions};
+pub use air::{
+    circuit_compiler, interop, AuxRandElements, FieldDescriptor, GkrVerifier, HashFunction,
+    PartitionOptions, PowScheme, ProofOptionsError, ProofSizeBreakdown, ProofSizeOptimizer,
+};
 pub use prover::{
-    crypto, iterators, math, matrix, Air, AirContext, Assertion, AuxTraceWithMetadata,
-    BoundaryConstraint, BoundaryConstraintGroup, CompositionPoly, CompositionPolyTrace,
+    constraint, crypto, iterators, math, matrix,
+    proof::{
+        estimate_security, AirMetadata, AnnotatedProof, ChainedProof, ContinuationProof,
+        ContinuationState,
+    },
+    validate_trace, Air, AirContext, Assertion, AuxTraceWithMetadata, BoundaryConstraint,
+    BoundaryConstraintGroup, ChunkedTraceLde, CompositionPoly, CompositionPolyTrace,
     ConstraintCompositionCoefficients, ConstraintDivisor, ConstraintEvaluator,
+    ConstraintExpression, ConstraintType, ConstraintViolation, ContinuationProver,
     DeepCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
-    DefaultTraceLde, EvaluationFrame, FieldExtension, Proof, ProofOptions, Prover, ProverError,
-    ProverGkrProof, StarkDomain, Trace, TraceInfo, TraceLde, TracePolyTable, TraceTable,
-    TraceTableFragment, TransitionConstraintDegree,
+    DefaultTraceLde, DynamicTraceTable, EvaluationFrame, FieldExtension, FriFoldingFactor,
+    ProgressListener, Proof, ProofOptions, Prover, ProverDebugTrace, ProverError, ProverGkrProof,
+    ProvingDebugTrace, ProvingPhase, RowRef, StarkDomain, Trace, TraceInfo, TraceIterator,
+    TraceLde, TracePadding, TracePolyTable, TraceSegmentRows, TraceSegmentView, TraceTable,
+    TraceTableFragment, TraceValidationError, TranscriptEvent, TransitionConstraintDegree,
+    TransitionExemption,
+};
+#[cfg(feature = "profile")]
+pub use prover::{ProfilingProver, ProvingProfile};
+#[cfg(feature = "std")]
+pub use prover::{StderrProgressListener, TraceError};
+pub use verifier::{
+    chain, verify, verify_chained, verify_continuation, verify_older_version,
+    verify_self_contained, verify_with_context_data, verify_with_report, AcceptableOptions,
+    ByteWriter, DifferentialResult, DifferentialVerifier, ReferenceOutcome, VerificationPhase,
+    VerificationReport, VerifierError,
 };
-pub use verifier::{verify, AcceptableOptions, ByteWriter, VerifierError};
+#[cfg(feature = "std")]
+pub use verifier::{verify_from_file, ProofReader, ProofWriter};
 
 #[cfg(test)]
 mod tests;
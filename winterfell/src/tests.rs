@@ -3,16 +3,34 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use std::{vec, vec::Vec};
+use std::{
+    boxed::Box,
+    marker::PhantomData,
+    string::ToString,
+    sync::{Arc, Mutex},
+    vec,
+    vec::Vec,
+};
 
-use air::{GkrRandElements, LagrangeKernelRandElements};
-use crypto::MerkleTree;
+use air::{
+    circuit_compiler::{Circuit, CircuitAir, CircuitInputs},
+    interop::{R1csAir, R1csInputs, R1csInstance},
+    proof::{Context, LEGACY_PROOF_VERSION, PROOF_VERSION, UNLABELED_PROOF_VERSION},
+    GkrRandElements, LagrangeKernelRandElements,
+};
+use crypto::{Blake3Pow, HashPow, MerkleTree, ProofOfWork};
 use prover::{
-    crypto::{hashers::Blake3_256, DefaultRandomCoin, RandomCoin},
-    math::{fields::f64::BaseElement, ExtensionOf, FieldElement},
+    crypto::{
+        hashers::Blake3_256, DefaultRandomCoin, LegacyRandomCoin, RandomCoin, UnlabeledRandomCoin,
+    },
+    math::{
+        fields::{f64::BaseElement, QuadExtension},
+        ExtensionOf, FieldElement, StarkField, ToElements,
+    },
     matrix::ColMatrix,
     CompositionPoly, DefaultConstraintCommitment,
 };
+use verifier::{Deserializable, Serializable};
 
 use super::*;
 
@@ -31,6 +49,7 @@ fn test_complex_lagrange_kernel_air() {
         Blake3_256<BaseElement>,
         DefaultRandomCoin<Blake3_256<BaseElement>>,
         MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
     >(proof, (), &AcceptableOptions::MinConjecturedSecurity(0))
     .unwrap()
 }
@@ -206,7 +225,7 @@ impl LagrangeComplexProver {
     fn new(aux_trace_width: usize) -> Self {
         Self {
             aux_trace_width,
-            options: ProofOptions::new(1, 2, 0, FieldExtension::None, 2, 1),
+            options: ProofOptions::new(8, 2, 0, FieldExtension::None, 2, 1),
         }
     }
 }
@@ -218,6 +237,7 @@ impl Prover for LagrangeComplexProver {
     type HashFn = Blake3_256<BaseElement>;
     type VC = MerkleTree<Blake3_256<BaseElement>>;
     type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type ProofOfWork = HashPow;
     type TraceLde<E: FieldElement<BaseField = BaseElement>> =
         DefaultTraceLde<E, Self::HashFn, Self::VC>;
     type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
@@ -232,6 +252,10 @@ impl Prover for LagrangeComplexProver {
         &self.options
     }
 
+    fn allow_insecure(&self) -> bool {
+        true
+    }
+
     fn new_trace_lde<E>(
         &self,
         trace_info: &TraceInfo,
@@ -280,16 +304,9 @@ impl Prover for LagrangeComplexProver {
     where
         E: FieldElement<BaseField = Self::BaseField>,
     {
-        let main_trace = main_trace.main_segment();
-        let log_trace_len = main_trace.num_rows().ilog2() as usize;
-        let lagrange_kernel_rand_elements = {
-            let mut rand_elements = Vec::with_capacity(log_trace_len);
-            for _ in 0..log_trace_len {
-                rand_elements.push(public_coin.draw().unwrap());
-            }
-
-            LagrangeKernelRandElements::new(rand_elements)
-        };
+        let log_trace_len = main_trace.length().ilog2() as usize;
+        let lagrange_kernel_rand_elements =
+            draw_lagrange_kernel_rand_elements(public_coin, log_trace_len);
 
         (log_trace_len, GkrRandElements::new(lagrange_kernel_rand_elements, Vec::new()))
     }
@@ -323,26 +340,2761 @@ impl Prover for LagrangeComplexProver {
         }
 
         // then build the Lagrange kernel column
-        {
-            let r = &lagrange_kernel_rand_elements;
-
-            let mut lagrange_col = Vec::with_capacity(main_trace.num_rows());
-
-            for row_idx in 0..main_trace.num_rows() {
-                let mut row_value = E::ONE;
-                for (bit_idx, &r_i) in r.iter().enumerate() {
-                    if row_idx & (1 << bit_idx) == 0 {
-                        row_value *= E::ONE - r_i;
-                    } else {
-                        row_value *= r_i;
-                    }
-                }
-                lagrange_col.push(row_value);
+        columns.push(build_lagrange_kernel_column(
+            lagrange_kernel_rand_elements,
+            main_trace.num_rows(),
+        ));
+
+        ColMatrix::new(columns)
+    }
+}
+
+/// Draws random elements for a Lagrange kernel column of a trace of length 2^`log_trace_len`
+/// from the specified public coin.
+fn draw_lagrange_kernel_rand_elements<E, R>(
+    public_coin: &mut R,
+    log_trace_len: usize,
+) -> LagrangeKernelRandElements<E>
+where
+    E: FieldElement<BaseField = BaseElement>,
+    R: RandomCoin<BaseField = BaseElement>,
+{
+    let mut rand_elements = Vec::with_capacity(log_trace_len);
+    for _ in 0..log_trace_len {
+        rand_elements.push(public_coin.draw().unwrap());
+    }
+
+    LagrangeKernelRandElements::new(rand_elements)
+}
+
+/// Returns a Lagrange kernel column of the specified length for the specified random elements.
+fn build_lagrange_kernel_column<E: FieldElement>(r: &[E], num_rows: usize) -> Vec<E> {
+    let mut lagrange_col = Vec::with_capacity(num_rows);
+
+    for row_idx in 0..num_rows {
+        let mut row_value = E::ONE;
+        for (bit_idx, &r_i) in r.iter().enumerate() {
+            if row_idx & (1 << bit_idx) == 0 {
+                row_value *= E::ONE - r_i;
+            } else {
+                row_value *= r_i;
             }
+        }
+        lagrange_col.push(row_value);
+    }
+
+    lagrange_col
+}
+
+// CONTINUATIONS
+// ================================================================================================
+
+const SEGMENT_LENGTH: usize = 16;
+
+#[test]
+fn test_continuation_proof() {
+    let (segments, states) = build_fib_segments([BaseElement::ONE, BaseElement::ONE], 4);
+
+    let mut prover = ContinuationProver::new(fib_segment_prover(), SEGMENT_LENGTH);
+    for (trace, state) in segments.into_iter().zip(states) {
+        prover.add_segment(trace, state).unwrap();
+    }
+    assert_eq!(4, prover.num_segments());
+    let proof = prover.finalize();
+
+    let first = proof.segments().first().unwrap().0.initial_state().to_vec();
+    let last = proof.segments().last().unwrap().0.final_state().to_vec();
+    let boundary = ContinuationState::new(first.clone(), last.clone());
+
+    // serialization round trip
+    let proof = ContinuationProof::from_bytes(&proof.to_bytes()).unwrap();
+    verify_fib_continuation(proof.clone(), boundary).unwrap();
+
+    // verification fails if the boundary of the computation is not the expected one
+    let wrong_boundary = ContinuationState::new(first, vec![last[1], last[0]]);
+    assert!(matches!(
+        verify_fib_continuation(proof.clone(), wrong_boundary),
+        Err(VerifierError::ContinuationBoundaryMismatch)
+    ));
+
+    // verification fails if the segments are not linked
+    let mut segments = proof.into_segments();
+    segments.swap(1, 2);
+    let first = segments.first().unwrap().0.initial_state().to_vec();
+    let last = segments.last().unwrap().0.final_state().to_vec();
+    assert!(matches!(
+        verify_fib_continuation(
+            ContinuationProof::new(segments),
+            ContinuationState::new(first, last)
+        ),
+        Err(VerifierError::ContinuationStateMismatch(0))
+    ));
+
+    // verification fails for an empty proof
+    assert!(matches!(
+        verify_fib_continuation(
+            ContinuationProof::new(Vec::new()),
+            ContinuationState::new(vec![BaseElement::ONE; 2], vec![BaseElement::ONE; 2])
+        ),
+        Err(VerifierError::EmptyContinuationProof)
+    ));
+}
+
+#[test]
+fn test_continuation_prover_errors() {
+    let (mut segments, mut states) = build_fib_segments([BaseElement::ONE, BaseElement::ONE], 2);
+    let mut prover = ContinuationProver::new(fib_segment_prover(), SEGMENT_LENGTH);
+
+    // segment of a wrong length
+    let short_trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH / 2);
+    let short_state = states[0].clone();
+    assert!(matches!(
+        prover.add_segment(short_trace, short_state),
+        Err(ProverError::ContinuationSegmentLengthMismatch { expected: SEGMENT_LENGTH, actual: 8 })
+    ));
+
+    // state which does not describe the trace
+    let (trace, state) = (segments.remove(0), states.remove(0));
+    let wrong_state =
+        ContinuationState::new(state.initial_state().to_vec(), state.initial_state().to_vec());
+    assert!(matches!(
+        prover.add_segment(trace.clone(), wrong_state),
+        Err(ProverError::ContinuationPublicInputsMismatch(0))
+    ));
+    prover.add_segment(trace, state).unwrap();
+
+    // segment which does not start where the previous one ended
+    let (unlinked_trace, unlinked_states) =
+        build_fib_segments([BaseElement::new(2), BaseElement::new(3)], 1);
+    assert!(matches!(
+        prover.add_segment(
+            unlinked_trace.into_iter().next().unwrap(),
+            unlinked_states.into_iter().next().unwrap()
+        ),
+        Err(ProverError::ContinuationStateMismatch(1))
+    ));
+
+    prover.add_segment(segments.remove(0), states.remove(0)).unwrap();
+    assert_eq!(2, prover.finalize().num_segments());
+}
+
+// PROVER REUSE
+// ================================================================================================
+
+#[test]
+fn test_prover_reuse() {
+    // a single prover instance generates proofs for multiple traces of the same AIR
+    let prover = fib_segment_prover();
+    let first_trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+    let second_trace = build_fib_trace([BaseElement::new(3), BaseElement::new(5)], SEGMENT_LENGTH);
+    let first_inputs = prover.get_pub_inputs(&first_trace);
+    let second_inputs = prover.get_pub_inputs(&second_trace);
+
+    let first_proof = prover.prove(first_trace).unwrap();
+    let second_proof = prover.prove(second_trace).unwrap();
+
+    assert_eq!(Ok(()), verify_fib_segment(first_proof.clone(), first_inputs.clone()));
+    assert_eq!(Ok(()), verify_fib_segment(second_proof.clone(), second_inputs.clone()));
+
+    // proofs are bound to the public inputs of their own traces
+    assert!(verify_fib_segment(first_proof, second_inputs).is_err());
+    assert!(verify_fib_segment(second_proof, first_inputs).is_err());
+}
+
+fn verify_fib_segment(
+    proof: Proof,
+    pub_inputs: ContinuationState<BaseElement>,
+) -> Result<(), VerifierError> {
+    verify::<
+        FibSegmentAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, pub_inputs, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+// PROVER DEBUG MODE
+// ================================================================================================
+
+#[test]
+fn test_prove_debug() {
+    type E = QuadExtension<BaseElement>;
+
+    let prover = fib_segment_prover();
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let (proof, debug_trace) = prover.prove_debug(trace.clone()).unwrap();
+
+    // the proof is the same as the proof generated in regular mode, and it is valid
+    assert_eq!(prover.prove(trace).unwrap().to_bytes(), proof.to_bytes());
+    assert_eq!(Ok(()), verify_fib_segment(proof.clone(), pub_inputs));
+
+    let num_fri_layers = prover.options().to_fri_options().num_fri_layers(8 * SEGMENT_LENGTH);
+    assert_eq!(1, debug_trace.trace_roots().len());
+    assert!(debug_trace.constraint_root().is_some());
+    assert_eq!(num_fri_layers + 1, debug_trace.fri_layer_roots().len());
+    assert_eq!(num_fri_layers, debug_trace.fri_layers().len());
+    assert_eq!(2, debug_trace.ood_point().len());
 
-            columns.push(lagrange_col);
+    // replaying the transcript with a fresh public coin reproduces all drawn challenges
+    let mut coin = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(debug_trace.coin_seed());
+    for event in debug_trace.events() {
+        match event {
+            TranscriptEvent::Reseed { domain_label, digest, .. } => {
+                coin.reseed_with_label(domain_label, *digest)
+            },
+            TranscriptEvent::Draw { values, .. } => {
+                let drawn: Vec<E> = (0..values.len() / 2).map(|_| coin.draw().unwrap()).collect();
+                assert_eq!(values.as_slice(), E::slice_as_base_elements(&drawn));
+            },
+            TranscriptEvent::Grind { nonce } => assert_eq!(proof.pow_nonce, *nonce),
+            TranscriptEvent::DrawQueryPositions { positions } => {
+                let mut drawn = coin
+                    .draw_integers(
+                        prover.options().num_queries(),
+                        8 * SEGMENT_LENGTH,
+                        proof.pow_nonce,
+                    )
+                    .unwrap();
+                drawn.sort_unstable();
+                drawn.dedup();
+                assert_eq!(positions, &drawn);
+            },
         }
+    }
+    assert_eq!(proof.num_unique_queries as usize, debug_trace.query_positions().unwrap().len());
 
-        ColMatrix::new(columns)
+    // transcripts of different traces diverge at the first commitment
+    let other_trace = build_fib_trace([BaseElement::new(3), BaseElement::new(5)], SEGMENT_LENGTH);
+    let (_, other_debug_trace) = prover.prove_debug(other_trace).unwrap();
+    assert_eq!(None, debug_trace.first_divergence(&debug_trace.clone()));
+    assert_eq!(Some(0), debug_trace.first_divergence(&other_debug_trace));
+}
+
+// PROVING PROFILE
+// ================================================================================================
+
+#[cfg(feature = "profile")]
+#[test]
+fn test_profiling_prover() {
+    let prover = ProfilingProver::new(fib_segment_prover());
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+    let pub_inputs = prover.inner().get_pub_inputs(&trace);
+    let (proof, profile) = prover.prove(trace.clone()).unwrap();
+
+    // profiling does not affect the generated proof
+    assert_eq!(prover.inner().prove(trace).unwrap().to_bytes(), proof.to_bytes());
+    assert_eq!(Ok(()), verify_fib_segment(proof, pub_inputs));
+
+    let phases = [
+        profile.trace_commit,
+        profile.aux_trace_commit,
+        profile.constraint_eval,
+        profile.constraint_commit,
+        profile.deep_composition,
+        profile.fri,
+        profile.pow,
+        profile.queries,
+    ];
+    assert!(profile.total >= phases.iter().sum());
+    assert!(profile.trace_commit > core::time::Duration::ZERO);
+}
+
+// PROGRESS REPORTING
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ProgressEvent {
+    PhaseStart(ProvingPhase),
+    PhaseEnd(ProvingPhase),
+    FriLayer(usize, usize),
+}
+
+#[derive(Default)]
+struct RecordingListener(Arc<Mutex<Vec<ProgressEvent>>>);
+
+impl ProgressListener for RecordingListener {
+    fn on_phase_start(&self, phase: ProvingPhase) {
+        self.0.lock().unwrap().push(ProgressEvent::PhaseStart(phase));
+    }
+
+    fn on_phase_end(&self, phase: ProvingPhase, _elapsed_ms: u64) {
+        self.0.lock().unwrap().push(ProgressEvent::PhaseEnd(phase));
+    }
+
+    fn on_fri_layer(&self, layer: usize, total_layers: usize) {
+        self.0.lock().unwrap().push(ProgressEvent::FriLayer(layer, total_layers));
+    }
+}
+
+#[test]
+fn test_prove_with_progress() {
+    let prover = fib_segment_prover();
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+    let listener = RecordingListener::default();
+    let events = listener.0.clone();
+    let proof = prover.prove_with_progress(trace.clone(), Some(Box::new(listener))).unwrap();
+
+    // reporting progress does not affect the generated proof
+    assert_eq!(prover.prove(trace.clone()).unwrap().to_bytes(), proof.to_bytes());
+    assert_eq!(proof.to_bytes(), prover.prove_with_progress(trace, None).unwrap().to_bytes());
+
+    // all phases are reported in order, and FRI layers are reported within the FRI phase
+    let num_fri_layers = prover.options().to_fri_options().num_fri_layers(8 * SEGMENT_LENGTH);
+    let mut expected = Vec::new();
+    let mut phase = Some(ProvingPhase::TraceCommit);
+    while let Some(current) = phase {
+        expected.push(ProgressEvent::PhaseStart(current));
+        if current == ProvingPhase::Fri {
+            expected
+                .extend((0..num_fri_layers).map(|i| ProgressEvent::FriLayer(i, num_fri_layers)));
+        }
+        expected.push(ProgressEvent::PhaseEnd(current));
+        phase = current.next();
+    }
+    assert_eq!(expected, *events.lock().unwrap());
+}
+
+// TRACE FILES
+// ================================================================================================
+
+#[test]
+fn test_prove_trace_from_file() {
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+    let path =
+        std::env::temp_dir().join(std::format!("winterfell_{}_trace.bin", std::process::id()));
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(&path).unwrap());
+    trace.write_to(&mut writer).unwrap();
+    drop(writer);
+
+    // the reloaded trace can be proved with different proof options
+    let mut reader = std::io::BufReader::new(std::fs::File::open(&path).unwrap());
+    let reloaded = TraceTable::<BaseElement>::read_from(&mut reader).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    for extension in [FieldExtension::None, FieldExtension::Quadratic] {
+        let prover = fib_segment_prover().with_options(ProofOptions::new(4, 8, 0, extension, 2, 1));
+        let pub_inputs = prover.get_pub_inputs(&reloaded);
+        assert_eq!(prover.get_pub_inputs(&trace), pub_inputs);
+        let proof = prover.prove(reloaded.clone()).unwrap();
+        assert_eq!(Ok(()), verify_fib_segment(proof, pub_inputs));
+    }
+}
+
+// CHUNKED TRACE COMMITMENT
+// ================================================================================================
+
+#[test]
+fn test_chunked_trace_commitment() {
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+    for extension in [FieldExtension::None, FieldExtension::Quadratic] {
+        let options = ProofOptions::new(28, 8, 0, extension, 2, 1);
+        let prover = fib_segment_prover().with_options(options.clone());
+        let chunked_prover = chunked_fib_segment_prover().with_options(options);
+        let pub_inputs = prover.get_pub_inputs(&trace);
+
+        // proofs generated with and without the full trace LDE in memory are identical
+        let proof = prover.prove(trace.clone()).unwrap();
+        let chunked_proof = chunked_prover.prove(trace.clone()).unwrap();
+        assert_eq!(proof.commitments, chunked_proof.commitments);
+        assert_eq!(proof, chunked_proof);
+        assert_eq!(Ok(()), verify_fib_segment(chunked_proof, pub_inputs));
+    }
+}
+
+// TRACING
+// ================================================================================================
+
+#[cfg(feature = "tracing")]
+#[test]
+fn test_tracing_spans() {
+    let collector = SpanCollector::default();
+    let spans = collector.spans.clone();
+
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], 1 << 6);
+    let lde_domain_size = trace.length() * 8;
+    let prover = fib_segment_prover();
+    let num_fri_layers = prover.options().to_fri_options().num_fri_layers(lde_domain_size);
+    tracing::subscriber::with_default(collector, || {
+        let pub_inputs = prover.get_pub_inputs(&trace);
+        let proof = prover.prove(trace).unwrap();
+        assert_eq!(Ok(()), verify_fib_segment(proof, pub_inputs));
+    });
+
+    // spans of individual FRI layers are emitted once per layer
+    let spans = spans.lock().unwrap();
+    let count = |name: &str| spans.iter().filter(|span| span.name == name).count();
+    assert_eq!(num_fri_layers, count("build_fri_layer"));
+    assert_eq!(num_fri_layers, count("verify_fri_layer"));
+
+    // all stages of proof generation and verification are emitted in order
+    let stages: Vec<&str> = spans
+        .iter()
+        .map(|span| span.name)
+        .filter(|&name| !matches!(name, "build_fri_layer" | "verify_fri_layer"))
+        .collect();
+    let expected = [
+        "generate_proof",
+        "build_domain",
+        "commit_to_main_trace_segment",
+        "extend_execution_trace",
+        "compute_execution_trace_commitment",
+        "evaluate_constraints",
+        "commit_to_constraint_evaluations",
+        "build_composition_poly_columns",
+        "evaluate_composition_poly_columns",
+        "compute_constraint_evaluation_commitment",
+        "build_deep_composition_poly",
+        "evaluate_ood_frame",
+        "evaluate_deep_composition_poly",
+        "compute_fri_layers",
+        "build_fri_remainder",
+        "determine_query_positions",
+        "grind_query_seed",
+        "build_proof_object",
+        "verify_proof",
+        "check_ood_consistency",
+        "read_fri_commitments",
+        "verify_grinding_nonce",
+        "read_queried_states",
+        "compose_deep_evaluations",
+        "verify_fri_proof",
+    ];
+    assert_eq!(expected.as_slice(), stages.as_slice());
+
+    // spans of the entire proof generation and verification describe the computation
+    for name in ["generate_proof", "verify_proof"] {
+        let span = spans.iter().find(|span| span.name == name).unwrap();
+        for field in ["trace_length", "trace_width", "lde_domain_size", "num_fri_layers"] {
+            assert!(span.fields.contains(&field), "{name} does not record {field}");
+        }
+    }
+}
+
+/// A subscriber which records the names and the fields of all spans in the order in which the
+/// spans are created.
+#[cfg(feature = "tracing")]
+#[derive(Default)]
+struct SpanCollector {
+    spans: Arc<Mutex<Vec<CollectedSpan>>>,
+}
+
+#[cfg(feature = "tracing")]
+struct CollectedSpan {
+    name: &'static str,
+    fields: Vec<&'static str>,
+}
+
+#[cfg(feature = "tracing")]
+impl tracing::field::Visit for CollectedSpan {
+    fn record_debug(&mut self, field: &tracing::field::Field, _value: &dyn core::fmt::Debug) {
+        self.fields.push(field.name());
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl tracing::Subscriber for SpanCollector {
+    fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        let mut span = CollectedSpan {
+            name: attrs.metadata().name(),
+            fields: Vec::new(),
+        };
+        attrs.record(&mut span);
+
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(span);
+        tracing::span::Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, id: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        values.record(&mut spans[id.into_u64() as usize - 1]);
+    }
+
+    fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+    fn event(&self, _event: &tracing::Event<'_>) {}
+
+    fn enter(&self, _span: &tracing::span::Id) {}
+
+    fn exit(&self, _span: &tracing::span::Id) {}
+}
+
+// PROOF SIZE ESTIMATION
+// ================================================================================================
+
+#[test]
+fn test_proof_size_optimizer() {
+    let trace_length = 1 << 12;
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], trace_length);
+    let optimizer = ProofSizeOptimizer::new(
+        96,
+        HashFunction::Blake3_256,
+        FieldDescriptor::of::<BaseElement>(),
+        trace.info().clone(),
+    );
+    let (options, breakdown) = optimizer.minimize_size().unwrap();
+
+    // the estimated size is close to the size of an actual proof generated with these options
+    let prover = fib_segment_prover().with_options(options);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+    let proof_size = proof.to_bytes().len();
+    assert!(breakdown.total().abs_diff(proof_size) * 10 < proof_size);
+
+    assert!(proof.security_level::<Blake3_256<BaseElement>>(true) >= 96);
+    assert_eq!(Ok(()), verify_fib_segment(proof, pub_inputs));
+}
+
+// VERIFICATION FROM FILE
+// ================================================================================================
+
+#[test]
+fn test_verify_from_file() {
+    let prover = fib_segment_prover();
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+
+    let path =
+        std::env::temp_dir().join(std::format!("winterfell_{}_proof.bin", std::process::id()));
+    ProofWriter::write(&proof, &path).unwrap();
+
+    let verify_file = |pub_inputs| {
+        verify_from_file::<
+            FibSegmentAir,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+            HashPow,
+        >(&path, pub_inputs, &AcceptableOptions::MinConjecturedSecurity(0))
+    };
+    verify_file(pub_inputs.clone()).unwrap();
+
+    // a corrupted proof cannot be deserialized
+    let mut bytes = proof.to_bytes();
+    bytes.truncate(bytes.len() - 1);
+    std::fs::write(&path, bytes).unwrap();
+    let err = verify_file(pub_inputs.clone()).unwrap_err();
+    assert!(matches!(err, VerifierError::ProofDeserializationError(_)));
+
+    // a missing proof file cannot be read
+    std::fs::remove_file(&path).unwrap();
+    let err = verify_file(pub_inputs).unwrap_err();
+    assert!(matches!(err, VerifierError::ProofReadFailed(_)));
+}
+
+// VERIFICATION REPORT
+// ================================================================================================
+
+#[test]
+fn test_verification_report() {
+    let prover = fib_segment_prover();
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+
+    let report = verify_with_report::<
+        FibSegmentAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof.clone(), pub_inputs, &AcceptableOptions::MinConjecturedSecurity(0))
+    .unwrap();
+
+    let options = proof.options();
+    assert_eq!(
+        proof.security_level::<Blake3_256<BaseElement>>(true),
+        report.conjectured_security
+    );
+
+    // duplicate query positions are checked only once
+    assert_eq!(proof.num_unique_queries as usize, report.num_unique_queries);
+    assert!(report.num_unique_queries <= options.num_queries());
+
+    let num_fri_layers = options.to_fri_options().num_fri_layers(proof.lde_domain_size());
+    assert_eq!(num_fri_layers, report.num_fri_layers);
+    assert!(report.verification_time.is_some());
+
+    let bytes = report.to_bytes();
+    assert_eq!(report, VerificationReport::read_from_bytes(&bytes).unwrap());
+}
+
+// SELF-CONTAINED PROOFS
+// ================================================================================================
+
+#[test]
+fn test_verify_self_contained() {
+    let prover = fib_segment_prover();
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+
+    let air =
+        FibSegmentAir::new(proof.trace_info().clone(), pub_inputs.clone(), proof.options().clone());
+    let annotated = proof.clone().embed_air_metadata(&air);
+    assert_eq!(2, annotated.metadata().num_main_transition_constraints);
+    assert_eq!(2, annotated.metadata().frame_size);
+
+    // serialization round trip
+    let annotated = AnnotatedProof::from_bytes(&annotated.to_bytes()).unwrap();
+    let pub_inputs_bytes = pub_inputs.to_elements().to_bytes();
+    assert_eq!(Ok(()), verify_fib_self_contained(annotated.clone(), &pub_inputs_bytes));
+
+    // the proof is rejected against different public inputs
+    let wrong_inputs = ContinuationState::new(
+        vec![BaseElement::ONE, BaseElement::new(2)],
+        pub_inputs.final_state().to_vec(),
+    );
+    let wrong_bytes = wrong_inputs.to_elements().to_bytes();
+    assert!(verify_fib_self_contained(annotated.clone(), &wrong_bytes).is_err());
+
+    // public inputs with trailing bytes are rejected
+    let mut trailing_bytes = pub_inputs_bytes.clone();
+    trailing_bytes.push(0);
+    assert!(matches!(
+        verify_fib_self_contained(annotated.clone(), &trailing_bytes),
+        Err(VerifierError::PublicInputsDeserializationError(_))
+    ));
+
+    // a proof generated for another computation is rejected
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::new(2)], SEGMENT_LENGTH);
+    let other_proof = prover.prove(trace).unwrap();
+    let (_, metadata) = annotated.clone().into_parts();
+    let forged = AnnotatedProof::new(other_proof, metadata);
+    assert!(verify_fib_self_contained(forged, &pub_inputs_bytes).is_err());
+
+    // metadata which is inconsistent with the proof is rejected on deserialization
+    let (proof, mut metadata) = annotated.into_parts();
+    metadata.num_main_columns += 1;
+    let mut bytes = metadata.to_bytes();
+    bytes.extend_from_slice(&proof.to_bytes());
+    assert!(AnnotatedProof::from_bytes(&bytes).is_err());
+
+    // an AIR with a wider evaluation frame is supported as well
+    let prover = squares_prover();
+    let trace = build_squares_trace(SEGMENT_LENGTH);
+    let last_square = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+    let air = SquaresAir::new(proof.trace_info().clone(), last_square, proof.options().clone());
+    let annotated = proof.embed_air_metadata(&air);
+    assert_eq!(3, annotated.metadata().frame_size);
+    assert_eq!(
+        Ok(()),
+        verify_self_contained::<
+            BaseElement,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+            HashPow,
+        >(
+            annotated,
+            &vec![last_square].to_bytes(),
+            &AcceptableOptions::MinConjecturedSecurity(0)
+        )
+    );
+}
+
+fn verify_fib_self_contained(
+    proof: AnnotatedProof,
+    pub_inputs_bytes: &[u8],
+) -> Result<(), VerifierError> {
+    verify_self_contained::<
+        BaseElement,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, pub_inputs_bytes, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+// PROOF STATS
+// ================================================================================================
+
+#[test]
+fn test_proof_stats() {
+    let options = ProofOptions::new(4, 8, 0, FieldExtension::Quadratic, 2, 1);
+    let prover = fib_segment_prover().with_options(options);
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+    let proof = prover.prove(trace).unwrap();
+    let stats = proof.stats();
+
+    assert_eq!(proof.to_bytes().len(), stats.serialized_bytes);
+    assert_eq!(proof.fri_proof.num_layers(), stats.num_fri_layers);
+    assert!(stats.num_fri_layers > 0);
+
+    // each FRI layer opens at least one and at most as many leaves as there are unique queries
+    let num_unique_queries = proof.num_unique_queries as usize;
+    let num_commitment_openings = (proof.trace_queries.len() + 1) * num_unique_queries;
+    let num_fri_openings = stats.total_query_nodes - num_commitment_openings;
+    assert!(num_fri_openings >= stats.num_fri_layers);
+    assert!(num_fri_openings <= stats.num_fri_layers * num_unique_queries);
+
+    // the frame contains current and next rows of the trace and the constraint evaluations
+    let trace_width = proof.trace_info().width();
+    let num_constraint_columns = proof.context.num_constraint_composition_columns().unwrap();
+    assert_eq!(2 * trace_width + num_constraint_columns, stats.ood_frame_elements);
+}
+
+// FIELD SECURITY
+// ================================================================================================
+
+#[test]
+fn test_enforce_field_security() {
+    // 28 queries with blowup factor 8 provide 84 bits of security, while the 64-bit base field
+    // provides only 64 - log2(lde_domain_size) bits
+    let options = ProofOptions::new(28, 8, 0, FieldExtension::None, 2, 1);
+    let lde_domain_size = SEGMENT_LENGTH * options.blowup_factor();
+    let field_security = options.field_security(BaseElement::MODULUS_BITS, lde_domain_size);
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+
+    // by default, the proof is still generated
+    let mut prover = fib_segment_prover().with_options(options.clone());
+    assert!(prover.prove(trace.clone()).is_ok());
+
+    prover.enforce_field_security = true;
+    assert_eq!(
+        Err(ProverError::InsufficientFieldSecurity { field_security, other_security: 84 }),
+        prover.prove(trace.clone())
+    );
+
+    // the field extension returned by the utility function fixes the bottleneck
+    let extension =
+        ProofOptions::required_extension_degree(BaseElement::MODULUS_BITS, 84, lde_domain_size);
+    assert_eq!(FieldExtension::Quadratic, extension);
+    prover.options = ProofOptions::new(28, 8, 0, extension, 2, 1);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(Ok(()), verify_fib_segment(proof, pub_inputs));
+}
+
+// SECURITY FLOOR
+// ================================================================================================
+
+#[test]
+fn test_security_floor() {
+    // 4 queries with blowup factor 8 provide only 12 bits of query security
+    let options = ProofOptions::new(4, 8, 0, FieldExtension::Quadratic, 2, 1);
+    let estimated = options.conjectured_security(BaseElement::MODULUS_BITS, SEGMENT_LENGTH, 128);
+    assert_eq!(11, estimated);
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+
+    // by default, the prover refuses to generate the proof
+    let mut prover = fib_segment_prover().with_options(options);
+    prover.allow_insecure = false;
+    assert_eq!(
+        Err(ProverError::InsufficientSecurity {
+            estimated,
+            required: ProofOptions::SECURITY_FLOOR
+        }),
+        prover.prove(trace.clone())
+    );
+
+    // once insecure proofs are allowed, the proof is generated, but it is rejected by the default
+    // acceptable options of the verifier
+    prover.allow_insecure = true;
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace.clone()).unwrap();
+    assert_eq!(Ok(()), verify_fib_segment(proof.clone(), pub_inputs.clone()));
+    assert_eq!(
+        Err(VerifierError::InsufficientConjecturedSecurity(80, estimated)),
+        verify::<
+            FibSegmentAir,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+            HashPow,
+        >(proof, pub_inputs.clone(), &AcceptableOptions::default())
+    );
+
+    // 28 queries with blowup factor 8 over a quadratic extension provide 83 bits of security
+    prover.allow_insecure = false;
+    prover.options = ProofOptions::new(28, 8, 0, FieldExtension::Quadratic, 2, 1);
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(83, proof.security_level::<Blake3_256<BaseElement>>(true));
+    assert_eq!(
+        Ok(()),
+        verify::<
+            FibSegmentAir,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+            HashPow,
+        >(proof, pub_inputs, &AcceptableOptions::default())
+    );
+}
+
+// PROOF-OF-WORK SCHEMES
+// ================================================================================================
+
+#[test]
+fn test_pow_scheme() {
+    let hash_options = ProofOptions::new(4, 8, 8, FieldExtension::Quadratic, 2, 1);
+    let blake3_options = hash_options.clone().with_pow_scheme(PowScheme::Blake3);
+
+    let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+
+    // a prover grinding with a proof-of-work function other than the one specified in the proof
+    // options refuses to generate the proof
+    assert_eq!(
+        Err(ProverError::PowSchemeMismatch {
+            expected: PowScheme::Hash,
+            actual: PowScheme::Blake3
+        }),
+        fib_segment_prover().with_options(blake3_options.clone()).prove(trace.clone())
+    );
+
+    let prover = blake3_fib_segment_prover().with_options(blake3_options.clone());
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(PowScheme::Blake3, proof.options().pow_scheme());
+
+    let verify_with_options = |proof: Proof, options: Vec<ProofOptions>| {
+        verify::<
+            FibSegmentAir,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+            Blake3Pow,
+        >(proof, pub_inputs.clone(), &AcceptableOptions::OptionSet(options))
+    };
+
+    assert_eq!(Ok(()), verify_with_options(proof.clone(), vec![blake3_options.clone()]));
+
+    // a verifier instantiated with a different proof-of-work function rejects the proof
+    assert_eq!(
+        Err(VerifierError::UnsupportedPowScheme(PowScheme::Blake3)),
+        verify_fib_segment(proof.clone(), pub_inputs.clone())
+    );
+
+    // a verifier which accepts only the hash-based scheme rejects the proof
+    assert_eq!(
+        Err(VerifierError::UnacceptableProofOptions),
+        verify_with_options(proof.clone(), vec![hash_options.clone()])
+    );
+
+    // claiming a different scheme in the proof context invalidates the proof
+    let mut forged_proof = proof;
+    forged_proof.context = Context::new::<BaseElement>(
+        forged_proof.trace_info().clone(),
+        hash_options.clone(),
+        forged_proof.context.num_constraint_composition_columns().unwrap(),
+    );
+    assert!(verify_fib_segment(forged_proof, pub_inputs).is_err());
+}
+
+#[test]
+fn test_pow_scheme_with_opaque_coin() {
+    let hash_options = ProofOptions::new(4, 8, 8, FieldExtension::Quadratic, 2, 1);
+    let blake3_options = hash_options.clone().with_pow_scheme(PowScheme::Blake3);
+
+    fn verify_with_opaque_coin<P: ProofOfWork<Blake3_256<BaseElement>>>(
+        options: ProofOptions,
+    ) -> Result<(), VerifierError> {
+        let prover = GenericProver::<FibSegmentAir, DefaultCoin, DefaultLde, P>::from_trace(
+            fib_segment_pub_inputs,
+        )
+        .with_options(options.clone());
+        let trace = build_fib_trace([BaseElement::ONE, BaseElement::ONE], SEGMENT_LENGTH);
+        let pub_inputs = prover.get_pub_inputs(&trace);
+        let proof = prover.prove(trace).unwrap();
+        verify::<
+            FibSegmentAir,
+            Blake3_256<BaseElement>,
+            OpaqueRandomCoin,
+            MerkleTree<Blake3_256<BaseElement>>,
+            P,
+        >(proof, pub_inputs, &AcceptableOptions::OptionSet(vec![options]))
+    }
+
+    // the hash-based scheme grinds through the coin, and so it works with coins which do not
+    // expose their seed; the BLAKE3 scheme requires the seed
+    assert_eq!(Ok(()), verify_with_opaque_coin::<HashPow>(hash_options));
+    assert_eq!(
+        Err(VerifierError::QuerySeedProofOfWorkVerificationFailed),
+        verify_with_opaque_coin::<Blake3Pow>(blake3_options)
+    );
+}
+
+/// A random coin which behaves exactly as the default random coin, but does not expose its seed.
+struct OpaqueRandomCoin(DefaultRandomCoin<Blake3_256<BaseElement>>);
+
+impl RandomCoin for OpaqueRandomCoin {
+    type BaseField = BaseElement;
+    type Hasher = Blake3_256<BaseElement>;
+
+    fn new(seed: &[BaseElement]) -> Self {
+        Self(DefaultRandomCoin::new(seed))
+    }
+
+    fn reseed(&mut self, data: <Self::Hasher as crypto::Hasher>::Digest) {
+        self.0.reseed(data)
+    }
+
+    fn check_leading_zeros(&self, value: u64) -> u32 {
+        self.0.check_leading_zeros(value)
+    }
+
+    fn draw<E: FieldElement<BaseField = BaseElement>>(
+        &mut self,
+    ) -> Result<E, crypto::RandomCoinError> {
+        self.0.draw()
+    }
+
+    fn draw_integers(
+        &mut self,
+        num_values: usize,
+        domain_size: usize,
+        nonce: u64,
+    ) -> Result<Vec<usize>, crypto::RandomCoinError> {
+        self.0.draw_integers(num_values, domain_size, nonce)
+    }
+}
+
+// WIDE EVALUATION FRAMES
+// ================================================================================================
+
+#[test]
+fn test_wide_evaluation_frame() {
+    let prover = squares_prover();
+    let trace = build_squares_trace(SEGMENT_LENGTH);
+    let last_square = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(Ok(()), verify_squares(proof.clone(), last_square));
+
+    // the proof is rejected against a different final value
+    assert!(verify_squares(proof, last_square + BaseElement::ONE).is_err());
+
+    // a trace which violates the constraint over the 3-row frame is rejected; in debug builds,
+    // the prover refuses to generate a proof for it
+    let mut trace = build_squares_trace(SEGMENT_LENGTH);
+    trace.set(0, 5, trace.get(0, 5) + BaseElement::ONE);
+    let last_square = prover.get_pub_inputs(&trace);
+    match prover.prove(trace) {
+        Ok(proof) => assert!(verify_squares(proof, last_square).is_err()),
+        Err(err) => assert!(matches!(
+            err,
+            ProverError::InvalidTrace(TraceValidationError::MainTransitionNotSatisfied {
+                step: 3,
+                ..
+            })
+        )),
+    }
+}
+
+#[test]
+fn test_wide_evaluation_frame_exemptions() {
+    let trace_info = TraceInfo::new(1, SEGMENT_LENGTH);
+    let options = squares_prover().options;
+    let air = SquaresAir::new(trace_info, BaseElement::ONE, options);
+    assert_eq!(3, air.frame_size());
+    assert_eq!(2, air.context().num_transition_exemptions());
+}
+
+fn verify_squares(proof: Proof, last_square: BaseElement) -> Result<(), VerifierError> {
+    verify::<
+        SquaresAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, last_square, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+/// Builds a trace of a single column containing the squares 0, 1, 4, 9, ... of the step indexes.
+fn build_squares_trace(length: usize) -> TraceTable<BaseElement> {
+    let mut trace = TraceTable::new(1, length);
+    trace.fill(
+        |state| state[0] = BaseElement::ZERO,
+        |step, state| state[0] = BaseElement::new((step as u64 + 1).pow(2)),
+    );
+    trace
+}
+
+/// AIR of a trace containing squares of the step indexes; the second difference of consecutive
+/// squares is always 2, and thus, the transition constraint is defined over a frame of 3 rows.
+struct SquaresAir {
+    context: AirContext<BaseElement>,
+    last_square: BaseElement,
+}
+
+impl Air for SquaresAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+    type PublicInputs = BaseElement;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1)];
+        Self {
+            context: AirContext::new(trace_info, degrees, 3, options).set_frame_size(3),
+            last_square: pub_inputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let two = E::ONE + E::ONE;
+        result[0] = frame.row(2)[0] - two * frame.row(1)[0] + frame.row(0)[0] - two;
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, BaseElement::ZERO),
+            Assertion::single(0, 1, BaseElement::ONE),
+            Assertion::single(0, last_step, self.last_square),
+        ]
+    }
+}
+
+fn squares_prover() -> GenericProver<SquaresAir> {
+    GenericProver::from_trace(|trace| trace.get(0, trace.length() - 1))
+}
+
+#[test]
+fn test_wide_evaluation_frame_with_aux_segment() {
+    prove_and_verify_squares_aux(1);
+}
+
+#[test]
+fn test_wide_evaluation_frame_with_lagrange_kernel() {
+    prove_and_verify_squares_aux(2);
+}
+
+/// Proves and verifies a trace of [SquaresAuxAir] with an auxiliary segment of the specified
+/// width.
+fn prove_and_verify_squares_aux(aux_segment_width: usize) {
+    let prover = SquaresAuxProver::new();
+    let trace = SquaresAuxTrace::new(SEGMENT_LENGTH, aux_segment_width);
+    let last_square = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(Ok(()), verify_squares_aux(proof.clone(), last_square));
+
+    // the proof is rejected against a different final value
+    assert!(verify_squares_aux(proof, last_square + BaseElement::ONE).is_err());
+}
+
+fn verify_squares_aux(proof: Proof, last_square: BaseElement) -> Result<(), VerifierError> {
+    verify::<
+        SquaresAuxAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, last_square, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+/// Trace of [SquaresAuxAir]; the main segment is the same as the trace of [SquaresAir], and the
+/// auxiliary segment is built by [SquaresAuxProver].
+struct SquaresAuxTrace {
+    main_trace: TraceTable<BaseElement>,
+    info: TraceInfo,
+}
+
+impl SquaresAuxTrace {
+    fn new(length: usize, aux_segment_width: usize) -> Self {
+        Self {
+            main_trace: build_squares_trace(length),
+            info: TraceInfo::new_multi_segment(1, aux_segment_width, 1, length, vec![]),
+        }
+    }
+}
+
+impl Trace for SquaresAuxTrace {
+    type BaseField = BaseElement;
+
+    fn info(&self) -> &TraceInfo {
+        &self.info
+    }
+
+    fn main_segment(&self) -> &ColMatrix<Self::BaseField> {
+        self.main_trace.main_segment()
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>) {
+        self.main_trace.read_main_frame(row_idx, frame)
+    }
+}
+
+/// AIR of a trace with the same main segment as [SquaresAir] and an auxiliary segment whose first
+/// column contains the squares multiplied by a random element `r`; the second difference of this
+/// column is always `2r`. If the auxiliary segment has two columns, the second column is a
+/// Lagrange kernel column.
+struct SquaresAuxAir {
+    context: AirContext<BaseElement>,
+    last_square: BaseElement,
+}
+
+impl Air for SquaresAuxAir {
+    type BaseField = BaseElement;
+    // `GkrProof` is log(trace_len), so that the verifier knows how many random elements to draw
+    // for the Lagrange kernel column
+    type GkrProof = usize;
+    type GkrVerifier = DummyGkrVerifier;
+    type PublicInputs = BaseElement;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let lagrange_kernel_aux_column_idx = (trace_info.aux_segment_width() == 2).then_some(1);
+        let main_degrees = vec![TransitionConstraintDegree::new(1)];
+        let aux_degrees = vec![TransitionConstraintDegree::new(1)];
+        Self {
+            context: AirContext::new_multi_segment(
+                trace_info,
+                main_degrees,
+                aux_degrees,
+                3,
+                2,
+                lagrange_kernel_aux_column_idx,
+                options,
+            )
+            .set_frame_size(3),
+            last_square: pub_inputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let two = E::ONE + E::ONE;
+        result[0] = frame.row(2)[0] - two * frame.row(1)[0] + frame.row(0)[0] - two;
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, BaseElement::ZERO),
+            Assertion::single(0, 1, BaseElement::ONE),
+            Assertion::single(0, last_step, self.last_square),
+        ]
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        _main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        _periodic_values: &[F],
+        aux_rand_elements: &AuxRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        let r = aux_rand_elements.rand_elements()[0];
+        let two = E::ONE + E::ONE;
+        result[0] = aux_frame.row(2)[0] - two * aux_frame.row(1)[0] + aux_frame.row(0)[0] - two * r;
+    }
+
+    fn get_aux_assertions<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> Vec<Assertion<E>> {
+        let r = aux_rand_elements.rand_elements()[0];
+        vec![Assertion::single(0, 0, E::ZERO), Assertion::single(0, 1, r)]
+    }
+
+    fn get_gkr_proof_verifier<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+    ) -> Self::GkrVerifier {
+        DummyGkrVerifier
+    }
+}
+
+/// Prover for [SquaresAuxAir].
+struct SquaresAuxProver {
+    options: ProofOptions,
+}
+
+impl SquaresAuxProver {
+    fn new() -> Self {
+        Self {
+            options: ProofOptions::new(4, 8, 0, FieldExtension::Quadratic, 2, 1),
+        }
+    }
+}
+
+impl Prover for SquaresAuxProver {
+    type BaseField = BaseElement;
+    type Air = SquaresAuxAir;
+    type Trace = SquaresAuxTrace;
+    type HashFn = Blake3_256<BaseElement>;
+    type VC = MerkleTree<Blake3_256<BaseElement>>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type ProofOfWork = HashPow;
+    type TraceLde<E: FieldElement<BaseField = BaseElement>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, Blake3_256<BaseElement>, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = BaseElement>> =
+        DefaultConstraintEvaluator<'a, SquaresAuxAir, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+        trace.main_trace.get(0, trace.length() - 1)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn allow_insecure(&self) -> bool {
+        true
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn generate_gkr_proof<E>(
+        &self,
+        main_trace: &Self::Trace,
+        public_coin: &mut Self::RandomCoin,
+    ) -> (ProverGkrProof<Self>, GkrRandElements<E>)
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let log_trace_len = main_trace.length().ilog2() as usize;
+        let lagrange_kernel_rand_elements =
+            draw_lagrange_kernel_rand_elements(public_coin, log_trace_len);
+
+        (log_trace_len, GkrRandElements::new(lagrange_kernel_rand_elements, Vec::new()))
+    }
+
+    fn build_aux_trace<E>(
+        &self,
+        main_trace: &Self::Trace,
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let r = aux_rand_elements.rand_elements()[0];
+        let squares = main_trace.main_segment().get_column(0);
+        let mut columns = vec![squares.iter().map(|&square| r.mul_base(square)).collect()];
+
+        if let Some(lagrange_kernel_rand_elements) = aux_rand_elements.lagrange() {
+            columns.push(build_lagrange_kernel_column(
+                lagrange_kernel_rand_elements,
+                main_trace.length(),
+            ));
+        }
+
+        ColMatrix::new(columns)
+    }
+}
+
+// LINEAR COMBINATION ASSERTIONS
+// ================================================================================================
+
+#[test]
+fn test_linear_combination_assertion() {
+    let prover = fib_combination_prover::<DefaultCoin>();
+    let trace = build_fib_trace([BaseElement::ONE; 2], SEGMENT_LENGTH);
+    let combination = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(Ok(()), verify_fib_combination(proof.clone(), combination));
+
+    // the proof is rejected against a different value of the combination
+    assert!(verify_fib_combination(proof, combination + BaseElement::ONE).is_err());
+}
+
+fn verify_fib_combination(proof: Proof, combination: BaseElement) -> Result<(), VerifierError> {
+    verify::<
+        FibCombinationAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, combination, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+fn verify_fib_combination_older_version(
+    proof: Proof,
+    combination: BaseElement,
+) -> Result<(), VerifierError> {
+    verify_older_version::<
+        FibCombinationAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, combination, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+/// AIR of a Fibonacci trace starting with [1, 1] for which the public input is the value of
+/// `2 * c0 + c1` at the last step, where `ci` is the value in column `i`.
+struct FibCombinationAir {
+    context: AirContext<BaseElement>,
+    combination: BaseElement,
+}
+
+impl Air for FibCombinationAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+    type PublicInputs = BaseElement;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(1)];
+        Self {
+            context: AirContext::new(trace_info, degrees, 3, options),
+            combination: pub_inputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        result[0] = next[0] - current[1];
+        result[1] = next[1] - (current[0] + current[1]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        let terms = vec![(0, BaseElement::new(2)), (1, BaseElement::ONE)];
+        vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::single(1, 0, BaseElement::ONE),
+            Assertion::linear_combination(terms, last_step, self.combination),
+        ]
+    }
+}
+
+/// Returns a prover for [FibCombinationAir] which generates the Fiat-Shamir transcript using the
+/// random coin `R`.
+fn fib_combination_prover<R>() -> GenericProver<FibCombinationAir, R> {
+    GenericProver::from_trace(|trace| {
+        let last_step = trace.length() - 1;
+        BaseElement::new(2) * trace.get(0, last_step) + trace.get(1, last_step)
+    })
+}
+
+// CUSTOM TRANSITION EXEMPTIONS
+// ================================================================================================
+
+const RESET_PERIOD: usize = 8;
+const RESET_TRACE_LENGTH: usize = 64;
+
+#[test]
+fn test_periodic_transition_exemption() {
+    let prover = cyclic_reset_prover();
+    let trace = build_cyclic_reset_trace(RESET_TRACE_LENGTH);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(Ok(()), verify_cyclic_reset(proof.clone(), pub_inputs));
+
+    // the proof is rejected against a different final total
+    assert!(verify_cyclic_reset(proof, pub_inputs + BaseElement::ONE).is_err());
+}
+
+#[test]
+fn test_periodic_transition_exemption_violated() {
+    let prover = cyclic_reset_prover();
+
+    // the counter is incremented by 2 in the middle of a cycle; transitions between steps 4 and
+    // 5 are not exempt, and thus, the trace is rejected; in debug builds, the prover refuses to
+    // generate a proof for it
+    let mut trace = build_cyclic_reset_trace(RESET_TRACE_LENGTH);
+    trace.set(0, 5, trace.get(0, 5) + BaseElement::ONE);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    match prover.prove(trace) {
+        Ok(proof) => assert!(verify_cyclic_reset(proof, pub_inputs).is_err()),
+        Err(err) => assert!(matches!(
+            err,
+            ProverError::InvalidTrace(TraceValidationError::MainTransitionNotSatisfied {
+                constraint_idx: 0,
+                step: 4,
+                ..
+            })
+        )),
+    }
+}
+
+#[test]
+fn test_periodic_transition_exemption_divisors() {
+    let trace_info = TraceInfo::new(3, RESET_TRACE_LENGTH);
+    let options = cyclic_reset_prover().options;
+    let air = CyclicResetAir::new(trace_info, BaseElement::ONE, options);
+
+    // the counter and the sum share the periodic divisor, and the total uses the default divisor
+    let coefficients = vec![BaseElement::ONE; 3];
+    let constraints = air.get_transition_constraints(&coefficients);
+    assert_eq!(2, constraints.divisors().len());
+    assert_eq!(constraints.constraint_divisor(0), constraints.constraint_divisor(1));
+    assert_eq!(constraints.divisor(), constraints.constraint_divisor(2));
+    assert_eq!(
+        RESET_TRACE_LENGTH - RESET_TRACE_LENGTH / RESET_PERIOD,
+        constraints.constraint_divisor(0).degree()
+    );
+
+    // the degree of the sum constraint is increased by the smaller divisor, and thus, requires
+    // two composition columns
+    assert_eq!(2, air.context().num_constraint_composition_columns());
+}
+
+#[test]
+fn test_constraint_composition_columns_mismatch() {
+    let prover = cyclic_reset_prover();
+    let trace = build_cyclic_reset_trace(RESET_TRACE_LENGTH);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(Some(2), proof.context.num_constraint_composition_columns());
+
+    // a proof claiming a different number of composition columns is rejected before its
+    // queries are parsed
+    for num_columns in [1, 3] {
+        let mut forged_proof = proof.clone();
+        forged_proof.context = Context::new::<BaseElement>(
+            proof.trace_info().clone(),
+            proof.options().clone(),
+            num_columns,
+        );
+        assert_eq!(
+            Err(VerifierError::InconsistentConstraintCompositionColumns(2, num_columns)),
+            verify_cyclic_reset(forged_proof, pub_inputs)
+        );
+    }
+}
+
+fn verify_cyclic_reset(proof: Proof, pub_inputs: BaseElement) -> Result<(), VerifierError> {
+    verify::<
+        CyclicResetAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, pub_inputs, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+/// Builds a trace of a counter which is reset every [RESET_PERIOD] steps, a running sum of
+/// squares of the counter which is reset together with the counter, and a running total of the
+/// counter which is never reset.
+fn build_cyclic_reset_trace(length: usize) -> TraceTable<BaseElement> {
+    let mut trace = TraceTable::new(3, length);
+    trace.fill(
+        |state| {
+            state[0] = BaseElement::ZERO;
+            state[1] = BaseElement::ZERO;
+            state[2] = BaseElement::ZERO;
+        },
+        |step, state| {
+            state[2] += state[0];
+            if step % RESET_PERIOD == RESET_PERIOD - 1 {
+                state[0] = BaseElement::ZERO;
+                state[1] = BaseElement::ZERO;
+            } else {
+                state[0] += BaseElement::ONE;
+                state[1] += state[0].square();
+            }
+        },
+    );
+    trace
+}
+
+/// AIR of a computation which resets its state every [RESET_PERIOD] steps.
+///
+/// The transitions of the counter and of the sum do not hold on the last step of every cycle;
+/// instead of multiplying these constraints by a periodic selector column, the last steps of all
+/// cycles are exempt from the constraints. The resets are enforced by periodic assertions.
+struct CyclicResetAir {
+    context: AirContext<BaseElement>,
+    total: BaseElement,
+}
+
+impl Air for CyclicResetAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+    type PublicInputs = BaseElement;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(2),
+            TransitionConstraintDegree::new(1),
+        ];
+        let exemption = TransitionExemption::Periodic {
+            offset: RESET_PERIOD - 1,
+            period: RESET_PERIOD,
+        };
+        let context = AirContext::new(trace_info, degrees, 4, options)
+            .set_transition_exemption(0, exemption)
+            .set_transition_exemption(1, exemption);
+        Self { context, total: pub_inputs }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        result[0] = next[0] - current[0] - E::ONE;
+        result[1] = next[1] - current[1] - next[0].square();
+        result[2] = next[2] - current[2] - current[0];
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::periodic(0, 0, RESET_PERIOD, BaseElement::ZERO),
+            Assertion::periodic(1, 0, RESET_PERIOD, BaseElement::ZERO),
+            Assertion::single(2, 0, BaseElement::ZERO),
+            Assertion::single(2, last_step, self.total),
+        ]
+    }
+}
+
+fn cyclic_reset_prover() -> GenericProver<CyclicResetAir> {
+    GenericProver::from_trace(|trace| trace.get(2, trace.length() - 1))
+}
+
+// PUBLIC COIN VERSIONS
+// ================================================================================================
+
+#[test]
+fn test_public_coin_consistency() {
+    let trace = build_fib_trace([BaseElement::ONE; 2], SEGMENT_LENGTH);
+    let prover = fib_combination_prover::<DefaultCoin>();
+    let combination = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(PROOF_VERSION, proof.context.proof_version());
+    assert_eq!(Ok(()), verify_fib_combination(proof.clone(), combination));
+
+    // the verifier must draw the same values as the prover; a proof generated with the default
+    // coin cannot be verified by replaying the transcript using the legacy coin
+    let result = verify::<
+        FibCombinationAir,
+        Blake3_256<BaseElement>,
+        LegacyRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof.clone(), combination, &AcceptableOptions::MinConjecturedSecurity(0));
+    assert!(result.is_err());
+
+    // proofs of older versions are rejected by default; when they are accepted, their
+    // transcripts are replayed using the legacy coin or without domain separation labels, and
+    // thus, a current proof is rejected if it is tagged as such
+    for version in [LEGACY_PROOF_VERSION, UNLABELED_PROOF_VERSION] {
+        let proof = with_proof_version(proof.clone(), version);
+        assert_eq!(
+            Err(VerifierError::UnsupportedProofVersion(version)),
+            verify_fib_combination(proof.clone(), combination)
+        );
+        assert!(verify_fib_combination_older_version(proof, combination).is_err());
+    }
+
+    // proofs of the current version are verified in the same way by both entry points
+    assert_eq!(Ok(()), verify_fib_combination_older_version(proof, combination));
+}
+
+#[test]
+fn test_legacy_proof_version() {
+    let trace = build_fib_trace([BaseElement::ONE; 2], SEGMENT_LENGTH);
+    let prover = fib_combination_prover::<
+        LegacySeedRandomCoin<UnlabeledRandomCoin<LegacyRandomCoin<Blake3_256<BaseElement>>>>,
+    >();
+    let combination = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+
+    // a proof with the current version is verified using the default coin, and thus, the legacy
+    // transcript is rejected
+    assert!(verify_fib_combination_older_version(proof.clone(), combination).is_err());
+
+    // once tagged with the legacy version, the proof is verified using the legacy coin even
+    // though the verifier is instantiated with the default one, but only if older versions are
+    // explicitly accepted
+    let proof = with_proof_version(proof, LEGACY_PROOF_VERSION);
+    assert_eq!(LEGACY_PROOF_VERSION, proof.context.proof_version());
+    assert_eq!(
+        Err(VerifierError::UnsupportedProofVersion(LEGACY_PROOF_VERSION)),
+        verify_fib_combination(proof.clone(), combination)
+    );
+    assert_eq!(Ok(()), verify_fib_combination_older_version(proof.clone(), combination));
+    assert!(verify_fib_combination_older_version(proof, combination + BaseElement::ONE).is_err());
+}
+
+#[test]
+fn test_unlabeled_proof_version() {
+    let trace = build_fib_trace([BaseElement::ONE; 2], SEGMENT_LENGTH);
+    let prover = fib_combination_prover::<UnlabeledRandomCoin<DefaultCoin>>();
+    let combination = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+
+    // a proof with the current version is expected to carry domain separation labels in its
+    // transcript, and thus, the unlabeled transcript is rejected
+    assert!(verify_fib_combination_older_version(proof.clone(), combination).is_err());
+
+    // once tagged with the version preceding domain separation labels, the transcript is
+    // replayed without labels, but only if older versions are explicitly accepted
+    let proof = with_proof_version(proof, UNLABELED_PROOF_VERSION);
+    assert_eq!(
+        Err(VerifierError::UnsupportedProofVersion(UNLABELED_PROOF_VERSION)),
+        verify_fib_combination(proof.clone(), combination)
+    );
+    assert_eq!(Ok(()), verify_fib_combination_older_version(proof.clone(), combination));
+    assert!(verify_fib_combination_older_version(proof, combination + BaseElement::ONE).is_err());
+}
+
+/// Re-tags the specified proof with the specified proof format version; the layout of all
+/// supported proof format versions is the same, except that the context of legacy proofs does
+/// not record the number of constraint composition columns.
+fn with_proof_version(proof: Proof, version: u8) -> Proof {
+    let mut bytes = proof.to_bytes();
+    bytes[0] = version;
+    if version == LEGACY_PROOF_VERSION {
+        bytes.remove(proof.context.to_bytes().len() - 1);
+    }
+    Proof::deserialize_versioned(&bytes).unwrap()
+}
+
+/// A random coin which seeds the wrapped coin as the verifier does for legacy proofs, i.e.,
+/// without the number of constraint composition columns recorded in the proof context.
+///
+/// The number of columns is the last element of the context, which is followed by a single
+/// element of the public inputs of [FibCombinationAir].
+struct LegacySeedRandomCoin<R: RandomCoin>(R);
+
+impl<R: RandomCoin> RandomCoin for LegacySeedRandomCoin<R> {
+    type BaseField = R::BaseField;
+    type Hasher = R::Hasher;
+
+    fn new(seed: &[Self::BaseField]) -> Self {
+        let mut seed = seed.to_vec();
+        seed.remove(seed.len() - 2);
+        Self(R::new(&seed))
+    }
+
+    fn reseed(&mut self, data: <Self::Hasher as crypto::Hasher>::Digest) {
+        self.0.reseed(data)
+    }
+
+    fn reseed_with_label(
+        &mut self,
+        label: &'static [u8],
+        data: <Self::Hasher as crypto::Hasher>::Digest,
+    ) {
+        self.0.reseed_with_label(label, data)
+    }
+
+    fn seed(&self) -> Option<<Self::Hasher as crypto::Hasher>::Digest> {
+        self.0.seed()
+    }
+
+    fn check_leading_zeros(&self, value: u64) -> u32 {
+        self.0.check_leading_zeros(value)
+    }
+
+    fn draw<E: FieldElement<BaseField = Self::BaseField>>(
+        &mut self,
+    ) -> Result<E, crypto::RandomCoinError> {
+        self.0.draw()
+    }
+
+    fn draw_integers(
+        &mut self,
+        num_values: usize,
+        domain_size: usize,
+        nonce: u64,
+    ) -> Result<Vec<usize>, crypto::RandomCoinError> {
+        self.0.draw_integers(num_values, domain_size, nonce)
+    }
+}
+
+// CHAINED PROOFS
+// ================================================================================================
+
+#[test]
+fn test_chained_proof() {
+    let prover = fib_segment_prover();
+    let (mut segments, _) = build_fib_segments([BaseElement::ONE, BaseElement::ONE], 2);
+    let first_trace = segments.remove(0);
+    let first_inputs = prover.get_pub_inputs(&first_trace);
+    let first_proof = prover.prove(first_trace).unwrap();
+    let second_proof = prover.prove(segments.remove(0)).unwrap();
+
+    // the second computation starts where the first one ended and ends in the state which the
+    // extractor computes from the output of the first one
+    let chained_proof = chain::<
+        FibSegmentAir,
+        FibSegmentAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(
+        first_proof.clone(),
+        first_inputs.clone(),
+        extract_fib_segment,
+        second_proof,
+        &AcceptableOptions::MinConjecturedSecurity(0),
+    )
+    .unwrap();
+
+    let chained_proof = ChainedProof::from_bytes(&chained_proof.to_bytes()).unwrap();
+    assert_eq!(Ok(first_inputs.clone()), verify_fib_chained(chained_proof.clone()));
+
+    // the second proof does not start at the output of the first proof
+    let (segments, _) = build_fib_segments([BaseElement::new(2), BaseElement::new(3)], 1);
+    let unlinked_proof = prover.prove(segments.into_iter().next().unwrap()).unwrap();
+    let proof = ChainedProof::new(
+        first_proof.clone(),
+        chained_proof.first_pub_inputs().to_vec(),
+        unlinked_proof,
+    );
+    assert!(matches!(
+        verify_fib_chained(proof),
+        Err(VerifierError::ChainedProofVerificationFailed(1, _))
+    ));
+
+    // public inputs of the first proof do not match the first proof
+    let (first_proof, mut first_inputs, second_proof) = chained_proof.into_parts();
+    let last_element_idx = first_inputs.len() - BaseElement::ELEMENT_BYTES;
+    first_inputs[last_element_idx] ^= 1;
+    let proof = ChainedProof::new(first_proof.clone(), first_inputs, second_proof.clone());
+    assert!(matches!(
+        verify_fib_chained(proof),
+        Err(VerifierError::ChainedProofVerificationFailed(0, _))
+    ));
+
+    // public inputs of the first proof cannot be deserialized
+    let proof = ChainedProof::new(first_proof, vec![1, 2, 3], second_proof);
+    assert!(matches!(
+        verify_fib_chained(proof),
+        Err(VerifierError::ChainedPubInputsDeserializationError(_))
+    ));
+}
+
+fn verify_fib_chained(
+    proof: ChainedProof,
+) -> Result<ContinuationState<BaseElement>, VerifierError> {
+    verify_chained::<
+        FibSegmentAir,
+        FibSegmentAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, extract_fib_segment, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+/// Returns the boundary states of a Fibonacci segment which starts in the final state of the
+/// specified segment.
+fn extract_fib_segment(state: &ContinuationState<BaseElement>) -> ContinuationState<BaseElement> {
+    let initial_state = [state.final_state()[0], state.final_state()[1]];
+    let trace = build_fib_trace(initial_state, SEGMENT_LENGTH);
+    let final_state = vec![trace.get(0, SEGMENT_LENGTH - 1), trace.get(1, SEGMENT_LENGTH - 1)];
+    ContinuationState::new(initial_state.to_vec(), final_state)
+}
+
+fn verify_fib_continuation(
+    proof: ContinuationProof<BaseElement>,
+    state: ContinuationState<BaseElement>,
+) -> Result<(), VerifierError> {
+    verify_continuation::<
+        FibSegmentAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, state, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+/// Splits a Fibonacci computation into `num_segments` segments such that the last row of every
+/// segment is the first row of the next segment.
+fn build_fib_segments(
+    initial_state: [BaseElement; 2],
+    num_segments: usize,
+) -> (Vec<TraceTable<BaseElement>>, Vec<ContinuationState<BaseElement>>) {
+    let mut traces = Vec::new();
+    let mut states = Vec::new();
+    let mut state = initial_state;
+    for _ in 0..num_segments {
+        let trace = build_fib_trace(state, SEGMENT_LENGTH);
+        let final_state = [trace.get(0, SEGMENT_LENGTH - 1), trace.get(1, SEGMENT_LENGTH - 1)];
+        states.push(ContinuationState::new(state.to_vec(), final_state.to_vec()));
+        traces.push(trace);
+        state = final_state;
+    }
+    (traces, states)
+}
+
+fn build_fib_trace(initial_state: [BaseElement; 2], length: usize) -> TraceTable<BaseElement> {
+    let mut trace = TraceTable::new(2, length);
+    trace.fill(
+        |state| state.copy_from_slice(&initial_state),
+        |_, state| {
+            let next = state[0] + state[1];
+            state[0] = state[1];
+            state[1] = next;
+        },
+    );
+    trace
+}
+
+struct FibSegmentAir {
+    context: AirContext<BaseElement>,
+    state: ContinuationState<BaseElement>,
+}
+
+impl Air for FibSegmentAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+    type PublicInputs = ContinuationState<BaseElement>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(1)];
+        Self {
+            context: AirContext::new(trace_info, degrees, 4, options),
+            state: pub_inputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        result[0] = next[0] - current[1];
+        result[1] = next[1] - (current[0] + current[1]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        self.state.to_assertions(&[0, 1], &[0, 1], self.trace_length())
+    }
+}
+
+fn fib_segment_prover() -> GenericProver<FibSegmentAir> {
+    GenericProver::from_trace(fib_segment_pub_inputs)
+}
+
+/// Same as [fib_segment_prover()], but the returned prover commits to the trace via
+/// [ChunkedTraceLde].
+fn chunked_fib_segment_prover() -> GenericProver<FibSegmentAir, DefaultCoin, ChunkedLde> {
+    GenericProver::from_trace(fib_segment_pub_inputs)
+}
+
+/// Same as [fib_segment_prover()], but the returned prover grinds the query seed using
+/// [Blake3Pow].
+fn blake3_fib_segment_prover() -> GenericProver<FibSegmentAir, DefaultCoin, DefaultLde, Blake3Pow> {
+    GenericProver::from_trace(fib_segment_pub_inputs)
+}
+
+fn fib_segment_pub_inputs(trace: &TraceTable<BaseElement>) -> ContinuationState<BaseElement> {
+    let last_step = trace.length() - 1;
+    ContinuationState::new(
+        vec![trace.get(0, 0), trace.get(1, 0)],
+        vec![trace.get(0, last_step), trace.get(1, last_step)],
+    )
+}
+
+// SPARSE CONSTRAINT EVALUATION
+// ================================================================================================
+
+const SPARSE_TRACE_WIDTH: usize = 8;
+
+#[test]
+fn test_sparse_constraint_evaluation() {
+    let trace = build_sparse_trace(SEGMENT_LENGTH);
+    let pub_inputs = SparseInputs {
+        last_fib: trace.get(1, SEGMENT_LENGTH - 1),
+        last_free: trace.get(3, SEGMENT_LENGTH - 1),
+        declare_used_columns: true,
+    };
+
+    // declaring the used columns changes only which columns the prover reads into evaluation
+    // frames, and thus, the resulting proofs are the same
+    let sparse_proof = GenericProver::<SparseAir>::new(pub_inputs).prove(trace.clone()).unwrap();
+    let dense_inputs = SparseInputs {
+        declare_used_columns: false,
+        ..pub_inputs
+    };
+    let dense_proof = GenericProver::<SparseAir>::new(dense_inputs).prove(trace).unwrap();
+    assert_eq!(dense_proof, sparse_proof);
+
+    assert_eq!(Ok(()), verify_sparse(sparse_proof.clone(), pub_inputs));
+    let wrong_inputs = SparseInputs {
+        last_free: pub_inputs.last_free + BaseElement::ONE,
+        ..pub_inputs
+    };
+    assert!(verify_sparse(sparse_proof, wrong_inputs).is_err());
+}
+
+fn verify_sparse(proof: Proof, pub_inputs: SparseInputs) -> Result<(), VerifierError> {
+    verify::<
+        SparseAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, pub_inputs, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+/// Builds a trace in which columns 0 and 1 contain a Fibonacci sequence, column 5 doubles at
+/// every step, and all other columns contain unconstrained values.
+fn build_sparse_trace(length: usize) -> TraceTable<BaseElement> {
+    let mut trace = TraceTable::new(SPARSE_TRACE_WIDTH, length);
+    trace.fill(
+        |state| {
+            for (i, value) in state.iter_mut().enumerate() {
+                *value = BaseElement::new(i as u64 + 1);
+            }
+            state[0] = BaseElement::ONE;
+            state[1] = BaseElement::ONE;
+            state[5] = BaseElement::ONE;
+        },
+        |step, state| {
+            let next = state[0] + state[1];
+            state[0] = state[1];
+            state[1] = next;
+            state[5] = state[5].double();
+            for (i, value) in state.iter_mut().enumerate() {
+                if ![0, 1, 5].contains(&i) {
+                    *value = value.square() + BaseElement::new(step as u64);
+                }
+            }
+        },
+    );
+    trace
+}
+
+#[derive(Clone, Copy)]
+struct SparseInputs {
+    last_fib: BaseElement,
+    last_free: BaseElement,
+    declare_used_columns: bool,
+}
+
+impl ToElements<BaseElement> for SparseInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![self.last_fib, self.last_free]
+    }
+}
+
+/// AIR whose transition constraints use only columns 0, 1, and 5 of the trace; the value of
+/// column 3 (not used by transition constraints) at the last step is asserted.
+struct SparseAir {
+    context: AirContext<BaseElement>,
+    pub_inputs: SparseInputs,
+}
+
+impl Air for SparseAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+    type PublicInputs = SparseInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1); 3];
+        Self {
+            context: AirContext::new(trace_info, degrees, 5, options),
+            pub_inputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        result[0] = next[0] - current[1];
+        result[1] = next[1] - (current[0] + current[1]);
+        result[2] = next[5] - current[5].double();
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::single(1, 0, BaseElement::ONE),
+            Assertion::single(5, 0, BaseElement::ONE),
+            Assertion::single(1, last_step, self.pub_inputs.last_fib),
+            Assertion::single(3, last_step, self.pub_inputs.last_free),
+        ]
+    }
+
+    fn used_columns(&self) -> Option<&[usize]> {
+        if self.pub_inputs.declare_used_columns {
+            Some(&[5, 0, 1])
+        } else {
+            None
+        }
+    }
+}
+
+// CONSTRAINT COMPOSITION COLUMNS
+// ================================================================================================
+
+const POWER_TRACE_LENGTH: usize = 32;
+
+#[test]
+fn test_num_constraint_composition_columns() {
+    let trace = build_power_trace(POWER_TRACE_LENGTH, 5);
+    let last = trace.get(0, POWER_TRACE_LENGTH - 1);
+
+    // the degree 5 constraint requires 4 composition columns; requesting 4 or 8 columns results
+    // in valid proofs which record the number of columns in their context
+    for num_columns in [None, Some(4), Some(8)] {
+        let pub_inputs = PowerInputs { exponent: 5, last, num_columns };
+        let proof = power_prover(pub_inputs, 16).prove(trace.clone()).unwrap();
+        assert_eq!(
+            Some(num_columns.unwrap_or(4)),
+            proof.context.num_constraint_composition_columns()
+        );
+        assert_eq!(Ok(()), verify_power(proof, pub_inputs));
+    }
+
+    // the verifier rejects a proof which uses a different number of columns than its AIR
+    let pub_inputs = PowerInputs { exponent: 5, last, num_columns: Some(8) };
+    let proof = power_prover(pub_inputs, 16).prove(trace).unwrap();
+    let verifier_inputs = PowerInputs { num_columns: Some(4), ..pub_inputs };
+    assert_eq!(
+        Err(VerifierError::InconsistentConstraintCompositionColumns(4, 8)),
+        verify_power(proof, verifier_inputs)
+    );
+}
+
+#[test]
+#[should_panic(expected = "number of constraint composition columns must be at least 4, but was 2")]
+fn test_num_constraint_composition_columns_too_few() {
+    let trace = build_power_trace(POWER_TRACE_LENGTH, 5);
+    let pub_inputs = PowerInputs {
+        exponent: 5,
+        last: trace.get(0, POWER_TRACE_LENGTH - 1),
+        num_columns: Some(2),
+    };
+    let _ = power_prover(pub_inputs, 16).prove(trace);
+}
+
+#[test]
+fn test_ce_blowup_factor() {
+    // the constraint evaluation domain is derived from the degree of the constraint, and proofs
+    // verify with the blowup factor equal to the blowup factor of the constraint evaluation domain
+    for (exponent, ce_blowup_factor) in [(3, 2), (5, 4), (9, 8)] {
+        let trace = build_power_trace(POWER_TRACE_LENGTH, exponent);
+        let pub_inputs = PowerInputs {
+            exponent,
+            last: trace.get(0, POWER_TRACE_LENGTH - 1),
+            num_columns: None,
+        };
+        let prover = power_prover(pub_inputs, ce_blowup_factor);
+        let air = PowerAir::new(trace.info().clone(), pub_inputs, prover.options.clone());
+        assert_eq!(ce_blowup_factor, air.ce_blowup_factor());
+
+        let proof = prover.prove(trace).unwrap();
+        assert_eq!(Ok(()), verify_power(proof, pub_inputs));
+    }
+}
+
+#[test]
+fn test_ce_blowup_factor_exceeds_blowup_factor() {
+    let trace = build_power_trace(POWER_TRACE_LENGTH, 5);
+    let pub_inputs = PowerInputs {
+        exponent: 5,
+        last: trace.get(0, POWER_TRACE_LENGTH - 1),
+        num_columns: None,
+    };
+    let err = power_prover(pub_inputs, 2).prove(trace).unwrap_err();
+    assert_eq!(
+        ProverError::BlowupFactorTooSmall {
+            blowup_factor: 2,
+            ce_blowup_factor: 4,
+            constraint_idx: 0,
+            constraint_degree: TransitionConstraintDegree::new(5),
+        },
+        err
+    );
+    assert_eq!(
+        "blowup factor 2 is too small for transition constraint 0 of degree 5; the blowup factor \
+        must be at least 4",
+        err.to_string()
+    );
+}
+
+#[test]
+fn test_context_data() {
+    let trace = build_power_trace(POWER_TRACE_LENGTH, 3);
+    let pub_inputs = PowerInputs {
+        exponent: 3,
+        last: trace.get(0, POWER_TRACE_LENGTH - 1),
+        num_columns: None,
+    };
+    let verify_with = |proof: Proof, context_data: &[u8]| {
+        verify_with_context_data::<
+            PowerAir,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+            HashPow,
+        >(proof, pub_inputs, context_data, &AcceptableOptions::MinConjecturedSecurity(0))
+    };
+
+    // a proof bound to context data verifies only against the same data
+    let prover = power_prover(pub_inputs, 4).with_context_data(b"batch root A");
+    let proof = prover.prove(trace.clone()).unwrap();
+    assert_eq!(Ok(()), verify_with(proof.clone(), b"batch root A"));
+    assert!(verify_with(proof.clone(), b"batch root B").is_err());
+    assert!(verify_with(proof.clone(), &[]).is_err());
+    assert!(verify_power(proof, pub_inputs).is_err());
+
+    // empty context data is not absorbed into the transcript
+    let unbound_proof = power_prover(pub_inputs, 4).prove(trace.clone()).unwrap();
+    let proof = power_prover(pub_inputs, 4).with_context_data(&[]).prove(trace).unwrap();
+    assert_eq!(unbound_proof.to_bytes(), proof.to_bytes());
+    assert_eq!(Ok(()), verify_with(proof.clone(), &[]));
+    assert_eq!(Ok(()), verify_power(proof, pub_inputs));
+}
+
+#[test]
+fn test_differential_verification() {
+    type Verifier<'a> = DifferentialVerifier<
+        'a,
+        PowerAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >;
+
+    let trace = build_power_trace(POWER_TRACE_LENGTH, 3);
+    let pub_inputs = PowerInputs {
+        exponent: 3,
+        last: trace.get(0, POWER_TRACE_LENGTH - 1),
+        num_columns: None,
+    };
+    let proof = power_prover(pub_inputs, 4).prove(trace).unwrap();
+    let acceptable_options = AcceptableOptions::MinConjecturedSecurity(0);
+    let verifier = Verifier::new(&acceptable_options);
+
+    // a reference which parses the proof bytes and verifies the proof agrees with this verifier
+    let reference = |bytes: &[u8]| {
+        let proof = Proof::from_bytes(bytes).map_err(|_| VerificationPhase::Setup)?;
+        verify_power(proof, pub_inputs).map_err(|err| VerificationPhase::from(&err))
+    };
+    let result = verifier.verify(&proof, pub_inputs, reference);
+    assert_eq!(DifferentialResult::BothAccepted, result);
+    assert!(result.agree());
+
+    let result = verifier.verify(&proof, pub_inputs, |_| false);
+    assert_eq!(DifferentialResult::ReferenceRejected { phase: None }, result);
+    assert!(!result.agree());
+
+    // a proof for different public inputs is rejected by both verifiers
+    let wrong_inputs = PowerInputs {
+        last: pub_inputs.last + BaseElement::ONE,
+        ..pub_inputs
+    };
+    let result =
+        verifier.verify(&proof, wrong_inputs, |_| Err::<(), _>(VerificationPhase::OutOfDomain));
+    assert!(result.agree());
+    assert_eq!(Some(VerificationPhase::OutOfDomain), result.winterfell_phase());
+    assert_eq!(Some(VerificationPhase::OutOfDomain), result.reference_phase());
+
+    // a reference accepting everything disagrees with this verifier on the invalid proof
+    let result = verifier.verify(&proof, wrong_inputs, |_| true);
+    assert_eq!(
+        DifferentialResult::WinterfellRejected {
+            error: VerifierError::InconsistentOodConstraintEvaluations
+        },
+        result
+    );
+    assert!(!result.agree());
+}
+
+fn verify_power(proof: Proof, pub_inputs: PowerInputs) -> Result<(), VerifierError> {
+    verify::<
+        PowerAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, pub_inputs, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+/// Builds a single-column trace in which every value is the previous value raised to the
+/// specified power.
+fn build_power_trace(length: usize, exponent: u32) -> TraceTable<BaseElement> {
+    let mut trace = TraceTable::new(1, length);
+    trace.fill(
+        |state| state[0] = BaseElement::new(3),
+        |_, state| state[0] = state[0].exp(exponent.into()),
+    );
+    trace
+}
+
+#[derive(Clone, Copy)]
+struct PowerInputs {
+    exponent: u32,
+    last: BaseElement,
+    num_columns: Option<usize>,
+}
+
+impl ToElements<BaseElement> for PowerInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![BaseElement::from(self.exponent), self.last]
+    }
+}
+
+/// AIR with a single transition constraint which raises the value of the only column to the
+/// power specified in public inputs; the number of constraint composition columns can be
+/// overridden via public inputs.
+struct PowerAir {
+    context: AirContext<BaseElement>,
+    exponent: u32,
+    last: BaseElement,
+}
+
+impl Air for PowerAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+    type PublicInputs = PowerInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(pub_inputs.exponent as usize)];
+        let mut context = AirContext::new(trace_info, degrees, 2, options);
+        if let Some(num_columns) = pub_inputs.num_columns {
+            context = context.set_num_constraint_composition_columns(num_columns);
+        }
+        Self {
+            context,
+            exponent: pub_inputs.exponent,
+            last: pub_inputs.last,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        result[0] = frame.next()[0] - frame.current()[0].exp(self.exponent.into());
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, BaseElement::new(3)),
+            Assertion::single(0, last_step, self.last),
+        ]
+    }
+}
+
+fn power_prover(pub_inputs: PowerInputs, blowup_factor: usize) -> GenericProver<PowerAir> {
+    GenericProver::new(pub_inputs).with_options(ProofOptions::new(
+        8,
+        blowup_factor,
+        0,
+        FieldExtension::Quadratic,
+        2,
+        7,
+    ))
+}
+
+// CIRCUIT COMPILER
+// ================================================================================================
+
+const COMPILED_TRACE_LENGTH: usize = 16;
+
+#[test]
+fn test_circuit_air() {
+    let circuit = build_sha256_ch_xor_circuit();
+    assert_eq!(10, circuit.gates().len() - circuit.num_inputs());
+
+    // every row except the last one evaluates the circuit on a different assignment of the
+    // input bits; the last row is exempt from constraints and holds padding
+    let mut rows = Vec::with_capacity(COMPILED_TRACE_LENGTH * circuit.trace_width());
+    let mut outputs = Vec::with_capacity(COMPILED_TRACE_LENGTH - 1);
+    for i in 0..COMPILED_TRACE_LENGTH - 1 {
+        let bits = [i & 1, (i >> 1) & 1, (i >> 2) & 1, (i >> 3) & 1];
+        let row = circuit.build_row(&bits.map(|bit| BaseElement::new(bit as u64)));
+        outputs.push(vec![row[circuit.output_column(0)]]);
+        rows.extend(row);
+    }
+    rows.extend((0..circuit.trace_width() as u64).map(|i| BaseElement::new((i + 2).pow(5))));
+    let trace = TraceTable::from_rows(&rows, circuit.trace_width());
+
+    // Ch(e, f, g) XOR h for e = 1, f = 0, g = 1, h = 0 and e = 0, f = 1, g = 1, h = 1
+    assert_eq!(vec![BaseElement::ZERO], outputs[5]);
+    assert_eq!(vec![BaseElement::ZERO], outputs[14]);
+    assert_eq!(vec![BaseElement::ONE], outputs[13]);
+
+    let pub_inputs = CircuitInputs { circuit, outputs };
+    let proof = GenericProver::<CircuitAir<BaseElement>>::new(pub_inputs.clone())
+        .prove(trace)
+        .unwrap();
+    assert_eq!(Ok(()), verify_circuit(proof.clone(), pub_inputs.clone()));
+
+    // the proof does not verify against different outputs
+    let mut wrong_inputs = pub_inputs;
+    wrong_inputs.outputs[3][0] += BaseElement::ONE;
+    assert!(verify_circuit(proof, wrong_inputs).is_err());
+}
+
+fn verify_circuit(
+    proof: Proof,
+    pub_inputs: CircuitInputs<BaseElement>,
+) -> Result<(), VerifierError> {
+    verify::<
+        CircuitAir<BaseElement>,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, pub_inputs, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+/// Builds a circuit over input bits e, f, g, h computing Ch(e, f, g) XOR h, where Ch is the
+/// choice function of SHA-256; the circuit consists of 10 gates.
+fn build_sha256_ch_xor_circuit() -> Circuit<BaseElement> {
+    let mut circuit = Circuit::new();
+    let e = circuit.input();
+    let f = circuit.input();
+    let g = circuit.input();
+    let h = circuit.input();
+
+    // Ch(e, f, g) = g + e * (f - g)
+    let minus_one = circuit.constant(-BaseElement::ONE);
+    let minus_g = circuit.mul(minus_one, g);
+    let f_minus_g = circuit.add(f, minus_g);
+    let t = circuit.mul(e, f_minus_g);
+    let ch = circuit.add(g, t);
+
+    // a XOR b = a + b - 2 * a * b
+    let ch_h = circuit.mul(ch, h);
+    let minus_two = circuit.constant(-BaseElement::new(2));
+    let minus_two_ch_h = circuit.mul(minus_two, ch_h);
+    let ch_plus_h = circuit.add(ch, h);
+    let result = circuit.add(ch_plus_h, minus_two_ch_h);
+
+    circuit.output(result);
+    circuit
+}
+
+// R1CS INTEROP
+// ================================================================================================
+
+#[test]
+fn test_r1cs_air() {
+    let one = BaseElement::ONE;
+
+    // x^3 + x + 5 = out over variables (1, out, x, sym1, y, sym2)
+    let mut r1cs = R1csInstance::new(6, 1);
+    r1cs.add_constraint(vec![(2, one)], vec![(2, one)], vec![(3, one)]);
+    r1cs.add_constraint(vec![(3, one)], vec![(2, one)], vec![(4, one)]);
+    r1cs.add_constraint(vec![(4, one), (2, one)], vec![(0, one)], vec![(5, one)]);
+    r1cs.add_constraint(vec![(5, one), (0, BaseElement::new(5))], vec![(0, one)], vec![(1, one)]);
+
+    // every row except the last one holds a satisfying assignment for a different x; the last
+    // row is exempt from constraints and holds padding
+    let mut rows = Vec::with_capacity(COMPILED_TRACE_LENGTH * r1cs.trace_width());
+    let mut public_values = Vec::with_capacity(COMPILED_TRACE_LENGTH - 1);
+    for x in 0..COMPILED_TRACE_LENGTH as u64 - 1 {
+        let assignment =
+            [1, x.pow(3) + x + 5, x, x * x, x.pow(3), x.pow(3) + x].map(BaseElement::new);
+        assert!(r1cs.is_satisfied(&assignment));
+        public_values.push(vec![assignment[1]]);
+        rows.extend(r1cs.build_row(&assignment));
+    }
+    rows.extend((0..r1cs.trace_width() as u64).map(|i| BaseElement::new((i + 2).pow(5))));
+    let trace = TraceTable::from_rows(&rows, r1cs.trace_width());
+
+    let pub_inputs = R1csInputs { r1cs, public_values };
+    let proof = GenericProver::<R1csAir<BaseElement>>::new(pub_inputs.clone())
+        .prove(trace)
+        .unwrap();
+    assert_eq!(Ok(()), verify_r1cs(proof.clone(), pub_inputs.clone()));
+
+    // the proof does not verify against different public values
+    let mut wrong_inputs = pub_inputs;
+    wrong_inputs.public_values[2][0] += BaseElement::ONE;
+    assert!(verify_r1cs(proof, wrong_inputs).is_err());
+}
+
+fn verify_r1cs(proof: Proof, pub_inputs: R1csInputs<BaseElement>) -> Result<(), VerifierError> {
+    verify::<
+        R1csAir<BaseElement>,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, pub_inputs, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+// TRACE PADDING
+// ================================================================================================
+
+#[test]
+fn test_padded_trace() {
+    // 20 steps of the computation are padded to 32 rows by repeating the last row
+    let mut dynamic_trace = DynamicTraceTable::new(1);
+    let mut value = BaseElement::new(3);
+    for _ in 0..20 {
+        dynamic_trace.push_row(vec![value]);
+        value = value.exp(3);
+    }
+    let (trace, num_rows) = dynamic_trace.finalize(TracePadding::RepeatLastRow);
+    assert_eq!((32, 20), (trace.length(), num_rows));
+
+    let pub_inputs = PaddedPowerInputs {
+        last: trace.get(0, num_rows - 1),
+        num_rows,
+    };
+    let proof = GenericProver::<PaddedPowerAir>::new(pub_inputs).prove(trace).unwrap();
+    assert_eq!(Ok(()), verify_padded_power(proof.clone(), pub_inputs));
+
+    // the proof does not verify against a different result or a different number of steps
+    let wrong_inputs = PaddedPowerInputs {
+        last: pub_inputs.last + BaseElement::ONE,
+        ..pub_inputs
+    };
+    assert!(verify_padded_power(proof.clone(), wrong_inputs).is_err());
+    let wrong_inputs = PaddedPowerInputs { num_rows: 19, ..pub_inputs };
+    assert!(verify_padded_power(proof, wrong_inputs).is_err());
+}
+
+fn verify_padded_power(proof: Proof, pub_inputs: PaddedPowerInputs) -> Result<(), VerifierError> {
+    verify::<
+        PaddedPowerAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        HashPow,
+    >(proof, pub_inputs, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+#[derive(Clone, Copy)]
+struct PaddedPowerInputs {
+    last: BaseElement,
+    num_rows: usize,
+}
+
+impl ToElements<BaseElement> for PaddedPowerInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![self.last, BaseElement::new(self.num_rows as u64)]
+    }
+}
+
+/// AIR which cubes the value of the only column in every step of the computation; rows past the
+/// end of the computation are padding, and are exempt from the transition constraint.
+struct PaddedPowerAir {
+    context: AirContext<BaseElement>,
+    pub_inputs: PaddedPowerInputs,
+}
+
+impl Air for PaddedPowerAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+    type PublicInputs = PaddedPowerInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let num_padding_rows = trace_info.length() - pub_inputs.num_rows;
+        let degrees = vec![TransitionConstraintDegree::new(3)];
+        let context = AirContext::new(trace_info, degrees, 2, options)
+            .set_num_transition_exemptions(num_padding_rows + 1);
+        Self { context, pub_inputs }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn unpadded_trace_length(&self) -> usize {
+        self.pub_inputs.num_rows
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        result[0] = frame.next()[0] - frame.current()[0].exp(3u32.into());
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.unpadded_trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, BaseElement::new(3)),
+            Assertion::single(0, last_step, self.pub_inputs.last),
+        ]
+    }
+}
+
+// GENERIC PROVER
+// ================================================================================================
+
+type DefaultCoin = DefaultRandomCoin<Blake3_256<BaseElement>>;
+
+/// Prover for the AIRs defined in these tests.
+///
+/// Public inputs are derived from the trace by the function provided on construction. The
+/// Fiat-Shamir transcript is generated using the random coin `R`, the trace is committed to via
+/// the trace LDE selected by `L`, and the query seed is ground using the proof-of-work `P`.
+struct GenericProver<A: Air, R = DefaultCoin, L = DefaultLde, P = HashPow> {
+    pub_inputs: PubInputsFn<A>,
+    options: ProofOptions,
+    enforce_field_security: bool,
+    allow_insecure: bool,
+    context_data: Vec<u8>,
+    _phantom: PhantomData<(R, L, P)>,
+}
+
+type PubInputsFn<A> =
+    Box<dyn Fn(&TraceTable<BaseElement>) -> <A as Air>::PublicInputs + Send + Sync>;
+
+impl<A: Air, R, L, P> GenericProver<A, R, L, P> {
+    /// Returns a prover which generates proofs for the specified public inputs.
+    fn new(pub_inputs: A::PublicInputs) -> Self
+    where
+        A::PublicInputs: Clone + Send + Sync + 'static,
+    {
+        Self::from_trace(move |_| pub_inputs.clone())
+    }
+
+    /// Returns a prover which derives public inputs from the trace via `pub_inputs`.
+    fn from_trace(
+        pub_inputs: impl Fn(&TraceTable<BaseElement>) -> A::PublicInputs + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            pub_inputs: Box::new(pub_inputs),
+            options: ProofOptions::new(4, 8, 0, FieldExtension::Quadratic, 2, 1),
+            enforce_field_security: false,
+            allow_insecure: true,
+            context_data: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn with_options(mut self, options: ProofOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    fn with_context_data(mut self, context_data: &[u8]) -> Self {
+        self.context_data = context_data.to_vec();
+        self
+    }
+}
+
+impl<A, R, L, P> Prover for GenericProver<A, R, L, P>
+where
+    A: Air<BaseField = BaseElement> + 'static,
+    R: RandomCoin<BaseField = BaseElement, Hasher = Blake3_256<BaseElement>>,
+    L: TestTraceLde,
+    P: ProofOfWork<Blake3_256<BaseElement>>,
+{
+    type BaseField = BaseElement;
+    type Air = A;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = Blake3_256<BaseElement>;
+    type VC = MerkleTree<Blake3_256<BaseElement>>;
+    type RandomCoin = R;
+    type ProofOfWork = P;
+    type TraceLde<E: FieldElement<BaseField = BaseElement>> = L::TraceLde<E>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, Blake3_256<BaseElement>, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = BaseElement>> =
+        DefaultConstraintEvaluator<'a, A, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> A::PublicInputs {
+        (self.pub_inputs)(trace)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn enforce_field_security(&self) -> bool {
+        self.enforce_field_security
+    }
+
+    fn allow_insecure(&self) -> bool {
+        self.allow_insecure
+    }
+
+    fn context_data(&self) -> &[u8] {
+        &self.context_data
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        L::new_trace_lde(trace_info, main_trace, domain, partition_option)
+    }
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+}
+
+/// Trace LDE used by [GenericProver] to commit to the trace.
+trait TestTraceLde {
+    type TraceLde<E: FieldElement<BaseField = BaseElement>>: TraceLde<
+        E,
+        HashFn = Blake3_256<BaseElement>,
+        VC = MerkleTree<Blake3_256<BaseElement>>,
+    >;
+
+    fn new_trace_lde<E: FieldElement<BaseField = BaseElement>>(
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<BaseElement>,
+        domain: &StarkDomain<BaseElement>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>);
+}
+
+/// Commits to the trace via [DefaultTraceLde].
+struct DefaultLde;
+
+impl TestTraceLde for DefaultLde {
+    type TraceLde<E: FieldElement<BaseField = BaseElement>> =
+        DefaultTraceLde<E, Blake3_256<BaseElement>, MerkleTree<Blake3_256<BaseElement>>>;
+
+    fn new_trace_lde<E: FieldElement<BaseField = BaseElement>>(
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<BaseElement>,
+        domain: &StarkDomain<BaseElement>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+}
+
+/// Commits to the trace via [ChunkedTraceLde].
+struct ChunkedLde;
+
+impl TestTraceLde for ChunkedLde {
+    type TraceLde<E: FieldElement<BaseField = BaseElement>> =
+        ChunkedTraceLde<E, Blake3_256<BaseElement>, MerkleTree<Blake3_256<BaseElement>>>;
+
+    fn new_trace_lde<E: FieldElement<BaseField = BaseElement>>(
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<BaseElement>,
+        domain: &StarkDomain<BaseElement>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        ChunkedTraceLde::new(trace_info, main_trace, domain, partition_option)
     }
 }